@@ -1,5 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use mixed_signals::prelude::*;
+use mixed_signals::rng::RngAlgo;
 
 fn bench_sine_sample(c: &mut Criterion) {
     let sine = Sine::default();
@@ -40,10 +41,102 @@ fn bench_mix_sample(c: &mut Criterion) {
     });
 }
 
+fn bench_rng_gaussian(c: &mut Criterion) {
+    for (name, algorithm) in [
+        ("rng_gaussian_chacha", RngAlgo::ChaCha),
+        ("rng_gaussian_fast", RngAlgo::Fast),
+    ] {
+        let mut rng = Rng::with_seed(42).with_algorithm(algorithm);
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                let mut acc = 0.0;
+                for _ in 0..1024 {
+                    acc += rng.gaussian(0.0, 1.0).unwrap();
+                }
+                black_box(acc);
+            })
+        });
+    }
+}
+
+fn bench_keyframes_sample(c: &mut Criterion) {
+    let pairs: Vec<(f32, f32)> = (0..100)
+        .map(|i| (i as f32 * 0.01, ((i * 37) % 101) as f32 / 100.0))
+        .collect();
+    let keyframes = Keyframes::from_pairs(&pairs);
+    let mut buffer = vec![0.0f32; 1024];
+    c.bench_function("keyframes_sample_into_100pt", |b| {
+        b.iter(|| {
+            keyframes.sample_into(0.0, 1.0 / 1024.0, &mut buffer);
+            black_box(buffer[0]);
+        })
+    });
+}
+
+fn bench_composition_batch(c: &mut Criterion) {
+    // Three levels: Mix(Add(Sine, Multiply(Sine, Triangle)), Add(Square, Sawtooth))
+    let tree = Mix::new(
+        Add::new(
+            Sine::with_frequency(440.0),
+            Multiply::new(Sine::with_frequency(3.0), Triangle::with_frequency(220.0)),
+        ),
+        Add::new(
+            Square::with_frequency(110.0),
+            Sawtooth::with_frequency(55.0),
+        ),
+        0.4,
+    );
+    let dt = 1.0 / 48_000.0;
+    let mut buffer = vec![0.0f32; 48_000];
+    c.bench_function("composition_per_sample_48k", |b| {
+        b.iter(|| {
+            for (i, value) in buffer.iter_mut().enumerate() {
+                *value = tree.sample(i as f64 * dt);
+            }
+            black_box(buffer[0]);
+        })
+    });
+    c.bench_function("composition_batch_48k", |b| {
+        b.iter(|| {
+            tree.sample_batch(0.0, dt, &mut buffer);
+            black_box(buffer[0]);
+        })
+    });
+}
+
+fn bench_fast_white_noise(c: &mut Criterion) {
+    let noise = FastWhiteNoise::new(42, 1.0, 48_000.0);
+    let dt = 1.0 / 48_000.0;
+    let mut buffer = vec![0.0f32; 48_000];
+    c.bench_function("fast_white_noise_per_sample_48k", |b| {
+        b.iter(|| {
+            for (i, value) in buffer.iter_mut().enumerate() {
+                *value = noise.sample(i as f64 * dt);
+            }
+            black_box(buffer[0]);
+        })
+    });
+    for (name, noise) in [
+        ("fast_white_noise_batch_scalar_48k", noise.scalar_only()),
+        ("fast_white_noise_batch_48k", noise),
+    ] {
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                noise.sample_batch(0.0, dt, &mut buffer);
+                black_box(buffer[0]);
+            })
+        });
+    }
+}
+
 criterion_group!(
     signal_benches,
     bench_sine_sample,
     bench_sine_sample_into,
-    bench_mix_sample
+    bench_mix_sample,
+    bench_rng_gaussian,
+    bench_keyframes_sample,
+    bench_composition_batch,
+    bench_fast_white_noise
 );
 criterion_main!(signal_benches);
@@ -1,7 +1,7 @@
 // <FILE>examples/kitt.rs</FILE> - <DESC>KITT scanner with police lights and siren audio</DESC>
-// <VERS>VERSION: 4.2.0 - 2026-01-02</VERS>
-// <WCTX>Merge kitt_circular effects and add police siren audio</WCTX>
-// <CLOG>Space=mode switch, unique sounds per mode tied to light waveforms</CLOG>
+// <VERS>VERSION: 4.7.0 - 2026-10-16</VERS>
+// <WCTX>Stereo audio output</WCTX>
+// <CLOG>Stereo realtime audio with the KITT sweep panned by scanner position</CLOG>
 
 //! # K.I.T.T. Scanner with Police Lights & Siren Audio
 //!
@@ -21,6 +21,7 @@
 //! - US Police: Alternating wail/yelp siren (650-1500Hz sweeps)
 //! - EU modes: Two-tone "nee-naw" (525Hz/660Hz alternating)
 //! - Cylinder modes: Low rumbling pulse with rotation sweep
+//! - Live audio is stereo: the KITT sweep pans with the scanner position
 //!
 //! Run with:
 //!   Visual only: `cargo run --example kitt --features visualization`
@@ -39,6 +40,7 @@ mod tui_demo {
         execute,
         terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     };
+    use mixed_signals::math::radial_falloff;
     use mixed_signals::prelude::*;
     use mixed_signals::types::SignalSpec;
     use ratatui::{
@@ -68,7 +70,7 @@ mod tui_demo {
 // === LIGHT EFFECTS ===
 // KITT: Larson Scanner (Triangle Wave LFO)
 let position = triangle_wave(time, period);  // 0→1→0
-let brightness = (1.0 - distance / falloff).max(0.0);
+let brightness = radial_falloff(distance, falloff);
 
 // Cylinder: 3D rotation with foreshortening
 let phi = rotation_angle();  // 0 to 2π
@@ -247,8 +249,7 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
 
         fn kitt_brightness_at(&self, x_norm: f64) -> f64 {
             let center = self.triangle_wave();
-            let distance = (center - x_norm).abs();
-            (1.0 - (distance / self.falloff_width)).max(0.0)
+            radial_falloff(center - x_norm, self.falloff_width)
         }
 
         // --- Cylinder rotation ---
@@ -270,7 +271,7 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
             let phi = self.rotation_angle();
             let theta = (x_norm - 0.5) * PI;
             let angular_dist = (phi.sin() - theta.sin()).abs();
-            let proximity = (1.0 - angular_dist / self.angular_falloff.sin()).max(0.0);
+            let proximity = radial_falloff(angular_dist, self.angular_falloff.sin());
             let foreshortening = theta.cos().max(0.0);
             proximity * foreshortening * self.facing_brightness()
         }
@@ -285,7 +286,7 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
             let theta = (band_pos - 0.5) * PI;
             let phi = self.rotation_angle();
             let angular_dist = (phi.sin() - theta.sin()).abs();
-            let proximity = (1.0 - angular_dist / self.angular_falloff.sin()).max(0.0);
+            let proximity = radial_falloff(angular_dist, self.angular_falloff.sin());
             let foreshortening = theta.cos().max(0.0);
             proximity * foreshortening * self.facing_brightness()
         }
@@ -323,7 +324,7 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
             };
             let half_center = if is_left { 0.25 } else { 0.75 };
             let dist = (x_norm - half_center).abs() / 0.25;
-            let falloff = (1.0 - dist * 0.5).max(0.0);
+            let falloff = radial_falloff(dist, 2.0);
             let final_bright = brightness * falloff;
             let val = (final_bright * 255.0) as u8;
             if is_left {
@@ -342,7 +343,7 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
             let screen_x = theta.sin();
             let beam_width = 0.3;
             let dist = (lens_x - screen_x).abs();
-            let beam = (1.0 - dist / beam_width).max(0.0);
+            let beam = radial_falloff(dist, beam_width);
             let facing = lens_angle.cos().max(0.0);
             let brightness = beam * facing;
             let b = (brightness * 235.0 + 20.0) as u8;
@@ -428,9 +429,10 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
                 carrier_freq,
                 depth,
                 ..
-            } => {
-                format!("FM(carrier={}Hz, depth={})", carrier_freq, depth)
-            }
+            } => match depth.as_static() {
+                Some(depth) => format!("FM(carrier={}Hz, depth={})", carrier_freq, depth),
+                None => format!("FM(carrier={}Hz, depth=signal)", carrier_freq),
+            },
             SignalSpec::Add { a, b } => {
                 format!("Add({}, {})", describe_spec(a), describe_spec(b))
             }
@@ -504,17 +506,10 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
         sample_rate: u32,
         sample_index: u64,
         state: Arc<SharedAudioState>,
-        // Filter states
-        svf_low: f32,
-        svf_band: f32,
-        lp_x1: f32,
-        lp_x2: f32,
-        lp_y1: f32,
-        lp_y2: f32,
-        nt_x1: f32,
-        nt_x2: f32,
-        nt_y1: f32,
-        nt_y2: f32,
+        // Streaming filters
+        breath: SvfState,
+        lowpass: BiquadState,
+        notch: BiquadState,
         // KITT oscillator phases
         phase_beat1: f32,
         phase_beat2: f32,
@@ -523,82 +518,33 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
         siren_phase: f32,
         // RNG
         rng_state: u64,
-        // Filter coefficients
-        lp_b0: f32,
-        lp_b1: f32,
-        lp_b2: f32,
-        lp_a1: f32,
-        lp_a2: f32,
-        nt_b0: f32,
-        nt_b1: f32,
-        nt_b2: f32,
-        nt_a1: f32,
-        nt_a2: f32,
+        // KITT pitch contour
+        envelope: Keyframes,
+        // Right half of the current stereo frame
+        pending_right: Option<f32>,
     }
 
     #[cfg(feature = "realtime-audio")]
     impl MultiModeAudioSource {
         fn new(sample_rate: u32, state: Arc<SharedAudioState>) -> Self {
             let sr = sample_rate as f32;
-            let (lp_b0, lp_b1, lp_b2, lp_a1, lp_a2) =
-                Self::biquad_lp(480.0, std::f32::consts::FRAC_1_SQRT_2, sr);
-            let (nt_b0, nt_b1, nt_b2, nt_a1, nt_a2) = Self::biquad_notch(450.0, 2.0, sr);
             Self {
                 sample_rate,
                 sample_index: 0,
                 state,
-                svf_low: 0.0,
-                svf_band: 0.0,
-                lp_x1: 0.0,
-                lp_x2: 0.0,
-                lp_y1: 0.0,
-                lp_y2: 0.0,
-                nt_x1: 0.0,
-                nt_x2: 0.0,
-                nt_y1: 0.0,
-                nt_y2: 0.0,
+                breath: SvfState::bandpass(380.0, 15.0, sr),
+                lowpass: BiquadState::lowpass(480.0, sr),
+                notch: BiquadState::notch(450.0, 2.0, sr),
                 phase_beat1: 0.0,
                 phase_beat2: 0.0,
                 phase_sub: 0.0,
                 siren_phase: 0.0,
                 rng_state: 42,
-                lp_b0,
-                lp_b1,
-                lp_b2,
-                lp_a1,
-                lp_a2,
-                nt_b0,
-                nt_b1,
-                nt_b2,
-                nt_a1,
-                nt_a2,
+                envelope: Self::kitt_envelope(),
+                pending_right: None,
             }
         }
 
-        fn biquad_lp(fc: f32, q: f32, sr: f32) -> (f32, f32, f32, f32, f32) {
-            use std::f32::consts::PI;
-            let w = 2.0 * PI * fc / sr;
-            let (c, a) = (w.cos(), w.sin() / (2.0 * q));
-            let b1 = 1.0 - c;
-            let b0 = b1 / 2.0;
-            let a0 = 1.0 + a;
-            (b0 / a0, b1 / a0, b0 / a0, -2.0 * c / a0, (1.0 - a) / a0)
-        }
-
-        fn biquad_notch(fc: f32, q: f32, sr: f32) -> (f32, f32, f32, f32, f32) {
-            use std::f32::consts::PI;
-            let w = 2.0 * PI * fc / sr;
-            let (c, a) = (w.cos(), w.sin() / (2.0 * q));
-            let a0 = 1.0 + a;
-            (
-                1.0 / a0,
-                -2.0 * c / a0,
-                1.0 / a0,
-                -2.0 * c / a0,
-                (1.0 - a) / a0,
-            )
-        }
-
         fn next_noise(&mut self) -> f32 {
             self.rng_state = self.rng_state.wrapping_add(0x9e3779b97f4a7c15);
             let mut z = self.rng_state;
@@ -608,8 +554,8 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
             (z as f32 / u64::MAX as f32) * 2.0 - 1.0
         }
 
-        fn kitt_envelope(t: f32) -> f32 {
-            const KF: &[(f32, f32)] = &[
+        fn kitt_envelope() -> Keyframes {
+            Keyframes::from_pairs(&[
                 (0.00, 0.20),
                 (0.06, 0.22),
                 (0.10, 0.45),
@@ -631,25 +577,12 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
                 (0.85, 0.01),
                 (1.00, 0.00),
                 (1.20, 0.00),
-            ];
-            if t <= KF[0].0 {
-                return KF[0].1;
-            }
-            if t >= KF[KF.len() - 1].0 {
-                return KF[KF.len() - 1].1;
-            }
-            for i in 0..KF.len() - 1 {
-                if t >= KF[i].0 && t < KF[i + 1].0 {
-                    let a = (t - KF[i].0) / (KF[i + 1].0 - KF[i].0);
-                    return KF[i].1 + a * (KF[i + 1].1 - KF[i].1);
-                }
-            }
-            KF[KF.len() - 1].1
+            ])
         }
 
         /// Generate KITT scanner sound
         fn generate_kitt(&mut self) -> f32 {
-            use std::f32::consts::{PI, TAU};
+            use std::f32::consts::TAU;
             let period = self.state.get_period() as f32;
             let sr = self.sample_rate as f32;
             let dt = 1.0 / sr;
@@ -657,7 +590,7 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
             let t_in_period = global_t % period;
             let stretch = period / BASE_SOUND_DURATION as f32;
             let t_sound = t_in_period / stretch;
-            let env_val = Self::kitt_envelope(t_sound);
+            let env_val = self.envelope.sample(t_sound as f64);
             let base_freq = 380.0 + env_val * 140.0;
             let vib = 3.25 * (TAU * 8.33 * t_sound).sin();
             let freq = base_freq + vib;
@@ -669,28 +602,13 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
                 + (TAU * self.phase_beat2).sin()
                 + (TAU * self.phase_sub).sin() * 0.4)
                 / 2.4;
-            let lp = self.lp_b0 * raw + self.lp_b1 * self.lp_x1 + self.lp_b2 * self.lp_x2
-                - self.lp_a1 * self.lp_y1
-                - self.lp_a2 * self.lp_y2;
-            self.lp_x2 = self.lp_x1;
-            self.lp_x1 = raw;
-            self.lp_y2 = self.lp_y1;
-            self.lp_y1 = lp;
-            let nt = self.nt_b0 * lp + self.nt_b1 * self.nt_x1 + self.nt_b2 * self.nt_x2
-                - self.nt_a1 * self.nt_y1
-                - self.nt_a2 * self.nt_y2;
-            self.nt_x2 = self.nt_x1;
-            self.nt_x1 = lp;
-            self.nt_y2 = self.nt_y1;
-            self.nt_y1 = nt;
+            let lp = self.lowpass.process(raw);
+            let nt = self.notch.process(lp);
             let clip = nt.clamp(-1.0, 0.7);
             let white = self.next_noise();
-            let fc = base_freq.clamp(20.0, sr * 0.49);
-            let f = 2.0 * (PI * fc / sr).sin();
-            self.svf_low += f * self.svf_band;
-            let hi = white - self.svf_low - self.svf_band / 15.0;
-            self.svf_band += f * hi;
-            let mix = clip * 0.9 + self.svf_band * 0.1;
+            self.breath.set_cutoff(base_freq);
+            let breath = self.breath.process(white);
+            let mix = clip * 0.9 + breath * 0.1;
             mix * env_val * 0.7
         }
 
@@ -887,6 +805,22 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
             (thump + body + click) * beat_env.max(0.05) * facing.max(0.3) * 0.6
         }
 
+        /// Stereo position: the KITT sweep follows the scanner, other modes sit centered
+        fn pan_position(&self) -> f32 {
+            if self.state.get_mode() != DisplayMode::Kitt {
+                return 0.0;
+            }
+            let period = self.state.get_period();
+            let global_t = self.sample_index as f64 / self.sample_rate as f64;
+            let t_norm = (global_t % period) / period;
+            let position = if t_norm < 0.5 {
+                t_norm * 2.0
+            } else {
+                2.0 - t_norm * 2.0
+            };
+            (position * 2.0 - 1.0) as f32
+        }
+
         fn generate_sample(&mut self) -> f32 {
             let mode = self.state.get_mode();
             let sample = match mode {
@@ -917,12 +851,18 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
             if !self.state.is_running() {
                 return None;
             }
-            if self.state.is_muted() {
+            if let Some(right) = self.pending_right.take() {
+                return Some(right);
+            }
+            let (left_gain, right_gain) = equal_power_gains(self.pan_position());
+            let sample = if self.state.is_muted() {
                 self.sample_index += 1;
-                Some(0.0)
+                0.0
             } else {
-                Some(self.generate_sample())
-            }
+                self.generate_sample()
+            };
+            self.pending_right = Some(sample * right_gain);
+            Some(sample * left_gain)
         }
     }
 
@@ -932,7 +872,7 @@ let eu_freq = Remap::new(selector, -1.0, 1.0, 525.0, 660.0);
             None
         }
         fn channels(&self) -> u16 {
-            1
+            2
         }
         fn sample_rate(&self) -> u32 {
             self.sample_rate
@@ -1613,4 +1553,4 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 // <FILE>examples/kitt.rs</FILE> - <DESC>KITT scanner with police lights and siren audio</DESC>
-// <VERS>END OF VERSION: 4.2.0 - 2026-01-02</VERS>
+// <VERS>END OF VERSION: 4.7.0 - 2026-10-16</VERS>
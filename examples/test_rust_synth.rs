@@ -1,14 +1,15 @@
 // <FILE>examples/test_rust_synth.rs</FILE> - <DESC>KITT scanner sound synthesis test</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Testing mixed-signals library synthesis capabilities</WCTX>
-// <CLOG>Fixed double normalization and noise speckles</CLOG>
+// <VERS>VERSION: 2.2.0</VERS>
+// <WCTX>Library WAV rendering</WCTX>
+// <CLOG>Use audio::write_wav instead of a hand-rolled RIFF writer</CLOG>
 
 //! KITT Scanner Sound - Full mixed-signals Synthesis
 //!
 //! Implements the complete tone_only.py recipe using library primitives.
 //!
-//! Run with: cargo run --example test_rust_synth
+//! Run with: cargo run --example test_rust_synth --features audio
 
+use mixed_signals::audio::write_wav;
 use mixed_signals::generators::{Keyframe, Keyframes, Sine};
 use mixed_signals::processing::Remap;
 use mixed_signals::traits::Signal;
@@ -283,44 +284,5 @@ fn biquad_notch_coeffs(center_hz: f32, q: f32, sample_rate: f32) -> (f32, f32, f
     (b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0)
 }
 
-// Minimal WAV writer
-fn write_wav(path: &str, sample_rate: u32, samples: &[f32]) -> std::io::Result<()> {
-    use std::fs::File;
-    use std::io::{BufWriter, Write};
-
-    let file = File::create(path)?;
-    let mut writer = BufWriter::new(file);
-
-    let num_samples = samples.len() as u32;
-    let bytes_per_sample = 2u16;
-    let num_channels = 1u16;
-    let byte_rate = sample_rate * num_channels as u32 * bytes_per_sample as u32;
-    let block_align = num_channels * bytes_per_sample;
-    let data_size = num_samples * bytes_per_sample as u32;
-    let file_size = 36 + data_size;
-
-    writer.write_all(b"RIFF")?;
-    writer.write_all(&file_size.to_le_bytes())?;
-    writer.write_all(b"WAVE")?;
-    writer.write_all(b"fmt ")?;
-    writer.write_all(&16u32.to_le_bytes())?;
-    writer.write_all(&1u16.to_le_bytes())?;
-    writer.write_all(&num_channels.to_le_bytes())?;
-    writer.write_all(&sample_rate.to_le_bytes())?;
-    writer.write_all(&byte_rate.to_le_bytes())?;
-    writer.write_all(&block_align.to_le_bytes())?;
-    writer.write_all(&(bytes_per_sample * 8).to_le_bytes())?;
-    writer.write_all(b"data")?;
-    writer.write_all(&data_size.to_le_bytes())?;
-
-    for &sample in samples {
-        let clamped = sample.clamp(-1.0, 1.0);
-        let int_sample = (clamped * 32767.0) as i16;
-        writer.write_all(&int_sample.to_le_bytes())?;
-    }
-
-    Ok(())
-}
-
 // <FILE>examples/test_rust_synth.rs</FILE> - <DESC>KITT scanner sound synthesis test</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.2.0</VERS>
@@ -1,7 +1,7 @@
 // <FILE>examples/visualizer.rs</FILE> - <DESC>Interactive signal visualizer demonstration</DESC>
-// <VERS>VERSION: 1.6.0 - 2025-12-27T13:14:12Z</VERS>
-// <WCTX>Upgrade time precision to f64</WCTX>
-// <CLOG>Updated time variables and SignalEntry time_span to f64</CLOG>
+// <VERS>VERSION: 1.10.0 - 2026-10-16</VERS>
+// <WCTX>Tuning noise distributions visually</WCTX>
+// <CLOG>h toggles HistogramView of each signal</CLOG>
 
 //! # Signal Visualizer
 //!
@@ -14,13 +14,16 @@
 //! cargo run --example visualizer --features visualization
 //! ```
 //!
-//! Press 'q' to quit.
+//! Press 'q' to quit, 'h' to show value distributions instead of waveforms.
 use crossterm::{
     event::{self, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use mixed_signals::{prelude::*, visualization::ColorGradient};
+use mixed_signals::{
+    prelude::*,
+    visualization::{ColorGradient, FrameClock, HistogramView},
+};
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
@@ -334,10 +337,13 @@ fn run_app<B: ratatui::backend::Backend>(
 where
     <B as ratatui::backend::Backend>::Error: 'static,
 {
-    let mut time: f64 = 0.0;
     let mut paused = false;
     let mut scroll_speed: f64 = 0.002;
+    // One frame advances time by scroll_speed; loops every 2 seconds
+    let mut clock = FrameClock::new(1.0 / scroll_speed).with_wrap(2.0);
+    let mut time = clock.time();
     let mut use_gradients = false;
+    let mut histograms = false;
     let mut selected_graph: usize = 0;
     let mut fullscreen = false;
     const MAX_SPEED: f64 = 0.006;
@@ -392,12 +398,13 @@ where
             let status = if paused { " [PAUSED]" } else { "" };
             let gradient_status = if use_gradients { " [GRADIENTS]" } else { "" };
             let fullscreen_status = if fullscreen { " [FULLSCREEN]" } else { "" };
+            let histogram_status = if histograms { " [HISTOGRAM]" } else { "" };
             let speed_pct = (scroll_speed / MAX_SPEED * 100.0) as u8;
             let selected_name = filtered.get(selected_graph).map(|e| e.name).unwrap_or("None");
             let title = Paragraph::new(vec![
                 Line::from(Span::styled(
-                    format!("mixed-signals Visualizer{}{}{} | Speed: {}% | Selected: {}",
-                        status, gradient_status, fullscreen_status, speed_pct, selected_name),
+                    format!("mixed-signals Visualizer{}{}{}{} | Speed: {}% | Selected: {}",
+                        status, gradient_status, fullscreen_status, histogram_status, speed_pct, selected_name),
                     Style::default().fg(Color::Cyan),
                 )),
                 Line::from(Span::styled(
@@ -405,7 +412,7 @@ where
                     Style::default().fg(Color::Gray),
                 )),
                 Line::from(Span::styled(
-                    format!("q:quit | ←→:speed | c:gradients | ↑↓:select | 1:all 2:waves 3:noise 4:env 5:mix/proc | Page {}/{}",
+                    format!("q:quit | ←→:speed | c:gradients | h:histogram | ↑↓:select | 1:all 2:waves 3:noise 4:env 5:mix/proc | Page {}/{}",
                         page + 1, page_count),
                     Style::default().fg(Color::Gray),
                 )),
@@ -438,6 +445,7 @@ where
                         time_span: entry.time_span,
                         time_mode: entry.time_mode,
                         use_gradients,
+                        histogram: histograms,
                         is_selected: true,
                     },
                 );
@@ -456,6 +464,7 @@ where
                             time_span: entry.time_span,
                             time_mode: entry.time_mode,
                             use_gradients,
+                            histogram: histograms,
                             is_selected: index == selected_graph,
                         },
                     );
@@ -469,13 +478,16 @@ where
                     KeyCode::Char('q') => return Ok(()),
                     KeyCode::Char(' ') => paused = !paused,
                     KeyCode::Char('c') => use_gradients = !use_gradients,
+                    KeyCode::Char('h') => histograms = !histograms,
                     KeyCode::Left => {
                         // Speed up (increase scroll_speed toward max)
                         scroll_speed = (scroll_speed + SPEED_STEP).min(MAX_SPEED);
+                        clock.set_fps(1.0 / scroll_speed);
                     }
                     KeyCode::Right => {
                         // Slow down (decrease scroll_speed toward min)
                         scroll_speed = (scroll_speed - SPEED_STEP).max(MIN_SPEED);
+                        clock.set_fps(1.0 / scroll_speed);
                     }
                     KeyCode::Up => {
                         selected_graph = if selected_graph == 0 {
@@ -517,21 +529,20 @@ where
         }
         // Advance time (simulate animation) unless paused
         if !paused {
-            time += scroll_speed;
-            if time > 2.0 {
-                time = 0.0; // Reset for looping
-            }
+            clock.tick();
+            time = clock.time();
         }
     }
 }
 struct RenderConfig<'a> {
-    signal: &'a dyn Signal,
+    signal: &'a (dyn Signal + 'static),
     title: &'a str,
     color: Color,
     current_time: f64,
     time_span: f64,
     time_mode: TimeMode,
     use_gradients: bool,
+    histogram: bool,
     is_selected: bool,
 }
 
@@ -544,6 +555,7 @@ fn render_signal(f: &mut ratatui::Frame, area: Rect, cfg: RenderConfig<'_>) {
         time_span,
         time_mode,
         use_gradients,
+        histogram,
         is_selected,
     } = cfg;
     // Render block with title - white border if selected
@@ -557,30 +569,34 @@ fn render_signal(f: &mut ratatui::Frame, area: Rect, cfg: RenderConfig<'_>) {
     // Render signal within the block
     // Braille mode is the default, providing 2×4 sub-cell resolution
     let range = signal.output_range();
-    if matches!(time_mode, TimeMode::Loop) {
-        struct LoopSignal<'a> {
-            inner: &'a dyn Signal,
-            phase: f64,
-            period: f64,
-        }
-        impl<'a> Signal for LoopSignal<'a> {
-            fn output_range(&self) -> SignalRange {
-                self.inner.output_range()
-            }
-            fn sample(&self, t: f64) -> f32 {
-                let wrapped = (t + self.phase).rem_euclid(self.period);
-                self.inner.sample(wrapped)
-            }
-            fn sample_with_context(&self, t: f64, ctx: &SignalContext) -> f32 {
-                let wrapped = (t + self.phase).rem_euclid(self.period);
-                self.inner.sample_with_context(wrapped, ctx)
-            }
-        }
-        let looped = LoopSignal {
-            inner: signal,
-            phase: current_time,
-            period: time_span.max(0.0001),
+    if histogram {
+        // Distribution of values over the visible window, one bar per half cell
+        let start = match time_mode {
+            TimeMode::Static | TimeMode::Loop => 0.0,
+            TimeMode::Moving => current_time,
         };
+        let mut view = HistogramView::from_signal(signal, (start, start + time_span), 4096)
+            .bins(inner.width as usize * 2)
+            .value_range(range.min, range.max)
+            .log_scale(true);
+        if use_gradients {
+            let gradient = ColorGradient::new()
+                .add_stop(0.0, Color::Blue)
+                .add_stop(0.5, Color::Green)
+                .add_stop(1.0, Color::Red);
+            view = view.gradient(gradient);
+        } else {
+            view = view.style(Style::default().fg(color));
+        }
+        f.render_widget(view, inner);
+        return;
+    }
+    if matches!(time_mode, TimeMode::Loop) {
+        let period = time_span.max(0.0001);
+        let looped = signal
+            .clone_box()
+            .looped(period as f32)
+            .with_phase(current_time.rem_euclid(period) as f32);
         let mut signal_view = SignalView::new(&looped)
             .time_range(0.0, time_span)
             .value_range(range.min, range.max);
@@ -620,4 +636,4 @@ fn render_signal(f: &mut ratatui::Frame, area: Rect, cfg: RenderConfig<'_>) {
 }
 
 // <FILE>examples/visualizer.rs</FILE> - <DESC>Interactive signal visualizer demonstration</DESC>
-// <VERS>END OF VERSION: 1.6.0 - 2025-12-27T13:14:12Z</VERS>
+// <VERS>END OF VERSION: 1.10.0 - 2026-10-16</VERS>
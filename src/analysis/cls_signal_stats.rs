@@ -0,0 +1,204 @@
+// <FILE>mixed-signals/src/analysis/cls_signal_stats.rs</FILE> - <DESC>Summary statistics over a sampled window</DESC>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>One set of window statistics</WCTX>
+// <CLOG>Added measure_window (slot midpoints) and energy for processing::mean/energy</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalTime};
+
+/// Level statistics of a signal sampled over a time window.
+///
+/// [`measure`](Self::measure) samples at `t_start + i / sample_rate` for
+/// `i in 0..round(duration * sample_rate)`. Every value is exact for those
+/// sampled points and therefore an estimate of the continuous signal:
+/// a peak between samples is missed, and RMS/mean converge as the rate rises.
+///
+/// [`measure_window`](Self::measure_window) instead takes a sample count and
+/// samples the middle of each equal slot of `[start, end)`, the convention of
+/// [`processing::mean`](crate::processing::mean) and
+/// [`processing::energy`](crate::processing::energy).
+///
+/// Non-finite samples are skipped and counted in `skipped`. A window with no
+/// finite samples (zero duration, invalid bounds or rate) measures all zeros.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::analysis::SignalStats;
+/// use mixed_signals::generators::Sine;
+///
+/// let stats = SignalStats::measure(&Sine::with_frequency(1.0), 0.25, 1.0, 1000.0);
+/// assert!((stats.rms - core::f32::consts::FRAC_1_SQRT_2).abs() < 1e-3);
+/// assert_eq!(stats.zero_crossings, 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SignalStats {
+    /// Largest absolute value
+    pub peak: f32,
+    /// Root mean square
+    pub rms: f32,
+    /// Mean (DC offset)
+    pub mean: f32,
+    pub min: f32,
+    pub max: f32,
+    /// Sign changes between consecutive non-zero samples
+    pub zero_crossings: usize,
+    /// Finite samples measured
+    pub samples: usize,
+    /// Non-finite samples skipped
+    pub skipped: usize,
+}
+
+impl SignalStats {
+    /// Sample `signal` over `[t_start, t_start + duration)` at `sample_rate` Hz.
+    pub fn measure(
+        signal: &dyn Signal,
+        t_start: SignalTime,
+        duration: SignalTime,
+        sample_rate: f32,
+    ) -> Self {
+        let count = duration * sample_rate as SignalTime;
+        if !t_start.is_finite() || !count.is_finite() || count < 0.5 || sample_rate <= 0.0 {
+            return Self::default();
+        }
+        let rate = sample_rate as SignalTime;
+        Self::from_samples(
+            (0..count.round() as usize).map(|i| signal.sample(t_start + i as SignalTime / rate)),
+        )
+    }
+
+    /// Sample `signal` at the middle of `samples` equal slots over `[start, end)`.
+    pub fn measure_window(
+        signal: &dyn Signal,
+        start: SignalTime,
+        end: SignalTime,
+        samples: usize,
+    ) -> Self {
+        if samples == 0 || !start.is_finite() || !end.is_finite() || end <= start {
+            return Self::default();
+        }
+        let dt = (end - start) / samples as SignalTime;
+        Self::from_samples(
+            (0..samples).map(|i| signal.sample(start + dt * (i as SignalTime + 0.5))),
+        )
+    }
+
+    /// Sum of squared samples times the sample spacing `dt`: a midpoint
+    /// approximation of ∫ x(t)² dt over the window. 0.0 with no samples.
+    pub fn energy(&self, dt: SignalTime) -> f32 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+        let rms = self.rms as f64;
+        (rms * rms * self.samples as f64 * dt) as f32
+    }
+
+    /// Statistics of already-rendered samples.
+    pub fn from_samples(samples: impl IntoIterator<Item = f32>) -> Self {
+        let mut stats = Self {
+            min: f32::INFINITY,
+            max: f32::NEG_INFINITY,
+            ..Self::default()
+        };
+        let (mut sum, mut sum_sq) = (0.0f64, 0.0f64);
+        let mut last_sign = 0.0f32;
+        for value in samples {
+            if !value.is_finite() {
+                stats.skipped += 1;
+                continue;
+            }
+            stats.samples += 1;
+            stats.min = stats.min.min(value);
+            stats.max = stats.max.max(value);
+            sum += value as f64;
+            sum_sq += value as f64 * value as f64;
+            if value != 0.0 {
+                let sign = value.signum();
+                if last_sign != 0.0 && sign != last_sign {
+                    stats.zero_crossings += 1;
+                }
+                last_sign = sign;
+            }
+        }
+        if stats.samples == 0 {
+            return Self {
+                skipped: stats.skipped,
+                ..Self::default()
+            };
+        }
+        let n = stats.samples as f64;
+        stats.peak = stats.min.abs().max(stats.max.abs());
+        stats.mean = (sum / n) as f32;
+        stats.rms = (sum_sq / n).sqrt() as f32;
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_stats_unit_sine_period() {
+        let stats = SignalStats::measure(&Sine::with_frequency(1.0), 0.25, 1.0, 1000.0);
+        assert_eq!(stats.samples, 1000);
+        assert!((stats.rms - core::f32::consts::FRAC_1_SQRT_2).abs() < 1e-3);
+        assert!(stats.mean.abs() < 1e-3);
+        assert_eq!(stats.zero_crossings, 2);
+        assert!((stats.peak - 1.0).abs() < 1e-6);
+        assert!((stats.min + 1.0).abs() < 1e-4 && (stats.max - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_stats_constant() {
+        let stats = SignalStats::measure(&Constant::new(0.5), 0.0, 1.0, 100.0);
+        assert_eq!(stats.peak, 0.5);
+        assert_eq!(stats.rms, 0.5);
+        assert_eq!(stats.mean, 0.5);
+        assert_eq!((stats.min, stats.max), (0.5, 0.5));
+        assert_eq!(stats.zero_crossings, 0);
+    }
+
+    #[test]
+    fn test_stats_empty_windows() {
+        let sine = Sine::with_frequency(1.0);
+        let zero = SignalStats::default();
+        assert_eq!(SignalStats::measure(&sine, 0.0, 0.0, 1000.0), zero);
+        assert_eq!(SignalStats::measure(&sine, 0.0, 1.0, 0.0), zero);
+        assert_eq!(SignalStats::measure(&sine, f64::NAN, 1.0, 1000.0), zero);
+        assert_eq!(SignalStats::measure(&sine, 0.0, -1.0, 1000.0), zero);
+        assert_eq!(SignalStats::from_samples([]), zero);
+    }
+
+    #[test]
+    fn test_stats_skip_non_finite() {
+        let stats = SignalStats::from_samples([1.0, f32::NAN, -3.0, f32::INFINITY, 0.0, 2.0]);
+        assert_eq!((stats.samples, stats.skipped), (4, 2));
+        assert_eq!((stats.min, stats.max, stats.peak), (-3.0, 2.0, 3.0));
+        assert_eq!(stats.mean, 0.0);
+        // 1 -> -3 and -3 -> (0) -> 2
+        assert_eq!(stats.zero_crossings, 2);
+
+        let nan = from_fn(|_| f32::NAN);
+        let stats = SignalStats::measure(&nan, 0.0, 1.0, 10.0);
+        assert_eq!((stats.samples, stats.skipped, stats.rms), (0, 10, 0.0));
+    }
+
+    #[test]
+    fn test_stats_measure_window_samples_slot_midpoints() {
+        let ramp = from_fn(|t| t as f32);
+        let stats = SignalStats::measure_window(&ramp, 0.0, 1.0, 4);
+        assert_eq!(stats.samples, 4);
+        assert_eq!((stats.min, stats.max), (0.125, 0.875));
+        assert_eq!(stats.mean, 0.5);
+        let c = SignalStats::measure_window(&Constant::new(0.5), 0.0, 4.0, 100);
+        assert!((c.energy(0.04) - 1.0).abs() < 1e-5);
+        assert_eq!(SignalStats::default().energy(f64::NAN), 0.0);
+    }
+}
+
+// <FILE>mixed-signals/src/analysis/cls_signal_stats.rs</FILE> - <DESC>Summary statistics over a sampled window</DESC>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -0,0 +1,236 @@
+// <FILE>mixed-signals/src/analysis/fnc_spectrum.rs</FILE> - <DESC>Magnitude spectrum via an in-crate radix-2 FFT</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, alloc imports, core:: instead of std:: paths, ChaCha-backed tests gated on the chacha feature</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalTime};
+use alloc::vec::Vec;
+use core::f64::consts::TAU;
+
+/// Magnitude spectrum of a signal window as `(hz, magnitude)` pairs.
+///
+/// Samples the signal at `sample_rate` starting at `t_start`, taking at most
+/// `fft_size` samples and no more than `duration` covers. A Hann window is
+/// applied, the block is zero-padded to the next power of two at or above
+/// `fft_size`, and a radix-2 FFT gives bins from DC to Nyquist (`N / 2 + 1`
+/// entries, `sample_rate / N` Hz apart).
+///
+/// Magnitudes are scaled so a full-window sine of amplitude `A` peaks near
+/// `A`. Non-finite samples count as 0. Returns an empty Vec when
+/// `fft_size`, `duration` or `sample_rate` is zero, negative or non-finite.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::analysis::spectrum;
+/// use mixed_signals::generators::Sine;
+///
+/// let bins = spectrum(&Sine::with_frequency(1000.0), 0.0, 1.0, 8000.0, 256);
+/// assert_eq!(bins.len(), 129);
+/// let (hz, magnitude) = bins[32];
+/// assert_eq!(hz, 1000.0);
+/// assert!((magnitude - 1.0).abs() < 0.01);
+/// ```
+pub fn spectrum(
+    signal: &dyn Signal,
+    t_start: SignalTime,
+    duration: SignalTime,
+    sample_rate: f32,
+    fft_size: usize,
+) -> Vec<(f32, f32)> {
+    if fft_size == 0
+        || !t_start.is_finite()
+        || !duration.is_finite()
+        || duration <= 0.0
+        || !sample_rate.is_finite()
+        || sample_rate <= 0.0
+    {
+        return Vec::new();
+    }
+    let n = fft_size.next_power_of_two();
+    let rate = sample_rate as f64;
+    let captured = ((duration * rate).round() as usize).clamp(1, fft_size);
+
+    let mut re = vec![0.0; n];
+    let mut im = vec![0.0; n];
+    let mut window_sum = 0.0;
+    for (i, slot) in re.iter_mut().enumerate().take(captured) {
+        let window = if captured > 1 {
+            0.5 - 0.5 * (TAU * i as f64 / (captured - 1) as f64).cos()
+        } else {
+            1.0
+        };
+        let value = signal.sample(t_start + i as SignalTime / rate) as f64;
+        *slot = if value.is_finite() {
+            value * window
+        } else {
+            0.0
+        };
+        window_sum += window;
+    }
+    fft(&mut re, &mut im);
+
+    let scale = if window_sum > 0.0 {
+        2.0 / window_sum
+    } else {
+        0.0
+    };
+    (0..=n / 2)
+        .map(|k| {
+            let magnitude = (re[k] * re[k] + im[k] * im[k]).sqrt() * scale;
+            // DC and Nyquist have no mirrored twin
+            let magnitude = if k == 0 || k == n / 2 {
+                magnitude / 2.0
+            } else {
+                magnitude
+            };
+            ((k as f64 * rate / n as f64) as f32, magnitude as f32)
+        })
+        .collect()
+}
+
+/// Frequency in Hz of the strongest non-DC bin of [`spectrum`].
+///
+/// Accurate to one bin width (`sample_rate / fft_size` rounded up to a power
+/// of two). Returns None when the spectrum is empty or silent.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::analysis::dominant_frequency;
+/// use mixed_signals::generators::Sine;
+///
+/// let hz = dominant_frequency(&Sine::with_frequency(440.0), 0.0, 1.0, 8000.0, 1024).unwrap();
+/// assert!((hz - 440.0).abs() <= 8000.0 / 1024.0);
+/// ```
+pub fn dominant_frequency(
+    signal: &dyn Signal,
+    t_start: SignalTime,
+    duration: SignalTime,
+    sample_rate: f32,
+    fft_size: usize,
+) -> Option<f32> {
+    spectrum(signal, t_start, duration, sample_rate, fft_size)
+        .into_iter()
+        .skip(1)
+        .filter(|(_, magnitude)| *magnitude > 0.0)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(hz, _)| hz)
+}
+
+/// In-place iterative radix-2 FFT. `re.len()` must be a power of two.
+fn fft(re: &mut [f64], im: &mut [f64]) {
+    let n = re.len();
+    let bits = n.trailing_zeros();
+    if n < 2 {
+        return;
+    }
+    // Bit-reversal permutation
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if j > i {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let angle = -TAU / len as f64;
+        let (w_im, w_re) = angle.sin_cos();
+        for start in (0..n).step_by(len) {
+            let (mut cur_re, mut cur_im) = (1.0, 0.0);
+            for k in 0..len / 2 {
+                let a = start + k;
+                let b = a + len / 2;
+                let t_re = re[b] * cur_re - im[b] * cur_im;
+                let t_im = re[b] * cur_im + im[b] * cur_re;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+                let next_re = cur_re * w_re - cur_im * w_im;
+                cur_im = cur_re * w_im + cur_im * w_re;
+                cur_re = next_re;
+            }
+        }
+        len *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+    #[cfg(feature = "chacha")]
+    use crate::noise::WhiteNoise;
+
+    #[test]
+    fn test_fft_matches_naive_dft() {
+        let input: Vec<f64> = (0..8).map(|i| ((i * 7 + 3) % 5) as f64 - 2.0).collect();
+        let (mut re, mut im) = (input.clone(), vec![0.0; 8]);
+        fft(&mut re, &mut im);
+        for k in 0..8 {
+            let (mut dre, mut dim) = (0.0, 0.0);
+            for (i, x) in input.iter().enumerate() {
+                let angle = -TAU * (k * i) as f64 / 8.0;
+                dre += x * angle.cos();
+                dim += x * angle.sin();
+            }
+            assert!((re[k] - dre).abs() < 1e-9 && (im[k] - dim).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_spectrum_sine_440_at_48k() {
+        let sine = Sine::with_frequency(440.0);
+        let hz = dominant_frequency(&sine, 0.0, 1.0, 48_000.0, 4096).unwrap();
+        let bin_width = 48_000.0 / 4096.0;
+        assert!((hz - 440.0).abs() <= bin_width, "{hz}");
+    }
+
+    #[test]
+    fn test_spectrum_rounds_up_and_zero_pads() {
+        let sine = Sine::with_frequency(100.0);
+        // 1000 samples padded to 1024
+        let bins = spectrum(&sine, 0.0, 1.0, 1000.0, 1000);
+        assert_eq!(bins.len(), 513);
+        assert_eq!(bins[512].0, 500.0);
+        // Duration shorter than fft_size captures fewer samples
+        let short = spectrum(&sine, 0.0, 0.25, 1000.0, 1000);
+        assert_eq!(short.len(), 513);
+        let hz = dominant_frequency(&sine, 0.0, 0.25, 1000.0, 1000).unwrap();
+        assert!((hz - 100.0).abs() <= 1000.0 / 1024.0 * 2.0, "{hz}");
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn test_spectrum_white_noise_is_flat() {
+        let noise = WhiteNoise::new(7, 1.0, 48_000.0);
+        let bins = spectrum(&noise, 0.0, 1.0, 48_000.0, 4096);
+        let magnitudes: Vec<f32> = bins[1..].iter().map(|(_, m)| *m).collect();
+        let mean = magnitudes.iter().sum::<f32>() / magnitudes.len() as f32;
+        let max = magnitudes.iter().copied().fold(0.0, f32::max);
+        assert!(max < 5.0 * mean, "max {max}, mean {mean}");
+    }
+
+    #[test]
+    fn test_spectrum_invalid_and_silent() {
+        let sine = Sine::with_frequency(1.0);
+        assert!(spectrum(&sine, 0.0, 1.0, 100.0, 0).is_empty());
+        assert!(spectrum(&sine, 0.0, 0.0, 100.0, 64).is_empty());
+        assert!(spectrum(&sine, 0.0, 1.0, f32::NAN, 64).is_empty());
+        assert!(spectrum(&sine, f64::INFINITY, 1.0, 100.0, 64).is_empty());
+        assert_eq!(
+            dominant_frequency(&Constant::new(0.0), 0.0, 1.0, 100.0, 64),
+            None
+        );
+        // DC alone is not a dominant frequency
+        let dc = spectrum(&Constant::new(0.5), 0.0, 1.0, 64.0, 64);
+        assert!((dc[0].1 - 0.5).abs() < 0.01);
+    }
+}
+
+// <FILE>mixed-signals/src/analysis/fnc_spectrum.rs</FILE> - <DESC>Magnitude spectrum via an in-crate radix-2 FFT</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
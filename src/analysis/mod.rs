@@ -0,0 +1,15 @@
+// <FILE>mixed-signals/src/analysis/mod.rs</FILE> - <DESC>Signal analysis module</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>Level metering and test assertions</WCTX>
+// <CLOG>Added SignalStats</CLOG>
+
+//! Measurements of sampled signals: spectra and summary statistics.
+
+mod cls_signal_stats;
+mod fnc_spectrum;
+
+pub use cls_signal_stats::SignalStats;
+pub use fnc_spectrum::{dominant_frequency, spectrum};
+
+// <FILE>mixed-signals/src/analysis/mod.rs</FILE> - <DESC>Signal analysis module</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
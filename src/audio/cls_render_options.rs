@@ -0,0 +1,53 @@
+// <FILE>src/audio/cls_render_options.rs</FILE> - <DESC>Post-processing options for audio rendering</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Library WAV rendering</WCTX>
+// <CLOG>Initial creation - peak normalization and clamping flags</CLOG>
+
+/// Post-processing applied by [`render_samples`](super::render_samples).
+///
+/// Non-finite samples always become 0.0. Normalization runs before clamping.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::audio::RenderOptions;
+///
+/// let options = RenderOptions::new().with_normalize(true);
+/// assert!(options.normalize && options.clamp);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Scale so the loudest sample has magnitude 1.0 (silence is left as is)
+    pub normalize: bool,
+    /// Clamp samples to [-1, 1]
+    pub clamp: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            normalize: false,
+            clamp: true,
+        }
+    }
+}
+
+impl RenderOptions {
+    /// No normalization, clamping on.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    pub fn with_clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+}
+
+// <FILE>src/audio/cls_render_options.rs</FILE> - <DESC>Post-processing options for audio rendering</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
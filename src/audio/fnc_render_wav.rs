@@ -0,0 +1,302 @@
+// <FILE>src/audio/fnc_render_wav.rs</FILE> - <DESC>Render signals to samples and 16-bit WAV files</DESC>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>One WAV encoder</WCTX>
+// <CLOG>WAV files written through export::WavExportConfig instead of hound</CLOG>
+
+use super::RenderOptions;
+use crate::export::WavExportConfig;
+use crate::traits::{Signal, StereoSignal};
+use std::io;
+use std::path::Path;
+
+/// Sample `signal` at `sample_rate` for `duration` seconds from t = 0.
+///
+/// Returns `round(duration * sample_rate)` samples; a zero, negative, or
+/// non-finite duration (or a zero sample rate) yields an empty buffer.
+/// Non-finite samples become 0.0, then `options` apply.
+pub fn render_samples(
+    signal: &dyn Signal,
+    sample_rate: u32,
+    duration: f32,
+    options: &RenderOptions,
+) -> Vec<f32> {
+    let mut samples = vec![0.0; frame_count(sample_rate, duration)];
+    signal.sample_batch(0.0, 1.0 / sample_rate.max(1) as f64, &mut samples);
+    apply_options(&mut samples, options);
+    samples
+}
+
+/// Sample a stereo `signal` into interleaved `[l0, r0, l1, r1, ...]` frames.
+///
+/// Same frame count and sanitizing as [`render_samples`]. Normalization uses
+/// the peak across both channels, so the balance between them is kept.
+pub fn render_stereo_samples(
+    signal: &dyn StereoSignal,
+    sample_rate: u32,
+    duration: f32,
+    options: &RenderOptions,
+) -> Vec<f32> {
+    let mut samples = vec![0.0; 2 * frame_count(sample_rate, duration)];
+    signal.sample_stereo_interleaved(0.0, 1.0 / sample_rate.max(1) as f64, &mut samples);
+    apply_options(&mut samples, options);
+    samples
+}
+
+fn frame_count(sample_rate: u32, duration: f32) -> usize {
+    if duration.is_finite() && duration > 0.0 && sample_rate > 0 {
+        (duration as f64 * sample_rate as f64).round() as usize
+    } else {
+        0
+    }
+}
+
+fn apply_options(samples: &mut [f32], options: &RenderOptions) {
+    for sample in samples.iter_mut() {
+        if !sample.is_finite() {
+            *sample = 0.0;
+        }
+    }
+    if options.normalize {
+        let peak = samples.iter().fold(0.0_f32, |peak, s| peak.max(s.abs()));
+        if peak > 0.0 {
+            let gain = 1.0 / peak;
+            samples.iter_mut().for_each(|s| *s *= gain);
+        }
+    }
+    if options.clamp {
+        samples.iter_mut().for_each(|s| *s = s.clamp(-1.0, 1.0));
+    }
+}
+
+/// Render `signal` to a 16-bit mono WAV with default [`RenderOptions`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use mixed_signals::audio::render_wav;
+/// use mixed_signals::generators::Sine;
+///
+/// render_wav(&Sine::with_frequency(440.0), "a440.wav", 48_000, 1.0).unwrap();
+/// ```
+pub fn render_wav<P: AsRef<Path>>(
+    signal: &dyn Signal,
+    path: P,
+    sample_rate: u32,
+    duration: f32,
+) -> io::Result<()> {
+    render_wav_with(
+        signal,
+        path,
+        sample_rate,
+        duration,
+        &RenderOptions::default(),
+    )
+}
+
+/// Render `signal` to a 16-bit mono WAV after applying `options`.
+pub fn render_wav_with<P: AsRef<Path>>(
+    signal: &dyn Signal,
+    path: P,
+    sample_rate: u32,
+    duration: f32,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let samples = render_samples(signal, sample_rate, duration, options);
+    write_wav(path, sample_rate, &samples)
+}
+
+/// Render a stereo `signal` to a 16-bit stereo WAV with default [`RenderOptions`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use mixed_signals::audio::render_stereo_wav;
+/// use mixed_signals::composition::Pan;
+/// use mixed_signals::generators::Sine;
+///
+/// // A440 circling between the speakers every 2 seconds
+/// let sweep = Pan::new(Sine::with_frequency(440.0), Sine::with_frequency(0.5));
+/// render_stereo_wav(&sweep, "a440_pan.wav", 48_000, 4.0).unwrap();
+/// ```
+pub fn render_stereo_wav<P: AsRef<Path>>(
+    signal: &dyn StereoSignal,
+    path: P,
+    sample_rate: u32,
+    duration: f32,
+) -> io::Result<()> {
+    render_stereo_wav_with(
+        signal,
+        path,
+        sample_rate,
+        duration,
+        &RenderOptions::default(),
+    )
+}
+
+/// Render a stereo `signal` to a 16-bit stereo WAV after applying `options`.
+pub fn render_stereo_wav_with<P: AsRef<Path>>(
+    signal: &dyn StereoSignal,
+    path: P,
+    sample_rate: u32,
+    duration: f32,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let samples = render_stereo_samples(signal, sample_rate, duration, options);
+    write_stereo_wav(path, sample_rate, &samples)
+}
+
+/// Write `samples` as a 16-bit mono PCM WAV.
+///
+/// Shorthand for a 16-bit mono [`WavExportConfig`]: samples are clamped to
+/// [-1, 1] and non-finite values written as silence. A zero sample rate is
+/// rejected with `InvalidInput`.
+pub fn write_wav<P: AsRef<Path>>(path: P, sample_rate: u32, samples: &[f32]) -> io::Result<()> {
+    write_pcm16(path, sample_rate, 1, samples)
+}
+
+/// Write interleaved `[l0, r0, l1, r1, ...]` samples as a 16-bit stereo WAV.
+///
+/// Same conversion as [`write_wav`]. An odd sample count (a half frame) is
+/// rejected with `InvalidInput`.
+pub fn write_stereo_wav<P: AsRef<Path>>(
+    path: P,
+    sample_rate: u32,
+    samples: &[f32],
+) -> io::Result<()> {
+    write_pcm16(path, sample_rate, 2, samples)
+}
+
+fn write_pcm16<P: AsRef<Path>>(
+    path: P,
+    sample_rate: u32,
+    channels: u16,
+    samples: &[f32],
+) -> io::Result<()> {
+    WavExportConfig::new(sample_rate)
+        .with_channels(channels)
+        .write_file(path, samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composition::Pan;
+    use crate::generators::{Constant, Sine};
+    use crate::traits::from_fn;
+    use std::path::PathBuf;
+
+    fn temp_wav(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("mixed_signals_{name}_{}.wav", std::process::id()))
+    }
+
+    #[test]
+    fn test_render_wav_header_fields() {
+        let path = temp_wav("header");
+        render_wav(&Sine::with_frequency(440.0), &path, 48_000, 1.0).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let u16_at = |i: usize| u16::from_le_bytes([bytes[i], bytes[i + 1]]);
+        let u32_at = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap());
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(u32_at(4) as usize, bytes.len() - 8);
+        assert_eq!(&bytes[8..16], b"WAVEfmt ");
+        assert_eq!(u16_at(20), 1); // PCM
+        assert_eq!(u16_at(22), 1); // mono
+        assert_eq!(u32_at(24), 48_000);
+        assert_eq!(u32_at(28), 96_000); // byte rate
+        assert_eq!(u16_at(32), 2); // block align
+        assert_eq!(u16_at(34), 16);
+        assert_eq!(&bytes[36..40], b"data");
+        assert_eq!(u32_at(40), 96_000);
+    }
+
+    #[test]
+    fn test_render_wav_reads_back() {
+        let path = temp_wav("readback");
+        render_wav(&Sine::new(440.0, 0.5, 0.0, 0.0), &path, 48_000, 1.0).unwrap();
+        let mut reader = hound::WavReader::open(&path).unwrap();
+        let samples: Vec<i16> = reader.samples::<i16>().map(|s| s.unwrap()).collect();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(reader.spec().sample_rate, 48_000);
+        assert_eq!(samples.len(), 48_000);
+        let peak = samples.iter().map(|s| s.unsigned_abs()).max().unwrap() as f32;
+        assert!((peak / i16::MAX as f32 - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_render_samples_options() {
+        let quiet = Sine::new(10.0, 0.25, 0.0, 0.0);
+        let plain = render_samples(&quiet, 1000, 0.5, &RenderOptions::new());
+        assert_eq!(plain.len(), 500);
+        let peak = |v: &[f32]| v.iter().fold(0.0_f32, |p, s| p.max(s.abs()));
+        assert!((peak(&plain) - 0.25).abs() < 1e-3);
+
+        let normalized = render_samples(
+            &quiet,
+            1000,
+            0.5,
+            &RenderOptions::new().with_normalize(true),
+        );
+        assert!((peak(&normalized) - 1.0).abs() < 1e-6);
+
+        let loud = Constant::new(3.0);
+        let clamped = render_samples(&loud, 100, 0.1, &RenderOptions::new());
+        assert!(clamped.iter().all(|&s| s == 1.0));
+        let raw = render_samples(&loud, 100, 0.1, &RenderOptions::new().with_clamp(false));
+        assert!(raw.iter().all(|&s| s == 3.0));
+    }
+
+    #[test]
+    fn test_render_edge_cases() {
+        let sine = Sine::with_frequency(1.0);
+        for duration in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(render_samples(&sine, 48_000, duration, &RenderOptions::new()).is_empty());
+        }
+        assert!(render_samples(&sine, 0, 1.0, &RenderOptions::new()).is_empty());
+
+        let broken = from_fn(|_| f32::NAN);
+        let options = RenderOptions::new().with_normalize(true);
+        assert!(render_samples(&broken, 100, 0.1, &options)
+            .iter()
+            .all(|&s| s == 0.0));
+
+        let path = temp_wav("empty");
+        render_wav(&sine, &path, 48_000, 0.0).unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 44);
+        std::fs::remove_file(&path).ok();
+        assert!(write_wav(&path, 0, &[0.0]).is_err());
+    }
+
+    #[test]
+    fn test_render_stereo_wav_reads_back() {
+        let path = temp_wav("stereo");
+        let left = Pan::new(Sine::new(440.0, 0.5, 0.0, 0.0), Constant::new(-1.0));
+        render_stereo_wav(&left, &path, 48_000, 0.5).unwrap();
+        let mut reader = hound::WavReader::open(&path).unwrap();
+        let samples: Vec<i16> = reader.samples::<i16>().map(|s| s.unwrap()).collect();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(reader.spec().channels, 2);
+        assert_eq!(samples.len(), 48_000);
+        let peak = |channel: usize| {
+            samples
+                .chunks(2)
+                .map(|frame| frame[channel].unsigned_abs())
+                .max()
+                .unwrap()
+        };
+        assert!((peak(0) as f32 / i16::MAX as f32 - 0.5).abs() < 0.01);
+        assert_eq!(peak(1), 0);
+
+        let normalized =
+            render_stereo_samples(&left, 1000, 0.5, &RenderOptions::new().with_normalize(true));
+        assert_eq!(normalized.len(), 1000);
+        assert!(normalized.chunks(2).all(|frame| frame[1].abs() < 1e-6));
+        assert!(write_stereo_wav(&path, 48_000, &[0.0; 3]).is_err());
+    }
+}
+
+// <FILE>src/audio/fnc_render_wav.rs</FILE> - <DESC>Render signals to samples and 16-bit WAV files</DESC>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -0,0 +1,26 @@
+// <FILE>src/audio/mod.rs</FILE> - <DESC>Audio rendering module root</DESC>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>One WAV encoder</WCTX>
+// <CLOG>WAV writing goes through export::WavExportConfig</CLOG>
+
+//! Rendering signals to audio buffers and WAV files.
+//!
+//! Enabled by the `audio` feature (implies `wav`). [`render_samples`]
+//! samples a signal at an audio rate with optional peak normalization and
+//! clamping; [`render_wav`] and [`write_wav`] write 16-bit mono PCM.
+//! [`render_stereo_samples`], [`render_stereo_wav`] and [`write_stereo_wav`]
+//! do the same for a [`StereoSignal`](crate::traits::StereoSignal). Files
+//! are encoded by [`WavExportConfig`](crate::export::WavExportConfig); use it
+//! directly for other bit depths or µ-law.
+
+mod cls_render_options;
+mod fnc_render_wav;
+
+pub use cls_render_options::RenderOptions;
+pub use fnc_render_wav::{
+    render_samples, render_stereo_samples, render_stereo_wav, render_stereo_wav_with, render_wav,
+    render_wav_with, write_stereo_wav, write_wav,
+};
+
+// <FILE>src/audio/mod.rs</FILE> - <DESC>Audio rendering module root</DESC>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -0,0 +1,133 @@
+// <FILE>src/composition/cls_crossfade.rs</FILE> - <DESC>Signal-driven crossfade between two signals</DESC>
+// <VERS>VERSION: 1.0.1</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Example uses FastWhiteNoise so it runs without chacha</CLOG>
+
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Crossfades from signal A to signal B under control of a blend signal.
+///
+/// Output = a * (1 - m) + b * m, where `m` is the blend signal's raw value
+/// clamped to [0, 1] at each sample (non-finite values count as 0.5). Unlike
+/// [`EnvelopeMix`](super::EnvelopeMix), the blend is not rescaled from its
+/// `output_range()`, so a `Ramp` from 0 to 1 maps directly onto the fade.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::Crossfade;
+/// use mixed_signals::generators::{Ramp, Sine};
+/// use mixed_signals::random::FastWhiteNoise;
+/// use mixed_signals::traits::Signal;
+///
+/// // Sine to noise over 2 seconds
+/// let fade = Crossfade::new(Sine::with_frequency(3.0), FastWhiteNoise::with_seed(1), Ramp::new(0.0, 1.0, 2.0));
+/// assert_eq!(fade.sample(0.0), Sine::with_frequency(3.0).sample(0.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Crossfade<A, B, M> {
+    pub a: A,
+    pub b: B,
+    /// Blend control (0 = all A, 1 = all B)
+    pub blend: M,
+}
+
+impl<A: Signal, B: Signal, M: Signal> Crossfade<A, B, M> {
+    pub fn new(a: A, b: B, blend: M) -> Self {
+        Self { a, b, blend }
+    }
+
+    /// Blend factor at time `t` (0 = all A, 1 = all B).
+    pub fn blend_at(&self, t: SignalTime) -> f32 {
+        clamp_blend(self.blend.sample(t))
+    }
+}
+
+fn clamp_blend(blend: f32) -> f32 {
+    if blend.is_finite() {
+        blend.clamp(0.0, 1.0)
+    } else {
+        0.5
+    }
+}
+
+fn lerp(a: f32, b: f32, blend: f32) -> f32 {
+    a * (1.0 - blend) + b * blend
+}
+
+impl<A: Signal + Clone, B: Signal + Clone, M: Signal + Clone> Signal for Crossfade<A, B, M> {
+    fn output_range(&self) -> SignalRange {
+        // Blend varies over time, so cover both inputs
+        let ra = self.a.output_range();
+        let rb = self.b.output_range();
+        SignalRange::new(ra.min.min(rb.min), ra.max.max(rb.max))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        lerp(self.a.sample(t), self.b.sample(t), self.blend_at(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let blend = clamp_blend(self.blend.sample_with_context(t, ctx));
+        lerp(
+            self.a.sample_with_context(t, ctx),
+            self.b.sample_with_context(t, ctx),
+            blend,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composition::Mix;
+    use crate::generators::{Constant, Ramp, Sine, Triangle};
+
+    #[test]
+    fn test_crossfade_ramp_matches_mix_at_endpoints() {
+        let (a, b) = (Sine::with_frequency(2.0), Triangle::with_frequency(5.0));
+        let fade = Crossfade::new(a, b, Ramp::new(0.0, 1.0, 2.0));
+        let (start, end) = (Mix::new(a, b, 0.0), Mix::new(a, b, 1.0));
+        assert_eq!(fade.sample(0.0), start.sample(0.0));
+        for t in [2.0, 2.3, 3.7] {
+            assert_eq!(fade.sample(t), end.sample(t));
+        }
+        let mid = Mix::new(a, b, 0.5);
+        assert!((fade.sample(1.0) - mid.sample(1.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_crossfade_clamps_out_of_range_blend() {
+        let fade =
+            |m: f32| Crossfade::new(Constant::new(0.2), Constant::new(0.8), Constant::new(m));
+        assert_eq!(fade(-3.0).sample(0.0), 0.2);
+        assert_eq!(fade(7.0).sample(0.0), 0.8);
+        let nan = Crossfade::new(
+            Constant::new(0.2),
+            Constant::new(0.8),
+            crate::traits::from_fn(|_| f32::NAN),
+        );
+        assert!((nan.sample(0.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_crossfade_propagates_context() {
+        #[derive(Clone)]
+        struct FrameBlend;
+        impl Signal for FrameBlend {
+            fn sample(&self, _t: SignalTime) -> f32 {
+                0.0
+            }
+            fn sample_with_context(&self, _t: SignalTime, ctx: &SignalContext) -> f32 {
+                ctx.frame as f32 / 4.0
+            }
+        }
+        let fade = Crossfade::new(Constant::new(0.0), Constant::new(1.0), FrameBlend);
+        let ctx = SignalContext::new(1, 0);
+        assert!((fade.sample_with_context(0.0, &ctx) - 0.25).abs() < 1e-6);
+        assert_eq!(fade.sample(0.0), 0.0);
+    }
+}
+
+// <FILE>src/composition/cls_crossfade.rs</FILE> - <DESC>Signal-driven crossfade between two signals</DESC>
+// <VERS>END OF VERSION: 1.0.1</VERS>
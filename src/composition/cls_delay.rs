@@ -0,0 +1,119 @@
+// <FILE>src/composition/cls_delay.rs</FILE> - <DESC>Time-shift (delay) operator</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Staggered layering</WCTX>
+// <CLOG>Initial creation - Delay shifts the inner time axis by a fixed offset</CLOG>
+
+use crate::math::finite_or;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Shifts a signal later in time by `offset` seconds.
+///
+/// Output = inner(t - offset). A positive offset delays the signal (an
+/// envelope triggered at 0 now starts at `offset`); a negative offset
+/// advances it. Non-finite offsets are treated as 0.0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::Delay;
+/// use mixed_signals::envelopes::Impact;
+/// use mixed_signals::traits::Signal;
+///
+/// let first = Impact::new(1.0, 4.0);
+/// let echo = Delay::new(first, 0.3);
+/// assert_eq!(echo.sample(0.3), first.sample(0.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Delay<S> {
+    pub inner: S,
+    /// Time shift in seconds (positive = later)
+    pub offset: f32,
+}
+
+impl<S: Signal> Delay<S> {
+    pub fn new(inner: S, offset: f32) -> Self {
+        Self {
+            inner,
+            offset: finite_or(offset, 0.0),
+        }
+    }
+
+    fn shifted(&self, t: SignalTime) -> SignalTime {
+        t - finite_or(self.offset, 0.0) as SignalTime
+    }
+}
+
+impl<S: Signal + Clone> Signal for Delay<S> {
+    fn output_range(&self) -> SignalRange {
+        self.inner.output_range()
+    }
+
+    fn display_range(&self) -> (f32, f32) {
+        self.inner.display_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.inner.sample(self.shifted(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.inner.sample_with_context(self.shifted(t), ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Sine;
+    use crate::traits::SignalExt;
+
+    #[test]
+    fn test_delay_shifts_later() {
+        let sine = Sine::with_frequency(1.0);
+        let delayed = Delay::new(sine, 0.25);
+        for i in 0..50 {
+            let t = i as f64 * 0.03;
+            assert!((delayed.sample(t + 0.25) - sine.sample(t)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_delay_negative_offset_advances() {
+        let sine = Sine::with_frequency(2.0);
+        let advanced = sine.delay(-0.1);
+        for i in 0..50 {
+            let t = i as f64 * 0.03;
+            assert!((advanced.sample(t) - sine.sample(t + 0.1)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_delay_non_finite_offset_is_zero() {
+        let sine = Sine::with_frequency(3.0);
+        for offset in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let delayed = Delay::new(sine, offset);
+            assert_eq!(delayed.offset, 0.0);
+            assert_eq!(delayed.sample(0.3), sine.sample(0.3));
+        }
+    }
+
+    #[test]
+    fn test_delay_forwards_context() {
+        #[derive(Clone)]
+        struct FrameSignal;
+        impl Signal for FrameSignal {
+            fn sample(&self, t: SignalTime) -> f32 {
+                t as f32
+            }
+            fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+                t as f32 + ctx.frame as f32
+            }
+        }
+        let delayed = Delay::new(FrameSignal, 0.5);
+        let ctx = SignalContext::new(10, 0);
+        assert!((delayed.sample_with_context(2.0, &ctx) - 11.5).abs() < 1e-6);
+    }
+}
+
+// <FILE>src/composition/cls_delay.rs</FILE> - <DESC>Time-shift (delay) operator</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -1,58 +1,139 @@
 // <FILE>mixed-signals/src/composition/cls_frequency_mod.rs</FILE> - <DESC>Frequency modulation operator</DESC>
-// <VERS>VERSION: 1.0.0 - 2025-12-22</VERS>
-// <WCTX>Signal generator implementation</WCTX>
-// <CLOG>Initial implementation</CLOG>
+// <VERS>VERSION: 1.5.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Depth is an FmDepth (static or signal) so FrequencyMod no longer needs serde</CLOG>
 
 use crate::traits::{Signal, SignalContext, SignalTime};
-use std::f64::consts::TAU;
+#[cfg(feature = "serde")]
+use crate::types::SignalOrFloat;
+use alloc::boxed::Box;
+use core::f64::consts::TAU;
+
+/// Modulation depth for [`FrequencyMod`]: a fixed index or a signal.
+///
+/// A signal depth (typically an envelope) is sampled at every `t`.
+#[derive(Clone)]
+pub enum FmDepth {
+    /// Fixed modulation index
+    Static(f32),
+    /// Modulation index sampled from a signal
+    Signal(Box<dyn Signal>),
+}
+
+impl core::fmt::Debug for FmDepth {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FmDepth::Static(depth) => f.debug_tuple("Static").field(depth).finish(),
+            FmDepth::Signal(_) => f.debug_tuple("Signal").finish(),
+        }
+    }
+}
+
+impl From<f32> for FmDepth {
+    fn from(depth: f32) -> Self {
+        FmDepth::Static(depth)
+    }
+}
+
+impl From<Box<dyn Signal>> for FmDepth {
+    fn from(signal: Box<dyn Signal>) -> Self {
+        FmDepth::Signal(signal)
+    }
+}
+
+/// Builds a signal spec immediately, reusing a cached build. A spec that
+/// fails to build becomes a depth of 0.0, leaving the carrier unmodulated.
+#[cfg(feature = "serde")]
+impl From<SignalOrFloat> for FmDepth {
+    fn from(depth: SignalOrFloat) -> Self {
+        let (spec, cache) = match depth {
+            SignalOrFloat::Static(depth) => return FmDepth::Static(depth),
+            SignalOrFloat::Signal { spec, cache } => (spec, cache),
+        };
+        match cache.into_inner().unwrap_or_else(|| spec.build()) {
+            Ok(signal) => FmDepth::Signal(signal),
+            Err(_) => FmDepth::Static(0.0),
+        }
+    }
+}
 
 /// Phase modulation operator (commonly called "FM" in synthesizers).
 ///
 /// Despite the name, this implements phase modulation rather than true
 /// frequency modulation. This matches how most "FM" synthesizers actually
 /// work (including the DX7). True FM would require integrating the modulator;
-/// we modulate the phase directly for simplicity.
+/// we modulate the phase directly for simplicity. Use
+/// [`TrueFrequencyMod`](super::TrueFrequencyMod) when the instantaneous
+/// frequency itself must follow the modulator.
 ///
 /// The modulator signal offsets the carrier's phase, creating evolving waveforms.
 ///
-/// Output ≈ carrier(t + depth * modulator(t) / (TAU * carrier_freq))
+/// Output ≈ carrier(t + depth(t) * modulator(t) / (TAU * carrier_freq))
+///
+/// `depth` is an [`FmDepth`]: a plain `f32` gives a fixed modulation index,
+/// while a boxed signal (typically an envelope) is sampled at every `t` for
+/// DX-style evolving timbres. With the `serde` feature a `SignalOrFloat`
+/// converts too. A non-finite depth leaves the carrier unmodulated.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::FrequencyMod;
+/// use mixed_signals::generators::{Ramp, Sine};
+/// use mixed_signals::traits::Signal;
+///
+/// // Modulation index decays from 3 to 0 over one second
+/// let index: Box<dyn Signal> = Box::new(Ramp::new(3.0, 0.0, 1.0));
+/// let carrier = Sine::with_frequency(220.0);
+/// let fm = FrequencyMod::new(carrier, Sine::with_frequency(440.0), index, 220.0);
+/// assert_eq!(fm.sample(1.5), carrier.sample(1.5));
+/// ```
 #[derive(Debug, Clone)]
 pub struct FrequencyMod<C, M> {
     pub carrier: C,
     pub modulator: M,
     /// Modulation depth (how much the modulator affects the carrier)
-    pub depth: f32,
+    pub depth: FmDepth,
     /// Carrier frequency for the internal sine
     pub carrier_freq: f32,
 }
 
 impl<C: Signal, M: Signal> FrequencyMod<C, M> {
-    pub fn new(carrier: C, modulator: M, depth: f32, carrier_freq: f32) -> Self {
+    pub fn new(carrier: C, modulator: M, depth: impl Into<FmDepth>, carrier_freq: f32) -> Self {
         Self {
             carrier,
             modulator,
-            depth,
+            depth: depth.into(),
             carrier_freq,
         }
     }
 
     /// Simple FM with default carrier frequency
-    pub fn simple(carrier: C, modulator: M, depth: f32) -> Self {
+    pub fn simple(carrier: C, modulator: M, depth: impl Into<FmDepth>) -> Self {
         Self::new(carrier, modulator, depth, 1.0)
     }
-}
 
-impl<C: Signal, M: Signal> Signal for FrequencyMod<C, M> {
-    fn sample(&self, t: SignalTime) -> f32 {
+    /// Depth at `t`, or None when modulation should be skipped.
+    fn depth_at(&self, t: SignalTime, ctx: &SignalContext) -> Option<f64> {
         if !self.carrier_freq.is_finite() || self.carrier_freq == 0.0 {
-            return self.carrier.sample(t);
+            return None;
         }
-        if !self.depth.is_finite() {
+        let depth = match &self.depth {
+            FmDepth::Static(depth) => *depth,
+            FmDepth::Signal(signal) => signal.sample_with_context(t, ctx),
+        };
+        depth.is_finite().then_some(depth as f64)
+    }
+}
+
+impl<C: Signal + Clone, M: Signal + Clone> Signal for FrequencyMod<C, M> {
+    fn sample(&self, t: SignalTime) -> f32 {
+        let Some(depth) = self.depth_at(t, &SignalContext::default()) else {
             return self.carrier.sample(t);
-        }
+        };
         // Get modulator value and use it to offset the phase
         let mod_value = self.modulator.sample(t) * 2.0 - 1.0;
-        let phase_offset = self.depth as f64 * mod_value as f64;
+        let phase_offset = depth * mod_value as f64;
 
         // Phase modulation (not true FM, but standard in synthesizers)
         let modulated_t = t + phase_offset / (TAU * self.carrier_freq as f64);
@@ -61,14 +142,11 @@ impl<C: Signal, M: Signal> Signal for FrequencyMod<C, M> {
     }
 
     fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
-        if !self.carrier_freq.is_finite() || self.carrier_freq == 0.0 {
-            return self.carrier.sample_with_context(t, ctx);
-        }
-        if !self.depth.is_finite() {
+        let Some(depth) = self.depth_at(t, ctx) else {
             return self.carrier.sample_with_context(t, ctx);
-        }
+        };
         let mod_value = self.modulator.sample_with_context(t, ctx) * 2.0 - 1.0;
-        let phase_offset = self.depth as f64 * mod_value as f64;
+        let phase_offset = depth * mod_value as f64;
         let modulated_t = t + phase_offset / (TAU * self.carrier_freq as f64);
         self.carrier.sample_with_context(modulated_t, ctx)
     }
@@ -77,7 +155,10 @@ impl<C: Signal, M: Signal> Signal for FrequencyMod<C, M> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::envelopes::Adsr;
     use crate::generators::{Constant, Sine};
+    #[cfg(feature = "serde")]
+    use crate::types::SignalSpec;
 
     #[test]
     fn test_fm_zero_depth() {
@@ -133,7 +214,67 @@ mod tests {
         let t = 0.37;
         assert!((fm.sample(t) - carrier.sample(t)).abs() < 0.001);
     }
+
+    #[test]
+    fn test_fm_static_depth_bit_exact() {
+        let carrier = Sine::with_frequency(3.0);
+        let modulator = Sine::with_frequency(7.0);
+        let fm = FrequencyMod::new(carrier, modulator, 1.7, 3.0);
+        for i in 0..200 {
+            let t = i as f64 * 0.0137;
+            let mod_value = modulator.sample(t) * 2.0 - 1.0;
+            let modulated_t = t + 1.7f32 as f64 * mod_value as f64 / (TAU * 3.0);
+            assert_eq!(fm.sample(t), carrier.sample(modulated_t));
+        }
+    }
+
+    #[test]
+    fn test_fm_envelope_depth() {
+        let carrier = Sine::with_frequency(1.0);
+        let modulator = Sine::with_frequency(3.0);
+        let envelope: Box<dyn Signal> = Box::new(Adsr::new(0.5, 0.1, 0.7, 0.2).with_peak(4.0));
+        let fm = FrequencyMod::simple(carrier, modulator, envelope);
+        // Depth starts at 0, so t = 0 is the raw carrier
+        assert_eq!(fm.sample(0.0), carrier.sample(0.0));
+        let ctx = SignalContext::default();
+        assert_eq!(fm.sample_with_context(0.0, &ctx), carrier.sample(0.0));
+        // Once the envelope opens, the output is modulated
+        assert!((fm.sample(0.3) - carrier.sample(0.3)).abs() > 0.01);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_fm_spec_depth() {
+        let carrier = Sine::with_frequency(1.0);
+        let ramp = SignalSpec::Ramp {
+            start: 0.0,
+            end: 2.0,
+            duration: 1.0,
+        };
+        let fm = FrequencyMod::simple(
+            carrier,
+            Sine::with_frequency(3.0),
+            SignalOrFloat::from(ramp),
+        );
+        assert!(matches!(fm.depth, FmDepth::Signal(_)));
+        assert_eq!(fm.sample(0.0), carrier.sample(0.0));
+        assert!((fm.sample(0.4) - carrier.sample(0.4)).abs() > 0.01);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_fm_invalid_depth_spec_falls_back() {
+        let carrier = Sine::with_frequency(1.0);
+        let bad = SignalSpec::PoissonNoise {
+            seed: 0,
+            lambda: -1.0,
+            amplitude: 1.0,
+            offset: 0.0,
+        };
+        let fm = FrequencyMod::simple(carrier, Sine::with_frequency(2.0), SignalOrFloat::from(bad));
+        assert_eq!(fm.sample(0.37), carrier.sample(0.37));
+    }
 }
 
 // <FILE>mixed-signals/src/composition/cls_frequency_mod.rs</FILE> - <DESC>Frequency modulation operator</DESC>
-// <VERS>END OF VERSION: 1.0.0 - 2025-12-22</VERS>
+// <VERS>END OF VERSION: 1.5.0</VERS>
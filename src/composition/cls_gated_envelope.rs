@@ -0,0 +1,349 @@
+// <FILE>src/composition/cls_gated_envelope.rs</FILE> - <DESC>ADSR envelope driven by a gate signal</DESC>
+// <VERS>VERSION: 1.1.1</VERS>
+// <WCTX>Gate-driven ADSR envelope</WCTX>
+// <CLOG>Clamp level and report output range against the configured peak</CLOG>
+
+use crate::math::{finite_or, finite_or_f64, finite_or_min};
+use crate::traits::{Phase, Signal, SignalContext, SignalRange, SignalTime};
+use std::sync::Mutex;
+
+/// Envelope state captured at the most recent gate edge.
+#[derive(Debug, Clone, Copy)]
+struct GateState {
+    /// Time of the most recent sample
+    prev_time: SignalTime,
+    /// Gate level at the most recent sample
+    gate_high: bool,
+    /// Time of the most recent gate edge
+    edge_time: SignalTime,
+    /// Envelope level at the most recent gate edge
+    edge_level: f32,
+}
+
+impl GateState {
+    fn idle(t: SignalTime) -> Self {
+        Self {
+            prev_time: t,
+            gate_high: false,
+            edge_time: t,
+            edge_level: 0.0,
+        }
+    }
+}
+
+/// ADSR envelope driven by a gate signal, the way hardware synths work.
+///
+/// While the gate is high the envelope runs attack → decay → sustain and holds
+/// at the sustain level for as long as the gate stays high. When the gate drops
+/// the envelope releases from whatever level it reached to 0. Re-opening the
+/// gate mid-release restarts the attack from the current level (no click).
+///
+/// Unlike [`Adsr`](crate::envelopes::Adsr), which shapes normalized progress
+/// over a fixed duration, all stage times here are in seconds.
+///
+/// The gate is considered high when it samples above `threshold` (default 0.5,
+/// which works for both unipolar `Pulse` gates and bipolar `Square` gates).
+///
+/// # Stateful Evaluation
+///
+/// Gate edges are detected between successive samples, so time should advance
+/// monotonically (as in audio rendering or an animation loop). Sampling earlier
+/// than the previous sample resets the envelope to idle.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::GatedEnvelope;
+/// use mixed_signals::generators::Pulse;
+/// use mixed_signals::traits::Signal;
+///
+/// // Gate held from 0.0s to 1.0s
+/// let gate = Pulse::new(0.0, 1.0, 0.0, 1.0);
+/// let env = GatedEnvelope::new(gate, 0.1, 0.1, 0.6, 0.2);
+///
+/// let mut level = 0.0;
+/// for i in 0..=50 {
+///     level = env.sample(i as f64 * 0.01);
+/// }
+/// assert!((level - 0.6).abs() < 0.01); // Holding at sustain
+/// ```
+#[derive(Debug)]
+pub struct GatedEnvelope<G> {
+    /// Gate signal (high = note on)
+    pub gate: G,
+    /// Attack time in seconds (0 → peak)
+    pub attack: f32,
+    /// Decay time in seconds (peak → sustain)
+    pub decay: f32,
+    /// Sustain level as a fraction of peak (0..1)
+    pub sustain: f32,
+    /// Release time in seconds (current level → 0)
+    pub release: f32,
+    /// Peak level at end of attack (default 1.0)
+    pub peak: f32,
+    /// Gate threshold (gate is high when above this value)
+    pub threshold: f32,
+    state: Mutex<GateState>,
+}
+
+impl<G: Signal> GatedEnvelope<G> {
+    /// Create a gated envelope.
+    ///
+    /// # Arguments
+    /// * `gate` - Gate signal (high = note on)
+    /// * `attack` - Attack time in seconds
+    /// * `decay` - Decay time in seconds
+    /// * `sustain` - Sustain level (0..1, fraction of peak)
+    /// * `release` - Release time in seconds
+    pub fn new(gate: G, attack: f32, decay: f32, sustain: f32, release: f32) -> Self {
+        Self {
+            gate,
+            attack: finite_or_min(attack, 0.0, 0.01),
+            decay: finite_or_min(decay, 0.0, 0.1),
+            sustain: finite_or(sustain, 0.7).clamp(0.0, 1.0),
+            release: finite_or_min(release, 0.0, 0.2),
+            peak: 1.0,
+            threshold: 0.5,
+            state: Mutex::new(GateState::idle(0.0)),
+        }
+    }
+
+    pub fn with_peak(mut self, peak: f32) -> Self {
+        self.peak = peak;
+        self
+    }
+
+    /// Set the level above which the gate is considered high.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Lifecycle phase at the most recently sampled time.
+    ///
+    /// `Start` = attack/decay, `Active` = sustain, `End` = release,
+    /// `Done` = idle (gate low and fully released).
+    pub fn phase(&self) -> Phase {
+        let state = *self.state.lock().unwrap();
+        let elapsed = state.prev_time - state.edge_time;
+        if state.gate_high {
+            if elapsed < self.attack_time(state.edge_level) + self.decay_secs() {
+                Phase::Start
+            } else {
+                Phase::Active
+            }
+        } else if self.level_at(&state, state.prev_time) > 0.0 {
+            Phase::End
+        } else {
+            Phase::Done
+        }
+    }
+
+    /// Reset to idle (gate low, level 0).
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = GateState::idle(0.0);
+    }
+
+    fn peak_level(&self) -> f64 {
+        finite_or(self.peak, 1.0) as f64
+    }
+
+    fn decay_secs(&self) -> f64 {
+        finite_or_min(self.decay, 0.0, 0.1) as f64
+    }
+
+    /// Attack duration from `start_level`, keeping the attack slope constant.
+    fn attack_time(&self, start_level: f32) -> f64 {
+        let attack = finite_or_min(self.attack, 0.0, 0.01) as f64;
+        let peak = self.peak_level();
+        if peak <= 0.0 {
+            return 0.0;
+        }
+        attack * (1.0 - start_level as f64 / peak).clamp(0.0, 1.0)
+    }
+
+    /// Envelope level at `t` given the most recent gate edge.
+    fn level_at(&self, state: &GateState, t: SignalTime) -> f32 {
+        let elapsed = (t - state.edge_time).max(0.0);
+        let start = state.edge_level as f64;
+
+        let level = if state.gate_high {
+            let peak = self.peak_level();
+            let sustain_level = finite_or(self.sustain, 0.7).clamp(0.0, 1.0) as f64 * peak;
+            let attack_time = self.attack_time(state.edge_level);
+            let decay = self.decay_secs();
+
+            if elapsed < attack_time {
+                // Attack phase: start → peak
+                start + (peak - start) * (elapsed / attack_time)
+            } else if elapsed < attack_time + decay {
+                // Decay phase: peak → sustain
+                let progress = (elapsed - attack_time) / decay;
+                peak - (peak - sustain_level) * progress
+            } else {
+                // Sustain phase: hold while gate is high
+                sustain_level
+            }
+        } else {
+            // Release phase: level at gate-off → 0
+            let release = finite_or_min(self.release, 0.0, 0.2) as f64;
+            if release > 0.0 {
+                start * (1.0 - elapsed / release).max(0.0)
+            } else {
+                0.0
+            }
+        };
+
+        let peak = self.peak_level();
+        level.clamp(peak.min(0.0), peak.max(0.0)) as f32
+    }
+
+    fn advance(&self, t: SignalTime, gate_value: f32) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let gate_high = gate_value.is_finite() && gate_value > finite_or(self.threshold, 0.5);
+
+        let mut state = self.state.lock().unwrap();
+        if t < state.prev_time {
+            // Time went backwards (reset/seek) - start from idle
+            *state = GateState::idle(t);
+        }
+
+        if gate_high != state.gate_high {
+            // Gate edge: restart from the current level to avoid clicks
+            let level = self.level_at(&state, t);
+            state.gate_high = gate_high;
+            state.edge_time = t;
+            state.edge_level = level;
+        }
+        state.prev_time = t;
+
+        self.level_at(&state, t)
+    }
+}
+
+/// Clones the configuration only; the clone starts idle with fresh gate state.
+impl<G: Clone> Clone for GatedEnvelope<G> {
+    fn clone(&self) -> Self {
+        Self {
+            gate: self.gate.clone(),
+            attack: self.attack,
+            decay: self.decay,
+            sustain: self.sustain,
+            release: self.release,
+            peak: self.peak,
+            threshold: self.threshold,
+            state: Mutex::new(GateState::idle(0.0)),
+        }
+    }
+}
+
+impl<G: Signal + Clone> Signal for GatedEnvelope<G> {
+    fn output_range(&self) -> SignalRange {
+        let peak = self.peak_level() as f32;
+        SignalRange::new(peak.min(0.0), peak.max(0.0))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let gate_value = self.gate.sample(t);
+        self.advance(t, gate_value)
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let gate_value = self.gate.sample_with_context(t, ctx);
+        self.advance(t, gate_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Pulse};
+
+    const DT: f64 = 0.001;
+
+    fn render<G: Signal + Clone>(env: &GatedEnvelope<G>, until: f64) -> Vec<f32> {
+        let steps = (until / DT).round() as usize;
+        (0..=steps).map(|i| env.sample(i as f64 * DT)).collect()
+    }
+
+    #[test]
+    fn test_gated_envelope_reaches_sustain() {
+        let env = GatedEnvelope::new(Constant::new(1.0), 0.1, 0.1, 0.6, 0.2);
+        let values = render(&env, 1.0);
+
+        // Peak at the end of attack
+        assert!((values[100] - 1.0).abs() < 0.02, "peak {}", values[100]);
+        // Holds at sustain after decay
+        assert!((values[500] - 0.6).abs() < 0.001);
+        assert!((values[1000] - 0.6).abs() < 0.001);
+        assert_eq!(env.phase(), Phase::Active);
+    }
+
+    #[test]
+    fn test_gated_envelope_peak_above_unit() {
+        let env = GatedEnvelope::new(Constant::new(1.0), 0.1, 0.1, 0.5, 0.2).with_peak(2.0);
+        assert_eq!(env.output_range(), SignalRange::new(0.0, 2.0));
+
+        let values = render(&env, 1.0);
+        assert!((values[100] - 2.0).abs() < 0.04, "peak {}", values[100]);
+        assert!((values[500] - 1.0).abs() < 0.001);
+        assert!(values.iter().all(|v| (0.0..=2.0).contains(v)));
+    }
+
+    #[test]
+    fn test_gated_envelope_release_on_gate_off() {
+        // Gate high from 0.0 to 0.5
+        let env = GatedEnvelope::new(Pulse::new(0.0, 1.0, 0.0, 0.5), 0.05, 0.05, 0.5, 0.2);
+        let values = render(&env, 1.0);
+
+        assert!((values[400] - 0.5).abs() < 0.001);
+        // Halfway through release
+        assert!((values[600] - 0.25).abs() < 0.01, "release {}", values[600]);
+        assert!(values[600] < values[550]);
+        // Fully released
+        assert_eq!(values[800], 0.0);
+        assert_eq!(env.phase(), Phase::Done);
+    }
+
+    #[test]
+    fn test_gated_envelope_idle_without_gate() {
+        let env = GatedEnvelope::new(Constant::new(0.0), 0.1, 0.1, 0.6, 0.2);
+        for v in render(&env, 0.5) {
+            assert_eq!(v, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_gated_envelope_retrigger_from_release_level() {
+        // Gate on 0.0-0.3, off 0.3-0.35, on again from 0.35
+        #[derive(Clone)]
+        struct Retrigger;
+        impl Signal for Retrigger {
+            fn sample(&self, t: SignalTime) -> f32 {
+                if (0.3..0.35).contains(&t) {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+
+        let env = GatedEnvelope::new(Retrigger, 0.1, 0.1, 0.5, 0.2);
+        let values = render(&env, 0.5);
+        let at_retrigger = values[350];
+        // Released partway, not back to zero
+        assert!(at_retrigger > 0.3 && at_retrigger < 0.5);
+        // Attack resumes upward from the release level
+        assert!(values[360] > at_retrigger);
+    }
+
+    #[test]
+    fn test_gated_envelope_time_reversal_resets() {
+        let env = GatedEnvelope::new(Constant::new(1.0), 0.1, 0.1, 0.6, 0.2);
+        render(&env, 0.5);
+        assert_eq!(env.sample(0.0), 0.0);
+    }
+}
+
+// <FILE>src/composition/cls_gated_envelope.rs</FILE> - <DESC>ADSR envelope driven by a gate signal</DESC>
+// <VERS>END OF VERSION: 1.1.1</VERS>
@@ -0,0 +1,202 @@
+// <FILE>src/composition/cls_glitch.rs</FILE> - <DESC>Seeded stutter and dropout glitches</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{fast_random, finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// What happens to one grain of a [`Glitch`]ed signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlitchEvent {
+    /// The first `1 / repeats` of the grain is looped `repeats` times
+    Stutter { repeats: u32 },
+    /// The grain outputs 0.0
+    Silence,
+}
+
+/// Randomly stutters and drops out a signal, datamosh style.
+///
+/// Time is cut into grains of `grain_seconds`. Each grain is glitched with
+/// chance `probability`; a glitched grain either stutters (loops its opening
+/// half or quarter) or goes silent (one in four glitches). Which grains glitch
+/// and how is decided by `seed` alone, so the same seed always produces the
+/// same pattern.
+///
+/// `probability` is clamped to [0, 1]; at 0 the signal passes through
+/// untouched. A non-finite or non-positive grain falls back to 0.1 s.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::{Glitch, GlitchEvent};
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::traits::Signal;
+///
+/// let glitchy = Glitch::new(Sine::with_frequency(3.0), 0.3, 0.125, 42);
+/// let silent = (0..64).filter(|&k| glitchy.event(k) == Some(GlitchEvent::Silence)).count();
+/// assert!(silent < 64);
+/// let _ = glitchy.sample(1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Glitch<S> {
+    pub inner: S,
+    /// Chance that a grain is glitched (0..1)
+    pub probability: f32,
+    /// Grain length in seconds
+    pub grain_seconds: f32,
+    pub seed: u64,
+}
+
+impl<S: Signal> Glitch<S> {
+    pub fn new(inner: S, probability: f32, grain_seconds: f32, seed: u64) -> Self {
+        Self {
+            inner,
+            probability,
+            grain_seconds,
+            seed,
+        }
+    }
+
+    fn grain(&self) -> SignalTime {
+        let grain = finite_or(self.grain_seconds, 0.1);
+        if grain > 0.0 {
+            grain as SignalTime
+        } else {
+            0.1
+        }
+    }
+
+    /// Glitch applied to grain `index`, or `None` if it plays normally.
+    pub fn event(&self, index: i64) -> Option<GlitchEvent> {
+        let probability = finite_or(self.probability, 0.0).clamp(0.0, 1.0);
+        if fast_random(self.seed, index as u64) >= probability {
+            return None;
+        }
+        let kind = fast_random(self.seed ^ 0x9e3779b97f4a7c15, index as u64);
+        Some(if kind < 0.25 {
+            GlitchEvent::Silence
+        } else if kind < 0.625 {
+            GlitchEvent::Stutter { repeats: 2 }
+        } else {
+            GlitchEvent::Stutter { repeats: 4 }
+        })
+    }
+
+    /// Inner time to read at `t`, or `None` when silent.
+    fn source_time(&self, t: SignalTime) -> Option<SignalTime> {
+        let t = finite_or_f64(t, 0.0);
+        let grain = self.grain();
+        let index = (t / grain).floor();
+        match self.event(index as i64) {
+            None => Some(t),
+            Some(GlitchEvent::Silence) => None,
+            Some(GlitchEvent::Stutter { repeats }) => {
+                let start = index * grain;
+                let window = grain / repeats as SignalTime;
+                Some(start + (t - start).rem_euclid(window))
+            }
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for Glitch<S> {
+    fn output_range(&self) -> SignalRange {
+        let range = self.inner.output_range();
+        if self.probability > 0.0 {
+            SignalRange::new(range.min.min(0.0), range.max.max(0.0))
+        } else {
+            range
+        }
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.source_time(t)
+            .map_or(0.0, |source| self.inner.sample(source))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.source_time(t)
+            .map_or(0.0, |source| self.inner.sample_with_context(source, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Sine;
+    use crate::traits::from_fn;
+
+    /// Outputs its own (shifted) time, so source times are visible.
+    fn clock() -> impl Signal + Clone {
+        from_fn(|t| t as f32 + 1.0)
+    }
+
+    #[test]
+    fn test_glitch_zero_probability_is_transparent() {
+        let sine = Sine::with_frequency(2.5);
+        let glitch = Glitch::new(sine, 0.0, 0.05, 3);
+        for i in 0..500 {
+            let t = i as f64 * 0.0071;
+            assert_eq!(glitch.sample(t), sine.sample(t));
+        }
+        assert_eq!(glitch.output_range(), sine.output_range());
+    }
+
+    #[test]
+    fn test_glitch_events_repeat_or_silence_grains() {
+        let glitch = Glitch::new(clock(), 1.0, 0.1, 17);
+        let (mut stutters, mut silences) = (0, 0);
+        for k in 0..40i64 {
+            let start = k as f64 * 0.1;
+            let probe = start + 0.07;
+            match glitch.event(k) {
+                Some(GlitchEvent::Silence) => {
+                    silences += 1;
+                    assert_eq!(glitch.sample(probe), 0.0);
+                }
+                Some(GlitchEvent::Stutter { repeats }) => {
+                    stutters += 1;
+                    let window = 0.1 / repeats as f64;
+                    let expected = start + (0.07f64).rem_euclid(window) + 1.0;
+                    assert!((glitch.sample(probe) as f64 - expected).abs() < 1e-4);
+                    // Same value one window later: the segment repeats
+                    let a = glitch.sample(start + 0.01);
+                    let b = glitch.sample(start + 0.01 + window);
+                    assert!((a - b).abs() < 1e-5);
+                }
+                None => panic!("probability 1 left grain {} untouched", k),
+            }
+        }
+        assert!(stutters > 0 && silences > 0);
+    }
+
+    #[test]
+    fn test_glitch_probability_scales_event_count() {
+        let count = |p: f32| {
+            let glitch = Glitch::new(clock(), p, 0.1, 5);
+            (0..1000).filter(|&k| glitch.event(k).is_some()).count()
+        };
+        let (low, high) = (count(0.1), count(0.6));
+        assert!(low > 50 && low < 150, "low {}", low);
+        assert!(high > 500 && high < 700, "high {}", high);
+    }
+
+    #[test]
+    fn test_glitch_reproducible_per_seed() {
+        let render = |seed: u64| -> Vec<f32> {
+            let glitch = Glitch::new(clock(), 0.5, 0.05, seed);
+            (0..400).map(|i| glitch.sample(i as f64 * 0.01)).collect()
+        };
+        assert_eq!(render(9), render(9));
+        assert_ne!(render(9), render(10));
+        let fallback = Glitch::new(clock(), f32::NAN, f32::NAN, 1);
+        assert_eq!(fallback.sample(0.3), 1.3);
+        assert!(fallback.sample(f64::NAN).is_finite());
+    }
+}
+
+// <FILE>src/composition/cls_glitch.rs</FILE> - <DESC>Seeded stutter and dropout glitches</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
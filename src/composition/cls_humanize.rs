@@ -0,0 +1,184 @@
+// <FILE>src/composition/cls_humanize.rs</FILE> - <DESC>Per-beat seeded timing jitter for rhythmic signals</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{fast_random, finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Nudges each beat of a rhythmic signal early or late by a small seeded
+/// amount, so machine-perfect triggers feel played.
+///
+/// Time is split into beat slots centered on multiples of `beat_seconds`
+/// (default 0.5 s, i.e. 120 BPM). Every sample inside slot `k` reads the
+/// inner signal at `t - offset(k)`, where `offset(k)` is a seeded value in
+/// `[-amount, amount]` seconds. A trigger on beat `k` therefore moves by
+/// exactly `offset(k)`, and the same seed always produces the same groove.
+///
+/// `amount` is capped just under half a beat so a shifted trigger stays in
+/// its own slot; events far from the beat (or pulses longer than the slack
+/// left by the shift) may be clipped at slot edges. Negative or non-finite
+/// amounts are treated as 0.0, which is fully transparent.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::Humanize;
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::traits::Signal;
+///
+/// let groove = Humanize::new(Sine::with_frequency(2.0), 0.02, 7);
+/// let shift = groove.offset(3);
+/// assert!(shift.abs() <= 0.02);
+/// assert_eq!(shift, Humanize::new(Sine::with_frequency(2.0), 0.02, 7).offset(3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Humanize<S> {
+    pub inner: S,
+    /// Maximum timing shift in seconds
+    pub amount: f32,
+    pub seed: u64,
+    /// Beat spacing in seconds
+    pub beat_seconds: f32,
+}
+
+impl<S: Signal> Humanize<S> {
+    pub fn new(inner: S, amount: f32, seed: u64) -> Self {
+        Self {
+            inner,
+            amount,
+            seed,
+            beat_seconds: 0.5,
+        }
+    }
+
+    /// Set the beat spacing in seconds (default 0.5).
+    ///
+    /// Non-finite or non-positive values fall back to 0.5.
+    pub fn with_beat(mut self, beat_seconds: f32) -> Self {
+        self.beat_seconds = beat_seconds;
+        self
+    }
+
+    fn beat(&self) -> SignalTime {
+        let beat = finite_or(self.beat_seconds, 0.5);
+        if beat > 0.0 {
+            beat as SignalTime
+        } else {
+            0.5
+        }
+    }
+
+    /// Timing shift applied to beat `index`, in seconds.
+    pub fn offset(&self, index: i64) -> SignalTime {
+        let max_shift = 0.499 * self.beat();
+        let amount = (finite_or(self.amount, 0.0).max(0.0) as SignalTime).min(max_shift);
+        if amount == 0.0 {
+            return 0.0;
+        }
+        let unit = fast_random(self.seed, index as u64) as SignalTime * 2.0 - 1.0;
+        unit * amount
+    }
+
+    fn shifted_time(&self, t: SignalTime) -> SignalTime {
+        let t = finite_or_f64(t, 0.0);
+        let index = (t / self.beat()).round() as i64;
+        t - self.offset(index)
+    }
+}
+
+impl<S: Signal + Clone> Signal for Humanize<S> {
+    fn output_range(&self) -> SignalRange {
+        self.inner.output_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.inner.sample(self.shifted_time(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.inner.sample_with_context(self.shifted_time(t), ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Sine;
+    use crate::traits::from_fn;
+
+    /// 10 ms trigger at the start of every 0.5 s beat.
+    fn clicks() -> impl Signal + Clone {
+        from_fn(|t| if t.rem_euclid(0.5) < 0.01 { 1.0 } else { 0.0 })
+    }
+
+    /// Times of rising edges over `[-0.2, seconds)`, sampled at 10 kHz.
+    fn onsets<S: Signal>(signal: &S, seconds: f64) -> Vec<f64> {
+        let n = ((seconds + 0.2) * 10_000.0) as usize;
+        let mut previous = signal.sample(-0.2);
+        let mut times = Vec::new();
+        for i in 1..n {
+            let t = i as f64 / 10_000.0 - 0.2;
+            let v = signal.sample(t);
+            if v > 0.5 && previous <= 0.5 {
+                times.push(t);
+            }
+            previous = v;
+        }
+        times
+    }
+
+    #[test]
+    fn test_humanize_zero_amount_is_transparent() {
+        let sine = Sine::with_frequency(3.0);
+        let human = Humanize::new(sine, 0.0, 99);
+        for i in 0..500 {
+            let t = i as f64 * 0.0123;
+            assert_eq!(human.sample(t), sine.sample(t));
+        }
+        let negative = Humanize::new(sine, -0.2, 99);
+        assert_eq!(negative.offset(4), 0.0);
+    }
+
+    #[test]
+    fn test_humanize_shifts_triggers_within_bound() {
+        let human = Humanize::new(clicks(), 0.03, 5);
+        let times = onsets(&human, 10.2);
+        assert_eq!(times.len(), 21, "{:?}", times);
+        let mut moved = 0;
+        for (k, &t) in times.iter().enumerate() {
+            let shift = t - k as f64 * 0.5;
+            assert!(shift.abs() <= 0.03 + 1e-4, "beat {} shifted {}", k, shift);
+            assert!((shift - human.offset(k as i64)).abs() < 2e-4);
+            if shift.abs() > 0.002 {
+                moved += 1;
+            }
+        }
+        assert!(moved > 10, "only {} beats moved", moved);
+    }
+
+    #[test]
+    fn test_humanize_reproducible_per_seed() {
+        let a = Humanize::new(clicks(), 0.05, 11);
+        let b = Humanize::new(clicks(), 0.05, 11);
+        let c = Humanize::new(clicks(), 0.05, 12);
+        assert_eq!(onsets(&a, 5.2), onsets(&b, 5.2));
+        assert_ne!(onsets(&a, 5.2), onsets(&c, 5.2));
+    }
+
+    #[test]
+    fn test_humanize_amount_capped_below_half_beat() {
+        let human = Humanize::new(clicks(), 10.0, 3).with_beat(0.2);
+        for k in -20..20 {
+            assert!(human.offset(k).abs() < 0.1);
+        }
+        let fallback = Humanize::new(clicks(), 0.01, 3).with_beat(f32::NAN);
+        assert_eq!(fallback.beat(), 0.5);
+        assert!(fallback.sample(f64::NAN).is_finite());
+    }
+}
+
+// <FILE>src/composition/cls_humanize.rs</FILE> - <DESC>Per-beat seeded timing jitter for rhythmic signals</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
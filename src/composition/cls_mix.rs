@@ -1,8 +1,10 @@
 // <FILE>mixed-signals/src/composition/cls_mix.rs</FILE> - <DESC>Signal mixing/crossfade operator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Removed clamping, added output_range()</CLOG>
+// <VERS>VERSION: 2.3.1</VERS>
+// <WCTX>Batch sampling</WCTX>
+// <CLOG>Chunked sample_batch_from with a stack scratch buffer (no allocation)</CLOG>
 
+use super::BATCH_CHUNK;
+use crate::processing::Normalized;
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 
 /// Mixes two signals with a blend factor.
@@ -35,9 +37,17 @@ impl<A: Signal, B: Signal> Mix<A, B> {
     pub fn equal(a: A, b: B) -> Self {
         Self::new(a, b, 0.5)
     }
+
+    /// Blend driven by an envelope signal instead of a fixed factor.
+    ///
+    /// The envelope is wrapped in [`Normalized`], so any signal range maps to a
+    /// [0, 1] blend (0 = all A, 1 = all B). See [`EnvelopeMix`].
+    pub fn with_envelope<E: Signal + Clone>(a: A, b: B, envelope: E) -> EnvelopeMix<A, B, E> {
+        EnvelopeMix::new(a, b, envelope)
+    }
 }
 
-impl<A: Signal, B: Signal> Signal for Mix<A, B> {
+impl<A: Signal + Clone, B: Signal + Clone> Signal for Mix<A, B> {
     fn output_range(&self) -> SignalRange {
         let ra = self.a.output_range();
         let rb = self.b.output_range();
@@ -54,6 +64,25 @@ impl<A: Signal, B: Signal> Signal for Mix<A, B> {
         va * (1.0 - self.mix) + vb * self.mix
     }
 
+    fn sample_batch_from(
+        &self,
+        t_start: SignalTime,
+        dt: SignalTime,
+        first: usize,
+        out: &mut [f32],
+    ) {
+        self.a.sample_batch_from(t_start, dt, first, out);
+        let mut scratch = [0.0; BATCH_CHUNK];
+        for (chunk_index, chunk) in out.chunks_mut(BATCH_CHUNK).enumerate() {
+            let b = &mut scratch[..chunk.len()];
+            self.b
+                .sample_batch_from(t_start, dt, first + chunk_index * BATCH_CHUNK, b);
+            for (va, vb) in chunk.iter_mut().zip(b.iter()) {
+                *va = *va * (1.0 - self.mix) + vb * self.mix;
+            }
+        }
+    }
+
     fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
         let va = self.a.sample_with_context(t, ctx);
         let vb = self.b.sample_with_context(t, ctx);
@@ -61,6 +90,80 @@ impl<A: Signal, B: Signal> Signal for Mix<A, B> {
     }
 }
 
+/// Mixes two signals with a time-varying blend taken from an envelope.
+///
+/// Output = a * (1 - env) + b * env (no clamping), where `env` is the envelope
+/// normalized to [0, 1] from its `output_range()`.
+///
+/// The common case is fading from noise to tone over an ADSR.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::Mix;
+/// use mixed_signals::envelopes::Adsr;
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::random::FastWhiteNoise;
+/// use mixed_signals::traits::Signal;
+///
+/// let adsr = Adsr::new(0.2, 0.2, 0.6, 0.2);
+/// let voice = Mix::with_envelope(FastWhiteNoise::with_seed(1), Sine::with_frequency(4.0), adsr);
+/// let _ = voice.sample(0.5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EnvelopeMix<A, B, E> {
+    pub a: A,
+    pub b: B,
+    /// Blend envelope, normalized to [0, 1]
+    pub envelope: Normalized<E>,
+}
+
+impl<A: Signal, B: Signal, E: Signal + Clone> EnvelopeMix<A, B, E> {
+    pub fn new(a: A, b: B, envelope: E) -> Self {
+        Self {
+            a,
+            b,
+            envelope: Normalized::new(envelope),
+        }
+    }
+
+    /// Blend factor at time `t` (0 = all A, 1 = all B).
+    pub fn blend_at(&self, t: SignalTime) -> f32 {
+        blend_or_half(self.envelope.sample(t))
+    }
+}
+
+fn blend_or_half(mix: f32) -> f32 {
+    if mix.is_finite() {
+        mix.clamp(0.0, 1.0)
+    } else {
+        0.5
+    }
+}
+
+impl<A: Signal + Clone, B: Signal + Clone, E: Signal + Clone> Signal for EnvelopeMix<A, B, E> {
+    fn output_range(&self) -> SignalRange {
+        // Blend varies over time, so cover both inputs
+        let ra = self.a.output_range();
+        let rb = self.b.output_range();
+        SignalRange::new(ra.min.min(rb.min), ra.max.max(rb.max))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let mix = self.blend_at(t);
+        let va = self.a.sample(t);
+        let vb = self.b.sample(t);
+        va * (1.0 - mix) + vb * mix
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let mix = blend_or_half(self.envelope.sample_with_context(t, ctx));
+        let va = self.a.sample_with_context(t, ctx);
+        let vb = self.b.sample_with_context(t, ctx);
+        va * (1.0 - mix) + vb * mix
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,7 +208,67 @@ mod tests {
         let mixed = Mix::new(a, b, f32::NAN);
         assert!((mixed.sample(0.0) - 0.5).abs() < 0.001);
     }
+
+    #[test]
+    fn test_envelope_mix_follows_adsr_shape() {
+        use crate::envelopes::Adsr;
+
+        let adsr = Adsr::new(0.2, 0.2, 0.5, 0.2);
+        let mixed = Mix::with_envelope(Constant::new(0.0), Constant::new(1.0), adsr);
+
+        // With A=0 and B=1 the output is exactly the blend, i.e. the envelope
+        for i in 0..=100 {
+            let t = i as f64 / 100.0;
+            assert!(
+                (mixed.sample(t) - adsr.sample(t)).abs() < 1e-6,
+                "t={t}: mix {} env {}",
+                mixed.sample(t),
+                adsr.sample(t)
+            );
+        }
+        // Starts on A, peaks fully on B, settles at the sustain blend
+        assert!(mixed.sample(0.0).abs() < 1e-6);
+        assert!((mixed.sample(0.2) - 1.0).abs() < 1e-6);
+        assert!((mixed.sample(0.6) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_envelope_mix_blends_between_signals() {
+        use crate::envelopes::Adsr;
+
+        let adsr = Adsr::new(0.2, 0.2, 0.5, 0.2);
+        let mixed = Mix::with_envelope(Constant::new(-1.0), Constant::new(1.0), adsr);
+        assert!((mixed.sample(0.0) + 1.0).abs() < 1e-6);
+        assert!((mixed.sample(0.6) - 0.0).abs() < 1e-6);
+        assert_eq!(mixed.output_range(), SignalRange::new(-1.0, 1.0));
+    }
+
+    #[test]
+    fn test_envelope_mix_normalizes_bipolar_envelope() {
+        use crate::generators::Sine;
+
+        // Bipolar sine maps to a [0, 1] blend: -1 → A, +1 → B
+        let mixed = Mix::with_envelope(Constant::new(0.0), Constant::new(1.0), Sine::default());
+        assert!((mixed.sample(0.25) - 1.0).abs() < 1e-5);
+        assert!(mixed.sample(0.75).abs() < 1e-5);
+        assert!((mixed.blend_at(0.0) - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_mix_sample_batch_bitwise_identical() {
+        let signal = Mix::new(
+            crate::generators::Sine::with_frequency(2.0),
+            crate::generators::Triangle::with_frequency(7.0),
+            0.35,
+        );
+        let mut batch = vec![0.0; 480];
+        signal.sample_batch(-0.25, 1.0 / 480.0, &mut batch);
+        for (i, value) in batch.iter().enumerate() {
+            let t = -0.25 + i as f64 * (1.0 / 480.0);
+            assert_eq!(value.to_bits(), signal.sample(t).to_bits());
+        }
+    }
 }
 
 // <FILE>mixed-signals/src/composition/cls_mix.rs</FILE> - <DESC>Signal mixing/crossfade operator</DESC>
-// <VERS>END OF VERSION: 1.0.0 - 2025-12-22</VERS>
+// <VERS>END OF VERSION: 2.3.1</VERS>
@@ -0,0 +1,268 @@
+// <FILE>src/composition/cls_mod_matrix.rs</FILE> - <DESC>Modulation matrix routing named sources to destinations</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Alloc imports, core:: instead of std:: paths</CLOG>
+
+use crate::math::finite_or;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Depth-weighted sum of modulation sources.
+///
+/// Output = Σ depth_i * source_i(t). A single source at depth 1 passes
+/// through unchanged; negative depths invert a source. Non-finite depths are
+/// treated as 0.0, and an empty list outputs 0.0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::ModSum;
+/// use mixed_signals::generators::Constant;
+/// use mixed_signals::traits::Signal;
+///
+/// let sum = ModSum::new(vec![(Constant::new(0.5), 1.0), (Constant::new(0.2), -2.0)]);
+/// assert!((sum.sample(0.0) - 0.1).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ModSum<S> {
+    /// `(source, depth)` pairs
+    pub inputs: Vec<(S, f32)>,
+}
+
+impl<S: Signal> ModSum<S> {
+    pub fn new(inputs: Vec<(S, f32)>) -> Self {
+        Self { inputs }
+    }
+}
+
+impl<S: Signal + Clone> Signal for ModSum<S> {
+    fn output_range(&self) -> SignalRange {
+        let (min, max) = self
+            .inputs
+            .iter()
+            .fold((0.0, 0.0), |(min, max), (source, depth)| {
+                let depth = finite_or(*depth, 0.0);
+                let range = source.output_range();
+                let (a, b) = (range.min * depth, range.max * depth);
+                (min + a.min(b), max + a.max(b))
+            });
+        SignalRange::new(min, max)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.inputs
+            .iter()
+            .map(|(source, depth)| source.sample(t) * finite_or(*depth, 0.0))
+            .sum()
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.inputs
+            .iter()
+            .map(|(source, depth)| source.sample_with_context(t, ctx) * finite_or(*depth, 0.0))
+            .sum()
+    }
+}
+
+/// One source-to-destination connection in a [`ModMatrix`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModRoute {
+    pub source: String,
+    pub destination: String,
+    pub depth: f32,
+}
+
+/// Routing table from named modulation sources to named destinations.
+///
+/// Register sources (LFOs, envelopes, ...) by name, connect them to
+/// destination names with a depth, then take each destination's combined
+/// modulation as a [`ModSum`]. A source may feed several destinations, and a
+/// destination sums every route into it. Routes naming an unregistered
+/// source contribute nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::ModMatrix;
+/// use mixed_signals::envelopes::Adsr;
+/// use mixed_signals::generators::lfo;
+/// use mixed_signals::traits::Signal;
+///
+/// let matrix = ModMatrix::new()
+///     .with_source("lfo", lfo("sine", 0.5))
+///     .with_source("env", Box::new(Adsr::new(0.1, 0.2, 0.7, 0.3)))
+///     .with_route("lfo", "cutoff", 0.3)
+///     .with_route("env", "cutoff", 0.7)
+///     .with_route("lfo", "pan", 1.0);
+///
+/// let cutoff = matrix.destination("cutoff");
+/// let _ = cutoff.sample(0.4);
+/// assert_eq!(matrix.destinations(), vec!["cutoff", "pan"]);
+/// ```
+#[derive(Clone, Default)]
+pub struct ModMatrix {
+    sources: Vec<(String, Box<dyn Signal>)>,
+    routes: Vec<ModRoute>,
+}
+
+impl core::fmt::Debug for ModMatrix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sources: Vec<&str> = self.sources.iter().map(|(name, _)| name.as_str()).collect();
+        f.debug_struct("ModMatrix")
+            .field("sources", &sources)
+            .field("routes", &self.routes)
+            .finish()
+    }
+}
+
+impl ModMatrix {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a source; a repeated name replaces the earlier signal.
+    pub fn with_source(mut self, name: impl Into<String>, signal: Box<dyn Signal>) -> Self {
+        let name = name.into();
+        match self
+            .sources
+            .iter_mut()
+            .find(|(existing, _)| *existing == name)
+        {
+            Some(entry) => entry.1 = signal,
+            None => self.sources.push((name, signal)),
+        }
+        self
+    }
+
+    /// Connect `source` to `destination` with `depth`.
+    pub fn with_route(
+        mut self,
+        source: impl Into<String>,
+        destination: impl Into<String>,
+        depth: f32,
+    ) -> Self {
+        self.routes.push(ModRoute {
+            source: source.into(),
+            destination: destination.into(),
+            depth,
+        });
+        self
+    }
+
+    pub fn routes(&self) -> &[ModRoute] {
+        &self.routes
+    }
+
+    /// Destination names in first-routed order, without duplicates.
+    pub fn destinations(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for route in &self.routes {
+            if !names.contains(&route.destination.as_str()) {
+                names.push(&route.destination);
+            }
+        }
+        names
+    }
+
+    fn source(&self, name: &str) -> Option<&(dyn Signal + 'static)> {
+        self.sources
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, signal)| signal.as_ref())
+    }
+
+    /// Combined modulation signal for `destination`.
+    ///
+    /// Unrouted destinations give an empty sum (constant 0.0).
+    pub fn destination(&self, destination: &str) -> ModSum<Box<dyn Signal>> {
+        let inputs = self
+            .routes
+            .iter()
+            .filter(|route| route.destination == destination)
+            .filter_map(|route| {
+                self.source(&route.source)
+                    .map(|signal| (signal.clone_box(), route.depth))
+            })
+            .collect();
+        ModSum::new(inputs)
+    }
+
+    /// Modulation value for `destination` at time `t`.
+    pub fn sample(&self, destination: &str, t: SignalTime) -> f32 {
+        self.routes
+            .iter()
+            .filter(|route| route.destination == destination)
+            .filter_map(|route| {
+                self.source(&route.source)
+                    .map(|signal| signal.sample(t) * finite_or(route.depth, 0.0))
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Sine, Triangle};
+
+    #[test]
+    fn test_mod_sum_single_source_depth_one_passes_through() {
+        let sine = Sine::with_frequency(2.0);
+        let sum = ModSum::new(vec![(sine, 1.0)]);
+        for i in 0..100 {
+            let t = i as f64 * 0.017;
+            assert_eq!(sum.sample(t), sine.sample(t));
+        }
+        assert_eq!(sum.output_range(), sine.output_range());
+    }
+
+    #[test]
+    fn test_mod_matrix_sums_sources_with_depths() {
+        let sine = Sine::with_frequency(1.0);
+        let tri = Triangle::with_frequency(3.0);
+        let matrix = ModMatrix::new()
+            .with_source("lfo1", Box::new(sine))
+            .with_source("lfo2", Box::new(tri))
+            .with_route("lfo1", "pitch", 0.5)
+            .with_route("lfo2", "pitch", -0.25)
+            .with_route("lfo2", "gain", 2.0);
+        let pitch = matrix.destination("pitch");
+        for i in 0..100 {
+            let t = i as f64 * 0.013;
+            let expected = sine.sample(t) * 0.5 - tri.sample(t) * 0.25;
+            assert!((pitch.sample(t) - expected).abs() < 1e-6);
+            assert_eq!(matrix.sample("pitch", t), pitch.sample(t));
+            assert!((matrix.sample("gain", t) - tri.sample(t) * 2.0).abs() < 1e-6);
+        }
+        assert_eq!(pitch.output_range(), SignalRange::new(-0.75, 0.75));
+    }
+
+    #[test]
+    fn test_mod_matrix_unknown_names_contribute_nothing() {
+        let matrix = ModMatrix::new()
+            .with_source("lfo", Box::new(Sine::with_frequency(1.0)))
+            .with_route("missing", "cutoff", 1.0)
+            .with_route("lfo", "cutoff", f32::NAN);
+        assert_eq!(matrix.sample("cutoff", 0.25), 0.0);
+        assert_eq!(matrix.destination("nowhere").sample(0.25), 0.0);
+        assert_eq!(matrix.destinations(), vec!["cutoff"]);
+    }
+
+    #[test]
+    fn test_mod_matrix_source_replaced_by_name() {
+        let matrix = ModMatrix::new()
+            .with_source("lfo", Box::new(Sine::with_frequency(1.0)))
+            .with_source(
+                "lfo",
+                Box::new(Sine::with_frequency(1.0).with_harmonic(3, 0.0)),
+            )
+            .with_route("lfo", "x", 1.0);
+        assert_eq!(matrix.routes().len(), 1);
+        assert!((matrix.sample("x", 0.25) - 1.0).abs() < 1e-6);
+    }
+}
+
+// <FILE>src/composition/cls_mod_matrix.rs</FILE> - <DESC>Modulation matrix routing named sources to destinations</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
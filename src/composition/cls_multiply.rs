@@ -1,8 +1,9 @@
 // <FILE>mixed-signals/src/composition/cls_multiply.rs</FILE> - <DESC>Signal multiplication operator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Removed clamping, added output_range()</CLOG>
+// <VERS>VERSION: 2.2.1</VERS>
+// <WCTX>Batch sampling</WCTX>
+// <CLOG>Chunked sample_batch_from with a stack scratch buffer (no allocation)</CLOG>
 
+use super::BATCH_CHUNK;
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 
 /// Multiplies two signals together (ring modulation).
@@ -22,7 +23,7 @@ impl<A: Signal, B: Signal> Multiply<A, B> {
     }
 }
 
-impl<A: Signal, B: Signal> Signal for Multiply<A, B> {
+impl<A: Signal + Clone, B: Signal + Clone> Signal for Multiply<A, B> {
     fn output_range(&self) -> SignalRange {
         let ra = self.a.output_range();
         let rb = self.b.output_range();
@@ -42,6 +43,25 @@ impl<A: Signal, B: Signal> Signal for Multiply<A, B> {
         self.a.sample(t) * self.b.sample(t)
     }
 
+    fn sample_batch_from(
+        &self,
+        t_start: SignalTime,
+        dt: SignalTime,
+        first: usize,
+        out: &mut [f32],
+    ) {
+        self.a.sample_batch_from(t_start, dt, first, out);
+        let mut scratch = [0.0; BATCH_CHUNK];
+        for (chunk_index, chunk) in out.chunks_mut(BATCH_CHUNK).enumerate() {
+            let b = &mut scratch[..chunk.len()];
+            self.b
+                .sample_batch_from(t_start, dt, first + chunk_index * BATCH_CHUNK, b);
+            for (va, vb) in chunk.iter_mut().zip(b.iter()) {
+                *va *= vb;
+            }
+        }
+    }
+
     fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
         self.a.sample_with_context(t, ctx) * self.b.sample_with_context(t, ctx)
     }
@@ -87,7 +107,21 @@ mod tests {
         assert!((range.min - (-0.5)).abs() < 0.001);
         assert!((range.max - 0.5).abs() < 0.001);
     }
+
+    #[test]
+    fn test_multiply_sample_batch_bitwise_identical() {
+        let signal = Multiply::new(
+            Sine::with_frequency(3.0),
+            Box::new(Sine::with_frequency(0.5)) as Box<dyn Signal>,
+        );
+        let mut batch = vec![0.0; 480];
+        signal.sample_batch(-0.25, 1.0 / 480.0, &mut batch);
+        for (i, value) in batch.iter().enumerate() {
+            let t = -0.25 + i as f64 * (1.0 / 480.0);
+            assert_eq!(value.to_bits(), signal.sample(t).to_bits());
+        }
+    }
 }
 
 // <FILE>mixed-signals/src/composition/cls_multiply.rs</FILE> - <DESC>Signal multiplication operator</DESC>
-// <VERS>END OF VERSION: 1.0.0 - 2025-12-22</VERS>
+// <VERS>END OF VERSION: 2.2.1</VERS>
@@ -0,0 +1,137 @@
+// <FILE>src/composition/cls_pan.rs</FILE> - <DESC>Equal-power stereo panner</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Stereo audio output</WCTX>
+// <CLOG>Initial creation - Pan with constant or signal-driven position</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalContext, SignalTime, StereoSignal};
+use core::f32::consts::FRAC_PI_4;
+
+/// Equal-power `(left, right)` gains for a pan position.
+///
+/// -1 is hard left, 0 is center (both ≈ 0.707), 1 is hard right. Positions
+/// are clamped to [-1, 1]; non-finite positions count as center. The gains
+/// always satisfy left² + right² = 1, so loudness holds steady across a sweep.
+pub fn equal_power_gains(position: f32) -> (f32, f32) {
+    let position = if position.is_finite() {
+        position.clamp(-1.0, 1.0)
+    } else {
+        0.0
+    };
+    let angle = (position + 1.0) * FRAC_PI_4;
+    (angle.cos(), angle.sin())
+}
+
+/// Places a mono signal in the stereo field.
+///
+/// Output = (x · cos θ, x · sin θ) with θ = (pan + 1) · π/4, where `pan` is
+/// the position signal's raw value at each sample (see
+/// [`equal_power_gains`]). Use a [`Constant`](crate::generators::Constant)
+/// for a fixed position or any signal for an automated sweep.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::Pan;
+/// use mixed_signals::generators::{Constant, Sine};
+/// use mixed_signals::traits::StereoSignal;
+///
+/// // Tone drifting between the speakers once every 4 seconds
+/// let sweep = Pan::new(Sine::with_frequency(440.0), Sine::with_frequency(0.25));
+/// let hard_left = Pan::new(Constant::new(1.0), Constant::new(-1.0));
+/// assert!(hard_left.sample_stereo(0.0).1.abs() < 1e-6);
+/// # let _ = sweep.sample_stereo(0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pan<S, P> {
+    pub signal: S,
+    /// Pan position (-1 = left, 0 = center, 1 = right)
+    pub position: P,
+}
+
+impl<S: Signal, P: Signal> Pan<S, P> {
+    pub fn new(signal: S, position: P) -> Self {
+        Self { signal, position }
+    }
+
+    /// Equal-power `(left, right)` gains at time `t`.
+    pub fn gains_at(&self, t: SignalTime) -> (f32, f32) {
+        equal_power_gains(self.position.sample(t))
+    }
+}
+
+impl<S: Signal, P: Signal> StereoSignal for Pan<S, P> {
+    fn sample_stereo(&self, t: SignalTime) -> (f32, f32) {
+        let x = self.signal.sample(t);
+        let (left, right) = self.gains_at(t);
+        (x * left, x * right)
+    }
+
+    fn sample_stereo_with_context(&self, t: SignalTime, ctx: &SignalContext) -> (f32, f32) {
+        let x = self.signal.sample_with_context(t, ctx);
+        let (left, right) = equal_power_gains(self.position.sample_with_context(t, ctx));
+        (x * left, x * right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+    use core::f32::consts::FRAC_1_SQRT_2;
+
+    #[test]
+    fn test_pan_hard_left_and_right() {
+        let left = Pan::new(Constant::new(0.8), Constant::new(-1.0));
+        let (l, r) = left.sample_stereo(0.0);
+        assert!((l - 0.8).abs() < 1e-6);
+        assert!(r.abs() < 1e-6, "right = {r}");
+
+        let right = Pan::new(Constant::new(0.8), Constant::new(1.0));
+        let (l, r) = right.sample_stereo(0.0);
+        assert!(l.abs() < 1e-6, "left = {l}");
+        assert!((r - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_pan_center_is_equal_power() {
+        let sine = Sine::with_frequency(5.0);
+        let center = Pan::new(sine, Constant::new(0.0));
+        for t in [0.01, 0.05, 0.13] {
+            let x = sine.sample(t);
+            let (l, r) = center.sample_stereo(t);
+            assert!((l - x * FRAC_1_SQRT_2).abs() < 1e-6);
+            assert!((r - x * FRAC_1_SQRT_2).abs() < 1e-6);
+        }
+        for position in [-0.7, 0.2, 0.9] {
+            let (l, r) = equal_power_gains(position);
+            assert!((l * l + r * r - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_pan_sine_position_oscillates() {
+        // 1 Hz pan: hard right at t = 0.25, hard left at t = 0.75
+        let pan = Pan::new(Constant::new(1.0), Sine::with_frequency(1.0));
+        let (l, r) = pan.sample_stereo(0.25);
+        assert!(l.abs() < 1e-3 && (r - 1.0).abs() < 1e-3, "({l}, {r})");
+        let (l, r) = pan.sample_stereo(0.75);
+        assert!((l - 1.0).abs() < 1e-3 && r.abs() < 1e-3, "({l}, {r})");
+
+        let mut frames = [0.0; 200];
+        pan.sample_stereo_interleaved(0.0, 0.01, &mut frames);
+        let louder_right = frames.chunks(2).filter(|f| f[1] > f[0]).count();
+        assert!((45..=55).contains(&louder_right), "{louder_right}");
+    }
+
+    #[test]
+    fn test_pan_sanitizes_position() {
+        assert_eq!(equal_power_gains(5.0), equal_power_gains(1.0));
+        assert_eq!(equal_power_gains(-5.0), equal_power_gains(-1.0));
+        assert_eq!(equal_power_gains(f32::NAN), equal_power_gains(0.0));
+    }
+}
+
+// <FILE>src/composition/cls_pan.rs</FILE> - <DESC>Equal-power stereo panner</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -0,0 +1,154 @@
+// <FILE>src/composition/cls_round_robin.rs</FILE> - <DESC>Round-robin time-sliced signal mux</DESC>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, alloc imports</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use alloc::vec::Vec;
+
+/// Plays each signal in a list for `slice_seconds`, then advances, looping.
+///
+/// Signal `i` plays during `[i * slice, (i + 1) * slice)` (modulo the full
+/// cycle of `len * slice`). Each signal is sampled with slice-local time, so
+/// it starts from its own t = 0 every time its slice begins; envelopes and
+/// one-shots replay instead of resuming mid-way.
+///
+/// An empty list outputs 0.0. Non-finite or non-positive slice lengths fall
+/// back to 1 second. Negative times wrap backwards through the list.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::RoundRobin;
+/// use mixed_signals::generators::Constant;
+/// use mixed_signals::traits::Signal;
+///
+/// let mux = RoundRobin::new(vec![Constant::new(0.1), Constant::new(0.9)], 2.0);
+/// assert_eq!(mux.sample(1.0), 0.1);
+/// assert_eq!(mux.sample(3.0), 0.9);
+/// assert_eq!(mux.sample(5.0), 0.1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RoundRobin<S> {
+    pub signals: Vec<S>,
+    /// Seconds each signal plays before advancing
+    pub slice_seconds: f32,
+}
+
+impl<S: Signal> RoundRobin<S> {
+    pub fn new(signals: Vec<S>, slice_seconds: f32) -> Self {
+        Self {
+            signals,
+            slice_seconds,
+        }
+    }
+
+    fn slice(&self) -> SignalTime {
+        let slice = finite_or(self.slice_seconds, 1.0);
+        if slice > 0.0 {
+            slice as SignalTime
+        } else {
+            1.0
+        }
+    }
+
+    /// Index of the signal playing at time `t` and the slice-local time.
+    ///
+    /// Returns `None` for an empty list.
+    pub fn locate(&self, t: SignalTime) -> Option<(usize, SignalTime)> {
+        if self.signals.is_empty() {
+            return None;
+        }
+        let slice = self.slice();
+        let t = finite_or_f64(t, 0.0);
+        let slot = (t / slice).floor();
+        let local = (t - slot * slice).max(0.0);
+        let index = (slot.rem_euclid(self.signals.len() as f64) as usize) % self.signals.len();
+        Some((index, local))
+    }
+}
+
+impl<S: Signal + Clone> Signal for RoundRobin<S> {
+    fn output_range(&self) -> SignalRange {
+        self.signals
+            .iter()
+            .map(|s| s.output_range())
+            .reduce(|a, b| SignalRange::new(a.min.min(b.min), a.max.max(b.max)))
+            .unwrap_or(SignalRange::new(0.0, 0.0))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        match self.locate(t) {
+            Some((index, local)) => self.signals[index].sample(local),
+            None => 0.0,
+        }
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        match self.locate(t) {
+            Some((index, local)) => self.signals[index].sample_with_context(local, ctx),
+            None => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Ramp};
+
+    fn constants() -> RoundRobin<Constant> {
+        RoundRobin::new(
+            vec![Constant::new(0.0), Constant::new(0.5), Constant::new(1.0)],
+            0.5,
+        )
+    }
+
+    #[test]
+    fn test_round_robin_first_slice_plays_signal_zero() {
+        let mux = constants();
+        for i in 0..10 {
+            assert_eq!(mux.sample(i as f64 * 0.049), 0.0);
+        }
+        assert_eq!(mux.locate(0.2), Some((0, 0.2)));
+    }
+
+    #[test]
+    fn test_round_robin_wraps_index() {
+        let mux = constants();
+        for n in 0..12 {
+            let t = n as f64 * 0.5 + 0.1;
+            let expected = [0.0, 0.5, 1.0][n % 3];
+            assert_eq!(mux.sample(t), expected, "slice {}", n);
+        }
+        // Negative time walks backwards through the list
+        assert_eq!(mux.sample(-0.1), 1.0);
+    }
+
+    #[test]
+    fn test_round_robin_slice_local_time() {
+        let ramps = vec![Ramp::new(0.0, 1.0, 1.0), Ramp::new(0.0, 1.0, 1.0)];
+        let mux = RoundRobin::new(ramps, 1.0);
+        assert!((mux.sample(0.25) - 0.25).abs() < 1e-6);
+        assert!((mux.sample(1.25) - 0.25).abs() < 1e-6);
+        assert!((mux.sample(2.75) - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_round_robin_boxed_and_edge_cases() {
+        let boxed: Vec<Box<dyn Signal>> = vec![Box::new(Constant::new(0.25))];
+        let mux = RoundRobin::new(boxed, f32::NAN);
+        assert_eq!(mux.sample(7.3), 0.25);
+
+        let empty: RoundRobin<Constant> = RoundRobin::new(vec![], 1.0);
+        assert_eq!(empty.sample(1.0), 0.0);
+        assert_eq!(empty.locate(1.0), None);
+        assert_eq!(constants().output_range(), SignalRange::UNIT);
+    }
+}
+
+// <FILE>src/composition/cls_round_robin.rs</FILE> - <DESC>Round-robin time-sliced signal mux</DESC>
+// <VERS>END OF VERSION: 1.2.0</VERS>
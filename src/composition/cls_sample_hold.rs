@@ -0,0 +1,372 @@
+// <FILE>mixed-signals/src/composition/cls_sample_hold.rs</FILE> - <DESC>Trigger-driven sample and hold</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, ChaCha-backed tests gated on the chacha feature</CLOG>
+
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Default trigger scan resolution in seconds.
+pub const DEFAULT_SAMPLE_HOLD_RESOLUTION: f32 = 0.001;
+/// Default distance in seconds scanned back for a trigger when no period is set.
+pub const DEFAULT_SAMPLE_HOLD_LOOKBACK: f32 = 10.0;
+/// Bisection steps refining a crossing inside one scan cell.
+const REFINE_STEPS: usize = 48;
+/// Upper bound on scan cells per lookup.
+const MAX_SCAN_CELLS: usize = 1 << 20;
+
+/// Which threshold crossings of the trigger capture a new value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TriggerEdge {
+    /// Trigger moves from below to at-or-above the threshold (default)
+    #[default]
+    Rising,
+    /// Trigger moves from at-or-above to below the threshold
+    Falling,
+    /// Either direction
+    Both,
+}
+
+impl TriggerEdge {
+    fn matches(self, was_above: bool, is_above: bool) -> bool {
+        match self {
+            TriggerEdge::Rising => !was_above && is_above,
+            TriggerEdge::Falling => was_above && !is_above,
+            TriggerEdge::Both => was_above != is_above,
+        }
+    }
+}
+
+/// Sample & hold: captures `source` whenever `trigger` crosses `threshold`
+/// and holds that value until the next crossing.
+///
+/// Stateless and deterministic: `sample(t)` finds the last trigger at or
+/// before `t` and returns `source` at that instant, so every `t` between two
+/// triggers returns the bit-identical value regardless of sampling order.
+///
+/// Triggers are located on a fixed time grid of `resolution` seconds anchored
+/// at t = 0, and each crossing is refined by bisection within its grid cell.
+/// Two crossings closer together than `resolution` may be missed.
+///
+/// - With [`with_trigger_period`](Self::with_trigger_period) (e.g. a
+///   [`Square`](crate::generators::Square) or pulse clock), the crossings in
+///   one period are found once and every later trigger time is derived from
+///   the period, so lookups cost the same at any `t`.
+/// - Otherwise each lookup scans backwards from `t`, up to `lookback`
+///   seconds. Before the first trigger the output is 0.0.
+///
+/// Non-finite trigger values count as below the threshold.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::SampleHold;
+/// use mixed_signals::generators::Square;
+/// use mixed_signals::noise::WhiteNoise;
+/// use mixed_signals::traits::Signal;
+///
+/// // A new random value four times per second
+/// let steps = SampleHold::new(WhiteNoise::new(1, 1.0, 1000.0), Square::with_frequency(4.0))
+///     .with_trigger_period(0.25);
+/// assert_eq!(steps.sample(0.30), steps.sample(0.45));
+/// assert_ne!(steps.sample(0.45), steps.sample(0.55));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SampleHold<S, T> {
+    source: S,
+    trigger: T,
+    threshold: f32,
+    edge: TriggerEdge,
+    resolution: f32,
+    lookback: f32,
+    trigger_period: Option<f32>,
+    /// Trigger times within `(0, period]`, found on first use.
+    period_edges: OnceLock<Vec<SignalTime>>,
+}
+
+impl<S: Signal, T: Signal> SampleHold<S, T> {
+    /// Hold `source` on rising crossings of 0.0 by `trigger`.
+    pub fn new(source: S, trigger: T) -> Self {
+        Self {
+            source,
+            trigger,
+            threshold: 0.0,
+            edge: TriggerEdge::Rising,
+            resolution: DEFAULT_SAMPLE_HOLD_RESOLUTION,
+            lookback: DEFAULT_SAMPLE_HOLD_LOOKBACK,
+            trigger_period: None,
+            period_edges: OnceLock::new(),
+        }
+    }
+
+    /// Trigger level to cross (default 0.0).
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self.period_edges = OnceLock::new();
+        self
+    }
+
+    /// Crossing direction that captures a value (default rising).
+    pub fn with_edge(mut self, edge: TriggerEdge) -> Self {
+        self.edge = edge;
+        self.period_edges = OnceLock::new();
+        self
+    }
+
+    /// Grid spacing in seconds used to find crossings (default 1 ms).
+    pub fn with_resolution(mut self, resolution: f32) -> Self {
+        self.resolution = resolution;
+        self.period_edges = OnceLock::new();
+        self
+    }
+
+    /// How far back in seconds to scan for a trigger without a period (default 10 s).
+    pub fn with_lookback(mut self, lookback: f32) -> Self {
+        self.lookback = lookback;
+        self
+    }
+
+    /// Declare the trigger periodic with this period in seconds, e.g.
+    /// `1.0 / frequency` for a square or pulse clock.
+    pub fn with_trigger_period(mut self, period: f32) -> Self {
+        self.trigger_period = (period.is_finite() && period > 0.0).then_some(period);
+        self.period_edges = OnceLock::new();
+        self
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    pub fn trigger(&self) -> &T {
+        &self.trigger
+    }
+
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    pub fn edge(&self) -> TriggerEdge {
+        self.edge
+    }
+
+    pub fn resolution(&self) -> f32 {
+        self.resolution
+    }
+
+    pub fn lookback(&self) -> f32 {
+        self.lookback
+    }
+
+    pub fn trigger_period(&self) -> Option<f32> {
+        self.trigger_period
+    }
+
+    /// Time of the last trigger at or before `t`, if one was found.
+    pub fn last_trigger(&self, t: SignalTime) -> Option<SignalTime> {
+        self.find_trigger(t, |ti| self.trigger.sample(ti))
+    }
+
+    fn step(&self) -> SignalTime {
+        if self.resolution.is_finite() && self.resolution > 0.0 {
+            self.resolution as SignalTime
+        } else {
+            DEFAULT_SAMPLE_HOLD_RESOLUTION as SignalTime
+        }
+    }
+
+    fn find_trigger(
+        &self,
+        t: SignalTime,
+        trigger_at: impl Fn(SignalTime) -> f32,
+    ) -> Option<SignalTime> {
+        if !t.is_finite() {
+            return None;
+        }
+        let above = |ti: SignalTime| trigger_at(ti) >= self.threshold;
+        match self.trigger_period {
+            Some(period) => {
+                let period = period as SignalTime;
+                self.period_edges
+                    .get_or_init(|| {
+                        let above = |ti: SignalTime| self.trigger.sample(ti) >= self.threshold;
+                        self.scan(0.0, period, above)
+                    })
+                    .iter()
+                    .map(|&offset| offset + ((t - offset) / period).floor() * period)
+                    .max_by(|a, b| a.total_cmp(b))
+            }
+            None => {
+                let step = self.step();
+                let lookback = if self.lookback.is_finite() {
+                    self.lookback.max(0.0) as SignalTime
+                } else {
+                    0.0
+                };
+                let cells = ((lookback / step).ceil() as usize).min(MAX_SCAN_CELLS);
+                let last = (t / step).floor();
+                (0..=cells).find_map(|i| {
+                    let lo = (last - i as SignalTime) * step;
+                    let hi = (last - i as SignalTime + 1.0) * step;
+                    self.crossing(lo, hi, &above).filter(|&edge| edge <= t)
+                })
+            }
+        }
+    }
+
+    /// All crossings in `(start, end]`, scanning grid cells forwards.
+    fn scan(
+        &self,
+        start: SignalTime,
+        end: SignalTime,
+        above: impl Fn(SignalTime) -> bool,
+    ) -> Vec<SignalTime> {
+        let step = self.step();
+        let cells = (((end - start) / step).ceil() as usize).min(MAX_SCAN_CELLS);
+        (0..cells)
+            .filter_map(|i| {
+                let lo = start + i as SignalTime * step;
+                let hi = (start + (i + 1) as SignalTime * step).min(end);
+                self.crossing(lo, hi, &above)
+            })
+            .collect()
+    }
+
+    /// Refined crossing time within `[lo, hi]` if the cell ends differ in the
+    /// configured direction. Returns the first time found past the crossing.
+    fn crossing(
+        &self,
+        mut lo: SignalTime,
+        mut hi: SignalTime,
+        above: &impl Fn(SignalTime) -> bool,
+    ) -> Option<SignalTime> {
+        let was_above = above(lo);
+        if !self.edge.matches(was_above, above(hi)) {
+            return None;
+        }
+        for _ in 0..REFINE_STEPS {
+            let mid = 0.5 * (lo + hi);
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if above(mid) == was_above {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Some(hi)
+    }
+}
+
+impl<S: Signal + Clone, T: Signal + Clone> Signal for SampleHold<S, T> {
+    fn output_range(&self) -> SignalRange {
+        let range = self.source.output_range();
+        SignalRange::new(range.min.min(0.0), range.max.max(0.0))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.last_trigger(t)
+            .map_or(0.0, |edge| self.source.sample(edge))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.find_trigger(t, |ti| self.trigger.sample_with_context(ti, ctx))
+            .map_or(0.0, |edge| self.source.sample_with_context(edge, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+    #[cfg(feature = "chacha")]
+    use crate::generators::Square;
+    #[cfg(feature = "chacha")]
+    use crate::noise::WhiteNoise;
+    use crate::traits::from_fn;
+
+    /// Runs of identical values over one second sampled at 1 kHz (offset half
+    /// a sample so no point lands exactly on a clock edge).
+    #[cfg(feature = "chacha")]
+    fn runs(signal: &dyn Signal) -> Vec<(usize, f32)> {
+        let mut runs: Vec<(usize, f32)> = Vec::new();
+        for i in 0..1000 {
+            let v = signal.sample((i as f64 + 0.5) / 1000.0 + 3.0);
+            match runs.last_mut() {
+                Some((count, value)) if *value == v => *count += 1,
+                _ => runs.push((1, v)),
+            }
+        }
+        runs
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn test_sample_hold_noise_four_steps_per_second() {
+        let noise = WhiteNoise::new(42, 1.0, 48_000.0);
+        let clock = Square::with_frequency(4.0);
+        let periodic = SampleHold::new(noise, clock).with_trigger_period(0.25);
+        let scanning = SampleHold::new(noise, clock);
+        for held in [&periodic as &dyn Signal, &scanning] {
+            let runs = runs(held);
+            assert_eq!(runs.len(), 4, "{runs:?}");
+            assert!(runs.iter().all(|(count, _)| *count == 250), "{runs:?}");
+        }
+        // Both paths find the same trigger instants
+        for i in 0..100 {
+            let t = 1.0 + i as f64 * 0.037;
+            let a = periodic.last_trigger(t).unwrap();
+            let b = scanning.last_trigger(t).unwrap();
+            assert!((a - b).abs() < 1e-9, "{a} vs {b}");
+        }
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn test_sample_hold_is_order_independent() {
+        let held = SampleHold::new(WhiteNoise::new(7, 1.0, 1000.0), Square::with_frequency(4.0));
+        let forward: Vec<f32> = (0..40).map(|i| held.sample(i as f64 * 0.01)).collect();
+        let backward: Vec<f32> = (0..40)
+            .rev()
+            .map(|i| held.sample(i as f64 * 0.01))
+            .collect();
+        assert!(forward.iter().eq(backward.iter().rev()));
+    }
+
+    #[test]
+    fn test_sample_hold_edges_and_threshold() {
+        // Ramp from 0 to 1 each second; source reports the time it was sampled
+        let saw = from_fn(|t| t.rem_euclid(1.0) as f32);
+        let clock = from_fn(|t| t as f32);
+        let rising = SampleHold::new(clock, saw).with_threshold(0.5);
+        assert!((rising.sample(2.75) - 2.5).abs() < 1e-4);
+        assert!((rising.sample(3.25) - 2.5).abs() < 1e-4);
+        let falling = SampleHold::new(clock, saw)
+            .with_threshold(0.5)
+            .with_edge(TriggerEdge::Falling);
+        assert!((falling.sample(2.75) - 2.0).abs() < 1e-4);
+        let both = SampleHold::new(clock, saw)
+            .with_threshold(0.5)
+            .with_edge(TriggerEdge::Both);
+        assert!((both.sample(3.25) - 3.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_sample_hold_without_trigger_is_zero() {
+        let held = SampleHold::new(Constant::new(0.7), Constant::new(-1.0)).with_lookback(1.0);
+        assert_eq!(held.last_trigger(5.0), None);
+        assert_eq!(held.sample(5.0), 0.0);
+        assert_eq!(held.sample(f64::NAN), 0.0);
+        assert_eq!(held.output_range(), SignalRange::new(0.0, 0.7));
+        let periodic = held.with_trigger_period(0.5);
+        assert_eq!(periodic.sample(5.0), 0.0);
+    }
+}
+
+// <FILE>mixed-signals/src/composition/cls_sample_hold.rs</FILE> - <DESC>Trigger-driven sample and hold</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
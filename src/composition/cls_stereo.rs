@@ -0,0 +1,80 @@
+// <FILE>src/composition/cls_stereo.rs</FILE> - <DESC>Pair of mono signals as one stereo signal</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Stereo audio output</WCTX>
+// <CLOG>Initial creation - Stereo with independent left and right signals</CLOG>
+
+use crate::traits::{Signal, SignalContext, SignalTime, StereoSignal};
+
+/// Two independent mono signals played as the left and right channels.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::Stereo;
+/// use mixed_signals::generators::{Constant, Sine};
+/// use mixed_signals::traits::StereoSignal;
+///
+/// let wide = Stereo::new(Sine::with_frequency(440.0), Constant::new(0.0));
+/// assert_eq!(wide.sample_stereo(0.0).1, 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stereo<L, R> {
+    pub left: L,
+    pub right: R,
+}
+
+impl<L: Signal, R: Signal> Stereo<L, R> {
+    pub fn new(left: L, right: R) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<S: Signal + Clone> Stereo<S, S> {
+    /// The same signal on both channels.
+    pub fn mono(signal: S) -> Self {
+        Self::new(signal.clone(), signal)
+    }
+}
+
+impl<L: Signal, R: Signal> StereoSignal for Stereo<L, R> {
+    fn sample_stereo(&self, t: SignalTime) -> (f32, f32) {
+        (self.left.sample(t), self.right.sample(t))
+    }
+
+    fn sample_stereo_with_context(&self, t: SignalTime, ctx: &SignalContext) -> (f32, f32) {
+        (
+            self.left.sample_with_context(t, ctx),
+            self.right.sample_with_context(t, ctx),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+
+    #[test]
+    fn test_stereo_channels_are_independent() {
+        let (a, b) = (Sine::with_frequency(3.0), Sine::with_frequency(7.0));
+        let stereo = Stereo::new(a, b);
+        for t in [0.0, 0.1, 0.37] {
+            assert_eq!(stereo.sample_stereo(t), (a.sample(t), b.sample(t)));
+        }
+    }
+
+    #[test]
+    fn test_stereo_interleaved_layout() {
+        let stereo = Stereo::new(Constant::new(0.25), Constant::new(-0.5));
+        let mut out = [9.0; 5];
+        stereo.sample_stereo_interleaved(0.0, 0.1, &mut out);
+        assert_eq!(out, [0.25, -0.5, 0.25, -0.5, 9.0]);
+
+        let mono = Stereo::mono(Sine::with_frequency(2.0));
+        let (l, r) = mono.sample_stereo(0.3);
+        assert_eq!(l, r);
+    }
+}
+
+// <FILE>src/composition/cls_stereo.rs</FILE> - <DESC>Pair of mono signals as one stereo signal</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
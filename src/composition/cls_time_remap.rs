@@ -0,0 +1,151 @@
+// <FILE>src/composition/cls_time_remap.rs</FILE> - <DESC>Time remapping ("tape speed") operator</DESC>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std</CLOG>
+
+use crate::math::finite_or;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Reads an inner signal through a time-position signal ("tape speed").
+///
+/// Output = inner(position(t)). The position signal's value is used directly
+/// as the playback time, so its slope is the playback rate:
+/// - `position(t) = t` plays normally
+/// - `position(t) = 2t` plays at double speed
+/// - `position(t) = -t` plays in reverse
+///
+/// With [`with_loop`](Self::with_loop) the playback time wraps into
+/// `[0, length)`, so any position sweeps back and forth over one loop of the
+/// inner signal like a tape loop.
+///
+/// Position values are f32, so very long playback times lose sub-millisecond
+/// precision. Non-finite positions read the inner signal at time 0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::TimeRemap;
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::traits::{from_fn, Signal};
+///
+/// let sine = Sine::with_frequency(1.0);
+/// let double_speed = TimeRemap::new(sine, from_fn(|t| 2.0 * t as f32));
+/// assert!((double_speed.sample(0.125) - sine.sample(0.25)).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimeRemap<S, P> {
+    pub inner: S,
+    /// Playback position in seconds of inner time
+    pub position: P,
+    /// Wrap playback time into [0, length) when set
+    pub loop_length: Option<f32>,
+}
+
+impl<S: Signal, P: Signal> TimeRemap<S, P> {
+    pub fn new(inner: S, position: P) -> Self {
+        Self {
+            inner,
+            position,
+            loop_length: None,
+        }
+    }
+
+    /// Wrap playback time into `[0, length)` seconds.
+    ///
+    /// Non-finite or non-positive lengths disable looping.
+    pub fn with_loop(mut self, length: f32) -> Self {
+        self.loop_length = (length.is_finite() && length > 0.0).then_some(length);
+        self
+    }
+
+    fn remap(&self, position: f32) -> SignalTime {
+        let time = finite_or(position, 0.0) as SignalTime;
+        match self.loop_length {
+            Some(length) if length.is_finite() && length > 0.0 => {
+                time.rem_euclid(length as SignalTime)
+            }
+            _ => time,
+        }
+    }
+}
+
+impl<S: Signal + Clone, P: Signal + Clone> Signal for TimeRemap<S, P> {
+    fn output_range(&self) -> SignalRange {
+        self.inner.output_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let time = self.remap(self.position.sample(t));
+        self.inner.sample(time)
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let time = self.remap(self.position.sample_with_context(t, ctx));
+        self.inner.sample_with_context(time, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sawtooth, Sine};
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_time_remap_identity() {
+        let sine = Sine::with_frequency(3.0);
+        let remap = TimeRemap::new(sine, from_fn(|t| t as f32));
+        for i in 0..50 {
+            let t = i as f64 * 0.013;
+            assert!((remap.sample(t) - sine.sample(t)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_time_remap_slope_two_doubles_rate() {
+        let sine = Sine::with_frequency(1.0);
+        let remap = TimeRemap::new(sine, from_fn(|t| 2.0 * t as f32));
+        for i in 0..50 {
+            let t = i as f64 * 0.02;
+            assert!((remap.sample(t) - sine.sample(2.0 * t)).abs() < 1e-5);
+        }
+        // One full inner cycle completes in half a second
+        assert!((remap.sample(0.125) - 1.0).abs() < 1e-5);
+        assert!((remap.sample(0.375) + 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_time_remap_slope_negative_reverses() {
+        let saw = Sawtooth::default();
+        let remap = TimeRemap::new(saw, from_fn(|t| 1.0 - t as f32));
+        for i in 1..20 {
+            let t = i as f64 * 0.05;
+            assert!((remap.sample(t) - saw.sample(1.0 - t)).abs() < 1e-5);
+        }
+        // A rising sawtooth played in reverse falls
+        assert!(remap.sample(0.3) < remap.sample(0.2));
+    }
+
+    #[test]
+    fn test_time_remap_loop_wraps() {
+        let saw = Sawtooth::with_frequency(0.5);
+        let looped = TimeRemap::new(saw, from_fn(|t| t as f32)).with_loop(1.0);
+        assert!((looped.sample(1.25) - saw.sample(0.25)).abs() < 1e-5);
+        assert!((looped.sample(-0.25) - saw.sample(0.75)).abs() < 1e-5);
+
+        let unlooped = TimeRemap::new(saw, from_fn(|t| t as f32)).with_loop(f32::NAN);
+        assert_eq!(unlooped.loop_length, None);
+    }
+
+    #[test]
+    fn test_time_remap_non_finite_position() {
+        let sine = Sine::new(1.0, 1.0, 0.0, 0.25);
+        let remap = TimeRemap::new(sine, Constant::new(f32::NAN));
+        assert!((remap.sample(0.7) - sine.sample(0.0)).abs() < 1e-6);
+    }
+}
+
+// <FILE>src/composition/cls_time_remap.rs</FILE> - <DESC>Time remapping ("tape speed") operator</DESC>
+// <VERS>END OF VERSION: 1.2.0</VERS>
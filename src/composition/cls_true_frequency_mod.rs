@@ -0,0 +1,253 @@
+// <FILE>mixed-signals/src/composition/cls_true_frequency_mod.rs</FILE> - <DESC>True frequency modulation operator</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Core:: instead of std:: paths</CLOG>
+
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use core::f64::consts::TAU;
+use std::sync::Mutex;
+
+/// Longest integration step in seconds; larger gaps between samples are subdivided.
+const MAX_STEP: SignalTime = 1.0 / 1000.0;
+/// Cap on substeps per sample so a huge jump in `t` stays bounded.
+const MAX_SUBSTEPS: usize = 4096;
+
+/// Accumulator state: wrapped phase in cycles at `prev_time`.
+#[derive(Debug, Clone, Copy, Default)]
+struct FmState {
+    phase: f64,
+    prev_freq: f64,
+    prev_time: Option<SignalTime>,
+}
+
+/// True frequency modulation: a sine whose instantaneous frequency is
+/// `carrier_freq + depth * modulator(t)` Hz.
+///
+/// [`FrequencyMod`](super::FrequencyMod) offsets the carrier's phase, which is
+/// what most "FM" synths do. This operator instead integrates the frequency
+/// with a phase accumulator, so a constant modulator is a pure detune and a
+/// sine modulator produces sidebands at `carrier_freq ± k * modulator_freq`
+/// with Bessel-function amplitudes (modulation index `depth / modulator_freq`).
+/// Output is `sin(2π * phase)` in [-1, 1].
+///
+/// Like [`SlewLimit`](crate::processing::SlewLimit), this is stateful and
+/// needs monotonically increasing `t`:
+///
+/// - Each sample integrates the frequency from the previous `t` with the
+///   trapezoidal rule, subdividing gaps longer than 1 ms.
+/// - The first sample (and the first after [`reset`](Self::reset), or after
+///   time goes backwards) starts from `phase = frequency(t) * t`, which is
+///   exact for a constant modulator.
+/// - A repeated `t` returns the same output.
+///
+/// Non-finite modulator values contribute no deviation.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::composition::TrueFrequencyMod;
+/// use mixed_signals::generators::Constant;
+/// use mixed_signals::traits::Signal;
+///
+/// // A constant modulator detunes 440 Hz by 0.5 * 20 = 10 Hz
+/// let fm = TrueFrequencyMod::new(Constant::new(0.5), 20.0, 440.0);
+/// let t = 0.01;
+/// let expected = (core::f64::consts::TAU * 450.0 * t).sin() as f32;
+/// assert!((fm.sample(t) - expected).abs() < 1e-4);
+/// ```
+#[derive(Debug)]
+pub struct TrueFrequencyMod<M> {
+    modulator: M,
+    depth: f32,
+    carrier_freq: f32,
+    state: Mutex<FmState>,
+}
+
+impl<M: Signal> TrueFrequencyMod<M> {
+    /// Sine at `carrier_freq` Hz, deviated by `depth` Hz per unit of modulator.
+    pub fn new(modulator: M, depth: f32, carrier_freq: f32) -> Self {
+        Self {
+            modulator,
+            depth,
+            carrier_freq,
+            state: Mutex::new(FmState::default()),
+        }
+    }
+
+    pub fn modulator(&self) -> &M {
+        &self.modulator
+    }
+
+    /// Frequency deviation in Hz per unit of modulator.
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+
+    pub fn carrier_freq(&self) -> f32 {
+        self.carrier_freq
+    }
+
+    /// Forget the accumulated phase so the next sample starts fresh.
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = FmState::default();
+    }
+
+    /// Instantaneous frequency in Hz for a modulator value.
+    fn frequency(&self, mod_value: f32) -> f64 {
+        let carrier = if self.carrier_freq.is_finite() {
+            self.carrier_freq as f64
+        } else {
+            0.0
+        };
+        let deviation = self.depth as f64 * mod_value as f64;
+        if deviation.is_finite() {
+            carrier + deviation
+        } else {
+            carrier
+        }
+    }
+
+    fn step(&self, t: SignalTime, mod_at: impl Fn(SignalTime) -> f32) -> f32 {
+        if !t.is_finite() {
+            return 0.0;
+        }
+        let mut state = self.state.lock().unwrap();
+        match state.prev_time {
+            Some(prev) if t == prev => {}
+            Some(prev) if t > prev => {
+                let span = t - prev;
+                let steps = ((span / MAX_STEP).ceil() as usize).clamp(1, MAX_SUBSTEPS);
+                let dt = span / steps as SignalTime;
+                let mut phase = state.phase;
+                let mut prev_freq = state.prev_freq;
+                for i in 1..=steps {
+                    let ti = if i == steps {
+                        t
+                    } else {
+                        prev + i as SignalTime * dt
+                    };
+                    let freq = self.frequency(mod_at(ti));
+                    phase += (prev_freq + freq) * 0.5 * dt;
+                    prev_freq = freq;
+                }
+                state.phase = phase.rem_euclid(1.0);
+                state.prev_freq = prev_freq;
+            }
+            // First sample or time went backwards
+            _ => {
+                let freq = self.frequency(mod_at(t));
+                state.phase = (freq * t).rem_euclid(1.0);
+                state.prev_freq = freq;
+            }
+        }
+        state.prev_time = Some(t);
+        (TAU * state.phase).sin() as f32
+    }
+}
+
+/// Clones the configuration only; the clone starts with fresh state.
+impl<M: Clone> Clone for TrueFrequencyMod<M> {
+    fn clone(&self) -> Self {
+        Self {
+            modulator: self.modulator.clone(),
+            depth: self.depth,
+            carrier_freq: self.carrier_freq,
+            state: Mutex::new(FmState::default()),
+        }
+    }
+}
+
+impl<M: Signal + Clone> Signal for TrueFrequencyMod<M> {
+    fn output_range(&self) -> SignalRange {
+        SignalRange::BIPOLAR
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.step(t, |ti| self.modulator.sample(ti))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.step(t, |ti| self.modulator.sample_with_context(ti, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+
+    const SAMPLE_RATE: usize = 1000;
+
+    fn render<S: Signal>(signal: &S, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| signal.sample(i as SignalTime / SAMPLE_RATE as SignalTime))
+            .collect()
+    }
+
+    /// Magnitude of DFT bin `k` for a one-second buffer (bin spacing 1 Hz).
+    fn dft_magnitude(samples: &[f32], k: usize) -> f64 {
+        let n = samples.len() as f64;
+        let (mut re, mut im) = (0.0, 0.0);
+        for (i, &x) in samples.iter().enumerate() {
+            let angle = TAU * k as f64 * i as f64 / n;
+            re += x as f64 * angle.cos();
+            im -= x as f64 * angle.sin();
+        }
+        2.0 * (re * re + im * im).sqrt() / n
+    }
+
+    #[test]
+    fn test_true_fm_constant_modulator_is_detune() {
+        let fm = TrueFrequencyMod::new(Constant::new(0.75), 40.0, 200.0);
+        for i in 0..2000 {
+            let t = i as f64 / 8000.0;
+            let expected = (TAU * 230.0 * t).sin() as f32;
+            let v = fm.sample(t);
+            assert!((v - expected).abs() < 1e-4, "t={t}: {v} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn test_true_fm_sine_modulator_sidebands() {
+        // Index 2: J0 = 0.224, J1 = 0.577, J2 = 0.353, J3 = 0.129
+        let fm = TrueFrequencyMod::new(Sine::with_frequency(20.0), 40.0, 200.0);
+        let samples = render(&fm, SAMPLE_RATE);
+        let spectrum: Vec<f64> = (0..SAMPLE_RATE / 2)
+            .map(|k| dft_magnitude(&samples, k))
+            .collect();
+        let peak = (0..spectrum.len())
+            .max_by(|&a, &b| spectrum[a].total_cmp(&spectrum[b]))
+            .unwrap();
+        assert!(peak == 180 || peak == 220, "peak at {peak} Hz");
+        for (k, expected) in [(0, 0.224), (1, 0.577), (2, 0.353), (3, 0.129)] {
+            for bin in [200 - 20 * k, 200 + 20 * k] {
+                let got = spectrum[bin];
+                assert!((got - expected).abs() < 0.02, "{bin} Hz: {got}");
+            }
+        }
+        // Nothing between the sidebands
+        assert!(spectrum[190] < 0.01 && spectrum[257] < 0.01);
+    }
+
+    #[test]
+    fn test_true_fm_repeat_and_reset() {
+        let fm = TrueFrequencyMod::new(Sine::with_frequency(3.0), 5.0, 10.0);
+        let first = render(&fm, 100);
+        assert_eq!(fm.sample(0.099), first[99]);
+        fm.reset();
+        assert_eq!(render(&fm, 100), first);
+        assert_eq!(render(&fm.clone(), 100), first);
+    }
+
+    #[test]
+    fn test_true_fm_non_finite_inputs() {
+        let fm = TrueFrequencyMod::new(Constant::new(f32::NAN), 10.0, 1.0);
+        assert!((fm.sample(0.25) - 1.0).abs() < 1e-6);
+        assert_eq!(fm.sample(f64::NAN), 0.0);
+        let silent = TrueFrequencyMod::new(Constant::new(0.0), 1.0, f32::INFINITY);
+        assert_eq!(silent.sample(0.3), 0.0);
+    }
+}
+
+// <FILE>mixed-signals/src/composition/cls_true_frequency_mod.rs</FILE> - <DESC>True frequency modulation operator</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,7 +1,7 @@
 // <FILE>mixed-signals/src/composition/cls_vca_centered.rs</FILE> - <DESC>Centered voltage-controlled amplifier</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Audio synthesis primitives - VCA with centered silence</WCTX>
-// <CLOG>Initial implementation - VCA that maintains 0.5 center when amplitude is zero</CLOG>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>Cloneable signal collections</WCTX>
+// <CLOG>Require Clone on inner signals for SignalClone</CLOG>
 
 use crate::traits::{Signal, SignalContext, SignalTime};
 
@@ -49,7 +49,7 @@ impl<C: Signal, A: Signal> VcaCentered<C, A> {
     }
 }
 
-impl<C: Signal, A: Signal> Signal for VcaCentered<C, A> {
+impl<C: Signal + Clone, A: Signal + Clone> Signal for VcaCentered<C, A> {
     fn sample(&self, t: SignalTime) -> f32 {
         let c = self.carrier.sample(t);
         let a = self.amplitude.sample(t);
@@ -161,6 +161,7 @@ mod tests {
     #[test]
     fn test_vca_nan_carrier() {
         // Use a custom NaN signal to bypass Constant's sanitization
+        #[derive(Clone)]
         struct NanSignal;
         impl Signal for NanSignal {
             fn sample(&self, _t: SignalTime) -> f32 {
@@ -178,6 +179,7 @@ mod tests {
     fn test_vca_nan_amplitude() {
         let carrier = Constant::new(0.8);
         // Simulate NaN amplitude
+        #[derive(Clone)]
         struct NanSignal;
         impl Signal for NanSignal {
             fn sample(&self, _t: SignalTime) -> f32 {
@@ -193,4 +195,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/composition/cls_vca_centered.rs</FILE> - <DESC>Centered voltage-controlled amplifier</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.1.0</VERS>
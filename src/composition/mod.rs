@@ -1,20 +1,55 @@
 // <FILE>mixed-signals/src/composition/mod.rs</FILE> - <DESC>Signal composition operators module</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Deduplication refactor</WCTX>
-// <CLOG>Removed redundant Sum/Scale (use Add/Multiply instead)</CLOG>
+// <VERS>VERSION: 2.13.1</VERS>
+// <WCTX>Batch sampling</WCTX>
+// <CLOG>Added BATCH_CHUNK scratch length for batch operators</CLOG>
 
 mod cls_add;
+mod cls_crossfade;
+mod cls_delay;
 mod cls_frequency_mod;
+#[cfg(feature = "std")]
+mod cls_gated_envelope;
+mod cls_glitch;
+mod cls_humanize;
 mod cls_mix;
+mod cls_mod_matrix;
 mod cls_multiply;
+mod cls_pan;
+mod cls_round_robin;
+#[cfg(feature = "std")]
+mod cls_sample_hold;
+mod cls_stereo;
+mod cls_time_remap;
+#[cfg(feature = "std")]
+mod cls_true_frequency_mod;
 mod cls_vca_centered;
 
 pub use cls_add::Add;
-pub use cls_frequency_mod::FrequencyMod;
-pub use cls_mix::Mix;
+pub use cls_crossfade::Crossfade;
+pub use cls_delay::Delay;
+pub use cls_frequency_mod::{FmDepth, FrequencyMod};
+#[cfg(feature = "std")]
+pub use cls_gated_envelope::GatedEnvelope;
+pub use cls_glitch::{Glitch, GlitchEvent};
+pub use cls_humanize::Humanize;
+pub use cls_mix::{EnvelopeMix, Mix};
+pub use cls_mod_matrix::{ModMatrix, ModRoute, ModSum};
 pub use cls_multiply::Multiply;
+pub use cls_pan::{equal_power_gains, Pan};
+pub use cls_round_robin::RoundRobin;
+#[cfg(feature = "std")]
+pub use cls_sample_hold::{
+    SampleHold, TriggerEdge, DEFAULT_SAMPLE_HOLD_LOOKBACK, DEFAULT_SAMPLE_HOLD_RESOLUTION,
+};
+pub use cls_stereo::Stereo;
+pub use cls_time_remap::TimeRemap;
+#[cfg(feature = "std")]
+pub use cls_true_frequency_mod::TrueFrequencyMod;
 pub use cls_vca_centered::VcaCentered;
 
+/// Scratch buffer length for binary operators' `sample_batch_from`.
+pub(crate) const BATCH_CHUNK: usize = 256;
+
 /// Type alias for backward compatibility - use `Add` instead.
 #[deprecated(since = "2.0.0", note = "Use Add instead - Sum was identical")]
 pub type Sum<A, B> = Add<A, B>;
@@ -24,4 +59,4 @@ pub type Sum<A, B> = Add<A, B>;
 pub type Scale<A, B> = Multiply<A, B>;
 
 // <FILE>mixed-signals/src/composition/mod.rs</FILE> - <DESC>Signal composition operators module</DESC>
-// <VERS>END OF VERSION: 2.0.0</VERS>
+// <VERS>END OF VERSION: 2.13.1</VERS>
@@ -1,11 +1,19 @@
 // <FILE>src/core/noise_helpers.rs</FILE> - <DESC>Common noise generator helpers</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Noise helpers refactor - extracting common patterns</WCTX>
-// <CLOG>Initial creation with 7 helper functions</CLOG>
-
-use crate::math::{derive_seed, finite_or, finite_or_f64};
-use crate::traits::{SignalContext, SignalRange, SignalTime};
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, rng helpers gated on the chacha feature</CLOG>
+
+use crate::math::finite_or;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+#[cfg(feature = "chacha")]
+use crate::math::{derive_seed, finite_or_f64};
+use crate::traits::SignalRange;
+#[cfg(feature = "chacha")]
+use crate::traits::{SignalContext, SignalTime};
+#[cfg(feature = "chacha")]
 use rand::SeedableRng;
+#[cfg(feature = "chacha")]
 use rand_chacha::ChaCha8Rng;
 
 // --- Pattern #1: Bipolar Output Range ---
@@ -43,6 +51,7 @@ pub fn bipolar_range(amplitude: f32, offset: f32) -> SignalRange {
 /// let value = rng.next_u64();
 /// ```
 #[inline]
+#[cfg(feature = "chacha")]
 pub fn rng_from_time(seed: u64, t: SignalTime) -> ChaCha8Rng {
     let time_ms = (finite_or_f64(t, 0.0) * 1000.0) as u64;
     let seed_bytes = derive_seed(seed, time_ms);
@@ -66,6 +75,7 @@ pub fn rng_from_time(seed: u64, t: SignalTime) -> ChaCha8Rng {
 /// let value = rng.next_u64();
 /// ```
 #[inline]
+#[cfg(feature = "chacha")]
 pub fn rng_from_context(base_seed: u64, t: SignalTime, ctx: &SignalContext) -> ChaCha8Rng {
     let effective_seed = base_seed.wrapping_add(ctx.seed);
     let time_ms = (finite_or_f64(t, 0.0) * 1000.0) as u64;
@@ -198,6 +208,35 @@ where
     }
 }
 
+/// Sum octave rows with equal weights (Voss-McCartney pink noise).
+///
+/// Like [`octave_sum`], but every row contributes equally, which gives a
+/// -3 dB/octave slope across `rows` octaves. Returns the mean of the rows,
+/// so bipolar inputs stay bipolar.
+///
+/// # Example
+/// ```
+/// use mixed_signals::core::voss_sum;
+/// let result = voss_sum(42, 100, 8, |_, octave_frame| octave_frame as f32);
+/// assert!((result - 197.0 / 8.0).abs() < 1e-4);
+/// ```
+#[inline]
+pub fn voss_sum<F>(seed: u64, frame: u64, rows: usize, sample_fn: F) -> f32
+where
+    F: Fn(u64, u64) -> f32,
+{
+    if rows == 0 {
+        return 0.0;
+    }
+    let sum: f64 = (0..rows)
+        .map(|row| {
+            let row_seed = seed.wrapping_add(row as u64 * 1000);
+            sample_fn(row_seed, frame >> row) as f64
+        })
+        .sum();
+    (sum / rows as f64) as f32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,6 +290,7 @@ mod tests {
         assert!((result - 0.5).abs() < 0.001);
     }
 
+    #[cfg(feature = "chacha")]
     #[test]
     fn test_rng_from_time_deterministic() {
         use rand::RngCore;
@@ -259,6 +299,7 @@ mod tests {
         assert_eq!(rng1.next_u64(), rng2.next_u64());
     }
 
+    #[cfg(feature = "chacha")]
     #[test]
     fn test_rng_from_time_different_seeds() {
         use rand::RngCore;
@@ -267,6 +308,7 @@ mod tests {
         assert_ne!(rng1.next_u64(), rng2.next_u64());
     }
 
+    #[cfg(feature = "chacha")]
     #[test]
     fn test_rng_from_time_different_times() {
         use rand::RngCore;
@@ -275,6 +317,7 @@ mod tests {
         assert_ne!(rng1.next_u64(), rng2.next_u64());
     }
 
+    #[cfg(feature = "chacha")]
     #[test]
     fn test_rng_from_context_deterministic() {
         use rand::RngCore;
@@ -284,6 +327,7 @@ mod tests {
         assert_eq!(rng1.next_u64(), rng2.next_u64());
     }
 
+    #[cfg(feature = "chacha")]
     #[test]
     fn test_rng_from_context_different_frames() {
         use rand::RngCore;
@@ -323,7 +367,16 @@ mod tests {
         let result = octave_sum(42, 100, 1, |_, _| 0.7);
         assert!((result - 0.7).abs() < 0.01);
     }
+
+    #[test]
+    fn test_voss_sum_equal_weights() {
+        assert_eq!(voss_sum(42, 100, 0, |_, _| 0.5), 0.0);
+        assert!((voss_sum(42, 100, 12, |_, _| -0.25) + 0.25).abs() < 1e-6);
+        // Row k sees frame >> k
+        let frames = voss_sum(0, 12, 3, |_, frame| frame as f32);
+        assert!((frames - (12.0 + 6.0 + 3.0) / 3.0).abs() < 1e-6);
+    }
 }
 
 // <FILE>src/core/noise_helpers.rs</FILE> - <DESC>Common noise generator helpers</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
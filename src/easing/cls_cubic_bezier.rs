@@ -0,0 +1,201 @@
+// <FILE>mixed-signals/src/easing/cls_cubic_bezier.rs</FILE> - <DESC>CSS-style cubic-bezier easing curve</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
+
+use crate::math::{finite_or, solve_bezier};
+use crate::traits::SignalTime;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A CSS `cubic-bezier(x1, y1, x2, y2)` timing curve.
+///
+/// The curve runs from (0, 0) to (1, 1) with two free control points. X is
+/// progress in, Y is eased progress out. The X coordinates must stay in
+/// [0, 1] so the curve remains a function of time. [`CubicBezier::new`]
+/// clamps them and swaps non-finite values for the linear defaults. The Y
+/// coordinates are unrestricted: values outside [0, 1] make the curve
+/// overshoot, as in CSS.
+///
+/// This is a separate type rather than an [`EasingType`](super::EasingType)
+/// variant because `EasingType` is `Eq` and stores no data.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::easing::CubicBezier;
+///
+/// let ease = CubicBezier::ease();
+/// assert_eq!(ease.apply(0.0), 0.0);
+/// assert_eq!(ease.apply(1.0), 1.0);
+/// assert!(ease.apply(0.5) > 0.5);
+///
+/// // X outside [0, 1] is clamped
+/// assert_eq!(CubicBezier::new(-2.0, 0.0, 3.0, 1.0).x1, 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CubicBezier {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+impl Default for CubicBezier {
+    fn default() -> Self {
+        Self::ease()
+    }
+}
+
+impl CubicBezier {
+    /// Create a curve, clamping x1/x2 to [0, 1] and replacing non-finite
+    /// coordinates with the linear curve's.
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            x1: finite_or(x1, 0.0).clamp(0.0, 1.0),
+            y1: finite_or(y1, 0.0),
+            x2: finite_or(x2, 1.0).clamp(0.0, 1.0),
+            y2: finite_or(y2, 1.0),
+        }
+    }
+
+    /// CSS `linear`: cubic-bezier(0, 0, 1, 1).
+    pub fn linear() -> Self {
+        Self::new(0.0, 0.0, 1.0, 1.0)
+    }
+
+    /// CSS `ease`: cubic-bezier(0.25, 0.1, 0.25, 1).
+    pub fn ease() -> Self {
+        Self::new(0.25, 0.1, 0.25, 1.0)
+    }
+
+    /// CSS `ease-in`: cubic-bezier(0.42, 0, 1, 1).
+    pub fn ease_in() -> Self {
+        Self::new(0.42, 0.0, 1.0, 1.0)
+    }
+
+    /// CSS `ease-out`: cubic-bezier(0, 0, 0.58, 1).
+    pub fn ease_out() -> Self {
+        Self::new(0.0, 0.0, 0.58, 1.0)
+    }
+
+    /// CSS `ease-in-out`: cubic-bezier(0.42, 0, 0.58, 1).
+    pub fn ease_in_out() -> Self {
+        Self::new(0.42, 0.0, 0.58, 1.0)
+    }
+
+    /// True when a control point lies above 1 or below 0, so the output leaves [0, 1].
+    pub fn overshoots(&self) -> bool {
+        !(0.0..=1.0).contains(&self.y1) || !(0.0..=1.0).contains(&self.y2)
+    }
+
+    /// Eased value at progress `t`.
+    ///
+    /// `t` is clamped to [0, 1], and NaN maps to 0. The endpoints are exact:
+    /// `apply(0.0) == 0.0` and `apply(1.0) == 1.0`.
+    pub fn apply(&self, t: SignalTime) -> f32 {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        // Re-sanitize: the fields are public and may have been deserialized
+        let c = Self::new(self.x1, self.y1, self.x2, self.y2);
+        solve_bezier(t, c.x1, c.y1, c.x2, c.y2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::bezier_x;
+
+    /// Reference inverse of x(u) by f64 bisection, then y(u).
+    fn reference(c: &CubicBezier, t: f64) -> f32 {
+        let (mut lo, mut hi) = (0.0f64, 1.0f64);
+        for _ in 0..60 {
+            let mid = 0.5 * (lo + hi);
+            if (bezier_x(mid, c.x1, c.x2) as f64) < t {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        crate::math::bezier_y(0.5 * (lo + hi), c.y1, c.y2)
+    }
+
+    #[test]
+    fn test_endpoints_are_exact() {
+        let curves = [
+            CubicBezier::linear(),
+            CubicBezier::ease(),
+            CubicBezier::ease_in(),
+            CubicBezier::ease_out(),
+            CubicBezier::ease_in_out(),
+            CubicBezier::new(0.0, 0.0, 0.0, 1.0),
+            CubicBezier::new(1.0, 0.0, 1.0, 1.0),
+            CubicBezier::new(0.5, -1.5, 0.5, 2.5),
+        ];
+        for c in curves {
+            assert_eq!(c.apply(0.0), 0.0, "{:?}", c);
+            assert_eq!(c.apply(1.0), 1.0, "{:?}", c);
+            assert_eq!(c.apply(-3.0), 0.0);
+            assert_eq!(c.apply(f64::NAN), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_solver_converges_for_extreme_control_points() {
+        let curves = [
+            CubicBezier::new(0.0, 0.0, 0.0, 1.0),
+            CubicBezier::new(1.0, 0.0, 1.0, 1.0),
+            CubicBezier::new(0.0, 1.0, 0.0, 1.0),
+            CubicBezier::new(1.0, 0.0, 1.0, 0.0),
+            CubicBezier::new(0.0, 0.3, 1.0, 0.7),
+        ];
+        for c in curves {
+            for i in 1..200 {
+                let t = i as f64 / 200.0;
+                let got = c.apply(t);
+                let want = reference(&c, t);
+                assert!(
+                    (got - want).abs() < 1e-3,
+                    "{:?} at {}: {} vs {}",
+                    c,
+                    t,
+                    got,
+                    want
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sanitizes_control_points() {
+        let c = CubicBezier::new(-0.5, 0.2, 1.5, f32::NAN);
+        assert_eq!((c.x1, c.y1, c.x2, c.y2), (0.0, 0.2, 1.0, 1.0));
+        assert_eq!(CubicBezier::new(f32::INFINITY, 0.0, 1.0, 1.0).x1, 0.0);
+
+        // Raw out-of-range fields (e.g. from a config file) are clamped at apply time
+        let raw = CubicBezier {
+            x1: -4.0,
+            y1: 0.1,
+            x2: 9.0,
+            y2: 1.0,
+        };
+        assert_eq!(
+            raw.apply(0.3),
+            CubicBezier::new(0.0, 0.1, 1.0, 1.0).apply(0.3)
+        );
+    }
+
+    #[test]
+    fn test_presets_match_css_shape() {
+        assert!(CubicBezier::ease_in().apply(0.25) < 0.25);
+        assert!(CubicBezier::ease_out().apply(0.25) > 0.25);
+        let in_out = CubicBezier::ease_in_out();
+        assert!((in_out.apply(0.5) - 0.5).abs() < 1e-4);
+        assert!(!in_out.overshoots());
+        assert!(CubicBezier::new(0.3, -0.5, 0.7, 1.5).overshoots());
+    }
+}
+
+// <FILE>mixed-signals/src/easing/cls_cubic_bezier.rs</FILE> - <DESC>CSS-style cubic-bezier easing curve</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,71 +1,197 @@
 // <FILE>mixed-signals/src/easing/fnc_ease.rs</FILE> - <DESC>Easing functions</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Mixed-signals migration Phase 5 - WP2</WCTX>
-// <CLOG>Migrated from tui-geometry - 27 easing variants (Linear through CircInOut)</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::traits::SignalTime;
+use core::f32::consts::PI;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::f32::consts::PI;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+/// Default overshoot of the `Back*` curves (about 10% past the target).
+pub const DEFAULT_BACK_OVERSHOOT: f32 = 1.70158;
+
+/// Standard easing curves (the Penner set plus linear).
+///
+/// New variants are only ever appended, so the serialized names and the
+/// declaration order stay stable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum EasingType {
     #[default]
-    #[serde(alias = "Linear")]
+    #[cfg_attr(feature = "serde", serde(alias = "Linear"))]
     Linear,
-    #[serde(alias = "QuadIn")]
+    #[cfg_attr(feature = "serde", serde(alias = "QuadIn"))]
     QuadIn,
-    #[serde(alias = "QuadOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "QuadOut"))]
     QuadOut,
-    #[serde(alias = "QuadInOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "QuadInOut"))]
     QuadInOut,
-    #[serde(alias = "CubicIn")]
+    #[cfg_attr(feature = "serde", serde(alias = "CubicIn"))]
     CubicIn,
-    #[serde(alias = "CubicOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "CubicOut"))]
     CubicOut,
-    #[serde(alias = "CubicInOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "CubicInOut"))]
     CubicInOut,
-    #[serde(alias = "SineIn")]
+    #[cfg_attr(feature = "serde", serde(alias = "SineIn"))]
     SineIn,
-    #[serde(alias = "SineOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "SineOut"))]
     SineOut,
-    #[serde(alias = "SineInOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "SineInOut"))]
     SineInOut,
-    #[serde(alias = "BackIn")]
+    #[cfg_attr(feature = "serde", serde(alias = "BackIn"))]
     BackIn,
-    #[serde(alias = "BackOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "BackOut"))]
     BackOut,
-    #[serde(alias = "BackInOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "BackInOut"))]
     BackInOut,
-    #[serde(alias = "ElasticIn")]
+    #[cfg_attr(feature = "serde", serde(alias = "ElasticIn"))]
     ElasticIn,
-    #[serde(alias = "ElasticOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "ElasticOut"))]
     ElasticOut,
-    #[serde(alias = "ElasticInOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "ElasticInOut"))]
     ElasticInOut,
-    #[serde(alias = "BounceIn")]
+    #[cfg_attr(feature = "serde", serde(alias = "BounceIn"))]
     BounceIn,
-    #[serde(alias = "BounceOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "BounceOut"))]
     BounceOut,
-    #[serde(alias = "BounceInOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "BounceInOut"))]
     BounceInOut,
-    #[serde(alias = "ExpoIn")]
+    #[cfg_attr(feature = "serde", serde(alias = "ExpoIn"))]
     ExpoIn,
-    #[serde(alias = "ExpoOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "ExpoOut"))]
     ExpoOut,
-    #[serde(alias = "ExpoInOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "ExpoInOut"))]
     ExpoInOut,
-    #[serde(alias = "CircIn")]
+    #[cfg_attr(feature = "serde", serde(alias = "CircIn"))]
     CircIn,
-    #[serde(alias = "CircOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "CircOut"))]
     CircOut,
-    #[serde(alias = "CircInOut")]
+    #[cfg_attr(feature = "serde", serde(alias = "CircInOut"))]
     CircInOut,
 }
 
+impl EasingType {
+    /// Every easing type, in declaration order.
+    pub const ALL: [EasingType; 25] = [
+        EasingType::Linear,
+        EasingType::QuadIn,
+        EasingType::QuadOut,
+        EasingType::QuadInOut,
+        EasingType::CubicIn,
+        EasingType::CubicOut,
+        EasingType::CubicInOut,
+        EasingType::SineIn,
+        EasingType::SineOut,
+        EasingType::SineInOut,
+        EasingType::BackIn,
+        EasingType::BackOut,
+        EasingType::BackInOut,
+        EasingType::ElasticIn,
+        EasingType::ElasticOut,
+        EasingType::ElasticInOut,
+        EasingType::BounceIn,
+        EasingType::BounceOut,
+        EasingType::BounceInOut,
+        EasingType::ExpoIn,
+        EasingType::ExpoOut,
+        EasingType::ExpoInOut,
+        EasingType::CircIn,
+        EasingType::CircOut,
+        EasingType::CircInOut,
+    ];
+
+    /// True for curves that leave [0, 1] on the way (`Back*`, `Elastic*`).
+    pub fn overshoots(self) -> bool {
+        matches!(
+            self,
+            EasingType::BackIn
+                | EasingType::BackOut
+                | EasingType::BackInOut
+                | EasingType::ElasticIn
+                | EasingType::ElasticOut
+                | EasingType::ElasticInOut
+        )
+    }
+}
+
+/// Tuning for the parameterized easing families.
+///
+/// Only `Back*` (overshoot) and `Elastic*` (amplitude, period) read these;
+/// every other curve ignores them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EaseParams {
+    /// How far `Back*` curves pull past the ends (default 1.70158, ~10%).
+    #[cfg_attr(feature = "serde", serde(default = "default_overshoot"))]
+    pub overshoot: f32,
+    /// Peak size of the `Elastic*` oscillation; values below 1 act as 1.
+    #[cfg_attr(feature = "serde", serde(default = "default_elastic_amplitude"))]
+    pub amplitude: f32,
+    /// Length of one `Elastic*` oscillation in normalized time
+    /// (None = 0.3, or 0.45 for `ElasticInOut`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub period: Option<f32>,
+}
+
+#[cfg(feature = "serde")]
+fn default_overshoot() -> f32 {
+    DEFAULT_BACK_OVERSHOOT
+}
+
+#[cfg(feature = "serde")]
+fn default_elastic_amplitude() -> f32 {
+    1.0
+}
+
+impl Default for EaseParams {
+    fn default() -> Self {
+        Self {
+            overshoot: DEFAULT_BACK_OVERSHOOT,
+            amplitude: 1.0,
+            period: None,
+        }
+    }
+}
+
+impl EaseParams {
+    /// Set the `Back*` overshoot.
+    pub fn with_overshoot(mut self, overshoot: f32) -> Self {
+        self.overshoot = overshoot;
+        self
+    }
+
+    /// Set the `Elastic*` amplitude and period.
+    pub fn with_elastic(mut self, amplitude: f32, period: f32) -> Self {
+        self.amplitude = amplitude;
+        self.period = Some(period);
+        self
+    }
+}
+
 /// Calculates the eased value for time `t` (usually 0.0 to 1.0).
 pub fn ease(t: SignalTime, type_: EasingType) -> f32 {
-    let t = t.clamp(0.0, 1.0) as f32;
+    ease_with(t, type_, EaseParams::default())
+}
+
+/// Like [`ease`], with tunable `Back*` overshoot and `Elastic*` shape.
+///
+/// ```rust
+/// use mixed_signals::easing::{ease, ease_with, EaseParams, EasingType};
+///
+/// let gentle = EaseParams::default().with_overshoot(0.5);
+/// assert!(ease_with(0.9, EasingType::BackOut, gentle) < ease(0.9, EasingType::BackOut));
+/// assert_eq!(ease_with(1.0, EasingType::ElasticOut, gentle), 1.0);
+/// ```
+pub fn ease_with(t: SignalTime, type_: EasingType, params: EaseParams) -> f32 {
+    let t = if t.is_nan() {
+        0.0
+    } else {
+        t.clamp(0.0, 1.0) as f32
+    };
     match type_ {
         EasingType::Linear => t,
         EasingType::QuadIn => t * t,
@@ -96,63 +222,12 @@ pub fn ease(t: SignalTime, type_: EasingType) -> f32 {
         EasingType::SineIn => 1.0 - (t * PI / 2.0).cos(),
         EasingType::SineOut => (t * PI / 2.0).sin(),
         EasingType::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
-        EasingType::BackIn => {
-            const C1: f32 = 1.70158;
-            const C3: f32 = C1 + 1.0;
-            C3 * t * t * t - C1 * t * t
-        }
-        EasingType::BackOut => {
-            const C1: f32 = 1.70158;
-            const C3: f32 = C1 + 1.0;
-            let t = t - 1.0;
-            1.0 + C3 * t * t * t + C1 * t * t
-        }
-        EasingType::BackInOut => {
-            const C1: f32 = 1.70158;
-            const C2: f32 = C1 * 1.525;
-            let t = t * 2.0;
-            if t < 1.0 {
-                (t * t * ((C2 + 1.0) * t - C2)) / 2.0
-            } else {
-                let t = t - 2.0;
-                (t * t * ((C2 + 1.0) * t + C2) + 2.0) / 2.0
-            }
-        }
-        EasingType::ElasticIn => {
-            if t == 0.0 || t == 1.0 {
-                t
-            } else {
-                let p = 0.3;
-                let s = p / 4.0;
-                let t = t - 1.0;
-                -(2.0_f32.powf(10.0 * t) * ((t - s) * (2.0 * PI) / p).sin())
-            }
-        }
-        EasingType::ElasticOut => {
-            if t == 0.0 || t == 1.0 {
-                t
-            } else {
-                let p = 0.3;
-                let s = p / 4.0;
-                2.0_f32.powf(-10.0 * t) * ((t - s) * (2.0 * PI) / p).sin() + 1.0
-            }
-        }
-        EasingType::ElasticInOut => {
-            if t == 0.0 || t == 1.0 {
-                t
-            } else {
-                let p = 0.3 * 1.5;
-                let s = p / 4.0;
-                let t = t * 2.0;
-                if t < 1.0 {
-                    let t = t - 1.0;
-                    -0.5 * (2.0_f32.powf(10.0 * t) * ((t - s) * (2.0 * PI) / p).sin())
-                } else {
-                    let t = t - 1.0;
-                    2.0_f32.powf(-10.0 * t) * ((t - s) * (2.0 * PI) / p).sin() * 0.5 + 1.0
-                }
-            }
-        }
+        EasingType::BackIn => back(t, EaseMode::In, params.overshoot),
+        EasingType::BackOut => back(t, EaseMode::Out, params.overshoot),
+        EasingType::BackInOut => back(t, EaseMode::InOut, params.overshoot),
+        EasingType::ElasticIn => elastic(t, EaseMode::In, params),
+        EasingType::ElasticOut => elastic(t, EaseMode::Out, params),
+        EasingType::ElasticInOut => elastic(t, EaseMode::InOut, params),
         EasingType::BounceIn => 1.0 - bounce_out(1.0 - t),
         EasingType::BounceOut => bounce_out(t),
         EasingType::BounceInOut => {
@@ -202,6 +277,87 @@ pub fn ease(t: SignalTime, type_: EasingType) -> f32 {
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum EaseMode {
+    In,
+    Out,
+    InOut,
+}
+
+/// Back easing: dips below 0 (in) or overshoots 1 (out) by an amount set by `c1`.
+fn back(t: f32, mode: EaseMode, c1: f32) -> f32 {
+    let c1 = if c1.is_finite() {
+        c1
+    } else {
+        DEFAULT_BACK_OVERSHOOT
+    };
+    match mode {
+        EaseMode::In => {
+            let c3 = c1 + 1.0;
+            c3 * t * t * t - c1 * t * t
+        }
+        EaseMode::Out => {
+            let c3 = c1 + 1.0;
+            let t = t - 1.0;
+            1.0 + c3 * t * t * t + c1 * t * t
+        }
+        EaseMode::InOut => {
+            let c2 = c1 * 1.525;
+            let t = t * 2.0;
+            if t < 1.0 {
+                (t * t * ((c2 + 1.0) * t - c2)) / 2.0
+            } else {
+                let t = t - 2.0;
+                (t * t * ((c2 + 1.0) * t + c2) + 2.0) / 2.0
+            }
+        }
+    }
+}
+
+/// Elastic easing (Penner), with amplitude `a` and period `p`.
+fn elastic(t: f32, mode: EaseMode, params: EaseParams) -> f32 {
+    if t == 0.0 || t == 1.0 {
+        return t;
+    }
+    let default_period = if mode == EaseMode::InOut {
+        0.3 * 1.5
+    } else {
+        0.3
+    };
+    let p = params
+        .period
+        .filter(|p| p.is_finite() && *p > 0.0)
+        .unwrap_or(default_period);
+    let a = if params.amplitude.is_finite() {
+        params.amplitude.max(1.0)
+    } else {
+        1.0
+    };
+    // Phase shift so the curve still passes through the endpoints
+    let s = if a <= 1.0 {
+        p / 4.0
+    } else {
+        p / (2.0 * PI) * (1.0 / a).asin()
+    };
+    let wave = |t: f32| a * ((t - s) * (2.0 * PI) / p).sin();
+
+    match mode {
+        EaseMode::In => {
+            let t = t - 1.0;
+            -(2.0_f32.powf(10.0 * t) * wave(t))
+        }
+        EaseMode::Out => 2.0_f32.powf(-10.0 * t) * wave(t) + 1.0,
+        EaseMode::InOut => {
+            let t = t * 2.0 - 1.0;
+            if t < 0.0 {
+                -0.5 * (2.0_f32.powf(10.0 * t) * wave(t))
+            } else {
+                2.0_f32.powf(-10.0 * t) * wave(t) * 0.5 + 1.0
+            }
+        }
+    }
+}
+
 /// Helper for bounce easing calculations.
 fn bounce_out(t: f32) -> f32 {
     const N1: f32 = 7.5625;
@@ -235,8 +391,94 @@ mod tests {
     fn test_ease_clamps_to_avoid_nan() {
         let v = ease(1.5, EasingType::CircIn);
         assert!(v.is_finite());
+        assert_eq!(ease(f64::NAN, EasingType::QuadOut), 0.0);
+    }
+
+    #[test]
+    fn test_every_easing_hits_endpoints() {
+        let tuned = EaseParams::default()
+            .with_overshoot(3.0)
+            .with_elastic(1.8, 0.2);
+        for easing in EasingType::ALL {
+            for params in [EaseParams::default(), tuned] {
+                let start = ease_with(0.0, easing, params);
+                let end = ease_with(1.0, easing, params);
+                assert!(start.abs() < 1e-5, "{:?} f(0) = {}", easing, start);
+                assert!((end - 1.0).abs() < 1e-5, "{:?} f(1) = {}", easing, end);
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_overshooting_families_are_monotonic() {
+        use EasingType::*;
+        let monotonic = [
+            Linear, QuadIn, QuadOut, QuadInOut, CubicIn, CubicOut, CubicInOut, SineIn, SineOut,
+            SineInOut, ExpoIn, ExpoOut, ExpoInOut, CircIn, CircOut, CircInOut,
+        ];
+        for easing in monotonic {
+            let mut prev = ease(0.0, easing);
+            for i in 1..=1000 {
+                let v = ease(i as f64 / 1000.0, easing);
+                assert!(v >= prev - 1e-6, "{:?} decreases at {}", easing, i);
+                prev = v;
+            }
+        }
+    }
+
+    fn extremes(easing: EasingType, params: EaseParams) -> (f32, f32) {
+        (0..=2000)
+            .map(|i| ease_with(i as f64 / 2000.0, easing, params))
+            .fold((f32::MAX, f32::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)))
+    }
+
+    #[test]
+    fn test_back_and_elastic_overshoot_as_designed() {
+        let defaults = EaseParams::default();
+        for easing in EasingType::ALL {
+            let (lo, hi) = extremes(easing, defaults);
+            assert_eq!(
+                lo < -1e-3 || hi > 1.0 + 1e-3,
+                easing.overshoots(),
+                "{:?} range [{}, {}]",
+                easing,
+                lo,
+                hi
+            );
+        }
+
+        // Back: ~10% dip by default, deeper with more overshoot
+        let (lo, _) = extremes(EasingType::BackIn, defaults);
+        assert!((lo + 0.1).abs() < 0.005, "BackIn min {}", lo);
+        let (lo_big, _) = extremes(EasingType::BackIn, defaults.with_overshoot(4.0));
+        assert!(lo_big < lo);
+        let (_, hi) = extremes(EasingType::BackOut, defaults);
+        assert!(hi > 1.09 && hi < 1.11, "BackOut max {}", hi);
+
+        // Elastic: larger amplitude rings harder
+        let (_, hi) = extremes(EasingType::ElasticOut, defaults);
+        let (_, hi_big) = extremes(EasingType::ElasticOut, defaults.with_elastic(2.0, 0.3));
+        assert!(hi > 1.0 && hi_big > hi, "{} vs {}", hi, hi_big);
+    }
+
+    #[test]
+    fn test_default_params_match_classic_curves() {
+        // Reference Penner formulas with the classic constants
+        const C1: f32 = 1.70158;
+        for i in 0..=20 {
+            let t = i as f32 / 20.0;
+            let back_in = (C1 + 1.0) * t * t * t - C1 * t * t;
+            assert!((ease(t as f64, EasingType::BackIn) - back_in).abs() < 1e-6);
+
+            if t > 0.0 && t < 1.0 {
+                let p = 0.3;
+                let elastic_out =
+                    2.0_f32.powf(-10.0 * t) * ((t - p / 4.0) * (2.0 * PI) / p).sin() + 1.0;
+                assert!((ease(t as f64, EasingType::ElasticOut) - elastic_out).abs() < 1e-6);
+            }
+        }
     }
 }
 
 // <FILE>mixed-signals/src/easing/fnc_ease.rs</FILE> - <DESC>Easing functions</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -1,10 +1,12 @@
 // <FILE>mixed-signals/src/easing/mod.rs</FILE> - <DESC>Easing module root</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Mixed-signals migration Phase 5 - WP2</WCTX>
-// <CLOG>Initial creation - exports easing functions from mixed-signals</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>Cubic-bezier easing</WCTX>
+// <CLOG>Added CubicBezier</CLOG>
 
+pub mod cls_cubic_bezier;
 pub mod fnc_ease;
-pub use fnc_ease::{ease, EasingType};
+pub use cls_cubic_bezier::CubicBezier;
+pub use fnc_ease::{ease, ease_with, EaseParams, EasingType, DEFAULT_BACK_OVERSHOOT};
 
 // <FILE>mixed-signals/src/easing/mod.rs</FILE> - <DESC>Easing module root</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -1,10 +1,11 @@
 // <FILE>mixed-signals/src/envelopes/cls_adsr.rs</FILE> - <DESC>ADSR envelope generator</DESC>
-// <VERS>VERSION: 1.1.0 - 2025-12-27</VERS>
-// <WCTX>Migrate time types from f32 to f64</WCTX>
-// <CLOG>Updated Signal::sample parameter from f32 to f64</CLOG>
+// <VERS>VERSION: 1.4.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::math::{finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// ADSR (Attack-Decay-Sustain-Release) envelope generator.
@@ -16,7 +17,10 @@ use serde::{Deserialize, Serialize};
 /// - Release: Ramp from sustain to 0
 ///
 /// Time is treated as normalized progress (0..1) over the envelope duration.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// For interactive notes held for an unknown time, use
+/// [`Adsr::gated`] / [`GatedAdsr`](super::GatedAdsr) instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Adsr {
     /// Attack time as fraction of total (0..1)
     pub attack: f32,
@@ -58,6 +62,26 @@ impl Adsr {
         self.peak = peak;
         self
     }
+
+    /// Build a note-off driven envelope with stage times in seconds.
+    ///
+    /// Shorthand for [`GatedAdsr::new`](super::GatedAdsr::new).
+    pub fn gated(attack: f32, decay: f32, sustain: f32, release: f32) -> super::GatedAdsr {
+        super::GatedAdsr::new(attack, decay, sustain, release)
+    }
+
+    /// Sample the envelope relative to the most recent trigger time.
+    ///
+    /// Equivalent to `sample(t - trigger_time)` once triggered. Before the
+    /// trigger the envelope has not started and returns the pre-attack level (0.0).
+    pub fn sample_triggered(&self, t: SignalTime, trigger_time: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let trigger_time = finite_or_f64(trigger_time, 0.0);
+        if t < trigger_time {
+            return 0.0;
+        }
+        self.sample(t - trigger_time)
+    }
 }
 
 impl Default for Adsr {
@@ -160,7 +184,24 @@ mod tests {
         let total = env.attack + env.decay + env.release;
         assert!(total <= 1.0 + 1e-6, "phase sum not normalized: {}", total);
     }
+
+    #[test]
+    fn test_adsr_sample_triggered_matches_offset_sample() {
+        let env = Adsr::new(0.2, 0.2, 0.5, 0.2);
+        let trigger = 2.5;
+        for i in 0..=20 {
+            let t = trigger + i as f64 * 0.05;
+            assert_eq!(env.sample_triggered(t, trigger), env.sample(t - trigger));
+        }
+    }
+
+    #[test]
+    fn test_adsr_sample_triggered_before_trigger() {
+        let env = Adsr::new(0.2, 0.2, 0.5, 0.2);
+        assert_eq!(env.sample_triggered(1.0, 2.5), 0.0);
+        assert_eq!(env.sample_triggered(2.49, 2.5), 0.0);
+    }
 }
 
 // <FILE>mixed-signals/src/envelopes/cls_adsr.rs</FILE> - <DESC>ADSR envelope generator</DESC>
-// <VERS>END OF VERSION: 1.1.0 - 2025-12-27</VERS>
+// <VERS>END OF VERSION: 1.4.0</VERS>
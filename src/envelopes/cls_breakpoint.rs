@@ -0,0 +1,147 @@
+// <FILE>src/envelopes/cls_breakpoint.rs</FILE> - <DESC>Multi-segment linear (breakpoint) envelope</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, alloc imports</CLOG>
+
+use crate::math::{finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalTime};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Multi-segment linear envelope (like Csound's `linseg`).
+///
+/// Starts at `initial` and ramps linearly through each `(duration, target)`
+/// segment in order: segment `i` moves from the previous target to `target`
+/// over `duration` seconds. After the last segment the final target is held;
+/// before t = 0 the initial value is held.
+///
+/// Zero (or negative/non-finite) durations jump straight to their target.
+/// Output is clamped to [0, 1] like the other envelopes.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::envelopes::BreakpointEnvelope;
+/// use mixed_signals::traits::Signal;
+///
+/// // Rise to 1.0 in 0.1s, fall to 0.4 in 0.2s, fade out over 0.5s
+/// let env = BreakpointEnvelope::from_segments(vec![(0.1, 1.0), (0.2, 0.4), (0.5, 0.0)]);
+/// assert!((env.sample(0.05) - 0.5).abs() < 1e-6);
+/// assert!((env.sample(0.3) - 0.4).abs() < 1e-6);
+/// assert_eq!(env.sample(2.0), 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BreakpointEnvelope {
+    /// Value at t = 0
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub initial: f32,
+    /// `(duration_seconds, target_value)` segments in order
+    pub segments: Vec<(f32, f32)>,
+}
+
+impl BreakpointEnvelope {
+    /// Create an envelope starting at 0.0 that ramps through `segments`.
+    pub fn from_segments(segments: Vec<(f32, f32)>) -> Self {
+        Self {
+            initial: 0.0,
+            segments,
+        }
+    }
+
+    /// Set the starting value.
+    pub fn with_initial(mut self, initial: f32) -> Self {
+        self.initial = initial;
+        self
+    }
+
+    /// Total length of all segments in seconds.
+    pub fn duration(&self) -> f32 {
+        self.segments
+            .iter()
+            .map(|&(duration, _)| finite_or(duration, 0.0).max(0.0))
+            .sum()
+    }
+}
+
+impl Default for BreakpointEnvelope {
+    fn default() -> Self {
+        Self::from_segments(Vec::new())
+    }
+}
+
+impl Signal for BreakpointEnvelope {
+    fn sample(&self, t: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let mut value = finite_or(self.initial, 0.0) as f64;
+        if t > 0.0 {
+            let mut start = 0.0f64;
+            for &(duration, target) in &self.segments {
+                let duration = finite_or(duration, 0.0).max(0.0) as f64;
+                let target = finite_or(target, value as f32) as f64;
+                let end = start + duration;
+                if t < end {
+                    let progress = (t - start) / duration;
+                    value += (target - value) * progress;
+                    break;
+                }
+                value = target;
+                start = end;
+            }
+        }
+        value.clamp(0.0, 1.0) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape() -> BreakpointEnvelope {
+        BreakpointEnvelope::from_segments(vec![(0.5, 1.0), (0.25, 0.5), (1.0, 0.0)])
+    }
+
+    #[test]
+    fn test_breakpoint_hits_segment_boundaries() {
+        let env = shape();
+        assert_eq!(env.sample(0.0), 0.0);
+        assert!((env.sample(0.5) - 1.0).abs() < 1e-6);
+        assert!((env.sample(0.75) - 0.5).abs() < 1e-6);
+        assert!(env.sample(1.75).abs() < 1e-6);
+        assert!((env.duration() - 1.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_breakpoint_linear_between_boundaries() {
+        let env = shape();
+        for i in 0..=10 {
+            let frac = i as f64 / 10.0;
+            let expected = 1.0 - 0.5 * frac;
+            assert!((env.sample(0.5 + 0.25 * frac) as f64 - expected).abs() < 1e-5);
+        }
+        // Midpoints are the mean of the neighbouring targets
+        assert!((env.sample(0.25) - 0.5).abs() < 1e-6);
+        assert!((env.sample(1.25) - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_breakpoint_holds_initial_and_last_value() {
+        let env = BreakpointEnvelope::from_segments(vec![(1.0, 0.2)]).with_initial(0.8);
+        assert!((env.sample(-1.0) - 0.8).abs() < 1e-6);
+        assert!((env.sample(5.0) - 0.2).abs() < 1e-6);
+        assert!((BreakpointEnvelope::default().with_initial(0.3).sample(2.0) - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_breakpoint_zero_duration_jumps() {
+        let env = BreakpointEnvelope::from_segments(vec![(0.0, 1.0), (1.0, 0.0)]);
+        assert!((env.sample(0.001) - 0.999).abs() < 1e-4);
+        let env = BreakpointEnvelope::from_segments(vec![(f32::NAN, 0.6), (-1.0, 0.9)]);
+        assert!((env.sample(0.1) - 0.9).abs() < 1e-6);
+        assert!(env.sample(f64::NAN).is_finite());
+    }
+}
+
+// <FILE>src/envelopes/cls_breakpoint.rs</FILE> - <DESC>Multi-segment linear (breakpoint) envelope</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
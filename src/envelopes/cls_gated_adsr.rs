@@ -0,0 +1,212 @@
+// <FILE>mixed-signals/src/envelopes/cls_gated_adsr.rs</FILE> - <DESC>ADSR envelope driven by a note-off (gate) time</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
+
+use crate::math::{finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// ADSR envelope whose sustain lasts as long as the gate is held.
+///
+/// [`Adsr`](super::Adsr) lays all four stages out over a fixed normalized
+/// timeline. That doesn't work when a key is held for an unknown time.
+/// `GatedAdsr` takes its stage times in seconds and separates the shape from
+/// the gate:
+///
+/// - While the gate is open: attack 0 -> peak, decay to `sustain * peak`,
+///   then hold.
+/// - When the gate closes at `gate` seconds: fall linearly from the level
+///   the envelope had at that instant to 0 over `release` seconds.
+///
+/// Releasing mid-attack or mid-decay therefore starts from the partial
+/// level, so there's no jump. Output always stays within [0, peak].
+///
+/// The gate length is either stored on the envelope ([`with_gate`], used by
+/// [`Signal::sample`]) or passed per call to [`sample_gated`]. Without a gate
+/// the note is held forever.
+///
+/// [`with_gate`]: GatedAdsr::with_gate
+/// [`sample_gated`]: GatedAdsr::sample_gated
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::envelopes::GatedAdsr;
+///
+/// let env = GatedAdsr::new(0.1, 0.2, 0.6, 0.5);
+/// // Key held for 2 seconds, then released
+/// assert!((env.sample_gated(1.0, 2.0) - 0.6).abs() < 1e-6);
+/// assert!((env.sample_gated(2.25, 2.0) - 0.3).abs() < 1e-6);
+/// // Released after 50ms, halfway up the attack: release starts from 0.5
+/// assert!((env.sample_gated(0.3, 0.05) - 0.25).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GatedAdsr {
+    /// Attack time in seconds
+    pub attack: f32,
+    /// Decay time in seconds
+    pub decay: f32,
+    /// Sustain level as a fraction of peak (0..1)
+    pub sustain: f32,
+    /// Release time in seconds
+    pub release: f32,
+    /// Peak level at end of attack (default 1.0)
+    pub peak: f32,
+    /// Note-off time in seconds (None = held forever)
+    pub gate: Option<f32>,
+}
+
+impl GatedAdsr {
+    pub fn new(attack: f32, decay: f32, sustain: f32, release: f32) -> Self {
+        Self {
+            attack: finite_or(attack, 0.0).max(0.0),
+            decay: finite_or(decay, 0.0).max(0.0),
+            sustain: finite_or(sustain, 0.7).clamp(0.0, 1.0),
+            release: finite_or(release, 0.0).max(0.0),
+            peak: 1.0,
+            gate: None,
+        }
+    }
+
+    pub fn with_peak(mut self, peak: f32) -> Self {
+        self.peak = peak;
+        self
+    }
+
+    /// Close the gate `gate` seconds after the note starts.
+    pub fn with_gate(mut self, gate: f32) -> Self {
+        self.gate = Some(gate);
+        self
+    }
+
+    fn peak_level(&self) -> f64 {
+        finite_or(self.peak, 1.0).max(0.0) as f64
+    }
+
+    /// Level while the gate is held, `t` seconds after note-on.
+    fn held_level(&self, t: f64) -> f64 {
+        let attack = finite_or(self.attack, 0.0).max(0.0) as f64;
+        let decay = finite_or(self.decay, 0.0).max(0.0) as f64;
+        let sustain = finite_or(self.sustain, 0.7).clamp(0.0, 1.0) as f64;
+        let peak = self.peak_level();
+
+        if t < attack {
+            peak * t / attack
+        } else if t < attack + decay {
+            let progress = (t - attack) / decay;
+            peak - (peak - sustain * peak) * progress
+        } else {
+            sustain * peak
+        }
+    }
+
+    /// Level `t` seconds after note-on, with the gate closing at `gate` seconds.
+    ///
+    /// Returns 0.0 before note-on (t < 0). A non-finite or negative gate is
+    /// treated as never closing.
+    pub fn sample_gated(&self, t: SignalTime, gate: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        if t < 0.0 {
+            return 0.0;
+        }
+        let peak = self.peak_level();
+        let value = if gate.is_finite() && gate >= 0.0 && t >= gate {
+            let release = finite_or(self.release, 0.0).max(0.0) as f64;
+            let elapsed = t - gate;
+            if elapsed >= release {
+                0.0
+            } else {
+                self.held_level(gate) * (1.0 - elapsed / release)
+            }
+        } else {
+            self.held_level(t)
+        };
+        value.clamp(0.0, peak) as f32
+    }
+}
+
+impl Default for GatedAdsr {
+    fn default() -> Self {
+        Self::new(0.01, 0.1, 0.7, 0.2)
+    }
+}
+
+impl Signal for GatedAdsr {
+    fn output_range(&self) -> SignalRange {
+        SignalRange::new(0.0, self.peak_level() as f32)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let gate = self.gate.map_or(SignalTime::INFINITY, |g| g as SignalTime);
+        self.sample_gated(t, gate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gated_adsr_release_during_attack() {
+        let env = GatedAdsr::new(1.0, 0.5, 0.5, 0.4);
+        // Gate closes at 0.25s: level 0.25, then falls to 0 over 0.4s
+        let at_gate = env.sample_gated(0.25, 0.25);
+        assert!((at_gate - 0.25).abs() < 1e-6);
+        assert!((env.sample_gated(0.45, 0.25) - 0.125).abs() < 1e-6);
+        assert_eq!(env.sample_gated(0.7, 0.25), 0.0);
+        // No jump at the gate
+        assert!((env.sample_gated(0.2499, 0.25) - at_gate).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_gated_adsr_release_during_decay() {
+        let env = GatedAdsr::new(0.1, 0.4, 0.2, 1.0);
+        // Halfway through decay: 1.0 -> 0.2, level 0.6
+        let gate = 0.3;
+        assert!((env.sample_gated(gate, gate) - 0.6).abs() < 1e-6);
+        assert!((env.sample_gated(gate + 0.5, gate) - 0.3).abs() < 1e-6);
+        assert_eq!(env.sample_gated(gate + 1.0, gate), 0.0);
+    }
+
+    #[test]
+    fn test_gated_adsr_zero_length_attack_and_sustain() {
+        let env = GatedAdsr::new(0.0, 0.0, 0.4, 0.0).with_peak(2.0);
+        assert_eq!(env.sample_gated(0.0, 5.0), 0.8);
+        assert_eq!(env.sample_gated(4.9, 5.0), 0.8);
+        // Zero release cuts straight to silence
+        assert_eq!(env.sample_gated(5.0, 5.0), 0.0);
+
+        let attack_only = GatedAdsr::new(0.0, 0.5, 0.5, 0.5);
+        assert_eq!(attack_only.sample_gated(0.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn test_gated_adsr_stays_within_peak() {
+        let env = GatedAdsr::new(0.3, 0.2, 0.6, 0.7).with_peak(0.8);
+        for gate in [0.0, 0.1, 0.35, 2.0] {
+            for i in -10..400 {
+                let v = env.sample_gated(i as f64 * 0.01, gate);
+                assert!((0.0..=0.8).contains(&v), "{v} at gate {gate}");
+            }
+        }
+        assert_eq!(env.output_range(), SignalRange::new(0.0, 0.8));
+    }
+
+    #[test]
+    fn test_gated_adsr_signal_uses_stored_gate() {
+        let env = GatedAdsr::new(0.1, 0.1, 0.5, 0.5);
+        assert_eq!(env.sample(100.0), 0.5);
+        let released = env.with_gate(1.0);
+        for i in 0..300 {
+            let t = i as f64 * 0.01;
+            assert_eq!(released.sample(t), env.sample_gated(t, 1.0));
+        }
+        assert_eq!(env.sample_gated(1.0, f64::NAN), 0.5);
+    }
+}
+
+// <FILE>mixed-signals/src/envelopes/cls_gated_adsr.rs</FILE> - <DESC>ADSR envelope driven by a note-off (gate) time</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
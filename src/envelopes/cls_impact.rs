@@ -1,24 +1,72 @@
 // <FILE>mixed-signals/src/envelopes/cls_impact.rs</FILE> - <DESC>Impact/decay envelope</DESC>
-// <VERS>VERSION: 1.1.0 - 2025-12-27</VERS>
-// <WCTX>Migrate time types from f32 to f64</WCTX>
-// <CLOG>Updated Signal::sample parameter from f32 to f64</CLOG>
+// <VERS>VERSION: 1.4.0</VERS>
+// <WCTX>Configurable impact rebounds</WCTX>
+// <CLOG>Added rebound frequency, bounce count, double hit and settle_time</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Rebound frequency in Hz used when only a bounce count is set.
+pub const DEFAULT_IMPACT_BOUNCE_FREQUENCY: f32 = 6.0;
+
+/// Fraction of `intensity` below which [`Impact::settle_time`] counts the
+/// envelope as settled.
+pub const IMPACT_SETTLE_LEVEL: f32 = 0.01;
+
 /// Impact envelope with instant attack and exponential decay.
 ///
 /// Useful for shake effects, hit feedback, or any sudden impact
 /// that fades over time.
 ///
 /// Formula: `output = intensity * e^(-decay * t)`
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+///
+/// # Bounces
+///
+/// [`with_frequency`](Self::with_frequency) adds rebounds: the decay is
+/// multiplied by `|cos(π · frequency · t)|`, so the hit at t = 0 is followed
+/// by a bounce peaking near every `1 / frequency` seconds.
+/// [`with_bounces`](Self::with_bounces) stops after `n` bounces (output is 0
+/// from `(n + 0.5) / frequency`). Without a frequency, bounces use
+/// [`DEFAULT_IMPACT_BOUNCE_FREQUENCY`].
+///
+/// [`with_double_hit`](Self::with_double_hit) adds a second, scaled copy of
+/// the hit after a delay; the output is the louder of the two, so it never
+/// exceeds the first hit's peak.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::envelopes::Impact;
+/// use mixed_signals::traits::Signal;
+///
+/// // Thud with three rebounds at 4 Hz
+/// let thud = Impact::new(1.0, 2.0).with_frequency(4.0).with_bounces(3);
+/// assert_eq!(thud.sample(0.0), 1.0);
+/// assert_eq!(thud.sample(thud.settle_time() as f64 + 0.01), 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Impact {
     /// Starting intensity (peak value at t=0)
     pub intensity: f32,
     /// Decay rate (higher = faster decay)
     pub decay: f32,
+    /// Rebound frequency in Hz (0 = no rebounds)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub frequency: f32,
+    /// Number of rebounds before the envelope stops (None = unlimited)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bounces: Option<u32>,
+    /// Delay of the secondary hit in seconds (0 = single hit)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub double_hit_delay: f32,
+    /// Secondary hit level relative to the first, in [0, 1]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub double_hit_level: f32,
 }
 
 impl Impact {
@@ -26,6 +74,10 @@ impl Impact {
         Self {
             intensity,
             decay: decay.max(0.0),
+            frequency: 0.0,
+            bounces: None,
+            double_hit_delay: 0.0,
+            double_hit_level: 0.0,
         }
     }
 
@@ -33,14 +85,104 @@ impl Impact {
     pub fn with_intensity(intensity: f32) -> Self {
         Self::new(intensity, 3.0)
     }
+
+    /// Rebound at `hz` (one bounce peak every `1 / hz` seconds).
+    pub fn with_frequency(mut self, hz: f32) -> Self {
+        self.frequency = hz.max(0.0);
+        self
+    }
+
+    /// Stop after `n` rebounds (0 = only the initial hit).
+    pub fn with_bounces(mut self, n: u32) -> Self {
+        self.bounces = Some(n);
+        self
+    }
+
+    /// Repeat the hit `delay` seconds later at `level` times the intensity.
+    pub fn with_double_hit(mut self, delay: f32, level: f32) -> Self {
+        self.double_hit_delay = delay.max(0.0);
+        self.double_hit_level = level.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Time in seconds after which the output stays below
+    /// [`IMPACT_SETTLE_LEVEL`] of `intensity`.
+    ///
+    /// `ln(100) / decay`, or the end of the last bounce if that comes first,
+    /// plus the double-hit delay. Returns `f32::INFINITY` when nothing ever
+    /// settles (zero decay and no bounce limit).
+    pub fn settle_time(&self) -> f32 {
+        let decay = finite_or(self.decay, 3.0) as f64;
+        let mut settle = if decay > 0.0 {
+            -(IMPACT_SETTLE_LEVEL as f64).ln() / decay
+        } else {
+            f64::INFINITY
+        };
+        if let Some(end) = self.bounce_end() {
+            settle = settle.min(end);
+        }
+        if let Some((delay, _)) = self.double_hit() {
+            settle += delay;
+        }
+        settle as f32
+    }
+
+    /// Sample the envelope relative to the most recent trigger time.
+    ///
+    /// Equivalent to `sample(t - trigger_time)` once triggered. Before the
+    /// trigger the envelope has not started and returns 0.0 (no impact yet).
+    pub fn sample_triggered(&self, t: SignalTime, trigger_time: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let trigger_time = finite_or_f64(trigger_time, 0.0);
+        if t < trigger_time {
+            return 0.0;
+        }
+        self.sample(t - trigger_time)
+    }
+
+    /// Effective rebound frequency in Hz (0 = plain decay).
+    fn bounce_frequency(&self) -> f64 {
+        let hz = finite_or(self.frequency, 0.0).max(0.0);
+        match self.bounces {
+            Some(n) if n > 0 && hz == 0.0 => DEFAULT_IMPACT_BOUNCE_FREQUENCY as f64,
+            _ => hz as f64,
+        }
+    }
+
+    /// Time at which the last allowed bounce ends, if bounces are limited.
+    fn bounce_end(&self) -> Option<f64> {
+        let hz = self.bounce_frequency();
+        match self.bounces {
+            Some(n) if hz > 0.0 => Some((n as f64 + 0.5) / hz),
+            _ => None,
+        }
+    }
+
+    /// `(delay, level)` of the secondary hit, if enabled.
+    fn double_hit(&self) -> Option<(f64, f64)> {
+        let delay = finite_or(self.double_hit_delay, 0.0) as f64;
+        let level = finite_or(self.double_hit_level, 0.0).clamp(0.0, 1.0) as f64;
+        (delay > 0.0 && level > 0.0).then_some((delay, level))
+    }
+
+    /// Single hit at `t >= 0`, before intensity scaling and clamping.
+    fn hit(&self, t: f64) -> f64 {
+        let decay = finite_or(self.decay, 3.0) as f64;
+        let envelope = (-decay * t).exp();
+        let hz = self.bounce_frequency();
+        if hz == 0.0 {
+            return envelope;
+        }
+        if self.bounce_end().is_some_and(|end| t >= end) {
+            return 0.0;
+        }
+        envelope * (core::f64::consts::PI * hz * t).cos().abs()
+    }
 }
 
 impl Default for Impact {
     fn default() -> Self {
-        Self {
-            intensity: 1.0,
-            decay: 3.0,
-        }
+        Self::new(1.0, 3.0)
     }
 }
 
@@ -48,12 +190,17 @@ impl Signal for Impact {
     fn sample(&self, t: SignalTime) -> f32 {
         let t = finite_or_f64(t, 0.0);
         let intensity = finite_or(self.intensity, 1.0) as f64;
-        let decay = finite_or(self.decay, 3.0) as f64;
 
         if t < 0.0 {
             return intensity.clamp(0.0, 1.0) as f32;
         }
-        (intensity * (-decay * t).exp()).clamp(0.0, 1.0) as f32
+        let mut value = self.hit(t);
+        if let Some((delay, level)) = self.double_hit() {
+            if t >= delay {
+                value = value.max(level * self.hit(t - delay));
+            }
+        }
+        (intensity * value).clamp(0.0, 1.0) as f32
     }
 }
 
@@ -98,7 +245,92 @@ mod tests {
         // At t=3 with decay=3, should be e^-9 ≈ 0.0001
         assert!(impact.sample(3.0) < 0.01);
     }
+
+    #[test]
+    fn test_impact_sample_triggered_matches_offset_sample() {
+        let env = Impact::new(0.8, 2.0);
+        let trigger = 2.5;
+        for i in 0..=20 {
+            let t = trigger + i as f64 * 0.05;
+            assert_eq!(env.sample_triggered(t, trigger), env.sample(t - trigger));
+        }
+    }
+
+    #[test]
+    fn test_impact_sample_triggered_before_trigger() {
+        let env = Impact::new(0.8, 2.0);
+        assert_eq!(env.sample_triggered(1.0, 2.5), 0.0);
+        assert_eq!(env.sample_triggered(2.49, 2.5), 0.0);
+    }
+
+    fn local_maxima(env: &Impact, threshold: f32) -> usize {
+        let values: Vec<f32> = (0..4000).map(|i| env.sample(i as f64 * 0.001)).collect();
+        values
+            .windows(3)
+            .filter(|w| w[1] > threshold && w[1] > w[0] && w[1] >= w[2])
+            .count()
+    }
+
+    #[test]
+    fn test_impact_bounce_count_matches_maxima() {
+        for n in [1, 3, 5] {
+            let thud = Impact::new(1.0, 1.5).with_frequency(4.0).with_bounces(n);
+            assert_eq!(local_maxima(&thud, 0.01), n as usize, "bounces = {n}");
+        }
+        // Bounce count alone falls back to the default rebound frequency
+        let default_rate = Impact::new(1.0, 1.5).with_bounces(2);
+        assert_eq!(local_maxima(&default_rate, 0.01), 2);
+        // The two-argument constructor keeps the plain decay
+        assert_eq!(local_maxima(&Impact::new(1.0, 1.5), 0.01), 0);
+    }
+
+    #[test]
+    fn test_impact_settles_after_settle_time() {
+        let cases = [
+            Impact::new(0.9, 3.0),
+            Impact::new(1.0, 2.0).with_frequency(5.0),
+            Impact::new(1.0, 0.5).with_frequency(3.0).with_bounces(2),
+            Impact::new(0.8, 4.0)
+                .with_frequency(6.0)
+                .with_double_hit(0.3, 0.6),
+        ];
+        for env in cases {
+            let settle = env.settle_time() as f64;
+            assert!(settle.is_finite(), "{env:?}");
+            for i in 0..200 {
+                let t = settle + 1e-4 + i as f64 * 0.01;
+                assert!(env.sample(t) < 0.01 * env.intensity, "{env:?} at {t}");
+            }
+        }
+        assert_eq!(Impact::new(1.0, 0.0).settle_time(), f32::INFINITY);
+        assert_eq!(
+            Impact::new(1.0, 0.0).with_bounces(0).settle_time(),
+            f32::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_impact_double_hit_and_range() {
+        let env = Impact::new(1.0, 6.0).with_double_hit(0.5, 0.5);
+        let plain = Impact::new(1.0, 6.0);
+        assert_eq!(env.sample(0.2), plain.sample(0.2));
+        assert!((env.sample(0.5) - 0.5).abs() < 1e-6);
+
+        let range = env.output_range();
+        for env in [
+            env,
+            Impact::new(2.0, 3.0).with_frequency(8.0).with_bounces(4),
+            Impact::new(0.7, 1.0)
+                .with_frequency(2.0)
+                .with_double_hit(0.25, 1.0),
+        ] {
+            let peak = (0..3000)
+                .map(|i| env.sample(i as f64 * 0.001))
+                .fold(f32::MIN, f32::max);
+            assert!(peak <= range.max && peak >= range.min, "{peak}");
+        }
+    }
 }
 
 // <FILE>mixed-signals/src/envelopes/cls_impact.rs</FILE> - <DESC>Impact/decay envelope</DESC>
-// <VERS>END OF VERSION: 1.1.0 - 2025-12-27</VERS>
+// <VERS>END OF VERSION: 1.4.0</VERS>
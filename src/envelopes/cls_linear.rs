@@ -1,17 +1,19 @@
 // <FILE>mixed-signals/src/envelopes/cls_linear.rs</FILE> - <DESC>Simple linear envelope</DESC>
-// <VERS>VERSION: 1.1.0 - 2025-12-27</VERS>
-// <WCTX>Migrate time types from f32 to f64</WCTX>
-// <CLOG>Updated Signal::sample parameter from f32 to f64</CLOG>
+// <VERS>VERSION: 1.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::math::{finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Simple linear attack-release envelope.
 ///
 /// Ramps up linearly during attack, holds at peak, then ramps down during release.
 /// Simpler than ADSR when you don't need decay/sustain distinction.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LinearEnvelope {
     /// Attack time as fraction of total (0..1)
     pub attack: f32,
@@ -48,6 +50,19 @@ impl LinearEnvelope {
     pub fn symmetric(time: f32) -> Self {
         Self::new(time, time)
     }
+
+    /// Sample the envelope relative to the most recent trigger time.
+    ///
+    /// Equivalent to `sample(t - trigger_time)` once triggered. Before the
+    /// trigger the envelope has not started and returns the pre-attack level (0.0).
+    pub fn sample_triggered(&self, t: SignalTime, trigger_time: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let trigger_time = finite_or_f64(trigger_time, 0.0);
+        if t < trigger_time {
+            return 0.0;
+        }
+        self.sample(t - trigger_time)
+    }
 }
 
 impl Default for LinearEnvelope {
@@ -137,7 +152,24 @@ mod tests {
         let total = env.attack + env.release;
         assert!(total <= 1.0 + 1e-6, "phase sum not normalized: {}", total);
     }
+
+    #[test]
+    fn test_linear_sample_triggered_matches_offset_sample() {
+        let env = LinearEnvelope::new(0.2, 0.2);
+        let trigger = 2.5;
+        for i in 0..=20 {
+            let t = trigger + i as f64 * 0.05;
+            assert_eq!(env.sample_triggered(t, trigger), env.sample(t - trigger));
+        }
+    }
+
+    #[test]
+    fn test_linear_sample_triggered_before_trigger() {
+        let env = LinearEnvelope::new(0.2, 0.2);
+        assert_eq!(env.sample_triggered(1.0, 2.5), 0.0);
+        assert_eq!(env.sample_triggered(2.49, 2.5), 0.0);
+    }
 }
 
 // <FILE>mixed-signals/src/envelopes/cls_linear.rs</FILE> - <DESC>Simple linear envelope</DESC>
-// <VERS>END OF VERSION: 1.1.0 - 2025-12-27</VERS>
+// <VERS>END OF VERSION: 1.3.0</VERS>
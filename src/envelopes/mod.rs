@@ -1,7 +1,7 @@
 // <FILE>mixed-signals/src/envelopes/mod.rs</FILE> - <DESC>Envelope generators module</DESC>
-// <VERS>VERSION: 1.0.0 - 2025-12-22</VERS>
-// <WCTX>Signal generator implementation</WCTX>
-// <CLOG>Initial implementation</CLOG>
+// <VERS>VERSION: 1.3.0</VERS>
+// <WCTX>Configurable impact rebounds</WCTX>
+// <CLOG>Export impact bounce constants</CLOG>
 
 //! Envelope generators for time-shaped control signals.
 //!
@@ -9,12 +9,16 @@
 //! outputs finite. For valid finite inputs, behavior is unchanged.
 
 mod cls_adsr;
+mod cls_breakpoint;
+mod cls_gated_adsr;
 mod cls_impact;
 mod cls_linear;
 
 pub use cls_adsr::Adsr;
-pub use cls_impact::Impact;
+pub use cls_breakpoint::BreakpointEnvelope;
+pub use cls_gated_adsr::GatedAdsr;
+pub use cls_impact::{Impact, DEFAULT_IMPACT_BOUNCE_FREQUENCY, IMPACT_SETTLE_LEVEL};
 pub use cls_linear::LinearEnvelope;
 
 // <FILE>mixed-signals/src/envelopes/mod.rs</FILE> - <DESC>Envelope generators module</DESC>
-// <VERS>END OF VERSION: 1.0.0 - 2025-12-22</VERS>
+// <VERS>END OF VERSION: 1.3.0</VERS>
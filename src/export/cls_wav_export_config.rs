@@ -0,0 +1,285 @@
+// <FILE>src/export/cls_wav_export_config.rs</FILE> - <DESC>Configurable WAV encoding</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>One WAV encoder</WCTX>
+// <CLOG>write_file validates before creating the file</CLOG>
+
+use super::mu_law_encode;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Sample encoding applied before writing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Companding {
+    /// Linear PCM at `bits_per_sample`
+    #[default]
+    None,
+    /// G.711 µ-law, always 8 bits per sample
+    MuLaw,
+}
+
+/// WAV file layout: bit depth, channel count, sample rate, and companding.
+///
+/// Samples are `f32` in [-1, 1] (clamped; non-finite values become silence),
+/// interleaved by channel. Linear PCM supports 8-bit (unsigned, 256 levels),
+/// 16-bit, and 24-bit; µ-law companding writes 8-bit G.711 data (WAV format
+/// 7) whatever `bits_per_sample` says.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::export::WavExportConfig;
+///
+/// let config = WavExportConfig::new(8000).with_bits_per_sample(8);
+/// let bytes = config.to_bytes(&[0.0, 0.5, -0.5]).unwrap();
+/// assert_eq!(&bytes[..4], b"RIFF");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WavExportConfig {
+    /// Bits per linear PCM sample: 8, 16, or 24
+    pub bits_per_sample: u16,
+    /// Interleaved channel count
+    pub channels: u16,
+    /// Frames per second
+    pub sample_rate: u32,
+    /// Optional companding
+    pub companding: Companding,
+}
+
+impl Default for WavExportConfig {
+    fn default() -> Self {
+        Self::new(44_100)
+    }
+}
+
+impl WavExportConfig {
+    /// 16-bit mono linear PCM at `sample_rate`.
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            bits_per_sample: 16,
+            channels: 1,
+            sample_rate,
+            companding: Companding::None,
+        }
+    }
+
+    /// Set the linear PCM bit depth (8, 16, or 24).
+    pub fn with_bits_per_sample(mut self, bits_per_sample: u16) -> Self {
+        self.bits_per_sample = bits_per_sample;
+        self
+    }
+
+    /// Set the number of interleaved channels.
+    pub fn with_channels(mut self, channels: u16) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// Encode with G.711 µ-law (8-bit) instead of linear PCM.
+    pub fn with_mu_law(mut self) -> Self {
+        self.companding = Companding::MuLaw;
+        self
+    }
+
+    /// Bits actually written per sample.
+    pub fn stored_bits(&self) -> u16 {
+        match self.companding {
+            Companding::None => self.bits_per_sample,
+            Companding::MuLaw => 8,
+        }
+    }
+
+    fn validate(&self, sample_count: usize) -> io::Result<()> {
+        let invalid = |message: String| Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+        if self.companding == Companding::None && ![8, 16, 24].contains(&self.bits_per_sample) {
+            return invalid(format!(
+                "unsupported bits_per_sample {} (expected 8, 16, or 24)",
+                self.bits_per_sample
+            ));
+        }
+        if self.channels == 0 || self.sample_rate == 0 {
+            return invalid("channels and sample_rate must be non-zero".to_string());
+        }
+        if sample_count % self.channels as usize != 0 {
+            return invalid(format!(
+                "{} samples do not fill whole {}-channel frames",
+                sample_count, self.channels
+            ));
+        }
+        Ok(())
+    }
+
+    /// Encode interleaved samples to the WAV data chunk payload.
+    pub fn encode_samples(&self, samples: &[f32]) -> io::Result<Vec<u8>> {
+        self.validate(samples.len())?;
+        let bytes_per_sample = self.stored_bits() as usize / 8;
+        let mut data = Vec::with_capacity(samples.len() * bytes_per_sample);
+        for &sample in samples {
+            let x = if sample.is_finite() {
+                sample.clamp(-1.0, 1.0)
+            } else {
+                0.0
+            };
+            match (self.companding, self.bits_per_sample) {
+                (Companding::MuLaw, _) => data.push(mu_law_encode(x)),
+                // 8-bit WAV is unsigned with silence at 128
+                (Companding::None, 8) => data.push((x * 127.5 + 127.5).round() as u8),
+                (Companding::None, 16) => {
+                    data.extend_from_slice(&((x * 32767.0).round() as i16).to_le_bytes())
+                }
+                (Companding::None, _) => {
+                    let value = (x * 8_388_607.0).round() as i32;
+                    data.extend_from_slice(&value.to_le_bytes()[..3]);
+                }
+            }
+        }
+        Ok(data)
+    }
+
+    /// Write a complete WAV file (header and data) to `writer`.
+    pub fn write<W: Write>(&self, mut writer: W, samples: &[f32]) -> io::Result<()> {
+        let data = self.encode_samples(samples)?;
+        let data_len = u32::try_from(data.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "WAV data exceeds 4 GiB"))?;
+        let pad = data.len() % 2;
+        let channels = self.channels;
+        let bits = self.stored_bits();
+        let block_align = channels * (bits / 8);
+        let byte_rate = self.sample_rate * block_align as u32;
+        let mu_law = self.companding == Companding::MuLaw;
+
+        // Non-PCM formats carry cbSize in fmt and a fact chunk
+        let fmt_len: u32 = if mu_law { 18 } else { 16 };
+        let fact_len: u32 = if mu_law { 12 } else { 0 };
+        let riff_len = 4 + (8 + fmt_len) + fact_len + 8 + data_len + pad as u32;
+
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&riff_len.to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&fmt_len.to_le_bytes())?;
+        writer.write_all(&(if mu_law { 7u16 } else { 1u16 }).to_le_bytes())?;
+        writer.write_all(&channels.to_le_bytes())?;
+        writer.write_all(&self.sample_rate.to_le_bytes())?;
+        writer.write_all(&byte_rate.to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&bits.to_le_bytes())?;
+        if mu_law {
+            writer.write_all(&0u16.to_le_bytes())?;
+            writer.write_all(b"fact")?;
+            writer.write_all(&4u32.to_le_bytes())?;
+            let frames = (samples.len() / channels as usize) as u32;
+            writer.write_all(&frames.to_le_bytes())?;
+        }
+        writer.write_all(b"data")?;
+        writer.write_all(&data_len.to_le_bytes())?;
+        writer.write_all(&data)?;
+        if pad == 1 {
+            writer.write_all(&[0])?;
+        }
+        writer.flush()
+    }
+
+    /// Encode a complete WAV file in memory.
+    pub fn to_bytes(&self, samples: &[f32]) -> io::Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write(&mut bytes, samples)?;
+        Ok(bytes)
+    }
+
+    /// Write a WAV file to `path`.
+    ///
+    /// An invalid configuration is rejected before the file is created.
+    pub fn write_file<P: AsRef<Path>>(&self, path: P, samples: &[f32]) -> io::Result<()> {
+        self.validate(samples.len())?;
+        self.write(BufWriter::new(File::create(path)?), samples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::mu_law_decode;
+    use std::collections::HashSet;
+
+    fn u16_at(bytes: &[u8], at: usize) -> u16 {
+        u16::from_le_bytes([bytes[at], bytes[at + 1]])
+    }
+
+    fn u32_at(bytes: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+    }
+
+    fn ramp(len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| i as f32 / (len - 1) as f32 * 2.0 - 1.0)
+            .collect()
+    }
+
+    #[test]
+    fn test_wav_16_bit_header_fields() {
+        let config = WavExportConfig::new(22_050).with_channels(2);
+        let bytes = config.to_bytes(&[0.0, 1.0, -1.0, 0.5]).unwrap();
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(u32_at(&bytes, 4) as usize, bytes.len() - 8);
+        assert_eq!(&bytes[8..16], b"WAVEfmt ");
+        assert_eq!(u16_at(&bytes, 20), 1); // PCM
+        assert_eq!(u16_at(&bytes, 22), 2);
+        assert_eq!(u32_at(&bytes, 24), 22_050);
+        assert_eq!(u32_at(&bytes, 28), 22_050 * 4);
+        assert_eq!(u16_at(&bytes, 32), 4);
+        assert_eq!(u16_at(&bytes, 34), 16);
+        assert_eq!(&bytes[36..40], b"data");
+        assert_eq!(u32_at(&bytes, 40), 8);
+        assert_eq!(i16::from_le_bytes([bytes[46], bytes[47]]), 32767);
+    }
+
+    #[test]
+    fn test_wav_8_bit_quantizes_to_256_levels() {
+        let config = WavExportConfig::new(8000).with_bits_per_sample(8);
+        let samples = ramp(10_001);
+        let data = config.encode_samples(&samples).unwrap();
+        assert_eq!(data.len(), samples.len());
+        let levels: HashSet<u8> = data.iter().copied().collect();
+        assert_eq!(levels.len(), 256);
+        assert_eq!((data[0], data[5000], data[10_000]), (0, 128, 255));
+
+        // Odd-length 8-bit data gets a RIFF pad byte
+        let bytes = config.to_bytes(&[0.0; 3]).unwrap();
+        assert_eq!(bytes.len(), 44 + 4);
+        assert_eq!(u32_at(&bytes, 4) as usize, bytes.len() - 8);
+    }
+
+    #[test]
+    fn test_wav_mu_law_round_trips_approximately() {
+        let config = WavExportConfig::new(8000).with_mu_law();
+        let samples: Vec<f32> = (0..400).map(|i| (i as f32 * 0.05).sin() * 0.8).collect();
+        let bytes = config.to_bytes(&samples).unwrap();
+        assert_eq!(u16_at(&bytes, 20), 7); // µ-law format tag
+        assert_eq!(u16_at(&bytes, 34), 8);
+        assert_eq!(&bytes[38..42], b"fact");
+        assert_eq!(u32_at(&bytes, 46), 400);
+        assert_eq!(&bytes[50..54], b"data");
+        let data = &bytes[58..58 + 400];
+        for (&x, &code) in samples.iter().zip(data) {
+            assert!((mu_law_decode(code) - x).abs() < 0.03, "{}", x);
+        }
+    }
+
+    #[test]
+    fn test_wav_24_bit_and_invalid_configs() {
+        let data = WavExportConfig::new(48_000)
+            .with_bits_per_sample(24)
+            .encode_samples(&[1.0, f32::NAN])
+            .unwrap();
+        assert_eq!(data, vec![0xFF, 0xFF, 0x7F, 0, 0, 0]);
+        let bad_bits = WavExportConfig::new(48_000).with_bits_per_sample(12);
+        assert!(bad_bits.encode_samples(&[0.0]).is_err());
+        let stereo = WavExportConfig::new(48_000).with_channels(2);
+        assert!(stereo.to_bytes(&[0.0, 0.1, 0.2]).is_err());
+        assert!(WavExportConfig::new(0).to_bytes(&[]).is_err());
+    }
+}
+
+// <FILE>src/export/cls_wav_export_config.rs</FILE> - <DESC>Configurable WAV encoding</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -0,0 +1,86 @@
+// <FILE>src/export/fnc_mu_law.rs</FILE> - <DESC>G.711 µ-law companding</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Retro WAV export</WCTX>
+// <CLOG>Initial creation - mu_law_encode/mu_law_decode</CLOG>
+
+/// Bias added before segment lookup (G.711).
+const BIAS: i32 = 0x84;
+/// Largest magnitude that survives biasing without overflow.
+const CLIP: i32 = 32635;
+
+/// Encode a sample in [-1, 1] as a G.711 µ-law byte.
+///
+/// µ-law spends its 8 bits logarithmically, so quiet passages keep roughly
+/// 14-bit resolution while loud ones are coarse - the classic telephone
+/// sound. Out-of-range input is clamped; non-finite input encodes silence.
+pub fn mu_law_encode(sample: f32) -> u8 {
+    let sample = if sample.is_finite() { sample } else { 0.0 };
+    let mut pcm = (sample.clamp(-1.0, 1.0) * 32767.0).round() as i32;
+
+    let sign = if pcm < 0 { 0x80 } else { 0x00 };
+    if pcm < 0 {
+        pcm = -pcm;
+    }
+    pcm = pcm.min(CLIP) + BIAS;
+
+    // Segment = position of the highest set bit above bit 7
+    let mut exponent = 7;
+    let mut mask = 0x4000;
+    while exponent > 0 && pcm & mask == 0 {
+        exponent -= 1;
+        mask >>= 1;
+    }
+    let mantissa = (pcm >> (exponent + 3)) & 0x0F;
+    !(sign | (exponent << 4) | mantissa) as u8
+}
+
+/// Decode a G.711 µ-law byte to a sample in [-1, 1].
+pub fn mu_law_decode(byte: u8) -> f32 {
+    let byte = !byte as i32;
+    let exponent = (byte >> 4) & 0x07;
+    let mantissa = byte & 0x0F;
+    let magnitude = (((mantissa << 3) + BIAS) << exponent) - BIAS;
+    let pcm = if byte & 0x80 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    };
+    pcm as f32 / 32767.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mu_law_round_trip_approximate() {
+        for i in -1000..=1000 {
+            let x = i as f32 / 1000.0;
+            let y = mu_law_decode(mu_law_encode(x));
+            // Logarithmic steps: error scales with magnitude
+            let tolerance = 0.002 + x.abs() * 0.035;
+            assert!((x - y).abs() <= tolerance, "{} -> {}", x, y);
+        }
+    }
+
+    #[test]
+    fn test_mu_law_known_codes() {
+        // G.711: silence is 0xFF, full scale is 0x80 / 0x00
+        assert_eq!(mu_law_encode(0.0), 0xFF);
+        assert_eq!(mu_law_encode(1.0), 0x80);
+        assert_eq!(mu_law_encode(-1.0), 0x00);
+        assert_eq!(mu_law_decode(0xFF), 0.0);
+        assert_eq!(mu_law_encode(f32::NAN), 0xFF);
+    }
+
+    #[test]
+    fn test_mu_law_monotonic() {
+        let decoded: Vec<f32> = (0..=2000)
+            .map(|i| mu_law_decode(mu_law_encode(i as f32 / 1000.0 - 1.0)))
+            .collect();
+        assert!(decoded.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
+
+// <FILE>src/export/fnc_mu_law.rs</FILE> - <DESC>G.711 µ-law companding</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -0,0 +1,19 @@
+// <FILE>src/export/mod.rs</FILE> - <DESC>Export module root</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Retro WAV export</WCTX>
+// <CLOG>Initial creation - WAV export config and µ-law companding</CLOG>
+
+//! Writing sampled signals to files.
+//!
+//! Enabled by the `wav` feature. [`WavExportConfig`] encodes `f32` samples as
+//! 8/16/24-bit PCM or µ-law WAV; [`mu_law_encode`] and [`mu_law_decode`]
+//! expose the companding curve on its own.
+
+mod cls_wav_export_config;
+mod fnc_mu_law;
+
+pub use cls_wav_export_config::{Companding, WavExportConfig};
+pub use fnc_mu_law::{mu_law_decode, mu_law_encode};
+
+// <FILE>src/export/mod.rs</FILE> - <DESC>Export module root</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
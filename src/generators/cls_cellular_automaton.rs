@@ -0,0 +1,265 @@
+// <FILE>src/generators/cls_cellular_automaton.rs</FILE> - <DESC>Elementary cellular automaton density generator</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>Bounded sampling cost</WCTX>
+// <CLOG>Cap rate and generation index; checkpoint rows so seeks restart nearby</CLOG>
+
+use crate::math::{fast_random, finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalRange, SignalTime};
+use std::sync::Mutex;
+
+/// Largest supported row width.
+pub const MAX_CA_WIDTH: usize = 4096;
+/// Fastest supported rate in generations per second.
+pub const MAX_CA_STEPS_PER_SECOND: f32 = 1000.0;
+/// Length of the generation history; later generations repeat it.
+pub const MAX_CA_GENERATIONS: u64 = 1 << 16;
+/// Generations between stored row snapshots.
+const CHECKPOINT_INTERVAL: u64 = 1024;
+
+/// Most recently computed generation, kept so forward sampling is O(width).
+#[derive(Debug, Clone)]
+struct CaState {
+    generation: u64,
+    row: Vec<bool>,
+    /// Row at every multiple of `CHECKPOINT_INTERVAL` reached so far
+    checkpoints: Vec<Vec<bool>>,
+}
+
+impl CaState {
+    fn new(width: usize, seed: u64) -> Self {
+        let row = initial_row(width, seed);
+        Self {
+            generation: 0,
+            checkpoints: vec![row.clone()],
+            row,
+        }
+    }
+}
+
+/// Elementary (1D, two-state, radius-1) cellular automaton.
+///
+/// A row of `width` cells wraps around at the edges. Generation 0 is seeded
+/// randomly (each cell alive with probability 1/2); each later generation
+/// applies Wolfram `rule` (0-255) to every cell's three-cell neighbourhood.
+/// The output is the fraction of live cells, held for one generation, with
+/// `steps_per_second` generations per second, so it is a stepped signal in
+/// [0, 1]. Negative times hold generation 0.
+///
+/// The output is a pure function of `t`; the latest row is cached so sampling
+/// forward in time costs O(width) per generation. A row is also kept every
+/// 1024 generations, so sampling backwards restarts from the nearest
+/// snapshot, and a jump far ahead costs O(width) per generation not yet
+/// computed. The history is [`MAX_CA_GENERATIONS`] long and then repeats,
+/// which bounds both the work and the snapshot memory.
+///
+/// `width` is clamped to `1..=MAX_CA_WIDTH` and the rate to
+/// [`MAX_CA_STEPS_PER_SECOND`]. A non-finite or non-positive rate falls back
+/// to 1 generation per second.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::CellularAutomaton;
+/// use mixed_signals::traits::Signal;
+///
+/// let rule30 = CellularAutomaton::new(30, 64, 7, 8.0);
+/// let density = rule30.sample(2.0); // generation 16
+/// assert!((0.0..=1.0).contains(&density));
+/// ```
+#[derive(Debug)]
+pub struct CellularAutomaton {
+    rule: u8,
+    width: usize,
+    seed: u64,
+    steps_per_second: f32,
+    state: Mutex<CaState>,
+}
+
+impl CellularAutomaton {
+    pub fn new(rule: u8, width: usize, seed: u64, steps_per_second: f32) -> Self {
+        let width = width.clamp(1, MAX_CA_WIDTH);
+        Self {
+            rule,
+            width,
+            seed,
+            steps_per_second,
+            state: Mutex::new(CaState::new(width, seed)),
+        }
+    }
+
+    pub fn rule(&self) -> u8 {
+        self.rule
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn steps_per_second(&self) -> f32 {
+        self.steps_per_second
+    }
+
+    /// Generation shown at time `t`.
+    pub fn generation_at(&self, t: SignalTime) -> u64 {
+        let rate = finite_or(self.steps_per_second, 1.0);
+        let rate = if rate > 0.0 {
+            rate.min(MAX_CA_STEPS_PER_SECOND) as f64
+        } else {
+            1.0
+        };
+        let generation = (finite_or_f64(t, 0.0) * rate).floor();
+        if generation > 0.0 {
+            generation as u64
+        } else {
+            0
+        }
+    }
+
+    /// Fraction of live cells in `generation` (modulo [`MAX_CA_GENERATIONS`]).
+    pub fn density(&self, generation: u64) -> f32 {
+        let generation = generation % MAX_CA_GENERATIONS;
+        let mut state = self.state.lock().unwrap();
+
+        // Restart from the nearest snapshot when seeking back or skipping past one
+        let index = ((generation / CHECKPOINT_INTERVAL) as usize).min(state.checkpoints.len() - 1);
+        let base = index as u64 * CHECKPOINT_INTERVAL;
+        if generation < state.generation || base > state.generation {
+            state.row = state.checkpoints[index].clone();
+            state.generation = base;
+        }
+        while state.generation < generation {
+            state.row = step(&state.row, self.rule);
+            state.generation += 1;
+            if state.generation == state.checkpoints.len() as u64 * CHECKPOINT_INTERVAL {
+                let row = state.row.clone();
+                state.checkpoints.push(row);
+            }
+        }
+        let alive = state.row.iter().filter(|&&cell| cell).count();
+        alive as f32 / self.width as f32
+    }
+}
+
+fn initial_row(width: usize, seed: u64) -> Vec<bool> {
+    (0..width as u64)
+        .map(|i| fast_random(seed, i) < 0.5)
+        .collect()
+}
+
+/// One generation of `rule` on a ring.
+fn step(row: &[bool], rule: u8) -> Vec<bool> {
+    let n = row.len();
+    (0..n)
+        .map(|i| {
+            let left = row[(i + n - 1) % n] as u8;
+            let center = row[i] as u8;
+            let right = row[(i + 1) % n] as u8;
+            (rule >> ((left << 2) | (center << 1) | right)) & 1 == 1
+        })
+        .collect()
+}
+
+/// Clones the configuration only; the clone recomputes from generation 0.
+impl Clone for CellularAutomaton {
+    fn clone(&self) -> Self {
+        Self::new(self.rule, self.width, self.seed, self.steps_per_second)
+    }
+}
+
+impl Signal for CellularAutomaton {
+    fn output_range(&self) -> SignalRange {
+        SignalRange::UNIT
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.density(self.generation_at(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ca_rule_0_dies_out() {
+        let ca = CellularAutomaton::new(0, 64, 3, 10.0);
+        let start = ca.sample(0.0);
+        assert!(start > 0.2 && start < 0.8, "seeded density {}", start);
+        for i in 1..50 {
+            assert_eq!(ca.sample(i as f64 * 0.1), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_ca_rule_255_fills() {
+        let ca = CellularAutomaton::new(255, 64, 3, 10.0);
+        assert!(ca.sample(0.05) < 1.0);
+        for i in 1..50 {
+            assert_eq!(ca.sample(i as f64 * 0.1), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_ca_deterministic_in_any_order() {
+        let forward = CellularAutomaton::new(30, 48, 11, 4.0);
+        let times: Vec<f64> = (0..80).map(|i| i as f64 * 0.125).collect();
+        let values: Vec<f32> = times.iter().map(|&t| forward.sample(t)).collect();
+
+        let shuffled = CellularAutomaton::new(30, 48, 11, 4.0);
+        for &i in &[70usize, 3, 55, 0, 79, 12, 12, 40] {
+            assert_eq!(shuffled.sample(times[i]), values[i]);
+        }
+        assert_eq!(forward.clone().sample(times[33]), values[33]);
+        // Rule 30 keeps changing; different seeds give different histories
+        assert!(values.windows(2).any(|pair| pair[0] != pair[1]));
+        let other = CellularAutomaton::new(30, 48, 12, 4.0);
+        assert!(times
+            .iter()
+            .zip(&values)
+            .any(|(&t, &v)| other.sample(t) != v));
+    }
+
+    #[test]
+    fn test_ca_step_matches_rule_table() {
+        // Rule 90: new cell = left XOR right
+        let row = [true, false, false, true, false];
+        let next = step(&row, 90);
+        assert_eq!(next, vec![false, true, true, false, false]);
+    }
+
+    #[test]
+    fn test_ca_invalid_inputs() {
+        let ca = CellularAutomaton::new(110, 0, 1, f32::NAN);
+        assert_eq!(ca.width(), 1);
+        assert_eq!(ca.generation_at(2.5), 2);
+        assert_eq!(ca.generation_at(-3.0), 0);
+        assert!(ca.sample(f64::NAN).is_finite());
+        assert_eq!(
+            CellularAutomaton::new(30, 1 << 20, 1, 1.0).width(),
+            MAX_CA_WIDTH
+        );
+        let fast = CellularAutomaton::new(30, 8, 1, 1e9);
+        assert_eq!(fast.generation_at(2.0), 2 * MAX_CA_STEPS_PER_SECOND as u64);
+    }
+
+    #[test]
+    fn test_ca_seeks_through_checkpoints() {
+        let reference = CellularAutomaton::new(110, 32, 9, 1.0);
+        let expected: Vec<f32> = (0..3000).map(|g| reference.density(g)).collect();
+
+        let ca = CellularAutomaton::new(110, 32, 9, 1.0);
+        for &g in &[2999u64, 5, 2048, 1023, 1024, 2500, 0] {
+            assert_eq!(ca.density(g), expected[g as usize], "generation {}", g);
+        }
+        // The history repeats after MAX_CA_GENERATIONS
+        assert_eq!(ca.density(MAX_CA_GENERATIONS + 7), expected[7]);
+        assert!(ca.sample(1e12).is_finite());
+    }
+}
+
+// <FILE>src/generators/cls_cellular_automaton.rs</FILE> - <DESC>Elementary cellular automaton density generator</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,10 +1,11 @@
 // <FILE>mixed-signals/src/generators/cls_constant.rs</FILE> - <DESC>Constant value signal</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Removed clamping, added output_range()</CLOG>
+// <VERS>VERSION: 2.3.1</VERS>
+// <WCTX>Batch sampling</WCTX>
+// <CLOG>Batch override moved to sample_batch_from</CLOG>
 
-use crate::math::finite_or;
+use crate::math::{finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Constant signal that always returns the same value.
@@ -12,7 +13,8 @@ use serde::{Deserialize, Serialize};
 /// Useful as a baseline, for mixing, or for holding a fixed parameter.
 ///
 /// **Note:** Output is NOT clamped. Use `.normalized()` to get [0, 1] range.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Constant {
     pub value: f32,
 }
@@ -46,6 +48,20 @@ impl Signal for Constant {
     fn sample(&self, _t: SignalTime) -> f32 {
         finite_or(self.value, 0.0)
     }
+
+    fn integral(&self, t: SignalTime) -> f64 {
+        finite_or(self.value, 0.0) as f64 * finite_or_f64(t, 0.0)
+    }
+
+    fn sample_batch_from(
+        &self,
+        _t_start: SignalTime,
+        _dt: SignalTime,
+        _first: usize,
+        out: &mut [f32],
+    ) {
+        out.fill(finite_or(self.value, 0.0));
+    }
 }
 
 #[cfg(test)]
@@ -87,7 +103,25 @@ mod tests {
         assert_eq!(range.min, 0.5);
         assert_eq!(range.max, 0.5);
     }
+
+    #[test]
+    fn test_constant_sample_batch_bitwise_identical() {
+        let signal = Constant::new(f32::NAN);
+        let mut batch = vec![0.0; 480];
+        signal.sample_batch(-0.25, 1.0 / 480.0, &mut batch);
+        for (i, value) in batch.iter().enumerate() {
+            let t = -0.25 + i as f64 * (1.0 / 480.0);
+            assert_eq!(value.to_bits(), signal.sample(t).to_bits());
+        }
+    }
+
+    #[test]
+    fn test_constant_integral_exact() {
+        assert_eq!(Constant::new(440.0).integral(0.5), 220.0);
+        assert_eq!(Constant::new(2.0).integral(-1.5), -3.0);
+        assert_eq!(Constant::new(f32::NAN).integral(3.0), 0.0);
+    }
 }
 
 // <FILE>mixed-signals/src/generators/cls_constant.rs</FILE> - <DESC>Constant value signal</DESC>
-// <VERS>END OF VERSION: 1.1.0 - 2025-12-27</VERS>
+// <VERS>END OF VERSION: 2.3.1</VERS>
@@ -1,31 +1,85 @@
 // <FILE>mixed-signals/src/generators/cls_keyframes.rs</FILE> - <DESC>Keyframe-based signal with interpolation</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Audio synthesis primitives - arbitrary curve definition</WCTX>
-// <CLOG>Initial implementation - piecewise linear interpolation between keyframes</CLOG>
-
+// <VERS>VERSION: 1.5.1</VERS>
+// <WCTX>Order-independent phase accumulation</WCTX>
+// <CLOG>Drop keyframes with non-finite times; saturating segment lookup</CLOG>
+
+use crate::easing::{ease, EasingType};
+use crate::math::integrate;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::traits::{Signal, SignalContext, SignalTime};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// How a keyframe's outgoing segment moves toward the next keyframe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Interpolation {
+    /// Hold this keyframe's value until the next keyframe
+    Step,
+    /// Straight line to the next keyframe (default)
+    #[default]
+    Linear,
+    /// Catmull-Rom spline through the neighbouring keyframes
+    CubicSmooth,
+    /// Linear endpoints shaped by an easing curve
+    Eased(EasingType),
+}
+
 /// A keyframe defining a value at a specific time.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Keyframe {
     /// Time position (0.0 to 1.0 normalized, or absolute depending on usage)
     pub time: f32,
     /// Value at this time
     pub value: f32,
+    /// Interpolation toward the next keyframe
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub interpolation: Interpolation,
 }
 
 impl Keyframe {
     pub fn new(time: f32, value: f32) -> Self {
-        Self { time, value }
+        Self {
+            time,
+            value,
+            interpolation: Interpolation::Linear,
+        }
     }
+
+    /// Set how the segment leaving this keyframe is interpolated.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+}
+
+/// How [`Keyframes`] extrapolates outside its first..last keyframe times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Boundary {
+    /// Hold the first/last keyframe value (default)
+    #[default]
+    Hold,
+    /// Repeat the curve periodically with period `last - first`
+    Loop,
+    /// Ping-pong: play the curve forward, then mirrored back
+    Reflect,
+    /// Continue the end segments' slopes, clamped to the keyframe value range
+    Clamp,
 }
 
 /// Signal that interpolates between keyframes.
 ///
-/// Provides piecewise linear interpolation between a series of (time, value)
-/// pairs. This enables defining arbitrary curves that can't be expressed
-/// with simple oscillators.
+/// Interpolates between a series of (time, value) pairs, linearly by
+/// default. This enables defining arbitrary curves that can't be expressed
+/// with simple oscillators. Each segment uses the [`Interpolation`] of the
+/// keyframe it leaves, so holds, smooth curves and eased moves can be mixed
+/// in one curve.
 ///
 /// # Stateless Design
 ///
@@ -57,23 +111,58 @@ impl Keyframe {
 /// assert!((curve.sample(0.5) - 1.0).abs() < 0.001);
 /// assert!((curve.sample(0.75) - 0.5).abs() < 0.001);
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// # Interpolation Modes
+///
+/// ```
+/// use mixed_signals::easing::EasingType;
+/// use mixed_signals::generators::{Interpolation, Keyframe, Keyframes};
+/// use mixed_signals::traits::Signal;
+///
+/// let beat = Keyframes::new(vec![
+///     Keyframe::new(0.0, 0.2).with_interpolation(Interpolation::Step),
+///     Keyframe::new(0.3, 1.0).with_interpolation(Interpolation::Eased(EasingType::QuadOut)),
+///     Keyframe::new(1.0, 0.2),
+/// ]);
+/// assert_eq!(beat.sample(0.29), 0.2); // held
+/// assert_eq!(beat.sample(0.3), 1.0);
+/// ```
+///
+/// # Boundary Modes
+///
+/// Outside the keyframe time domain the curve holds its endpoint values by
+/// default. Use [`Keyframes::with_boundary`] to loop or ping-pong instead:
+///
+/// ```
+/// use mixed_signals::generators::{Boundary, Keyframes};
+/// use mixed_signals::traits::Signal;
+///
+/// let ramp = Keyframes::from_pairs(&[(0.0, 0.0), (1.0, 1.0)]).with_boundary(Boundary::Reflect);
+/// assert!((ramp.sample(1.25) - 0.75).abs() < 0.001);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Keyframes {
     /// Sorted list of keyframes (by time)
     keyframes: Vec<Keyframe>,
+    /// Extrapolation outside the keyframe time domain
+    #[cfg_attr(feature = "serde", serde(default))]
+    boundary: Boundary,
 }
 
 impl Keyframes {
     /// Create a new Keyframes signal from a list of keyframes.
     ///
-    /// Keyframes will be sorted by time automatically.
-    /// At least one keyframe is required.
+    /// Keyframes will be sorted by time automatically; keyframes with a
+    /// non-finite time are dropped. At least one keyframe is required.
     pub fn new(mut keyframes: Vec<Keyframe>) -> Self {
+        keyframes.retain(|k| k.time.is_finite());
+
         // Sort by time
         keyframes.sort_by(|a, b| {
             a.time
                 .partial_cmp(&b.time)
-                .unwrap_or(std::cmp::Ordering::Equal)
+                .unwrap_or(core::cmp::Ordering::Equal)
         });
 
         // Ensure at least one keyframe
@@ -81,7 +170,20 @@ impl Keyframes {
             keyframes.push(Keyframe::new(0.0, 0.0));
         }
 
-        Self { keyframes }
+        Self {
+            keyframes,
+            boundary: Boundary::Hold,
+        }
+    }
+
+    /// Set how the curve extrapolates outside its keyframe times.
+    pub fn with_boundary(mut self, boundary: Boundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
     }
 
     /// Create from a slice of (time, value) tuples for convenience.
@@ -90,6 +192,23 @@ impl Keyframes {
         Self::new(keyframes)
     }
 
+    /// Like [`from_pairs`](Self::from_pairs), with a parallel list of
+    /// per-keyframe interpolation modes. Missing modes default to Linear.
+    pub fn from_pairs_with_modes(pairs: &[(f32, f32)], modes: &[Interpolation]) -> Self {
+        let keyframes = pairs
+            .iter()
+            .enumerate()
+            .map(|(i, &(t, v))| {
+                Keyframe::new(t, v).with_interpolation(modes.get(i).copied().unwrap_or_default())
+            })
+            .collect();
+        Self::new(keyframes)
+    }
+
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+
     /// Get the number of keyframes.
     pub fn len(&self) -> usize {
         self.keyframes.len()
@@ -100,7 +219,7 @@ impl Keyframes {
         self.keyframes.is_empty()
     }
 
-    /// Find the value at time t using binary search and linear interpolation.
+    /// Find the value at time t using binary search and the segment's interpolation.
     fn interpolate(&self, t: f32) -> f32 {
         let kf = &self.keyframes;
 
@@ -119,13 +238,10 @@ impl Keyframes {
             return kf[kf.len() - 1].value;
         }
 
-        // Binary search for the interval containing t
-        let idx = match kf
-            .binary_search_by(|k| k.time.partial_cmp(&t).unwrap_or(std::cmp::Ordering::Equal))
-        {
-            Ok(i) => return kf[i].value,   // Exact match
-            Err(i) => i.saturating_sub(1), // Insert position - 1 gives lower bound
-        };
+        // Keyframes are sorted at construction, so the segment containing t
+        // is found in O(log n): the first keyframe after t ends the segment.
+        // With duplicate times this picks the last one, matching a forward scan.
+        let idx = kf.partition_point(|k| k.time <= t).saturating_sub(1);
 
         // Interpolate between keyframes[idx] and keyframes[idx + 1]
         let k0 = &kf[idx];
@@ -137,19 +253,216 @@ impl Keyframes {
         }
 
         let progress = (t - k0.time) / dt;
-        k0.value + (k1.value - k0.value) * progress
+        match k0.interpolation {
+            Interpolation::Step => k0.value,
+            Interpolation::Linear => k0.value + (k1.value - k0.value) * progress,
+            Interpolation::Eased(easing) => {
+                k0.value + (k1.value - k0.value) * ease(progress as SignalTime, easing)
+            }
+            Interpolation::CubicSmooth => self.catmull_rom(idx, progress),
+        }
+    }
+
+    /// Cubic Hermite segment idx..idx+1 with Catmull-Rom tangents.
+    ///
+    /// Tangents are finite differences over the neighbouring keyframes (one-sided
+    /// at the ends), scaled for uneven spacing, so the curve passes through
+    /// every keyframe.
+    /// Catmull-Rom tangents of segment `idx`, scaled to its duration.
+    fn segment_tangents(&self, idx: usize) -> (f32, f32) {
+        let kf = &self.keyframes;
+        let tangent = |i: usize| -> f32 {
+            let lo = i.saturating_sub(1);
+            let hi = (i + 1).min(kf.len() - 1);
+            let dt = kf[hi].time - kf[lo].time;
+            if dt.abs() < 1e-10 {
+                0.0
+            } else {
+                (kf[hi].value - kf[lo].value) / dt
+            }
+        };
+        let dt = kf[idx + 1].time - kf[idx].time;
+        (tangent(idx) * dt, tangent(idx + 1) * dt)
+    }
+
+    fn catmull_rom(&self, idx: usize, s: f32) -> f32 {
+        let (k0, k1) = (self.keyframes[idx], self.keyframes[idx + 1]);
+        let (m0, m1) = self.segment_tangents(idx);
+
+        let s2 = s * s;
+        let s3 = s2 * s;
+        let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+        let h10 = s3 - 2.0 * s2 + s;
+        let h01 = -2.0 * s3 + 3.0 * s2;
+        let h11 = s3 - s2;
+        h00 * k0.value + h10 * m0 + h01 * k1.value + h11 * m1
+    }
+
+    /// Area under segment `idx` from its first key to `progress` (0..=1).
+    fn segment_area(&self, idx: usize, progress: f64) -> f64 {
+        let (k0, k1) = (self.keyframes[idx], self.keyframes[idx + 1]);
+        let dt = (k1.time - k0.time) as f64;
+        if dt.abs() < 1e-10 {
+            return 0.0;
+        }
+        let (v0, v1) = (k0.value as f64, k1.value as f64);
+        let s = progress;
+        let unit_area = match k0.interpolation {
+            Interpolation::Step => v0 * s,
+            Interpolation::Linear => v0 * s + (v1 - v0) * s * s / 2.0,
+            Interpolation::Eased(easing) => {
+                let delta = k1.value - k0.value;
+                integrate(|p| k0.value + delta * ease(p, easing), 0.0, s)
+            }
+            Interpolation::CubicSmooth => {
+                // Antiderivatives of the Hermite basis functions
+                let (m0, m1) = self.segment_tangents(idx);
+                let (s2, s3, s4) = (s * s, s * s * s, s * s * s * s);
+                let h00 = s4 / 2.0 - s3 + s;
+                let h10 = s4 / 4.0 - 2.0 * s3 / 3.0 + s2 / 2.0;
+                let h01 = -s4 / 2.0 + s3;
+                let h11 = s4 / 4.0 - s3 / 3.0;
+                h00 * v0 + h10 * m0 as f64 + h01 * v1 + h11 * m1 as f64
+            }
+        };
+        unit_area * dt
+    }
+
+    /// Area under the keyframed curve from the first key to `x` (within the keys).
+    fn area_within(&self, x: f64) -> f64 {
+        let kf = &self.keyframes;
+        let mut area = 0.0;
+        for idx in 0..kf.len() - 1 {
+            let (start, end) = (kf[idx].time as f64, kf[idx + 1].time as f64);
+            if x >= end {
+                area += self.segment_area(idx, 1.0);
+            } else {
+                if x > start {
+                    area += self.segment_area(idx, (x - start) / (end - start));
+                }
+                break;
+            }
+        }
+        area
+    }
+}
+
+impl Keyframes {
+    /// Apply the boundary mode to a time outside the keyframe domain.
+    fn sample_bounded(&self, t: SignalTime) -> f32 {
+        let first = self.keyframes[0];
+        let last = self.keyframes[self.keyframes.len() - 1];
+        let start = first.time as f64;
+        let span = last.time as f64 - start;
+        if span <= 0.0 || (start..=last.time as f64).contains(&t) {
+            return self.interpolate(t as f32);
+        }
+
+        match self.boundary {
+            Boundary::Hold => self.interpolate(t as f32),
+            Boundary::Loop => {
+                let local = (t - start).rem_euclid(span);
+                self.interpolate((start + local) as f32)
+            }
+            Boundary::Reflect => {
+                let mut local = (t - start).rem_euclid(2.0 * span);
+                if local > span {
+                    local = 2.0 * span - local;
+                }
+                self.interpolate((start + local) as f32)
+            }
+            Boundary::Clamp => self.extrapolate_clamped(t),
+        }
+    }
+
+    /// Extend the nearest end segment linearly, clamped to the value range.
+    fn extrapolate_clamped(&self, t: SignalTime) -> f32 {
+        let kf = &self.keyframes;
+        let (k0, k1) = if t < kf[0].time as f64 {
+            (kf[0], kf[1])
+        } else {
+            (kf[kf.len() - 2], kf[kf.len() - 1])
+        };
+        let dt = (k1.time - k0.time) as f64;
+        let value = if dt.abs() < 1e-10 {
+            if t < k0.time as f64 {
+                k0.value
+            } else {
+                k1.value
+            }
+        } else {
+            let slope = (k1.value - k0.value) as f64 / dt;
+            (k0.value as f64 + slope * (t - k0.time as f64)) as f32
+        };
+
+        let (min, max) = kf
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), k| {
+                (lo.min(k.value), hi.max(k.value))
+            });
+        value.clamp(min, max)
+    }
+
+    /// Area under [`sample_bounded`](Self::sample_bounded) from the first key
+    /// to `x`, following the boundary mode outside the keys.
+    fn area_from_start(&self, x: f64) -> f64 {
+        let first = self.keyframes[0];
+        let last = self.keyframes[self.keyframes.len() - 1];
+        let start = first.time as f64;
+        let end = last.time as f64;
+        let span = end - start;
+        if span <= 0.0 {
+            let value = if x <= start { first.value } else { last.value };
+            return (x - start) * value as f64;
+        }
+        if (start..=end).contains(&x) {
+            return self.area_within(x);
+        }
+
+        let full = self.area_within(end);
+        match self.boundary {
+            Boundary::Hold if x < start => (x - start) * first.value as f64,
+            Boundary::Hold => full + (x - end) * last.value as f64,
+            Boundary::Loop => {
+                let cycles = ((x - start) / span).floor();
+                cycles * full + self.area_within(start + (x - start).rem_euclid(span))
+            }
+            Boundary::Reflect => {
+                let period = 2.0 * span;
+                let cycles = ((x - start) / period).floor();
+                let local = (x - start).rem_euclid(period);
+                let partial = if local <= span {
+                    self.area_within(start + local)
+                } else {
+                    2.0 * full - self.area_within(start + period - local)
+                };
+                cycles * 2.0 * full + partial
+            }
+            Boundary::Clamp if x < start => {
+                integrate(|tau| self.extrapolate_clamped(tau), start, x)
+            }
+            Boundary::Clamp => full + integrate(|tau| self.extrapolate_clamped(tau), end, x),
+        }
     }
 }
 
 impl Signal for Keyframes {
     fn sample(&self, t: SignalTime) -> f32 {
-        let t = if t.is_finite() { t as f32 } else { 0.0 };
-        self.interpolate(t)
+        let t = if t.is_finite() { t } else { 0.0 };
+        self.sample_bounded(t)
     }
 
     fn sample_with_context(&self, t: SignalTime, _ctx: &SignalContext) -> f32 {
         self.sample(t)
     }
+
+    /// Exact per segment (Step, Linear, CubicSmooth) and across `Hold`,
+    /// `Loop` and `Reflect` boundaries; eased segments and `Clamp`
+    /// extrapolation are integrated numerically.
+    fn integral(&self, t: SignalTime) -> f64 {
+        let t = if t.is_finite() { t } else { 0.0 };
+        self.area_from_start(t) - self.area_from_start(0.0)
+    }
 }
 
 #[cfg(test)]
@@ -235,6 +548,18 @@ mod tests {
         assert!((kf.sample(f64::NAN) - 0.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_keyframes_nan_keyframe_time_dropped() {
+        let kf = Keyframes::from_pairs(&[(0.0, 0.0), (f32::NAN, 5.0), (1.0, 1.0)]);
+        assert_eq!(kf.len(), 2);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert!((kf.sample(t) - t as f32).abs() < 0.001);
+        }
+        let only_nan = Keyframes::from_pairs(&[(f32::NAN, 3.0)]);
+        assert_eq!(only_nan.sample(0.5), 0.0);
+    }
+
     #[test]
     fn test_keyframes_frequency_curve() {
         // Simulate V17's frequency curve (normalized to 0-1)
@@ -252,7 +577,188 @@ mod tests {
         // Descending by 0.5
         assert!(kf.sample(0.5) < kf.sample(0.1));
     }
+
+    #[test]
+    fn test_keyframes_boundary_hold_default() {
+        let kf = Keyframes::from_pairs(&[(0.0, 0.2), (0.5, 1.0), (1.0, 0.6)]);
+        assert_eq!(kf.boundary(), Boundary::Hold);
+        assert!((kf.sample(-3.0) - 0.2).abs() < 0.001);
+        assert!((kf.sample(1.5) - 0.6).abs() < 0.001);
+        assert!((kf.sample(42.0) - 0.6).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_keyframes_boundary_loop() {
+        let kf = Keyframes::from_pairs(&[(1.0, 0.0), (1.5, 1.0), (3.0, 0.4)])
+            .with_boundary(Boundary::Loop);
+        // Period = 2.0 (from t=1 to t=3)
+        for i in 0..20 {
+            let t = 1.05 + i as f64 * 0.1;
+            for k in [-2.0, -1.0, 1.0, 3.0] {
+                let shifted = t + 2.0 * k;
+                assert!(
+                    (kf.sample(shifted) - kf.sample(t)).abs() < 0.001,
+                    "t={t} shifted={shifted}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_keyframes_boundary_reflect() {
+        let kf = Keyframes::from_pairs(&[(0.0, 0.0), (0.25, 1.0), (1.0, 0.5)])
+            .with_boundary(Boundary::Reflect);
+        // Mirrored about the end: f(1 + d) = f(1 - d)
+        for d in [0.1, 0.3, 0.6, 0.9] {
+            assert!((kf.sample(1.0 + d) - kf.sample(1.0 - d)).abs() < 0.001);
+            // Mirrored about the start: f(-d) = f(d)
+            assert!((kf.sample(-d) - kf.sample(d)).abs() < 0.001);
+        }
+        // Period of 2 * span
+        assert!((kf.sample(2.1) - kf.sample(0.1)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_keyframes_boundary_clamp() {
+        let kf = Keyframes::from_pairs(&[(0.0, 0.5), (1.0, 0.8), (2.0, 0.6)])
+            .with_boundary(Boundary::Clamp);
+        // Continues the last segment's slope (-0.2/s)...
+        assert!((kf.sample(2.5) - 0.5).abs() < 0.001);
+        // ...but never leaves the keyframe value range [0.5, 0.8]
+        assert!((kf.sample(10.0) - 0.5).abs() < 0.001);
+        // Before start: slope +0.3/s extended backwards, clamped at 0.5
+        assert!((kf.sample(-1.0) - 0.5).abs() < 0.001);
+    }
+
+    /// Reference lookup: forward scan for the segment containing t.
+    fn linear_scan(kf: &[Keyframe], t: f32) -> f32 {
+        if t <= kf[0].time {
+            return kf[0].value;
+        }
+        if t >= kf[kf.len() - 1].time {
+            return kf[kf.len() - 1].value;
+        }
+        let mut value = kf[kf.len() - 1].value;
+        for pair in kf.windows(2) {
+            if t >= pair[0].time && t < pair[1].time {
+                let progress = (t - pair[0].time) / (pair[1].time - pair[0].time);
+                value = pair[0].value + (pair[1].value - pair[0].value) * progress;
+                break;
+            }
+        }
+        value
+    }
+
+    #[test]
+    fn test_keyframes_lookup_matches_linear_scan() {
+        // 100 unevenly spaced points with pseudo-random values
+        let mut time = 0.0f32;
+        let points: Vec<Keyframe> = (0..100)
+            .map(|i| {
+                time += 0.005 + (i % 7) as f32 * 0.003;
+                Keyframe::new(time, ((i * 37) % 101) as f32 / 100.0)
+            })
+            .collect();
+        let kf = Keyframes::new(points.clone());
+        assert_eq!(kf.len(), 100);
+
+        let end = points[99].time as f64;
+        for i in 0..=20_000 {
+            let t = -0.1 + (end + 0.2) * i as f64 / 20_000.0;
+            assert_eq!(kf.sample(t), linear_scan(&points, t as f32), "t={t}");
+        }
+        // Exactly on every keyframe
+        for p in &points {
+            assert_eq!(kf.sample(p.time as f64), p.value);
+        }
+    }
+
+    #[test]
+    fn test_keyframes_step_holds_until_next_key() {
+        let kf = Keyframes::from_pairs_with_modes(
+            &[(0.0, 0.2), (0.5, 0.9), (1.0, 0.4)],
+            &[Interpolation::Step, Interpolation::Step],
+        );
+        for i in 0..50 {
+            assert_eq!(kf.sample(i as f64 * 0.01), 0.2);
+        }
+        assert_eq!(kf.sample(0.5), 0.9);
+        assert_eq!(kf.sample(0.999), 0.9);
+        assert_eq!(kf.sample(1.0), 0.4);
+    }
+
+    #[test]
+    fn test_keyframes_cubic_passes_through_keys() {
+        let pairs = [(0.0, 0.0), (0.2, 1.0), (0.5, 0.3), (0.6, 0.8), (1.0, 0.1)];
+        let kf = Keyframes::from_pairs_with_modes(&pairs, &[Interpolation::CubicSmooth; 5]);
+        for &(t, v) in &pairs {
+            assert!((kf.sample(t as f64) - v).abs() < 1e-6, "t={t}");
+        }
+        // Smooth: no kink at an interior key (left/right slopes agree)
+        let h = 1e-4;
+        let left = (kf.sample(0.2) - kf.sample(0.2 - h)) / h as f32;
+        let right = (kf.sample(0.2 + h) - kf.sample(0.2)) / h as f32;
+        assert!((left - right).abs() < 0.05, "{left} vs {right}");
+        // Differs from linear between keys
+        assert!((kf.sample(0.35) - Keyframes::from_pairs(&pairs).sample(0.35)).abs() > 1e-3);
+    }
+
+    #[test]
+    fn test_keyframes_eased_segment_and_mixed_modes() {
+        let kf = Keyframes::new(vec![
+            Keyframe::new(0.0, 0.0).with_interpolation(Interpolation::Eased(EasingType::QuadIn)),
+            Keyframe::new(1.0, 1.0).with_interpolation(Interpolation::Step),
+            Keyframe::new(2.0, 0.0),
+        ]);
+        assert!((kf.sample(0.5) - 0.25).abs() < 1e-6);
+        assert_eq!(kf.sample(1.5), 1.0);
+        assert_eq!(kf.keyframes()[2].interpolation, Interpolation::Linear);
+    }
+
+    #[test]
+    fn test_keyframes_modes_still_clamp_outside_keys() {
+        for mode in [
+            Interpolation::Step,
+            Interpolation::CubicSmooth,
+            Interpolation::Eased(EasingType::BackOut),
+        ] {
+            let kf = Keyframes::from_pairs_with_modes(&[(0.2, 0.5), (0.8, 0.8)], &[mode; 2]);
+            assert_eq!(kf.sample(0.0), 0.5, "{mode:?}");
+            assert_eq!(kf.sample(-5.0), 0.5, "{mode:?}");
+            assert_eq!(kf.sample(0.9), 0.8, "{mode:?}");
+            assert_eq!(kf.sample(7.0), 0.8, "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn test_keyframes_integral_matches_quadrature() {
+        let pairs = [(0.5, 0.2), (1.0, 0.9), (1.5, -0.4), (2.5, 0.6)];
+        for mode in [
+            Interpolation::Step,
+            Interpolation::Linear,
+            Interpolation::CubicSmooth,
+            Interpolation::Eased(EasingType::QuadInOut),
+        ] {
+            for boundary in [
+                Boundary::Hold,
+                Boundary::Loop,
+                Boundary::Reflect,
+                Boundary::Clamp,
+            ] {
+                let kf =
+                    Keyframes::from_pairs_with_modes(&pairs, &[mode; 4]).with_boundary(boundary);
+                for &t in &[0.3, 1.2, 2.5, 4.1, 7.7, -1.3] {
+                    let numeric = integrate(|tau| kf.sample(tau), 0.0, t);
+                    let exact = kf.integral(t);
+                    assert!(
+                        (exact - numeric).abs() < 2e-3,
+                        "{mode:?} {boundary:?} at {t}: {exact} vs {numeric}"
+                    );
+                }
+            }
+        }
+    }
 }
 
 // <FILE>mixed-signals/src/generators/cls_keyframes.rs</FILE> - <DESC>Keyframe-based signal with interpolation</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.5.1</VERS>
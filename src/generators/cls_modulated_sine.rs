@@ -0,0 +1,162 @@
+// <FILE>src/generators/cls_modulated_sine.rs</FILE> - <DESC>Sine oscillator with signal-driven frequency and amplitude</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Core:: instead of std:: paths</CLOG>
+
+use crate::math::{finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use crate::types::{SignalBuildError, SignalOrFloat};
+use core::f64::consts::TAU;
+
+/// Sine oscillator whose frequency and amplitude are [`SignalOrFloat`]s.
+///
+/// Created with [`Sine::from_specs`](super::Sine::from_specs). Each parameter
+/// is evaluated at the sample time, so a signal spec modulates it per sample
+/// while a static float behaves exactly like [`Sine`](super::Sine).
+///
+/// Like [`PhaseAccumulator`](super::PhaseAccumulator), the phase uses the
+/// current frequency (`sin(2π * (f(t) * t + phase))`) rather than integrating
+/// it; this is accurate for slow frequency sweeps but chirps for fast ones.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::traits::Signal;
+/// use mixed_signals::types::{SignalOrFloat, SignalSpec};
+///
+/// let swell = SignalSpec::Ramp { start: 0.0, end: 1.0, duration: 2.0 };
+/// let tone = Sine::from_specs(4.0.into(), SignalOrFloat::from(swell)).unwrap();
+/// assert!(tone.sample(0.0625).abs() < 0.05);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModulatedSine {
+    /// Frequency in Hz
+    pub frequency: SignalOrFloat,
+    /// Output amplitude
+    pub amplitude: SignalOrFloat,
+    /// DC offset (shifts the output)
+    pub offset: f32,
+    /// Phase shift (normalized 0..1)
+    pub phase: f32,
+}
+
+impl ModulatedSine {
+    /// Create from parameter specs, building any signal specs up front.
+    pub fn new(
+        frequency: SignalOrFloat,
+        amplitude: SignalOrFloat,
+    ) -> Result<Self, SignalBuildError> {
+        // Evaluating once builds and caches signal specs, surfacing errors here
+        frequency.evaluate_simple(0.0)?;
+        amplitude.evaluate_simple(0.0)?;
+        Ok(Self {
+            frequency,
+            amplitude,
+            offset: 0.0,
+            phase: 0.0,
+        })
+    }
+
+    /// Set the DC offset.
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the phase shift (normalized 0..1).
+    pub fn with_phase(mut self, phase: f32) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    fn sample_at(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let frequency = finite_or(self.frequency.evaluate(t, ctx).unwrap_or(1.0), 1.0) as f64;
+        let amplitude = finite_or(self.amplitude.evaluate(t, ctx).unwrap_or(1.0), 1.0) as f64;
+        let offset = finite_or(self.offset, 0.0) as f64;
+        let phase = finite_or(self.phase, 0.0) as f64;
+
+        let angle = TAU * (frequency * t + phase);
+        (offset + amplitude * angle.sin()) as f32
+    }
+}
+
+impl Signal for ModulatedSine {
+    fn output_range(&self) -> SignalRange {
+        let range = self.amplitude.output_range();
+        let amplitude = finite_or(range.min.abs().max(range.max.abs()), 1.0);
+        let offset = finite_or(self.offset, 0.0);
+        SignalRange::new(offset - amplitude, offset + amplitude)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.sample_at(t, &SignalContext::default())
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.sample_at(t, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Sine;
+    use crate::types::SignalSpec;
+
+    #[test]
+    fn test_modulated_sine_static_matches_plain_sine() {
+        let plain = Sine::new(3.0, 0.7, 0.0, 0.0);
+        let spec = Sine::from_specs(3.0.into(), 0.7.into()).unwrap();
+        for i in 0..300 {
+            let t = i as f64 * 0.0093;
+            assert_eq!(spec.sample(t), plain.sample(t));
+        }
+        assert_eq!(spec.output_range(), plain.output_range());
+    }
+
+    #[test]
+    fn test_modulated_sine_amplitude_follows_signal() {
+        let ramp = SignalSpec::Ramp {
+            start: 0.0,
+            end: 1.0,
+            duration: 1.0,
+        };
+        let tone = Sine::from_specs(4.0.into(), SignalOrFloat::from(ramp)).unwrap();
+        // Peaks of a 4 Hz sine sit at t = 1/16 + k/4
+        for k in 0..4 {
+            let t = 0.0625 + k as f64 * 0.25;
+            assert!((tone.sample(t) - t as f32).abs() < 1e-5, "k {}", k);
+        }
+        assert_eq!(tone.output_range(), SignalRange::new(-1.0, 1.0));
+    }
+
+    #[test]
+    fn test_modulated_sine_frequency_follows_signal() {
+        let step = SignalSpec::Step {
+            before: 1.0,
+            after: 5.0,
+            threshold: 2.0,
+        };
+        let tone = Sine::from_specs(SignalOrFloat::from(step), 1.0.into()).unwrap();
+        for i in 0..50 {
+            let early = 0.5 + i as f64 * 0.02;
+            let late = 3.0 + i as f64 * 0.02;
+            assert!((tone.sample(early) - Sine::with_frequency(1.0).sample(early)).abs() < 1e-6);
+            assert!((tone.sample(late) - Sine::with_frequency(5.0).sample(late)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_modulated_sine_reports_build_errors() {
+        let bad = SignalSpec::Table {
+            values: vec![],
+            frequency: 1.0,
+        };
+        assert!(Sine::from_specs(SignalOrFloat::from(bad), 1.0.into()).is_err());
+    }
+}
+
+// <FILE>src/generators/cls_modulated_sine.rs</FILE> - <DESC>Sine oscillator with signal-driven frequency and amplitude</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
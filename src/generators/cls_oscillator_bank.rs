@@ -0,0 +1,304 @@
+// <FILE>src/generators/cls_oscillator_bank.rs</FILE> - <DESC>Detuned stack of oscillator voices</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std, alloc imports, core:: instead of std:: paths</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{fast_random, finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalRange, SignalTime};
+use alloc::vec::Vec;
+use core::f64::consts::TAU;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Upper bound on bank voices evaluated per sample.
+pub const MAX_BANK_VOICES: u32 = 64;
+
+/// Waveform of every voice in an [`OscillatorBank`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum BankWaveform {
+    #[default]
+    Sine,
+    /// Same phase as [`Triangle`](super::Triangle): -1 at phase 0, +1 at 0.5
+    Triangle,
+    /// Same phase as [`Sawtooth`](super::Sawtooth): rising from -1 to +1
+    Saw,
+}
+
+impl BankWaveform {
+    /// Bipolar value at `phase` cycles.
+    fn at(self, phase: f64) -> f64 {
+        let frac = phase.rem_euclid(1.0);
+        match self {
+            BankWaveform::Sine => (TAU * phase).sin(),
+            BankWaveform::Triangle => 1.0 - 4.0 * (frac - 0.5).abs(),
+            BankWaveform::Saw => 2.0 * frac - 1.0,
+        }
+    }
+}
+
+/// Total detune width of an [`OscillatorBank`].
+///
+/// Voices are spaced evenly from `-spread` to `+spread` around the base
+/// frequency, so two voices with `Hz(1.0)` sit at `f - 1` and `f + 1` and
+/// beat at 2 Hz.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Detune {
+    /// Outermost voice offset in Hz
+    Hz(f32),
+    /// Outermost voice offset in cents (1/100 semitone)
+    Cents(f32),
+}
+
+impl Default for Detune {
+    fn default() -> Self {
+        Detune::Hz(0.0)
+    }
+}
+
+/// Stack of detuned oscillator voices summed into one signal.
+///
+/// Voice `i` of `n` runs at the base frequency offset by
+/// `spread * (2i / (n - 1) - 1)`. Offsets are in Hz or cents, see [`Detune`].
+/// Each voice's amplitude is `falloff ^ d`, where `d` is its distance in
+/// voice steps from the centre of the stack. So `falloff < 1` keeps the
+/// outer voices quieter. The sum is divided by the total weight, so output
+/// stays within [-1, 1].
+///
+/// By default all voices start in phase. [`with_seed`](Self::with_seed)
+/// gives each voice a deterministic random starting phase so they don't all
+/// peak together at t = 0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::{Detune, OscillatorBank};
+/// use mixed_signals::traits::Signal;
+///
+/// // Three sines 4 Hz apart, beating around 220 Hz
+/// let stack = OscillatorBank::new(220.0, 3, Detune::Hz(4.0)).with_seed(7);
+/// assert!(stack.sample(0.123).abs() <= 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OscillatorBank {
+    frequency: f32,
+    voices: u32,
+    detune: Detune,
+    falloff: f32,
+    waveform: BankWaveform,
+    seed: Option<u64>,
+}
+
+impl OscillatorBank {
+    pub fn new(frequency: f32, voices: u32, detune: Detune) -> Self {
+        Self {
+            frequency,
+            voices: voices.clamp(1, MAX_BANK_VOICES),
+            detune,
+            falloff: 1.0,
+            waveform: BankWaveform::Sine,
+            seed: None,
+        }
+    }
+
+    /// Set the per-step amplitude falloff away from the centre voice (0..=1).
+    pub fn with_falloff(mut self, falloff: f32) -> Self {
+        self.falloff = falloff;
+        self
+    }
+
+    pub fn with_waveform(mut self, waveform: BankWaveform) -> Self {
+        self.waveform = waveform;
+        self
+    }
+
+    /// Offset each voice's starting phase by a value derived from `seed`.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn frequency(&self) -> f32 {
+        self.frequency
+    }
+
+    pub fn voices(&self) -> u32 {
+        self.voices
+    }
+
+    pub fn detune(&self) -> Detune {
+        self.detune
+    }
+
+    pub fn falloff(&self) -> f32 {
+        self.falloff
+    }
+
+    pub fn waveform(&self) -> BankWaveform {
+        self.waveform
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Frequency (Hz) and amplitude weight of each voice.
+    pub fn voice_params(&self) -> Vec<(f64, f64)> {
+        let base = finite_or(self.frequency, 1.0) as f64;
+        let falloff = finite_or(self.falloff, 1.0).clamp(0.0, 1.0) as f64;
+        let n = self.voices.clamp(1, MAX_BANK_VOICES);
+        (0..n)
+            .map(|i| {
+                // Position in the stack, -1 (lowest) ..= +1 (highest)
+                let pos = if n == 1 {
+                    0.0
+                } else {
+                    2.0 * i as f64 / (n - 1) as f64 - 1.0
+                };
+                let frequency = match self.detune {
+                    Detune::Hz(hz) => base + finite_or(hz, 0.0) as f64 * pos,
+                    Detune::Cents(cents) => {
+                        base * 2f64.powf(finite_or(cents, 0.0) as f64 * pos / 1200.0)
+                    }
+                };
+                let steps = (2.0 * i as f64 - (n - 1) as f64).abs() / 2.0;
+                (frequency, falloff.powf(steps))
+            })
+            .collect()
+    }
+
+    fn start_phase(&self, voice: u32) -> f64 {
+        self.seed
+            .map_or(0.0, |seed| fast_random(seed, voice as u64) as f64)
+    }
+}
+
+impl Default for OscillatorBank {
+    fn default() -> Self {
+        Self::new(1.0, 3, Detune::Hz(0.1))
+    }
+}
+
+impl Signal for OscillatorBank {
+    fn output_range(&self) -> SignalRange {
+        SignalRange::BIPOLAR
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let (sum, total) = self.voice_params().into_iter().enumerate().fold(
+            (0.0, 0.0),
+            |(sum, total), (i, (frequency, weight))| {
+                let phase = frequency * t + self.start_phase(i as u32);
+                (sum + weight * self.waveform.at(phase), total + weight)
+            },
+        );
+        if total <= 0.0 {
+            return 0.0;
+        }
+        (sum / total).clamp(-1.0, 1.0) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Sawtooth, Sine, Triangle};
+
+    /// Peak |output| in a short window around `t`.
+    fn local_peak(bank: &OscillatorBank, t: f64) -> f32 {
+        (0..=100)
+            .map(|i| bank.sample(t - 0.01 + i as f64 * 0.0002).abs())
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn test_bank_two_voices_beat_at_twice_detune() {
+        // 50 +/- 1 Hz: envelope |cos(2 pi t)| -> beats every 0.5 s
+        let bank = OscillatorBank::new(50.0, 2, Detune::Hz(1.0));
+        for k in 0..4 {
+            let loud = k as f64 * 0.5;
+            let null = loud + 0.25;
+            assert!(local_peak(&bank, loud + 0.02) > 0.95, "peak near {loud}");
+            assert!(local_peak(&bank, null) < 0.1, "null near {null}");
+        }
+    }
+
+    #[test]
+    fn test_bank_single_voice_matches_waveforms() {
+        let cases: [(BankWaveform, Box<dyn Signal>); 3] = [
+            (BankWaveform::Sine, Box::new(Sine::with_frequency(3.0))),
+            (
+                BankWaveform::Triangle,
+                Box::new(Triangle::with_frequency(3.0)),
+            ),
+            (BankWaveform::Saw, Box::new(Sawtooth::with_frequency(3.0))),
+        ];
+        for (waveform, reference) in cases {
+            let bank = OscillatorBank::new(3.0, 1, Detune::Hz(5.0)).with_waveform(waveform);
+            for i in 0..97 {
+                let t = i as f64 * 0.0123;
+                assert!(
+                    (bank.sample(t) - reference.sample(t)).abs() < 1e-4,
+                    "{waveform:?} at {t}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bank_stays_normalized() {
+        for waveform in [
+            BankWaveform::Sine,
+            BankWaveform::Triangle,
+            BankWaveform::Saw,
+        ] {
+            let bank = OscillatorBank::new(110.0, 7, Detune::Cents(25.0))
+                .with_falloff(0.6)
+                .with_waveform(waveform);
+            for i in 0..5000 {
+                let v = bank.sample(i as f64 * 0.00037);
+                assert!((-1.0..=1.0).contains(&v), "{waveform:?}: {v}");
+            }
+        }
+        // All voices aligned at t = 0 with a saw: exactly -1
+        let saw = OscillatorBank::new(10.0, 5, Detune::Hz(2.0)).with_waveform(BankWaveform::Saw);
+        assert_eq!(saw.sample(0.0), -1.0);
+    }
+
+    #[test]
+    fn test_bank_detune_and_falloff_layout() {
+        let bank = OscillatorBank::new(440.0, 3, Detune::Cents(1200.0)).with_falloff(0.5);
+        let params = bank.voice_params();
+        assert!((params[0].0 - 220.0).abs() < 1e-9);
+        assert!((params[1].0 - 440.0).abs() < 1e-9);
+        assert!((params[2].0 - 880.0).abs() < 1e-9);
+        assert_eq!(
+            params.iter().map(|p| p.1).collect::<Vec<_>>(),
+            vec![0.5, 1.0, 0.5]
+        );
+    }
+
+    #[test]
+    fn test_bank_seeded_phases_are_deterministic() {
+        let aligned = OscillatorBank::new(5.0, 4, Detune::Hz(0.5));
+        let a = aligned.with_seed(9);
+        let b = aligned.with_seed(9);
+        assert_eq!(aligned.sample(0.0), 0.0);
+        assert_ne!(a.sample(0.0), 0.0);
+        for i in 0..50 {
+            let t = i as f64 * 0.031;
+            assert_eq!(a.sample(t), b.sample(t));
+        }
+        assert_ne!(a.sample(0.1), aligned.with_seed(10).sample(0.1));
+    }
+}
+
+// <FILE>src/generators/cls_oscillator_bank.rs</FILE> - <DESC>Detuned stack of oscillator voices</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,8 +1,11 @@
 // <FILE>mixed-signals/src/generators/cls_phase_accumulator.rs</FILE> - <DESC>Phase accumulator for FM synthesis</DESC>
-// <VERS>VERSION: 1.2.0</VERS>
-// <WCTX>Audio synthesis primitives - true frequency modulation</WCTX>
-// <CLOG>Fixed trapezoidal integration: prev_freq was shadowed and never updated</CLOG>
+// <VERS>VERSION: 1.5.0</VERS>
+// <WCTX>Order-independent phase accumulation</WCTX>
+// <CLOG>Phase from Signal::integral (exact for closed-form frequencies), wrapped in f64</CLOG>
 
+use crate::math::integrate;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::traits::{Signal, SignalContext, SignalTime};
 
 /// Phase accumulator for true frequency modulation synthesis.
@@ -16,14 +19,15 @@ use crate::traits::{Signal, SignalContext, SignalTime};
 /// Returns accumulated phase normalized to [0.0, 1.0), where 1.0 represents
 /// a full cycle. The phase wraps at 1.0.
 ///
-/// # Stateless Implementation
+/// # Semantics
 ///
-/// The Signal trait requires pure functions. For constant frequency, exact
-/// integration is trivial: `phase = initial_phase + freq * t`. For varying
-/// frequency, we approximate using the current frequency value.
-///
-/// For slowly varying frequency signals (LFOs, envelopes), this approximation
-/// is accurate. For rapidly varying signals, precision decreases.
+/// The phase at `t` is `initial_phase + ∫₀ᵗ frequency(τ) dτ`, wrapped, taken
+/// from [`Signal::integral`]. It depends only on `t`: sampling out of order
+/// (as a view rendering a window does) or from several consumers gives the
+/// same value for the same `t`. `Constant`, `Sine`, `Triangle`, `Keyframes`,
+/// `Remap` and `Add` integrate exactly; other frequency signals fall back to
+/// Simpson's rule at 1000 panels per second, so cost grows with `t`.
+/// Negative or non-finite `t` returns `initial_phase`.
 ///
 /// # Examples
 ///
@@ -64,87 +68,48 @@ impl<F: Signal> PhaseAccumulator<F> {
     pub fn with_frequency(frequency: F) -> Self {
         Self::new(frequency, 0.0)
     }
-}
-
-impl<F: Signal> Signal for PhaseAccumulator<F> {
-    fn sample(&self, t: SignalTime) -> f32 {
-        if !t.is_finite() || t < 0.0 {
-            return self.initial_phase;
-        }
-
-        // Numerical integration of frequency over time using trapezoidal rule.
-        // For FM synthesis, we need: phase = ∫freq(τ)dτ from 0 to t
-        //
-        // Use ~1000 steps per second for audio-quality integration.
-        // This balances accuracy with performance.
-        let steps_per_second = 1000.0;
-        let num_steps = ((t * steps_per_second).ceil() as usize).max(1);
-        let dt = t / num_steps as f64;
-
-        let mut accumulated_phase = self.initial_phase as f64;
-        let freq_0 = self.frequency.sample(0.0);
-        let mut prev_freq = if freq_0.is_finite() { freq_0 } else { 0.0 };
-
-        for i in 1..=num_steps {
-            let t_i = i as f64 * dt;
-            let freq_i = self.frequency.sample(t_i);
-            let curr_freq = if freq_i.is_finite() { freq_i } else { 0.0 };
-
-            // Trapezoidal rule: (f(a) + f(b)) / 2 * dt
-            accumulated_phase += (prev_freq + curr_freq) as f64 / 2.0 * dt;
-            prev_freq = curr_freq;
-        }
 
-        // Wrap to [0, 1) - handle floating point edge case at exactly 0
-        let phase = accumulated_phase as f32;
-        let wrapped = phase.rem_euclid(1.0);
-        // If very close to 1.0 due to floating point, treat as 0.0
-        if (wrapped - 1.0).abs() < 1e-6 {
+    /// Wrap `initial_phase + cycles` to [0.0, 1.0) in f64 before narrowing,
+    /// so long renders keep full phase precision.
+    fn wrap(&self, cycles: f64) -> f32 {
+        let wrapped = (self.initial_phase as f64 + cycles).rem_euclid(1.0) as f32;
+        // Values just below 1.0 can round up when narrowed to f32
+        if wrapped >= 1.0 {
             0.0
         } else {
             wrapped
         }
     }
+}
 
-    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+impl<F: Signal + Clone> Signal for PhaseAccumulator<F> {
+    fn sample(&self, t: SignalTime) -> f32 {
         if !t.is_finite() || t < 0.0 {
             return self.initial_phase;
         }
+        let cycles = self.frequency.integral(t);
+        self.wrap(if cycles.is_finite() { cycles } else { 0.0 })
+    }
 
-        let steps_per_second = 1000.0;
-        let num_steps = ((t * steps_per_second).ceil() as usize).max(1);
-        let dt = t / num_steps as f64;
-
-        let mut accumulated_phase = self.initial_phase as f64;
-        let freq_0 = self.frequency.sample_with_context(0.0, ctx);
-        let mut prev_freq = if freq_0.is_finite() { freq_0 } else { 0.0 };
-
-        for i in 1..=num_steps {
-            let t_i = i as f64 * dt;
-            let freq_i = self.frequency.sample_with_context(t_i, ctx);
-            let curr_freq = if freq_i.is_finite() { freq_i } else { 0.0 };
-
-            accumulated_phase += (prev_freq + curr_freq) as f64 / 2.0 * dt;
-            prev_freq = curr_freq;
-        }
-
-        // Wrap to [0, 1) - handle floating point edge case at exactly 0
-        let phase = accumulated_phase as f32;
-        let wrapped = phase.rem_euclid(1.0);
-        if (wrapped - 1.0).abs() < 1e-6 {
-            0.0
-        } else {
-            wrapped
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        if !t.is_finite() || t < 0.0 {
+            return self.initial_phase;
         }
+        // Context-dependent frequencies have no closed form: integrate the
+        // contextual samples with the same quadrature as the default integral
+        let cycles = integrate(|tau| self.frequency.sample_with_context(tau, ctx), 0.0, t);
+        self.wrap(cycles)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::generators::Constant;
+    use crate::generators::{Constant, Keyframes, Sine};
+    use crate::traits::from_fn;
 
     /// Test helper: an unclamped frequency signal that returns raw Hz values
+    #[derive(Clone)]
     struct RawFrequency(f32);
     impl Signal for RawFrequency {
         fn sample(&self, _t: SignalTime) -> f32 {
@@ -252,6 +217,7 @@ mod tests {
 
     #[test]
     fn test_phase_accumulator_nan_frequency() {
+        #[derive(Clone)]
         struct NanSignal;
         impl Signal for NanSignal {
             fn sample(&self, _t: SignalTime) -> f32 {
@@ -284,6 +250,44 @@ mod tests {
         assert!((phase.sample_with_context(0.25, &ctx) - 0.25).abs() < 0.001);
     }
 
+    #[test]
+    fn test_phase_accumulator_constant_440_exact() {
+        let phase = PhaseAccumulator::with_frequency(Constant::new(440.0));
+        for t in [0.001, 0.0123, 0.5, 1.2345, 59.99f64] {
+            let expected = (440.0 * t).rem_euclid(1.0) as f32;
+            assert_eq!(phase.sample(t), expected, "t = {t}");
+        }
+    }
+
+    #[test]
+    fn test_phase_accumulator_repeated_queries_identical() {
+        let lfo = crate::processing::Remap::new(Sine::with_frequency(3.0), -1.0, 1.0, 200.0, 600.0);
+        for phase in [
+            PhaseAccumulator::with_frequency(Box::new(lfo) as Box<dyn Signal>),
+            PhaseAccumulator::with_frequency(Box::new(RawFrequency(330.0)) as Box<dyn Signal>),
+        ] {
+            let first = phase.sample(0.5);
+            let earlier = phase.sample(0.25);
+            assert_eq!(phase.sample(0.5), first);
+            assert_eq!(phase.sample(0.25), earlier);
+        }
+    }
+
+    #[test]
+    fn test_phase_accumulator_chirp_matches_closed_form() {
+        // f(t) = 100 + 200t  →  phase(t) = 100t + 100t²
+        let chirp = Keyframes::from_pairs(&[(0.0, 100.0), (2.0, 500.0)]);
+        let exact = PhaseAccumulator::with_frequency(chirp.clone());
+        let numeric = PhaseAccumulator::with_frequency(from_fn(move |t| chirp.sample(t)));
+        for i in 1..40 {
+            let t = i as f64 * 0.0473;
+            let expected = (100.0 * t + 100.0 * t * t).rem_euclid(1.0) as f32;
+            assert!((exact.sample(t) - expected).abs() < 1e-5, "t = {t}");
+            let drift = (numeric.sample(t) - expected).abs();
+            assert!(drift.min(1.0 - drift) < 1e-4, "t = {t}");
+        }
+    }
+
     #[test]
     fn test_phase_accumulator_initial_phase_normalization() {
         let freq = Constant::new(1.0);
@@ -299,4 +303,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/generators/cls_phase_accumulator.rs</FILE> - <DESC>Phase accumulator for FM synthesis</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.5.0</VERS>
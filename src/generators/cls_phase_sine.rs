@@ -1,10 +1,12 @@
 // <FILE>src/generators/cls_phase_sine.rs</FILE> - <DESC>Convert phase signal to sine wave</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Audio synthesis primitives - true FM support</WCTX>
-// <CLOG>Initial implementation for phase-to-sine conversion</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::traits::{Signal, SignalContext, SignalTime};
-use std::f32::consts::TAU;
+use core::f32::consts::TAU;
 
 /// Converts a phase signal [0, 1) to a sine wave [-1, 1].
 ///
@@ -43,7 +45,7 @@ impl<P: Signal> PhaseSine<P> {
     }
 }
 
-impl<P: Signal> Signal for PhaseSine<P> {
+impl<P: Signal + Clone> Signal for PhaseSine<P> {
     fn sample(&self, t: SignalTime) -> f32 {
         let phase = self.phase.sample(t);
         (TAU * phase).sin()
@@ -106,4 +108,4 @@ mod tests {
 }
 
 // <FILE>src/generators/cls_phase_sine.rs</FILE> - <DESC>Convert phase signal to sine wave</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
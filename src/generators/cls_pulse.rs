@@ -1,10 +1,13 @@
 // <FILE>mixed-signals/src/generators/cls_pulse.rs</FILE> - <DESC>Pulse window signal</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Removed clamping, added output_range()</CLOG>
+// <VERS>VERSION: 2.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_f64};
-use crate::traits::{Signal, SignalRange, SignalTime};
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Pulse signal that is high during a specific time window.
@@ -13,7 +16,8 @@ use serde::{Deserialize, Serialize};
 /// Useful for triggering effects during specific time ranges.
 ///
 /// Use `.normalized()` if output values exceed [0, 1].
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Pulse {
     /// Value outside the pulse window
     pub low: f32,
@@ -39,6 +43,21 @@ impl Pulse {
     pub fn window(start: f32, end: f32) -> Self {
         Self::new(0.0, 1.0, start, end)
     }
+
+    /// Modulate the window's end edge with an LFO (pulse-width modulation).
+    ///
+    /// The end edge moves by `depth * lfo(t)` seconds, so a bipolar LFO
+    /// widens and narrows the high portion around the static window.
+    /// See [`PwmPulse`].
+    pub fn with_pwm<L: Signal>(self, lfo: L, depth: f32) -> PwmPulse<L> {
+        PwmPulse::new(self, lfo, depth)
+    }
+
+    fn is_high(&self, t: SignalTime, end_shift: f64) -> bool {
+        let start = finite_or(self.start, 0.25) as f64;
+        let end = finite_or(self.end, 0.75) as f64 + end_shift;
+        t >= start && t < end
+    }
 }
 
 impl Default for Pulse {
@@ -63,10 +82,8 @@ impl Signal for Pulse {
         let t = finite_or_f64(t, 0.0);
         let low = finite_or(self.low, 0.0);
         let high = finite_or(self.high, 1.0);
-        let start = finite_or(self.start, 0.25);
-        let end = finite_or(self.end, 0.75);
 
-        if t >= start as f64 && t < end as f64 {
+        if self.is_high(t, 0.0) {
             high
         } else {
             low
@@ -74,6 +91,85 @@ impl Signal for Pulse {
     }
 }
 
+/// Pulse whose window width is modulated by an LFO.
+///
+/// The high window runs from `pulse.start` to `pulse.end + depth * lfo(t)`,
+/// so the high duration follows the LFO. With [`with_period`](Self::with_period)
+/// the window repeats every `period` seconds, giving a classic PWM oscillator.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::{Pulse, Sine};
+/// use mixed_signals::traits::Signal;
+///
+/// // 10 Hz pulse, nominally 50% duty, width swept ±25% by a 0.5 Hz LFO
+/// let pwm = Pulse::window(0.0, 0.05)
+///     .with_pwm(Sine::with_frequency(0.5), 0.025)
+///     .with_period(0.1);
+/// let v = pwm.sample(0.3);
+/// assert!(v == 0.0 || v == 1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PwmPulse<L> {
+    /// Static window (low/high values and nominal edges)
+    pub pulse: Pulse,
+    /// Width modulation source (typically a bipolar LFO)
+    pub lfo: L,
+    /// End-edge shift in seconds per unit of LFO output
+    pub depth: f32,
+    /// Repeat period in seconds (None = one-shot window)
+    pub period: Option<f32>,
+}
+
+impl<L: Signal> PwmPulse<L> {
+    pub fn new(pulse: Pulse, lfo: L, depth: f32) -> Self {
+        Self {
+            pulse,
+            lfo,
+            depth,
+            period: None,
+        }
+    }
+
+    /// Repeat the window every `period` seconds.
+    ///
+    /// Non-finite or non-positive periods leave the pulse one-shot.
+    pub fn with_period(mut self, period: f32) -> Self {
+        self.period = (period.is_finite() && period > 0.0).then_some(period);
+        self
+    }
+
+    fn level(&self, t: SignalTime, lfo_value: f32) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let local = match self.period {
+            Some(period) if period.is_finite() && period > 0.0 => t.rem_euclid(period as f64),
+            _ => t,
+        };
+        let shift = finite_or(self.depth, 0.0) as f64 * finite_or(lfo_value, 0.0) as f64;
+
+        if self.pulse.is_high(local, shift) {
+            finite_or(self.pulse.high, 1.0)
+        } else {
+            finite_or(self.pulse.low, 0.0)
+        }
+    }
+}
+
+impl<L: Signal + Clone> Signal for PwmPulse<L> {
+    fn output_range(&self) -> SignalRange {
+        self.pulse.output_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.level(t, self.lfo.sample(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.level(t, self.lfo.sample_with_context(t, ctx))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,7 +203,62 @@ mod tests {
         assert!((pulse.sample(0.35) - 0.8).abs() < 0.001);
         assert!((pulse.sample(0.5) - 0.2).abs() < 0.001);
     }
+
+    #[test]
+    fn test_pwm_static_matches_pulse() {
+        use crate::generators::Constant;
+
+        let pulse = Pulse::new(0.2, 0.8, 0.3, 0.4);
+        let pwm = pulse.with_pwm(Constant::new(0.0), 0.25);
+        for i in 0..100 {
+            let t = i as f64 * 0.01;
+            assert_eq!(pwm.sample(t), pulse.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_pwm_high_duration_follows_lfo() {
+        use crate::generators::Sine;
+
+        // 1s period, nominal 0.5s high, width swept ±0.25s by a 0.1 Hz LFO
+        let lfo = Sine::with_frequency(0.1);
+        let pwm = Pulse::window(0.0, 0.5).with_pwm(lfo, 0.25).with_period(1.0);
+
+        let dt = 0.001;
+        let high_time = |cycle: usize| {
+            (0..1000)
+                .filter(|&i| pwm.sample(cycle as f64 + i as f64 * dt) > 0.5)
+                .count() as f64
+                * dt
+        };
+
+        let durations: Vec<f64> = (0..10).map(high_time).collect();
+        // Cycle 2 sits near the LFO peak, cycle 7 near the trough
+        assert!(durations[2] > 0.7, "{durations:?}");
+        assert!(durations[7] < 0.3, "{durations:?}");
+        // Width tracks the LFO sampled around the falling edge
+        for (cycle, &d) in durations.iter().enumerate() {
+            let edge_t = cycle as f64 + d;
+            let expected = 0.5 + 0.25 * lfo.sample(edge_t) as f64;
+            assert!(
+                (d - expected).abs() < 0.01,
+                "cycle {cycle}: {d} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_pwm_one_shot_and_invalid_period() {
+        use crate::generators::Constant;
+
+        let pwm = Pulse::window(0.0, 0.5).with_pwm(Constant::new(1.0), 0.25);
+        assert_eq!(pwm.sample(0.7), 1.0);
+        assert_eq!(pwm.sample(0.8), 0.0);
+        // One-shot: no repeat
+        assert_eq!(pwm.sample(1.1), 0.0);
+        assert_eq!(pwm.with_period(f32::NAN).period, None);
+    }
 }
 
 // <FILE>mixed-signals/src/generators/cls_pulse.rs</FILE> - <DESC>Pulse window signal</DESC>
-// <VERS>END OF VERSION: 1.1.0 - 2025-12-27</VERS>
+// <VERS>END OF VERSION: 2.3.0</VERS>
@@ -1,10 +1,11 @@
 // <FILE>mixed-signals/src/generators/cls_ramp.rs</FILE> - <DESC>Linear ramp signal</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Removed clamping, added output_range()</CLOG>
+// <VERS>VERSION: 2.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::math::{finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Linear ramp from start value to end value over a duration.
@@ -13,7 +14,8 @@ use serde::{Deserialize, Serialize};
 /// Clamps to end value after duration.
 ///
 /// Use `.normalized()` if output values exceed [0, 1].
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Ramp {
     /// Starting value
     pub start: f32,
@@ -113,4 +115,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/generators/cls_ramp.rs</FILE> - <DESC>Linear ramp signal</DESC>
-// <VERS>END OF VERSION: 1.1.0 - 2025-12-27</VERS>
+// <VERS>END OF VERSION: 2.1.0</VERS>
@@ -0,0 +1,220 @@
+// <FILE>src/generators/cls_sequence.rs</FILE> - <DESC>Consecutive signal segments played in order</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, alloc imports, core:: instead of std:: paths</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Plays signals one after another, each for its own duration.
+///
+/// During segment `i` the segment's signal is sampled with local time
+/// (`t` minus the segment's start), so every stage starts from its own t = 0.
+/// Past the end the final segment holds its value at local time = its
+/// duration, or with [`looping`](Self::looping) the whole sequence repeats.
+///
+/// Edge cases:
+/// - An empty sequence outputs 0.0
+/// - Zero-duration segments are never active (except as the final hold)
+/// - Negative or non-finite durations count as zero
+/// - Negative `t` holds the first segment at local time 0 (unless looping)
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::envelopes::{Adsr, Impact};
+/// use mixed_signals::generators::Sequence;
+/// use mixed_signals::traits::Signal;
+///
+/// let stages = Sequence::new()
+///     .then(Adsr::new(0.1, 0.1, 0.7, 0.2), 0.5)
+///     .then(Impact::new(1.0, 6.0), 1.0);
+/// assert_eq!(stages.duration(), 1.5);
+/// // 0.7 s is 0.2 s into the Impact stage
+/// assert_eq!(stages.sample(0.7), Impact::new(1.0, 6.0).sample(0.2));
+/// ```
+#[derive(Clone, Default)]
+pub struct Sequence {
+    segments: Vec<(Box<dyn Signal>, f32)>,
+    /// End time of each segment (running sum of durations)
+    ends: Vec<SignalTime>,
+    looping: bool,
+}
+
+impl core::fmt::Debug for Sequence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let durations: Vec<f32> = self.segments.iter().map(|(_, d)| *d).collect();
+        f.debug_struct("Sequence")
+            .field("durations", &durations)
+            .field("looping", &self.looping)
+            .finish()
+    }
+}
+
+impl Sequence {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a segment playing `signal` for `duration` seconds.
+    pub fn then<S: Signal + 'static>(self, signal: S, duration: f32) -> Self {
+        self.then_boxed(Box::new(signal), duration)
+    }
+
+    /// Append an already boxed segment.
+    pub fn then_boxed(mut self, signal: Box<dyn Signal>, duration: f32) -> Self {
+        let duration = finite_or(duration, 0.0).max(0.0);
+        let start = self.duration() as SignalTime;
+        self.segments.push((signal, duration));
+        self.ends.push(start + duration as SignalTime);
+        self
+    }
+
+    /// Repeat the whole sequence instead of holding the final value.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Whether the sequence repeats past its end.
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    /// Total length in seconds.
+    pub fn duration(&self) -> f32 {
+        self.ends.last().copied().unwrap_or(0.0) as f32
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Active segment index and segment-local time at `t`.
+    ///
+    /// Returns `None` for an empty sequence.
+    pub fn locate(&self, t: SignalTime) -> Option<(usize, SignalTime)> {
+        let last = self.segments.len().checked_sub(1)?;
+        let total = self.ends[last];
+        let mut t = finite_or_f64(t, 0.0);
+        if self.looping && total > 0.0 {
+            t = t.rem_euclid(total);
+        }
+        if t < 0.0 {
+            return Some((0, 0.0));
+        }
+        if t >= total {
+            return Some((last, self.segments[last].1 as SignalTime));
+        }
+        let index = self.ends.partition_point(|&end| end <= t);
+        let start = if index == 0 {
+            0.0
+        } else {
+            self.ends[index - 1]
+        };
+        Some((index, t - start))
+    }
+}
+
+impl Signal for Sequence {
+    fn output_range(&self) -> SignalRange {
+        self.segments
+            .iter()
+            .map(|(signal, _)| signal.output_range())
+            .reduce(|a, b| SignalRange::new(a.min.min(b.min), a.max.max(b.max)))
+            .unwrap_or(SignalRange::new(0.0, 0.0))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        match self.locate(t) {
+            Some((index, local)) => self.segments[index].0.sample(local),
+            None => 0.0,
+        }
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        match self.locate(t) {
+            Some((index, local)) => self.segments[index].0.sample_with_context(local, ctx),
+            None => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Ramp};
+
+    fn stages() -> Sequence {
+        Sequence::new()
+            .then(Ramp::new(0.0, 1.0, 1.0), 1.0)
+            .then(Constant::new(0.5), 0.5)
+            .then(Ramp::new(1.0, 0.0, 2.0), 2.0)
+    }
+
+    #[test]
+    fn test_sequence_plays_segments_with_local_time() {
+        let seq = stages();
+        assert!((seq.sample(0.25) - 0.25).abs() < 1e-6);
+        assert_eq!(seq.sample(1.2), 0.5);
+        assert!((seq.sample(2.5) - 0.5).abs() < 1e-6); // 1 s into the 2 s ramp down
+        assert_eq!(seq.locate(1.5), Some((2, 0.0)));
+    }
+
+    #[test]
+    fn test_sequence_holds_or_loops_past_end() {
+        let held = stages();
+        assert_eq!(held.sample(10.0), 0.0);
+        assert_eq!(held.locate(10.0), Some((2, 2.0)));
+        let looped = stages().looping(true);
+        assert!((looped.sample(3.5 + 0.25) - 0.25).abs() < 1e-6);
+        assert!((looped.sample(-0.5) - held.sample(3.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sequence_edge_cases() {
+        assert_eq!(Sequence::new().sample(1.0), 0.0);
+        assert_eq!(Sequence::new().looping(true).sample(1.0), 0.0);
+
+        let seq = Sequence::new()
+            .then(Constant::new(0.1), 0.0)
+            .then(Constant::new(0.2), 1.0)
+            .then(Constant::new(0.3), f32::NAN)
+            .then(Constant::new(0.4), -2.0);
+        assert_eq!(seq.duration(), 1.0);
+        assert_eq!(seq.sample(0.0), 0.2);
+        assert_eq!(seq.sample(5.0), 0.4);
+        assert_eq!(seq.sample(-1.0), 0.1);
+        assert_eq!(seq.sample(f64::NAN), 0.2);
+    }
+
+    #[test]
+    fn test_sequence_forwards_context() {
+        #[derive(Clone)]
+        struct FrameSignal;
+        impl Signal for FrameSignal {
+            fn sample(&self, t: SignalTime) -> f32 {
+                t as f32
+            }
+            fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+                t as f32 + ctx.frame as f32
+            }
+        }
+        let seq = Sequence::new()
+            .then(Constant::new(0.0), 1.0)
+            .then(FrameSignal, 1.0);
+        let ctx = SignalContext::new(3, 0);
+        assert!((seq.sample_with_context(1.5, &ctx) - 3.5).abs() < 1e-6);
+    }
+}
+
+// <FILE>src/generators/cls_sequence.rs</FILE> - <DESC>Consecutive signal segments played in order</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,12 +1,20 @@
 // <FILE>mixed-signals/src/generators/cls_sine.rs</FILE> - <DESC>Sine wave oscillator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Changed to bipolar output [-1, 1], removed clamping, added output_range()</CLOG>
+// <VERS>VERSION: 2.6.1</VERS>
+// <WCTX>Batch sampling</WCTX>
+// <CLOG>Batch override moved to sample_batch_from</CLOG>
 
+#[cfg(feature = "serde")]
+use super::ModulatedSine;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
+use crate::types::{SignalBuildError, SignalOrFloat};
+use alloc::vec::Vec;
+use core::f64::consts::TAU;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::f64::consts::TAU;
 
 /// Sine wave oscillator.
 ///
@@ -16,7 +24,25 @@ use std::f64::consts::TAU;
 /// Formula: `output = offset + amplitude * sin(2π * (frequency * t + phase))`
 ///
 /// Use `.normalized()` to convert to [0, 1] for TUI animations.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+///
+/// # Harmonics
+///
+/// [`Sine::with_harmonic`] adds overtones at integer multiples of the
+/// frequency (up to [`MAX_HARMONICS`]). The waveform is divided by the sum of
+/// absolute harmonic weights (fundamental = 1), so output stays within
+/// `offset ± amplitude`. With no harmonics the output is a pure sine.
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::traits::Signal;
+///
+/// let rich = Sine::with_frequency(110.0)
+///     .with_harmonic(2, 0.5)
+///     .with_harmonic(3, 0.25);
+/// assert!(rich.sample(0.3).abs() <= 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Sine {
     /// Frequency in Hz (cycles per second)
     pub frequency: f32,
@@ -26,6 +52,81 @@ pub struct Sine {
     pub offset: f32,
     /// Phase shift (normalized 0..1)
     pub phase: f32,
+    /// Overtones added on top of the fundamental
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Harmonics::is_empty")
+    )]
+    pub harmonics: Harmonics,
+}
+
+/// Maximum number of overtones a [`Sine`] can carry.
+pub const MAX_HARMONICS: usize = 8;
+
+/// Fixed-capacity list of `(harmonic, amplitude)` overtones.
+///
+/// Harmonic `n` sounds at `n * frequency`. Stored inline so [`Sine`] stays
+/// `Copy`; serializes as a list of pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec<(u32, f32)>", into = "Vec<(u32, f32)>")
+)]
+pub struct Harmonics {
+    len: u8,
+    entries: [(u32, f32); MAX_HARMONICS],
+}
+
+impl Harmonics {
+    /// Add or replace the overtone at harmonic `n`.
+    ///
+    /// Returns false if `n` is 0, the amplitude is non-finite, or the list is
+    /// full.
+    pub fn set(&mut self, n: u32, amplitude: f32) -> bool {
+        if n == 0 || !amplitude.is_finite() {
+            return false;
+        }
+        let len = self.len as usize;
+        if let Some(entry) = self.entries[..len].iter_mut().find(|(h, _)| *h == n) {
+            entry.1 = amplitude;
+            return true;
+        }
+        if len == MAX_HARMONICS {
+            return false;
+        }
+        self.entries[len] = (n, amplitude);
+        self.len += 1;
+        true
+    }
+
+    pub fn as_slice(&self) -> &[(u32, f32)] {
+        &self.entries[..(self.len as usize).min(MAX_HARMONICS)]
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl From<Vec<(u32, f32)>> for Harmonics {
+    fn from(pairs: Vec<(u32, f32)>) -> Self {
+        let mut harmonics = Harmonics::default();
+        for (n, amplitude) in pairs {
+            harmonics.set(n, amplitude);
+        }
+        harmonics
+    }
+}
+
+impl From<Harmonics> for Vec<(u32, f32)> {
+    fn from(harmonics: Harmonics) -> Self {
+        harmonics.as_slice().to_vec()
+    }
 }
 
 impl Sine {
@@ -35,6 +136,7 @@ impl Sine {
             amplitude,
             offset,
             phase,
+            harmonics: Harmonics::default(),
         }
     }
 
@@ -42,6 +144,81 @@ impl Sine {
     pub fn with_frequency(frequency: f32) -> Self {
         Self::new(frequency, 1.0, 0.0, 0.0)
     }
+
+    /// Sine whose frequency and amplitude may each be a fixed float or a
+    /// signal spec, evaluated per sample. See [`ModulatedSine`].
+    ///
+    /// Returns the build error of an invalid signal spec.
+    #[cfg(feature = "serde")]
+    pub fn from_specs(
+        frequency: SignalOrFloat,
+        amplitude: SignalOrFloat,
+    ) -> Result<ModulatedSine, SignalBuildError> {
+        ModulatedSine::new(frequency, amplitude)
+    }
+
+    /// Add an overtone at `n * frequency` with relative `amplitude`
+    /// (fundamental = 1.0). Chainable; setting the same `n` again replaces it.
+    ///
+    /// Ignored for `n == 0`, non-finite amplitudes, or once
+    /// [`MAX_HARMONICS`] overtones are present.
+    pub fn with_harmonic(mut self, n: u32, amplitude: f32) -> Self {
+        self.harmonics.set(n, amplitude);
+        self
+    }
+
+    /// Sanitized (frequency, amplitude, offset, phase).
+    fn params(&self) -> (f64, f64, f64, f64) {
+        (
+            finite_or(self.frequency, 1.0) as f64,
+            finite_or(self.amplitude, 1.0) as f64,
+            finite_or(self.offset, 0.0) as f64,
+            finite_or(self.phase, 0.0) as f64,
+        )
+    }
+
+    fn value_at(&self, t: SignalTime, params: (f64, f64, f64, f64)) -> f32 {
+        let (frequency, amplitude, offset, phase) = params;
+        let t = finite_or_f64(t, 0.0);
+        let angle = TAU * (frequency * t + phase);
+        if self.harmonics.is_empty() {
+            return (offset + amplitude * angle.sin()) as f32;
+        }
+
+        // Sum overtones and divide by total weight to stay within [-1, 1]
+        let mut value = angle.sin();
+        let mut weight = 1.0;
+        for &(n, harmonic_amp) in self.harmonics.as_slice() {
+            let harmonic_amp = finite_or(harmonic_amp, 0.0) as f64;
+            value += harmonic_amp * (angle * n as f64).sin();
+            weight += harmonic_amp.abs();
+        }
+        (offset + amplitude * value / weight) as f32
+    }
+
+    /// ∫₀ᵗ of the sine plus its overtones, in closed form.
+    fn integral_at(&self, t: SignalTime, params: (f64, f64, f64, f64)) -> f64 {
+        let (frequency, amplitude, offset, phase) = params;
+        let t = finite_or_f64(t, 0.0);
+        // ∫₀ᵗ sin(2πn(fτ + φ)) dτ; a zero frequency holds sin(2πnφ)
+        let partial = |n: f64| {
+            if frequency == 0.0 {
+                t * (TAU * n * phase).sin()
+            } else {
+                ((TAU * n * phase).cos() - (TAU * n * (frequency * t + phase)).cos())
+                    / (TAU * n * frequency)
+            }
+        };
+
+        let mut area = partial(1.0);
+        let mut weight = 1.0;
+        for &(n, harmonic_amp) in self.harmonics.as_slice() {
+            let harmonic_amp = finite_or(harmonic_amp, 0.0) as f64;
+            area += harmonic_amp * partial(n as f64);
+            weight += harmonic_amp.abs();
+        }
+        offset * t + amplitude * area / weight
+    }
 }
 
 impl Default for Sine {
@@ -51,6 +228,7 @@ impl Default for Sine {
             amplitude: 1.0,
             offset: 0.0,
             phase: 0.0,
+            harmonics: Harmonics::default(),
         }
     }
 }
@@ -63,14 +241,24 @@ impl Signal for Sine {
     }
 
     fn sample(&self, t: SignalTime) -> f32 {
-        let t = finite_or_f64(t, 0.0);
-        let frequency = finite_or(self.frequency, 1.0) as f64;
-        let amplitude = finite_or(self.amplitude, 1.0) as f64;
-        let offset = finite_or(self.offset, 0.0) as f64;
-        let phase = finite_or(self.phase, 0.0) as f64;
+        self.value_at(t, self.params())
+    }
 
-        let angle = TAU * (frequency * t + phase);
-        (offset + amplitude * angle.sin()) as f32
+    fn integral(&self, t: SignalTime) -> f64 {
+        self.integral_at(t, self.params())
+    }
+
+    fn sample_batch_from(
+        &self,
+        t_start: SignalTime,
+        dt: SignalTime,
+        first: usize,
+        out: &mut [f32],
+    ) {
+        let params = self.params();
+        for (i, value) in out.iter_mut().enumerate() {
+            *value = self.value_at(t_start + (first + i) as SignalTime * dt, params);
+        }
     }
 }
 
@@ -148,7 +336,106 @@ mod tests {
         assert!((range2.min - (-0.2)).abs() < 0.001);
         assert!((range2.max - 0.8).abs() < 0.001);
     }
+
+    /// Magnitude of DFT bin `k` over one second sampled at `n` points.
+    fn bin_magnitude(sine: &Sine, k: usize, n: usize) -> f64 {
+        let (mut re, mut im) = (0.0, 0.0);
+        for i in 0..n {
+            let t = i as f64 / n as f64;
+            let v = sine.sample(t) as f64;
+            let w = TAU * k as f64 * t;
+            re += v * w.cos();
+            im -= v * w.sin();
+        }
+        (re * re + im * im).sqrt() * 2.0 / n as f64
+    }
+
+    #[test]
+    fn test_sine_no_harmonics_is_pure() {
+        let plain = Sine::new(3.0, 0.8, 0.1, 0.2);
+        for i in 0..100 {
+            let t = i as f64 * 0.0123;
+            let expected = 0.1 + 0.8 * (TAU * (3.0 * t + 0.2)).sin();
+            assert!((plain.sample(t) as f64 - expected).abs() < 1e-6);
+        }
+        let sine = Sine::with_frequency(1.0);
+        assert!(bin_magnitude(&sine, 2, 256) < 1e-4);
+        assert!((bin_magnitude(&sine, 1, 256) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_sine_second_harmonic_overtone() {
+        let sine = Sine::with_frequency(1.0).with_harmonic(2, 0.5);
+        let fundamental = bin_magnitude(&sine, 1, 256);
+        let overtone = bin_magnitude(&sine, 2, 256);
+        // Normalized by 1 + 0.5
+        assert!((fundamental - 1.0 / 1.5).abs() < 1e-4, "{fundamental}");
+        assert!((overtone - 0.5 / 1.5).abs() < 1e-4, "{overtone}");
+        assert!(bin_magnitude(&sine, 3, 256) < 1e-4);
+    }
+
+    #[test]
+    fn test_sine_harmonics_stay_bipolar() {
+        let sine = Sine::with_frequency(2.0)
+            .with_harmonic(2, 1.0)
+            .with_harmonic(3, -0.7)
+            .with_harmonic(5, 0.4);
+        assert_eq!(sine.harmonics.len(), 3);
+        for i in 0..1000 {
+            let v = sine.sample(i as f64 * 0.001);
+            assert!((-1.0..=1.0).contains(&v));
+        }
+    }
+
+    #[test]
+    fn test_sine_harmonics_replace_and_capacity() {
+        let mut sine = Sine::default().with_harmonic(2, 0.5).with_harmonic(2, 0.25);
+        assert_eq!(sine.harmonics.as_slice(), &[(2, 0.25)]);
+        sine = sine.with_harmonic(0, 1.0).with_harmonic(4, f32::NAN);
+        assert_eq!(sine.harmonics.len(), 1);
+        for n in 3..20 {
+            sine = sine.with_harmonic(n, 0.1);
+        }
+        assert_eq!(sine.harmonics.len(), MAX_HARMONICS);
+    }
+
+    #[test]
+    fn test_sine_display_range_is_exact() {
+        let osc = Sine::new(2.0, 0.5, 0.25, 0.0);
+        let range = osc.output_range();
+        assert_eq!(osc.display_range(), (range.min, range.max));
+        assert_eq!(osc.display_range(), (-0.25, 0.75));
+    }
+
+    #[test]
+    fn test_sine_sample_batch_bitwise_identical() {
+        let signal = Sine::new(3.0, 0.8, 0.1, 0.2).with_harmonic(3, 0.3);
+        let mut batch = vec![0.0; 480];
+        signal.sample_batch(-0.25, 1.0 / 480.0, &mut batch);
+        for (i, value) in batch.iter().enumerate() {
+            let t = -0.25 + i as f64 * (1.0 / 480.0);
+            assert_eq!(value.to_bits(), signal.sample(t).to_bits());
+        }
+    }
+
+    #[test]
+    fn test_sine_integral_matches_quadrature() {
+        use crate::math::integrate;
+        for signal in [
+            Sine::new(3.0, 0.8, 0.1, 0.2).with_harmonic(3, 0.3),
+            Sine::new(0.0, 0.5, 0.2, 0.125),
+            Sine::new(-2.0, 1.0, 0.0, 0.0),
+        ] {
+            for &t in &[0.0, 0.37, 1.0, 2.71, -0.6] {
+                let numeric = integrate(|tau| signal.sample(tau), 0.0, t);
+                assert!(
+                    (signal.integral(t) - numeric).abs() < 1e-6,
+                    "{signal:?} at {t}"
+                );
+            }
+        }
+    }
 }
 
 // <FILE>mixed-signals/src/generators/cls_sine.rs</FILE> - <DESC>Sine wave oscillator</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 2.6.1</VERS>
@@ -1,10 +1,13 @@
 // <FILE>mixed-signals/src/generators/cls_square.rs</FILE> - <DESC>Square wave oscillator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Changed to bipolar output [-1, 1], removed clamping, added output_range()</CLOG>
+// <VERS>VERSION: 2.2.1</VERS>
+// <WCTX>Batch sampling</WCTX>
+// <CLOG>Batch override moved to sample_batch_from</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_clamp, finite_or_f64};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Square wave oscillator.
@@ -13,7 +16,8 @@ use serde::{Deserialize, Serialize};
 /// Output is bipolar [-1, 1] scaled by amplitude and shifted by offset.
 ///
 /// Use `.normalized()` to convert to [0, 1] for TUI animations.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Square {
     /// Frequency in Hz (cycles per second)
     pub frequency: f32,
@@ -41,6 +45,26 @@ impl Square {
     pub fn with_frequency(frequency: f32) -> Self {
         Self::new(frequency, 1.0, 0.0, 0.0, 0.5)
     }
+
+    /// Sanitized (frequency, amplitude, offset, phase, duty).
+    fn params(&self) -> (f64, f64, f64, f64, f64) {
+        (
+            finite_or(self.frequency, 1.0) as f64,
+            finite_or(self.amplitude, 1.0) as f64,
+            finite_or(self.offset, 0.0) as f64,
+            finite_or(self.phase, 0.0) as f64,
+            finite_or_clamp(self.duty, 0.0, 1.0, 0.5) as f64,
+        )
+    }
+
+    fn value_at(&self, t: SignalTime, params: (f64, f64, f64, f64, f64)) -> f32 {
+        let (frequency, amplitude, offset, phase, duty) = params;
+        let t = finite_or_f64(t, 0.0);
+        let cycle_pos = (t * frequency + phase).rem_euclid(1.0);
+        // Bipolar: +1 during duty, -1 otherwise
+        let bipolar = if cycle_pos < duty { 1.0 } else { -1.0 };
+        (offset + amplitude * bipolar) as f32
+    }
 }
 
 impl Default for Square {
@@ -63,17 +87,20 @@ impl Signal for Square {
     }
 
     fn sample(&self, t: SignalTime) -> f32 {
-        let t = finite_or_f64(t, 0.0);
-        let frequency = finite_or(self.frequency, 1.0) as f64;
-        let amplitude = finite_or(self.amplitude, 1.0) as f64;
-        let offset = finite_or(self.offset, 0.0) as f64;
-        let phase = finite_or(self.phase, 0.0) as f64;
-        let duty = finite_or_clamp(self.duty, 0.0, 1.0, 0.5) as f64;
+        self.value_at(t, self.params())
+    }
 
-        let cycle_pos = (t * frequency + phase).rem_euclid(1.0);
-        // Bipolar: +1 during duty, -1 otherwise
-        let bipolar = if cycle_pos < duty { 1.0 } else { -1.0 };
-        (offset + amplitude * bipolar) as f32
+    fn sample_batch_from(
+        &self,
+        t_start: SignalTime,
+        dt: SignalTime,
+        first: usize,
+        out: &mut [f32],
+    ) {
+        let params = self.params();
+        for (i, value) in out.iter_mut().enumerate() {
+            *value = self.value_at(t_start + (first + i) as SignalTime * dt, params);
+        }
     }
 }
 
@@ -121,7 +148,18 @@ mod tests {
         assert_eq!(range.min, -1.0);
         assert_eq!(range.max, 1.0);
     }
+
+    #[test]
+    fn test_square_sample_batch_bitwise_identical() {
+        let signal = Square::new(4.0, 0.5, 0.5, 0.0, 0.25);
+        let mut batch = vec![0.0; 480];
+        signal.sample_batch(-0.25, 1.0 / 480.0, &mut batch);
+        for (i, value) in batch.iter().enumerate() {
+            let t = -0.25 + i as f64 * (1.0 / 480.0);
+            assert_eq!(value.to_bits(), signal.sample(t).to_bits());
+        }
+    }
 }
 
 // <FILE>mixed-signals/src/generators/cls_square.rs</FILE> - <DESC>Square wave oscillator</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 2.2.1</VERS>
@@ -1,10 +1,11 @@
 // <FILE>mixed-signals/src/generators/cls_step.rs</FILE> - <DESC>Step function signal</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Removed clamping, added output_range()</CLOG>
+// <VERS>VERSION: 2.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::math::{finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Step function that transitions from one value to another at a threshold.
@@ -12,7 +13,8 @@ use serde::{Deserialize, Serialize};
 /// Returns `before` when t < threshold, `after` when t >= threshold.
 ///
 /// Use `.normalized()` if output values exceed [0, 1].
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Step {
     /// Value before the threshold
     pub before: f32,
@@ -101,4 +103,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/generators/cls_step.rs</FILE> - <DESC>Step function signal</DESC>
-// <VERS>END OF VERSION: 1.0.0 - 2025-12-22</VERS>
+// <VERS>END OF VERSION: 2.1.0</VERS>
@@ -1,10 +1,13 @@
 // <FILE>mixed-signals/src/generators/cls_triangle.rs</FILE> - <DESC>Triangle wave oscillator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Changed to bipolar output [-1, 1], removed clamping, added output_range()</CLOG>
+// <VERS>VERSION: 2.5.1</VERS>
+// <WCTX>Batch sampling</WCTX>
+// <CLOG>Batch override moved to sample_batch_from</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Triangle wave oscillator.
@@ -12,8 +15,14 @@ use serde::{Deserialize, Serialize};
 /// Produces a linear ramp up then linear ramp down pattern.
 /// Output is bipolar [-1, 1] scaled by amplitude and shifted by offset.
 ///
+/// `skew` sets the fraction of each cycle spent rising (default 0.5 =
+/// symmetric). Low skew rises fast and falls slowly (approaching a falling
+/// sawtooth at 0); high skew rises slowly and drops fast (a rising sawtooth
+/// at 1).
+///
 /// Use `.normalized()` to convert to [0, 1] for TUI animations.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Triangle {
     /// Frequency in Hz (cycles per second)
     pub frequency: f32,
@@ -23,6 +32,14 @@ pub struct Triangle {
     pub offset: f32,
     /// Phase shift (normalized 0..1)
     pub phase: f32,
+    /// Fraction of the cycle spent rising (0..1, 0.5 = symmetric)
+    #[cfg_attr(feature = "serde", serde(default = "default_skew"))]
+    pub skew: f32,
+}
+
+#[cfg(feature = "serde")]
+fn default_skew() -> f32 {
+    0.5
 }
 
 impl Triangle {
@@ -32,12 +49,73 @@ impl Triangle {
             amplitude,
             offset,
             phase,
+            skew: 0.5,
         }
     }
 
     pub fn with_frequency(frequency: f32) -> Self {
         Self::new(frequency, 1.0, 0.0, 0.0)
     }
+
+    /// Set the rise/fall ratio (clamped to 0..1, NaN falls back to 0.5).
+    pub fn with_skew(mut self, skew: f32) -> Self {
+        self.skew = finite_or(skew, 0.5).clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sanitized (frequency, amplitude, offset, phase, skew).
+    fn params(&self) -> (f64, f64, f64, f64, f64) {
+        (
+            finite_or(self.frequency, 1.0) as f64,
+            finite_or(self.amplitude, 1.0) as f64,
+            finite_or(self.offset, 0.0) as f64,
+            finite_or(self.phase, 0.0) as f64,
+            finite_or(self.skew, 0.5).clamp(0.0, 1.0) as f64,
+        )
+    }
+
+    fn value_at(&self, t: SignalTime, params: (f64, f64, f64, f64, f64)) -> f32 {
+        let (frequency, amplitude, offset, phase, skew) = params;
+        let t = finite_or_f64(t, 0.0);
+
+        // Normalized position in cycle (0..1)
+        let cycle_pos = (t * frequency + phase).rem_euclid(1.0);
+
+        (offset + amplitude * unit_triangle(cycle_pos, skew)) as f32
+    }
+
+    /// ∫₀ᵗ in closed form. Every full cycle integrates to zero, so only the
+    /// position within the cycle matters.
+    fn integral_at(&self, t: SignalTime, params: (f64, f64, f64, f64, f64)) -> f64 {
+        let (frequency, amplitude, offset, phase, skew) = params;
+        let t = finite_or_f64(t, 0.0);
+        // Area under the unit bipolar triangle from cycle start to `x`
+        let cycle_area = |x: f64| {
+            let pos = x.rem_euclid(1.0);
+            if pos < skew {
+                pos * pos / skew - pos
+            } else {
+                let fall = pos - skew;
+                fall - fall * fall / (1.0 - skew)
+            }
+        };
+
+        let area = if frequency == 0.0 {
+            t * unit_triangle(phase.rem_euclid(1.0), skew)
+        } else {
+            (cycle_area(frequency * t + phase) - cycle_area(phase)) / frequency
+        };
+        offset * t + amplitude * area
+    }
+}
+
+/// Triangle wave in bipolar [-1, 1]: rise -1->1 over `skew`, fall over the rest.
+fn unit_triangle(cycle_pos: f64, skew: f64) -> f64 {
+    if cycle_pos < skew {
+        2.0 * cycle_pos / skew - 1.0
+    } else {
+        1.0 - 2.0 * (cycle_pos - skew) / (1.0 - skew)
+    }
 }
 
 impl Default for Triangle {
@@ -47,6 +125,7 @@ impl Default for Triangle {
             amplitude: 1.0,
             offset: 0.0,
             phase: 0.0,
+            skew: 0.5,
         }
     }
 }
@@ -59,23 +138,24 @@ impl Signal for Triangle {
     }
 
     fn sample(&self, t: SignalTime) -> f32 {
-        let t = finite_or_f64(t, 0.0);
-        let frequency = finite_or(self.frequency, 1.0) as f64;
-        let amplitude = finite_or(self.amplitude, 1.0) as f64;
-        let offset = finite_or(self.offset, 0.0) as f64;
-        let phase = finite_or(self.phase, 0.0) as f64;
-
-        // Normalized position in cycle (0..1)
-        let cycle_pos = (t * frequency + phase).rem_euclid(1.0);
+        self.value_at(t, self.params())
+    }
 
-        // Triangle wave in bipolar [-1, 1]: ramp -1->1, ramp 1->-1
-        let bipolar = if cycle_pos < 0.5 {
-            4.0 * cycle_pos - 1.0
-        } else {
-            3.0 - 4.0 * cycle_pos
-        };
+    fn integral(&self, t: SignalTime) -> f64 {
+        self.integral_at(t, self.params())
+    }
 
-        (offset + amplitude * bipolar) as f32
+    fn sample_batch_from(
+        &self,
+        t_start: SignalTime,
+        dt: SignalTime,
+        first: usize,
+        out: &mut [f32],
+    ) {
+        let params = self.params();
+        for (i, value) in out.iter_mut().enumerate() {
+            *value = self.value_at(t_start + (first + i) as SignalTime * dt, params);
+        }
     }
 }
 
@@ -125,7 +205,104 @@ mod tests {
         assert_eq!(range.min, -1.0);
         assert_eq!(range.max, 1.0);
     }
+
+    #[test]
+    fn test_triangle_symmetric_skew_matches_default() {
+        let tri = Triangle::with_frequency(2.0);
+        let skewed = Triangle::with_frequency(2.0).with_skew(0.5);
+        for i in 0..100 {
+            let t = i as f64 * 0.0137;
+            let expected = {
+                let p = (t * 2.0).rem_euclid(1.0);
+                if p < 0.5 {
+                    4.0 * p - 1.0
+                } else {
+                    3.0 - 4.0 * p
+                }
+            } as f32;
+            assert!((tri.sample(t) - expected).abs() < 1e-6);
+            assert_eq!(skewed.sample(t), tri.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_triangle_skew_extremes_approach_sawtooth() {
+        use crate::generators::Sawtooth;
+
+        let rising = Sawtooth::new(1.0, 1.0, 0.0, 0.0, false);
+        let falling = Sawtooth::new(1.0, 1.0, 0.0, 0.0, true);
+        let slow_rise = Triangle::default().with_skew(0.999);
+        let fast_rise = Triangle::default().with_skew(0.001);
+        // Compare away from the sawtooth reset where the skewed edge lives
+        for i in 1..99 {
+            let t = i as f64 * 0.01;
+            assert!(
+                (slow_rise.sample(t) - rising.sample(t)).abs() < 0.01,
+                "t={t}"
+            );
+            assert!(
+                (fast_rise.sample(t) - falling.sample(t)).abs() < 0.01,
+                "t={t}"
+            );
+        }
+        // Exact extremes stay finite and bipolar
+        for skew in [0.0, 1.0] {
+            let tri = Triangle::default().with_skew(skew);
+            for i in 0..20 {
+                let v = tri.sample(i as f64 * 0.05);
+                assert!((-1.0..=1.0).contains(&v));
+            }
+        }
+    }
+
+    #[test]
+    fn test_triangle_skew_peak_position() {
+        let tri = Triangle::default().with_skew(0.2);
+        assert!((tri.sample(0.0) + 1.0).abs() < 1e-6);
+        assert!((tri.sample(0.1) - 0.0).abs() < 1e-6);
+        assert!((tri.sample(0.2) - 1.0).abs() < 1e-6);
+        assert!((tri.sample(0.6) - 0.0).abs() < 1e-6);
+        assert_eq!(Triangle::default().with_skew(f32::NAN).skew, 0.5);
+        assert_eq!(Triangle::default().with_skew(3.0).skew, 1.0);
+    }
+
+    #[test]
+    fn test_triangle_display_range_is_exact() {
+        let osc = Triangle::new(2.0, 0.5, 0.25, 0.0);
+        let range = osc.output_range();
+        assert_eq!(osc.display_range(), (range.min, range.max));
+        assert_eq!(osc.display_range(), (-0.25, 0.75));
+    }
+
+    #[test]
+    fn test_triangle_sample_batch_bitwise_identical() {
+        let signal = Triangle::new(2.5, 1.0, 0.0, 0.1).with_skew(0.3);
+        let mut batch = vec![0.0; 480];
+        signal.sample_batch(-0.25, 1.0 / 480.0, &mut batch);
+        for (i, value) in batch.iter().enumerate() {
+            let t = -0.25 + i as f64 * (1.0 / 480.0);
+            assert_eq!(value.to_bits(), signal.sample(t).to_bits());
+        }
+    }
+
+    #[test]
+    fn test_triangle_integral_matches_quadrature() {
+        use crate::math::integrate;
+        for signal in [
+            Triangle::new(2.5, 1.0, 0.3, 0.1).with_skew(0.3),
+            Triangle::new(1.0, 0.5, 0.0, 0.0).with_skew(0.05),
+            Triangle::new(0.0, 1.0, 0.0, 0.6),
+        ] {
+            for &t in &[0.0, 0.37, 1.0, 2.71, -0.6] {
+                let numeric = integrate(|tau| signal.sample(tau), 0.0, t);
+                assert!(
+                    (signal.integral(t) - numeric).abs() < 1e-5,
+                    "{signal:?} at {t}"
+                );
+            }
+        }
+    }
 }
 
 // <FILE>mixed-signals/src/generators/cls_triangle.rs</FILE> - <DESC>Triangle wave oscillator</DESC>
-// <VERS>END OF VERSION: 1.3.0</VERS>
+// <VERS>END OF VERSION: 2.5.1</VERS>
@@ -0,0 +1,154 @@
+// <FILE>src/generators/cls_wavetable.rs</FILE> - <DESC>Wavetable oscillator over a baked lookup table</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std, alloc imports, core:: instead of std:: paths</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalRange, SignalTime};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wavetable oscillator that loops a baked single-cycle lookup table.
+///
+/// The table holds one cycle of the waveform sampled at evenly spaced phases
+/// (entry `i` is the value at phase `i / len`). Playback interpolates linearly
+/// between entries and wraps from the last entry back to the first.
+///
+/// Output values come straight from the table, so the output range is the
+/// table's min/max.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Wavetable;
+/// use mixed_signals::traits::Signal;
+///
+/// // Four-step square-ish cycle at 2 Hz
+/// let table = Wavetable::new(vec![1.0, 1.0, -1.0, -1.0], 2.0).unwrap();
+/// assert_eq!(table.sample(0.0), 1.0);
+/// assert_eq!(table.sample(0.25), -1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Wavetable {
+    /// One cycle of the waveform
+    values: Vec<f32>,
+    /// Playback frequency in Hz (cycles per second)
+    pub frequency: f32,
+}
+
+impl Wavetable {
+    /// Create a wavetable from one cycle of values.
+    ///
+    /// Returns an error if the table is empty or contains non-finite values.
+    pub fn new(values: Vec<f32>, frequency: f32) -> Result<Self, String> {
+        if values.is_empty() {
+            return Err("Wavetable requires at least one value".to_string());
+        }
+        if let Some(bad) = values.iter().find(|v| !v.is_finite()) {
+            return Err(format!("Wavetable values must be finite, got {}", bad));
+        }
+        Ok(Self { values, frequency })
+    }
+
+    /// The lookup table (one cycle).
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Signal for Wavetable {
+    fn output_range(&self) -> SignalRange {
+        let finite = self.values.iter().copied().filter(|v| v.is_finite());
+        let (min, max) = finite.fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(v), hi.max(v))
+        });
+        if min > max {
+            return SignalRange::new(0.0, 0.0);
+        }
+        SignalRange::new(min, max)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let len = self.values.len();
+        if len == 0 {
+            return 0.0;
+        }
+        let t = finite_or_f64(t, 0.0);
+        let frequency = finite_or(self.frequency, 1.0) as f64;
+
+        let position = (frequency * t).rem_euclid(1.0) * len as f64;
+        let index = (position.floor() as usize).min(len - 1);
+        let frac = (position - index as f64) as f32;
+
+        let a = finite_or(self.values[index], 0.0);
+        let b = finite_or(self.values[(index + 1) % len], 0.0);
+        a + (b - a) * frac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Sine;
+
+    fn sine_table(len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| (core::f32::consts::TAU * i as f32 / len as f32).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_wavetable_approximates_sine() {
+        let table = Wavetable::new(sine_table(256), 2.0).unwrap();
+        let sine = Sine::with_frequency(2.0);
+        for i in 0..200 {
+            let t = i as f64 * 0.0037;
+            assert!((table.sample(t) - sine.sample(t)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_wavetable_interpolates_and_wraps() {
+        let table = Wavetable::new(vec![0.0, 1.0], 1.0).unwrap();
+        assert!((table.sample(0.25) - 0.5).abs() < 1e-6);
+        // Between the last entry and the first (wrap-around)
+        assert!((table.sample(0.75) - 0.5).abs() < 1e-6);
+        assert!((table.sample(1.25) - table.sample(0.25)).abs() < 1e-6);
+        assert!((table.sample(-0.75) - table.sample(0.25)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_wavetable_single_value() {
+        let table = Wavetable::new(vec![0.4], 3.0).unwrap();
+        assert_eq!(table.sample(0.0), 0.4);
+        assert_eq!(table.sample(0.77), 0.4);
+    }
+
+    #[test]
+    fn test_wavetable_rejects_invalid() {
+        assert!(Wavetable::new(vec![], 1.0).is_err());
+        assert!(Wavetable::new(vec![0.0, f32::NAN], 1.0).is_err());
+    }
+
+    #[test]
+    fn test_wavetable_output_range() {
+        let table = Wavetable::new(vec![-0.5, 0.25, 2.0], 1.0).unwrap();
+        assert_eq!(table.output_range(), SignalRange::new(-0.5, 2.0));
+    }
+}
+
+// <FILE>src/generators/cls_wavetable.rs</FILE> - <DESC>Wavetable oscillator over a baked lookup table</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
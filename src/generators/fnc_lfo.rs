@@ -0,0 +1,148 @@
+// <FILE>src/generators/fnc_lfo.rs</FILE> - <DESC>Named LFO preset factory</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, alloc imports, core:: instead of std:: paths</CLOG>
+
+use super::{Sawtooth, Sine, Square, Triangle};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{fast_random, finite_or, finite_or_f64};
+use crate::processing::Normalized;
+use crate::traits::{from_fn, Signal};
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::str::FromStr;
+
+/// Waveform of an [`lfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LfoShape {
+    #[default]
+    Sine,
+    Triangle,
+    /// Rising sawtooth: 0 to 1, then reset
+    Ramp,
+    Square,
+    /// Sample-and-hold: a new seeded random level each cycle
+    Random,
+}
+
+impl FromStr for LfoShape {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "sine" => Ok(LfoShape::Sine),
+            "triangle" => Ok(LfoShape::Triangle),
+            "ramp" | "saw" | "sawtooth" => Ok(LfoShape::Ramp),
+            "square" => Ok(LfoShape::Square),
+            "random" => Ok(LfoShape::Random),
+            other => Err(format!(
+                "unknown LFO shape '{}' (expected sine, triangle, ramp, square, or random)",
+                other
+            )),
+        }
+    }
+}
+
+impl LfoShape {
+    /// Build this shape as a [0, 1] LFO cycling at `rate_hz`.
+    pub fn build(self, rate_hz: f32) -> Box<dyn Signal> {
+        match self {
+            LfoShape::Sine => Box::new(Normalized::new(Sine::with_frequency(rate_hz))),
+            LfoShape::Triangle => Box::new(Normalized::new(Triangle::with_frequency(rate_hz))),
+            LfoShape::Ramp => Box::new(Normalized::new(Sawtooth::with_frequency(rate_hz))),
+            LfoShape::Square => Box::new(Normalized::new(Square::with_frequency(rate_hz))),
+            LfoShape::Random => {
+                let rate = finite_or(rate_hz, 1.0) as f64;
+                Box::new(
+                    from_fn(move |t| {
+                        let cycle = (finite_or_f64(t, 0.0) * rate).floor();
+                        fast_random(0, cycle as i64 as u64)
+                    })
+                    .with_range(0.0, 1.0),
+                )
+            }
+        }
+    }
+}
+
+/// Low-frequency oscillator preset, normalized to [0, 1].
+///
+/// `shape` is one of `"sine"`, `"triangle"`, `"ramp"` (also `"saw"`),
+/// `"square"`, or `"random"` (case-insensitive). Every shape repeats (or, for
+/// `"random"`, picks a new level) every `1 / rate_hz` seconds, ready to drive
+/// a parameter through `Remap` or [`SignalOrFloat`](crate::types::SignalOrFloat).
+///
+/// Unknown names fall back to a sine LFO; parse [`LfoShape`] directly to
+/// reject them instead.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::lfo;
+///
+/// let wobble = lfo("triangle", 0.5);
+/// let v = wobble.sample(1.3);
+/// assert!((0.0..=1.0).contains(&v));
+/// ```
+pub fn lfo(shape: &str, rate_hz: f32) -> Box<dyn Signal> {
+    shape.parse::<LfoShape>().unwrap_or_default().build(rate_hz)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extremes(signal: &dyn Signal, seconds: f64) -> (f32, f32) {
+        (0..4000).fold((f32::MAX, f32::MIN), |(lo, hi), i| {
+            let v = signal.sample(i as f64 * seconds / 4000.0);
+            (lo.min(v), hi.max(v))
+        })
+    }
+
+    #[test]
+    fn test_lfo_shapes_span_unit_range_with_period() {
+        let rate = 2.0;
+        for name in ["sine", "triangle", "ramp", "square"] {
+            let signal = lfo(name, rate);
+            let (lo, hi) = extremes(signal.as_ref(), 2.0);
+            assert!((0.0..0.01).contains(&lo), "{} min {}", name, lo);
+            assert!(hi > 0.99 && hi <= 1.0, "{} max {}", name, hi);
+            for i in 0..100 {
+                let t = 0.013 + i as f64 * 0.037;
+                let (a, b) = (signal.sample(t), signal.sample(t + 0.5));
+                assert!((a - b).abs() < 1e-4, "{} not periodic at {}", name, t);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lfo_random_holds_each_cycle() {
+        let signal = lfo("Random", 4.0);
+        let mut levels = Vec::new();
+        for cycle in 0..20 {
+            let start = cycle as f64 * 0.25;
+            let level = signal.sample(start + 0.01);
+            assert!((0.0..1.0).contains(&level));
+            assert_eq!(signal.sample(start + 0.2), level);
+            levels.push(level);
+        }
+        assert!(levels.windows(2).any(|pair| pair[0] != pair[1]));
+        assert_eq!(lfo("random", 4.0).sample(1.1), signal.sample(1.1));
+    }
+
+    #[test]
+    fn test_lfo_unknown_shape_defaults_to_sine() {
+        assert!("wobble".parse::<LfoShape>().is_err());
+        assert_eq!("SAW".parse::<LfoShape>(), Ok(LfoShape::Ramp));
+        let fallback = lfo("wobble", 1.5);
+        let sine = lfo("sine", 1.5);
+        for i in 0..50 {
+            let t = i as f64 * 0.021;
+            assert_eq!(fallback.sample(t), sine.sample(t));
+        }
+    }
+}
+
+// <FILE>src/generators/fnc_lfo.rs</FILE> - <DESC>Named LFO preset factory</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,36 +1,56 @@
 // <FILE>mixed-signals/src/generators/mod.rs</FILE> - <DESC>Oscillator generators module</DESC>
-// <VERS>VERSION: 1.3.0</VERS>
-// <WCTX>Audio synthesis primitives - arbitrary curve support</WCTX>
-// <CLOG>Added Keyframes for piecewise linear interpolation between keyframe points</CLOG>
+// <VERS>VERSION: 1.16.0</VERS>
+// <WCTX>Bounded sampling cost</WCTX>
+// <CLOG>Export CellularAutomaton rate and generation limits</CLOG>
 
 //! Oscillator and utility signal generators.
 //!
 //! Invalid inputs (NaN/Inf) are sanitized to defaults at sample time to keep
 //! outputs finite. For valid finite inputs, behavior is unchanged.
 
+#[cfg(feature = "std")]
+mod cls_cellular_automaton;
 mod cls_constant;
+mod cls_ensemble;
 mod cls_keyframes;
+#[cfg(feature = "serde")]
+mod cls_modulated_sine;
+mod cls_oscillator_bank;
 mod cls_phase_accumulator;
 mod cls_phase_sine;
 mod cls_pulse;
 mod cls_ramp;
 mod cls_sawtooth;
+mod cls_sequence;
 mod cls_sine;
 mod cls_square;
 mod cls_step;
 mod cls_triangle;
+mod cls_wavetable;
+mod fnc_lfo;
 
+#[cfg(feature = "std")]
+pub use cls_cellular_automaton::{
+    CellularAutomaton, MAX_CA_GENERATIONS, MAX_CA_STEPS_PER_SECOND, MAX_CA_WIDTH,
+};
 pub use cls_constant::Constant;
-pub use cls_keyframes::{Keyframe, Keyframes};
+pub use cls_ensemble::Ensemble;
+pub use cls_keyframes::{Boundary, Interpolation, Keyframe, Keyframes};
+#[cfg(feature = "serde")]
+pub use cls_modulated_sine::ModulatedSine;
+pub use cls_oscillator_bank::{BankWaveform, Detune, OscillatorBank, MAX_BANK_VOICES};
 pub use cls_phase_accumulator::PhaseAccumulator;
 pub use cls_phase_sine::PhaseSine;
-pub use cls_pulse::Pulse;
+pub use cls_pulse::{Pulse, PwmPulse};
 pub use cls_ramp::Ramp;
 pub use cls_sawtooth::Sawtooth;
-pub use cls_sine::Sine;
+pub use cls_sequence::Sequence;
+pub use cls_sine::{Harmonics, Sine, MAX_HARMONICS};
 pub use cls_square::Square;
 pub use cls_step::Step;
 pub use cls_triangle::Triangle;
+pub use cls_wavetable::Wavetable;
+pub use fnc_lfo::{lfo, LfoShape};
 
 // <FILE>mixed-signals/src/generators/mod.rs</FILE> - <DESC>Oscillator generators module</DESC>
-// <VERS>END OF VERSION: 1.3.0</VERS>
+// <VERS>END OF VERSION: 1.16.0</VERS>
@@ -1,7 +1,7 @@
 // <FILE>src/lib.rs</FILE> - <DESC>Signal generator library for animations, audio, games, and simulations</DESC>
-// <VERS>VERSION: 1.7.0</VERS>
-// <WCTX>Pre-launch fixes</WCTX>
-// <CLOG>Updated docs for bipolar core architecture</CLOG>
+// <VERS>VERSION: 1.18.0</VERS>
+// <WCTX>Sparklines without a TUI</WCTX>
+// <CLOG>visualization module always present; its ratatui widgets stay behind the feature</CLOG>
 
 //! # mixed-signals
 //!
@@ -37,7 +37,7 @@
 //! ## Signal Categories
 //!
 //! - **Generators**: Sine, Triangle, Square, Sawtooth, Pulse, Step, Ramp, Constant, Keyframes
-//! - **Noise**: WhiteNoise, PerlinNoise, PinkNoise, CorrelatedNoise, SpatialNoise
+//! - **Noise**: WhiteNoise, PerlinNoise, SimplexNoise, ValueNoise, WorleyNoise, PinkNoise, CorrelatedNoise, SpatialNoise
 //! - **Random**: GaussianNoise, PoissonNoise, PerCharacterNoise, ImpulseNoise, StudentTNoise
 //! - **Envelopes**: ADSR, Linear, Impact
 //! - **Physics**: DampedSpring, BouncingDrop, FrictionDecay, Pendulum, Orbit, Projectile, Attractor
@@ -72,12 +72,15 @@
 //! Use [`rng::Rng`] for traditional random number generation:
 //!
 //! ```rust
+//! # #[cfg(feature = "chacha")]
+//! # {
 //! use mixed_signals::rng::Rng;
 //!
 //! let mut rng = Rng::with_seed(42);
 //! let dice = rng.uniform(1.0, 7.0).floor() as i32; // 1-6
 //! let hit = rng.chance(0.7); // 70% probability
 //! let color = rng.choose(&["red", "green", "blue"]);
+//! # }
 //! ```
 //!
 //! ## Context-Aware Signals
@@ -85,33 +88,67 @@
 //! Signals can use runtime context for deterministic, reproducible behavior:
 //!
 //! ```rust
+//! # #[cfg(feature = "chacha")]
+//! # {
 //! use mixed_signals::prelude::*;
 //!
 //! let noise = PerCharacterNoise::with_seed(99);
 //! let ctx = SignalContext::new(100, 42).with_char_index(5);
 //! // Same context always produces same value
 //! let value = noise.sample_with_context(0.5, &ctx);
+//! # }
 //! ```
+//!
+//! ## Feature Flags
+//!
+//! - `std` (default): without it the crate is `#![no_std]` and needs only
+//!   `alloc`; float math goes through `libm`. Stateful signals that keep
+//!   their state behind a `Mutex` (filters, `SlewLimit`, `TrueFrequencyMod`,
+//!   ...) require `std`.
+//! - `chacha` (default, implies `std`): ChaCha8-based random signals,
+//!   [`rng`], [`shuffle`] and [`noise::WhiteNoise`]. The SplitMix64 `Fast*`
+//!   variants are always available.
+//! - `serde` (default, implies `chacha`): `Serialize`/`Deserialize` derives
+//!   and [`types::SignalSpec`].
+//! - `visualization`, `audio`, `wav`, `realtime-audio`: optional integrations.
+//!   [`visualization::sparkline`] needs no feature; the `visualization`
+//!   feature adds the ratatui widgets.
+//!
+//! Stateless generators, envelopes, easing, physics and the `Fast*` noise
+//! build with `cargo build --no-default-features`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+pub mod analysis;
+#[cfg(feature = "audio")]
+pub mod audio;
 pub mod composition;
 pub mod core;
 pub mod easing;
 pub mod envelopes;
+#[cfg(feature = "wav")]
+pub mod export;
 pub mod generators;
 pub mod math;
 pub mod noise;
 pub mod physics;
 pub mod processing;
 pub mod random;
+#[cfg(feature = "chacha")]
 pub mod rng;
+#[cfg(feature = "chacha")]
 pub mod shuffle;
 pub mod traits;
+#[cfg(feature = "serde")]
 pub mod types;
-#[cfg(feature = "visualization")]
 pub mod visualization;
 pub mod prelude {
     //! Convenient re-exports for common usage.
     pub use crate::composition::*;
-    pub use crate::easing::{ease, EasingType};
+    pub use crate::easing::{ease, ease_with, CubicBezier, EaseParams, EasingType};
     pub use crate::envelopes::*;
     pub use crate::generators::*;
     pub use crate::math::{
@@ -121,12 +158,16 @@ pub mod prelude {
     pub use crate::physics::*;
     pub use crate::processing::*;
     pub use crate::random::*;
+    #[cfg(feature = "chacha")]
     pub use crate::rng::Rng;
-    pub use crate::traits::{Phase, Signal, SignalContext, SignalExt, SignalRange, SignalTime};
-    pub use crate::types::{SignalOrFloat, SignalSpec};
+    pub use crate::traits::{
+        Phase, Processor, Signal, SignalContext, SignalExt, SignalRange, SignalTime, StereoSignal,
+    };
+    #[cfg(feature = "serde")]
+    pub use crate::types::{SignalOrFloat, SignalSpec, SpecRegistry};
     #[cfg(feature = "visualization")]
     pub use crate::visualization::{RenderMode, SignalView};
 }
 
 // <FILE>src/lib.rs</FILE> - <DESC>Signal generator library for animations, audio, games, and simulations</DESC>
-// <VERS>END OF VERSION: 1.7.0</VERS>
+// <VERS>END OF VERSION: 1.18.0</VERS>
@@ -0,0 +1,224 @@
+// <FILE>mixed-signals/src/math/ext_float.rs</FILE> - <DESC>libm-backed float methods for no_std builds</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Initial creation - FloatExt mirroring the std float methods the crate uses</CLOG>
+
+/// The `std`-only float methods the crate calls, implemented with `libm`.
+///
+/// Without the `std` feature, f32/f64 have no `sin`, `sqrt`, `floor`, ...
+/// methods. Modules that need them import this trait under
+/// `#[cfg(not(any(feature = "std", test)))]` so the call sites read the same
+/// in both builds; with `std` the inherent methods are used unchanged.
+/// Results can differ from std in the last bit on some platforms.
+#[allow(dead_code)] // Full surface kept so new call sites need no trait edits
+pub(crate) trait FloatExt: Sized {
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn exp_m1(self) -> Self;
+    fn ln(self) -> Self;
+    fn ln_1p(self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
+
+macro_rules! impl_float_ext {
+    ($t:ty, $sin:ident, $cos:ident, $tan:ident, $asin:ident, $acos:ident, $atan:ident,
+     $atan2:ident, $sinh:ident, $cosh:ident, $tanh:ident, $exp:ident, $exp2:ident,
+     $expm1:ident, $log:ident, $log1p:ident, $log2:ident, $log10:ident, $pow:ident,
+     $sqrt:ident, $cbrt:ident, $hypot:ident, $floor:ident, $ceil:ident, $round:ident,
+     $trunc:ident, $fma:ident, $fabs:ident) => {
+        impl FloatExt for $t {
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+            fn cos(self) -> Self {
+                libm::$cos(self)
+            }
+            fn tan(self) -> Self {
+                libm::$tan(self)
+            }
+            fn sin_cos(self) -> (Self, Self) {
+                (libm::$sin(self), libm::$cos(self))
+            }
+            fn asin(self) -> Self {
+                libm::$asin(self)
+            }
+            fn acos(self) -> Self {
+                libm::$acos(self)
+            }
+            fn atan(self) -> Self {
+                libm::$atan(self)
+            }
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+            fn sinh(self) -> Self {
+                libm::$sinh(self)
+            }
+            fn cosh(self) -> Self {
+                libm::$cosh(self)
+            }
+            fn tanh(self) -> Self {
+                libm::$tanh(self)
+            }
+            fn exp(self) -> Self {
+                libm::$exp(self)
+            }
+            fn exp2(self) -> Self {
+                libm::$exp2(self)
+            }
+            fn exp_m1(self) -> Self {
+                libm::$expm1(self)
+            }
+            fn ln(self) -> Self {
+                libm::$log(self)
+            }
+            fn ln_1p(self) -> Self {
+                libm::$log1p(self)
+            }
+            fn log2(self) -> Self {
+                libm::$log2(self)
+            }
+            fn log10(self) -> Self {
+                libm::$log10(self)
+            }
+            fn powf(self, n: Self) -> Self {
+                libm::$pow(self, n)
+            }
+            fn powi(self, n: i32) -> Self {
+                // Square-and-multiply, like the std intrinsic
+                let mut base = self;
+                let mut exp = n.unsigned_abs();
+                let mut acc: Self = 1.0;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        acc *= base;
+                    }
+                    base *= base;
+                    exp >>= 1;
+                }
+                if n < 0 {
+                    1.0 / acc
+                } else {
+                    acc
+                }
+            }
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+            fn cbrt(self) -> Self {
+                libm::$cbrt(self)
+            }
+            fn hypot(self, other: Self) -> Self {
+                libm::$hypot(self, other)
+            }
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+            fn ceil(self) -> Self {
+                libm::$ceil(self)
+            }
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+            fn trunc(self) -> Self {
+                libm::$trunc(self)
+            }
+            fn fract(self) -> Self {
+                self - libm::$trunc(self)
+            }
+            fn rem_euclid(self, rhs: Self) -> Self {
+                let r = self % rhs;
+                if r < 0.0 {
+                    r + libm::$fabs(rhs)
+                } else {
+                    r
+                }
+            }
+            fn div_euclid(self, rhs: Self) -> Self {
+                let q = libm::$trunc(self / rhs);
+                if self % rhs < 0.0 {
+                    if rhs > 0.0 {
+                        q - 1.0
+                    } else {
+                        q + 1.0
+                    }
+                } else {
+                    q
+                }
+            }
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                libm::$fma(self, a, b)
+            }
+        }
+    };
+}
+
+impl_float_ext!(
+    f32, sinf, cosf, tanf, asinf, acosf, atanf, atan2f, sinhf, coshf, tanhf, expf, exp2f, expm1f,
+    logf, log1pf, log2f, log10f, powf, sqrtf, cbrtf, hypotf, floorf, ceilf, roundf, truncf, fmaf,
+    fabsf
+);
+impl_float_ext!(
+    f64, sin, cos, tan, asin, acos, atan, atan2, sinh, cosh, tanh, exp, exp2, expm1, log, log1p,
+    log2, log10, pow, sqrt, cbrt, hypot, floor, ceil, round, trunc, fma, fabs
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float_ext_matches_std() {
+        for x in [-2.75f64, -0.5, 0.0, 0.3, 1.0, 7.25] {
+            assert!((FloatExt::sin(x) - x.sin()).abs() < 1e-12);
+            assert!((FloatExt::exp(x) - x.exp()).abs() < 1e-9);
+            assert_eq!(FloatExt::floor(x), x.floor());
+            assert_eq!(FloatExt::round(x), x.round());
+            assert_eq!(FloatExt::fract(x), x.fract());
+            assert_eq!(FloatExt::rem_euclid(x, 0.75), x.rem_euclid(0.75));
+            assert_eq!(FloatExt::div_euclid(x, -0.75), x.div_euclid(-0.75));
+            let y = x as f32;
+            assert!((FloatExt::cos(y) - y.cos()).abs() < 1e-6);
+            assert_eq!(FloatExt::trunc(y), y.trunc());
+            assert_eq!(FloatExt::rem_euclid(y, 2.0), y.rem_euclid(2.0));
+        }
+    }
+
+    #[test]
+    fn test_float_ext_powi() {
+        for n in [-3, -1, 0, 1, 2, 5] {
+            assert!((FloatExt::powi(1.5f32, n) - 1.5f32.powi(n)).abs() < 1e-6);
+            assert!((FloatExt::powi(-2.0f64, n) - (-2.0f64).powi(n)).abs() < 1e-12);
+        }
+        assert_eq!(FloatExt::sqrt(9.0f32), 3.0);
+        assert_eq!(FloatExt::powf(2.0f64, 10.0), 1024.0);
+    }
+}
+
+// <FILE>mixed-signals/src/math/ext_float.rs</FILE> - <DESC>libm-backed float methods for no_std builds</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -1,9 +1,9 @@
 // <FILE>src/math/fnc_cpu_features.rs</FILE> - <DESC>CPU feature detection for SIMD optimizations</DESC>
-// <VERS>VERSION: 1.0.1</VERS>
-// <WCTX>Clippy fixes</WCTX>
-// <CLOG>Use derive(Default) instead of manual impl</CLOG>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Core:: instead of std:: paths</CLOG>
 
-use std::sync::atomic::{AtomicU8, Ordering};
+use core::sync::atomic::{AtomicU8, Ordering};
 
 /// CPU feature flags detected at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -26,7 +26,8 @@ static mut CACHED_FEATURES: CpuFeatures = CpuFeatures {
 /// Detect CPU features at runtime.
 ///
 /// Results are cached after first call for efficiency.
-/// Uses `is_x86_feature_detected!` macro on x86_64 targets.
+/// Uses `is_x86_feature_detected!` macro on x86_64 targets. Without the
+/// `std` feature nothing is detected and the scalar paths are used.
 pub fn detect_cpu_features() -> CpuFeatures {
     // Check if we've already detected features
     if FEATURES_CHECKED.load(Ordering::Relaxed) != 0 {
@@ -46,7 +47,7 @@ pub fn detect_cpu_features() -> CpuFeatures {
     features
 }
 
-#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[cfg(all(feature = "std", target_arch = "x86_64", target_feature = "sse2"))]
 fn detect_features_impl() -> CpuFeatures {
     CpuFeatures {
         avx2: std::arch::is_x86_feature_detected!("avx2"),
@@ -56,7 +57,7 @@ fn detect_features_impl() -> CpuFeatures {
     }
 }
 
-#[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+#[cfg(not(all(feature = "std", target_arch = "x86_64", target_feature = "sse2")))]
 fn detect_features_impl() -> CpuFeatures {
     CpuFeatures::default()
 }
@@ -105,4 +106,4 @@ mod tests {
 }
 
 // <FILE>src/math/fnc_cpu_features.rs</FILE> - <DESC>CPU feature detection for SIMD optimizations</DESC>
-// <VERS>END OF VERSION: 1.0.1</VERS>
+// <VERS>END OF VERSION: 1.1.0</VERS>
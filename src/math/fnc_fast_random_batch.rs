@@ -1,14 +1,15 @@
 // <FILE>src/math/fnc_fast_random_batch.rs</FILE> - <DESC>Batch random generation with SIMD when available</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>RNG performance optimization</WCTX>
-// <CLOG>Initial creation - AVX2 batch random with scalar fallback</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Core:: instead of std:: paths</CLOG>
 
 use super::fnc_fast_random::fast_random;
 
 /// Generate a batch of random f32 values.
 ///
-/// When AVX2 is available, processes 8 values at a time for ~4x throughput.
-/// Falls back to scalar fast_random when AVX2 is not available.
+/// On x86_64 CPUs where [`detect_cpu_features`](super::detect_cpu_features)
+/// reports AVX2, hashes 4 values per instruction; elsewhere falls back to
+/// scalar [`fast_random`]. Both paths produce bit-identical output.
 ///
 /// # Arguments
 /// * `seed` - Base seed for determinism
@@ -24,10 +25,10 @@ use super::fnc_fast_random::fast_random;
 /// ```
 #[inline]
 pub fn fast_random_batch(seed: u64, start_input: u64, output: &mut [f32]) {
-    #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+    #[cfg(target_arch = "x86_64")]
     {
         if super::fnc_cpu_features::has_avx2() {
-            // SAFETY: AVX2 is confirmed available
+            // SAFETY: AVX2 is confirmed available at runtime
             unsafe { fast_random_batch_avx2(seed, start_input, output) };
             return;
         }
@@ -39,17 +40,17 @@ pub fn fast_random_batch(seed: u64, start_input: u64, output: &mut [f32]) {
 
 /// Scalar implementation - always available
 #[inline]
-fn fast_random_batch_scalar(seed: u64, start_input: u64, output: &mut [f32]) {
+pub(crate) fn fast_random_batch_scalar(seed: u64, start_input: u64, output: &mut [f32]) {
     for (i, slot) in output.iter_mut().enumerate() {
         *slot = fast_random(seed, start_input.wrapping_add(i as u64));
     }
 }
 
 /// AVX2 implementation - processes 4 u64s at a time
-#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+#[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx2")]
 unsafe fn fast_random_batch_avx2(seed: u64, start_input: u64, output: &mut [f32]) {
-    use std::arch::x86_64::*;
+    use core::arch::x86_64::*;
 
     let len = output.len();
     let chunks = len / 4;
@@ -64,9 +65,9 @@ unsafe fn fast_random_batch_avx2(seed: u64, start_input: u64, output: &mut [f32]
     for chunk in 0..chunks {
         let base = start_input.wrapping_add((chunk * 4) as u64);
         let inputs = _mm256_set_epi64x(
-            (base + 3) as i64,
-            (base + 2) as i64,
-            (base + 1) as i64,
+            base.wrapping_add(3) as i64,
+            base.wrapping_add(2) as i64,
+            base.wrapping_add(1) as i64,
             base as i64,
         );
 
@@ -106,20 +107,20 @@ unsafe fn fast_random_batch_avx2(seed: u64, start_input: u64, output: &mut [f32]
 
     // Handle remaining elements with scalar
     let remainder_start = chunks * 4;
-    for i in remainder_start..len {
-        output[i] = fast_random(seed, start_input.wrapping_add(i as u64));
+    for (i, slot) in output.iter_mut().enumerate().skip(remainder_start) {
+        *slot = fast_random(seed, start_input.wrapping_add(i as u64));
     }
 }
 
 /// AVX2 64-bit multiply (emulated since AVX2 doesn't have native 64-bit mul)
-#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+#[cfg(target_arch = "x86_64")]
 #[inline]
 #[target_feature(enable = "avx2")]
 unsafe fn avx2_mul64(
-    a: std::arch::x86_64::__m256i,
-    b: std::arch::x86_64::__m256i,
-) -> std::arch::x86_64::__m256i {
-    use std::arch::x86_64::*;
+    a: core::arch::x86_64::__m256i,
+    b: core::arch::x86_64::__m256i,
+) -> core::arch::x86_64::__m256i {
+    use core::arch::x86_64::*;
 
     // 64-bit multiply using 32-bit parts:
     // (a_lo + a_hi * 2^32) * (b_lo + b_hi * 2^32)
@@ -170,6 +171,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_batch_paths_bit_identical() {
+        let mut scalar = [0.0f32; 37];
+        fast_random_batch_scalar(7, u64::MAX - 20, &mut scalar);
+        #[cfg(target_arch = "x86_64")]
+        if crate::math::has_avx2() {
+            let mut simd = [0.0f32; 37];
+            // SAFETY: AVX2 checked above
+            unsafe { fast_random_batch_avx2(7, u64::MAX - 20, &mut simd) };
+            assert!(simd
+                .iter()
+                .zip(&scalar)
+                .all(|(a, b)| a.to_bits() == b.to_bits()));
+        }
+        let mut dispatched = [0.0f32; 37];
+        fast_random_batch(7, u64::MAX - 20, &mut dispatched);
+        assert!(dispatched
+            .iter()
+            .zip(&scalar)
+            .all(|(a, b)| a.to_bits() == b.to_bits()));
+    }
+
     #[test]
     fn test_batch_range() {
         let mut out = [0.0f32; 100];
@@ -207,4 +230,4 @@ mod tests {
 }
 
 // <FILE>src/math/fnc_fast_random_batch.rs</FILE> - <DESC>Batch random generation with SIMD when available</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -1,9 +1,11 @@
 // <FILE>src/math/fnc_harmonic.rs</FILE> - <DESC>Harmonic phase helpers for trig stability</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Deduplication refactor</WCTX>
-// <CLOG>Initial implementation - extracted from physics solvers</CLOG>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
 
-use std::f64::consts::TAU;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use core::f64::consts::TAU;
 
 /// Compute sin and cos of harmonic phase with stability for large t.
 ///
@@ -26,7 +28,7 @@ use std::f64::consts::TAU;
 /// use mixed_signals::math::harmonic_sin_cos;
 ///
 /// // 1 Hz oscillation at t=0.25s (quarter cycle)
-/// let omega = std::f64::consts::TAU; // 2π rad/s = 1 Hz
+/// let omega = core::f64::consts::TAU; // 2π rad/s = 1 Hz
 /// let (sin, cos) = harmonic_sin_cos(omega, 0.25, 0.0);
 /// assert!((sin - 1.0).abs() < 0.001); // sin(π/2) = 1
 /// assert!((cos - 0.0).abs() < 0.001); // cos(π/2) = 0
@@ -58,7 +60,7 @@ pub fn harmonic_phase(omega: f64, t: f64, phase: f64) -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::f64::consts::PI;
+    use core::f64::consts::PI;
 
     #[test]
     fn test_harmonic_sin_cos_zero() {
@@ -106,4 +108,4 @@ mod tests {
 }
 
 // <FILE>src/math/fnc_harmonic.rs</FILE> - <DESC>Harmonic phase helpers for trig stability</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.1.0</VERS>
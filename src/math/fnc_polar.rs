@@ -0,0 +1,112 @@
+// <FILE>src/math/fnc_polar.rs</FILE> - <DESC>Polar coordinate and radial falloff helpers</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+
+/// Convert cartesian `(x, y)` to polar `(r, theta)`.
+///
+/// `theta` is in radians in `(-π, π]`, measured counter-clockwise from +x.
+/// The origin maps to `(0.0, 0.0)`.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::math::cartesian_to_polar;
+///
+/// let (r, theta) = cartesian_to_polar(0.0, 2.0);
+/// assert!((r - 2.0).abs() < 1e-12);
+/// assert!((theta - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn cartesian_to_polar(x: f64, y: f64) -> (f64, f64) {
+    (x.hypot(y), y.atan2(x))
+}
+
+/// Convert polar `(r, theta)` (theta in radians) to cartesian `(x, y)`.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::math::polar_to_cartesian;
+///
+/// let (x, y) = polar_to_cartesian(1.0, core::f64::consts::PI);
+/// assert!((x + 1.0).abs() < 1e-12);
+/// assert!(y.abs() < 1e-12);
+/// ```
+#[inline]
+pub fn polar_to_cartesian(r: f64, theta: f64) -> (f64, f64) {
+    let (sin, cos) = theta.sin_cos();
+    (r * cos, r * sin)
+}
+
+/// Linear falloff from 1.0 at `distance = 0` to 0.0 at `|distance| >= width`.
+///
+/// Distance is taken as an absolute value, so signed offsets work directly.
+/// Returns 0.0 for non-positive or non-finite widths and non-finite distances.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::math::radial_falloff;
+///
+/// assert_eq!(radial_falloff(0.0, 0.2), 1.0);
+/// assert!((radial_falloff(-0.1, 0.2) - 0.5).abs() < 1e-12);
+/// assert_eq!(radial_falloff(0.3, 0.2), 0.0);
+/// ```
+#[inline]
+pub fn radial_falloff(distance: f64, width: f64) -> f64 {
+    if !width.is_finite() || width <= 0.0 || !distance.is_finite() {
+        return 0.0;
+    }
+    (1.0 - distance.abs() / width).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_polar_round_trip() {
+        for &(x, y) in &[(1.0, 0.0), (0.3, -0.7), (-2.5, 1.25), (-1.0, -1.0)] {
+            let (r, theta) = cartesian_to_polar(x, y);
+            let (x2, y2) = polar_to_cartesian(r, theta);
+            assert!((x - x2).abs() < 1e-12 && (y - y2).abs() < 1e-12);
+        }
+        for i in 0..16 {
+            let theta = -PI + 0.1 + i as f64 * 0.39;
+            let (x, y) = polar_to_cartesian(1.5, theta);
+            let (r, theta2) = cartesian_to_polar(x, y);
+            assert!((r - 1.5).abs() < 1e-12);
+            assert!((theta - theta2).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cartesian_to_polar_origin() {
+        assert_eq!(cartesian_to_polar(0.0, 0.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_radial_falloff_center_and_beyond_width() {
+        assert_eq!(radial_falloff(0.0, 0.5), 1.0);
+        assert_eq!(radial_falloff(0.5, 0.5), 0.0);
+        assert_eq!(radial_falloff(0.75, 0.5), 0.0);
+        assert_eq!(radial_falloff(-0.75, 0.5), 0.0);
+        assert!((radial_falloff(0.125, 0.5) - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_radial_falloff_invalid_width() {
+        assert_eq!(radial_falloff(0.0, 0.0), 0.0);
+        assert_eq!(radial_falloff(0.0, -1.0), 0.0);
+        assert_eq!(radial_falloff(0.0, f64::NAN), 0.0);
+        assert_eq!(radial_falloff(f64::NAN, 1.0), 0.0);
+    }
+}
+
+// <FILE>src/math/fnc_polar.rs</FILE> - <DESC>Polar coordinate and radial falloff helpers</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -0,0 +1,114 @@
+// <FILE>src/math/fnc_signal_fingerprint.rs</FILE> - <DESC>Deterministic hash of sampled signal output</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Core:: instead of std:: paths</CLOG>
+
+use crate::traits::{Signal, SignalTime};
+use core::ops::Range;
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Hash a signal's sampled output into a stable 64-bit fingerprint.
+///
+/// Samples `samples` evenly spaced points over `time_range` (end exclusive)
+/// and hashes the raw f32 bits with FNV-1a. The hash does not depend on
+/// `std`'s hasher, so fingerprints are stable across Rust versions and can be
+/// snapshotted in CI to catch accidental changes in generator output.
+///
+/// `-0.0` is hashed as `0.0` and all NaNs hash identically.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::math::signal_fingerprint;
+///
+/// let a = signal_fingerprint(&Sine::with_frequency(2.0), 0.0..1.0, 256);
+/// let b = signal_fingerprint(&Sine::with_frequency(2.0), 0.0..1.0, 256);
+/// assert_eq!(a, b);
+/// ```
+pub fn signal_fingerprint(
+    signal: &dyn Signal,
+    time_range: Range<SignalTime>,
+    samples: usize,
+) -> u64 {
+    let start = time_range.start;
+    let span = time_range.end - time_range.start;
+
+    let mut hash = FNV_OFFSET;
+    for i in 0..samples {
+        let t = start + span * (i as SignalTime / samples as SignalTime);
+        let value = signal.sample(t);
+        let bits = if value.is_nan() {
+            f32::NAN.to_bits()
+        } else if value == 0.0 {
+            0.0f32.to_bits()
+        } else {
+            value.to_bits()
+        };
+        for byte in bits.to_le_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+
+    #[test]
+    fn test_fingerprint_stable_for_sine() {
+        let sine = Sine::new(2.0, 0.5, 0.1, 0.25);
+        let a = signal_fingerprint(&sine, 0.0..2.0, 512);
+        let b = signal_fingerprint(&sine, 0.0..2.0, 512);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_parameters() {
+        let base = signal_fingerprint(&Sine::new(2.0, 0.5, 0.1, 0.25), 0.0..2.0, 512);
+        let freq = signal_fingerprint(&Sine::new(2.1, 0.5, 0.1, 0.25), 0.0..2.0, 512);
+        let amp = signal_fingerprint(&Sine::new(2.0, 0.6, 0.1, 0.25), 0.0..2.0, 512);
+        let phase = signal_fingerprint(&Sine::new(2.0, 0.5, 0.1, 0.3), 0.0..2.0, 512);
+        assert_ne!(base, freq);
+        assert_ne!(base, amp);
+        assert_ne!(base, phase);
+    }
+
+    #[test]
+    fn test_fingerprint_depends_on_range_and_count() {
+        let sine = Sine::default();
+        let base = signal_fingerprint(&sine, 0.0..1.0, 100);
+        assert_ne!(base, signal_fingerprint(&sine, 0.0..0.5, 100));
+        assert_ne!(base, signal_fingerprint(&sine, 0.0..1.0, 101));
+    }
+
+    #[test]
+    fn test_fingerprint_signed_zero_and_nan() {
+        let pos = signal_fingerprint(&Constant::new(0.0), 0.0..1.0, 8);
+        let neg = signal_fingerprint(&Constant::new(-0.0), 0.0..1.0, 8);
+        assert_eq!(pos, neg);
+    }
+
+    #[test]
+    fn test_fingerprint_empty() {
+        assert_eq!(
+            signal_fingerprint(&Sine::default(), 0.0..1.0, 0),
+            FNV_OFFSET
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_snapshot() {
+        // Snapshot of Sine output; update only for intentional output changes
+        let sine = Sine::new(2.0, 0.5, 0.1, 0.25);
+        assert_eq!(signal_fingerprint(&sine, 0.0..2.0, 512), 0x7e7c92fb0563ba05);
+    }
+}
+
+// <FILE>src/math/fnc_signal_fingerprint.rs</FILE> - <DESC>Deterministic hash of sampled signal output</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,25 +1,36 @@
 // <FILE>mixed-signals/src/math/mod.rs</FILE> - <DESC>Mathematical utilities module</DESC>
-// <VERS>VERSION: 1.3.0</VERS>
-// <WCTX>Deduplication refactor</WCTX>
-// <CLOG>Added harmonic_sin_cos and harmonic_phase for physics trig stability</CLOG>
+// <VERS>VERSION: 1.8.0</VERS>
+// <WCTX>Order-independent phase accumulation</WCTX>
+// <CLOG>Added integrate (composite Simpson)</CLOG>
 
+#[cfg(not(feature = "std"))]
+mod ext_float;
 pub mod fnc_cpu_features;
 pub mod fnc_cubic_bezier;
 mod fnc_derive_seed;
 pub mod fnc_fast_random;
 pub mod fnc_fast_random_batch;
 mod fnc_harmonic;
+mod fnc_integrate;
+mod fnc_polar;
 pub mod fnc_quadratic_bezier;
 mod fnc_sanitize;
+mod fnc_signal_fingerprint;
 
+#[cfg(not(any(feature = "std", test)))]
+pub(crate) use ext_float::FloatExt;
 pub use fnc_cpu_features::{detect_cpu_features, has_avx2, has_fma, CpuFeatures};
 pub use fnc_cubic_bezier::{bezier_x, bezier_x_derivative, bezier_y, solve_bezier};
 pub(crate) use fnc_derive_seed::derive_seed;
 pub use fnc_fast_random::fast_random;
 pub use fnc_fast_random_batch::fast_random_batch;
+pub(crate) use fnc_fast_random_batch::fast_random_batch_scalar;
 pub use fnc_harmonic::{harmonic_phase, harmonic_sin_cos};
+pub use fnc_integrate::{integrate, INTEGRAL_PANELS_PER_SECOND, MAX_INTEGRAL_PANELS};
+pub use fnc_polar::{cartesian_to_polar, polar_to_cartesian, radial_falloff};
 pub use fnc_quadratic_bezier::quadratic_bezier;
 pub(crate) use fnc_sanitize::{finite_or, finite_or_clamp, finite_or_f64, finite_or_min};
+pub use fnc_signal_fingerprint::signal_fingerprint;
 
 // <FILE>mixed-signals/src/math/mod.rs</FILE> - <DESC>Mathematical utilities module</DESC>
-// <VERS>END OF VERSION: 1.3.0</VERS>
+// <VERS>END OF VERSION: 1.8.0</VERS>
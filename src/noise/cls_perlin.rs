@@ -1,10 +1,13 @@
 // <FILE>mixed-signals/src/noise/cls_perlin.rs</FILE> - <DESC>Perlin-like smooth noise generator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor</WCTX>
-// <CLOG>Converted to bipolar [-1,1] output, added output_range()</CLOG>
+// <VERS>VERSION: 2.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std</CLOG>
 
-use crate::math::{finite_or, finite_or_f64};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{derive_seed, finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Smooth coherent noise generator (simplified Perlin-like).
@@ -12,7 +15,12 @@ use serde::{Deserialize, Serialize};
 /// Produces smooth, continuous noise by interpolating between
 /// random values at integer time points.
 /// Output is bipolar [-amplitude, +amplitude] centered at offset.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+///
+/// With multiple octaves, each octave after the first draws its lattice from an
+/// independent seed (`derive_seed(seed, octave)`) so layers don't line up into
+/// visible structure. Octave frequencies grow by `lacunarity` (default 2.0).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PerlinNoise {
     /// Seed for reproducible randomness
     seed: u64,
@@ -26,6 +34,22 @@ pub struct PerlinNoise {
     octaves: u8,
     /// Persistence for octave amplitude decay (typically 0.5)
     persistence: f32,
+    /// Derive an independent seed per octave (default true)
+    #[cfg_attr(feature = "serde", serde(default = "default_octave_seeds"))]
+    octave_seeds: bool,
+    /// Frequency multiplier between octaves (typically 2.0)
+    #[cfg_attr(feature = "serde", serde(default = "default_lacunarity"))]
+    lacunarity: f32,
+}
+
+#[cfg(feature = "serde")]
+fn default_octave_seeds() -> bool {
+    true
+}
+
+#[cfg(feature = "serde")]
+fn default_lacunarity() -> f32 {
+    2.0
 }
 
 impl PerlinNoise {
@@ -37,6 +61,8 @@ impl PerlinNoise {
             offset: 0.0,
             octaves: 1,
             persistence: 0.5,
+            octave_seeds: true,
+            lacunarity: 2.0,
         }
     }
 
@@ -49,6 +75,8 @@ impl PerlinNoise {
             offset,
             octaves: 1,
             persistence: 0.5,
+            octave_seeds: true,
+            lacunarity: 2.0,
         }
     }
 
@@ -62,6 +90,23 @@ impl PerlinNoise {
         self
     }
 
+    /// Toggle independent per-octave seeds (default on).
+    ///
+    /// When off, every octave samples the same lattice (shared phase), which can
+    /// produce visible structure where octaves align.
+    pub fn with_octave_seeds(mut self, enabled: bool) -> Self {
+        self.octave_seeds = enabled;
+        self
+    }
+
+    /// Set the frequency multiplier between octaves (default 2.0).
+    ///
+    /// Non-finite or non-positive values fall back to 2.0.
+    pub fn with_lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = sanitize_lacunarity(lacunarity);
+        self
+    }
+
     pub fn seed(&self) -> u64 {
         self.seed
     }
@@ -85,6 +130,34 @@ impl PerlinNoise {
     pub fn persistence(&self) -> f32 {
         self.persistence
     }
+
+    pub fn octave_seeds(&self) -> bool {
+        self.octave_seeds
+    }
+
+    pub fn lacunarity(&self) -> f32 {
+        self.lacunarity
+    }
+
+    /// Lattice seed for an octave.
+    ///
+    /// Octave 0 always uses the base seed, so single-octave output doesn't
+    /// depend on the octave seed setting.
+    fn octave_seed(&self, octave: u8) -> u64 {
+        if !self.octave_seeds || octave == 0 {
+            return self.seed;
+        }
+        let bytes = derive_seed(self.seed, octave as u64);
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+fn sanitize_lacunarity(lacunarity: f32) -> f32 {
+    if lacunarity.is_finite() && lacunarity > 0.0 {
+        lacunarity
+    } else {
+        2.0
+    }
 }
 
 impl Default for PerlinNoise {
@@ -96,6 +169,8 @@ impl Default for PerlinNoise {
             offset: 0.0,
             octaves: 1,
             persistence: 0.5,
+            octave_seeds: true,
+            lacunarity: 2.0,
         }
     }
 }
@@ -147,6 +222,7 @@ impl Signal for PerlinNoise {
         let offset = finite_or(self.offset, 0.0) as f64;
         let persistence = finite_or(self.persistence, 0.5) as f64;
         let octaves = self.octaves.max(1);
+        let lacunarity = sanitize_lacunarity(self.lacunarity) as f64;
 
         let mut total: f64 = 0.0;
         let mut frequency = scale;
@@ -154,11 +230,10 @@ impl Signal for PerlinNoise {
         let mut max_value: f64 = 0.0;
 
         for i in 0..octaves {
-            let octave_seed = self.seed.wrapping_add(i as u64 * 31337);
-            total += noise_1d(octave_seed, t * frequency) * amplitude;
+            total += noise_1d(self.octave_seed(i), t * frequency) * amplitude;
             max_value += amplitude;
             amplitude *= persistence;
-            frequency *= 2.0;
+            frequency *= lacunarity;
         }
 
         // noise_1d returns [-1, 1], total/max_value is also [-1, 1]
@@ -228,6 +303,8 @@ mod tests {
             offset: 0.0,
             octaves: 0,
             persistence: 0.5,
+            octave_seeds: true,
+            lacunarity: 2.0,
         };
         let v = noise.sample(0.25);
         assert!(v.is_finite());
@@ -246,7 +323,84 @@ mod tests {
         assert!((range2.min - (-0.25)).abs() < 0.001);
         assert!((range2.max - 0.75).abs() < 0.001);
     }
+
+    /// Pearson correlation between two octave layers over many samples.
+    fn layer_correlation(noise: &PerlinNoise, a: u8, b: u8) -> f64 {
+        let lacunarity = noise.lacunarity() as f64;
+        let layer = |octave: u8, t: f64| {
+            noise_1d(
+                noise.octave_seed(octave),
+                t * lacunarity.powi(octave as i32),
+            )
+        };
+        let n = 4000;
+        let (xs, ys): (Vec<f64>, Vec<f64>) = (0..n)
+            .map(|i| {
+                let t = i as f64 * 0.037;
+                (layer(a, t), layer(b, t))
+            })
+            .unzip();
+        let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+        let (mx, my) = (mean(&xs), mean(&ys));
+        let cov: f64 = xs.iter().zip(&ys).map(|(x, y)| (x - mx) * (y - my)).sum();
+        let vx: f64 = xs.iter().map(|x| (x - mx).powi(2)).sum();
+        let vy: f64 = ys.iter().map(|y| (y - my).powi(2)).sum();
+        cov / (vx * vy).sqrt()
+    }
+
+    #[test]
+    fn test_perlin_octave_seeds_decorrelate_layers() {
+        // Lacunarity 1 is the worst case: shared-phase layers are identical
+        let shared = PerlinNoise::with_seed(42)
+            .with_octaves(3, 0.5)
+            .with_lacunarity(1.0)
+            .with_octave_seeds(false);
+        let independent = shared.with_octave_seeds(true);
+
+        let shared_corr = layer_correlation(&shared, 0, 1);
+        let independent_corr = layer_correlation(&independent, 0, 1);
+        assert!(shared_corr > 0.99, "shared {shared_corr}");
+        assert!(
+            independent_corr.abs() < 0.1,
+            "independent {independent_corr}"
+        );
+
+        for (a, b) in [(0, 2), (1, 2)] {
+            assert!(layer_correlation(&independent, a, b).abs() < shared_corr);
+        }
+    }
+
+    #[test]
+    fn test_perlin_octave_seeds_default_and_single_octave() {
+        let noise = PerlinNoise::with_seed(7);
+        assert!(noise.octave_seeds());
+        // Octave 0 always uses the base seed
+        let shared = noise.with_octave_seeds(false);
+        for i in 0..20 {
+            let t = i as f64 * 0.13;
+            assert_eq!(noise.sample(t), shared.sample(t));
+        }
+        // Multi-octave output differs once seeds diverge
+        let a = noise.with_octaves(4, 0.5);
+        let b = a.with_octave_seeds(false);
+        assert!((0..20).any(|i| a.sample(i as f64 * 0.13) != b.sample(i as f64 * 0.13)));
+    }
+
+    #[test]
+    fn test_perlin_lacunarity() {
+        let noise = PerlinNoise::with_seed(3).with_octaves(3, 0.5);
+        assert_eq!(noise.lacunarity(), 2.0);
+        assert_eq!(noise.with_lacunarity(f32::NAN).lacunarity(), 2.0);
+        assert_eq!(noise.with_lacunarity(-1.0).lacunarity(), 2.0);
+
+        let wide = noise.with_lacunarity(3.0);
+        assert_eq!(wide.lacunarity(), 3.0);
+        assert!((0..20).any(|i| wide.sample(i as f64 * 0.21) != noise.sample(i as f64 * 0.21)));
+        for i in 0..100 {
+            assert!((-1.0..=1.0).contains(&wide.sample(i as f64 * 0.07)));
+        }
+    }
 }
 
 // <FILE>mixed-signals/src/noise/cls_perlin.rs</FILE> - <DESC>Perlin-like smooth noise generator</DESC>
-// <VERS>END OF VERSION: 2.0.0</VERS>
+// <VERS>END OF VERSION: 2.2.0</VERS>
@@ -0,0 +1,301 @@
+// <FILE>mixed-signals/src/noise/cls_perlin_2d.rs</FILE> - <DESC>2D Perlin gradient noise field</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
+
+use super::PerlinPath;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{derive_seed, finite_or};
+use core::f64::consts::{SQRT_2, TAU};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Smooth 2D gradient noise (classic Perlin).
+///
+/// Each integer lattice point carries a seeded unit gradient; a sample blends
+/// the four surrounding corner contributions with the quintic fade
+/// `6t⁵ - 15t⁴ + 10t³`, so the field is C2-continuous across cell borders.
+/// Use it for terrain, clouds, or any smooth spatial texture; for a time
+/// signal, walk a path through it with [`along_line`](Self::along_line) or
+/// [`looping`](Self::looping).
+///
+/// [`sample_at`](Self::sample_at) returns values in [-amplitude, +amplitude]
+/// centered at offset. Octaves layer like [`PerlinNoise`](super::PerlinNoise),
+/// each with an independent lattice seed.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::noise::PerlinNoise2D;
+///
+/// let field = PerlinNoise2D::new(7, 0.1, 1.0).with_octaves(4, 0.5);
+/// let height = field.sample_at(12.5, -3.25);
+/// assert!((-1.0..=1.0).contains(&height));
+/// assert_eq!(height, field.sample_at(12.5, -3.25));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerlinNoise2D {
+    /// Seed for reproducible randomness
+    seed: u64,
+    /// Scale factor (lattice cells per input unit)
+    scale: f32,
+    /// Output amplitude (half of total range)
+    amplitude: f32,
+    /// Center value (offset)
+    offset: f32,
+    /// Number of octaves for fractal noise (1 = simple, more = detailed)
+    octaves: u8,
+    /// Persistence for octave amplitude decay (typically 0.5)
+    persistence: f32,
+    /// Frequency multiplier between octaves (typically 2.0)
+    lacunarity: f32,
+}
+
+impl PerlinNoise2D {
+    pub fn new(seed: u64, scale: f32, amplitude: f32) -> Self {
+        Self {
+            seed,
+            scale,
+            amplitude,
+            offset: 0.0,
+            octaves: 1,
+            persistence: 0.5,
+            lacunarity: 2.0,
+        }
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self::new(seed, 1.0, 1.0)
+    }
+
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_octaves(mut self, octaves: u8, persistence: f32) -> Self {
+        self.octaves = octaves.max(1);
+        self.persistence = persistence;
+        self
+    }
+
+    /// Set the frequency multiplier between octaves (default 2.0).
+    ///
+    /// Non-finite or non-positive values fall back to 2.0.
+    pub fn with_lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = sanitize_lacunarity(lacunarity);
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn amplitude(&self) -> f32 {
+        self.amplitude
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    pub fn octaves(&self) -> u8 {
+        self.octaves
+    }
+
+    pub fn persistence(&self) -> f32 {
+        self.persistence
+    }
+
+    pub fn lacunarity(&self) -> f32 {
+        self.lacunarity
+    }
+
+    /// Sample the field at `(x, y)`.
+    ///
+    /// Non-finite coordinates are treated as 0.
+    pub fn sample_at(&self, x: f32, y: f32) -> f32 {
+        self.sample_at_f64(finite_or(x, 0.0) as f64, finite_or(y, 0.0) as f64)
+    }
+
+    /// Signal that travels through the field along a straight line.
+    ///
+    /// At time `t` the field is sampled at `origin + t * velocity`.
+    pub fn along_line(self, origin: (f32, f32), velocity: (f32, f32)) -> PerlinPath {
+        PerlinPath::line(self, origin, velocity)
+    }
+
+    /// Periodic signal that circles through the field once every `period` seconds.
+    ///
+    /// Sampling around a circle makes the output loop seamlessly: `sample(t)`
+    /// equals `sample(t + period)`. Larger `radius` gives more variation per loop.
+    pub fn looping(self, radius: f32, period: f32) -> PerlinPath {
+        PerlinPath::circle(self, (0.0, 0.0), radius, period)
+    }
+
+    pub(crate) fn sample_at_f64(&self, x: f64, y: f64) -> f32 {
+        let scale = finite_or(self.scale, 1.0) as f64;
+        let amplitude_scale = finite_or(self.amplitude, 1.0) as f64;
+        let offset = finite_or(self.offset, 0.0) as f64;
+        let persistence = finite_or(self.persistence, 0.5) as f64;
+        let lacunarity = sanitize_lacunarity(self.lacunarity) as f64;
+
+        let mut total = 0.0;
+        let mut frequency = scale;
+        let mut amplitude = 1.0;
+        let mut max_value = 0.0;
+        for i in 0..self.octaves.max(1) {
+            total += perlin_2d(self.octave_seed(i), x * frequency, y * frequency) * amplitude;
+            max_value += amplitude;
+            amplitude *= persistence;
+            frequency *= lacunarity;
+        }
+
+        (offset + total / max_value * amplitude_scale) as f32
+    }
+
+    fn octave_seed(&self, octave: u8) -> u64 {
+        if octave == 0 {
+            return self.seed;
+        }
+        let bytes = derive_seed(self.seed, octave as u64);
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+impl Default for PerlinNoise2D {
+    fn default() -> Self {
+        Self::with_seed(0)
+    }
+}
+
+fn sanitize_lacunarity(lacunarity: f32) -> f32 {
+    if lacunarity.is_finite() && lacunarity > 0.0 {
+        lacunarity
+    } else {
+        2.0
+    }
+}
+
+/// Hash a lattice point to 64 well-mixed bits.
+fn hash_2d(seed: u64, ix: i64, iy: i64) -> u64 {
+    let mut n = seed
+        ^ (ix as u64).wrapping_mul(0x9e3779b97f4a7c15)
+        ^ (iy as u64).wrapping_mul(0xc2b2ae3d27d4eb4f);
+    n = (n ^ (n >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    n = (n ^ (n >> 27)).wrapping_mul(0x94d049bb133111eb);
+    n ^ (n >> 31)
+}
+
+/// Dot product of the lattice point's unit gradient with the offset `(dx, dy)`.
+fn corner(seed: u64, ix: i64, iy: i64, dx: f64, dy: f64) -> f64 {
+    let angle = (hash_2d(seed, ix, iy) >> 11) as f64 / (1u64 << 53) as f64 * TAU;
+    angle.cos() * dx + angle.sin() * dy
+}
+
+/// Quintic fade curve with zero first and second derivatives at 0 and 1.
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Single octave of 2D Perlin noise in [-1, 1].
+fn perlin_2d(seed: u64, x: f64, y: f64) -> f64 {
+    let (fx, fy) = (x.floor(), y.floor());
+    let (dx, dy) = (x - fx, y - fy);
+    let (ix, iy) = (fx as i64, fy as i64);
+    let (ix1, iy1) = (ix.wrapping_add(1), iy.wrapping_add(1));
+
+    let n00 = corner(seed, ix, iy, dx, dy);
+    let n10 = corner(seed, ix1, iy, dx - 1.0, dy);
+    let n01 = corner(seed, ix, iy1, dx, dy - 1.0);
+    let n11 = corner(seed, ix1, iy1, dx - 1.0, dy - 1.0);
+
+    let (u, v) = (fade(dx), fade(dy));
+    let n = lerp(lerp(n00, n10, u), lerp(n01, n11, u), v);
+    // Unit gradients bound the raw value by sqrt(2)/2
+    (n * SQRT_2).clamp(-1.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perlin_2d_deterministic_per_seed() {
+        let a = PerlinNoise2D::with_seed(42);
+        let b = PerlinNoise2D::with_seed(42);
+        let c = PerlinNoise2D::with_seed(43);
+        let points: Vec<(f32, f32)> = (0..50)
+            .map(|i| (i as f32 * 0.37, i as f32 * -0.21))
+            .collect();
+        for &(x, y) in &points {
+            assert_eq!(a.sample_at(x, y), b.sample_at(x, y));
+        }
+        assert!(points
+            .iter()
+            .any(|&(x, y)| a.sample_at(x, y) != c.sample_at(x, y)));
+    }
+
+    #[test]
+    fn test_perlin_2d_bounded_with_octaves() {
+        let noise = PerlinNoise2D::new(7, 1.3, 1.0).with_octaves(5, 0.6);
+        let mut peak = 0.0f32;
+        for i in 0..80 {
+            for j in 0..80 {
+                let v = noise.sample_at(i as f32 * 0.173 - 5.0, j as f32 * 0.119 - 3.0);
+                assert!((-1.0..=1.0).contains(&v), "{} at ({}, {})", v, i, j);
+                peak = peak.max(v.abs());
+            }
+        }
+        assert!(peak > 0.3, "peak {}", peak);
+
+        let shifted = PerlinNoise2D::new(7, 1.0, 0.5).with_offset(2.0);
+        let v = shifted.sample_at(0.4, 0.7);
+        assert!((1.5..=2.5).contains(&v));
+    }
+
+    #[test]
+    fn test_perlin_2d_continuous_across_lattice() {
+        let noise = PerlinNoise2D::with_seed(3);
+        let eps = 1e-4;
+        for k in -3..4 {
+            let edge = k as f64;
+            for j in 0..10 {
+                let other = j as f64 * 0.31 + 0.05;
+                // Crossing a vertical and a horizontal cell border
+                let dx = (noise.sample_at_f64(edge - eps, other)
+                    - noise.sample_at_f64(edge + eps, other))
+                .abs();
+                let dy = (noise.sample_at_f64(other, edge - eps)
+                    - noise.sample_at_f64(other, edge + eps))
+                .abs();
+                assert!(dx < 1e-3 && dy < 1e-3, "jump at {}: {} {}", edge, dx, dy);
+            }
+        }
+        // Gradient noise is zero at lattice points
+        assert!(noise.sample_at(2.0, -1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_perlin_2d_non_finite_inputs() {
+        let noise = PerlinNoise2D::with_seed(1).with_lacunarity(f32::NAN);
+        assert_eq!(noise.lacunarity(), 2.0);
+        assert_eq!(
+            noise.sample_at(f32::NAN, f32::INFINITY),
+            noise.sample_at(0.0, 0.0)
+        );
+    }
+}
+
+// <FILE>mixed-signals/src/noise/cls_perlin_2d.rs</FILE> - <DESC>2D Perlin gradient noise field</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -0,0 +1,283 @@
+// <FILE>mixed-signals/src/noise/cls_perlin_3d.rs</FILE> - <DESC>3D Perlin gradient noise field</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{derive_seed, finite_or};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Normalizes a single octave to [-1, 1] (unit gradients bound it by sqrt(3)/2).
+const PERLIN_3D_NORM: f64 = 1.154_700_538_379_251_5;
+
+/// Gradient directions of improved Perlin noise: the 12 cube edge midpoints.
+const GRADIENTS: [(f64, f64, f64); 12] = [
+    (1.0, 1.0, 0.0),
+    (-1.0, 1.0, 0.0),
+    (1.0, -1.0, 0.0),
+    (-1.0, -1.0, 0.0),
+    (1.0, 0.0, 1.0),
+    (-1.0, 0.0, 1.0),
+    (1.0, 0.0, -1.0),
+    (-1.0, 0.0, -1.0),
+    (0.0, 1.0, 1.0),
+    (0.0, -1.0, 1.0),
+    (0.0, 1.0, -1.0),
+    (0.0, -1.0, -1.0),
+];
+
+/// Smooth 3D gradient noise (improved Perlin).
+///
+/// The 3D counterpart of [`PerlinNoise2D`](super::PerlinNoise2D): seeded
+/// gradients at integer lattice points blended with the quintic fade. Use the
+/// third axis for volumes, or as a slow time axis to animate a 2D slice
+/// ("boiling" clouds, drifting fog).
+///
+/// [`sample_at`](Self::sample_at) returns values in [-amplitude, +amplitude]
+/// centered at offset.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::noise::PerlinNoise3D;
+///
+/// let fog = PerlinNoise3D::new(3, 0.2, 1.0).with_octaves(3, 0.5);
+/// let density = fog.sample_at(4.0, 2.5, 0.75);
+/// assert!((-1.0..=1.0).contains(&density));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerlinNoise3D {
+    /// Seed for reproducible randomness
+    seed: u64,
+    /// Scale factor (lattice cells per input unit)
+    scale: f32,
+    /// Output amplitude (half of total range)
+    amplitude: f32,
+    /// Center value (offset)
+    offset: f32,
+    /// Number of octaves for fractal noise (1 = simple, more = detailed)
+    octaves: u8,
+    /// Persistence for octave amplitude decay (typically 0.5)
+    persistence: f32,
+    /// Frequency multiplier between octaves (typically 2.0)
+    lacunarity: f32,
+}
+
+impl PerlinNoise3D {
+    pub fn new(seed: u64, scale: f32, amplitude: f32) -> Self {
+        Self {
+            seed,
+            scale,
+            amplitude,
+            offset: 0.0,
+            octaves: 1,
+            persistence: 0.5,
+            lacunarity: 2.0,
+        }
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self::new(seed, 1.0, 1.0)
+    }
+
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_octaves(mut self, octaves: u8, persistence: f32) -> Self {
+        self.octaves = octaves.max(1);
+        self.persistence = persistence;
+        self
+    }
+
+    /// Set the frequency multiplier between octaves (default 2.0).
+    ///
+    /// Non-finite or non-positive values fall back to 2.0.
+    pub fn with_lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = if lacunarity.is_finite() && lacunarity > 0.0 {
+            lacunarity
+        } else {
+            2.0
+        };
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn amplitude(&self) -> f32 {
+        self.amplitude
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    pub fn octaves(&self) -> u8 {
+        self.octaves
+    }
+
+    pub fn persistence(&self) -> f32 {
+        self.persistence
+    }
+
+    pub fn lacunarity(&self) -> f32 {
+        self.lacunarity
+    }
+
+    /// Sample the field at `(x, y, z)`.
+    ///
+    /// Non-finite coordinates are treated as 0.
+    pub fn sample_at(&self, x: f32, y: f32, z: f32) -> f32 {
+        let (x, y, z) = (
+            finite_or(x, 0.0) as f64,
+            finite_or(y, 0.0) as f64,
+            finite_or(z, 0.0) as f64,
+        );
+        let scale = finite_or(self.scale, 1.0) as f64;
+        let amplitude_scale = finite_or(self.amplitude, 1.0) as f64;
+        let offset = finite_or(self.offset, 0.0) as f64;
+        let persistence = finite_or(self.persistence, 0.5) as f64;
+        let lacunarity = finite_or(self.lacunarity, 2.0) as f64;
+
+        let mut total = 0.0;
+        let mut frequency = scale;
+        let mut amplitude = 1.0;
+        let mut max_value = 0.0;
+        for i in 0..self.octaves.max(1) {
+            total += perlin_3d(
+                self.octave_seed(i),
+                x * frequency,
+                y * frequency,
+                z * frequency,
+            ) * amplitude;
+            max_value += amplitude;
+            amplitude *= persistence;
+            frequency *= lacunarity;
+        }
+
+        (offset + total / max_value * amplitude_scale) as f32
+    }
+
+    fn octave_seed(&self, octave: u8) -> u64 {
+        if octave == 0 {
+            return self.seed;
+        }
+        let bytes = derive_seed(self.seed, octave as u64);
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+impl Default for PerlinNoise3D {
+    fn default() -> Self {
+        Self::with_seed(0)
+    }
+}
+
+/// Hash a lattice point to 64 well-mixed bits.
+fn hash_3d(seed: u64, ix: i64, iy: i64, iz: i64) -> u64 {
+    let mut n = seed
+        ^ (ix as u64).wrapping_mul(0x9e3779b97f4a7c15)
+        ^ (iy as u64).wrapping_mul(0xc2b2ae3d27d4eb4f)
+        ^ (iz as u64).wrapping_mul(0x165667b19e3779f9);
+    n = (n ^ (n >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    n = (n ^ (n >> 27)).wrapping_mul(0x94d049bb133111eb);
+    n ^ (n >> 31)
+}
+
+/// Dot product of the lattice point's gradient with the offset, unit-scaled.
+fn corner(seed: u64, cell: (i64, i64, i64), d: (f64, f64, f64)) -> f64 {
+    let (gx, gy, gz) = GRADIENTS[(hash_3d(seed, cell.0, cell.1, cell.2) % 12) as usize];
+    (gx * d.0 + gy * d.1 + gz * d.2) * core::f64::consts::FRAC_1_SQRT_2
+}
+
+/// Quintic fade curve with zero first and second derivatives at 0 and 1.
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Single octave of 3D Perlin noise in [-1, 1].
+fn perlin_3d(seed: u64, x: f64, y: f64, z: f64) -> f64 {
+    let (fx, fy, fz) = (x.floor(), y.floor(), z.floor());
+    let (dx, dy, dz) = (x - fx, y - fy, z - fz);
+    let (ix, iy, iz) = (fx as i64, fy as i64, fz as i64);
+
+    let mut layers = [0.0; 2];
+    for (k, layer) in layers.iter_mut().enumerate() {
+        let cz = iz.wrapping_add(k as i64);
+        let oz = dz - k as f64;
+        let c = |i: i64, j: i64| {
+            corner(
+                seed,
+                (ix.wrapping_add(i), iy.wrapping_add(j), cz),
+                (dx - i as f64, dy - j as f64, oz),
+            )
+        };
+        let u = fade(dx);
+        *layer = lerp(
+            lerp(c(0, 0), c(1, 0), u),
+            lerp(c(0, 1), c(1, 1), u),
+            fade(dy),
+        );
+    }
+
+    (lerp(layers[0], layers[1], fade(dz)) * PERLIN_3D_NORM).clamp(-1.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perlin_3d_deterministic_and_bounded() {
+        let noise = PerlinNoise3D::with_seed(11).with_octaves(4, 0.5);
+        let other = PerlinNoise3D::with_seed(12).with_octaves(4, 0.5);
+        let mut differs = false;
+        for i in 0..2000 {
+            let p = (i as f32 * 0.137, i as f32 * 0.071 - 4.0, i as f32 * -0.053);
+            let v = noise.sample_at(p.0, p.1, p.2);
+            assert!((-1.0..=1.0).contains(&v), "{} at {:?}", v, p);
+            assert_eq!(v, noise.sample_at(p.0, p.1, p.2));
+            differs |= v != other.sample_at(p.0, p.1, p.2);
+        }
+        assert!(differs);
+    }
+
+    #[test]
+    fn test_perlin_3d_continuous_across_lattice() {
+        let noise = PerlinNoise3D::with_seed(5);
+        let eps = 1e-4;
+        for k in -2..3 {
+            let edge = k as f32;
+            let a = noise.sample_at(0.3, 0.6, edge - eps);
+            let b = noise.sample_at(0.3, 0.6, edge + eps);
+            assert!((a - b).abs() < 1e-3, "jump at z={}: {} {}", edge, a, b);
+        }
+        assert!(noise.sample_at(1.0, 2.0, 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_perlin_3d_varied() {
+        let noise = PerlinNoise3D::with_seed(2);
+        let peak = (0..500)
+            .map(|i| noise.sample_at(i as f32 * 0.21, 0.5, i as f32 * 0.07).abs())
+            .fold(0.0f32, f32::max);
+        assert!(peak > 0.3, "peak {}", peak);
+    }
+}
+
+// <FILE>mixed-signals/src/noise/cls_perlin_3d.rs</FILE> - <DESC>3D Perlin gradient noise field</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
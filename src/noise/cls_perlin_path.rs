@@ -0,0 +1,167 @@
+// <FILE>mixed-signals/src/noise/cls_perlin_path.rs</FILE> - <DESC>Signal that walks a path through a 2D noise field</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
+
+use super::PerlinNoise2D;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalRange, SignalTime};
+use core::f64::consts::TAU;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Route taken through the field as time advances.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NoisePath {
+    /// `origin + t * velocity`
+    Line {
+        origin: (f32, f32),
+        velocity: (f32, f32),
+    },
+    /// One lap around `center` every `period` seconds
+    Circle {
+        center: (f32, f32),
+        radius: f32,
+        period: f32,
+    },
+}
+
+/// Time signal sampled from a [`PerlinNoise2D`] field along a [`NoisePath`].
+///
+/// A line path gives ordinary organic noise; a circle path gives noise that
+/// repeats exactly every `period` seconds with no seam, which is ideal for
+/// looping animations and idle motion.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::noise::PerlinNoise2D;
+/// use mixed_signals::traits::Signal;
+///
+/// let wobble = PerlinNoise2D::new(9, 1.0, 1.0).with_octaves(3, 0.5).looping(1.5, 4.0);
+/// let a = wobble.sample(0.7);
+/// assert!((a - wobble.sample(4.7)).abs() < 1e-5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerlinPath {
+    noise: PerlinNoise2D,
+    path: NoisePath,
+}
+
+impl PerlinPath {
+    pub fn new(noise: PerlinNoise2D, path: NoisePath) -> Self {
+        Self { noise, path }
+    }
+
+    /// Walk from `origin` at `velocity` field units per second.
+    pub fn line(noise: PerlinNoise2D, origin: (f32, f32), velocity: (f32, f32)) -> Self {
+        Self::new(noise, NoisePath::Line { origin, velocity })
+    }
+
+    /// Circle `center` at `radius`, completing a lap every `period` seconds.
+    pub fn circle(noise: PerlinNoise2D, center: (f32, f32), radius: f32, period: f32) -> Self {
+        Self::new(
+            noise,
+            NoisePath::Circle {
+                center,
+                radius,
+                period,
+            },
+        )
+    }
+
+    pub fn noise(&self) -> &PerlinNoise2D {
+        &self.noise
+    }
+
+    pub fn path(&self) -> NoisePath {
+        self.path
+    }
+
+    /// Field coordinates visited at time `t`.
+    pub fn position_at(&self, t: SignalTime) -> (f64, f64) {
+        let t = finite_or_f64(t, 0.0);
+        match self.path {
+            NoisePath::Line { origin, velocity } => (
+                finite_or(origin.0, 0.0) as f64 + t * finite_or(velocity.0, 0.0) as f64,
+                finite_or(origin.1, 0.0) as f64 + t * finite_or(velocity.1, 0.0) as f64,
+            ),
+            NoisePath::Circle {
+                center,
+                radius,
+                period,
+            } => {
+                let period = finite_or(period, 1.0) as f64;
+                let radius = finite_or(radius, 1.0) as f64;
+                let lap = if period > 0.0 {
+                    (t / period).rem_euclid(1.0)
+                } else {
+                    0.0
+                };
+                let angle = lap * TAU;
+                (
+                    finite_or(center.0, 0.0) as f64 + radius * angle.cos(),
+                    finite_or(center.1, 0.0) as f64 + radius * angle.sin(),
+                )
+            }
+        }
+    }
+}
+
+impl Signal for PerlinPath {
+    fn output_range(&self) -> SignalRange {
+        let amplitude = finite_or(self.noise.amplitude(), 1.0);
+        let offset = finite_or(self.noise.offset(), 0.0);
+        SignalRange::new(offset - amplitude, offset + amplitude)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let (x, y) = self.position_at(t);
+        self.noise.sample_at_f64(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circle_path_is_periodic() {
+        let signal = PerlinNoise2D::new(21, 0.8, 1.0)
+            .with_octaves(4, 0.5)
+            .looping(2.0, 3.0);
+        for i in 0..100 {
+            let t = i as f64 * 0.031;
+            let v = signal.sample(t);
+            assert!((v - signal.sample(t + 3.0)).abs() < 1e-5, "t={}", t);
+            assert!((v - signal.sample(t - 6.0)).abs() < 1e-5, "t={}", t);
+        }
+        // The seam at the lap boundary is smooth
+        assert!((signal.sample(2.9999) - signal.sample(3.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_line_path_samples_field() {
+        let field = PerlinNoise2D::with_seed(4);
+        let signal = field.along_line((1.0, -2.0), (0.5, 0.25));
+        assert_eq!(signal.position_at(2.0), (2.0, -1.5));
+        assert_eq!(signal.sample(2.0), field.sample_at(2.0, -1.5));
+        assert_eq!(signal.output_range(), SignalRange::new(-1.0, 1.0));
+    }
+
+    #[test]
+    fn test_degenerate_paths_are_finite() {
+        let field = PerlinNoise2D::with_seed(1);
+        let stuck = PerlinPath::circle(field, (0.5, 0.5), 1.0, 0.0);
+        assert_eq!(stuck.sample(0.0), stuck.sample(7.3));
+        let bad = PerlinPath::line(field, (f32::NAN, 0.0), (f32::INFINITY, 1.0));
+        assert!(bad.sample(f64::NAN).is_finite());
+    }
+}
+
+// <FILE>mixed-signals/src/noise/cls_perlin_path.rs</FILE> - <DESC>Signal that walks a path through a 2D noise field</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -0,0 +1,215 @@
+// <FILE>mixed-signals/src/noise/cls_simplex.rs</FILE> - <DESC>1D simplex gradient noise generator</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{derive_seed, fast_random, finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Peak magnitude of a single simplex octave with unit gradients.
+const SIMPLEX_PEAK: f64 = 0.316_406_25;
+
+/// Smooth gradient noise (1D simplex).
+///
+/// Each integer lattice point carries a random gradient; a sample sums the
+/// radially falling-off contributions `(1 - d²)⁴ · g · d` of its two
+/// neighbouring points. Unlike [`PerlinNoise`](super::PerlinNoise), which
+/// interpolates random values, the output crosses zero at every lattice
+/// point and has no flat spots, giving a livelier texture.
+///
+/// Output is bipolar [-amplitude, +amplitude] centered at offset. Octaves
+/// layer like `PerlinNoise`, each with an independent lattice seed.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::noise::SimplexNoise;
+/// use mixed_signals::traits::Signal;
+///
+/// let noise = SimplexNoise::new(7, 2.0, 1.0).with_octaves(3, 0.5);
+/// let v = noise.sample(0.3);
+/// assert!((-1.0..=1.0).contains(&v));
+/// assert_eq!(v, noise.sample(0.3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SimplexNoise {
+    /// Seed for reproducible randomness
+    seed: u64,
+    /// Scale factor (higher = faster variation)
+    scale: f32,
+    /// Output amplitude (half of total range)
+    amplitude: f32,
+    /// Center value (offset)
+    offset: f32,
+    /// Number of octaves for fractal noise (1 = simple, more = detailed)
+    octaves: u8,
+    /// Persistence for octave amplitude decay (typically 0.5)
+    persistence: f32,
+}
+
+impl SimplexNoise {
+    pub fn new(seed: u64, scale: f32, amplitude: f32) -> Self {
+        Self {
+            seed,
+            scale,
+            amplitude,
+            offset: 0.0,
+            octaves: 1,
+            persistence: 0.5,
+        }
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self::new(seed, 1.0, 1.0)
+    }
+
+    /// Set the center value.
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_octaves(mut self, octaves: u8, persistence: f32) -> Self {
+        self.octaves = octaves.max(1);
+        self.persistence = persistence;
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn amplitude(&self) -> f32 {
+        self.amplitude
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    pub fn octaves(&self) -> u8 {
+        self.octaves
+    }
+
+    pub fn persistence(&self) -> f32 {
+        self.persistence
+    }
+
+    fn octave_seed(&self, octave: u8) -> u64 {
+        if octave == 0 {
+            return self.seed;
+        }
+        let bytes = derive_seed(self.seed, octave as u64);
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+impl Default for SimplexNoise {
+    fn default() -> Self {
+        Self::with_seed(0)
+    }
+}
+
+/// Contribution of the lattice point `i` at signed distance `d`.
+fn corner(seed: u64, i: i64, d: f64) -> f64 {
+    let falloff = 1.0 - d * d;
+    if falloff <= 0.0 {
+        return 0.0;
+    }
+    let gradient = fast_random(seed, i as u64) as f64 * 2.0 - 1.0;
+    falloff.powi(4) * gradient * d
+}
+
+/// Single octave of 1D simplex noise in [-1, 1].
+fn simplex_1d(seed: u64, x: f64) -> f64 {
+    let i0 = x.floor();
+    let d0 = x - i0;
+    let i0 = i0 as i64;
+    let n = corner(seed, i0, d0) + corner(seed, i0.wrapping_add(1), d0 - 1.0);
+    (n / SIMPLEX_PEAK).clamp(-1.0, 1.0)
+}
+
+impl Signal for SimplexNoise {
+    fn output_range(&self) -> SignalRange {
+        let amplitude = finite_or(self.amplitude, 1.0);
+        let offset = finite_or(self.offset, 0.0);
+        SignalRange::new(offset - amplitude, offset + amplitude)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let scale = finite_or(self.scale, 1.0) as f64;
+        let amplitude_scale = finite_or(self.amplitude, 1.0) as f64;
+        let offset = finite_or(self.offset, 0.0) as f64;
+        let persistence = finite_or(self.persistence, 0.5) as f64;
+
+        let mut total = 0.0;
+        let mut frequency = scale;
+        let mut amplitude = 1.0;
+        let mut max_value = 0.0;
+        for i in 0..self.octaves.max(1) {
+            total += simplex_1d(self.octave_seed(i), t * frequency) * amplitude;
+            max_value += amplitude;
+            amplitude *= persistence;
+            frequency *= 2.0;
+        }
+
+        (offset + total / max_value * amplitude_scale) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simplex_bounded_and_deterministic() {
+        let noise = SimplexNoise::with_seed(42).with_octaves(4, 0.5);
+        for i in 0..2000 {
+            let t = i as f64 * 0.0137;
+            let v = noise.sample(t);
+            assert!((-1.0..=1.0).contains(&v), "{} at {}", v, t);
+            assert_eq!(v, noise.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_simplex_zero_at_lattice_points() {
+        let noise = SimplexNoise::with_seed(9);
+        for i in -5..5 {
+            assert!(noise.sample(i as f64).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_simplex_smooth_and_varied() {
+        let noise = SimplexNoise::with_seed(3);
+        let values: Vec<f32> = (0..1000).map(|i| noise.sample(i as f64 * 0.01)).collect();
+        for pair in values.windows(2) {
+            assert!((pair[1] - pair[0]).abs() < 0.1);
+        }
+        let peak = values.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+        assert!(peak > 0.3, "peak {}", peak);
+    }
+
+    #[test]
+    fn test_simplex_output_range_and_invalid_inputs() {
+        let noise = SimplexNoise::new(1, 1.0, 0.5).with_offset(0.5);
+        assert_eq!(noise.output_range(), SignalRange::new(0.0, 1.0));
+        assert!(noise.sample(f64::NAN).is_finite());
+        let nan_scale = SimplexNoise::new(1, f32::NAN, f32::NAN);
+        assert!(nan_scale.sample(0.3).is_finite());
+    }
+}
+
+// <FILE>mixed-signals/src/noise/cls_simplex.rs</FILE> - <DESC>1D simplex gradient noise generator</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
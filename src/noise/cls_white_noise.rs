@@ -1,20 +1,22 @@
 // <FILE>mixed-signals/src/noise/cls_white_noise.rs</FILE> - <DESC>White noise generator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor</WCTX>
-// <CLOG>Converted to bipolar [-1,1] output, added output_range()</CLOG>
+// <VERS>VERSION: 2.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::math::{derive_seed, finite_or, finite_or_f64, finite_or_min};
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 use rand::RngCore;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// White noise generator producing random values.
 ///
 /// Uses deterministic ChaCha8Rng based on seed and time for reproducible noise.
 /// Output is bipolar [-amplitude, +amplitude] centered at offset.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WhiteNoise {
     /// Seed for reproducible randomness
     seed: u64,
@@ -198,4 +200,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/noise/cls_white_noise.rs</FILE> - <DESC>White noise generator</DESC>
-// <VERS>END OF VERSION: 2.0.0</VERS>
+// <VERS>END OF VERSION: 2.1.0</VERS>
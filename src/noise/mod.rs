@@ -1,18 +1,35 @@
 // <FILE>mixed-signals/src/noise/mod.rs</FILE> - <DESC>Noise generators module</DESC>
-// <VERS>VERSION: 1.0.0 - 2025-12-22</VERS>
-// <WCTX>Signal generator implementation</WCTX>
-// <CLOG>Initial implementation</CLOG>
+// <VERS>VERSION: 1.3.0 - 2026-10-16</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>WhiteNoise behind the chacha feature</CLOG>
 
 //! Noise generators for continuous stochastic signals.
 //!
 //! Invalid inputs (NaN/Inf) are sanitized to defaults at sample time to keep
 //! outputs finite. For valid finite inputs, behavior is unchanged.
+//!
+//! [`PerlinNoise2D`] and [`PerlinNoise3D`] are spatial fields sampled with
+//! `sample_at`; [`PerlinPath`] turns a walk through a 2D field into a signal.
 
 mod cls_perlin;
+mod cls_perlin_2d;
+mod cls_perlin_3d;
+mod cls_perlin_path;
+mod cls_simplex;
+mod cls_value;
+#[cfg(feature = "chacha")]
 mod cls_white_noise;
+mod cls_worley;
 
 pub use cls_perlin::PerlinNoise;
+pub use cls_perlin_2d::PerlinNoise2D;
+pub use cls_perlin_3d::PerlinNoise3D;
+pub use cls_perlin_path::{NoisePath, PerlinPath};
+pub use cls_simplex::SimplexNoise;
+pub use cls_value::ValueNoise;
+#[cfg(feature = "chacha")]
 pub use cls_white_noise::WhiteNoise;
+pub use cls_worley::{DistanceMetric, WorleyNoise};
 
 // <FILE>mixed-signals/src/noise/mod.rs</FILE> - <DESC>Noise generators module</DESC>
-// <VERS>END OF VERSION: 1.0.0 - 2025-12-22</VERS>
+// <VERS>END OF VERSION: 1.3.0 - 2026-10-16</VERS>
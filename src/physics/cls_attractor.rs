@@ -1,10 +1,13 @@
 // <FILE>src/physics/cls_attractor.rs</FILE> - <DESC>Point attractor force field</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Physics module implementation</WCTX>
-// <CLOG>Initial implementation with inverse-square force field</CLOG>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std</CLOG>
 
 use crate::math::finite_or;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Point attractor force field.
@@ -34,7 +37,8 @@ use serde::{Deserialize, Serialize};
 /// let attractor = PointAttractor::new(100.0, 100.0, 500.0);
 /// let (fx, fy) = attractor.force_at(150.0, 100.0);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PointAttractor {
     /// Target X coordinate (attractor center).
     pub target_x: f32,
@@ -227,4 +231,4 @@ mod tests {
 }
 
 // <FILE>src/physics/cls_attractor.rs</FILE> - <DESC>Point attractor force field</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.1.0</VERS>
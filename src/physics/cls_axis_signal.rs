@@ -0,0 +1,103 @@
+// <FILE>src/physics/cls_axis_signal.rs</FILE> - <DESC>Single-axis projection of 2D motion</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>2D physics output</WCTX>
+// <CLOG>Initial AxisSignal adapter</CLOG>
+
+use crate::traits::{Axis, Planar, Signal, SignalRange, SignalTime};
+
+/// Projects a 2D [`Planar`] solver onto one axis as a [`Signal`].
+///
+/// Created by `x_signal()` / `y_signal()` on the 2D solvers. The solver is
+/// copied in, so one solver can feed both axes without rebuilding it with
+/// different parameters.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::physics::CircularOrbit;
+/// use mixed_signals::traits::Signal;
+///
+/// let orbit = CircularOrbit::centered(50.0, 1.0);
+/// let (x, y) = (orbit.x_signal(), orbit.y_signal());
+/// assert_eq!((x.sample(0.3), y.sample(0.3)), orbit.position_at(0.3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisSignal<P> {
+    solver: P,
+    axis: Axis,
+}
+
+impl<P: Planar> AxisSignal<P> {
+    /// Project `solver` onto `axis`.
+    pub fn new(solver: P, axis: Axis) -> Self {
+        Self { solver, axis }
+    }
+
+    /// The projected axis.
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// The underlying 2D solver.
+    pub fn solver(&self) -> &P {
+        &self.solver
+    }
+
+    /// Velocity along the projected axis at time `t`.
+    pub fn velocity_at(&self, t: SignalTime) -> f32 {
+        let (vx, vy) = self.solver.velocity_at(t);
+        match self.axis {
+            Axis::X => vx,
+            Axis::Y => vy,
+        }
+    }
+}
+
+impl<P: Planar + Clone + Send + Sync + 'static> Signal for AxisSignal<P> {
+    fn output_range(&self) -> SignalRange {
+        self.solver.axis_range(self.axis)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let (x, y) = self.solver.position_at(t);
+        match self.axis {
+            Axis::X => x,
+            Axis::Y => y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::BallisticTrajectory;
+
+    #[test]
+    fn test_axis_signal_projects_position() {
+        let toss = BallisticTrajectory::toss(10.0, 20.0, 30.0, -40.0, 100.0);
+        let x = AxisSignal::new(toss, Axis::X);
+        let y = AxisSignal::new(toss, Axis::Y);
+        for i in 0..20 {
+            let t = i as f64 * 0.05;
+            assert_eq!((x.sample(t), y.sample(t)), toss.position_at(t));
+        }
+    }
+
+    #[test]
+    fn test_axis_signal_velocity() {
+        let toss = BallisticTrajectory::toss(0.0, 0.0, 30.0, -40.0, 100.0);
+        assert_eq!(AxisSignal::new(toss, Axis::X).velocity_at(1.0), 30.0);
+        assert_eq!(AxisSignal::new(toss, Axis::Y).velocity_at(1.0), 60.0);
+    }
+
+    #[test]
+    fn test_axis_signal_composes() {
+        use crate::traits::SignalExt;
+        let toss = BallisticTrajectory::toss(0.0, 0.0, 100.0, 0.0, 0.0);
+        let doubled = AxisSignal::new(toss, Axis::X).scale(2.0);
+        assert!((doubled.sample(1.0) - 200.0).abs() < 1e-4);
+    }
+}
+
+// <FILE>src/physics/cls_axis_signal.rs</FILE> - <DESC>Single-axis projection of 2D motion</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -1,10 +1,13 @@
 // <FILE>src/physics/cls_bounce.rs</FILE> - <DESC>Bouncing drop with restitution</DESC>
-// <VERS>VERSION: 1.1.0</VERS>
-// <WCTX>Physics module robustness improvements</WCTX>
-// <CLOG>Fix height_at clamp bug, add g==0 handling for frozen motion</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_clamp, finite_or_f64};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Bouncing drop solver with energy loss per bounce.
@@ -33,7 +36,8 @@ use serde::{Deserialize, Serialize};
 /// let drop = BouncingDrop::new(0.0, 300.0, 500.0, 0.6);
 /// let height = drop.sample(0.5);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BouncingDrop {
     /// Starting height (Y position at t=0).
     pub start_height: f32,
@@ -365,4 +369,4 @@ mod tests {
 }
 
 // <FILE>src/physics/cls_bounce.rs</FILE> - <DESC>Bouncing drop with restitution</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
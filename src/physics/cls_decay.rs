@@ -1,10 +1,13 @@
 // <FILE>src/physics/cls_decay.rs</FILE> - <DESC>Friction/inertia decay solver</DESC>
-// <VERS>VERSION: 1.1.0</VERS>
-// <WCTX>Physics module robustness improvements</WCTX>
-// <CLOG>Add exp_m1 for precision, explicit drag==0 branch for linear motion</CLOG>
+// <VERS>VERSION: 1.3.0</VERS>
+// <WCTX>Kinetic scrolling queries</WCTX>
+// <CLOG>Added position_at, stop_time, total_distance, snap_velocity and stop_threshold</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Friction/inertia decay solver for scrolling and flinging.
@@ -20,6 +23,15 @@ use serde::{Deserialize, Serialize};
 ///
 /// As t → ∞, offset approaches `v0 / drag` (maximum travel distance).
 ///
+/// # Kinetic Scrolling
+///
+/// [`stop_time`](Self::stop_time) reports when speed falls below
+/// `stop_threshold` ([`DEFAULT_FRICTION_STOP_THRESHOLD`] unless set with
+/// [`with_stop_threshold`](Self::with_stop_threshold)). At that moment the
+/// position is `threshold / drag` short of [`total_distance`](Self::total_distance).
+/// [`snap_velocity`](Self::snap_velocity) picks the initial velocity that
+/// lands exactly on an item boundary.
+///
 /// # Signal Integration
 ///
 /// Implements `Signal` where `sample(t)` returns the displacement from start.
@@ -32,19 +44,47 @@ use serde::{Deserialize, Serialize};
 ///
 /// let scroll = FrictionDecay::new(500.0, 3.0); // Fast flick, moderate drag
 /// let offset = scroll.sample(0.5); // Displacement after 0.5s
+///
+/// // Same drag, but land on a 48-unit row boundary
+/// let snapped = FrictionDecay::new(scroll.snap_velocity(0.0, 48.0), 3.0);
+/// assert!((snapped.total_distance() % 48.0).abs() < 1e-3);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FrictionDecay {
     /// Initial velocity (units/sec).
     pub v0: f32,
     /// Drag coefficient (exponential decay rate). Higher = faster stop.
     pub drag: f32,
+    /// Speed (units/sec) below which motion counts as stopped.
+    #[cfg_attr(feature = "serde", serde(default = "default_stop_threshold"))]
+    pub stop_threshold: f32,
+}
+
+/// Default speed (units/sec) below which [`FrictionDecay::stop_time`] counts
+/// the motion as stopped.
+pub const DEFAULT_FRICTION_STOP_THRESHOLD: f32 = 1.0;
+
+#[cfg(feature = "serde")]
+fn default_stop_threshold() -> f32 {
+    DEFAULT_FRICTION_STOP_THRESHOLD
 }
 
 impl FrictionDecay {
     /// Create a new friction decay with initial velocity and drag.
     pub fn new(v0: f32, drag: f32) -> Self {
-        Self { v0, drag }
+        Self {
+            v0,
+            drag,
+            stop_threshold: DEFAULT_FRICTION_STOP_THRESHOLD,
+        }
+    }
+
+    /// Set the speed below which [`stop_time`](Self::stop_time) counts the
+    /// motion as stopped. 0 means it never stops.
+    pub fn with_stop_threshold(mut self, threshold: f32) -> Self {
+        self.stop_threshold = threshold;
+        self
     }
 
     /// Light friction for smooth, long scrolling.
@@ -79,6 +119,11 @@ impl FrictionDecay {
         result as f32
     }
 
+    /// Position (scroll offset) at time t; same as [`offset_at`](Self::offset_at).
+    pub fn position_at(&self, t: SignalTime) -> f32 {
+        self.offset_at(t)
+    }
+
     /// Current velocity at time t.
     pub fn velocity_at(&self, t: SignalTime) -> f32 {
         let t = finite_or_f64(t, 0.0).max(0.0);
@@ -134,14 +179,55 @@ impl FrictionDecay {
 
         v0 / drag
     }
+
+    /// Time at which speed drops below `stop_threshold`.
+    ///
+    /// 0.0 if it starts below the threshold. Returns `f32::INFINITY` for a
+    /// zero (or invalid) threshold or zero drag, since the motion never stops.
+    pub fn stop_time(&self) -> f32 {
+        let threshold = finite_or(self.stop_threshold, 0.0);
+        if threshold <= 0.0 {
+            return f32::INFINITY;
+        }
+        let v0 = finite_or(self.v0, 0.0).abs();
+        if v0 <= threshold {
+            return 0.0;
+        }
+        let drag = finite_or(self.drag, 0.0).max(0.0);
+        if drag < 1e-10 {
+            return f32::INFINITY;
+        }
+        (v0 / threshold).ln() / drag
+    }
+
+    /// Distance covered before coming to rest, `|v0| / drag`.
+    /// Returns `f32::INFINITY` if drag is zero.
+    pub fn total_distance(&self) -> f32 {
+        self.max_offset().abs()
+    }
+
+    /// Initial velocity that makes a fling from `start` come to rest on the
+    /// item boundary (a multiple of `spacing`) nearest its natural landing.
+    ///
+    /// Keeps `drag`, so the adjusted fling feels the same. Returns `v0`
+    /// unchanged when `spacing` is not positive or drag is zero.
+    pub fn snap_velocity(&self, start: f32, spacing: f32) -> f32 {
+        let v0 = finite_or(self.v0, 0.0);
+        let drag = finite_or(self.drag, 0.0).max(0.0);
+        let start = finite_or(start, 0.0) as f64;
+        if !(spacing.is_finite() && spacing > 0.0) || drag < 1e-10 {
+            return v0;
+        }
+        let spacing = spacing as f64;
+        let landing = start + v0 as f64 / drag as f64;
+        let target = (landing / spacing).round() * spacing;
+        ((target - start) * drag as f64) as f32
+    }
 }
 
 impl Default for FrictionDecay {
     fn default() -> Self {
-        Self {
-            v0: 200.0,
-            drag: 4.0,
-        }
+        Self::new(200.0, 4.0)
     }
 }
 
@@ -275,8 +361,64 @@ mod tests {
         // Duration until stop is infinite
         let duration = decay.duration_until_stop(1.0);
         assert!(duration.is_infinite(), "Should never stop with no drag");
+        assert_eq!(decay.stop_time(), f32::INFINITY);
+        assert_eq!(decay.snap_velocity(0.0, 10.0), 100.0);
+    }
+
+    #[test]
+    fn test_position_at_stop_time_reaches_total_distance() {
+        for (v0, drag) in [(800.0, 3.0), (-450.0, 5.0), (120.0, 0.5)] {
+            let fling = FrictionDecay::new(v0, drag);
+            let stop = fling.stop_time();
+            let landed = fling.position_at(stop as f64);
+            // Exactly threshold / drag short of the resting point
+            let gap = DEFAULT_FRICTION_STOP_THRESHOLD / drag;
+            assert!((landed.abs() - (fling.total_distance() - gap)).abs() < 1e-2);
+            assert!((fling.velocity_at(stop as f64).abs() - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_total_distance_scales_with_velocity() {
+        let slow = FrictionDecay::new(300.0, 4.0);
+        let fast = FrictionDecay::new(600.0, 4.0);
+        assert!((fast.total_distance() - 2.0 * slow.total_distance()).abs() < 1e-4);
+        assert_eq!(FrictionDecay::new(-300.0, 4.0).total_distance(), 75.0);
+    }
+
+    #[test]
+    fn test_stop_threshold_edge_cases() {
+        let fling = FrictionDecay::new(500.0, 3.0);
+        for threshold in [0.0, -1.0, f32::NAN] {
+            assert_eq!(
+                fling.with_stop_threshold(threshold).stop_time(),
+                f32::INFINITY
+            );
+        }
+        assert_eq!(fling.with_stop_threshold(600.0).stop_time(), 0.0);
+        let coarse = fling.with_stop_threshold(50.0).stop_time();
+        assert!(coarse < fling.stop_time());
+        assert!((coarse - (10.0_f32).ln() / 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_snap_velocity_lands_on_boundary() {
+        let spacing = 48.0;
+        for (start, v0) in [(0.0, 500.0), (13.0, 500.0), (100.0, -260.0), (5.0, 20.0)] {
+            let fling = FrictionDecay::new(v0, 3.0);
+            let snapped = FrictionDecay::new(fling.snap_velocity(start, spacing), 3.0);
+            let rest = start + snapped.max_offset();
+            let items = rest / spacing;
+            assert!((items - items.round()).abs() < 1e-4, "rest = {rest}");
+            // Lands on the boundary nearest the natural landing
+            let natural = start + fling.max_offset();
+            assert!((rest - natural).abs() <= spacing / 2.0 + 1e-3);
+        }
+        let fling = FrictionDecay::new(500.0, 3.0);
+        assert_eq!(fling.snap_velocity(0.0, 0.0), 500.0);
+        assert_eq!(fling.snap_velocity(0.0, f32::NAN), 500.0);
     }
 }
 
 // <FILE>src/physics/cls_decay.rs</FILE> - <DESC>Friction/inertia decay solver</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 1.3.0</VERS>
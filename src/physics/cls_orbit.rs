@@ -1,12 +1,14 @@
 // <FILE>src/physics/cls_orbit.rs</FILE> - <DESC>Circular orbital motion solver</DESC>
-// <VERS>VERSION: 1.2.0</VERS>
-// <WCTX>Deduplication refactor</WCTX>
-// <CLOG>Use harmonic_phase/harmonic_sin_cos helpers for trig stability</CLOG>
+// <VERS>VERSION: 1.4.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, core:: instead of std:: paths</CLOG>
 
+use super::AxisSignal;
 use crate::math::{finite_or, finite_or_f64, harmonic_phase, harmonic_sin_cos};
-use crate::traits::{Signal, SignalRange, SignalTime};
+use crate::traits::{Axis, Planar, Signal, SignalRange, SignalTime};
+use core::f64::consts::TAU;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::f64::consts::TAU;
 
 /// Circular orbital motion solver.
 ///
@@ -25,8 +27,9 @@ use std::f64::consts::TAU;
 ///
 /// # Signal Integration
 ///
-/// Implements `Signal` where `sample(t)` returns the angle in radians.
-/// Use `position_at()` for x,y coordinates.
+/// Implements `Signal` where `sample(t)` returns the **angle** in radians
+/// (kept for backward compatibility). Use `position_at()` for x,y coordinates,
+/// or `x_signal()` / `y_signal()` to compose each axis as its own signal.
 ///
 /// # Example
 ///
@@ -37,7 +40,8 @@ use std::f64::consts::TAU;
 /// let orbit = CircularOrbit::new(100.0, 100.0, 50.0, 2.0, 0.0);
 /// let (x, y) = orbit.position_at(0.5);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CircularOrbit {
     /// Center X coordinate.
     pub center_x: f32,
@@ -131,6 +135,16 @@ impl CircularOrbit {
         (vx as f32, vy as f32)
     }
 
+    /// X position as a standalone signal.
+    pub fn x_signal(&self) -> AxisSignal<Self> {
+        AxisSignal::new(*self, Axis::X)
+    }
+
+    /// Y position as a standalone signal.
+    pub fn y_signal(&self) -> AxisSignal<Self> {
+        AxisSignal::new(*self, Axis::Y)
+    }
+
     /// Period of one complete revolution.
     pub fn period(&self) -> f32 {
         let omega = finite_or(self.angular_velocity, 1.0).abs();
@@ -153,6 +167,25 @@ impl Default for CircularOrbit {
     }
 }
 
+impl Planar for CircularOrbit {
+    fn position_at(&self, t: SignalTime) -> (f32, f32) {
+        CircularOrbit::position_at(self, t)
+    }
+
+    fn velocity_at(&self, t: SignalTime) -> (f32, f32) {
+        CircularOrbit::velocity_at(self, t)
+    }
+
+    fn axis_range(&self, axis: Axis) -> SignalRange {
+        let r = finite_or(self.radius, 1.0).max(0.0);
+        let center = match axis {
+            Axis::X => finite_or(self.center_x, 0.0),
+            Axis::Y => finite_or(self.center_y, 0.0),
+        };
+        SignalRange::new(center - r, center + r)
+    }
+}
+
 impl Signal for CircularOrbit {
     fn output_range(&self) -> SignalRange {
         // Wrapped angle is always in [0, TAU)
@@ -167,7 +200,7 @@ impl Signal for CircularOrbit {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::f32::consts::PI;
+    use core::f32::consts::PI;
 
     const EPSILON: f32 = 0.01;
 
@@ -256,7 +289,31 @@ mod tests {
         let period = orbit.period();
         assert!(period.is_infinite());
     }
+
+    #[test]
+    fn test_radius_constant_along_orbit() {
+        let orbit = CircularOrbit::new(0.0, 0.0, 75.0, 3.3, 0.4);
+        let (xs, ys) = (orbit.x_signal(), orbit.y_signal());
+        for i in 0..200 {
+            let t = i as f64 * 0.037;
+            let (x, y) = (xs.sample(t), ys.sample(t));
+            assert!(
+                ((x * x + y * y).sqrt() - 75.0).abs() < 1e-3,
+                "radius drifted at t={}",
+                t
+            );
+        }
+    }
+
+    #[test]
+    fn test_axis_ranges_bound_center() {
+        let orbit = CircularOrbit::new(100.0, -20.0, 10.0, 1.0, 0.0);
+        let rx = orbit.x_signal().output_range();
+        let ry = orbit.y_signal().output_range();
+        assert_eq!((rx.min, rx.max), (90.0, 110.0));
+        assert_eq!((ry.min, ry.max), (-30.0, -10.0));
+    }
 }
 
 // <FILE>src/physics/cls_orbit.rs</FILE> - <DESC>Circular orbital motion solver</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 1.4.0</VERS>
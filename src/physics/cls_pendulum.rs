@@ -1,10 +1,13 @@
 // <FILE>src/physics/cls_pendulum.rs</FILE> - <DESC>Simple pendulum oscillation solver</DESC>
-// <VERS>VERSION: 1.2.0</VERS>
-// <WCTX>Deduplication refactor</WCTX>
-// <CLOG>Use harmonic_phase/harmonic_sin_cos helpers for trig stability</CLOG>
+// <VERS>VERSION: 1.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_f64, finite_or_min, harmonic_phase, harmonic_sin_cos};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Simple pendulum oscillation solver.
@@ -35,7 +38,8 @@ use serde::{Deserialize, Serialize};
 /// let pendulum = SimplePendulum::new(1.0, 9.8, 0.3, 0.05);
 /// let angle = pendulum.sample(0.5); // Angle at t=0.5s
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SimplePendulum {
     /// Pendulum length (meters).
     pub length: f32,
@@ -81,7 +85,7 @@ impl SimplePendulum {
         if omega.abs() < 1e-6 {
             return f32::INFINITY;
         }
-        2.0 * std::f32::consts::PI / omega
+        2.0 * core::f32::consts::PI / omega
     }
 
     /// Angle at time t in radians.
@@ -237,4 +241,4 @@ mod tests {
 }
 
 // <FILE>src/physics/cls_pendulum.rs</FILE> - <DESC>Simple pendulum oscillation solver</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 1.3.0</VERS>
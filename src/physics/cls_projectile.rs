@@ -1,10 +1,14 @@
 // <FILE>src/physics/cls_projectile.rs</FILE> - <DESC>Ballistic trajectory solver</DESC>
-// <VERS>VERSION: 1.1.0</VERS>
-// <WCTX>Physics module robustness improvements</WCTX>
-// <CLOG>Remove Vec allocation in time_to_ground, consistent negative time handling</CLOG>
+// <VERS>VERSION: 1.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std</CLOG>
 
+use super::AxisSignal;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_f64};
-use crate::traits::{Signal, SignalRange, SignalTime};
+use crate::traits::{Axis, Planar, Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Ballistic trajectory solver for parabolic motion under gravity.
@@ -19,8 +23,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Signal Integration
 ///
-/// Implements `Signal` where `sample(t)` returns the Y position at time t.
-/// For X position or full coordinates, use `position_at()`.
+/// Implements `Signal` where `sample(t)` returns the **Y** position at time t
+/// (kept for backward compatibility). For full coordinates use `position_at()`,
+/// or `x_signal()` / `y_signal()` to compose each axis as its own signal.
 ///
 /// # Example
 ///
@@ -31,7 +36,8 @@ use serde::{Deserialize, Serialize};
 /// let toss = BallisticTrajectory::new(0.0, 100.0, 50.0, -200.0, 500.0, Some(500.0));
 /// let (x, y) = toss.position_at(0.5);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BallisticTrajectory {
     /// Starting X position.
     pub start_x: f32,
@@ -117,6 +123,34 @@ impl BallisticTrajectory {
         (vx as f32, vy as f32)
     }
 
+    /// Time at which vertical velocity crosses zero (the top of the arc).
+    ///
+    /// Returns `None` when gravity is zero or the projectile is already
+    /// moving with gravity (no apex ahead of `t = 0`).
+    pub fn time_to_apex(&self) -> Option<f64> {
+        let v0_y = finite_or(self.v0_y, 0.0) as f64;
+        let g = finite_or(self.gravity, 0.0) as f64;
+        if g.abs() < 1e-10 {
+            return None;
+        }
+        let t = -v0_y / g;
+        if t >= 0.0 {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    /// X position as a standalone signal.
+    pub fn x_signal(&self) -> AxisSignal<Self> {
+        AxisSignal::new(*self, Axis::X)
+    }
+
+    /// Y position as a standalone signal (same values as `sample()`).
+    pub fn y_signal(&self) -> AxisSignal<Self> {
+        AxisSignal::new(*self, Axis::Y)
+    }
+
     /// Time when projectile reaches ground_y, if specified.
     /// Returns None if no ground or if starting below ground.
     pub fn time_to_ground(&self) -> Option<f64> {
@@ -177,6 +211,31 @@ impl Default for BallisticTrajectory {
     }
 }
 
+impl Planar for BallisticTrajectory {
+    fn position_at(&self, t: SignalTime) -> (f32, f32) {
+        BallisticTrajectory::position_at(self, t)
+    }
+
+    fn velocity_at(&self, t: SignalTime) -> (f32, f32) {
+        BallisticTrajectory::velocity_at(self, t)
+    }
+
+    fn axis_range(&self, axis: Axis) -> SignalRange {
+        match axis {
+            Axis::X => {
+                // Horizontal travel until landing, or a fixed estimate without ground
+                let start_x = finite_or(self.start_x, 0.0);
+                let end_x = match self.time_to_ground() {
+                    Some(t) => self.position_at(t).0,
+                    None => start_x + finite_or(self.v0_x, 0.0).signum() * 200.0,
+                };
+                SignalRange::new(start_x.min(end_x), start_x.max(end_x))
+            }
+            Axis::Y => self.output_range(),
+        }
+    }
+}
+
 impl Signal for BallisticTrajectory {
     fn output_range(&self) -> SignalRange {
         // Range is from start_y to ground_y (or unbounded if no ground)
@@ -282,7 +341,37 @@ mod tests {
         let (x, _) = traj.position_at(1.0);
         assert!(x.is_finite());
     }
+
+    #[test]
+    fn test_apex_time_matches_vy_over_g() {
+        let traj = BallisticTrajectory::toss(0.0, 0.0, 20.0, -150.0, 300.0);
+        let apex = traj.time_to_apex().unwrap();
+        assert!((apex - 0.5).abs() < 1e-9);
+
+        // Vertical velocity is zero and y is at its minimum at the apex
+        let (_, vy) = traj.velocity_at(apex);
+        assert!(vy.abs() < EPSILON);
+        let y_apex = traj.position_at(apex).1;
+        assert!(y_apex <= traj.position_at(apex - 0.05).1);
+        assert!(y_apex <= traj.position_at(apex + 0.05).1);
+
+        let falling = BallisticTrajectory::toss(0.0, 0.0, 0.0, 50.0, 300.0);
+        assert!(falling.time_to_apex().is_none());
+    }
+
+    #[test]
+    fn test_axis_signals_match_position() {
+        let traj = BallisticTrajectory::default();
+        let (xs, ys) = (traj.x_signal(), traj.y_signal());
+        for i in 0..30 {
+            let t = i as f64 * 0.05;
+            assert_eq!((xs.sample(t), ys.sample(t)), traj.position_at(t));
+            assert_eq!(ys.sample(t), traj.sample(t));
+        }
+        let range = xs.output_range();
+        assert!(range.min <= 0.0 && range.max > 0.0);
+    }
 }
 
 // <FILE>src/physics/cls_projectile.rs</FILE> - <DESC>Ballistic trajectory solver</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 1.3.0</VERS>
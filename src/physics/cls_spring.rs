@@ -1,10 +1,13 @@
 // <FILE>src/physics/cls_spring.rs</FILE> - <DESC>Damped spring harmonic motion solver</DESC>
-// <VERS>VERSION: 1.2.0</VERS>
-// <WCTX>Deduplication refactor</WCTX>
-// <CLOG>Use harmonic_sin_cos helper for trig stability</CLOG>
+// <VERS>VERSION: 1.4.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, FloatExt import for libm math without std</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::math::{finite_or, finite_or_f64, finite_or_min, harmonic_sin_cos};
 use crate::traits::{Signal, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Damped spring harmonic motion solver.
@@ -19,6 +22,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// Implements `Signal` where `sample(t)` returns the displacement from equilibrium
 /// at time `t` (seconds). Output range is dynamic based on initial conditions.
+/// For a target that moves over time, use [`SpringTracker`](super::SpringTracker).
 ///
 /// # Example
 ///
@@ -30,7 +34,8 @@ use serde::{Deserialize, Serialize};
 /// let spring = DampedSpring::new(1.0, 100.0, 5.0, 0.0, 1.0);
 /// let pos = spring.sample(0.5); // Position at t=0.5s
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DampedSpring {
     /// Mass of the object (kg). Must be positive; defaults to 1.0.
     pub mass: f32,
@@ -291,4 +296,4 @@ mod tests {
 }
 
 // <FILE>src/physics/cls_spring.rs</FILE> - <DESC>Damped spring harmonic motion solver</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 1.4.0</VERS>
@@ -0,0 +1,260 @@
+// <FILE>src/physics/cls_spring_tracker.rs</FILE> - <DESC>Retargetable damped spring</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Alloc imports</CLOG>
+
+use super::DampedSpring;
+use crate::math::{finite_or, finite_or_f64};
+use crate::traits::{Signal, SignalRange, SignalTime};
+use alloc::vec::Vec;
+
+/// One analytic spring solution, valid from `start` until the next segment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SpringSegment {
+    start: SignalTime,
+    target: f32,
+    spring: DampedSpring,
+}
+
+impl SpringSegment {
+    fn position_at(&self, t: SignalTime) -> f32 {
+        self.target + self.spring.position_at(t - self.start)
+    }
+
+    fn velocity_at(&self, t: SignalTime) -> f32 {
+        self.spring.velocity_at(t - self.start)
+    }
+}
+
+/// Damped spring that chases a target which can change over time.
+///
+/// [`DampedSpring`] describes displacement from a fixed equilibrium. UI springs
+/// usually need to head somewhere new mid-flight ("spring to where the user
+/// just clicked"). `SpringTracker` keeps a list of analytic segments: each
+/// [`retarget`](Self::retarget) evaluates the current position and velocity at
+/// the retarget instant and starts a new spring solution from exactly that
+/// state, so motion is continuous in both position and velocity.
+///
+/// `sample(t)` returns the absolute position (not displacement). The result
+/// depends only on the sequence of `retarget` calls, never on how or when the
+/// tracker was sampled.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::physics::{DampedSpring, SpringTracker};
+/// use mixed_signals::traits::Signal;
+///
+/// let mut tracker = SpringTracker::at_rest(DampedSpring::default(), 0.0);
+/// tracker.retarget(100.0, 0.0);  // click: head to 100
+/// tracker.retarget(40.0, 0.25);  // click again mid-flight
+/// assert!((tracker.sample(5.0) - 40.0).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpringTracker {
+    segments: Vec<SpringSegment>,
+}
+
+impl SpringTracker {
+    /// Create a tracker heading to `target`, starting at time 0.
+    ///
+    /// The spring's `x0` and `v0` are the initial displacement from `target`
+    /// and the initial velocity.
+    pub fn new(spring: DampedSpring, target: f32) -> Self {
+        Self {
+            segments: vec![SpringSegment {
+                start: 0.0,
+                target: finite_or(target, 0.0),
+                spring,
+            }],
+        }
+    }
+
+    /// Create a tracker resting at `position` (zero displacement and velocity).
+    ///
+    /// Only the spring's mass, stiffness, and damping are used.
+    pub fn at_rest(spring: DampedSpring, position: f32) -> Self {
+        Self::new(
+            DampedSpring {
+                x0: 0.0,
+                v0: 0.0,
+                ..spring
+            },
+            position,
+        )
+    }
+
+    /// Send the spring toward `new_target` starting at `at_time`.
+    ///
+    /// Position and velocity at `at_time` are captured analytically from the
+    /// current motion, so the path is C1-continuous across the retarget.
+    /// Retargeting at a time earlier than a previous retarget discards the
+    /// later segments (history is rewritten from that point).
+    pub fn retarget(&mut self, new_target: f32, at_time: SignalTime) {
+        let at_time = finite_or_f64(at_time, 0.0);
+        let new_target = finite_or(new_target, 0.0);
+
+        let active = *self.active_segment(at_time);
+        let position = active.position_at(at_time);
+        let velocity = active.velocity_at(at_time);
+
+        let keep = self.segments.partition_point(|seg| seg.start <= at_time);
+        self.segments.truncate(keep.max(1));
+
+        self.segments.push(SpringSegment {
+            start: at_time,
+            target: new_target,
+            spring: DampedSpring {
+                x0: position - new_target,
+                v0: velocity,
+                ..active.spring
+            },
+        });
+    }
+
+    /// Alias for [`retarget`](Self::retarget) that returns `self` for chaining.
+    pub fn spring_to(mut self, new_target: f32, at_time: SignalTime) -> Self {
+        self.retarget(new_target, at_time);
+        self
+    }
+
+    /// Position at time `t`.
+    pub fn position_at(&self, t: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        self.active_segment(t).position_at(t)
+    }
+
+    /// Velocity at time `t`.
+    pub fn velocity_at(&self, t: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        self.active_segment(t).velocity_at(t)
+    }
+
+    /// The most recent target.
+    pub fn target(&self) -> f32 {
+        self.last_segment().target
+    }
+
+    /// Target in effect at time `t`.
+    pub fn target_at(&self, t: SignalTime) -> f32 {
+        self.active_segment(finite_or_f64(t, 0.0)).target
+    }
+
+    /// Number of retargets applied since construction.
+    pub fn retarget_count(&self) -> usize {
+        self.segments.len() - 1
+    }
+
+    fn last_segment(&self) -> &SpringSegment {
+        self.segments
+            .last()
+            .expect("SpringTracker always has at least one segment")
+    }
+
+    fn active_segment(&self, t: SignalTime) -> &SpringSegment {
+        let idx = self.segments.partition_point(|seg| seg.start <= t);
+        &self.segments[idx.saturating_sub(1)]
+    }
+}
+
+impl Default for SpringTracker {
+    fn default() -> Self {
+        Self::at_rest(DampedSpring::default(), 0.0)
+    }
+}
+
+impl Signal for SpringTracker {
+    fn output_range(&self) -> SignalRange {
+        let (mut min, mut max) = (f32::INFINITY, f32::NEG_INFINITY);
+        for seg in &self.segments {
+            let range = seg.spring.output_range();
+            min = min.min(seg.target + range.min);
+            max = max.max(seg.target + range.max);
+        }
+        SignalRange::new(min, max)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.position_at(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retarget_is_c1_continuous() {
+        let spring = DampedSpring::new(1.0, 120.0, 6.0, 0.0, 0.0);
+        let mut tracker = SpringTracker::at_rest(spring, 0.0).spring_to(1.0, 0.0);
+        let before = tracker.clone();
+
+        let t = 0.137;
+        tracker.retarget(-2.0, t);
+
+        let dp = (tracker.position_at(t) - before.position_at(t)).abs();
+        let dv = (tracker.velocity_at(t) - before.velocity_at(t)).abs();
+        assert!(dp < 1e-5, "position jump {}", dp);
+        assert!(dv < 1e-4, "velocity jump {}", dv);
+
+        // Earlier samples are unaffected by the later retarget
+        assert_eq!(tracker.sample(0.1), before.sample(0.1));
+        // Finite-difference slope just after the retarget matches the old velocity
+        let h = 1e-4;
+        let slope = (tracker.position_at(t + h) - tracker.position_at(t)) / h as f32;
+        assert!(
+            (slope - before.velocity_at(t)).abs() < 0.05,
+            "slope {}",
+            slope
+        );
+    }
+
+    #[test]
+    fn test_converges_to_final_target() {
+        let mut tracker = SpringTracker::default();
+        tracker.retarget(100.0, 0.0);
+        tracker.retarget(-50.0, 0.2);
+        tracker.retarget(30.0, 0.45);
+
+        assert_eq!(tracker.target(), 30.0);
+        assert_eq!(tracker.retarget_count(), 3);
+        assert!((tracker.sample(6.0) - 30.0).abs() < 0.01);
+        assert!(tracker.velocity_at(6.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_same_calls_are_deterministic() {
+        let build = || {
+            SpringTracker::at_rest(DampedSpring::critically_damped(80.0, 0.0), 5.0)
+                .spring_to(10.0, 0.1)
+                .spring_to(2.0, 0.3)
+        };
+        let (a, b) = (build(), build());
+        for i in 0..100 {
+            let t = i as f64 * 0.013;
+            assert_eq!(a.sample(t), b.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_retarget_in_past_rewrites_history() {
+        let mut tracker = SpringTracker::default()
+            .spring_to(10.0, 0.5)
+            .spring_to(20.0, 1.0);
+        tracker.retarget(-5.0, 0.75);
+
+        assert_eq!(tracker.retarget_count(), 2);
+        assert_eq!(tracker.target_at(0.6), 10.0);
+        assert_eq!(tracker.target_at(2.0), -5.0);
+    }
+
+    #[test]
+    fn test_before_start_holds_initial_state() {
+        let tracker = SpringTracker::at_rest(DampedSpring::default(), 3.0);
+        assert_eq!(tracker.sample(-1.0), 3.0);
+        assert_eq!(tracker.sample(f64::NAN), 3.0);
+    }
+}
+
+// <FILE>src/physics/cls_spring_tracker.rs</FILE> - <DESC>Retargetable damped spring</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,7 +1,7 @@
 // <FILE>src/physics/mod.rs</FILE> - <DESC>Physics solvers module</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Physics module implementation</WCTX>
-// <CLOG>Initial module hub with all physics components</CLOG>
+// <VERS>VERSION: 1.3.0</VERS>
+// <WCTX>Kinetic scrolling queries</WCTX>
+// <CLOG>Export DEFAULT_FRICTION_STOP_THRESHOLD</CLOG>
 
 //! Physics solvers for UI animations and simulations.
 //!
@@ -13,12 +13,14 @@
 //! # Components
 //!
 //! - [`DampedSpring`] - Harmonic motion with configurable damping
+//! - [`SpringTracker`] - Damped spring that can be retargeted mid-flight
 //! - [`BallisticTrajectory`] - Projectile motion under gravity
 //! - [`FrictionDecay`] - Exponential velocity decay (scrolling, flinging)
 //! - [`SimplePendulum`] - Pendulum oscillation
 //! - [`CircularOrbit`] - Uniform circular motion
 //! - [`PointAttractor`] - Force field toward a point
 //! - [`BouncingDrop`] - Multi-bounce with energy loss
+//! - [`AxisSignal`] - One axis of a 2D solver as a composable signal
 //!
 //! # Example
 //!
@@ -36,20 +38,24 @@
 //! ```
 
 mod cls_attractor;
+mod cls_axis_signal;
 mod cls_bounce;
 mod cls_decay;
 mod cls_orbit;
 mod cls_pendulum;
 mod cls_projectile;
 mod cls_spring;
+mod cls_spring_tracker;
 
 pub use cls_attractor::PointAttractor;
+pub use cls_axis_signal::AxisSignal;
 pub use cls_bounce::BouncingDrop;
-pub use cls_decay::FrictionDecay;
+pub use cls_decay::{FrictionDecay, DEFAULT_FRICTION_STOP_THRESHOLD};
 pub use cls_orbit::CircularOrbit;
 pub use cls_pendulum::SimplePendulum;
 pub use cls_projectile::BallisticTrajectory;
 pub use cls_spring::DampedSpring;
+pub use cls_spring_tracker::SpringTracker;
 
 // <FILE>src/physics/mod.rs</FILE> - <DESC>Physics solvers module</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.3.0</VERS>
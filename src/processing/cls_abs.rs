@@ -1,7 +1,7 @@
 // <FILE>mixed-signals/src/processing/cls_abs.rs</FILE> - <DESC>Signal absolute value operator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor</WCTX>
-// <CLOG>Removed clamping, added output_range() for bipolar compatibility</CLOG>
+// <VERS>VERSION: 2.1.0</VERS>
+// <WCTX>Cloneable signal collections</WCTX>
+// <CLOG>Require Clone on inner signals for SignalClone</CLOG>
 
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 
@@ -22,7 +22,7 @@ impl<S: Signal> Abs<S> {
     }
 }
 
-impl<S: Signal> Signal for Abs<S> {
+impl<S: Signal + Clone> Signal for Abs<S> {
     fn output_range(&self) -> SignalRange {
         let r = self.signal.output_range();
         // Abs of range: max is max(|min|, |max|)
@@ -52,6 +52,7 @@ mod tests {
     use super::*;
     use crate::generators::{Constant, Sine};
 
+    #[derive(Clone)]
     struct BipolarConstant(f32);
 
     impl Signal for BipolarConstant {
@@ -101,6 +102,7 @@ mod tests {
         assert!((range.max - 1.0).abs() < 0.001);
 
         // Positive-only range [0.2, 0.8] stays [0.2, 0.8]
+        #[derive(Clone)]
         struct PositiveSignal;
         impl Signal for PositiveSignal {
             fn output_range(&self) -> SignalRange {
@@ -118,4 +120,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/processing/cls_abs.rs</FILE> - <DESC>Signal absolute value operator</DESC>
-// <VERS>END OF VERSION: 2.0.0</VERS>
+// <VERS>END OF VERSION: 2.1.0</VERS>
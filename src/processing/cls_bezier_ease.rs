@@ -0,0 +1,85 @@
+// <FILE>src/processing/cls_bezier_ease.rs</FILE> - <DESC>Apply a cubic-bezier easing curve to a unit-range signal</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Cubic-bezier easing</WCTX>
+// <CLOG>Initial creation - BezierEase shapes a [0, 1] signal through a CubicBezier</CLOG>
+
+use crate::easing::CubicBezier;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Shapes a [0, 1] signal through a CSS-style cubic-bezier curve.
+///
+/// This is the [`Eased`](super::Eased) counterpart for [`CubicBezier`]:
+/// output = curve(inner(t)). The input is clamped to [0, 1]. The output is
+/// not clamped, so a curve with y control points outside [0, 1] overshoots.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::easing::CubicBezier;
+/// use mixed_signals::generators::Ramp;
+/// use mixed_signals::traits::{Signal, SignalExt};
+///
+/// let slide = Ramp::new(0.0, 1.0, 0.5).bezier_eased(CubicBezier::ease_out());
+/// assert_eq!(slide.sample(0.0), 0.0);
+/// assert_eq!(slide.sample(0.5), 1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BezierEase<S> {
+    pub inner: S,
+    pub curve: CubicBezier,
+}
+
+impl<S: Signal> BezierEase<S> {
+    pub fn new(inner: S, curve: CubicBezier) -> Self {
+        Self { inner, curve }
+    }
+}
+
+impl<S: Signal + Clone> Signal for BezierEase<S> {
+    fn output_range(&self) -> SignalRange {
+        if !self.curve.overshoots() {
+            return SignalRange::UNIT;
+        }
+        let (min, max) = (0..=512)
+            .map(|i| self.curve.apply(i as SignalTime / 512.0))
+            .fold((0.0f32, 1.0f32), |(lo, hi), v| (lo.min(v), hi.max(v)));
+        SignalRange::new(min, max)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.curve.apply(self.inner.sample(t) as SignalTime)
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.curve
+            .apply(self.inner.sample_with_context(t, ctx) as SignalTime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Ramp;
+    use crate::traits::SignalExt;
+
+    #[test]
+    fn test_bezier_ease_follows_curve() {
+        let ramp = Ramp::new(0.0, 1.0, 2.0);
+        let eased = ramp.bezier_eased(CubicBezier::ease());
+        assert_eq!(eased.sample(0.0), 0.0);
+        assert_eq!(eased.sample(2.0), 1.0);
+        assert_eq!(eased.sample(1.0), CubicBezier::ease().apply(0.5));
+        assert_eq!(eased.output_range(), SignalRange::UNIT);
+    }
+
+    #[test]
+    fn test_bezier_ease_reports_overshoot() {
+        let ramp = Ramp::new(0.0, 1.0, 1.0);
+        let springy = BezierEase::new(ramp, CubicBezier::new(0.3, -0.4, 0.6, 1.6));
+        let range = springy.output_range();
+        assert!(range.min < 0.0 && range.max > 1.0, "{:?}", range);
+    }
+}
+
+// <FILE>src/processing/cls_bezier_ease.rs</FILE> - <DESC>Apply a cubic-bezier easing curve to a unit-range signal</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
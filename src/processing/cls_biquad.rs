@@ -1,42 +1,21 @@
 // <FILE>src/processing/cls_biquad.rs</FILE> - <DESC>Biquad filter with multiple modes</DESC>
-// <VERS>VERSION: 1.0.1</VERS>
-// <WCTX>Clippy fixes</WCTX>
-// <CLOG>Use FRAC_1_SQRT_2 constant instead of 0.7071 literal</CLOG>
+// <VERS>VERSION: 1.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Move BiquadMode next to BiquadState so it builds without std</CLOG>
 
-use crate::traits::{Signal, SignalContext, SignalTime};
-use std::f32::consts::{FRAC_1_SQRT_2, PI};
+use super::{BiquadMode, BiquadState};
+use crate::traits::{Processor, Signal, SignalContext, SignalTime};
+use core::f32::consts::FRAC_1_SQRT_2;
 use std::sync::Mutex;
 
-/// Filter mode for the biquad filter.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum BiquadMode {
-    /// Low-pass: passes frequencies below cutoff
-    LowPass,
-    /// High-pass: passes frequencies above cutoff
-    HighPass,
-    /// Band-pass: passes frequencies around cutoff
-    BandPass,
-    /// Notch (band-reject): attenuates frequencies around cutoff
-    Notch,
-}
-
-/// Biquad filter state
-#[derive(Debug, Clone, Copy, Default)]
-struct BiquadState {
-    x1: f32, // x[n-1]
-    x2: f32, // x[n-2]
-    y1: f32, // y[n-1]
-    y2: f32, // y[n-2]
-    prev_time: f64,
-}
-
 /// Second-order biquad filter with configurable mode and Q.
 ///
 /// Implements the standard biquad difference equation:
 /// `y[n] = b0*x[n] + b1*x[n-1] + b2*x[n-2] - a1*y[n-1] - a2*y[n-2]`
 ///
 /// Supports low-pass, high-pass, band-pass, and notch filter modes.
-/// The Q parameter controls resonance/bandwidth.
+/// The Q parameter controls resonance/bandwidth. For streaming use (audio
+/// callbacks), use [`BiquadState`] directly.
 ///
 /// # Example
 /// ```ignore
@@ -50,14 +29,8 @@ pub struct Biquad<S> {
     cutoff_hz: f32,
     q: f32,
     sample_rate: f32,
-    // Coefficients
-    b0: f32,
-    b1: f32,
-    b2: f32,
-    a1: f32,
-    a2: f32,
-    // State
-    state: Mutex<BiquadState>,
+    // Filter plus the last processed time
+    state: Mutex<(BiquadState, SignalTime)>,
 }
 
 impl<S: Signal> Biquad<S> {
@@ -70,19 +43,13 @@ impl<S: Signal> Biquad<S> {
     /// * `q` - Quality factor (0.5 = gentle, 0.707 = Butterworth, >1 = resonant)
     /// * `sample_rate` - Sample rate in Hz
     pub fn new(signal: S, mode: BiquadMode, cutoff_hz: f32, q: f32, sample_rate: f32) -> Self {
-        let (b0, b1, b2, a1, a2) = Self::compute_coefficients(mode, cutoff_hz, q, sample_rate);
         Self {
             signal,
             mode,
             cutoff_hz,
             q,
             sample_rate,
-            b0,
-            b1,
-            b2,
-            a1,
-            a2,
-            state: Mutex::new(BiquadState::default()),
+            state: Mutex::new((BiquadState::new(mode, cutoff_hz, q, sample_rate), 0.0)),
         }
     }
 
@@ -137,96 +104,44 @@ impl<S: Signal> Biquad<S> {
     pub fn sample_rate(&self) -> f32 {
         self.sample_rate
     }
+}
 
-    /// Compute biquad coefficients using RBJ Audio EQ Cookbook formulas.
-    fn compute_coefficients(
-        mode: BiquadMode,
-        cutoff_hz: f32,
-        q: f32,
-        sample_rate: f32,
-    ) -> (f32, f32, f32, f32, f32) {
-        let omega = 2.0 * PI * cutoff_hz / sample_rate;
-        let sin_omega = omega.sin();
-        let cos_omega = omega.cos();
-        let alpha = sin_omega / (2.0 * q.max(0.001));
-
-        let (b0, b1, b2, a0, a1, a2) = match mode {
-            BiquadMode::LowPass => {
-                let b1 = 1.0 - cos_omega;
-                let b0 = b1 / 2.0;
-                let b2 = b0;
-                let a0 = 1.0 + alpha;
-                let a1 = -2.0 * cos_omega;
-                let a2 = 1.0 - alpha;
-                (b0, b1, b2, a0, a1, a2)
-            }
-            BiquadMode::HighPass => {
-                let b1 = -(1.0 + cos_omega);
-                let b0 = (1.0 + cos_omega) / 2.0;
-                let b2 = b0;
-                let a0 = 1.0 + alpha;
-                let a1 = -2.0 * cos_omega;
-                let a2 = 1.0 - alpha;
-                (b0, b1, b2, a0, a1, a2)
-            }
-            BiquadMode::BandPass => {
-                let b0 = alpha;
-                let b1 = 0.0;
-                let b2 = -alpha;
-                let a0 = 1.0 + alpha;
-                let a1 = -2.0 * cos_omega;
-                let a2 = 1.0 - alpha;
-                (b0, b1, b2, a0, a1, a2)
-            }
-            BiquadMode::Notch => {
-                let b0 = 1.0;
-                let b1 = -2.0 * cos_omega;
-                let b2 = 1.0;
-                let a0 = 1.0 + alpha;
-                let a1 = -2.0 * cos_omega;
-                let a2 = 1.0 - alpha;
-                (b0, b1, b2, a0, a1, a2)
-            }
-        };
-
-        // Normalize by a0
-        (b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0)
-    }
-
-    fn process_sample(&self, input: f32, state: &mut BiquadState) -> f32 {
-        let output = self.b0 * input + self.b1 * state.x1 + self.b2 * state.x2
-            - self.a1 * state.y1
-            - self.a2 * state.y2;
-
-        // Update state
-        state.x2 = state.x1;
-        state.x1 = input;
-        state.y2 = state.y1;
-        state.y1 = output;
-
-        output
+/// Clones the configuration only; the clone starts with fresh filter state.
+impl<S: Clone> Clone for Biquad<S> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            mode: self.mode,
+            cutoff_hz: self.cutoff_hz,
+            q: self.q,
+            sample_rate: self.sample_rate,
+            state: Mutex::new((
+                BiquadState::new(self.mode, self.cutoff_hz, self.q, self.sample_rate),
+                0.0,
+            )),
+        }
     }
 }
 
-impl<S: Signal> Signal for Biquad<S> {
+impl<S: Signal + Clone> Signal for Biquad<S> {
     fn sample(&self, t: SignalTime) -> f32 {
         let input = self.signal.sample(t);
 
         let mut state = self.state.lock().unwrap();
+        let (filter, prev_time) = &mut *state;
 
         // Only process if time has advanced
-        if t > state.prev_time {
-            let output = self.process_sample(input, &mut state);
-            state.prev_time = t;
-            output
-        } else if t < state.prev_time {
+        if t > *prev_time {
+            *prev_time = t;
+            filter.process(input)
+        } else if t < *prev_time {
             // Time went backwards - reset state
-            *state = BiquadState::default();
-            state.prev_time = t;
+            filter.reset();
+            *prev_time = t;
             input
         } else {
             // Same time - return last output
-            state.y1
+            filter.last_output()
         }
     }
 
@@ -234,17 +149,17 @@ impl<S: Signal> Signal for Biquad<S> {
         let input = self.signal.sample_with_context(t, ctx);
 
         let mut state = self.state.lock().unwrap();
-
-        if t > state.prev_time {
-            let output = self.process_sample(input, &mut state);
-            state.prev_time = t;
-            output
-        } else if t < state.prev_time {
-            *state = BiquadState::default();
-            state.prev_time = t;
+        let (filter, prev_time) = &mut *state;
+
+        if t > *prev_time {
+            *prev_time = t;
+            filter.process(input)
+        } else if t < *prev_time {
+            filter.reset();
+            *prev_time = t;
             input
         } else {
-            state.y1
+            filter.last_output()
         }
     }
 }
@@ -254,6 +169,7 @@ mod tests {
     use super::*;
     use crate::generators::Constant;
 
+    #[derive(Clone)]
     struct StepSignal {
         step_time: f64,
     }
@@ -364,7 +280,19 @@ mod tests {
             assert!(val.is_finite(), "Output should be finite at sample {}", i);
         }
     }
+
+    #[test]
+    fn test_biquad_matches_streaming_state() {
+        let step = StepSignal { step_time: 0.0 };
+        let lpf = Biquad::new(step, BiquadMode::LowPass, 800.0, 1.2, 48000.0);
+        let mut state = BiquadState::new(BiquadMode::LowPass, 800.0, 1.2, 48000.0);
+
+        for i in 1..256 {
+            let expected = state.process(1.0);
+            assert_eq!(lpf.sample(i as f64 / 48000.0), expected);
+        }
+    }
 }
 
 // <FILE>src/processing/cls_biquad.rs</FILE> - <DESC>Biquad filter with multiple modes</DESC>
-// <VERS>END OF VERSION: 1.0.1</VERS>
+// <VERS>END OF VERSION: 1.3.0</VERS>
@@ -0,0 +1,271 @@
+// <FILE>src/processing/cls_biquad_state.rs</FILE> - <DESC>Streaming biquad filter processor</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Own BiquadMode so the streaming processor builds without std</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::Processor;
+use core::f32::consts::{FRAC_1_SQRT_2, PI};
+
+/// Filter mode for the biquad filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BiquadMode {
+    /// Low-pass: passes frequencies below cutoff
+    LowPass,
+    /// High-pass: passes frequencies above cutoff
+    HighPass,
+    /// Band-pass: passes frequencies around cutoff
+    BandPass,
+    /// Notch (band-reject): attenuates frequencies around cutoff
+    Notch,
+}
+
+/// Normalized biquad coefficients `(b0, b1, b2, a1, a2)`.
+pub(crate) type BiquadCoefficients = (f32, f32, f32, f32, f32);
+
+/// Compute biquad coefficients using RBJ Audio EQ Cookbook formulas.
+///
+/// Shared by [`BiquadState`] and the [`Biquad`](super::Biquad) signal adapter.
+pub(crate) fn biquad_coefficients(
+    mode: BiquadMode,
+    cutoff_hz: f32,
+    q: f32,
+    sample_rate: f32,
+) -> BiquadCoefficients {
+    let omega = 2.0 * PI * cutoff_hz / sample_rate;
+    let sin_omega = omega.sin();
+    let cos_omega = omega.cos();
+    let alpha = sin_omega / (2.0 * q.max(0.001));
+
+    let (b0, b1, b2) = match mode {
+        BiquadMode::LowPass => {
+            let b1 = 1.0 - cos_omega;
+            (b1 / 2.0, b1, b1 / 2.0)
+        }
+        BiquadMode::HighPass => {
+            let b0 = (1.0 + cos_omega) / 2.0;
+            (b0, -(1.0 + cos_omega), b0)
+        }
+        BiquadMode::BandPass => (alpha, 0.0, -alpha),
+        BiquadMode::Notch => (1.0, -2.0 * cos_omega, 1.0),
+    };
+    let a0 = 1.0 + alpha;
+    let a1 = -2.0 * cos_omega;
+    let a2 = 1.0 - alpha;
+
+    // Normalize by a0
+    (b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0)
+}
+
+/// Streaming second-order biquad filter.
+///
+/// The [`Processor`] counterpart of [`Biquad`](super::Biquad): same
+/// coefficients, but driven one input sample at a time instead of by time
+/// queries. Suitable for audio callbacks and other real-time loops.
+///
+/// # Example
+/// ```
+/// use mixed_signals::processing::BiquadState;
+/// use mixed_signals::traits::Processor;
+///
+/// let mut notch = BiquadState::notch(450.0, 2.0, 48000.0);
+/// let y = notch.process(0.5);
+/// assert!(y.is_finite());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BiquadState {
+    mode: BiquadMode,
+    cutoff_hz: f32,
+    q: f32,
+    sample_rate: f32,
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+    x1: f32, // x[n-1]
+    x2: f32, // x[n-2]
+    y1: f32, // y[n-1]
+    y2: f32, // y[n-2]
+}
+
+impl BiquadState {
+    /// Create a new streaming biquad filter.
+    ///
+    /// # Arguments
+    /// * `mode` - Filter mode (LowPass, HighPass, BandPass, Notch)
+    /// * `cutoff_hz` - Cutoff/center frequency in Hz
+    /// * `q` - Quality factor (0.707 = Butterworth, >1 = resonant)
+    /// * `sample_rate` - Sample rate in Hz
+    pub fn new(mode: BiquadMode, cutoff_hz: f32, q: f32, sample_rate: f32) -> Self {
+        let (b0, b1, b2, a1, a2) = biquad_coefficients(mode, cutoff_hz, q, sample_rate);
+        Self {
+            mode,
+            cutoff_hz,
+            q,
+            sample_rate,
+            b0,
+            b1,
+            b2,
+            a1,
+            a2,
+            x1: 0.0,
+            x2: 0.0,
+            y1: 0.0,
+            y2: 0.0,
+        }
+    }
+
+    /// Create a low-pass filter with Butterworth response (Q = 1/√2).
+    pub fn lowpass(cutoff_hz: f32, sample_rate: f32) -> Self {
+        Self::new(BiquadMode::LowPass, cutoff_hz, FRAC_1_SQRT_2, sample_rate)
+    }
+
+    /// Create a high-pass filter with Butterworth response (Q = 1/√2).
+    pub fn highpass(cutoff_hz: f32, sample_rate: f32) -> Self {
+        Self::new(BiquadMode::HighPass, cutoff_hz, FRAC_1_SQRT_2, sample_rate)
+    }
+
+    /// Create a band-pass filter.
+    pub fn bandpass(center_hz: f32, q: f32, sample_rate: f32) -> Self {
+        Self::new(BiquadMode::BandPass, center_hz, q, sample_rate)
+    }
+
+    /// Create a notch (band-reject) filter.
+    pub fn notch(center_hz: f32, q: f32, sample_rate: f32) -> Self {
+        Self::new(BiquadMode::Notch, center_hz, q, sample_rate)
+    }
+
+    /// Retune the filter, keeping its history so sweeps stay click-free.
+    pub fn set_params(&mut self, mode: BiquadMode, cutoff_hz: f32, q: f32) {
+        let (b0, b1, b2, a1, a2) = biquad_coefficients(mode, cutoff_hz, q, self.sample_rate);
+        self.mode = mode;
+        self.cutoff_hz = cutoff_hz;
+        self.q = q;
+        self.b0 = b0;
+        self.b1 = b1;
+        self.b2 = b2;
+        self.a1 = a1;
+        self.a2 = a2;
+    }
+
+    /// Get the filter mode.
+    pub fn mode(&self) -> BiquadMode {
+        self.mode
+    }
+
+    /// Get the cutoff frequency in Hz.
+    pub fn cutoff_hz(&self) -> f32 {
+        self.cutoff_hz
+    }
+
+    /// Get the Q factor.
+    pub fn q(&self) -> f32 {
+        self.q
+    }
+
+    /// Get the sample rate in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Normalized coefficients as `(b0, b1, b2, a1, a2)`.
+    pub fn coefficients(&self) -> (f32, f32, f32, f32, f32) {
+        (self.b0, self.b1, self.b2, self.a1, self.a2)
+    }
+
+    /// Most recent output sample (0.0 after a reset).
+    pub fn last_output(&self) -> f32 {
+        self.y1
+    }
+}
+
+impl Processor for BiquadState {
+    fn process(&mut self, input: f32) -> f32 {
+        let output = self.b0 * input + self.b1 * self.x1 + self.b2 * self.x2
+            - self.a1 * self.y1
+            - self.a2 * self.y2;
+
+        self.x2 = self.x1;
+        self.x1 = input;
+        self.y2 = self.y1;
+        self.y1 = output;
+
+        output
+    }
+
+    fn reset(&mut self) {
+        self.x1 = 0.0;
+        self.x2 = 0.0;
+        self.y1 = 0.0;
+        self.y2 = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_biquad_state_impulse_matches_coefficients() {
+        let mut lp = BiquadState::lowpass(1000.0, 48000.0);
+        let (b0, b1, b2, a1, a2) = lp.coefficients();
+
+        // Reference coefficients from the RBJ cookbook (fc=1k, Q=1/√2, fs=48k)
+        assert!((b0 - 0.003_916_1).abs() < 1e-5, "b0 = {}", b0);
+        assert!((a1 + 1.815_341).abs() < 1e-4, "a1 = {}", a1);
+        assert!((a2 - 0.831_006).abs() < 1e-4, "a2 = {}", a2);
+
+        // Impulse response follows the difference equation directly
+        let h: Vec<f32> = (0..4)
+            .map(|i| lp.process(if i == 0 { 1.0 } else { 0.0 }))
+            .collect();
+        assert_eq!(h[0], b0);
+        assert!((h[1] - (b1 - a1 * h[0])).abs() < 1e-7);
+        assert!((h[2] - (b2 - a1 * h[1] - a2 * h[0])).abs() < 1e-7);
+        assert!((h[3] - (-a1 * h[2] - a2 * h[1])).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_biquad_state_reset_clears_history() {
+        let mut hp = BiquadState::highpass(500.0, 48000.0);
+        let first: Vec<f32> = (0..32).map(|_| hp.process(1.0)).collect();
+
+        hp.reset();
+        assert_eq!(hp.last_output(), 0.0);
+        let second: Vec<f32> = (0..32).map(|_| hp.process(1.0)).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_biquad_state_set_params_keeps_history() {
+        let mut bp = BiquadState::bandpass(1000.0, 1.0, 48000.0);
+        for _ in 0..16 {
+            bp.process(1.0);
+        }
+        let before = bp.last_output();
+        bp.set_params(BiquadMode::BandPass, 2000.0, 1.0);
+        assert_eq!(bp.last_output(), before);
+        assert_eq!(bp.cutoff_hz(), 2000.0);
+        assert_eq!(
+            bp.coefficients(),
+            biquad_coefficients(BiquadMode::BandPass, 2000.0, 1.0, 48000.0)
+        );
+    }
+
+    #[test]
+    fn test_biquad_state_process_block_matches_process() {
+        let input: Vec<f32> = (0..64).map(|i| ((i * 7) % 5) as f32 - 2.0).collect();
+        let mut a = BiquadState::notch(450.0, 2.0, 48000.0);
+        let mut b = a;
+
+        let expected: Vec<f32> = input.iter().map(|&x| a.process(x)).collect();
+        let mut buf = input.clone();
+        b.process_block(&mut buf);
+        assert_eq!(buf, expected);
+    }
+}
+
+// <FILE>src/processing/cls_biquad_state.rs</FILE> - <DESC>Streaming biquad filter processor</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
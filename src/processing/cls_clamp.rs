@@ -1,7 +1,7 @@
 // <FILE>mixed-signals/src/processing/cls_clamp.rs</FILE> - <DESC>Signal clamping operator</DESC>
-// <VERS>VERSION: 1.0.0 - 2025-12-22</VERS>
-// <WCTX>Signal generator implementation</WCTX>
-// <CLOG>Initial implementation</CLOG>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>Cloneable signal collections</WCTX>
+// <CLOG>Require Clone on inner signals for SignalClone</CLOG>
 
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 
@@ -36,7 +36,7 @@ impl<S: Signal> Clamp<S> {
     }
 }
 
-impl<S: Signal> Signal for Clamp<S> {
+impl<S: Signal + Clone> Signal for Clamp<S> {
     fn output_range(&self) -> SignalRange {
         SignalRange::new(self.min, self.max)
     }
@@ -101,4 +101,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/processing/cls_clamp.rs</FILE> - <DESC>Signal clamping operator</DESC>
-// <VERS>END OF VERSION: 1.0.0 - 2025-12-22</VERS>
+// <VERS>END OF VERSION: 1.1.0</VERS>
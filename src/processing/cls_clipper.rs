@@ -1,8 +1,10 @@
 // <FILE>src/processing/cls_clipper.rs</FILE> - <DESC>Asymmetric clipper/limiter</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Audio synthesis - adding distortion/saturation</WCTX>
-// <CLOG>Initial implementation - asymmetric hard/soft clipping</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::traits::{Signal, SignalContext, SignalTime};
 
 /// Clipping mode
@@ -112,7 +114,7 @@ impl<S: Signal> Clipper<S> {
     }
 }
 
-impl<S: Signal> Signal for Clipper<S> {
+impl<S: Signal + Clone> Signal for Clipper<S> {
     fn sample(&self, t: SignalTime) -> f32 {
         let input = self.signal.sample(t);
         self.clip(input)
@@ -130,6 +132,7 @@ mod tests {
     use crate::traits::SignalTime;
 
     /// Test signal that returns a raw value without clamping (for bipolar tests)
+    #[derive(Clone)]
     struct RawSignal(f32);
     impl Signal for RawSignal {
         fn sample(&self, _t: SignalTime) -> f32 {
@@ -235,4 +238,4 @@ mod tests {
 }
 
 // <FILE>src/processing/cls_clipper.rs</FILE> - <DESC>Asymmetric clipper/limiter</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
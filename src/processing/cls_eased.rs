@@ -0,0 +1,138 @@
+// <FILE>src/processing/cls_eased.rs</FILE> - <DESC>Apply an easing curve to a unit-range signal</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Configurable easing families</WCTX>
+// <CLOG>Initial creation - Eased shapes a [0, 1] signal through an easing curve</CLOG>
+
+use crate::easing::{ease_with, EaseParams, EasingType};
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Shapes a [0, 1] signal through an easing curve.
+///
+/// Output = ease(inner(t)). Feed it a progress signal such as
+/// `Ramp::new(0.0, 1.0, duration)` to turn a linear tween into a bounce,
+/// spring-back, or elastic snap. Input is clamped to [0, 1] first; unlike
+/// [`Normalized::with_easing`](super::Normalized::with_easing), the output is
+/// NOT clamped, so `Back*` and `Elastic*` curves overshoot as designed.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::easing::EasingType;
+/// use mixed_signals::generators::Ramp;
+/// use mixed_signals::traits::{Signal, SignalExt};
+///
+/// let drop_in = Ramp::new(0.0, 1.0, 0.8).eased(EasingType::BounceOut);
+/// assert_eq!(drop_in.sample(0.0), 0.0);
+/// assert!((drop_in.sample(0.8) - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Eased<S> {
+    pub inner: S,
+    pub easing: EasingType,
+    /// Overshoot / elastic tuning (only read by `Back*` and `Elastic*`)
+    pub params: EaseParams,
+}
+
+impl<S: Signal> Eased<S> {
+    pub fn new(inner: S, easing: EasingType) -> Self {
+        Self {
+            inner,
+            easing,
+            params: EaseParams::default(),
+        }
+    }
+
+    /// Replace the easing tuning parameters.
+    pub fn with_params(mut self, params: EaseParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Set how far `Back*` curves pull past the ends (default 1.70158).
+    pub fn with_overshoot(mut self, overshoot: f32) -> Self {
+        self.params.overshoot = overshoot;
+        self
+    }
+
+    /// Set the `Elastic*` amplitude and oscillation period.
+    pub fn with_elastic(mut self, amplitude: f32, period: f32) -> Self {
+        self.params = self.params.with_elastic(amplitude, period);
+        self
+    }
+
+    fn shape(&self, v: f32) -> f32 {
+        ease_with(v as SignalTime, self.easing, self.params)
+    }
+}
+
+impl<S: Signal + Clone> Signal for Eased<S> {
+    fn output_range(&self) -> SignalRange {
+        if !self.easing.overshoots() {
+            return SignalRange::UNIT;
+        }
+        // Overshooting curves: find the extremes numerically
+        let (min, max) = (0..=512)
+            .map(|i| ease_with(i as SignalTime / 512.0, self.easing, self.params))
+            .fold((0.0f32, 1.0f32), |(lo, hi), v| (lo.min(v), hi.max(v)));
+        SignalRange::new(min, max)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.shape(self.inner.sample(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.shape(self.inner.sample_with_context(t, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::easing::ease;
+    use crate::generators::Ramp;
+    use crate::traits::SignalExt;
+
+    #[test]
+    fn test_eased_ramp_follows_curve() {
+        let ramp = Ramp::new(0.0, 1.0, 2.0);
+        for easing in EasingType::ALL {
+            let eased = ramp.eased(easing);
+            assert_eq!(eased.sample(0.0), 0.0, "{:?}", easing);
+            assert!((eased.sample(2.0) - 1.0).abs() < 1e-5, "{:?}", easing);
+            assert_eq!(eased.sample(0.5), ease(0.25, easing));
+        }
+    }
+
+    #[test]
+    fn test_eased_keeps_overshoot_and_reports_it() {
+        let ramp = Ramp::new(0.0, 1.0, 1.0);
+        let back = ramp.eased(EasingType::BackOut);
+        let range = back.output_range();
+        assert!(range.max > 1.05 && range.min == 0.0, "{:?}", range);
+        assert!((0..100).any(|i| back.sample(i as f64 / 100.0) > 1.0));
+
+        let bigger = Eased::new(ramp, EasingType::BackOut).with_overshoot(3.0);
+        assert!(bigger.output_range().max > range.max);
+
+        assert_eq!(
+            ramp.eased(EasingType::BounceOut).output_range(),
+            SignalRange::UNIT
+        );
+    }
+
+    #[test]
+    fn test_eased_clamps_input() {
+        let ramp = Ramp::new(-1.0, 2.0, 1.0);
+        let eased = ramp.eased(EasingType::QuadIn);
+        assert_eq!(eased.sample(0.0), 0.0);
+        assert_eq!(eased.sample(1.0), 1.0);
+
+        let elastic = Eased::new(ramp, EasingType::ElasticOut).with_elastic(1.5, 0.4);
+        assert_eq!(elastic.params.period, Some(0.4));
+        assert_eq!(elastic.sample(1.0), 1.0);
+    }
+}
+
+// <FILE>src/processing/cls_eased.rs</FILE> - <DESC>Apply an easing curve to a unit-range signal</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
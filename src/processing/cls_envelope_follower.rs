@@ -0,0 +1,170 @@
+// <FILE>src/processing/cls_envelope_follower.rs</FILE> - <DESC>Amplitude envelope of a signal as a Signal</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std</CLOG>
+
+use super::cls_envelope_follower_state::EnvelopeFollowerState;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Processor, Signal, SignalContext, SignalRange, SignalTime};
+
+/// Lookback length in multiples of the longer time constant (e^-7 ≈ 0.1% error).
+const LOOKBACK_TIME_CONSTANTS: f32 = 7.0;
+/// Upper bound on samples run per output sample.
+pub const MAX_LOOKBACK_SAMPLES: usize = 16_384;
+
+/// Amplitude envelope of a signal: rectify, then smooth with separate attack
+/// and release time constants.
+///
+/// Stays stateless like most signals: each `sample(t)` runs an
+/// [`EnvelopeFollowerState`] from silence over the lookback window
+/// `[t - 7 * max(attack, release), t]` at `sample_rate`, ending exactly at `t`.
+/// Random access and repeated `t` are therefore deterministic, with an error
+/// below 0.1% of the level from before the window.
+///
+/// The tradeoff is cost: each sample processes up to
+/// `7 * max(attack, release) * sample_rate` input samples, capped at
+/// [`MAX_LOOKBACK_SAMPLES`] (a shorter, less accurate window beyond that).
+/// Keep `sample_rate` modest for meters, or use [`EnvelopeFollowerState`]
+/// directly when streaming audio.
+///
+/// Output is unipolar in `[0, peak]`, where `peak` is the inner signal's
+/// largest absolute value.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::processing::EnvelopeFollower;
+/// use mixed_signals::traits::Signal;
+///
+/// let tone = Sine::with_frequency(50.0);
+/// let level = EnvelopeFollower::new(tone, 0.001, 0.05, 2000.0);
+/// assert!(level.sample(1.0) > 0.9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EnvelopeFollower<S> {
+    signal: S,
+    attack_secs: f32,
+    release_secs: f32,
+    sample_rate: f32,
+}
+
+impl<S: Signal> EnvelopeFollower<S> {
+    /// Follow `signal` with attack/release time constants in seconds.
+    pub fn new(signal: S, attack_secs: f32, release_secs: f32, sample_rate: f32) -> Self {
+        Self {
+            signal,
+            attack_secs,
+            release_secs,
+            sample_rate,
+        }
+    }
+
+    pub fn attack_secs(&self) -> f32 {
+        self.attack_secs
+    }
+
+    pub fn release_secs(&self) -> f32 {
+        self.release_secs
+    }
+
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Input samples run per output sample.
+    pub fn lookback_samples(&self) -> usize {
+        let longest = self.attack_secs.max(self.release_secs);
+        let samples = LOOKBACK_TIME_CONSTANTS * longest * self.sample_rate;
+        if samples.is_finite() && samples > 0.0 {
+            (samples.ceil() as usize).min(MAX_LOOKBACK_SAMPLES)
+        } else {
+            0
+        }
+    }
+
+    fn follow(&self, t: SignalTime, input_at: impl Fn(SignalTime) -> f32) -> f32 {
+        if !t.is_finite() {
+            return 0.0;
+        }
+        let mut state =
+            EnvelopeFollowerState::new(self.attack_secs, self.release_secs, self.sample_rate);
+        let lookback = self.lookback_samples();
+        let dt = if self.sample_rate > 0.0 {
+            1.0 / self.sample_rate as SignalTime
+        } else {
+            0.0
+        };
+        for k in (1..=lookback).rev() {
+            state.process(input_at(t - k as SignalTime * dt));
+        }
+        state.process(input_at(t))
+    }
+}
+
+impl<S: Signal + Clone> Signal for EnvelopeFollower<S> {
+    fn output_range(&self) -> SignalRange {
+        let range = self.signal.output_range();
+        SignalRange::new(0.0, range.min.abs().max(range.max.abs()))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.follow(t, |ti| self.signal.sample(ti))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.follow(t, |ti| self.signal.sample_with_context(ti, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_follower_constant_converges_to_abs() {
+        for value in [0.5, -0.5] {
+            let follower = EnvelopeFollower::new(Constant::new(value), 0.01, 0.1, 1000.0);
+            assert!((follower.sample(1.0) - 0.5).abs() < 1e-3);
+            assert!((follower.sample(7.3) - 0.5).abs() < 1e-3);
+        }
+        let follower = EnvelopeFollower::new(Constant::new(0.5), 0.01, 0.1, 1000.0);
+        assert_eq!(follower.output_range(), SignalRange::new(0.0, 0.5));
+    }
+
+    #[test]
+    fn test_follower_burst_decays_with_release() {
+        // 100 Hz burst until t = 0.5, then silence
+        let sine = Sine::with_frequency(100.0);
+        let burst = from_fn(move |t| if t < 0.5 { sine.sample(t) } else { 0.0 });
+        let follower = EnvelopeFollower::new(burst, 0.001, 0.1, 2000.0);
+        let at_end = follower.sample(0.5);
+        assert!(at_end > 0.8, "{at_end}");
+        // Each release time constant leaves 1/e
+        let one = follower.sample(0.6) / at_end;
+        let two = follower.sample(0.7) / at_end;
+        assert!((one - (-1.0f32).exp()).abs() < 0.01, "{one}");
+        assert!((two - (-2.0f32).exp()).abs() < 0.01, "{two}");
+    }
+
+    #[test]
+    fn test_follower_lookback_is_bounded() {
+        let sine = Sine::with_frequency(1.0);
+        assert_eq!(
+            EnvelopeFollower::new(sine, 0.01, 0.1, 1000.0).lookback_samples(),
+            700
+        );
+        let huge = EnvelopeFollower::new(sine, 10.0, 10.0, 48_000.0);
+        assert_eq!(huge.lookback_samples(), MAX_LOOKBACK_SAMPLES);
+        let invalid = EnvelopeFollower::new(sine, 0.1, 0.1, f32::NAN);
+        assert_eq!(invalid.lookback_samples(), 0);
+        assert!(invalid.sample(0.25).is_finite());
+        assert_eq!(invalid.sample(f64::NAN), 0.0);
+    }
+}
+
+// <FILE>src/processing/cls_envelope_follower.rs</FILE> - <DESC>Amplitude envelope of a signal as a Signal</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -0,0 +1,116 @@
+// <FILE>src/processing/cls_envelope_follower_state.rs</FILE> - <DESC>Streaming attack/release envelope follower</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::Processor;
+
+/// Per-sample smoothing coefficient `exp(-1 / (secs * fs))` for a time constant.
+///
+/// Zero, negative or non-finite times give 0.0 (follow instantly).
+pub(crate) fn time_constant_coeff(secs: f32, sample_rate: f32) -> f32 {
+    let samples = secs * sample_rate;
+    if samples.is_finite() && samples > 0.0 {
+        (-1.0 / samples).exp()
+    } else {
+        0.0
+    }
+}
+
+/// Streaming amplitude envelope follower.
+///
+/// Rectifies each input (`|x|`) and smooths it with a one-pole filter whose
+/// time constant is `attack_secs` while the level rises and `release_secs`
+/// while it falls. After one time constant the output has covered about 63%
+/// of a step. Non-finite inputs count as silence.
+///
+/// [`EnvelopeFollower`](super::EnvelopeFollower) wraps this as a [`Signal`](crate::traits::Signal).
+///
+/// # Example
+/// ```
+/// use mixed_signals::processing::EnvelopeFollowerState;
+/// use mixed_signals::traits::Processor;
+///
+/// let mut follower = EnvelopeFollowerState::new(0.001, 0.1, 1000.0);
+/// let level = follower.process(-0.8);
+/// assert!(level > 0.5 && level < 0.8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnvelopeFollowerState {
+    attack: f32,
+    release: f32,
+    level: f32,
+}
+
+impl EnvelopeFollowerState {
+    /// Create a follower with attack and release time constants in seconds.
+    pub fn new(attack_secs: f32, release_secs: f32, sample_rate: f32) -> Self {
+        Self {
+            attack: time_constant_coeff(attack_secs, sample_rate),
+            release: time_constant_coeff(release_secs, sample_rate),
+            level: 0.0,
+        }
+    }
+
+    /// Most recent output level (0.0 after a reset).
+    pub fn level(&self) -> f32 {
+        self.level
+    }
+}
+
+impl Processor for EnvelopeFollowerState {
+    fn process(&mut self, input: f32) -> f32 {
+        let rectified = if input.is_finite() { input.abs() } else { 0.0 };
+        let coeff = if rectified > self.level {
+            self.attack
+        } else {
+            self.release
+        };
+        self.level = rectified + coeff * (self.level - rectified);
+        self.level
+    }
+
+    fn reset(&mut self) {
+        self.level = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_follower_state_attack_and_release() {
+        let mut follower = EnvelopeFollowerState::new(0.01, 0.1, 1000.0);
+        // One attack time constant: 1 - 1/e of the step
+        let mut level = 0.0;
+        for _ in 0..10 {
+            level = follower.process(1.0);
+        }
+        assert!((level - (1.0 - (-1.0f32).exp())).abs() < 1e-3, "{level}");
+        for _ in 0..1000 {
+            follower.process(-1.0);
+        }
+        assert!((follower.level() - 1.0).abs() < 1e-6);
+        // One release time constant: 1/e left
+        for _ in 0..100 {
+            level = follower.process(0.0);
+        }
+        assert!((level - (-1.0f32).exp()).abs() < 1e-3, "{level}");
+    }
+
+    #[test]
+    fn test_follower_state_instant_and_reset() {
+        let mut follower = EnvelopeFollowerState::new(0.0, f32::NAN, 1000.0);
+        assert_eq!(follower.process(0.7), 0.7);
+        assert_eq!(follower.process(f32::NAN), 0.0);
+        follower.process(0.5);
+        follower.reset();
+        assert_eq!(follower.level(), 0.0);
+    }
+}
+
+// <FILE>src/processing/cls_envelope_follower_state.rs</FILE> - <DESC>Streaming attack/release envelope follower</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -0,0 +1,198 @@
+// <FILE>src/processing/cls_fade_edges.rs</FILE> - <DESC>Anti-click fade-in/fade-out for one-shot signals</DESC>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::math::{finite_or_f64, finite_or_min};
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use core::f64::consts::PI;
+
+/// Shape of the fade ramps applied by [`FadeEdges`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FadeCurve {
+    /// Straight-line ramp
+    Linear,
+    /// Half-cosine ramp with zero slope at both ends (smoothest, default)
+    #[default]
+    RaisedCosine,
+}
+
+/// Fades a one-shot signal in at t=0 and out at `duration` to avoid clicks.
+///
+/// Output = inner(t) * gain(t), where gain ramps 0 → 1 over `fade_in`
+/// seconds, holds at 1, then ramps 1 → 0 over the last `fade_out` seconds
+/// before `duration`. Outside `[0, duration]` the output is 0.
+///
+/// If the fades are longer than the duration they are shortened
+/// proportionally so they meet in the middle.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::processing::FadeEdges;
+/// use mixed_signals::traits::Signal;
+///
+/// let tone = FadeEdges::new(Sine::new(440.0, 1.0, 0.0, 0.25), 1.0, 0.01, 0.01);
+/// assert_eq!(tone.sample(0.0), 0.0);
+/// assert_eq!(tone.sample(1.0), 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FadeEdges<S> {
+    pub signal: S,
+    /// Total length of the one-shot in seconds
+    pub duration: f32,
+    /// Fade-in length in seconds
+    pub fade_in: f32,
+    /// Fade-out length in seconds
+    pub fade_out: f32,
+    /// Ramp shape
+    pub curve: FadeCurve,
+}
+
+impl<S: Signal> FadeEdges<S> {
+    pub fn new(signal: S, duration: f32, fade_in: f32, fade_out: f32) -> Self {
+        Self {
+            signal,
+            duration: finite_or_min(duration, 0.0, 1.0),
+            fade_in: finite_or_min(fade_in, 0.0, 0.005),
+            fade_out: finite_or_min(fade_out, 0.0, 0.005),
+            curve: FadeCurve::RaisedCosine,
+        }
+    }
+
+    pub fn with_curve(mut self, curve: FadeCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Fade gain (0..1) at time `t`.
+    pub fn gain(&self, t: SignalTime) -> f32 {
+        let t = finite_or_f64(t, 0.0);
+        let duration = finite_or_min(self.duration, 0.0, 1.0) as f64;
+        if t < 0.0 || t > duration {
+            return 0.0;
+        }
+
+        let mut fade_in = finite_or_min(self.fade_in, 0.0, 0.005) as f64;
+        let mut fade_out = finite_or_min(self.fade_out, 0.0, 0.005) as f64;
+        let total = fade_in + fade_out;
+        if total > duration {
+            let scale = if total > 0.0 { duration / total } else { 0.0 };
+            fade_in *= scale;
+            fade_out *= scale;
+        }
+
+        let rising = if fade_in > 0.0 { t / fade_in } else { 1.0 };
+        let falling = if fade_out > 0.0 {
+            (duration - t) / fade_out
+        } else if t < duration {
+            1.0
+        } else {
+            0.0
+        };
+        let progress = rising.min(falling).clamp(0.0, 1.0);
+
+        let shaped = match self.curve {
+            FadeCurve::Linear => progress,
+            FadeCurve::RaisedCosine => 0.5 - 0.5 * (PI * progress).cos(),
+        };
+        shaped as f32
+    }
+}
+
+impl<S: Signal + Clone> Signal for FadeEdges<S> {
+    fn output_range(&self) -> SignalRange {
+        // Gain spans 0..1, so the range always includes 0
+        let range = self.signal.output_range();
+        SignalRange::new(range.min.min(0.0), range.max.max(0.0))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let gain = self.gain(t);
+        if gain == 0.0 {
+            return 0.0;
+        }
+        self.signal.sample(t) * gain
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let gain = self.gain(t);
+        if gain == 0.0 {
+            return 0.0;
+        }
+        self.signal.sample_with_context(t, ctx) * gain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    #[test]
+    fn test_fade_edges_zero_at_ends_full_in_middle() {
+        for curve in [FadeCurve::Linear, FadeCurve::RaisedCosine] {
+            let faded = FadeEdges::new(Constant::new(0.8), 1.0, 0.1, 0.2).with_curve(curve);
+            assert_eq!(faded.sample(0.0), 0.0);
+            assert_eq!(faded.sample(1.0), 0.0);
+            assert!((faded.sample(0.5) - 0.8).abs() < 1e-6);
+            assert!((faded.sample(0.1) - 0.8).abs() < 1e-6);
+            assert!((faded.sample(0.8) - 0.8).abs() < 1e-6);
+            // Silent outside the one-shot
+            assert_eq!(faded.sample(-0.1), 0.0);
+            assert_eq!(faded.sample(1.5), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_fade_edges_ramps_are_smooth() {
+        let linear =
+            FadeEdges::new(Constant::new(1.0), 1.0, 0.1, 0.1).with_curve(FadeCurve::Linear);
+        assert!((linear.sample(0.05) - 0.5).abs() < 1e-5);
+        assert!((linear.sample(0.95) - 0.5).abs() < 1e-5);
+
+        let cosine = FadeEdges::new(Constant::new(1.0), 1.0, 0.1, 0.1);
+        assert!((cosine.sample(0.05) - 0.5).abs() < 1e-5);
+        assert!(cosine.sample(0.02) < linear.sample(0.02));
+
+        // Monotonic, small steps at 1ms resolution
+        let mut prev = 0.0;
+        for i in 0..=100 {
+            let v = cosine.sample(i as f64 * 0.001);
+            assert!(v >= prev && v - prev < 0.03);
+            prev = v;
+        }
+    }
+
+    #[test]
+    fn test_fade_edges_overlapping_fades_shrink() {
+        let faded = FadeEdges::new(Constant::new(1.0), 0.2, 0.3, 0.1).with_curve(FadeCurve::Linear);
+        // Fades scaled to 0.15 / 0.05 seconds; peak where they meet
+        assert!((faded.gain(0.15) - 1.0).abs() < 1e-5);
+        assert!(faded.gain(0.075) > 0.4 && faded.gain(0.075) < 0.6);
+        assert!(faded.gain(0.2) < 1e-5);
+    }
+
+    #[test]
+    fn test_fade_edges_zero_length_fades() {
+        let faded = FadeEdges::new(Constant::new(0.5), 1.0, 0.0, 0.0);
+        assert_eq!(faded.sample(0.0), 0.5);
+        assert_eq!(faded.sample(0.999), 0.5);
+        assert_eq!(faded.sample(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_fade_edges_sanitizes_inputs() {
+        let faded = FadeEdges::new(Constant::new(1.0), f32::NAN, f32::INFINITY, -1.0);
+        assert_eq!(faded.duration, 1.0);
+        assert_eq!(faded.fade_in, 0.005);
+        assert_eq!(faded.fade_out, 0.0);
+        assert_eq!(faded.output_range(), SignalRange::new(0.0, 1.0));
+    }
+}
+
+// <FILE>src/processing/cls_fade_edges.rs</FILE> - <DESC>Anti-click fade-in/fade-out for one-shot signals</DESC>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -0,0 +1,208 @@
+// <FILE>src/processing/cls_fir.rs</FILE> - <DESC>Finite impulse response filter</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Custom convolution kernels</WCTX>
+// <CLOG>Initial implementation - fixed-tap convolution over a ring-buffered delay line</CLOG>
+
+use crate::math::finite_or;
+use crate::traits::{Signal, SignalContext, SignalTime};
+use std::sync::Mutex;
+
+/// FIR delay line state.
+#[derive(Debug, Clone, Default)]
+struct FirState {
+    /// Past inputs; `delay[pos]` receives the next sample
+    delay: Vec<f32>,
+    pos: usize,
+    last_output: f32,
+    prev_time: Option<f64>,
+}
+
+impl FirState {
+    fn new(len: usize) -> Self {
+        Self {
+            delay: vec![0.0; len.max(1)],
+            ..Self::default()
+        }
+    }
+}
+
+/// Finite impulse response filter: convolves the input with a fixed tap set.
+///
+/// `y[n] = Σ taps[k] * x[n-k]`
+///
+/// Use it for custom smoothing or antialiasing kernels; the impulse response
+/// is exactly `taps`. An empty tap set outputs 0.0 and non-finite taps are
+/// treated as 0.0.
+///
+/// The filter advances one step each time `t` increases (starting with the
+/// first sample), returns the last output for a repeated `t`, and restarts
+/// from an empty delay line when time goes backwards. `sample_rate` is
+/// informational; the taps fully define the response.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Step;
+/// use mixed_signals::processing::Fir;
+/// use mixed_signals::traits::Signal;
+///
+/// // Two-tap average softens a 0 → 1 step into 0 → 0.5 → 1
+/// let avg = Fir::new(Step::new(0.0, 1.0, 0.015), vec![0.5, 0.5], 100.0);
+/// assert_eq!(avg.sample(0.01), 0.0);
+/// assert_eq!(avg.sample(0.02), 0.5);
+/// assert_eq!(avg.sample(0.03), 1.0);
+/// ```
+#[derive(Debug)]
+pub struct Fir<S> {
+    signal: S,
+    taps: Vec<f32>,
+    sample_rate: f32,
+    state: Mutex<FirState>,
+}
+
+impl<S: Signal> Fir<S> {
+    /// Create a filter convolving `signal` with `taps` (`taps[0]` weights the
+    /// current sample).
+    pub fn new(signal: S, taps: Vec<f32>, sample_rate: f32) -> Self {
+        let taps: Vec<f32> = taps.into_iter().map(|c| finite_or(c, 0.0)).collect();
+        let state = Mutex::new(FirState::new(taps.len()));
+        Self {
+            signal,
+            taps,
+            sample_rate,
+            state,
+        }
+    }
+
+    /// Filter taps.
+    pub fn taps(&self) -> &[f32] {
+        &self.taps
+    }
+
+    /// Get the sample rate in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    fn process_sample(&self, input: f32, state: &mut FirState) -> f32 {
+        let len = state.delay.len();
+        let pos = state.pos;
+        state.delay[pos] = finite_or(input, 0.0);
+
+        let output = self
+            .taps
+            .iter()
+            .enumerate()
+            .map(|(k, tap)| tap * state.delay[(pos + len - k) % len])
+            .sum();
+
+        state.pos = (pos + 1) % len;
+        state.last_output = output;
+        output
+    }
+
+    fn step(&self, t: SignalTime, input: f32) -> f32 {
+        let mut state = self.state.lock().unwrap();
+
+        match state.prev_time {
+            Some(prev) if t == prev => return state.last_output,
+            // Time went backwards - restart with an empty delay line
+            Some(prev) if t < prev => *state = FirState::new(self.taps.len()),
+            _ => {}
+        }
+        state.prev_time = Some(t);
+        self.process_sample(input, &mut state)
+    }
+}
+
+/// Clones the configuration only; the clone starts with an empty delay line.
+impl<S: Clone> Clone for Fir<S> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            taps: self.taps.clone(),
+            sample_rate: self.sample_rate,
+            state: Mutex::new(FirState::new(self.taps.len())),
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for Fir<S> {
+    fn sample(&self, t: SignalTime) -> f32 {
+        let input = self.signal.sample(t);
+        self.step(t, input)
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let input = self.signal.sample_with_context(t, ctx);
+        self.step(t, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Sine, Step};
+
+    #[derive(Clone)]
+    struct Impulse;
+
+    impl Signal for Impulse {
+        fn sample(&self, t: SignalTime) -> f32 {
+            if t == 0.0 {
+                1.0
+            } else {
+                0.0
+            }
+        }
+    }
+
+    #[test]
+    fn test_fir_unit_tap_is_transparent() {
+        let sine = Sine::with_frequency(3.0);
+        let fir = Fir::new(sine, vec![1.0], 1000.0);
+        for i in 0..500 {
+            let t = i as f64 / 1000.0;
+            assert_eq!(fir.sample(t), sine.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_fir_two_tap_average_smooths_step() {
+        let step = Step::new(0.0, 1.0, 0.5);
+        let fir = Fir::new(step, vec![0.5, 0.5], 10.0);
+        let out: Vec<f32> = (0..10).map(|i| fir.sample(i as f64 * 0.1)).collect();
+        assert_eq!(&out[..5], &[0.0; 5]);
+        // The jump is split across two samples
+        assert_eq!(out[5], 0.5);
+        assert_eq!(&out[6..], &[1.0; 4]);
+    }
+
+    #[test]
+    fn test_fir_impulse_response_equals_taps() {
+        let taps = vec![0.1, -0.4, 0.75, 0.2, -0.05];
+        let fir = Fir::new(Impulse, taps.clone(), 100.0);
+        let out: Vec<f32> = (0..8).map(|i| fir.sample(i as f64 * 0.01)).collect();
+        assert_eq!(&out[..5], taps.as_slice());
+        assert_eq!(&out[5..], &[0.0; 3]);
+    }
+
+    #[test]
+    fn test_fir_repeat_rewind_and_clone() {
+        let fir = Fir::new(Impulse, vec![0.5, 0.25], 100.0);
+        assert_eq!(fir.sample(0.0), 0.5);
+        assert_eq!(fir.sample(0.01), 0.25);
+        assert_eq!(fir.sample(0.01), 0.25);
+        // Rewinding restarts from an empty delay line
+        assert_eq!(fir.sample(0.0), 0.5);
+        assert_eq!(fir.clone().sample(0.01), 0.0);
+
+        let empty = Fir::new(Impulse, vec![], 100.0);
+        assert_eq!(empty.sample(0.0), 0.0);
+        let nan = Fir::new(Impulse, vec![f32::NAN, 1.0], 100.0);
+        assert_eq!(nan.taps(), &[0.0, 1.0]);
+    }
+}
+
+// <FILE>src/processing/cls_fir.rs</FILE> - <DESC>Finite impulse response filter</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
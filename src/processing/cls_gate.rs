@@ -0,0 +1,229 @@
+// <FILE>src/processing/cls_gate.rs</FILE> - <DESC>Noise gate with optional hysteresis</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Glitch layering</WCTX>
+// <CLOG>Initial implementation - magnitude threshold gate with floor and open/close hysteresis</CLOG>
+
+use crate::math::finite_or;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use std::sync::Mutex;
+
+/// Hysteresis state: whether the gate is open and when it last stepped.
+#[derive(Debug, Clone, Copy, Default)]
+struct GateState {
+    open: bool,
+    prev_time: Option<f64>,
+}
+
+/// Noise gate: passes the input while its magnitude is at or above
+/// `threshold`, otherwise outputs `floor` (default 0.0).
+///
+/// The plain threshold mode is stateless and exact. With
+/// [`with_hysteresis`](Self::with_hysteresis) the gate opens at `threshold`
+/// but only closes once the magnitude drops below a lower close threshold,
+/// so values hovering near the threshold don't chatter. Hysteresis needs
+/// memory: like [`MovingAverage`](super::MovingAverage), the gate steps each
+/// time `t` increases, repeats its decision for a repeated `t`, and starts
+/// closed again when time goes backwards.
+///
+/// Non-finite or negative thresholds are treated as 0.0 (always open).
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Constant;
+/// use mixed_signals::processing::Gate;
+/// use mixed_signals::traits::Signal;
+///
+/// assert_eq!(Gate::new(Constant::new(0.05), 0.1).sample(0.0), 0.0);
+/// assert_eq!(Gate::new(Constant::new(-0.5), 0.1).sample(0.0), -0.5);
+/// ```
+#[derive(Debug)]
+pub struct Gate<S> {
+    signal: S,
+    threshold: f32,
+    close_threshold: Option<f32>,
+    floor: f32,
+    state: Mutex<GateState>,
+}
+
+impl<S: Signal> Gate<S> {
+    pub fn new(signal: S, threshold: f32) -> Self {
+        Self {
+            signal,
+            threshold: sanitize_threshold(threshold),
+            close_threshold: None,
+            floor: 0.0,
+            state: Mutex::new(GateState::default()),
+        }
+    }
+
+    /// Value to output while the gate is closed.
+    pub fn with_floor(mut self, floor: f32) -> Self {
+        self.floor = finite_or(floor, 0.0);
+        self
+    }
+
+    /// Keep the gate open until the magnitude drops below `close_threshold`.
+    ///
+    /// Clamped to at most the open threshold; non-finite values disable
+    /// hysteresis.
+    pub fn with_hysteresis(mut self, close_threshold: f32) -> Self {
+        self.close_threshold = close_threshold
+            .is_finite()
+            .then(|| sanitize_threshold(close_threshold).min(self.threshold));
+        self
+    }
+
+    /// Magnitude at which the gate opens.
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Magnitude below which an open gate closes (equals `threshold`
+    /// without hysteresis).
+    pub fn close_threshold(&self) -> f32 {
+        self.close_threshold.unwrap_or(self.threshold)
+    }
+
+    pub fn floor(&self) -> f32 {
+        self.floor
+    }
+
+    fn process(&self, t: SignalTime, input: f32) -> f32 {
+        let magnitude = input.abs();
+        let open = match self.close_threshold {
+            None => magnitude >= self.threshold,
+            Some(close) => {
+                let mut state = self.state.lock().unwrap();
+                match state.prev_time {
+                    Some(prev) if t == prev => {}
+                    Some(prev) if t < prev => {
+                        *state = GateState::default();
+                        state.prev_time = Some(t);
+                        state.open = magnitude >= self.threshold;
+                    }
+                    _ => {
+                        state.prev_time = Some(t);
+                        state.open = if state.open {
+                            magnitude >= close
+                        } else {
+                            magnitude >= self.threshold
+                        };
+                    }
+                }
+                state.open
+            }
+        };
+        // NaN magnitudes compare false and fall through to the floor
+        if open {
+            input
+        } else {
+            self.floor
+        }
+    }
+}
+
+fn sanitize_threshold(threshold: f32) -> f32 {
+    finite_or(threshold, 0.0).max(0.0)
+}
+
+/// Clones the configuration only; the clone starts closed.
+impl<S: Clone> Clone for Gate<S> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            threshold: self.threshold,
+            close_threshold: self.close_threshold,
+            floor: self.floor,
+            state: Mutex::new(GateState::default()),
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for Gate<S> {
+    fn output_range(&self) -> SignalRange {
+        let range = self.signal.output_range();
+        SignalRange::new(range.min.min(self.floor), range.max.max(self.floor))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.process(t, self.signal.sample(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.process(t, self.signal.sample_with_context(t, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_gate_straddling_threshold() {
+        let ramp = from_fn(|t| t as f32 - 1.0);
+        let gate = Gate::new(ramp, 0.5);
+        assert_eq!(gate.sample(0.0), -1.0);
+        assert_eq!(gate.sample(0.5), -0.5); // exactly at threshold stays open
+        assert_eq!(gate.sample(0.75), 0.0);
+        assert_eq!(gate.sample(1.25), 0.0);
+        assert_eq!(gate.sample(1.5), 0.5);
+        assert_eq!(gate.sample(2.0), 1.0);
+    }
+
+    #[test]
+    fn test_gate_floor_and_invalid_threshold() {
+        let quiet = Constant::new(0.05);
+        assert_eq!(Gate::new(quiet, 0.1).with_floor(-1.0).sample(0.0), -1.0);
+        for threshold in [f32::NAN, f32::INFINITY, -3.0] {
+            let gate = Gate::new(quiet, threshold);
+            assert_eq!(gate.threshold(), 0.0);
+            assert_eq!(gate.sample(0.0), 0.05);
+        }
+        let nan_input = Gate::new(from_fn(|_| f32::NAN), 0.1).with_floor(0.2);
+        assert_eq!(nan_input.sample(0.0), 0.2);
+    }
+
+    #[test]
+    fn test_gate_hysteresis_prevents_chatter() {
+        // Oscillates between 0.35 and 0.65 around a 0.5 threshold
+        let wobble = Sine::new(4.0, 0.15, 0.5, 0.0);
+        let plain = Gate::new(wobble, 0.5);
+        let latched = Gate::new(wobble, 0.5).with_hysteresis(0.3);
+        let (mut plain_closed, mut latched_closed) = (0, 0);
+        for i in 0..200 {
+            let t = 0.05 + i as f64 * 0.01;
+            plain_closed += (plain.sample(t) == 0.0) as usize;
+            latched_closed += (latched.sample(t) == 0.0) as usize;
+        }
+        assert!(plain_closed > 50);
+        // Opens on the first peak and never drops below 0.3 afterwards
+        assert!(latched_closed < 10);
+    }
+
+    #[test]
+    fn test_gate_hysteresis_resets_and_clamps() {
+        let level = from_fn(|t| if t < 1.0 { 0.6 } else { 0.4 });
+        let gate = Gate::new(level, 0.5).with_hysteresis(0.3);
+        assert_eq!(gate.sample(1.5), 0.0); // starts closed, 0.4 < open threshold
+        assert_eq!(gate.sample(0.5), 0.6); // time went backwards: reset, opens
+        assert_eq!(gate.sample(1.5), 0.4); // still open above the close threshold
+        assert_eq!(gate.clone().sample(1.5), 0.0); // clone starts closed
+
+        assert_eq!(
+            Gate::new(level, 0.5).with_hysteresis(0.9).close_threshold(),
+            0.5
+        );
+        assert_eq!(
+            Gate::new(level, 0.5)
+                .with_hysteresis(f32::NAN)
+                .close_threshold(),
+            0.5
+        );
+    }
+}
+
+// <FILE>src/processing/cls_gate.rs</FILE> - <DESC>Noise gate with optional hysteresis</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -0,0 +1,105 @@
+// <FILE>src/processing/cls_hold_after.rs</FILE> - <DESC>Freeze a signal after a trigger time</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Latching operators</WCTX>
+// <CLOG>Initial creation - HoldAfter latches the inner value at hold_time</CLOG>
+
+use crate::math::finite_or;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Follows a signal until `hold_time`, then holds the value it had there.
+///
+/// Output = inner(min(t, hold_time)). Useful for "decrypt then freeze" text
+/// effects or a progress bar that settles once complete. A negative or
+/// non-finite hold time is treated as 0.0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Ramp;
+/// use mixed_signals::processing::HoldAfter;
+/// use mixed_signals::traits::Signal;
+///
+/// let ramp = Ramp::new(0.0, 1.0, 2.0);
+/// let frozen = HoldAfter::new(ramp, 1.0);
+/// assert_eq!(frozen.sample(5.0), ramp.sample(1.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HoldAfter<S> {
+    pub inner: S,
+    /// Time in seconds after which the output stops changing
+    pub hold_time: f32,
+}
+
+impl<S: Signal> HoldAfter<S> {
+    pub fn new(inner: S, hold_time: f32) -> Self {
+        Self {
+            inner,
+            hold_time: finite_or(hold_time, 0.0).max(0.0),
+        }
+    }
+
+    fn clamped(&self, t: SignalTime) -> SignalTime {
+        let hold = finite_or(self.hold_time, 0.0).max(0.0) as SignalTime;
+        if t.is_nan() {
+            return t;
+        }
+        t.min(hold)
+    }
+}
+
+impl<S: Signal + Clone> Signal for HoldAfter<S> {
+    fn output_range(&self) -> SignalRange {
+        self.inner.output_range()
+    }
+
+    fn display_range(&self) -> (f32, f32) {
+        self.inner.display_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.inner.sample(self.clamped(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.inner.sample_with_context(self.clamped(t), ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Ramp;
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_hold_follows_then_freezes() {
+        let ramp = Ramp::new(0.0, 1.0, 2.0);
+        let held = HoldAfter::new(ramp, 1.0);
+        assert_eq!(held.sample(0.5), ramp.sample(0.5));
+        assert_eq!(held.sample(1.0), ramp.sample(1.0));
+        assert_eq!(held.sample(1.0 + 1e-9), ramp.sample(1.0));
+        assert_eq!(held.sample(100.0), ramp.sample(1.0));
+    }
+
+    #[test]
+    fn test_negative_hold_time_sanitized() {
+        let ramp = Ramp::new(0.0, 1.0, 2.0);
+        let held = HoldAfter::new(ramp, -3.0);
+        assert_eq!(held.hold_time, 0.0);
+        assert_eq!(held.sample(2.0), ramp.sample(0.0));
+
+        let nan = HoldAfter::new(ramp, f32::NAN);
+        assert_eq!(nan.hold_time, 0.0);
+    }
+
+    #[test]
+    fn test_hold_forwards_context() {
+        let held = HoldAfter::new(from_fn(|t| t as f32), 1.0);
+        let ctx = SignalContext::new(7, 42);
+        assert_eq!(held.sample_with_context(3.0, &ctx), 1.0);
+        assert_eq!(held.output_range(), SignalRange::BIPOLAR);
+    }
+}
+
+// <FILE>src/processing/cls_hold_after.rs</FILE> - <DESC>Freeze a signal after a trigger time</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -0,0 +1,274 @@
+// <FILE>src/processing/cls_iir.rs</FILE> - <DESC>Generic direct-form IIR filter</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Core:: instead of std:: paths</CLOG>
+
+use crate::math::finite_or;
+use crate::traits::{Signal, SignalContext, SignalTime};
+use std::sync::Mutex;
+
+/// IIR filter state: input/output history in a shared ring buffer.
+#[derive(Debug, Clone, Default)]
+struct IirState {
+    x: Vec<f32>,
+    y: Vec<f32>,
+    /// Ring slot that receives the next sample
+    pos: usize,
+    prev_time: f64,
+}
+
+impl IirState {
+    fn new(order: usize) -> Self {
+        Self {
+            x: vec![0.0; order],
+            y: vec![0.0; order],
+            pos: 0,
+            prev_time: 0.0,
+        }
+    }
+
+    /// Most recent output sample.
+    fn last_output(&self) -> f32 {
+        let len = self.y.len();
+        self.y[(self.pos + len - 1) % len]
+    }
+}
+
+/// Infinite impulse response filter of arbitrary order.
+///
+/// Implements the direct-form difference equation:
+/// `y[n] = Σ b[k]*x[n-k] - Σ a[k]*y[n-k]` (k ≥ 1 for the feedback sum)
+///
+/// `a_coeffs[0]` is the `a0` term; all coefficients are normalized by it, so
+/// `b = [b0, b1, b2]`, `a = [1, a1, a2]` reproduces a [`Biquad`](super::Biquad).
+/// An empty, zero, or non-finite `a0` is treated as 1.0, and non-finite
+/// coefficients are treated as 0.0.
+///
+/// Like `Biquad`, the filter advances one step each time `t` increases,
+/// returns the last output for a repeated `t`, and resets when time goes
+/// backwards. `sample_rate` is informational; the coefficients fully define
+/// the response.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Constant;
+/// use mixed_signals::processing::Iir;
+/// use mixed_signals::traits::Signal;
+///
+/// // One-pole smoother: y[n] = 0.5*x[n] + 0.5*y[n-1]
+/// let smooth = Iir::new(Constant::new(1.0), vec![0.5], vec![1.0, -0.5], 100.0);
+/// assert!((smooth.sample(0.01) - 0.5).abs() < 1e-6);
+/// assert!((smooth.sample(0.02) - 0.75).abs() < 1e-6);
+/// ```
+#[derive(Debug)]
+pub struct Iir<S> {
+    signal: S,
+    /// Feedforward coefficients, normalized by a0
+    b: Vec<f32>,
+    /// Feedback coefficients, normalized by a0 (a[0] == 1.0)
+    a: Vec<f32>,
+    sample_rate: f32,
+    state: Mutex<IirState>,
+}
+
+impl<S: Signal> Iir<S> {
+    /// Create a filter from feedforward (`b_coeffs`) and feedback
+    /// (`a_coeffs`, starting with `a0`) coefficients.
+    pub fn new(signal: S, b_coeffs: Vec<f32>, a_coeffs: Vec<f32>, sample_rate: f32) -> Self {
+        let a0 = match a_coeffs.first() {
+            Some(&a0) if a0.is_finite() && a0 != 0.0 => a0,
+            _ => 1.0,
+        };
+        let normalize = |c: &f32| finite_or(*c / a0, 0.0);
+        let b: Vec<f32> = b_coeffs.iter().map(normalize).collect();
+        let mut a: Vec<f32> = a_coeffs.iter().map(normalize).collect();
+        if a.is_empty() {
+            a.push(1.0);
+        }
+        a[0] = 1.0;
+
+        let order = b.len().max(a.len());
+        Self {
+            signal,
+            b,
+            a,
+            sample_rate,
+            state: Mutex::new(IirState::new(order)),
+        }
+    }
+
+    /// Normalized feedforward coefficients.
+    pub fn b_coeffs(&self) -> &[f32] {
+        &self.b
+    }
+
+    /// Normalized feedback coefficients (`a[0]` is always 1.0).
+    pub fn a_coeffs(&self) -> &[f32] {
+        &self.a
+    }
+
+    /// Get the sample rate in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    fn order(&self) -> usize {
+        self.b.len().max(self.a.len())
+    }
+
+    fn process_sample(&self, input: f32, state: &mut IirState) -> f32 {
+        let len = state.x.len();
+        let pos = state.pos;
+        state.x[pos] = finite_or(input, 0.0);
+
+        let mut output = 0.0;
+        for (k, b) in self.b.iter().enumerate() {
+            output += b * state.x[(pos + len - k) % len];
+        }
+        for (k, a) in self.a.iter().enumerate().skip(1) {
+            output -= a * state.y[(pos + len - k) % len];
+        }
+        let output = finite_or(output, 0.0);
+
+        state.y[pos] = output;
+        state.pos = (pos + 1) % len;
+        output
+    }
+
+    fn step(&self, t: SignalTime, input: f32) -> f32 {
+        let mut state = self.state.lock().unwrap();
+
+        if t > state.prev_time {
+            let output = self.process_sample(input, &mut state);
+            state.prev_time = t;
+            output
+        } else if t < state.prev_time {
+            // Time went backwards - reset state
+            *state = IirState::new(self.order());
+            state.prev_time = t;
+            input
+        } else {
+            // Same time - return last output
+            state.last_output()
+        }
+    }
+}
+
+/// Clones the configuration only; the clone starts with fresh filter state.
+impl<S: Clone> Clone for Iir<S> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            b: self.b.clone(),
+            a: self.a.clone(),
+            sample_rate: self.sample_rate,
+            state: Mutex::new(IirState::new(self.b.len().max(self.a.len()))),
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for Iir<S> {
+    fn sample(&self, t: SignalTime) -> f32 {
+        let input = self.signal.sample(t);
+        self.step(t, input)
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let input = self.signal.sample_with_context(t, ctx);
+        self.step(t, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+    use crate::processing::Biquad;
+    use core::f32::consts::{FRAC_1_SQRT_2, PI};
+
+    /// RBJ cookbook low-pass coefficients as (b, a) with a0 left unnormalized.
+    fn rbj_lowpass(cutoff_hz: f32, q: f32, sample_rate: f32) -> (Vec<f32>, Vec<f32>) {
+        let omega = 2.0 * PI * cutoff_hz / sample_rate;
+        let alpha = omega.sin() / (2.0 * q);
+        let cos = omega.cos();
+        let b1 = 1.0 - cos;
+        (
+            vec![b1 / 2.0, b1, b1 / 2.0],
+            vec![1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    #[test]
+    fn test_iir_second_order_matches_biquad() {
+        let sr = 48000.0;
+        let input = Sine::new(3000.0, 1.0, 0.0, 0.0);
+        let (b, a) = rbj_lowpass(1000.0, FRAC_1_SQRT_2, sr);
+        let iir = Iir::new(input, b, a, sr);
+        let biquad = Biquad::lowpass(input, 1000.0, sr);
+
+        for i in 0..2000 {
+            let t = i as f64 / sr as f64;
+            let (x, y) = (iir.sample(t), biquad.sample(t));
+            assert!((x - y).abs() < 1e-5, "sample {}: iir={} biquad={}", i, x, y);
+        }
+    }
+
+    #[test]
+    fn test_iir_impulse_response_of_fir_section() {
+        // Feedforward only: impulse response equals the b coefficients
+        #[derive(Clone)]
+        struct Impulse;
+        impl Signal for Impulse {
+            fn sample(&self, t: SignalTime) -> f32 {
+                if (t - 0.01).abs() < 1e-9 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+        let iir = Iir::new(Impulse, vec![0.5, 0.25, -0.125], vec![1.0], 100.0);
+        let out: Vec<f32> = (1..=5).map(|i| iir.sample(i as f64 * 0.01)).collect();
+        assert_eq!(out, vec![0.5, 0.25, -0.125, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_iir_normalizes_by_a0_and_reaches_dc_gain() {
+        let iir = Iir::new(Constant::new(1.0), vec![1.0], vec![4.0, -2.0], 100.0);
+        assert_eq!(iir.b_coeffs(), &[0.25]);
+        assert_eq!(iir.a_coeffs(), &[1.0, -0.5]);
+        let mut last = 0.0;
+        for i in 1..200 {
+            last = iir.sample(i as f64 * 0.01);
+        }
+        // DC gain = 0.25 / (1 - 0.5)
+        assert!((last - 0.5).abs() < 1e-5);
+        // Repeated time returns the same output
+        assert_eq!(iir.sample(199.0 * 0.01), last);
+    }
+
+    #[test]
+    fn test_iir_degenerate_coefficients() {
+        let iir = Iir::new(Constant::new(0.7), vec![f32::NAN, 1.0], vec![], 100.0);
+        assert_eq!(iir.a_coeffs(), &[1.0]);
+        assert!((iir.sample(0.01) - 0.0).abs() < 1e-6);
+        assert!((iir.sample(0.02) - 0.7).abs() < 1e-6);
+
+        let silent = Iir::new(Constant::new(0.7), vec![], vec![0.0], 100.0);
+        assert_eq!(silent.sample(0.01), 0.0);
+    }
+
+    #[test]
+    fn test_iir_clone_starts_fresh() {
+        let iir = Iir::new(Constant::new(1.0), vec![0.5], vec![1.0, -0.5], 100.0);
+        for i in 1..50 {
+            iir.sample(i as f64 * 0.01);
+        }
+        let copy = iir.clone();
+        assert!((copy.sample(0.01) - 0.5).abs() < 1e-6);
+    }
+}
+
+// <FILE>src/processing/cls_iir.rs</FILE> - <DESC>Generic direct-form IIR filter</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
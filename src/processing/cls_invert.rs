@@ -1,7 +1,7 @@
 // <FILE>mixed-signals/src/processing/cls_invert.rs</FILE> - <DESC>Signal inversion operator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor</WCTX>
-// <CLOG>Removed clamping, added output_range() - now negates signal</CLOG>
+// <VERS>VERSION: 2.1.0</VERS>
+// <WCTX>Cloneable signal collections</WCTX>
+// <CLOG>Require Clone on inner signals for SignalClone</CLOG>
 
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 
@@ -22,7 +22,7 @@ impl<S: Signal> Invert<S> {
     }
 }
 
-impl<S: Signal> Signal for Invert<S> {
+impl<S: Signal + Clone> Signal for Invert<S> {
     fn output_range(&self) -> SignalRange {
         let r = self.signal.output_range();
         // Negation swaps and negates bounds
@@ -89,4 +89,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/processing/cls_invert.rs</FILE> - <DESC>Signal inversion operator</DESC>
-// <VERS>END OF VERSION: 2.0.0</VERS>
+// <VERS>END OF VERSION: 2.1.0</VERS>
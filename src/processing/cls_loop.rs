@@ -0,0 +1,142 @@
+// <FILE>src/processing/cls_loop.rs</FILE> - <DESC>Time-wrapping loop operator</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std</CLOG>
+
+use crate::math::finite_or;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Repeats a window of a signal forever.
+///
+/// Output = inner((t + phase) mod period), so the inner signal only ever sees
+/// times in `[0, period)`. Useful for looping one-shot envelopes (ADSR,
+/// Impact) or repeating a Pulse window.
+///
+/// A non-finite or non-positive period disables wrapping and passes `t`
+/// through unchanged. A non-finite phase is treated as 0.0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::envelopes::Impact;
+/// use mixed_signals::processing::Loop;
+/// use mixed_signals::traits::Signal;
+///
+/// let hit = Impact::new(1.0, 8.0);
+/// let repeating = Loop::new(hit, 0.5);
+/// assert_eq!(repeating.sample(1.1), hit.sample(0.1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Loop<S> {
+    pub inner: S,
+    /// Loop length in seconds
+    pub period: f32,
+    /// Offset added to t before wrapping
+    pub phase: f32,
+}
+
+impl<S: Signal> Loop<S> {
+    pub fn new(inner: S, period: f32) -> Self {
+        Self {
+            inner,
+            period: finite_or(period, 0.0),
+            phase: 0.0,
+        }
+    }
+
+    /// Start the loop `phase` seconds into the window.
+    pub fn with_phase(mut self, phase: f32) -> Self {
+        self.phase = finite_or(phase, 0.0);
+        self
+    }
+
+    fn wrap(&self, t: SignalTime) -> SignalTime {
+        let period = finite_or(self.period, 0.0);
+        if period <= 0.0 || !t.is_finite() {
+            return t;
+        }
+        (t + finite_or(self.phase, 0.0) as SignalTime).rem_euclid(period as SignalTime)
+    }
+}
+
+impl<S: Signal + Clone> Signal for Loop<S> {
+    fn output_range(&self) -> SignalRange {
+        self.inner.output_range()
+    }
+
+    fn display_range(&self) -> (f32, f32) {
+        self.inner.display_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.inner.sample(self.wrap(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.inner.sample_with_context(self.wrap(t), ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Ramp;
+    use crate::traits::SignalExt;
+
+    #[test]
+    fn test_loop_wraps_time() {
+        let ramp = Ramp::new(0.0, 1.0, 2.0);
+        let looped = Loop::new(ramp, 2.0);
+        assert!((looped.sample(2.5) - 0.25).abs() < 1e-6);
+        assert!((looped.sample(-0.5) - 0.75).abs() < 1e-6);
+        assert_eq!(looped.sample(4.0), 0.0);
+    }
+
+    #[test]
+    fn test_loop_phase_offset() {
+        let looped = Ramp::new(0.0, 1.0, 1.0).looped(1.0).with_phase(0.5);
+        assert!((looped.sample(0.0) - 0.5).abs() < 1e-6);
+        assert!((looped.sample(0.75) - 0.25).abs() < 1e-6);
+        assert_eq!(
+            Loop::new(Ramp::new(0.0, 1.0, 1.0), 1.0)
+                .with_phase(f32::NAN)
+                .phase,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_loop_invalid_period_passes_through() {
+        let ramp = Ramp::new(0.0, 1.0, 4.0);
+        for period in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            let looped = Loop::new(ramp, period);
+            assert_eq!(looped.sample(3.0), ramp.sample(3.0));
+        }
+    }
+
+    #[test]
+    fn test_loop_forwards_context_and_range() {
+        #[derive(Clone)]
+        struct FrameSignal;
+        impl Signal for FrameSignal {
+            fn output_range(&self) -> SignalRange {
+                SignalRange::new(0.0, 10.0)
+            }
+            fn sample(&self, t: SignalTime) -> f32 {
+                t as f32
+            }
+            fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+                t as f32 + ctx.frame as f32
+            }
+        }
+        let looped = FrameSignal.looped(1.0);
+        let ctx = SignalContext::new(2, 0);
+        assert!((looped.sample_with_context(3.5, &ctx) - 2.5).abs() < 1e-6);
+        assert_eq!(looped.output_range(), SignalRange::new(0.0, 10.0));
+    }
+}
+
+// <FILE>src/processing/cls_loop.rs</FILE> - <DESC>Time-wrapping loop operator</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
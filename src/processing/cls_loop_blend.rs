@@ -0,0 +1,166 @@
+// <FILE>src/processing/cls_loop_blend.rs</FILE> - <DESC>Seamless loop operator with a crossfaded seam</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std</CLOG>
+
+use crate::math::finite_or;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Repeats a window of a signal forever without a pop at the wrap.
+///
+/// [`Loop`](super::Loop) jumps from `inner(period)` back to `inner(0)`, which
+/// is visible on anything that doesn't happen to be periodic (noise, random
+/// walks). `LoopBlend` instead crossfades the last `blend` seconds of each lap
+/// toward the signal just before time 0, so the lap ends exactly where the
+/// next one begins:
+///
+/// - `u = t mod period`
+/// - `u < period - blend`: output = inner(u), untouched
+/// - otherwise: output = mix(inner(u), inner(u - period), smoothstep(w)),
+///   with `w` rising from 0 to 1 across the blend window
+///
+/// The result is C0-continuous across the wrap and matches the un-looped
+/// signal everywhere outside the blend window. `blend` is clamped to
+/// `[0, period]`; a non-finite or non-positive period passes `t` through.
+///
+/// For noise that should loop without any blend region at all, walk a circle
+/// through a 2D field with [`PerlinNoise2D::looping`](crate::noise::PerlinNoise2D::looping).
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::noise::PerlinNoise;
+/// use mixed_signals::processing::LoopBlend;
+/// use mixed_signals::traits::Signal;
+///
+/// let noise = PerlinNoise::new(7, 2.0, 1.0);
+/// let looped = LoopBlend::new(noise, 4.0, 0.5);
+/// assert!((looped.sample(3.99999) - looped.sample(4.0)).abs() < 1e-3);
+/// assert_eq!(looped.sample(1.0), noise.sample(1.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoopBlend<S> {
+    pub inner: S,
+    /// Loop length in seconds
+    pub period: f32,
+    /// Length of the crossfade at the end of each lap, in seconds
+    pub blend: f32,
+}
+
+impl<S: Signal> LoopBlend<S> {
+    pub fn new(inner: S, period: f32, blend: f32) -> Self {
+        let period = finite_or(period, 0.0);
+        Self {
+            inner,
+            period,
+            blend: finite_or(blend, 0.0).clamp(0.0, period.max(0.0)),
+        }
+    }
+
+    /// Lap-local time and the crossfade weight toward the previous lap (0 outside the blend).
+    fn wrap(&self, t: SignalTime) -> Option<(SignalTime, SignalTime)> {
+        let period = finite_or(self.period, 0.0) as SignalTime;
+        if period <= 0.0 || !t.is_finite() {
+            return None;
+        }
+        let blend = (finite_or(self.blend, 0.0) as SignalTime).clamp(0.0, period);
+        let u = t.rem_euclid(period);
+        let start = period - blend;
+        let w = if blend > 0.0 && u > start {
+            let x = ((u - start) / blend).min(1.0);
+            x * x * (3.0 - 2.0 * x)
+        } else {
+            0.0
+        };
+        Some((u, w))
+    }
+
+    fn blended(&self, t: SignalTime, sample: impl Fn(SignalTime) -> f32) -> f32 {
+        let Some((u, w)) = self.wrap(t) else {
+            return sample(t);
+        };
+        let head = sample(u);
+        if w <= 0.0 {
+            return head;
+        }
+        let tail = sample(u - finite_or(self.period, 0.0) as SignalTime);
+        (head as SignalTime + (tail - head) as SignalTime * w) as f32
+    }
+}
+
+impl<S: Signal + Clone> Signal for LoopBlend<S> {
+    fn output_range(&self) -> SignalRange {
+        self.inner.output_range()
+    }
+
+    fn display_range(&self) -> (f32, f32) {
+        self.inner.display_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.blended(t, |t| self.inner.sample(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.blended(t, |t| self.inner.sample_with_context(t, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::noise::{PerlinNoise, SimplexNoise};
+    use crate::traits::SignalExt;
+
+    #[test]
+    fn test_loop_blend_seam_is_continuous() {
+        let noise = PerlinNoise::new(3, 3.0, 1.0).with_octaves(4, 0.5);
+        let looped = noise.loop_blend(4.0, 0.5);
+
+        // Plain wrapping pops at the seam
+        let plain = noise.looped(4.0);
+        assert!((plain.sample(4.0 - 1e-6) - plain.sample(4.0)).abs() > 1e-3);
+
+        for lap in [0.0, 4.0, 8.0, -4.0] {
+            let seam = lap + 4.0;
+            let jump = (looped.sample(seam - 1e-6) - looped.sample(seam)).abs();
+            assert!(jump < 1e-4, "jump {} at {}", jump, seam);
+        }
+        assert!((looped.sample(0.0) - looped.sample(4.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_loop_blend_interior_matches_inner() {
+        let noise = SimplexNoise::new(11, 2.0, 1.0);
+        let looped = LoopBlend::new(noise, 3.0, 0.75);
+        for i in 0..225 {
+            let u = i as f64 * 0.01;
+            assert_eq!(looped.sample(u), noise.sample(u));
+            assert_eq!(looped.sample(u + 6.0), noise.sample(u));
+        }
+        // Inside the blend window the output departs from the raw signal
+        assert!((0..75).any(|i| {
+            let u = 2.26 + i as f64 * 0.01;
+            looped.sample(u) != noise.sample(u)
+        }));
+    }
+
+    #[test]
+    fn test_loop_blend_clamps_parameters() {
+        let noise = PerlinNoise::with_seed(1);
+        assert_eq!(LoopBlend::new(noise, 2.0, 5.0).blend, 2.0);
+        assert_eq!(LoopBlend::new(noise, 2.0, -1.0).blend, 0.0);
+        assert_eq!(LoopBlend::new(noise, 2.0, f32::NAN).blend, 0.0);
+
+        // Zero blend behaves like Loop; invalid period passes through
+        let hard = LoopBlend::new(noise, 2.0, 0.0);
+        assert_eq!(hard.sample(2.5), noise.sample(0.5));
+        let off = LoopBlend::new(noise, f32::NAN, 1.0);
+        assert_eq!(off.sample(7.3), noise.sample(7.3));
+    }
+}
+
+// <FILE>src/processing/cls_loop_blend.rs</FILE> - <DESC>Seamless loop operator with a crossfaded seam</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
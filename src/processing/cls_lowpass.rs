@@ -1,10 +1,10 @@
 // <FILE>mixed-signals/src/processing/cls_lowpass.rs</FILE> - <DESC>One-pole low-pass filter</DESC>
-// <VERS>VERSION: 1.0.0 - 2025-12-28</VERS>
-// <WCTX>Larson Scanner audio implementation</WCTX>
-// <CLOG>Initial implementation - one-pole IIR filter with cutoff frequency</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>Streaming filters</WCTX>
+// <CLOG>Share alpha calculation with OnePoleState</CLOG>
 
+use super::cls_one_pole_state::one_pole_alpha;
 use crate::traits::{Signal, SignalContext, SignalTime};
-use std::f32::consts::PI;
 use std::sync::Mutex;
 
 /// One-pole low-pass filter for smoothing signals.
@@ -14,6 +14,7 @@ use std::sync::Mutex;
 ///
 /// This filter removes high-frequency content, creating a smoother,
 /// "rounder" sound. Useful for softening harsh digital waveforms.
+/// For streaming use, see [`OnePoleState`](super::OnePoleState).
 ///
 /// # Example
 /// ```ignore
@@ -37,12 +38,9 @@ impl<S: Signal> LowPass<S> {
     /// * `cutoff_hz` - Cutoff frequency in Hz (frequencies above this are attenuated)
     /// * `sample_rate` - Sample rate in Hz (e.g., 48000.0)
     pub fn new(signal: S, cutoff_hz: f32, sample_rate: f32) -> Self {
-        // Compute filter coefficient
-        // alpha = 1 - exp(-2 * PI * fc / fs)
-        let alpha = 1.0 - (-2.0 * PI * cutoff_hz / sample_rate).exp();
         Self {
             signal,
-            alpha: alpha.clamp(0.0, 1.0),
+            alpha: one_pole_alpha(cutoff_hz, sample_rate),
             state: Mutex::new((0.0, -1.0)), // (prev_output, prev_time)
         }
     }
@@ -63,7 +61,18 @@ impl<S: Signal> LowPass<S> {
     }
 }
 
-impl<S: Signal> Signal for LowPass<S> {
+/// Clones the configuration only; the clone starts with fresh filter state.
+impl<S: Clone> Clone for LowPass<S> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            alpha: self.alpha,
+            state: Mutex::new((0.0, -1.0)),
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for LowPass<S> {
     fn sample(&self, t: SignalTime) -> f32 {
         let input = self.signal.sample(t);
 
@@ -107,6 +116,7 @@ mod tests {
     use super::*;
     use crate::generators::Constant;
 
+    #[derive(Clone)]
     struct StepSignal {
         step_time: f64,
     }
@@ -172,7 +182,21 @@ mod tests {
         // Low cutoff should heavily attenuate
         assert!(result < 0.1, "Low cutoff should smooth heavily: {}", result);
     }
+
+    #[test]
+    fn test_lowpass_clone_starts_fresh() {
+        let lpf = LowPass::with_alpha(Constant::new(1.0), 0.1);
+        for i in 0..20 {
+            lpf.sample(i as f64 * 0.01);
+        }
+        let warmed = lpf.sample(0.2);
+        let cloned = lpf.clone();
+        let fresh = LowPass::with_alpha(Constant::new(1.0), 0.1);
+        assert_eq!(cloned.alpha(), lpf.alpha());
+        assert_eq!(cloned.sample(0.21), fresh.sample(0.21));
+        assert!(cloned.sample(0.22) < warmed);
+    }
 }
 
 // <FILE>mixed-signals/src/processing/cls_lowpass.rs</FILE> - <DESC>One-pole low-pass filter</DESC>
-// <VERS>END OF VERSION: 1.0.0 - 2025-12-28</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
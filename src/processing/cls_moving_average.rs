@@ -0,0 +1,270 @@
+// <FILE>src/processing/cls_moving_average.rs</FILE> - <DESC>Boxcar moving average smoother</DESC>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>Bounded window allocation</WCTX>
+// <CLOG>Window capped at MAX_WINDOW_SAMPLES; window_error for spec builds</CLOG>
+
+use crate::math::finite_or;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use std::sync::Mutex;
+
+/// Upper bound on the window length (4 MiB of `f32` samples).
+pub const MAX_WINDOW_SAMPLES: usize = 1 << 20;
+
+/// Moving average state: ring buffer plus running sum.
+#[derive(Debug, Clone, Default)]
+struct MovingAverageState {
+    window: Vec<f32>,
+    pos: usize,
+    /// Samples seen so far, saturating at the window length
+    filled: usize,
+    sum: f64,
+    last_output: f32,
+    prev_time: Option<f64>,
+}
+
+impl MovingAverageState {
+    fn new(len: usize) -> Self {
+        Self {
+            window: vec![0.0; len],
+            ..Self::default()
+        }
+    }
+}
+
+/// Moving (boxcar) average over the last `window_seconds` of input.
+///
+/// The window holds `round(window_seconds * sample_rate)` samples (at least
+/// one, at most [`MAX_WINDOW_SAMPLES`]) and the average is kept as a running sum, so each step is O(1)
+/// regardless of window length. Until the window fills, the output averages
+/// the samples seen so far, so constants pass through unchanged from the
+/// first sample. The group delay is half the window.
+///
+/// Like [`Fir`](super::Fir), the average advances one step each time `t`
+/// increases, returns the last output for a repeated `t`, and restarts when
+/// time goes backwards. Non-finite input samples are treated as 0.0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Constant;
+/// use mixed_signals::processing::MovingAverage;
+/// use mixed_signals::traits::Signal;
+///
+/// let smooth = MovingAverage::new(Constant::new(0.4), 0.1, 100.0);
+/// assert_eq!(smooth.window_len(), 10);
+/// assert!((smooth.sample(0.0) - 0.4).abs() < 1e-6);
+/// ```
+#[derive(Debug)]
+pub struct MovingAverage<S> {
+    signal: S,
+    window_seconds: f32,
+    sample_rate: f32,
+    len: usize,
+    state: Mutex<MovingAverageState>,
+}
+
+impl<S: Signal> MovingAverage<S> {
+    /// Average `signal` over the last `window_seconds` at `sample_rate`.
+    pub fn new(signal: S, window_seconds: f32, sample_rate: f32) -> Self {
+        let len = window_len(window_seconds, sample_rate);
+        Self {
+            signal,
+            window_seconds,
+            sample_rate,
+            len,
+            state: Mutex::new(MovingAverageState::new(len)),
+        }
+    }
+
+    /// Get the window length in seconds.
+    pub fn window_seconds(&self) -> f32 {
+        self.window_seconds
+    }
+
+    /// Get the sample rate in Hz.
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Number of samples in the window.
+    pub fn window_len(&self) -> usize {
+        self.len
+    }
+
+    fn process_sample(&self, input: f32, state: &mut MovingAverageState) -> f32 {
+        let input = finite_or(input, 0.0);
+        let pos = state.pos;
+        if state.filled == self.len {
+            state.sum -= state.window[pos] as f64;
+        } else {
+            state.filled += 1;
+        }
+        state.window[pos] = input;
+        state.sum += input as f64;
+        state.pos = (pos + 1) % self.len;
+
+        // Refresh the sum once per lap to shed accumulated rounding error
+        if state.pos == 0 {
+            state.sum = state.window.iter().map(|&v| v as f64).sum();
+        }
+
+        let output = (state.sum / state.filled as f64) as f32;
+        state.last_output = output;
+        output
+    }
+
+    fn step(&self, t: SignalTime, input: f32) -> f32 {
+        let mut state = self.state.lock().unwrap();
+
+        match state.prev_time {
+            Some(prev) if t == prev => return state.last_output,
+            // Time went backwards - restart with an empty window
+            Some(prev) if t < prev => *state = MovingAverageState::new(self.len),
+            _ => {}
+        }
+        state.prev_time = Some(t);
+        self.process_sample(input, &mut state)
+    }
+}
+
+/// Requested window length in samples, before clamping.
+fn window_samples(window_seconds: f32, sample_rate: f32) -> f32 {
+    (finite_or(window_seconds, 0.0) * finite_or(sample_rate, 0.0)).round()
+}
+
+/// Window length in samples; invalid inputs collapse to a single sample.
+fn window_len(window_seconds: f32, sample_rate: f32) -> usize {
+    let samples = window_samples(window_seconds, sample_rate);
+    if samples >= 1.0 {
+        samples.min(MAX_WINDOW_SAMPLES as f32) as usize
+    } else {
+        1
+    }
+}
+
+/// Why a window would be clamped to [`MAX_WINDOW_SAMPLES`], if it would be.
+///
+/// `SignalSpec` builds reject such windows instead of silently shortening them.
+pub(crate) fn window_error(window_seconds: f32, sample_rate: f32) -> Option<String> {
+    let samples = window_samples(window_seconds, sample_rate);
+    (samples > MAX_WINDOW_SAMPLES as f32).then(|| {
+        format!(
+            "window of {} samples exceeds MAX_WINDOW_SAMPLES ({})",
+            samples, MAX_WINDOW_SAMPLES
+        )
+    })
+}
+
+/// Clones the configuration only; the clone starts with an empty window.
+impl<S: Clone> Clone for MovingAverage<S> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            window_seconds: self.window_seconds,
+            sample_rate: self.sample_rate,
+            len: self.len,
+            state: Mutex::new(MovingAverageState::new(self.len)),
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for MovingAverage<S> {
+    fn output_range(&self) -> SignalRange {
+        self.signal.output_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let input = self.signal.sample(t);
+        self.step(t, input)
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let input = self.signal.sample_with_context(t, ctx);
+        self.step(t, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Ramp};
+    #[cfg(feature = "chacha")]
+    use crate::noise::WhiteNoise;
+
+    #[cfg(feature = "chacha")]
+    fn variance(values: &[f32]) -> f64 {
+        let mean = values.iter().map(|&v| v as f64).sum::<f64>() / values.len() as f64;
+        values
+            .iter()
+            .map(|&v| (v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / values.len() as f64
+    }
+
+    #[test]
+    fn test_moving_average_constant_passes_through() {
+        let avg = MovingAverage::new(Constant::new(0.37), 0.05, 1000.0);
+        for i in 0..200 {
+            assert!((avg.sample(i as f64 / 1000.0) - 0.37).abs() < 1e-6);
+        }
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn test_moving_average_reduces_noise_variance() {
+        let sr = 1000.0;
+        let noise = WhiteNoise::new(7, 1.0, sr);
+        let avg = MovingAverage::new(noise, 0.016, sr);
+        assert_eq!(avg.window_len(), 16);
+
+        let times: Vec<f64> = (0..4000).map(|i| i as f64 / sr as f64).collect();
+        let raw: Vec<f32> = times.iter().map(|&t| noise.sample(t)).collect();
+        let smoothed: Vec<f32> = times.iter().map(|&t| avg.sample(t)).collect();
+        let (raw_var, smooth_var) = (variance(&raw), variance(&smoothed[16..]));
+        // Averaging N independent samples divides variance by about N
+        assert!(smooth_var < raw_var / 8.0, "{} vs {}", smooth_var, raw_var);
+    }
+
+    #[test]
+    fn test_moving_average_group_delay_is_half_window() {
+        // A ramp's average lags by (N - 1) / 2 samples once the window fills
+        let sr = 100.0;
+        let avg = MovingAverage::new(Ramp::new(0.0, 10.0, 10.0), 0.2, sr);
+        let n = avg.window_len();
+        assert_eq!(n, 20);
+        let delay = (n - 1) as f64 / 2.0 / sr as f64;
+        for i in 0..300 {
+            let t = i as f64 / sr as f64;
+            let out = avg.sample(t);
+            if i >= n {
+                assert!((out as f64 - (t - delay)).abs() < 1e-4, "t={}", t);
+            }
+        }
+    }
+
+    #[test]
+    fn test_moving_average_invalid_window_and_rewind() {
+        let avg = MovingAverage::new(Ramp::new(0.0, 1.0, 1.0), f32::NAN, 100.0);
+        assert_eq!(avg.window_len(), 1);
+        assert!((avg.sample(0.5) - 0.5).abs() < 1e-6);
+
+        let avg = MovingAverage::new(Ramp::new(0.0, 1.0, 1.0), 1.0, 10.0);
+        avg.sample(0.5);
+        avg.sample(0.6);
+        // Rewinding restarts from an empty window
+        assert!((avg.sample(0.2) - 0.2).abs() < 1e-6);
+        assert!((avg.clone().sample(0.9) - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_moving_average_huge_window_is_capped() {
+        let avg = MovingAverage::new(Constant::new(0.25), 1e30, 48_000.0);
+        assert_eq!(avg.window_len(), MAX_WINDOW_SAMPLES);
+        assert!((avg.sample(0.0) - 0.25).abs() < 1e-6);
+        assert!(window_error(1e30, 48_000.0).is_some());
+        assert!(window_error(1.0, 48_000.0).is_none());
+    }
+}
+
+// <FILE>src/processing/cls_moving_average.rs</FILE> - <DESC>Boxcar moving average smoother</DESC>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -1,16 +1,18 @@
 // <FILE>src/processing/cls_normalized.rs</FILE> - <DESC>Normalize any signal to 0..1 range</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Initial implementation - auto-normalizes using signal's output_range()</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>Perceptual brightness curves</WCTX>
+// <CLOG>Added with_easing to shape the normalized output</CLOG>
 
 //! Signal normalization wrapper.
 //!
 //! The `Normalized` wrapper remaps any signal's output to the unit range [0, 1].
 //! It uses the signal's `output_range()` to determine the source range, then
-//! linearly remaps values to [0, 1] with clamping for safety.
+//! linearly remaps values to [0, 1] with clamping for safety. An optional
+//! easing curve then reshapes the result, e.g. for perceptual brightness.
 //!
 //! This is the primary API for TUI consumers who need normalized values.
 
+use crate::easing::{ease, EasingType};
 use crate::processing::remap_range;
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 
@@ -39,6 +41,7 @@ use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 #[derive(Debug, Clone)]
 pub struct Normalized<S> {
     signal: S,
+    easing: EasingType,
 }
 
 impl<S> Normalized<S> {
@@ -47,7 +50,25 @@ impl<S> Normalized<S> {
     /// The wrapper will use the signal's `output_range()` to determine
     /// how to remap values to [0, 1].
     pub fn new(signal: S) -> Self {
-        Self { signal }
+        Self {
+            signal,
+            easing: EasingType::Linear,
+        }
+    }
+
+    /// Apply an easing curve after normalization (default `Linear`).
+    ///
+    /// Useful for perceptual brightness: `QuadIn` darkens the mid-range so a
+    /// sine's center reads as half-bright on screen. Endpoints stay at 0 and
+    /// 1; curves that overshoot (`Back*`, `Elastic*`) are clamped to [0, 1].
+    pub fn with_easing(mut self, easing: EasingType) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Easing curve applied after normalization.
+    pub fn easing(&self) -> EasingType {
+        self.easing
     }
 
     /// Get a reference to the wrapped signal.
@@ -61,21 +82,28 @@ impl<S> Normalized<S> {
     }
 }
 
-impl<S: Signal> Signal for Normalized<S> {
+impl<S: Signal + Clone> Signal for Normalized<S> {
     fn output_range(&self) -> SignalRange {
         SignalRange::UNIT
     }
 
     fn sample(&self, t: SignalTime) -> f32 {
-        let v = self.signal.sample(t);
-        let from_range = self.signal.output_range();
-        remap_range(v, from_range, SignalRange::UNIT).clamp(0.0, 1.0)
+        self.shape(self.signal.sample(t))
     }
 
     fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
-        let v = self.signal.sample_with_context(t, ctx);
+        self.shape(self.signal.sample_with_context(t, ctx))
+    }
+}
+
+impl<S: Signal> Normalized<S> {
+    fn shape(&self, v: f32) -> f32 {
         let from_range = self.signal.output_range();
-        remap_range(v, from_range, SignalRange::UNIT).clamp(0.0, 1.0)
+        let unit = remap_range(v, from_range, SignalRange::UNIT).clamp(0.0, 1.0);
+        if self.easing == EasingType::Linear {
+            return unit;
+        }
+        ease(unit as SignalTime, self.easing).clamp(0.0, 1.0)
     }
 }
 
@@ -84,6 +112,7 @@ mod tests {
     use super::*;
 
     // Helper struct for testing bipolar signals
+    #[derive(Clone)]
     struct BipolarConstant(f32);
 
     impl Signal for BipolarConstant {
@@ -123,6 +152,7 @@ mod tests {
         // Constant(0.7) has output_range [0.7, 0.7], normalizing to unit
         // gives (0.7 - 0.7) / 0 = NaN, which clamps to 0.0
         // For a true passthrough test, use a unit-range signal
+        #[derive(Clone)]
         struct UnitConstant(f32);
         impl Signal for UnitConstant {
             fn output_range(&self) -> SignalRange {
@@ -147,6 +177,7 @@ mod tests {
     #[test]
     fn test_normalized_clamps_overflow() {
         // Value exceeds declared range → should be clamped
+        #[derive(Clone)]
         struct OverflowSignal;
         impl Signal for OverflowSignal {
             fn output_range(&self) -> SignalRange {
@@ -163,6 +194,7 @@ mod tests {
 
     #[test]
     fn test_normalized_clamps_underflow() {
+        #[derive(Clone)]
         struct UnderflowSignal;
         impl Signal for UnderflowSignal {
             fn output_range(&self) -> SignalRange {
@@ -191,7 +223,43 @@ mod tests {
         let normalized = Normalized::new(inner);
         assert_eq!(normalized.inner().0, 0.5);
     }
+
+    #[test]
+    fn test_normalized_linear_easing_matches_default() {
+        use crate::generators::Sine;
+
+        let plain = Normalized::new(Sine::with_frequency(1.3));
+        let linear = Normalized::new(Sine::with_frequency(1.3)).with_easing(EasingType::Linear);
+        for i in 0..200 {
+            let t = i as f64 * 0.011;
+            assert_eq!(plain.sample(t), linear.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_normalized_quad_in_darkens_mid_range() {
+        let eased = |v: f32| {
+            Normalized::new(BipolarConstant(v))
+                .with_easing(EasingType::QuadIn)
+                .sample(0.0)
+        };
+        assert_eq!(eased(-1.0), 0.0);
+        assert_eq!(eased(1.0), 1.0);
+        assert!((eased(0.0) - 0.25).abs() < 1e-6);
+        for v in [-0.6, -0.2, 0.3, 0.7] {
+            let linear = Normalized::new(BipolarConstant(v)).sample(0.0);
+            assert!(eased(v) < linear, "{} not darkened", v);
+        }
+    }
+
+    #[test]
+    fn test_normalized_overshooting_easing_clamped() {
+        for v in [-1.0, -0.5, 0.0, 0.5, 1.0] {
+            let sig = Normalized::new(BipolarConstant(v)).with_easing(EasingType::ElasticOut);
+            assert!((0.0..=1.0).contains(&sig.sample(0.0)));
+        }
+    }
 }
 
 // <FILE>src/processing/cls_normalized.rs</FILE> - <DESC>Normalize any signal to 0..1 range</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
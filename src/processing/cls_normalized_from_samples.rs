@@ -0,0 +1,148 @@
+// <FILE>src/processing/cls_normalized_from_samples.rs</FILE> - <DESC>Normalize to 0..1 using a measured range</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Normalizing signals with unreliable output ranges</WCTX>
+// <CLOG>Initial creation - NormalizedFromSamples measuring min/max at construction</CLOG>
+
+use crate::processing::remap_range;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Normalizes a signal to [0, 1] using a range measured by sampling it.
+///
+/// [`Normalized`](super::Normalized) trusts `output_range()`, which filters,
+/// noise compositions and phase chains often report conservatively (squashing
+/// the output) or degenerately (dividing by a near-zero span). This wrapper
+/// samples the signal once at construction, `samples` points evenly spaced
+/// over `[t_start, t_start + duration]`, and caches the observed min/max.
+///
+/// Values outside the measured range that turn up later are clamped. If the
+/// measured span is zero (or no sample was finite) the output is 0.5. The
+/// measurement uses plain [`Signal::sample`]; context-dependent signals are
+/// measured with the default context.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::traits::{Signal, SignalExt};
+///
+/// // A quiet sine, stretched to fill [0, 1]
+/// let quiet = Sine::with_frequency(1.0).map(|v| v * 0.1);
+/// let full = quiet.normalized_over(0.0, 1.0, 1001);
+/// assert!((full.sample(0.25) - 1.0).abs() < 1e-6);
+/// assert!(full.sample(0.75).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NormalizedFromSamples<S> {
+    signal: S,
+    measured: Option<SignalRange>,
+}
+
+impl<S: Signal> NormalizedFromSamples<S> {
+    /// Measure `signal` at `samples` points over `[t_start, t_start + duration]`.
+    pub fn new(signal: S, t_start: SignalTime, duration: SignalTime, samples: usize) -> Self {
+        let mut min = f32::INFINITY;
+        let mut max = f32::NEG_INFINITY;
+        if t_start.is_finite() && duration.is_finite() {
+            for i in 0..samples {
+                let t = if samples == 1 {
+                    t_start
+                } else {
+                    t_start + duration * (i as SignalTime / (samples - 1) as SignalTime)
+                };
+                let v = signal.sample(t);
+                if v.is_finite() {
+                    min = min.min(v);
+                    max = max.max(v);
+                }
+            }
+        }
+        let measured = (max > min).then(|| SignalRange::new(min, max));
+        Self { signal, measured }
+    }
+
+    /// The measured range, or None if it had zero span.
+    pub fn measured_range(&self) -> Option<SignalRange> {
+        self.measured
+    }
+
+    /// Get a reference to the wrapped signal.
+    pub fn inner(&self) -> &S {
+        &self.signal
+    }
+
+    fn shape(&self, v: f32) -> f32 {
+        match self.measured {
+            Some(range) if v.is_finite() => {
+                remap_range(v, range, SignalRange::UNIT).clamp(0.0, 1.0)
+            }
+            _ => 0.5,
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for NormalizedFromSamples<S> {
+    fn output_range(&self) -> SignalRange {
+        SignalRange::UNIT
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.shape(self.signal.sample(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.shape(self.signal.sample_with_context(t, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+    use crate::processing::Remap;
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_normalized_over_shifted_sine_hits_both_ends() {
+        // [-1, 1] shifted to [2, 3]
+        let shifted = Remap::new(Sine::with_frequency(1.0), -1.0, 1.0, 2.0, 3.0);
+        let normalized = NormalizedFromSamples::new(shifted, 0.0, 1.0, 1001);
+        let range = normalized.measured_range().unwrap();
+        assert!((range.min - 2.0).abs() < 1e-5 && (range.max - 3.0).abs() < 1e-5);
+        assert!((normalized.sample(0.25) - 1.0).abs() < 1e-5);
+        assert!(normalized.sample(0.75).abs() < 1e-5);
+        assert!((normalized.sample(0.0) - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_normalized_over_constant_is_half() {
+        let normalized = NormalizedFromSamples::new(Constant::new(0.8), 0.0, 1.0, 50);
+        assert_eq!(normalized.measured_range(), None);
+        assert_eq!(normalized.sample(0.3), 0.5);
+        assert_eq!(normalized.output_range(), SignalRange::UNIT);
+    }
+
+    #[test]
+    fn test_normalized_over_clamps_later_values() {
+        // Measured over [0, 1] where the ramp spans [0, 1]; later values overflow
+        let ramp = from_fn(|t| t as f32);
+        let normalized = NormalizedFromSamples::new(ramp, 0.0, 1.0, 11);
+        assert_eq!(normalized.sample(0.5), 0.5);
+        assert_eq!(normalized.sample(5.0), 1.0);
+        assert_eq!(normalized.sample(-5.0), 0.0);
+    }
+
+    #[test]
+    fn test_normalized_over_degenerate_windows() {
+        let sine = Sine::with_frequency(1.0);
+        for (start, duration, samples) in [(0.0, 1.0, 0), (0.0, 1.0, 1), (f64::NAN, 1.0, 10)] {
+            let normalized = NormalizedFromSamples::new(sine, start, duration, samples);
+            assert_eq!(normalized.measured_range(), None);
+            assert_eq!(normalized.sample(0.25), 0.5);
+        }
+        let nan = NormalizedFromSamples::new(from_fn(|_| f32::NAN), 0.0, 1.0, 10);
+        assert_eq!(nan.sample(0.0), 0.5);
+    }
+}
+
+// <FILE>src/processing/cls_normalized_from_samples.rs</FILE> - <DESC>Normalize to 0..1 using a measured range</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -0,0 +1,114 @@
+// <FILE>src/processing/cls_one_pole_state.rs</FILE> - <DESC>Streaming one-pole low-pass processor</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, core:: instead of std:: paths</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::Processor;
+use core::f32::consts::PI;
+
+/// One-pole smoothing coefficient `alpha = 1 - exp(-2 * PI * fc / fs)`, clamped to [0, 1].
+///
+/// Shared by [`OnePoleState`] and the [`LowPass`](super::LowPass) signal adapter.
+pub(crate) fn one_pole_alpha(cutoff_hz: f32, sample_rate: f32) -> f32 {
+    (1.0 - (-2.0 * PI * cutoff_hz / sample_rate).exp()).clamp(0.0, 1.0)
+}
+
+/// Streaming one-pole low-pass filter.
+///
+/// The [`Processor`] counterpart of [`LowPass`](super::LowPass):
+/// `y[n] = alpha * x[n] + (1 - alpha) * y[n-1]`.
+///
+/// # Example
+/// ```
+/// use mixed_signals::processing::OnePoleState;
+/// use mixed_signals::traits::Processor;
+///
+/// let mut smooth = OnePoleState::new(1000.0, 48000.0);
+/// let y = smooth.process(1.0);
+/// assert!(y > 0.0 && y < 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OnePoleState {
+    alpha: f32,
+    y1: f32,
+}
+
+impl OnePoleState {
+    /// Create a one-pole low-pass with the given cutoff.
+    pub fn new(cutoff_hz: f32, sample_rate: f32) -> Self {
+        Self::with_alpha(one_pole_alpha(cutoff_hz, sample_rate))
+    }
+
+    /// Create with a specific alpha coefficient (0-1).
+    /// Lower alpha = more smoothing, higher alpha = less smoothing.
+    pub fn with_alpha(alpha: f32) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            y1: 0.0,
+        }
+    }
+
+    /// Get the current alpha coefficient
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Most recent output sample (0.0 after a reset).
+    pub fn last_output(&self) -> f32 {
+        self.y1
+    }
+}
+
+impl Processor for OnePoleState {
+    fn process(&mut self, input: f32) -> f32 {
+        self.y1 = self.alpha * input + (1.0 - self.alpha) * self.y1;
+        self.y1
+    }
+
+    fn reset(&mut self) {
+        self.y1 = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_pole_impulse_response() {
+        let mut lp = OnePoleState::new(1000.0, 48000.0);
+        let alpha = 1.0 - (-2.0 * PI * 1000.0f32 / 48000.0).exp();
+        assert!((lp.alpha() - alpha).abs() < 1e-7);
+
+        // h[n] = alpha * (1 - alpha)^n
+        let mut expected = alpha;
+        for i in 0..16 {
+            let y = lp.process(if i == 0 { 1.0 } else { 0.0 });
+            assert!((y - expected).abs() < 1e-6, "sample {}: {}", i, y);
+            expected *= 1.0 - alpha;
+        }
+    }
+
+    #[test]
+    fn test_one_pole_reset_clears_history() {
+        let mut lp = OnePoleState::with_alpha(0.1);
+        for _ in 0..10 {
+            lp.process(1.0);
+        }
+        assert!(lp.last_output() > 0.5);
+        lp.reset();
+        assert_eq!(lp.last_output(), 0.0);
+        assert_eq!(lp.process(1.0), 0.1);
+    }
+
+    #[test]
+    fn test_one_pole_alpha_clamped() {
+        assert_eq!(OnePoleState::with_alpha(2.0).alpha(), 1.0);
+        assert_eq!(OnePoleState::with_alpha(-1.0).alpha(), 0.0);
+    }
+}
+
+// <FILE>src/processing/cls_one_pole_state.rs</FILE> - <DESC>Streaming one-pole low-pass processor</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,14 +1,18 @@
 // <FILE>mixed-signals/src/processing/cls_quantize.rs</FILE> - <DESC>Signal quantization operator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Bipolar core refactor</WCTX>
-// <CLOG>Removed input clamping, added output_range() - quantizes within signal's range</CLOG>
+// <VERS>VERSION: 2.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std</CLOG>
 
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 
 /// Quantizes a signal to discrete levels within its range.
 ///
 /// Useful for creating stepped/staircase effects or bit-crushing.
 /// Works with any signal range - quantizes values within [min, max] to discrete steps.
+/// To snap to arbitrary values (scale pitches, a brightness palette) use
+/// [`QuantizeToSet`](super::QuantizeToSet).
 #[derive(Debug, Clone)]
 pub struct Quantize<S> {
     pub signal: S,
@@ -25,7 +29,7 @@ impl<S: Signal> Quantize<S> {
     }
 }
 
-impl<S: Signal> Signal for Quantize<S> {
+impl<S: Signal + Clone> Signal for Quantize<S> {
     fn output_range(&self) -> SignalRange {
         // Output range is same as input - we just snap to discrete values within it
         self.signal.output_range()
@@ -99,6 +103,7 @@ mod tests {
     #[test]
     fn test_quantize_mid_value_unit_signal() {
         // Create a unit-range signal for testing old behavior
+        #[derive(Clone)]
         struct UnitSignal(f32);
         impl Signal for UnitSignal {
             fn output_range(&self) -> SignalRange {
@@ -117,4 +122,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/processing/cls_quantize.rs</FILE> - <DESC>Signal quantization operator</DESC>
-// <VERS>END OF VERSION: 2.0.0</VERS>
+// <VERS>END OF VERSION: 2.3.0</VERS>
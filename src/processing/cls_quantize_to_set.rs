@@ -0,0 +1,162 @@
+// <FILE>src/processing/cls_quantize_to_set.rs</FILE> - <DESC>Snap a signal to the nearest value in an arbitrary set</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>FloatExt import for libm math without std, alloc imports</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use alloc::vec::Vec;
+
+/// Snaps a signal to the nearest value in an arbitrary set.
+///
+/// [`Quantize`](super::Quantize) divides the input range into uniform steps.
+/// `QuantizeToSet` snaps to a given list instead, such as the frequencies of a
+/// musical scale or a hand-picked palette of brightness levels.
+///
+/// - Values are sorted internally; non-finite entries are dropped.
+/// - A value exactly halfway between two neighbours snaps to the **lower** one.
+/// - An empty set passes the input through unchanged; a single value makes
+///   the output constant.
+/// - `output_range` is the span of the set (the inner range when empty).
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Ramp;
+/// use mixed_signals::processing::QuantizeToSet;
+/// use mixed_signals::traits::Signal;
+///
+/// // A sweep from 200 to 500 Hz locked to an A-minor pentatonic
+/// let sweep = Ramp::new(200.0, 500.0, 1.0);
+/// let notes = QuantizeToSet::from_scale(sweep, 220.0, &[0.0, 3.0, 5.0, 7.0, 10.0], 1);
+/// assert_eq!(notes.sample(0.0), 220.0);
+/// assert_eq!(notes.sample(1.0), 440.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuantizeToSet<S> {
+    pub signal: S,
+    /// Sorted, finite snap targets
+    values: Vec<f32>,
+}
+
+impl<S: Signal> QuantizeToSet<S> {
+    pub fn new(signal: S, mut values: Vec<f32>) -> Self {
+        values.retain(|v| v.is_finite());
+        values.sort_by(f32::total_cmp);
+        values.dedup();
+        Self { signal, values }
+    }
+
+    /// Snap to a scale: `root * 2^(s / 12)` for each semitone offset `s`,
+    /// repeated over `octaves` octaves, plus the root of the octave above.
+    pub fn from_scale(signal: S, root: f32, semitones: &[f32], octaves: u32) -> Self {
+        let mut values = Vec::with_capacity(semitones.len() * octaves as usize + 1);
+        for octave in 0..octaves {
+            for s in semitones {
+                values.push(root * 2f32.powf((octave as f32 * 12.0 + s) / 12.0));
+            }
+        }
+        values.push(root * 2f32.powi(octaves as i32));
+        Self::new(signal, values)
+    }
+
+    /// The snap targets, ascending.
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Nearest set value to `x` (ties go to the lower value).
+    pub fn snap(&self, x: f32) -> f32 {
+        let values = &self.values;
+        let (Some(&first), Some(&last)) = (values.first(), values.last()) else {
+            return x;
+        };
+        if x.is_nan() || x <= first {
+            return first;
+        }
+        if x >= last {
+            return last;
+        }
+        let idx = values.partition_point(|&v| v < x);
+        let (lo, hi) = (values[idx - 1], values[idx]);
+        if x - lo <= hi - x {
+            lo
+        } else {
+            hi
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for QuantizeToSet<S> {
+    fn output_range(&self) -> SignalRange {
+        match (self.values.first(), self.values.last()) {
+            (Some(&min), Some(&max)) => SignalRange::new(min, max),
+            _ => self.signal.output_range(),
+        }
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.snap(self.signal.sample(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.snap(self.signal.sample_with_context(t, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Ramp, Sine};
+
+    #[test]
+    fn test_quantize_to_set_nearest_and_ties() {
+        let q = QuantizeToSet::new(Constant::new(0.0), vec![1.0, 2.0, 4.0]);
+        assert_eq!(q.snap(1.4), 1.0);
+        assert_eq!(q.snap(1.6), 2.0);
+        // Exactly halfway rounds down
+        assert_eq!(q.snap(1.5), 1.0);
+        assert_eq!(q.snap(3.0), 2.0);
+        assert_eq!(q.snap(3.01), 4.0);
+        // Outside the set clamps to the ends
+        assert_eq!(q.snap(-10.0), 1.0);
+        assert_eq!(q.snap(10.0), 4.0);
+    }
+
+    #[test]
+    fn test_quantize_to_set_sorts_and_reports_range() {
+        let sine = Sine::with_frequency(1.0);
+        let q = QuantizeToSet::new(sine, vec![0.5, -0.5, f32::NAN, 0.0, 0.5]);
+        assert_eq!(q.values(), &[-0.5, 0.0, 0.5]);
+        assert_eq!(q.output_range(), SignalRange::new(-0.5, 0.5));
+        assert_eq!(q.sample(0.25), 0.5);
+        assert_eq!(q.sample(0.75), -0.5);
+    }
+
+    #[test]
+    fn test_quantize_to_set_empty_and_single() {
+        let ramp = Ramp::new(0.0, 1.0, 1.0);
+        let empty = QuantizeToSet::new(ramp, vec![]);
+        assert_eq!(empty.sample(0.37), ramp.sample(0.37));
+        assert_eq!(empty.output_range(), ramp.output_range());
+
+        let single = QuantizeToSet::new(ramp, vec![0.8]);
+        for i in 0..=10 {
+            assert_eq!(single.sample(i as f64 * 0.1), 0.8);
+        }
+        assert_eq!(single.snap(f32::NAN), 0.8);
+    }
+
+    #[test]
+    fn test_quantize_to_set_scale_frequencies() {
+        let q = QuantizeToSet::from_scale(Constant::new(0.0), 220.0, &[0.0, 7.0], 1);
+        let values = q.values();
+        assert_eq!(values.len(), 3);
+        assert!((values[1] - 329.6276).abs() < 1e-2);
+        assert_eq!(values[2], 440.0);
+    }
+}
+
+// <FILE>src/processing/cls_quantize_to_set.rs</FILE> - <DESC>Snap a signal to the nearest value in an arbitrary set</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
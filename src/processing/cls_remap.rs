@@ -1,7 +1,7 @@
 // <FILE>mixed-signals/src/processing/cls_remap.rs</FILE> - <DESC>Signal range remapping operator</DESC>
-// <VERS>VERSION: 1.0.0 - 2025-12-22</VERS>
-// <WCTX>Signal generator implementation</WCTX>
-// <CLOG>Initial implementation</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>Order-independent phase accumulation</WCTX>
+// <CLOG>Linear integral override</CLOG>
 
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 
@@ -39,7 +39,7 @@ impl<S: Signal> Remap<S> {
     }
 }
 
-impl<S: Signal> Signal for Remap<S> {
+impl<S: Signal + Clone> Signal for Remap<S> {
     fn output_range(&self) -> SignalRange {
         SignalRange::new(self.out_min, self.out_max)
     }
@@ -64,6 +64,25 @@ impl<S: Signal> Signal for Remap<S> {
         self.out_min + normalized * out_range
     }
 
+    /// The map is linear, so the integral is the remapped inner integral.
+    fn integral(&self, t: SignalTime) -> f64 {
+        let inner = self.signal.integral(t);
+        if !self.in_min.is_finite()
+            || !self.in_max.is_finite()
+            || !self.out_min.is_finite()
+            || !self.out_max.is_finite()
+        {
+            return inner;
+        }
+        let t = if t.is_finite() { t } else { 0.0 };
+        let in_range = self.in_max - self.in_min;
+        if in_range.abs() < 0.0001 {
+            return self.out_min as f64 * t;
+        }
+        let scale = (self.out_max - self.out_min) as f64 / in_range as f64;
+        self.out_min as f64 * t + (inner - self.in_min as f64 * t) * scale
+    }
+
     fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
         let v = self.signal.sample_with_context(t, ctx);
         if !self.in_min.is_finite()
@@ -90,6 +109,7 @@ mod tests {
     use super::*;
     use crate::generators::Constant;
 
+    #[derive(Clone)]
     struct RawSignal(f32);
 
     impl Signal for RawSignal {
@@ -144,7 +164,19 @@ mod tests {
         let remapped = Remap::new(sig, f32::NAN, 1.0, 0.0, 1.0);
         assert!((remapped.sample(0.0) - 0.7).abs() < 0.001);
     }
+
+    #[test]
+    fn test_remap_integral_is_linear_in_inner() {
+        use crate::generators::Sine;
+        let sine = Sine::new(3.0, 1.0, 0.0, 0.1);
+        let remapped = Remap::new(sine, -1.0, 1.0, 200.0, 600.0);
+        // 400 + 200·sin → 400t + 200·∫sin
+        let expected = 400.0 * 1.3 + 200.0 * sine.integral(1.3);
+        assert!((remapped.integral(1.3) - expected).abs() < 1e-9);
+        let flat = Remap::new(sine, 0.5, 0.5, 2.0, 3.0);
+        assert_eq!(flat.integral(2.0), 4.0);
+    }
 }
 
 // <FILE>mixed-signals/src/processing/cls_remap.rs</FILE> - <DESC>Signal range remapping operator</DESC>
-// <VERS>END OF VERSION: 1.0.0 - 2025-12-22</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -0,0 +1,219 @@
+// <FILE>src/processing/cls_slew_limit.rs</FILE> - <DESC>Rate-of-change limiter (portamento)</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Portamento and de-clicking</WCTX>
+// <CLOG>Initial creation - SlewLimit with separate rise/fall rates and reset</CLOG>
+
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use std::sync::Mutex;
+
+/// Slew limiter state: last output and the time it was produced.
+#[derive(Debug, Clone, Copy, Default)]
+struct SlewState {
+    last_output: f32,
+    prev_time: Option<SignalTime>,
+}
+
+/// Limits how fast a signal can change, in units per second.
+///
+/// Each step moves the output toward the input by at most `rise * dt`
+/// (upward) or `fall * dt` (downward), where `dt` is the time since the
+/// previous sample. A jump in the input becomes a ramp with that slope,
+/// which covers portamento between notes, smoothed parameter changes and
+/// de-clicking envelope edges. Inputs that already change more slowly than
+/// the limit pass through unchanged.
+///
+/// Like [`MovingAverage`](super::MovingAverage), this is stateful. It needs
+/// monotonically increasing `t`:
+///
+/// - The first sample (and the first after [`reset`](Self::reset)) outputs
+///   the input directly.
+/// - A repeated `t` returns the last output.
+/// - If time goes backwards, the limiter restarts from the input.
+///
+/// For a fixed `dt` the output sequence is fully deterministic. A rate that
+/// is NaN or infinite means "no limit" in that direction. Negative rates
+/// act as 0, which freezes the output in that direction.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Step;
+/// use mixed_signals::processing::SlewLimit;
+/// use mixed_signals::traits::Signal;
+///
+/// // Jump from 0 to 1 at t = 0.5, limited to 2 units/s
+/// let glide = SlewLimit::new(Step::new(0.0, 1.0, 0.5), 2.0);
+/// assert_eq!(glide.sample(0.25), 0.0);
+/// assert_eq!(glide.sample(0.5), 0.5);
+/// assert_eq!(glide.sample(0.75), 1.0);
+/// ```
+#[derive(Debug)]
+pub struct SlewLimit<S> {
+    signal: S,
+    rise: f32,
+    fall: f32,
+    state: Mutex<SlewState>,
+}
+
+impl<S: Signal> SlewLimit<S> {
+    /// Limit both directions to `rate` units per second.
+    pub fn new(signal: S, rate: f32) -> Self {
+        Self::with_rates(signal, rate, rate)
+    }
+
+    /// Limit rising changes to `rise` and falling changes to `fall` units per second.
+    pub fn with_rates(signal: S, rise: f32, fall: f32) -> Self {
+        Self {
+            signal,
+            rise: sanitize_rate(rise),
+            fall: sanitize_rate(fall),
+            state: Mutex::new(SlewState::default()),
+        }
+    }
+
+    /// Maximum upward rate in units per second.
+    pub fn rise(&self) -> f32 {
+        self.rise
+    }
+
+    /// Maximum downward rate in units per second.
+    pub fn fall(&self) -> f32 {
+        self.fall
+    }
+
+    /// Forget the previous output so the next sample starts from the input.
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = SlewState::default();
+    }
+
+    fn step(&self, t: SignalTime, input: f32) -> f32 {
+        let mut state = self.state.lock().unwrap();
+        let output = match state.prev_time {
+            Some(prev) if t == prev => return state.last_output,
+            Some(prev) if t > prev && input.is_finite() => {
+                let dt = t - prev;
+                let last = state.last_output as SignalTime;
+                let max_up = self.rise as SignalTime * dt;
+                let max_down = self.fall as SignalTime * dt;
+                let delta = (input as SignalTime - last).clamp(-max_down, max_up);
+                (last + delta) as f32
+            }
+            // First sample, time went backwards, or a non-finite input
+            _ => {
+                if input.is_finite() {
+                    input
+                } else {
+                    state.last_output
+                }
+            }
+        };
+        state.last_output = output;
+        state.prev_time = Some(t);
+        output
+    }
+}
+
+/// NaN and infinite rates disable the limit; negative rates freeze.
+fn sanitize_rate(rate: f32) -> f32 {
+    if rate.is_finite() {
+        rate.max(0.0)
+    } else {
+        f32::INFINITY
+    }
+}
+
+/// Clones the configuration only; the clone starts with fresh state.
+impl<S: Clone> Clone for SlewLimit<S> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            rise: self.rise,
+            fall: self.fall,
+            state: Mutex::new(SlewState::default()),
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for SlewLimit<S> {
+    fn output_range(&self) -> SignalRange {
+        // Output only moves toward the input, so it stays within the input range
+        self.signal.output_range()
+    }
+
+    fn display_range(&self) -> (f32, f32) {
+        self.signal.display_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let input = self.signal.sample(t);
+        self.step(t, input)
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let input = self.signal.sample_with_context(t, ctx);
+        self.step(t, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Sine, Step};
+    use crate::traits::SignalExt;
+
+    #[test]
+    fn test_slew_step_becomes_ramp() {
+        // Jump at t = 0.25, sampled every 1/64 s, limited to 2 units/s
+        let slewed = Step::new(0.0, 1.0, 0.25).slew(2.0);
+        let dt = 1.0 / 64.0;
+        let outputs: Vec<f32> = (0..=64).map(|i| slewed.sample(i as f64 * dt)).collect();
+        assert!(outputs[..16].iter().all(|&v| v == 0.0));
+        // Rises 1/32 per step, reaching 1.0 after 32 steps
+        for (i, &v) in outputs.iter().enumerate().take(47).skip(16) {
+            let expected = (i - 15) as f32 / 32.0;
+            assert!((v - expected).abs() < 1e-6, "{i}: {v}");
+        }
+        assert!(outputs[47..].iter().all(|&v| v == 1.0));
+    }
+
+    #[test]
+    fn test_slew_separate_rise_and_fall() {
+        let step = Step::new(1.0, 0.0, 0.0);
+        let slewed = SlewLimit::with_rates(step, 100.0, 1.0);
+        assert_eq!(slewed.sample(-0.1), 1.0);
+        // Falling limited to 1 unit/s: 0.1 per 0.1 s step
+        assert!((slewed.sample(0.0) - 0.9).abs() < 1e-6);
+        assert!((slewed.sample(0.1) - 0.8).abs() < 1e-6);
+        assert_eq!((slewed.rise(), slewed.fall()), (100.0, 1.0));
+    }
+
+    #[test]
+    fn test_slew_slow_signal_passes_through() {
+        // 1 Hz sine has max slope 2*pi ~ 6.28 units/s
+        let sine = Sine::with_frequency(1.0);
+        let slewed = sine.slew(10.0);
+        for i in 0..1000 {
+            let t = i as f64 * 0.001;
+            assert!((slewed.sample(t) - sine.sample(t)).abs() < 1e-6, "t={t}");
+        }
+    }
+
+    #[test]
+    fn test_slew_reset_and_backwards_time() {
+        let slewed = Step::new(0.0, 1.0, 0.5).slew(1.0);
+        slewed.sample(0.0);
+        let mid = slewed.sample(0.75);
+        assert_eq!(mid, 0.75);
+        assert_eq!(slewed.sample(0.75), mid);
+
+        // Backwards time restarts from the input
+        assert_eq!(slewed.sample(0.625), 1.0);
+        slewed.reset();
+        assert_eq!(slewed.sample(0.0), 0.0);
+        // Clones start fresh
+        assert_eq!(slewed.clone().sample(0.7), 1.0);
+    }
+}
+
+// <FILE>src/processing/cls_slew_limit.rs</FILE> - <DESC>Rate-of-change limiter (portamento)</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -0,0 +1,125 @@
+// <FILE>src/processing/cls_start_at.rs</FILE> - <DESC>Start a signal at a trigger time</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Latching operators</WCTX>
+// <CLOG>Initial creation - StartAt outputs a constant until start_time, then the inner signal</CLOG>
+
+use crate::math::finite_or;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Outputs a constant until `start_time`, then the inner signal from local time 0.
+///
+/// The inverse of [`HoldAfter`](super::HoldAfter): for `t < start_time` the
+/// output is `before` (default 0.0); from `start_time` on it is
+/// inner(t - start_time). A negative or non-finite start time is treated as 0.0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::envelopes::Impact;
+/// use mixed_signals::processing::StartAt;
+/// use mixed_signals::traits::Signal;
+///
+/// let hit = Impact::new(1.0, 8.0);
+/// let late = StartAt::new(hit, 0.5);
+/// assert_eq!(late.sample(0.2), 0.0);
+/// assert_eq!(late.sample(0.5), hit.sample(0.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StartAt<S> {
+    pub inner: S,
+    /// Time in seconds at which the inner signal starts
+    pub start_time: f32,
+    /// Output before `start_time`
+    pub before: f32,
+}
+
+impl<S: Signal> StartAt<S> {
+    pub fn new(inner: S, start_time: f32) -> Self {
+        Self {
+            inner,
+            start_time: finite_or(start_time, 0.0).max(0.0),
+            before: 0.0,
+        }
+    }
+
+    /// Set the value output before `start_time`.
+    pub fn with_before(mut self, value: f32) -> Self {
+        self.before = finite_or(value, 0.0);
+        self
+    }
+
+    /// Local inner time, or `None` before the start.
+    fn local(&self, t: SignalTime) -> Option<SignalTime> {
+        let start = finite_or(self.start_time, 0.0).max(0.0) as SignalTime;
+        if t < start {
+            None
+        } else {
+            Some(t - start)
+        }
+    }
+}
+
+impl<S: Signal + Clone> Signal for StartAt<S> {
+    fn output_range(&self) -> SignalRange {
+        let inner = self.inner.output_range();
+        let before = finite_or(self.before, 0.0);
+        SignalRange::new(inner.min.min(before), inner.max.max(before))
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        match self.local(t) {
+            Some(local) => self.inner.sample(local),
+            None => finite_or(self.before, 0.0),
+        }
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        match self.local(t) {
+            Some(local) => self.inner.sample_with_context(local, ctx),
+            None => finite_or(self.before, 0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Ramp;
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_start_at_boundary() {
+        let ramp = Ramp::new(0.2, 1.0, 2.0);
+        let started = StartAt::new(ramp, 1.0);
+        assert_eq!(started.sample(0.999), 0.0);
+        assert_eq!(started.sample(1.0), ramp.sample(0.0));
+        assert_eq!(started.sample(2.0), ramp.sample(1.0));
+    }
+
+    #[test]
+    fn test_negative_start_time_sanitized() {
+        let ramp = Ramp::new(0.0, 1.0, 2.0);
+        let started = StartAt::new(ramp, -1.0);
+        assert_eq!(started.start_time, 0.0);
+        assert_eq!(started.sample(0.5), ramp.sample(0.5));
+        assert_eq!(started.sample(-0.5), 0.0);
+    }
+
+    #[test]
+    fn test_before_value_and_range() {
+        let started = StartAt::new(Ramp::new(0.0, 1.0, 1.0), 1.0).with_before(-0.5);
+        assert_eq!(started.sample(0.0), -0.5);
+        let range = started.output_range();
+        assert_eq!(range.min, -0.5);
+    }
+
+    #[test]
+    fn test_start_at_forwards_context() {
+        let started = StartAt::new(from_fn(|t| t as f32), 1.0);
+        let ctx = SignalContext::new(3, 9);
+        assert_eq!(started.sample_with_context(1.5, &ctx), 0.5);
+    }
+}
+
+// <FILE>src/processing/cls_start_at.rs</FILE> - <DESC>Start a signal at a trigger time</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
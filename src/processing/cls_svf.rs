@@ -1,31 +1,12 @@
 // <FILE>src/processing/cls_svf.rs</FILE> - <DESC>State Variable Filter with dynamic cutoff</DESC>
-// <VERS>VERSION: 1.0.1</VERS>
-// <WCTX>Clippy fixes</WCTX>
-// <CLOG>Use += operators instead of manual assign operations</CLOG>
+// <VERS>VERSION: 1.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Move SvfMode next to SvfState so it builds without std</CLOG>
 
-use crate::traits::{Signal, SignalContext, SignalTime};
-use std::f32::consts::PI;
+use super::{SvfMode, SvfState};
+use crate::traits::{Processor, Signal, SignalContext, SignalTime};
 use std::sync::Mutex;
 
-/// Output mode for the SVF.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum SvfMode {
-    /// Low-pass output
-    LowPass,
-    /// High-pass output
-    HighPass,
-    /// Band-pass output
-    BandPass,
-}
-
-/// SVF internal state
-#[derive(Debug, Clone, Copy, Default)]
-struct SvfState {
-    low: f32,
-    band: f32,
-    prev_time: f64,
-}
-
 /// State Variable Filter with dynamic cutoff frequency.
 ///
 /// The SVF is a classic analog-modeled filter that provides simultaneous
@@ -33,7 +14,8 @@ struct SvfState {
 /// the Chamberlin topology.
 ///
 /// Unlike `Biquad`, this filter accepts a signal for the cutoff frequency,
-/// enabling filter sweeps and modulation.
+/// enabling filter sweeps and modulation. For streaming use, drive an
+/// [`SvfState`] directly.
 ///
 /// # Example
 /// ```ignore
@@ -48,7 +30,7 @@ pub struct Svf<S, C> {
     q: f32,
     sample_rate: f32,
     mode: SvfMode,
-    state: Mutex<SvfState>,
+    state: Mutex<(SvfState, SignalTime)>,
 }
 
 impl<S: Signal, C: Signal> Svf<S, C> {
@@ -61,13 +43,14 @@ impl<S: Signal, C: Signal> Svf<S, C> {
     /// * `sample_rate` - Sample rate in Hz
     /// * `mode` - Output mode (LowPass, HighPass, BandPass)
     pub fn new(signal: S, cutoff: C, q: f32, sample_rate: f32, mode: SvfMode) -> Self {
+        let q = q.max(0.5);
         Self {
             signal,
             cutoff,
-            q: q.max(0.5),
+            q,
             sample_rate,
             mode,
-            state: Mutex::new(SvfState::default()),
+            state: Mutex::new((SvfState::new(mode, 20.0, q, sample_rate), 0.0)),
         }
     }
 
@@ -86,75 +69,58 @@ impl<S: Signal, C: Signal> Svf<S, C> {
         Self::new(signal, cutoff, q, sample_rate, SvfMode::BandPass)
     }
 
-    fn process_sample(&self, input: f32, cutoff_hz: f32, state: &mut SvfState) -> f32 {
-        // Chamberlin SVF
-        // f = 2 * sin(pi * fc / fs)
-        // For stability, clamp cutoff to Nyquist
-        let fc = cutoff_hz.clamp(20.0, self.sample_rate * 0.49);
-        let f = 2.0 * (PI * fc / self.sample_rate).sin();
-        let q_inv = 1.0 / self.q;
-
-        // Update state
-        state.low += f * state.band;
-        let high = input - state.low - q_inv * state.band;
-        state.band += f * high;
-
-        // Return selected output
-        match self.mode {
-            SvfMode::LowPass => state.low,
-            SvfMode::HighPass => high,
-            SvfMode::BandPass => state.band,
-        }
-    }
-}
-
-impl<S: Signal, C: Signal> Signal for Svf<S, C> {
-    fn sample(&self, t: SignalTime) -> f32 {
-        let input = self.signal.sample(t);
-        let cutoff_hz = self.cutoff.sample(t);
-
+    fn step(&self, t: SignalTime, input: f32, cutoff_hz: f32) -> f32 {
         let mut state = self.state.lock().unwrap();
+        let (filter, prev_time) = &mut *state;
 
-        if t > state.prev_time {
-            let output = self.process_sample(input, cutoff_hz, &mut state);
-            state.prev_time = t;
-            output
-        } else if t < state.prev_time {
+        if t > *prev_time {
+            *prev_time = t;
+            filter.set_cutoff(cutoff_hz);
+            filter.process(input)
+        } else if t < *prev_time {
             // Time went backwards - reset
-            *state = SvfState::default();
-            state.prev_time = t;
+            filter.reset();
+            *prev_time = t;
             input
         } else {
             // Same time
             match self.mode {
-                SvfMode::LowPass => state.low,
-                SvfMode::BandPass => state.band,
-                SvfMode::HighPass => input - state.low - state.band / self.q,
+                SvfMode::LowPass => filter.low(),
+                SvfMode::BandPass => filter.band(),
+                SvfMode::HighPass => input - filter.low() - filter.band() / self.q,
             }
         }
     }
+}
+
+/// Clones the configuration only; the clone starts with fresh filter state.
+impl<S: Clone, C: Clone> Clone for Svf<S, C> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            cutoff: self.cutoff.clone(),
+            q: self.q,
+            sample_rate: self.sample_rate,
+            mode: self.mode,
+            state: Mutex::new((
+                SvfState::new(self.mode, 20.0, self.q, self.sample_rate),
+                0.0,
+            )),
+        }
+    }
+}
+
+impl<S: Signal + Clone, C: Signal + Clone> Signal for Svf<S, C> {
+    fn sample(&self, t: SignalTime) -> f32 {
+        let input = self.signal.sample(t);
+        let cutoff_hz = self.cutoff.sample(t);
+        self.step(t, input, cutoff_hz)
+    }
 
     fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
         let input = self.signal.sample_with_context(t, ctx);
         let cutoff_hz = self.cutoff.sample_with_context(t, ctx);
-
-        let mut state = self.state.lock().unwrap();
-
-        if t > state.prev_time {
-            let output = self.process_sample(input, cutoff_hz, &mut state);
-            state.prev_time = t;
-            output
-        } else if t < state.prev_time {
-            *state = SvfState::default();
-            state.prev_time = t;
-            input
-        } else {
-            match self.mode {
-                SvfMode::LowPass => state.low,
-                SvfMode::BandPass => state.band,
-                SvfMode::HighPass => input - state.low - state.band / self.q,
-            }
-        }
+        self.step(t, input, cutoff_hz)
     }
 }
 
@@ -263,7 +229,19 @@ mod tests {
         let val = bpf.sample(0.01);
         assert!(val.is_finite());
     }
+
+    #[test]
+    fn test_svf_matches_streaming_state() {
+        let sig = Constant::new(0.5);
+        let svf = Svf::highpass(sig, Constant::new(750.0), 3.0, 48000.0);
+        let mut state = SvfState::highpass(750.0, 3.0, 48000.0);
+
+        for i in 1..256 {
+            let expected = state.process(0.5);
+            assert_eq!(svf.sample(i as f64 / 48000.0), expected);
+        }
+    }
 }
 
 // <FILE>src/processing/cls_svf.rs</FILE> - <DESC>State Variable Filter with dynamic cutoff</DESC>
-// <VERS>END OF VERSION: 1.0.1</VERS>
+// <VERS>END OF VERSION: 1.3.0</VERS>
@@ -0,0 +1,319 @@
+// <FILE>src/processing/cls_time_warp.rs</FILE> - <DESC>Variable-rate time warp driven by a rate signal</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>Bounded sampling cost</WCTX>
+// <CLOG>Cap resolution and integration horizon; extrapolate at the mean rate beyond it</CLOG>
+
+use crate::math::finite_or;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use std::sync::Mutex;
+
+/// Default integration steps per second.
+pub const DEFAULT_WARP_RESOLUTION: f32 = 1000.0;
+/// Highest supported integration steps per second.
+pub const MAX_WARP_RESOLUTION: f32 = 48_000.0;
+/// Integration steps in each direction before the clock is extrapolated.
+pub const MAX_WARP_STEPS: usize = 1 << 22;
+
+/// Integration steps between cached checkpoints.
+const CHECKPOINT_STEPS: usize = 1024;
+
+/// Cached running integrals at every `CHECKPOINT_STEPS` grid steps, one list
+/// per direction from t = 0. Entry 0 is always 0.0.
+#[derive(Debug)]
+struct WarpCache {
+    forward: Vec<f64>,
+    backward: Vec<f64>,
+}
+
+impl Default for WarpCache {
+    fn default() -> Self {
+        Self {
+            forward: vec![0.0],
+            backward: vec![0.0],
+        }
+    }
+}
+
+/// Plays a signal at a speed set by another signal.
+///
+/// Output = inner(W(t)), where `W(t) = ∫₀ᵗ rate(u) du` is the warped clock.
+/// A rate of 1.0 is normal speed, 2.0 double speed, 0.0 frozen, and a
+/// negative rate plays backwards. Unlike scaling `t`, changing the rate
+/// never makes the inner signal jump: a scanner that speeds up when an
+/// alert level rises just starts moving faster from where it is.
+///
+/// The integral uses the midpoint rule on a fixed grid of `resolution` steps
+/// per second, anchored at t = 0. A piecewise-constant rate (for example a
+/// [`Step`](crate::generators::Step), or [`Keyframes`](crate::generators::Keyframes)
+/// with [`Interpolation::Step`](crate::generators::Interpolation::Step))
+/// whose breakpoints fall on that grid integrates exactly, as does any
+/// constant or linear rate. Running totals are cached every 1024 steps, so
+/// a sample costs at most ~1024 rate evaluations after the first visit,
+/// regardless of sampling order. Because the grid is fixed, results are
+/// deterministic.
+///
+/// Integration stops at a horizon of [`MAX_WARP_STEPS`] steps (about 70
+/// minutes at the default resolution), which bounds the first-visit cost and
+/// the cache size. Beyond it the clock continues at the mean rate over the
+/// horizon, `W(t) = W(H) * t / H`: exact for a constant rate, and close for
+/// one that repeats or settles well within the horizon.
+///
+/// The rate is sampled without context (the cache can't depend on it);
+/// the inner signal receives the caller's context. Non-finite rate values
+/// count as 1.0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::{Ramp, Step};
+/// use mixed_signals::traits::{Signal, SignalExt};
+///
+/// // Normal speed for 2 s, then triple speed
+/// let clock = Ramp::new(0.0, 100.0, 100.0).time_warp(Step::new(1.0, 3.0, 2.0));
+/// assert!((clock.sample(2.0) - 2.0).abs() < 1e-4);
+/// assert!((clock.sample(3.0) - 5.0).abs() < 1e-4);
+/// ```
+#[derive(Debug)]
+pub struct TimeWarp<S, R> {
+    inner: S,
+    rate: R,
+    resolution: f32,
+    cache: Mutex<WarpCache>,
+}
+
+impl<S: Signal, R: Signal> TimeWarp<S, R> {
+    pub fn new(inner: S, rate: R) -> Self {
+        Self {
+            inner,
+            rate,
+            resolution: DEFAULT_WARP_RESOLUTION,
+            cache: Mutex::new(WarpCache::default()),
+        }
+    }
+
+    /// Set the integration steps per second (default 1000; clamped to
+    /// `1..=MAX_WARP_RESOLUTION`).
+    pub fn with_resolution(mut self, steps_per_second: f32) -> Self {
+        self.resolution = sanitize_resolution(steps_per_second);
+        self.cache = Mutex::new(WarpCache::default());
+        self
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn rate(&self) -> &R {
+        &self.rate
+    }
+
+    pub fn resolution(&self) -> f32 {
+        self.resolution
+    }
+
+    fn rate_at(&self, u: SignalTime) -> f64 {
+        finite_or(self.rate.sample(u), 1.0) as f64
+    }
+
+    /// Integral over grid step `j` in direction `dir`, measured along `dir`.
+    fn step_area(&self, j: usize, h: f64, dir: f64) -> f64 {
+        self.rate_at(dir * (j as f64 + 0.5) * h) * h
+    }
+
+    /// The warped clock W(t) fed to the inner signal.
+    pub fn warped_time(&self, t: SignalTime) -> SignalTime {
+        let t = if t.is_finite() { t } else { 0.0 };
+        let h = 1.0 / self.resolution as f64;
+        let dir = if t < 0.0 { -1.0 } else { 1.0 };
+        let distance = t.abs();
+        let horizon = MAX_WARP_STEPS as f64 * h;
+        if distance > horizon {
+            // Past the horizon, continue at the mean rate over it
+            return dir * self.integral(horizon, h, dir) * (distance / horizon);
+        }
+        dir * self.integral(distance, h, dir)
+    }
+
+    /// Integral of the rate over `[0, distance]` along `dir`, for
+    /// `distance` within the horizon.
+    fn integral(&self, distance: f64, h: f64, dir: f64) -> f64 {
+        let steps = ((distance / h).floor() as usize).min(MAX_WARP_STEPS);
+        let checkpoint = steps / CHECKPOINT_STEPS;
+
+        let mut acc = {
+            let mut cache = self.cache.lock().unwrap();
+            let totals = if dir > 0.0 {
+                &mut cache.forward
+            } else {
+                &mut cache.backward
+            };
+            while totals.len() <= checkpoint {
+                let start = (totals.len() - 1) * CHECKPOINT_STEPS;
+                let mut total = totals[totals.len() - 1];
+                for j in start..start + CHECKPOINT_STEPS {
+                    total += self.step_area(j, h, dir);
+                }
+                totals.push(total);
+            }
+            totals[checkpoint]
+        };
+
+        for j in checkpoint * CHECKPOINT_STEPS..steps {
+            acc += self.step_area(j, h, dir);
+        }
+        // Partial final step, midpoint rule over [steps * h, distance]
+        let tail_start = steps as f64 * h;
+        let tail = distance - tail_start;
+        if tail > 0.0 {
+            acc += self.rate_at(dir * (tail_start + 0.5 * tail)) * tail;
+        }
+        acc
+    }
+}
+
+fn sanitize_resolution(steps_per_second: f32) -> f32 {
+    if steps_per_second.is_finite() {
+        steps_per_second.clamp(1.0, MAX_WARP_RESOLUTION)
+    } else {
+        DEFAULT_WARP_RESOLUTION
+    }
+}
+
+/// Why a finite resolution would be clamped, if it would be.
+///
+/// `SignalSpec` builds reject such values instead of silently changing them.
+pub(crate) fn resolution_error(steps_per_second: f32) -> Option<String> {
+    (steps_per_second.is_finite() && !(1.0..=MAX_WARP_RESOLUTION).contains(&steps_per_second)).then(
+        || {
+            format!(
+                "resolution {} is outside 1..={}",
+                steps_per_second, MAX_WARP_RESOLUTION
+            )
+        },
+    )
+}
+
+/// Clones the configuration only; the clone starts with an empty cache.
+impl<S: Clone, R: Clone> Clone for TimeWarp<S, R> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            rate: self.rate.clone(),
+            resolution: self.resolution,
+            cache: Mutex::new(WarpCache::default()),
+        }
+    }
+}
+
+impl<S: Signal + Clone, R: Signal + Clone> Signal for TimeWarp<S, R> {
+    fn output_range(&self) -> SignalRange {
+        self.inner.output_range()
+    }
+
+    fn display_range(&self) -> (f32, f32) {
+        self.inner.display_range()
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.inner.sample(self.warped_time(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.inner.sample_with_context(self.warped_time(t), ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Ramp, Sine, Step};
+    use crate::traits::{from_fn, SignalExt};
+
+    #[test]
+    fn test_time_warp_constant_rate_scales_time() {
+        let sine = Sine::with_frequency(0.3);
+        let fast = sine.time_warp(Constant::new(2.0));
+        for i in 0..200 {
+            let t = i as f64 * 0.173;
+            assert!((fast.warped_time(t) - 2.0 * t).abs() < 1e-9, "t={t}");
+            assert!((fast.sample(t) - sine.sample(2.0 * t)).abs() < 1e-5);
+        }
+        // Negative time integrates backwards from 0
+        assert!((fast.warped_time(-1.5) + 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_time_warp_piecewise_rate_breakpoint() {
+        let clock = Ramp::new(0.0, 100.0, 100.0);
+        let warped = TimeWarp::new(clock, Step::new(0.5, 2.0, 4.0));
+        // Half speed until t = 4 (W = 2), then double speed
+        assert!((warped.warped_time(4.0) - 2.0).abs() < 1e-9);
+        assert!((warped.warped_time(6.0) - 6.0).abs() < 1e-9);
+        // Sampling order doesn't matter
+        let fresh = warped.clone();
+        assert_eq!(fresh.warped_time(6.0), warped.warped_time(6.0));
+        assert!((warped.sample(5.0) - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_time_warp_long_times_use_checkpoints() {
+        let warped = Ramp::new(0.0, 1.0, 1.0).time_warp(Constant::new(1.5));
+        let far = warped.warped_time(123.4567);
+        assert!((far - 1.5 * 123.4567).abs() < 1e-6);
+        let cached = warped.cache.lock().unwrap().forward.len();
+        assert_eq!(cached, 123_456 / CHECKPOINT_STEPS + 1);
+        // Earlier times reuse the cache and still match
+        assert!((warped.warped_time(10.0) - 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_time_warp_nan_rate_is_normal_speed() {
+        let sine = Sine::with_frequency(1.0);
+        let warped = sine
+            .time_warp(from_fn(|_| f32::NAN))
+            .with_resolution(f32::NAN);
+        assert_eq!(warped.resolution(), DEFAULT_WARP_RESOLUTION);
+        for i in 0..50 {
+            let t = i as f64 * 0.07;
+            let v = warped.sample(t);
+            assert!(v.is_finite());
+            assert!((v - sine.sample(t)).abs() < 1e-5);
+        }
+        assert!(warped.sample(f64::NAN).is_finite());
+    }
+
+    #[test]
+    fn test_time_warp_resolution_is_clamped() {
+        let warped = Ramp::new(0.0, 1.0, 1.0).time_warp(Constant::new(1.0));
+        assert_eq!(
+            warped.clone().with_resolution(1e9).resolution(),
+            MAX_WARP_RESOLUTION
+        );
+        assert_eq!(warped.with_resolution(0.0).resolution(), 1.0);
+        assert!(resolution_error(1e9).is_some());
+        assert!(resolution_error(0.5).is_some());
+        assert!(resolution_error(DEFAULT_WARP_RESOLUTION).is_none());
+        assert!(resolution_error(f32::NAN).is_none());
+    }
+
+    #[test]
+    fn test_time_warp_extrapolates_past_horizon() {
+        let sine = Sine::with_frequency(1.0);
+        let warped = sine.time_warp(Constant::new(1.0)).with_resolution(10_000.0);
+        let horizon = MAX_WARP_STEPS as f64 / 10_000.0;
+        assert!((warped.warped_time(1e6) - 1e6).abs() < 1e-3);
+        assert!((warped.warped_time(-1e6) + 1e6).abs() < 1e-3);
+        assert!(warped.sample(1e6).abs() < 1e-2);
+        // The clock stays continuous across the horizon
+        let (before, after) = (
+            warped.warped_time(horizon),
+            warped.warped_time(horizon + 1e-3),
+        );
+        assert!((after - before - 1e-3).abs() < 1e-9);
+        let cached = warped.cache.lock().unwrap().forward.len();
+        assert_eq!(cached, MAX_WARP_STEPS / CHECKPOINT_STEPS + 1);
+    }
+}
+
+// <FILE>src/processing/cls_time_warp.rs</FILE> - <DESC>Variable-rate time warp driven by a rate signal</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
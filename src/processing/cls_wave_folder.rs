@@ -0,0 +1,154 @@
+// <FILE>src/processing/cls_wave_folder.rs</FILE> - <DESC>Triangular wavefolder operator</DESC>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>Transparent below unity gain</WCTX>
+// <CLOG>Gain below 1 acts as 1 so low gain passes the signal through unchanged</CLOG>
+
+use crate::math::finite_or;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Reflect `x` back into [-1, 1] (triangular folding, period 4).
+fn fold(x: f32) -> f32 {
+    if (-1.0..=1.0).contains(&x) {
+        return x;
+    }
+    let y = (x + 1.0).rem_euclid(4.0);
+    if y <= 2.0 {
+        y - 1.0
+    } else {
+        3.0 - y
+    }
+}
+
+/// Wavefolder: amplifies a signal by `gain` and reflects it back into
+/// [-1, 1] whenever it crosses an edge.
+///
+/// Output = fold(gain * signal), where fold mirrors values past ±1 back
+/// inward (1.2 → 0.8, 3.5 → -0.5). Unlike clipping, the peaks turn over
+/// instead of flattening, adding bright odd harmonics that grow with gain.
+///
+/// A folder only adds drive: `gain` below 1 acts as 1, so with `gain <= 1`
+/// a bipolar input passes through unchanged. Non-finite gain falls back to
+/// 1.0; non-finite input samples output 0.0.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::processing::WaveFolder;
+/// use mixed_signals::traits::Signal;
+///
+/// let folded = WaveFolder::new(Sine::with_frequency(1.0), 2.0);
+/// // The sine peak (1.0 * 2.0 = 2.0) folds back to 0.0
+/// assert!(folded.sample(0.25).abs() < 1e-5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WaveFolder<S> {
+    pub signal: S,
+    /// Drive applied before folding
+    pub gain: f32,
+}
+
+impl<S: Signal> WaveFolder<S> {
+    pub fn new(signal: S, gain: f32) -> Self {
+        Self { signal, gain }
+    }
+
+    /// Gain actually applied: at least 1.0.
+    fn drive(&self) -> f32 {
+        finite_or(self.gain, 1.0).max(1.0)
+    }
+
+    fn process(&self, value: f32) -> f32 {
+        fold(finite_or(value, 0.0) * self.drive())
+    }
+}
+
+impl<S: Signal + Clone> Signal for WaveFolder<S> {
+    fn output_range(&self) -> SignalRange {
+        let r = self.signal.output_range();
+        let gain = self.drive();
+        let scaled = SignalRange::new(r.min * gain, r.max * gain);
+        if scaled.min >= -1.0 && scaled.max <= 1.0 {
+            scaled
+        } else {
+            SignalRange::BIPOLAR
+        }
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        self.process(self.signal.sample(t))
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        self.process(self.signal.sample_with_context(t, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Sine;
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_wave_folder_low_gain_transparent() {
+        let sine = Sine::with_frequency(3.0);
+        let unity = WaveFolder::new(sine, 1.0);
+        let half = WaveFolder::new(sine, 0.5);
+        let negative = WaveFolder::new(sine, -2.0);
+        for i in 0..100 {
+            let t = i as f64 * 0.0123;
+            assert_eq!(unity.sample(t), sine.sample(t));
+            assert_eq!(half.sample(t), sine.sample(t));
+            assert_eq!(negative.sample(t), sine.sample(t));
+        }
+        assert_eq!(half.output_range(), sine.output_range());
+    }
+
+    #[test]
+    fn test_wave_folder_reflects_past_edges() {
+        assert!((fold(1.2) - 0.8).abs() < 1e-6);
+        assert!((fold(-1.2) + 0.8).abs() < 1e-6);
+        assert!((fold(3.5) + 0.5).abs() < 1e-6);
+        assert!((fold(5.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_wave_folder_high_gain_bounded_with_more_turns() {
+        let sine = Sine::with_frequency(1.0);
+        // Count direction changes over one cycle: a plain sine turns twice
+        let turns = |gain: f32| {
+            let folder = WaveFolder::new(sine, gain);
+            let values: Vec<f32> = (0..2000)
+                .map(|i| folder.sample(i as f64 / 2000.0))
+                .collect();
+            for v in &values {
+                assert!((-1.0..=1.0).contains(v));
+            }
+            values
+                .windows(3)
+                .filter(|w| (w[1] - w[0]) * (w[2] - w[1]) < 0.0)
+                .count()
+        };
+        assert_eq!(turns(1.0), 2);
+        assert!(turns(3.0) > 2);
+        assert!(turns(6.0) > turns(3.0));
+        assert_eq!(
+            WaveFolder::new(sine, 4.0).output_range(),
+            SignalRange::BIPOLAR
+        );
+    }
+
+    #[test]
+    fn test_wave_folder_non_finite_inputs() {
+        let folder = WaveFolder::new(from_fn(|_| f32::NAN), 2.0);
+        assert_eq!(folder.sample(0.0), 0.0);
+        let folder = WaveFolder::new(Sine::with_frequency(1.0), f32::INFINITY);
+        assert!((folder.sample(0.25) - 1.0).abs() < 1e-6);
+    }
+}
+
+// <FILE>src/processing/cls_wave_folder.rs</FILE> - <DESC>Triangular wavefolder operator</DESC>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -0,0 +1,145 @@
+// <FILE>src/processing/fnc_find_peaks.rs</FILE> - <DESC>Local maxima search over a signal window</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Alloc imports</CLOG>
+
+use crate::traits::{Signal, SignalTime};
+use alloc::vec::Vec;
+
+/// Find local maxima of a signal over `[start, end]`.
+///
+/// Takes `samples` evenly spaced samples (both endpoints included) and
+/// returns `(time, value)` for every interior sample that is higher than both
+/// neighbours, in time order. Each peak is refined with a parabola through
+/// the three surrounding samples, so times are accurate to well below the
+/// sample spacing for smooth signals. A flat-topped peak is reported once, at
+/// the middle of its plateau.
+///
+/// The window endpoints are never reported (there is no neighbour to compare
+/// against), and non-finite samples break the search so no peak spans them.
+/// Returns an empty list for fewer than 3 samples, non-finite bounds, or
+/// `end <= start`.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::processing::find_peaks;
+///
+/// // A 1 Hz sine peaks at t = 0.25 + n
+/// let peaks = find_peaks(&Sine::with_frequency(1.0), 0.0, 3.0, 1000);
+/// assert_eq!(peaks.len(), 3);
+/// assert!((peaks[1].0 - 1.25).abs() < 1e-4);
+/// assert!((peaks[1].1 - 1.0).abs() < 1e-4);
+/// ```
+pub fn find_peaks(
+    signal: &dyn Signal,
+    start: SignalTime,
+    end: SignalTime,
+    samples: usize,
+) -> Vec<(SignalTime, f32)> {
+    if samples < 3 || !start.is_finite() || !end.is_finite() || end <= start {
+        return Vec::new();
+    }
+    let dt = (end - start) / (samples - 1) as SignalTime;
+    let values: Vec<f32> = (0..samples)
+        .map(|i| signal.sample(start + dt * i as SignalTime))
+        .collect();
+
+    let mut peaks = Vec::new();
+    let mut i = 1;
+    while i < samples - 1 {
+        let (prev, here) = (values[i - 1], values[i]);
+        if !(prev.is_finite() && here.is_finite() && here > prev) {
+            i += 1;
+            continue;
+        }
+        // Walk across any plateau to the first differing sample
+        let mut j = i + 1;
+        while j < samples && values[j] == here {
+            j += 1;
+        }
+        if j < samples && values[j].is_finite() && values[j] < here {
+            let peak = if j == i + 1 {
+                refine(start + dt * i as SignalTime, dt, prev, here, values[j])
+            } else {
+                let middle = (i + j - 1) as SignalTime / 2.0;
+                (start + dt * middle, here)
+            };
+            peaks.push(peak);
+        }
+        i = j;
+    }
+    peaks
+}
+
+/// Vertex of the parabola through three equally spaced samples centred on `t`.
+fn refine(t: SignalTime, dt: SignalTime, left: f32, mid: f32, right: f32) -> (SignalTime, f32) {
+    let (l, m, r) = (left as f64, mid as f64, right as f64);
+    let curvature = l - 2.0 * m + r;
+    if curvature >= 0.0 {
+        return (t, mid);
+    }
+    let offset = 0.5 * (l - r) / curvature;
+    let value = m - 0.25 * (l - r) * offset;
+    (t + offset * dt, value as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Sine;
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_find_peaks_sine_count_and_times() {
+        let sine = Sine::with_frequency(2.0);
+        let peaks = find_peaks(&sine, 0.0, 2.5, 500);
+        // Peaks at 0.125 + 0.5 * n
+        assert_eq!(peaks.len(), 5);
+        for (n, &(t, v)) in peaks.iter().enumerate() {
+            let expected = 0.125 + 0.5 * n as f64;
+            assert!((t - expected).abs() < 1e-4, "peak {} at {}", n, t);
+            assert!((v - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_find_peaks_plateau_reported_once() {
+        let shape = from_fn(|t| {
+            if (0.395..0.605).contains(&t) {
+                1.0
+            } else {
+                0.0
+            }
+        });
+        let peaks = find_peaks(&shape, 0.0, 1.0, 101);
+        assert_eq!(peaks.len(), 1);
+        assert!((peaks[0].0 - 0.5).abs() < 1e-9);
+        assert_eq!(peaks[0].1, 1.0);
+    }
+
+    #[test]
+    fn test_find_peaks_ignores_endpoints_and_monotonic() {
+        let rising = from_fn(|t| t as f32);
+        assert!(find_peaks(&rising, 0.0, 1.0, 100).is_empty());
+        // A sine starting at its peak doesn't report t = start
+        let cosine = Sine::new(1.0, 1.0, 0.0, 0.25);
+        let peaks = find_peaks(&cosine, 0.0, 1.5, 300);
+        assert_eq!(peaks.len(), 1);
+        assert!((peaks[0].0 - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_find_peaks_invalid_window() {
+        let sine = Sine::with_frequency(1.0);
+        assert!(find_peaks(&sine, 0.0, 1.0, 2).is_empty());
+        assert!(find_peaks(&sine, 1.0, 0.0, 100).is_empty());
+        assert!(find_peaks(&sine, 0.0, f64::NAN, 100).is_empty());
+        let nan = from_fn(|_| f32::NAN);
+        assert!(find_peaks(&nan, 0.0, 1.0, 100).is_empty());
+    }
+}
+
+// <FILE>src/processing/fnc_find_peaks.rs</FILE> - <DESC>Local maxima search over a signal window</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
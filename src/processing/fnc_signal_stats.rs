@@ -0,0 +1,99 @@
+// <FILE>src/processing/fnc_signal_stats.rs</FILE> - <DESC>Energy and mean of a signal over a time window</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>One set of window statistics</WCTX>
+// <CLOG>energy and mean delegate to analysis::SignalStats::measure_window</CLOG>
+
+use crate::analysis::SignalStats;
+use crate::traits::{Signal, SignalTime};
+
+/// Total energy of a signal over `[start, end)`: the sum of squared samples
+/// times the sample spacing (a midpoint approximation of ∫ x(t)² dt).
+///
+/// A unit-amplitude sine has energy ≈ `0.5 * duration` over whole periods.
+/// Non-finite samples contribute nothing. Returns 0.0 for an empty or invalid
+/// window (`samples == 0`, non-finite bounds, or `end <= start`). See
+/// [`SignalStats::measure_window`] for the full set of window statistics.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::processing::energy;
+///
+/// let e = energy(&Sine::with_frequency(2.0), 0.0, 3.0, 3000);
+/// assert!((e - 1.5).abs() < 0.01);
+/// ```
+pub fn energy(signal: &dyn Signal, start: SignalTime, end: SignalTime, samples: usize) -> f32 {
+    let dt = (end - start) / samples as SignalTime;
+    SignalStats::measure_window(signal, start, end, samples).energy(dt)
+}
+
+/// Mean (DC level) of a signal over `[start, end)`.
+///
+/// Averages `samples` evenly spaced samples, ignoring non-finite values.
+/// Returns 0.0 for an empty or invalid window or when no sample is finite.
+/// Same as the `mean` of [`SignalStats::measure_window`].
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::processing::mean;
+///
+/// let dc = mean(&Sine::new(1.0, 1.0, 0.3, 0.0), 0.0, 2.0, 1000);
+/// assert!((dc - 0.3).abs() < 1e-3);
+/// ```
+pub fn mean(signal: &dyn Signal, start: SignalTime, end: SignalTime, samples: usize) -> f32 {
+    SignalStats::measure_window(signal, start, end, samples).mean
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Sine};
+
+    #[test]
+    fn test_energy_unit_sine_whole_periods() {
+        let sine = Sine::with_frequency(3.0);
+        for duration in [1.0, 2.0, 5.0] {
+            let e = energy(&sine, 0.0, duration, 4096);
+            assert!(
+                (e - 0.5 * duration as f32).abs() < 1e-3,
+                "duration {duration}: energy {e}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mean_unit_sine_near_zero() {
+        let sine = Sine::with_frequency(3.0);
+        assert!(mean(&sine, 0.0, 2.0, 4096).abs() < 1e-4);
+        assert!(mean(&sine, 0.5, 1.5, 4096).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_energy_and_mean_constant() {
+        let c = Constant::new(0.5);
+        assert!((energy(&c, 0.0, 4.0, 100) - 1.0).abs() < 1e-5);
+        assert!((mean(&c, 0.0, 4.0, 100) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_energy_and_mean_invalid_window() {
+        let c = Constant::new(1.0);
+        assert_eq!(energy(&c, 0.0, 1.0, 0), 0.0);
+        assert_eq!(energy(&c, 1.0, 1.0, 10), 0.0);
+        assert_eq!(mean(&c, 2.0, 1.0, 10), 0.0);
+        assert_eq!(mean(&c, f64::NAN, 1.0, 10), 0.0);
+    }
+
+    #[test]
+    fn test_energy_and_mean_skip_non_finite() {
+        let nan = Constant::new(f32::NAN);
+        assert_eq!(energy(&nan, 0.0, 1.0, 10), 0.0);
+        assert_eq!(mean(&nan, 0.0, 1.0, 10), 0.0);
+    }
+}
+
+// <FILE>src/processing/fnc_signal_stats.rs</FILE> - <DESC>Energy and mean of a signal over a time window</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
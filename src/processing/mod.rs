@@ -1,31 +1,120 @@
 // <FILE>mixed-signals/src/processing/mod.rs</FILE> - <DESC>Signal processing operators module</DESC>
-// <VERS>VERSION: 1.4.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Added Normalized wrapper for converting any signal to 0..1 range</CLOG>
+// <VERS>VERSION: 1.29.0</VERS>
+// <WCTX>One blend operator</WCTX>
+// <CLOG>DryWet is an alias of composition::Mix</CLOG>
 
 mod cls_abs;
+mod cls_bezier_ease;
+#[cfg(feature = "std")]
 mod cls_biquad;
+mod cls_biquad_state;
 mod cls_clamp;
 mod cls_clipper;
+mod cls_eased;
+mod cls_envelope_follower;
+mod cls_envelope_follower_state;
+mod cls_fade_edges;
+#[cfg(feature = "std")]
+mod cls_fir;
+#[cfg(feature = "std")]
+mod cls_gate;
+mod cls_hold_after;
+#[cfg(feature = "std")]
+mod cls_iir;
 mod cls_invert;
+mod cls_loop;
+mod cls_loop_blend;
+#[cfg(feature = "std")]
 mod cls_lowpass;
+#[cfg(feature = "std")]
+mod cls_moving_average;
 mod cls_normalized;
+mod cls_normalized_from_samples;
+mod cls_one_pole_state;
 mod cls_quantize;
+mod cls_quantize_to_set;
 mod cls_remap;
+mod cls_signal_sampler;
+#[cfg(feature = "std")]
+mod cls_slew_limit;
+mod cls_start_at;
+#[cfg(feature = "std")]
 mod cls_svf;
+mod cls_svf_state;
+#[cfg(feature = "std")]
+mod cls_time_warp;
+mod cls_wave_folder;
 mod fnc_bipolar_helpers;
+mod fnc_detect_onsets;
+mod fnc_find_peaks;
+mod fnc_signal_stats;
 
 pub use cls_abs::Abs;
-pub use cls_biquad::{Biquad, BiquadMode};
+pub use cls_bezier_ease::BezierEase;
+#[cfg(feature = "std")]
+pub use cls_biquad::Biquad;
+pub use cls_biquad_state::{BiquadMode, BiquadState};
 pub use cls_clamp::Clamp;
 pub use cls_clipper::{ClipMode, Clipper};
+pub use cls_eased::Eased;
+pub use cls_envelope_follower::{EnvelopeFollower, MAX_LOOKBACK_SAMPLES};
+pub use cls_envelope_follower_state::EnvelopeFollowerState;
+pub use cls_fade_edges::{FadeCurve, FadeEdges};
+#[cfg(feature = "std")]
+pub use cls_fir::Fir;
+#[cfg(feature = "std")]
+pub use cls_gate::Gate;
+pub use cls_hold_after::HoldAfter;
+#[cfg(feature = "std")]
+pub use cls_iir::Iir;
 pub use cls_invert::Invert;
+pub use cls_loop::Loop;
+pub use cls_loop_blend::LoopBlend;
+#[cfg(feature = "std")]
 pub use cls_lowpass::LowPass;
+#[cfg(feature = "serde")]
+pub(crate) use cls_moving_average::window_error as moving_average_window_error;
+#[cfg(feature = "std")]
+pub use cls_moving_average::{MovingAverage, MAX_WINDOW_SAMPLES};
 pub use cls_normalized::Normalized;
+pub use cls_normalized_from_samples::NormalizedFromSamples;
+pub use cls_one_pole_state::OnePoleState;
 pub use cls_quantize::Quantize;
+pub use cls_quantize_to_set::QuantizeToSet;
 pub use cls_remap::Remap;
-pub use cls_svf::{Svf, SvfFixed, SvfMode};
+pub use cls_signal_sampler::SignalSampler;
+#[cfg(feature = "std")]
+pub use cls_slew_limit::SlewLimit;
+pub use cls_start_at::StartAt;
+#[cfg(feature = "std")]
+pub use cls_svf::{Svf, SvfFixed};
+pub use cls_svf_state::{SvfMode, SvfState};
+#[cfg(feature = "serde")]
+pub(crate) use cls_time_warp::resolution_error as time_warp_resolution_error;
+#[cfg(feature = "std")]
+pub use cls_time_warp::{TimeWarp, DEFAULT_WARP_RESOLUTION, MAX_WARP_RESOLUTION, MAX_WARP_STEPS};
+pub use cls_wave_folder::WaveFolder;
 pub use fnc_bipolar_helpers::{bipolar_to_unipolar, remap_range, unipolar_to_bipolar};
+pub use fnc_detect_onsets::detect_onsets;
+pub use fnc_find_peaks::find_peaks;
+pub use fnc_signal_stats::{energy, mean};
+
+/// Blends an unprocessed (dry) signal with a processed (wet) one.
+///
+/// Same operator as [`Mix`](crate::composition::Mix), named for the usual
+/// "amount" control on a processor: 0 is fully dry, 1 fully wet.
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::processing::{DryWet, WaveFolder};
+/// use mixed_signals::traits::Signal;
+///
+/// let dry = Sine::with_frequency(2.0);
+/// let wet = WaveFolder::new(dry, 3.0);
+/// let half_folded = DryWet::new(dry, wet, 0.3);
+/// let _ = half_folded.sample(0.1);
+/// ```
+pub type DryWet<D, W> = crate::composition::Mix<D, W>;
 
 // <FILE>mixed-signals/src/processing/mod.rs</FILE> - <DESC>Signal processing operators module</DESC>
-// <VERS>END OF VERSION: 1.4.0</VERS>
+// <VERS>END OF VERSION: 1.29.0</VERS>
@@ -1,7 +1,7 @@
 // <FILE>src/random/cls_correlated_noise.rs</FILE> - <DESC>Temporally smooth correlated noise using stateless RNG-based approach</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Noise helpers refactor</WCTX>
-// <CLOG>Use core::noise_helpers for bipolar_range, u64_to_bipolar; keep ChaCha8 for quality</CLOG>
+// <VERS>VERSION: 2.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::core::{bipolar_range, u64_to_bipolar};
 use crate::math::{derive_seed, finite_or, finite_or_f64};
@@ -9,6 +9,7 @@ use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 use rand::RngCore;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Correlated noise generator producing temporally smooth random drift.
@@ -16,7 +17,8 @@ use serde::{Deserialize, Serialize};
 /// Creates smooth random changes over time (Brownian motion / random walk).
 /// Uses stateless ChaCha8Rng-based approach with frame lookback window for determinism.
 /// Higher correlation (0.0-1.0) produces slower, smoother changes.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CorrelatedNoise {
     /// Seed for reproducible randomness
     seed: u64,
@@ -284,4 +286,4 @@ mod tests {
 }
 
 // <FILE>src/random/cls_correlated_noise.rs</FILE> - <DESC>Temporally smooth correlated noise using stateless RNG-based approach</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.2.0</VERS>
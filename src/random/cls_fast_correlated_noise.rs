@@ -1,11 +1,13 @@
 // <FILE>src/random/cls_fast_correlated_noise.rs</FILE> - <DESC>Fast temporally correlated noise using hash-based RNG</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Noise helpers refactor</WCTX>
-// <CLOG>Use core::noise_helpers for bipolar_range, ema_smoothing, scale_bipolar</CLOG>
+// <VERS>VERSION: 2.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature, alloc imports</CLOG>
 
 use crate::core::{bipolar_range, ema_smoothing, scale_bipolar};
 use crate::math::fast_random;
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+use alloc::string::String;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Fast correlated noise using hash-based RNG.
@@ -13,7 +15,8 @@ use serde::{Deserialize, Serialize};
 /// ~25x faster than `CorrelatedNoise` per lookback frame by using SplitMix64.
 /// With 10 lookback frames, this is ~250x faster overall.
 /// Suitable for animation/visualization where cryptographic quality isn't needed.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FastCorrelatedNoise {
     seed: u64,
     correlation: f32,
@@ -155,4 +158,4 @@ mod tests {
 }
 
 // <FILE>src/random/cls_fast_correlated_noise.rs</FILE> - <DESC>Fast temporally correlated noise using hash-based RNG</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.2.0</VERS>
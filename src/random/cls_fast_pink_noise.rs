@@ -1,11 +1,13 @@
 // <FILE>src/random/cls_fast_pink_noise.rs</FILE> - <DESC>Fast 1/f noise using hash-based RNG</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Noise helpers refactor</WCTX>
-// <CLOG>Use core::noise_helpers for bipolar_range, octave_sum, scale_bipolar</CLOG>
+// <VERS>VERSION: 2.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
-use crate::core::{bipolar_range, octave_sum, scale_bipolar};
+use super::MAX_PINK_ROWS;
+use crate::core::{bipolar_range, octave_sum, scale_bipolar, voss_sum};
 use crate::math::{fast_random, finite_or_f64};
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Fast pink noise (1/f noise) using hash-based RNG.
@@ -13,11 +15,21 @@ use serde::{Deserialize, Serialize};
 /// ~25x faster than `PinkNoise` per octave by using SplitMix64 mixing.
 /// With 5 octaves, this is ~125x faster overall.
 /// Suitable for animation/visualization where cryptographic quality isn't needed.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+///
+/// [`FastPinkNoise::with_rows`] switches to equally weighted Voss-McCartney
+/// rows, as [`PinkNoise::with_rows`](super::PinkNoise::with_rows) does.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FastPinkNoise {
     seed: u64,
     amplitude: f32,
     offset: f32,
+    /// Octave row count (None = classic five weighted rows)
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    rows: Option<u8>,
 }
 
 impl FastPinkNoise {
@@ -26,6 +38,7 @@ impl FastPinkNoise {
             seed,
             amplitude,
             offset,
+            rows: None,
         }
     }
 
@@ -33,6 +46,12 @@ impl FastPinkNoise {
         Self::new(seed, 1.0, 0.0)
     }
 
+    /// Sum `rows` equally weighted octave rows (clamped to `1..=32`).
+    pub fn with_rows(mut self, rows: usize) -> Self {
+        self.rows = Some(rows.clamp(1, MAX_PINK_ROWS) as u8);
+        self
+    }
+
     pub fn seed(&self) -> u64 {
         self.seed
     }
@@ -44,6 +63,22 @@ impl FastPinkNoise {
     pub fn offset(&self) -> f32 {
         self.offset
     }
+
+    /// Number of octave rows summed per sample.
+    pub fn rows(&self) -> usize {
+        self.rows
+            .map_or(5, |rows| (rows as usize).clamp(1, MAX_PINK_ROWS))
+    }
+
+    fn sample_frame(&self, seed: u64, frame: u64) -> f32 {
+        // fast_random returns [0, 1], convert to bipolar [-1, 1]
+        let row = |row_seed, row_frame| fast_random(row_seed, row_frame) * 2.0 - 1.0;
+        let bipolar = match self.rows {
+            None => octave_sum(seed, frame, 5, row),
+            Some(_) => voss_sum(seed, frame, self.rows(), row),
+        };
+        scale_bipolar(bipolar as f64, self.amplitude, self.offset)
+    }
 }
 
 impl Default for FastPinkNoise {
@@ -52,6 +87,7 @@ impl Default for FastPinkNoise {
             seed: 0,
             amplitude: 1.0,
             offset: 0.0,
+            rows: None,
         }
     }
 }
@@ -63,20 +99,12 @@ impl Signal for FastPinkNoise {
 
     fn sample(&self, t: SignalTime) -> f32 {
         let frame = (finite_or_f64(t, 0.0) * 60.0) as u64;
-        let bipolar = octave_sum(self.seed, frame, 5, |octave_seed, octave_frame| {
-            // fast_random returns [0, 1], convert to bipolar [-1, 1]
-            fast_random(octave_seed, octave_frame) * 2.0 - 1.0
-        });
-        scale_bipolar(bipolar as f64, self.amplitude, self.offset)
+        self.sample_frame(self.seed, frame)
     }
 
     fn sample_with_context(&self, _t: SignalTime, ctx: &SignalContext) -> f32 {
         let effective_seed = self.seed.wrapping_add(ctx.seed);
-        let bipolar = octave_sum(effective_seed, ctx.frame, 5, |octave_seed, octave_frame| {
-            // fast_random returns [0, 1], convert to bipolar [-1, 1]
-            fast_random(octave_seed, octave_frame) * 2.0 - 1.0
-        });
-        scale_bipolar(bipolar as f64, self.amplitude, self.offset)
+        self.sample_frame(effective_seed, ctx.frame)
     }
 }
 
@@ -154,7 +182,21 @@ mod tests {
         let mean = sum / 1000.0;
         assert!(mean.abs() < 0.2, "Mean {} should be near 0.0", mean);
     }
+
+    #[test]
+    fn test_fast_pink_noise_rows_bounded() {
+        assert_eq!(FastPinkNoise::default().rows(), 5);
+        assert_eq!(FastPinkNoise::default().with_rows(0).rows(), 1);
+        assert_eq!(FastPinkNoise::default().with_rows(99).rows(), MAX_PINK_ROWS);
+        for rows in [1, 8, 16, 32] {
+            let noise = FastPinkNoise::new(3, 0.5, 0.0).with_rows(rows);
+            for frame in 0..500 {
+                let v = noise.sample_with_context(0.0, &SignalContext::new(frame, 0));
+                assert!((-0.5..=0.5).contains(&v), "rows={rows}: {v}");
+            }
+        }
+    }
 }
 
 // <FILE>src/random/cls_fast_pink_noise.rs</FILE> - <DESC>Fast 1/f noise using hash-based RNG</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.3.0</VERS>
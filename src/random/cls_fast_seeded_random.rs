@@ -1,10 +1,11 @@
 // <FILE>src/random/cls_fast_seeded_random.rs</FILE> - <DESC>Fast seeded random using hash-based RNG</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>RNG performance optimization</WCTX>
-// <CLOG>Initial creation - ~25x faster than ChaCha8Rng variant</CLOG>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::math::{fast_random, finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalContext, SignalTime};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Fast seeded random value generator using hash-based RNG.
@@ -13,7 +14,8 @@ use serde::{Deserialize, Serialize};
 /// Suitable for animation/visualization where cryptographic quality isn't needed.
 ///
 /// Output is deterministic: same seed + time = same value.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FastSeededRandom {
     seed: u64,
     amplitude: f32,
@@ -121,4 +123,4 @@ mod tests {
 }
 
 // <FILE>src/random/cls_fast_seeded_random.rs</FILE> - <DESC>Fast seeded random using hash-based RNG</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -0,0 +1,242 @@
+// <FILE>src/random/cls_fast_white_noise.rs</FILE> - <DESC>Fast white noise with SIMD batch sampling</DESC>
+// <VERS>VERSION: 1.1.1</VERS>
+// <WCTX>Batch sampling</WCTX>
+// <CLOG>Batch override moved to sample_batch_from</CLOG>
+
+use crate::math::{
+    fast_random, fast_random_batch, fast_random_batch_scalar, finite_or, finite_or_f64,
+    finite_or_min,
+};
+use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Batches that would hash more than this many draws per output sample
+/// (heavy undersampling) fall back to per-sample hashing.
+const MAX_DRAWS_PER_SAMPLE: u64 = 2;
+
+/// Fast white noise using hash-based RNG.
+///
+/// The SplitMix64 counterpart of [`WhiteNoise`](crate::noise::WhiteNoise):
+/// one uniform draw per `1 / sample_rate` seconds, bipolar
+/// [-amplitude, +amplitude] around offset.
+///
+/// [`sample_batch`](Signal::sample_batch) hashes each block of draws with
+/// [`fast_random_batch`], which uses AVX2 when the CPU reports it, instead of
+/// one call per output sample. Output is bit-identical to per-sample
+/// [`sample`](Signal::sample) on every path; see the `fast_white_noise`
+/// benches for the speedup.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::random::FastWhiteNoise;
+/// use mixed_signals::traits::Signal;
+///
+/// let noise = FastWhiteNoise::new(42, 1.0, 48_000.0);
+/// let mut buffer = [0.0f32; 256];
+/// noise.sample_batch(0.0, 1.0 / 48_000.0, &mut buffer);
+/// assert_eq!(buffer[10], noise.sample(10.0 / 48_000.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FastWhiteNoise {
+    seed: u64,
+    amplitude: f32,
+    offset: f32,
+    sample_rate: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scalar_only: bool,
+}
+
+impl FastWhiteNoise {
+    pub fn new(seed: u64, amplitude: f32, sample_rate: f32) -> Self {
+        Self::with_offset(seed, amplitude, 0.0, sample_rate)
+    }
+
+    /// Create with full parameters including offset.
+    pub fn with_offset(seed: u64, amplitude: f32, offset: f32, sample_rate: f32) -> Self {
+        Self {
+            seed,
+            amplitude,
+            offset,
+            sample_rate: sample_rate.max(1.0),
+            scalar_only: false,
+        }
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self::new(seed, 1.0, 60.0)
+    }
+
+    /// Force the scalar batch path (for testing and benchmarking).
+    #[doc(hidden)]
+    pub fn scalar_only(mut self) -> Self {
+        self.scalar_only = true;
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn amplitude(&self) -> f32 {
+        self.amplitude
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Sanitized (amplitude, offset, sample_rate).
+    fn params(&self) -> (f32, f32, f64) {
+        (
+            finite_or(self.amplitude, 1.0),
+            finite_or(self.offset, 0.0),
+            finite_or_min(self.sample_rate, 1.0, 60.0) as f64,
+        )
+    }
+
+    fn draw_index(t: SignalTime, sample_rate: f64) -> u64 {
+        (finite_or_f64(t, 0.0) * sample_rate) as u64
+    }
+}
+
+impl Default for FastWhiteNoise {
+    fn default() -> Self {
+        Self::with_seed(0)
+    }
+}
+
+impl Signal for FastWhiteNoise {
+    fn output_range(&self) -> SignalRange {
+        let (amplitude, offset, _) = self.params();
+        SignalRange::new(offset - amplitude, offset + amplitude)
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        let (amplitude, offset, sample_rate) = self.params();
+        let value = fast_random(self.seed, Self::draw_index(t, sample_rate));
+        offset + amplitude * (value * 2.0 - 1.0)
+    }
+
+    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+        let (amplitude, offset, sample_rate) = self.params();
+        let index = Self::draw_index(t, sample_rate).wrapping_add(ctx.frame);
+        let value = fast_random(self.seed.wrapping_add(ctx.seed), index);
+        offset + amplitude * (value * 2.0 - 1.0)
+    }
+
+    fn sample_batch_from(
+        &self,
+        t_start: SignalTime,
+        dt: SignalTime,
+        first: usize,
+        out: &mut [f32],
+    ) {
+        let (amplitude, offset, sample_rate) = self.params();
+        let Some(last) = out.len().checked_sub(1) else {
+            return;
+        };
+        let time_at = |i: usize| t_start + (first + i) as SignalTime * dt;
+        let index_at = |i: usize| Self::draw_index(time_at(i), sample_rate);
+        let (first_index, last_index) = (index_at(0), index_at(last));
+        // Draw indices only rise with i for finite, non-negative steps
+        let dense = t_start.is_finite()
+            && dt.is_finite()
+            && dt >= 0.0
+            && last_index - first_index < out.len() as u64 * MAX_DRAWS_PER_SAMPLE;
+        if !dense {
+            for (i, value) in out.iter_mut().enumerate() {
+                *value = self.sample(time_at(i));
+            }
+            return;
+        }
+
+        let mut draws = vec![0.0f32; (last_index - first_index + 1) as usize];
+        if self.scalar_only {
+            fast_random_batch_scalar(self.seed, first_index, &mut draws);
+        } else {
+            fast_random_batch(self.seed, first_index, &mut draws);
+        }
+        for (i, value) in out.iter_mut().enumerate() {
+            let draw = draws[(index_at(i) - first_index) as usize];
+            *value = offset + amplitude * (draw * 2.0 - 1.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_bits_eq(a: &[f32], b: &[f32]) {
+        assert_eq!(a.len(), b.len());
+        for (i, (x, y)) in a.iter().zip(b).enumerate() {
+            assert_eq!(x.to_bits(), y.to_bits(), "index {i}: {x} vs {y}");
+        }
+    }
+
+    fn per_sample(noise: &FastWhiteNoise, t_start: f64, dt: f64, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| noise.sample(t_start + i as f64 * dt))
+            .collect()
+    }
+
+    #[test]
+    fn test_fast_white_noise_batch_paths_match_sample() {
+        let noise = FastWhiteNoise::with_offset(42, 0.8, 0.1, 48_000.0);
+        // Rate-matched, oversampled, mildly undersampled, odd lengths
+        for (t_start, dt, len) in [
+            (0.0, 1.0 / 48_000.0, 1000),
+            (0.37, 1.0 / 192_000.0, 333),
+            (2.5, 1.5 / 48_000.0, 17),
+        ] {
+            let expected = per_sample(&noise, t_start, dt, len);
+            let mut simd = vec![0.0; len];
+            noise.sample_batch(t_start, dt, &mut simd);
+            let mut scalar = vec![0.0; len];
+            noise.scalar_only().sample_batch(t_start, dt, &mut scalar);
+            assert_bits_eq(&simd, &expected);
+            assert_bits_eq(&scalar, &expected);
+        }
+    }
+
+    #[test]
+    fn test_fast_white_noise_sparse_and_invalid_batches_fall_back() {
+        let noise = FastWhiteNoise::new(7, 1.0, 1000.0);
+        for (t_start, dt) in [(0.0, 1.0), (1.0, -0.001), (f64::NAN, 0.001)] {
+            let mut out = [0.0; 8];
+            noise.sample_batch(t_start, dt, &mut out);
+            assert_bits_eq(&out, &per_sample(&noise, t_start, dt, 8));
+        }
+        noise.sample_batch(0.0, 0.001, &mut []);
+    }
+
+    #[test]
+    fn test_fast_white_noise_range_and_determinism() {
+        let noise = FastWhiteNoise::with_offset(3, 0.5, 0.5, 100.0);
+        assert_eq!(noise.output_range(), SignalRange::new(0.0, 1.0));
+        let mut out = [0.0; 500];
+        noise.sample_batch(0.0, 0.01, &mut out);
+        assert!(out.iter().all(|v| (0.0..=1.0).contains(v)));
+        assert_eq!(noise.sample(1.234), noise.sample(1.234));
+        assert_ne!(noise.sample(0.0), noise.sample(0.01));
+    }
+
+    #[test]
+    fn test_fast_white_noise_context() {
+        let noise = FastWhiteNoise::with_seed(11);
+        let ctx = SignalContext::new(0, 0);
+        assert_eq!(noise.sample_with_context(0.5, &ctx), noise.sample(0.5));
+        let ctx = SignalContext::new(5, 99);
+        assert_ne!(noise.sample_with_context(0.5, &ctx), noise.sample(0.5));
+    }
+}
+
+// <FILE>src/random/cls_fast_white_noise.rs</FILE> - <DESC>Fast white noise with SIMD batch sampling</DESC>
+// <VERS>END OF VERSION: 1.1.1</VERS>
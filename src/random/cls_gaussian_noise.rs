@@ -1,12 +1,13 @@
 // <FILE>src/random/cls_gaussian_noise.rs</FILE> - <DESC>Gaussian (normal) distribution noise generator</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Noise helpers refactor</WCTX>
-// <CLOG>Use core::noise_helpers for bipolar_range, rng_from_time, rng_from_context</CLOG>
+// <VERS>VERSION: 2.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::core::{bipolar_range, rng_from_context, rng_from_time};
 use crate::math::finite_or;
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 use rand_distr::{Distribution, Normal};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Gaussian (normal) distribution noise generator.
@@ -16,7 +17,8 @@ use serde::{Deserialize, Serialize};
 /// with decreasing probability further away.
 ///
 /// Output is bipolar [-amplitude, +amplitude] centered at offset.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GaussianNoise {
     /// Seed for reproducible randomness
     seed: u64,
@@ -103,6 +105,15 @@ impl Signal for GaussianNoise {
         bipolar_range(self.amplitude, self.offset)
     }
 
+    /// Values beyond 2 sigma are rare, so display the central ~95% window
+    /// (two thirds of the 3-sigma output range).
+    fn display_range(&self) -> (f32, f32) {
+        let range = self.output_range();
+        let center = (range.min + range.max) * 0.5;
+        let half = (range.max - range.min) * 0.5 * (2.0 / 3.0);
+        (center - half, center + half)
+    }
+
     fn sample(&self, t: SignalTime) -> f32 {
         let std_dev = finite_or(self.std_dev, 1.0);
         let amplitude = finite_or(self.amplitude, 1.0);
@@ -292,7 +303,17 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_gaussian_noise_display_range_narrower() {
+        let noise = GaussianNoise::new(42, 1.0, 1.0, 0.25).unwrap();
+        let range = noise.output_range();
+        let (min, max) = noise.display_range();
+        assert!(min > range.min && max < range.max);
+        assert!(((min + max) * 0.5 - 0.25).abs() < 1e-6);
+        assert!((max - min - 4.0 / 3.0).abs() < 1e-6);
+    }
 }
 
 // <FILE>mixed-signals/src/random/cls_gaussian_noise.rs</FILE> - <DESC>Gaussian (normal) distribution noise generator</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.3.0</VERS>
@@ -1,7 +1,7 @@
 // <FILE>src/random/cls_impulse_noise.rs</FILE> - <DESC>Impulse/shot noise generator</DESC>
-// <VERS>VERSION: 2.1.1</VERS>
-// <WCTX>Clippy fixes</WCTX>
-// <CLOG>Use clamp() instead of max().min() pattern</CLOG>
+// <VERS>VERSION: 2.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::core::bipolar_range;
 use crate::math::{derive_seed, finite_or, finite_or_f64};
@@ -9,6 +9,7 @@ use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 use rand_distr::{Distribution, Exp};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Impulse/shot noise generator.
@@ -41,7 +42,8 @@ use serde::{Deserialize, Serialize};
 /// let v = impulses.sample(0.0);
 /// assert!(v == -1.0 || v == 1.0);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ImpulseNoise {
     /// Average number of impulses per second
     rate_hz: f32,
@@ -378,4 +380,4 @@ mod tests {
 }
 
 // <FILE>src/random/cls_impulse_noise.rs</FILE> - <DESC>Impulse/shot noise generator</DESC>
-// <VERS>END OF VERSION: 2.1.1</VERS>
+// <VERS>END OF VERSION: 2.2.0</VERS>
@@ -1,7 +1,7 @@
 // <FILE>src/random/cls_per_character_noise.rs</FILE> - <DESC>Per-character deterministic noise using character index</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Noise helpers refactor</WCTX>
-// <CLOG>Use core::noise_helpers for bipolar_range, rng_from_time, u64_to_bipolar, scale_bipolar</CLOG>
+// <VERS>VERSION: 2.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::core::{bipolar_range, scale_bipolar, u64_to_bipolar};
 use crate::math::derive_seed;
@@ -9,6 +9,7 @@ use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 use rand::RngCore;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Per-character noise generator producing deterministic variance based on character index.
@@ -17,8 +18,11 @@ use serde::{Deserialize, Serialize};
 /// Same character index always produces the same value, regardless of time or frame.
 /// Useful for typewriter speed variance, scramble timing per character, etc.
 ///
-/// **Requires:** `char_index` field in `SignalContext`.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// **Requires:** `char_index` field in `SignalContext`. When the context also
+/// carries a cell `position`, it is mixed into the seed, so the same
+/// character index at different cells gets different (but stable) values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PerCharacterNoise {
     /// Base seed for reproducible randomness
     base_seed: u64,
@@ -81,7 +85,11 @@ impl Signal for PerCharacterNoise {
     fn sample_with_context(&self, _t: SignalTime, ctx: &SignalContext) -> f32 {
         // Use char_index from context if available, otherwise fallback to frame
         let char_index = ctx.char_index.unwrap_or(ctx.frame as usize) as u64;
-        let effective_seed = self.base_seed.wrapping_add(ctx.seed);
+        let mut effective_seed = self.base_seed.wrapping_add(ctx.seed);
+        if let Some((x, y)) = ctx.position {
+            let cell = ((x as u64) << 16 | y as u64).wrapping_add(1);
+            effective_seed ^= cell.wrapping_mul(0x9e3779b97f4a7c15).rotate_left(29);
+        }
         let seed_bytes = derive_seed(effective_seed, char_index);
         let mut rng = ChaCha8Rng::from_seed(seed_bytes);
         let bipolar = u64_to_bipolar(rng.next_u64());
@@ -215,7 +223,38 @@ mod tests {
             assert!(v.is_finite(), "Value must be finite, got {}", v);
         }
     }
+
+    #[test]
+    fn test_per_character_noise_position_varies_per_cell() {
+        let noise = PerCharacterNoise::with_seed(42);
+        let base = SignalContext::new(0, 0).with_char_index(3);
+        let a = base.clone().with_position(4, 2);
+        let b = base.clone().with_position(5, 2);
+        let c = base.clone().with_position(4, 3);
+
+        assert_eq!(
+            noise.sample_with_context(0.0, &a),
+            noise.sample_with_context(9.0, &a.clone())
+        );
+        assert_ne!(
+            noise.sample_with_context(0.0, &a),
+            noise.sample_with_context(0.0, &b)
+        );
+        assert_ne!(
+            noise.sample_with_context(0.0, &a),
+            noise.sample_with_context(0.0, &c)
+        );
+        // Without a position the value is unchanged from before
+        assert_ne!(
+            noise.sample_with_context(0.0, &base),
+            noise.sample_with_context(0.0, &a)
+        );
+        assert_eq!(
+            noise.sample_with_context(0.0, &base),
+            noise.sample_with_context(0.0, &SignalContext::new(0, 0).with_char_index(3))
+        );
+    }
 }
 
 // <FILE>src/random/cls_per_character_noise.rs</FILE> - <DESC>Per-character deterministic noise using character index</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.3.0</VERS>
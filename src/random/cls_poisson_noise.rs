@@ -1,12 +1,13 @@
 // <FILE>src/random/cls_poisson_noise.rs</FILE> - <DESC>Poisson distribution noise for event-based randomness</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Noise helpers refactor</WCTX>
-// <CLOG>Use core::noise_helpers for bipolar_range, rng_from_time, rng_from_context</CLOG>
+// <VERS>VERSION: 2.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::core::{bipolar_range, rng_from_context, rng_from_time};
 use crate::math::finite_or;
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 use rand_distr::{Distribution, Poisson};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Poisson distribution noise generator for modeling discrete random events.
@@ -15,7 +16,8 @@ use serde::{Deserialize, Serialize};
 /// Natural for simulating irregular timing like keypress intervals, network packets, or glitches.
 /// Produces a bipolar value derived from z-score normalization of event counts.
 /// For raw counts, use `rng::Rng::poisson`.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PoissonNoise {
     /// Seed for reproducible randomness
     seed: u64,
@@ -292,4 +294,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/random/cls_poisson_noise.rs</FILE> - <DESC>Poisson distribution noise for event-based randomness</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.2.0</VERS>
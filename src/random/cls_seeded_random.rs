@@ -1,13 +1,14 @@
 // <FILE>mixed-signals/src/random/cls_seeded_random.rs</FILE> - <DESC>Seeded random value generator for deterministic randomness</DESC>
-// <VERS>VERSION: 1.1.0</VERS>
-// <WCTX>mixed-signals extraction: RNG standardization</WCTX>
-// <CLOG>Replaced custom hash with ChaCha8Rng for reproducible randomness</CLOG>
+// <VERS>VERSION: 1.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::math::{derive_seed, finite_or, finite_or_f64};
 use crate::traits::{Signal, SignalContext, SignalTime};
 use rand::RngCore;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Seeded random value generator producing deterministic uniform random values.
@@ -15,7 +16,8 @@ use serde::{Deserialize, Serialize};
 /// Uses ChaCha8Rng for reproducible randomness.
 /// Each call to `sample()` with different time values produces different random values,
 /// but the sequence is reproducible given the same seed.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SeededRandom {
     /// Seed for reproducible randomness
     seed: u64,
@@ -175,4 +177,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/random/cls_seeded_random.rs</FILE> - <DESC>Seeded random value generator for deterministic randomness</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 1.2.0</VERS>
@@ -1,7 +1,7 @@
 // <FILE>src/random/cls_spatial_noise.rs</FILE> - <DESC>Position-based deterministic noise generator</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Noise helpers refactor</WCTX>
-// <CLOG>Use core::noise_helpers for u64_to_bipolar; keep custom spatial seeding</CLOG>
+// <VERS>VERSION: 2.4.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::core::u64_to_bipolar;
 use crate::math::{finite_or, finite_or_f64, finite_or_min};
@@ -9,6 +9,7 @@ use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 use rand::RngCore;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Spatial noise generator producing deterministic noise based on spatial coordinates.
@@ -16,7 +17,8 @@ use serde::{Deserialize, Serialize};
 /// Unlike time-based noise, this generates values based on (x, y) position.
 /// Same position always produces the same value, regardless of time.
 /// Useful for per-character effects where character index serves as spatial position.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SpatialNoise {
     /// Seed for reproducible randomness
     seed: u64,
@@ -103,9 +105,9 @@ impl Signal for SpatialNoise {
     fn sample_with_context(&self, _t: SignalTime, ctx: &SignalContext) -> f32 {
         let amplitude = finite_or(self.amplitude, 1.0);
 
-        // Use width and height from context for spatial coordinates
-        let x = ctx.width as i32;
-        let y = ctx.height as i32;
+        // Cell position when set, else width and height as spatial coordinates
+        let (x, y) = ctx.position.unwrap_or((ctx.width, ctx.height));
+        let (x, y) = (x as i32, y as i32);
 
         let effective_seed = self.seed.wrapping_add(ctx.seed);
         let seed_bytes = derive_spatial_seed(effective_seed, x, y);
@@ -195,7 +197,44 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_spatial_noise_sample_row_matches_per_cell() {
+        let noise = SpatialNoise::new(9, 1.0, 0.8);
+        let ctx = SignalContext::new(3, 17).with_position(0, 4);
+        let row = noise.sample_row(40, 1.5, &ctx);
+        assert_eq!(row.len(), 40);
+        for (x, value) in row.iter().enumerate() {
+            let cell = SignalContext::new(3, 17).with_position(x as u16, 4);
+            assert_eq!(*value, noise.sample_with_context(1.5, &cell));
+        }
+        assert!(row.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn test_spatial_noise_position_only_difference() {
+        let noise = SpatialNoise::with_seed(5);
+        let base = SignalContext::new(2, 11)
+            .with_dimensions(80, 24)
+            .with_elapsed(1.0);
+        let a = base.clone().with_position(10, 3);
+        let b = base.clone().with_position(11, 3);
+        assert_eq!(
+            noise.sample_with_context(0.0, &a),
+            noise.sample_with_context(0.0, &a.clone())
+        );
+        assert_ne!(
+            noise.sample_with_context(0.0, &a),
+            noise.sample_with_context(0.0, &b)
+        );
+        // Elapsed time doesn't move a cell's value
+        let later = a.clone().with_elapsed(30.0);
+        assert_eq!(
+            noise.sample_with_context(0.0, &a),
+            noise.sample_with_context(0.0, &later)
+        );
+    }
 }
 
 // <FILE>src/random/cls_spatial_noise.rs</FILE> - <DESC>Position-based deterministic noise generator</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.4.0</VERS>
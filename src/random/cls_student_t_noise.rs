@@ -1,12 +1,13 @@
 // <FILE>src/random/cls_student_t_noise.rs</FILE> - <DESC>Student-t distribution noise generator</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Noise helpers refactor</WCTX>
-// <CLOG>Use core::noise_helpers for bipolar_range, rng_from_time, rng_from_context</CLOG>
+// <VERS>VERSION: 2.2.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Serde derives gated on the serde feature</CLOG>
 
 use crate::core::{bipolar_range, rng_from_context, rng_from_time};
 use crate::math::finite_or;
 use crate::traits::{Signal, SignalContext, SignalRange, SignalTime};
 use rand_distr::{Distribution, StudentT};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Student-t distribution noise generator.
@@ -34,7 +35,8 @@ use serde::{Deserialize, Serialize};
 /// let v = noise.sample(0.5);
 /// assert!(v >= -1.0 && v <= 1.0);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StudentTNoise {
     /// Degrees of freedom (controls tail heaviness)
     degrees_of_freedom: f32,
@@ -349,4 +351,4 @@ mod tests {
 }
 
 // <FILE>mixed-signals/src/random/cls_student_t_noise.rs</FILE> - <DESC>Student-t distribution noise generator</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.2.0</VERS>
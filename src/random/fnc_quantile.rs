@@ -0,0 +1,147 @@
+// <FILE>src/random/fnc_quantile.rs</FILE> - <DESC>Percentile/quantile helpers for characterizing signal distributions</DESC>
+// <VERS>VERSION: 1.1.1</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>quantile example uses FastWhiteNoise so it runs without chacha</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalTime};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Estimate the `q`-th quantile of a signal's output over a time range.
+///
+/// Samples `samples` evenly spaced points over `time_range` (end exclusive,
+/// each taken at the middle of its slot so bucketed noise sees one sample per
+/// bucket) and returns the linearly interpolated quantile of the sorted
+/// outputs. `q` is a fraction in [0, 1]: 0.5 is the median, 0.95 the 95th
+/// percentile. Out-of-range `q` is clamped; NaN uses the median.
+///
+/// Non-finite outputs are ignored. Returns 0.0 when no finite samples exist.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::random::{quantile, FastWhiteNoise};
+///
+/// // Uniform in [-1, 1]: the 95th percentile sits near 0.9
+/// let noise = FastWhiteNoise::with_seed(7);
+/// let p95 = quantile(&noise, 0.0..10.0, 10_000, 0.95);
+/// assert!(p95 > 0.8 && p95 <= 1.0);
+/// ```
+pub fn quantile(signal: &dyn Signal, time_range: Range<SignalTime>, samples: usize, q: f32) -> f32 {
+    quantiles(signal, time_range, samples, &[q])[0]
+}
+
+/// Estimate several quantiles from a single pass over the signal.
+///
+/// Same sampling and interpolation as [`quantile`], returning one value per
+/// entry in `qs` (in the same order).
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sawtooth;
+/// use mixed_signals::random::quantiles;
+///
+/// let saw = Sawtooth::default();
+/// let q = quantiles(&saw, 0.0..1.0, 1000, &[0.25, 0.5, 0.75]);
+/// assert!(q[0] < q[1] && q[1] < q[2]);
+/// ```
+pub fn quantiles(
+    signal: &dyn Signal,
+    time_range: Range<SignalTime>,
+    samples: usize,
+    qs: &[f32],
+) -> Vec<f32> {
+    let start = time_range.start;
+    let span = time_range.end - time_range.start;
+
+    let mut values: Vec<f32> = (0..samples)
+        .map(|i| {
+            let t = start + span * ((i as SignalTime + 0.5) / samples as SignalTime);
+            signal.sample(t)
+        })
+        .filter(|v| v.is_finite())
+        .collect();
+    values.sort_by(f32::total_cmp);
+
+    qs.iter().map(|&q| interpolate_sorted(&values, q)).collect()
+}
+
+/// Linearly interpolated quantile of already-sorted values.
+fn interpolate_sorted(sorted: &[f32], q: f32) -> f32 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let q = if q.is_nan() { 0.5 } else { q.clamp(0.0, 1.0) };
+    let pos = q as f64 * (sorted.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = (lower + 1).min(sorted.len() - 1);
+    let frac = (pos - lower as f64) as f32;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Ramp};
+    #[cfg(feature = "chacha")]
+    use crate::random::GaussianNoise;
+
+    /// Gaussian noise scaled so output equals the raw N(0, 1) draw.
+    ///
+    /// GaussianNoise maps ±3σ onto ±1, so amplitude 3 undoes that mapping.
+    #[cfg(feature = "chacha")]
+    fn standard_normal(seed: u64) -> GaussianNoise {
+        GaussianNoise::new(seed, 1.0, 3.0, 0.0).unwrap()
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn test_quantile_gaussian_median_near_zero() {
+        let median = quantile(&standard_normal(42), 0.0..20.0, 20_000, 0.5);
+        assert!(median.abs() < 0.05, "median {median}");
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn test_quantile_gaussian_two_sigma() {
+        // 97.7th percentile of N(0, 1) is ~2.0
+        let p977 = quantile(&standard_normal(42), 0.0..20.0, 20_000, 0.977);
+        assert!((p977 - 2.0).abs() < 0.1, "p97.7 {p977}");
+    }
+
+    #[test]
+    fn test_quantiles_linear_ramp() {
+        // Ramp 0 → 1 over 1s sampled uniformly: quantile q ≈ q
+        let ramp = Ramp::new(0.0, 1.0, 1.0);
+        let q = quantiles(&ramp, 0.0..1.0, 1001, &[0.0, 0.1, 0.5, 0.9, 1.0]);
+        for (value, expected) in q.iter().zip([0.0, 0.1, 0.5, 0.9, 1.0]) {
+            assert!((value - expected).abs() < 0.002, "{value} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn test_quantile_sanitizes_q() {
+        let ramp = Ramp::new(0.0, 1.0, 1.0);
+        let lo = quantile(&ramp, 0.0..1.0, 100, 0.0);
+        let hi = quantile(&ramp, 0.0..1.0, 100, 1.0);
+        assert_eq!(quantile(&ramp, 0.0..1.0, 100, -2.0), lo);
+        assert_eq!(quantile(&ramp, 0.0..1.0, 100, 5.0), hi);
+        assert_eq!(
+            quantile(&ramp, 0.0..1.0, 100, f32::NAN),
+            quantile(&ramp, 0.0..1.0, 100, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_quantile_empty_and_non_finite() {
+        assert_eq!(quantile(&Constant::new(0.3), 0.0..1.0, 0, 0.5), 0.0);
+        assert_eq!(quantile(&Constant::new(f32::NAN), 0.0..1.0, 10, 0.5), 0.0);
+        assert_eq!(quantile(&Constant::new(0.3), 0.0..1.0, 10, 0.9), 0.3);
+    }
+}
+
+// <FILE>src/random/fnc_quantile.rs</FILE> - <DESC>Percentile/quantile helpers for characterizing signal distributions</DESC>
+// <VERS>END OF VERSION: 1.1.1</VERS>
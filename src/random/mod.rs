@@ -1,7 +1,7 @@
 // <FILE>mixed-signals/src/random/mod.rs</FILE> - <DESC>Random signal generators module</DESC>
-// <VERS>VERSION: 1.3.0</VERS>
-// <WCTX>Audio synthesis primitives</WCTX>
-// <CLOG>Added ImpulseNoise and StudentTNoise generators</CLOG>
+// <VERS>VERSION: 1.9.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>ChaCha8 types behind the chacha feature; MAX_PINK_ROWS defined here</CLOG>
 
 //! Random signal generators for stochastic and noise-based effects.
 //!
@@ -17,41 +17,77 @@
 //!
 //! Use Fast variants for animation/visualization where speed matters more than
 //! cryptographic quality. Both are deterministic and reproducible.
+//! [`FastWhiteNoise`] also fills buffers through the SIMD
+//! [`fast_random_batch`](crate::math::fast_random_batch) path.
 //!
 //! Invalid inputs (NaN/Inf) are sanitized to defaults at sample time to keep
 //! outputs finite. For valid finite inputs, behavior is unchanged.
+//!
+//! The ChaCha8 types need the `chacha` feature; the Fast variants,
+//! [`SmoothRandom`] and the helpers build without it (and without `std`).
 
+#[cfg(feature = "chacha")]
 mod cls_correlated_noise;
+#[cfg(feature = "chacha")]
 mod cls_gaussian_noise;
+#[cfg(feature = "chacha")]
 mod cls_impulse_noise;
+#[cfg(feature = "chacha")]
+mod cls_noise_stream;
+#[cfg(feature = "chacha")]
 mod cls_per_character_noise;
+#[cfg(feature = "chacha")]
 mod cls_pink_noise;
+#[cfg(feature = "chacha")]
 mod cls_poisson_noise;
+#[cfg(feature = "chacha")]
 mod cls_seeded_random;
+mod cls_smooth_random;
+#[cfg(feature = "chacha")]
 mod cls_spatial_noise;
+#[cfg(feature = "chacha")]
 mod cls_student_t_noise;
 mod fnc_hash_to_index;
+mod fnc_quantile;
 
 // Fast variants using hash-based RNG
 mod cls_fast_correlated_noise;
 mod cls_fast_pink_noise;
 mod cls_fast_seeded_random;
+mod cls_fast_white_noise;
 
+#[cfg(feature = "chacha")]
 pub use cls_correlated_noise::CorrelatedNoise;
+#[cfg(feature = "chacha")]
 pub use cls_gaussian_noise::GaussianNoise;
+#[cfg(feature = "chacha")]
 pub use cls_impulse_noise::ImpulseNoise;
+#[cfg(feature = "chacha")]
+pub use cls_noise_stream::NoiseStream;
+#[cfg(feature = "chacha")]
 pub use cls_per_character_noise::PerCharacterNoise;
+#[cfg(feature = "chacha")]
 pub use cls_pink_noise::PinkNoise;
+#[cfg(feature = "chacha")]
 pub use cls_poisson_noise::PoissonNoise;
+#[cfg(feature = "chacha")]
 pub use cls_seeded_random::SeededRandom;
+pub use cls_smooth_random::{Interp, SmoothRandom};
+#[cfg(feature = "chacha")]
 pub use cls_spatial_noise::SpatialNoise;
+#[cfg(feature = "chacha")]
 pub use cls_student_t_noise::StudentTNoise;
 pub use fnc_hash_to_index::hash_to_index;
+pub use fnc_quantile::{quantile, quantiles};
+
+/// Most octave rows accepted by `PinkNoise::with_rows` and `FastPinkNoise::with_rows`.
+pub const MAX_PINK_ROWS: usize = 32;
 
 // Fast variants
 pub use cls_fast_correlated_noise::FastCorrelatedNoise;
 pub use cls_fast_pink_noise::FastPinkNoise;
 pub use cls_fast_seeded_random::FastSeededRandom;
+pub use cls_fast_white_noise::FastWhiteNoise;
 
 // <FILE>mixed-signals/src/random/mod.rs</FILE> - <DESC>Random signal generators module</DESC>
-// <VERS>END OF VERSION: 1.3.0</VERS>
+// <VERS>END OF VERSION: 1.9.0</VERS>
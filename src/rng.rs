@@ -1,14 +1,15 @@
 // <FILE>src/rng.rs</FILE> - <DESC>Unified RNG API for common randomness patterns</DESC>
-// <VERS>VERSION: 1.4.0</VERS>
-// <WCTX>Migrate time types from f32 to f64</WCTX>
-// <CLOG>Internal time changed to f64 to match Signal trait</CLOG>
+// <VERS>VERSION: 1.10.1</VERS>
+// <WCTX>RNG algorithm choice</WCTX>
+// <CLOG>Replaced wall-clock Fast gaussian timing test with a moments check</CLOG>
 
 //! Central RNG interface for common randomness needs.
 //!
-//! This module provides a unified API for generating random values, wrapping
-//! the signal-based random generators for ease of use. Use this when you need
-//! simple random values; use the underlying signals when you need time-based
-//! or context-aware randomness.
+//! This module provides a unified API for generating random values. Uniform
+//! draws come from a counter-based SplitMix64 stream; distribution draws seed
+//! ChaCha8 from the same stream position. Use this when you need simple random
+//! values; use the signal generators when you need time-based or context-aware
+//! randomness.
 //!
 //! # Quick Start
 //!
@@ -41,22 +42,92 @@
 //! - Context-aware randomness (frame, phase, spatial)
 //! - Composable randomness (mix with other signals)
 //! - Serializable random configurations
+//!
+//! # Stream Modes
+//!
+//! By default ([`RngStream::Counter`]) `Rng` hashes an incrementing draw counter
+//! with SplitMix64. Each seed yields its own reproducible sequence that never
+//! repeats a stream position and is independent of time precision.
+//!
+//! [`RngStream::TimeStep`] (via [`Rng::with_time_step`]) instead samples
+//! [`SeededRandom`] at an internal time that advances by `time_step` per draw,
+//! matching `SeededRandom` signal output. `SeededRandom` quantizes time to whole
+//! milliseconds, so each draw lands in its own millisecond bucket. Larger steps
+//! skip buckets (a different but equally valid sequence); steps below 0.001
+//! would map several draws to the same bucket and repeat values, so they are
+//! clamped to 0.001.
+//!
+//! ```rust
+//! use mixed_signals::rng::Rng;
+//!
+//! let mut stepped = Rng::with_seed(42).with_time_step(0.005);
+//! let mut counted = Rng::with_seed(42).with_counter_stream();
+//! let a = stepped.uniform(0.0, 1.0);
+//! let b = counted.uniform(0.0, 1.0);
+//! assert!(a.is_finite() && b.is_finite());
+//! ```
+//!
+//! # Algorithms
+//!
+//! [`RngAlgo::ChaCha`] (the default) seeds ChaCha8 for distribution draws
+//! (`gaussian`, `poisson`) and time-step draws, mirroring the standard
+//! generators in [`crate::random`]. [`RngAlgo::Fast`] (via
+//! [`Rng::with_algorithm`]) uses SplitMix64 for every draw instead, mirroring
+//! the `Fast*` generators: much cheaper per draw, at lower statistical quality.
+//! Counter-stream uniform draws are SplitMix64 in both modes.
+//!
+//! ```rust
+//! use mixed_signals::rng::{Rng, RngAlgo};
+//!
+//! let mut fast = Rng::with_seed(42).with_algorithm(RngAlgo::Fast);
+//! let value = fast.gaussian(0.0, 1.0).unwrap();
+//! assert!(value.is_finite());
+//! ```
 
 use crate::math::derive_seed;
-use crate::random::SeededRandom;
-use crate::traits::Signal;
-use rand::SeedableRng;
+use crate::random::{FastSeededRandom, SeededRandom};
+use crate::traits::{Signal, SignalTime};
+use rand::{RngCore, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 use rand_distr::{Distribution, Normal, Poisson};
+use std::collections::HashMap;
+
+/// Default time advance per draw in [`RngStream::TimeStep`] mode (one millisecond).
+const DEFAULT_TIME_STEP: SignalTime = 0.001;
+
+/// Maximum dice per notation term accepted by [`Rng::roll`].
+const MAX_DICE_PER_TERM: u32 = 1000;
+
+/// How [`Rng`] advances its underlying random stream between draws.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RngStream {
+    /// Hash the draw index directly with SplitMix64 (no time quantization).
+    #[default]
+    Counter,
+    /// Sample `SeededRandom` at `draw_index * time_step` seconds.
+    TimeStep,
+}
+
+/// Generator family [`Rng`] draws from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RngAlgo {
+    /// ChaCha8 for distribution and time-step draws (higher quality).
+    #[default]
+    ChaCha,
+    /// SplitMix64 for all draws (faster, lower quality).
+    Fast,
+}
 
 /// Central RNG for common randomness patterns.
 ///
 /// Wraps signal-based random generators for traditional RNG usage.
-/// Each call advances an internal time counter for deterministic sequences.
+/// Each call advances an internal draw counter for deterministic sequences.
 pub struct Rng {
     seed: u64,
-    time: crate::traits::SignalTime,
-    time_step: crate::traits::SignalTime,
+    draws: u64,
+    time_step: SignalTime,
+    stream: RngStream,
+    algorithm: RngAlgo,
 }
 
 impl Rng {
@@ -73,8 +144,10 @@ impl Rng {
     pub fn new(seed: u64) -> Self {
         Self {
             seed,
-            time: 0.0,
-            time_step: 0.001, // Small step for fine-grained randomness
+            draws: 0,
+            time_step: DEFAULT_TIME_STEP,
+            stream: RngStream::Counter,
+            algorithm: RngAlgo::ChaCha,
         }
     }
 
@@ -85,6 +158,51 @@ impl Rng {
         Self::new(seed)
     }
 
+    /// Set the internal time advance per draw and use [`RngStream::TimeStep`].
+    ///
+    /// Steps are clamped to at least 0.001 (one `SeededRandom` millisecond
+    /// bucket) so consecutive draws never repeat. Non-finite or non-positive
+    /// steps fall back to the default 0.001.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mixed_signals::rng::Rng;
+    ///
+    /// let mut rng = Rng::with_seed(42).with_time_step(0.01);
+    /// assert_eq!(rng.time_step(), 0.01);
+    /// let value = rng.uniform(0.0, 1.0);
+    /// ```
+    pub fn with_time_step(mut self, step: SignalTime) -> Self {
+        self.time_step = if step.is_finite() && step > 0.0 {
+            step.max(DEFAULT_TIME_STEP)
+        } else {
+            DEFAULT_TIME_STEP
+        };
+        self.stream = RngStream::TimeStep;
+        self
+    }
+
+    /// Use a pure counter-based stream ([`RngStream::Counter`], the default).
+    ///
+    /// Draws hash an incrementing counter instead of sampling at a time
+    /// offset, so sequences never collide regardless of length. Useful to
+    /// switch back after `with_time_step`.
+    pub fn with_counter_stream(mut self) -> Self {
+        self.stream = RngStream::Counter;
+        self
+    }
+
+    /// Choose the generator family used for draws.
+    ///
+    /// [`RngAlgo::ChaCha`] is the default. [`RngAlgo::Fast`] trades
+    /// statistical quality for speed; sequences differ between algorithms but
+    /// each is deterministic per seed.
+    pub fn with_algorithm(mut self, algorithm: RngAlgo) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
     /// Create a new RNG from system entropy (non-deterministic).
     ///
     /// **Warning:** Not reproducible across runs. Use `with_seed()` for determinism.
@@ -110,9 +228,14 @@ impl Rng {
     /// ```
     pub fn uniform(&mut self, min: f32, max: f32) -> f32 {
         let (min, max) = if min <= max { (min, max) } else { (max, min) };
-        let noise = SeededRandom::with_seed(self.seed);
-        let value = noise.sample(self.time);
-        self.time += self.time_step;
+        let value = match self.stream {
+            RngStream::TimeStep => match self.algorithm {
+                RngAlgo::ChaCha => SeededRandom::with_seed(self.seed).sample(self.draw_time()),
+                RngAlgo::Fast => FastSeededRandom::with_seed(self.seed).sample(self.draw_time()),
+            },
+            RngStream::Counter => unit_from_u64(splitmix64(self.seed, self.draws)),
+        };
+        self.draws = self.draws.wrapping_add(1);
 
         // Map from [0, 1] to [min, max]
         min + value * (max - min)
@@ -142,17 +265,15 @@ impl Rng {
         }
 
         if std_dev == 0.0 {
-            self.time += self.time_step;
+            self.draws = self.draws.wrapping_add(1);
             return Ok(mean);
         }
 
-        let time_ms = (self.time * 1000.0) as u64;
-        let seed_bytes = derive_seed(self.seed, time_ms);
-        let mut rng = ChaCha8Rng::from_seed(seed_bytes);
+        let mut rng = self.draw_rng();
         let normal = Normal::new(mean as f64, std_dev as f64)
             .map_err(|_| format!("Gaussian std_dev must be > 0, got {}", std_dev))?;
         let value = normal.sample(&mut rng) as f32;
-        self.time += self.time_step;
+        self.draws = self.draws.wrapping_add(1);
         Ok(value)
     }
 
@@ -181,13 +302,11 @@ impl Rng {
             ));
         }
 
-        let time_ms = (self.time * 1000.0) as u64;
-        let seed_bytes = derive_seed(self.seed, time_ms);
-        let mut rng = ChaCha8Rng::from_seed(seed_bytes);
+        let mut rng = self.draw_rng();
         let poisson = Poisson::new(lambda as f64)
             .map_err(|_| format!("Poisson lambda must be > 0, got {}", lambda))?;
         let value = poisson.sample(&mut rng) as f32;
-        self.time += self.time_step;
+        self.draws = self.draws.wrapping_add(1);
         Ok(value)
     }
 
@@ -241,6 +360,148 @@ impl Rng {
         Some(&items[index])
     }
 
+    /// Pick an index with probability proportional to its weight.
+    ///
+    /// Zero, negative, and non-finite weights count as 0. Returns `None` (and
+    /// consumes no draw) when every weight is 0 or the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mixed_signals::rng::Rng;
+    ///
+    /// let mut rng = Rng::with_seed(42);
+    /// let idx = rng.weighted_index(&[0.0, 5.0, 1.0]).unwrap();
+    /// assert!(idx == 1 || idx == 2);
+    /// assert_eq!(rng.weighted_index(&[0.0, -1.0, f32::NAN]), None);
+    /// ```
+    pub fn weighted_index(&mut self, weights: &[f32]) -> Option<usize> {
+        let weight = |w: f32| {
+            if w.is_finite() && w > 0.0 {
+                w as f64
+            } else {
+                0.0
+            }
+        };
+        let total: f64 = weights.iter().map(|&w| weight(w)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let target = self.uniform(0.0, 1.0) as f64 * total;
+        let mut cumulative = 0.0;
+        let mut last_positive = 0;
+        for (i, &w) in weights.iter().enumerate() {
+            let w = weight(w);
+            if w == 0.0 {
+                continue;
+            }
+            cumulative += w;
+            last_positive = i;
+            if target < cumulative {
+                return Some(i);
+            }
+        }
+        // target == total (uniform is inclusive) or float rounding
+        Some(last_positive)
+    }
+
+    /// Choose one element with probability proportional to its weight.
+    ///
+    /// Only the first `min(items.len(), weights.len())` pairs are considered.
+    /// Weights follow [`weighted_index`](Self::weighted_index): zero, negative,
+    /// and non-finite weights count as 0, and all-zero weights return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mixed_signals::rng::Rng;
+    ///
+    /// let mut rng = Rng::with_seed(42);
+    /// let loot = ["common", "rare", "legendary"];
+    /// let drop = rng.choose_weighted(&loot, &[70.0, 25.0, 5.0]);
+    /// assert!(drop.is_some());
+    /// ```
+    pub fn choose_weighted<'a, T>(&mut self, items: &'a [T], weights: &[f32]) -> Option<&'a T> {
+        let len = items.len().min(weights.len());
+        self.weighted_index(&weights[..len]).map(|i| &items[i])
+    }
+
+    /// Roll a single die with `sides` faces, returning a value in `1..=sides`.
+    ///
+    /// Uses rejection sampling on a full 64-bit draw, so every face is exactly
+    /// equally likely (no modulo bias). A die with 0 sides returns 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mixed_signals::rng::Rng;
+    ///
+    /// let mut rng = Rng::with_seed(42);
+    /// let d20 = rng.dice(20);
+    /// assert!((1..=20).contains(&d20));
+    /// ```
+    pub fn dice(&mut self, sides: u32) -> u32 {
+        if sides == 0 {
+            return 0;
+        }
+        let sides = sides as u64;
+        // 2^64 mod sides: draws below this would over-represent low faces
+        let reject_below = sides.wrapping_neg() % sides;
+        loop {
+            let value = self.next_u64();
+            if value >= reject_below {
+                return (value % sides) as u32 + 1;
+            }
+        }
+    }
+
+    /// Roll dice described by tabletop notation and return the total.
+    ///
+    /// Notation is a sum of terms joined by `+` or `-`. Each term is either
+    /// dice (`NdS`, or `dS` for a single die) or an integer modifier, and at
+    /// least one dice term is required. Whitespace is ignored and `d` is
+    /// case-insensitive. Each term may roll at most 1000 dice.
+    ///
+    /// The whole string is validated before any dice are rolled, so malformed
+    /// notation returns `Err` without advancing the sequence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mixed_signals::rng::Rng;
+    ///
+    /// let mut rng = Rng::with_seed(42);
+    /// let damage = rng.roll("2d6+3").unwrap();
+    /// assert!((5..=15).contains(&damage));
+    /// assert!(rng.roll("2d+3").is_err());
+    /// ```
+    pub fn roll(&mut self, notation: &str) -> Result<i64, String> {
+        let terms = parse_dice_notation(notation)?;
+        let mut total: i64 = 0;
+        for term in terms {
+            let value = match term {
+                DiceTerm::Dice {
+                    count,
+                    sides,
+                    negative,
+                } => {
+                    let sum: i64 = (0..count).map(|_| self.dice(sides) as i64).sum();
+                    if negative {
+                        -sum
+                    } else {
+                        sum
+                    }
+                }
+                DiceTerm::Modifier(value) => value,
+            };
+            total = total
+                .checked_add(value)
+                .ok_or_else(|| format!("Dice notation '{}' overflows i64", notation))?;
+        }
+        Ok(total)
+    }
+
     /// Shuffle a slice in place using Fisher-Yates algorithm.
     ///
     /// # Example
@@ -286,6 +547,74 @@ impl Rng {
         }
     }
 
+    /// Draw `k` distinct indices from `0..len` in random order.
+    ///
+    /// Runs a partial Fisher-Yates over a virtual index array, so the result
+    /// is exactly the first `k` entries of `shuffle_partial` applied to
+    /// `0..len`. When `k` is small relative to `len` only the swapped
+    /// positions are stored, so drawing 3 of 1,000,000 allocates almost
+    /// nothing. `k > len` returns every index in random order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mixed_signals::rng::Rng;
+    ///
+    /// let mut rng = Rng::with_seed(42);
+    /// let picks = rng.choose_multiple_indices(20, 3);
+    /// assert_eq!(picks.len(), 3);
+    /// assert!(picks.iter().all(|&i| i < 20));
+    /// ```
+    pub fn choose_multiple_indices(&mut self, len: usize, k: usize) -> Vec<usize> {
+        let k = k.min(len);
+        let mut picks = Vec::with_capacity(k);
+        if k == 0 {
+            return picks;
+        }
+
+        if k * 4 >= len {
+            // Dense: cheap enough to materialize the whole index array
+            let mut indices: Vec<usize> = (0..len).collect();
+            self.shuffle_partial(&mut indices, k);
+            indices.truncate(k);
+            return indices;
+        }
+
+        // Sparse: positions not in the map still hold their own index
+        let mut swapped: HashMap<usize, usize> = HashMap::with_capacity(k * 2);
+        for i in 0..k {
+            let j = (self.uniform(0.0, (len - i) as f32).floor() as usize).min(len - i - 1) + i;
+            let at_j = swapped.get(&j).copied().unwrap_or(j);
+            let at_i = swapped.get(&i).copied().unwrap_or(i);
+            swapped.insert(j, at_i);
+            picks.push(at_j);
+        }
+        picks
+    }
+
+    /// Draw `k` distinct elements from a slice without modifying it.
+    ///
+    /// Elements come back in random order. `k == 0` returns an empty vec and
+    /// `k > items.len()` returns every element in random order. See
+    /// [`choose_multiple_indices`](Self::choose_multiple_indices).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mixed_signals::rng::Rng;
+    ///
+    /// let mut rng = Rng::with_seed(42);
+    /// let quests: Vec<u32> = (1..=20).collect();
+    /// let offered = rng.choose_multiple(&quests, 3);
+    /// assert_eq!(offered.len(), 3);
+    /// ```
+    pub fn choose_multiple<'a, T>(&mut self, items: &'a [T], k: usize) -> Vec<&'a T> {
+        self.choose_multiple_indices(items.len(), k)
+            .into_iter()
+            .map(|i| &items[i])
+            .collect()
+    }
+
     /// Cyclic shuffle - no element stays in its original position.
     ///
     /// Uses Sattolo's algorithm to generate a cyclic permutation (derangement).
@@ -336,11 +665,11 @@ impl Rng {
         crate::shuffle::weighted_shuffle(items, weights, self);
     }
 
-    /// Reset the internal time counter.
+    /// Reset the internal draw counter.
     ///
     /// Useful for restarting a deterministic sequence.
     pub fn reset(&mut self) {
-        self.time = 0.0;
+        self.draws = 0;
     }
 
     /// Get the current seed.
@@ -351,8 +680,209 @@ impl Rng {
     /// Change the seed, resetting the sequence.
     pub fn reseed(&mut self, seed: u64) {
         self.seed = seed;
-        self.time = 0.0;
+        self.draws = 0;
+    }
+
+    /// Time advance per draw in [`RngStream::TimeStep`] mode.
+    pub fn time_step(&self) -> SignalTime {
+        self.time_step
+    }
+
+    /// Stream mode used to advance between draws.
+    pub fn stream(&self) -> RngStream {
+        self.stream
+    }
+
+    /// Generator family used for draws.
+    pub fn algorithm(&self) -> RngAlgo {
+        self.algorithm
+    }
+
+    /// Sample time for the current draw.
+    ///
+    /// Derived from the draw count (not accumulated) and centered in its
+    /// millisecond bucket so float error can't truncate into the previous one.
+    fn draw_time(&self) -> SignalTime {
+        self.draws as SignalTime * self.time_step + DEFAULT_TIME_STEP * 0.5
+    }
+
+    /// Stream position used to seed distribution samplers.
+    fn draw_index(&self) -> u64 {
+        match self.stream {
+            RngStream::TimeStep => (self.draw_time() * 1000.0) as u64,
+            RngStream::Counter => self.draws,
+        }
+    }
+
+    fn draw_rng(&self) -> DrawRng {
+        match self.algorithm {
+            RngAlgo::ChaCha => DrawRng::ChaCha(ChaCha8Rng::from_seed(derive_seed(
+                self.seed,
+                self.draw_index(),
+            ))),
+            RngAlgo::Fast => DrawRng::Fast(splitmix64(self.seed, self.draw_index())),
+        }
     }
+
+    /// Raw 64-bit draw from the current stream position.
+    fn next_u64(&mut self) -> u64 {
+        let value = match self.stream {
+            RngStream::TimeStep => self.draw_rng().next_u64(),
+            RngStream::Counter => splitmix64(self.seed, self.draws),
+        };
+        self.draws = self.draws.wrapping_add(1);
+        value
+    }
+}
+
+/// Per-draw generator handed to distribution samplers.
+///
+/// Lives on the stack for a single draw, so the ChaCha variant is kept
+/// inline rather than boxed to avoid an allocation per sample.
+#[allow(clippy::large_enum_variant)]
+enum DrawRng {
+    ChaCha(ChaCha8Rng),
+    /// SplitMix64 state
+    Fast(u64),
+}
+
+impl RngCore for DrawRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            DrawRng::ChaCha(rng) => rng.next_u64(),
+            DrawRng::Fast(state) => {
+                *state = state.wrapping_add(0x9e3779b97f4a7c15);
+                mix64(*state)
+            }
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            DrawRng::ChaCha(rng) => rng.fill_bytes(dest),
+            DrawRng::Fast(_) => {
+                for chunk in dest.chunks_mut(8) {
+                    let bytes = self.next_u64().to_le_bytes();
+                    chunk.copy_from_slice(&bytes[..chunk.len()]);
+                }
+            }
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// One signed term of dice notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiceTerm {
+    /// Roll `count` dice with `sides` faces, subtracting the total if `negative`
+    Dice {
+        count: u32,
+        sides: u32,
+        negative: bool,
+    },
+    Modifier(i64),
+}
+
+/// Parse notation like `"2d6+1d4-1"` into terms, validating the whole string.
+fn parse_dice_notation(notation: &str) -> Result<Vec<DiceTerm>, String> {
+    let invalid = |reason: &str| format!("Invalid dice notation '{}': {}", notation, reason);
+    let compact: String = notation.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact.is_empty() {
+        return Err(invalid("empty"));
+    }
+
+    let mut terms = Vec::new();
+    let mut has_dice = false;
+    let mut rest = compact.as_str();
+    let mut negative = false;
+    if let Some(stripped) = rest.strip_prefix('-') {
+        negative = true;
+        rest = stripped;
+    } else if let Some(stripped) = rest.strip_prefix('+') {
+        rest = stripped;
+    }
+
+    loop {
+        let end = rest.find(['+', '-']).unwrap_or(rest.len());
+        let term = &rest[..end];
+        if term.is_empty() {
+            return Err(invalid("missing term"));
+        }
+
+        if let Some((count, sides)) = term.split_once(['d', 'D']) {
+            let count = if count.is_empty() {
+                1
+            } else {
+                parse_digits(count).ok_or_else(|| invalid("bad dice count"))?
+            };
+            let sides = parse_digits(sides).ok_or_else(|| invalid("bad die size"))?;
+            if count == 0 || count > MAX_DICE_PER_TERM as u64 {
+                return Err(invalid("dice count must be between 1 and 1000"));
+            }
+            if sides == 0 || sides > u32::MAX as u64 {
+                return Err(invalid("die size must be at least 1"));
+            }
+            has_dice = true;
+            terms.push(DiceTerm::Dice {
+                count: count as u32,
+                sides: sides as u32,
+                negative,
+            });
+        } else {
+            let value = parse_digits(term)
+                .and_then(|v| i64::try_from(v).ok())
+                .ok_or_else(|| invalid("bad modifier"))?;
+            terms.push(DiceTerm::Modifier(if negative { -value } else { value }));
+        }
+
+        if end == rest.len() {
+            break;
+        }
+        negative = rest.as_bytes()[end] == b'-';
+        rest = &rest[end + 1..];
+    }
+
+    if !has_dice {
+        return Err(invalid("no dice term"));
+    }
+    Ok(terms)
+}
+
+/// Parse a non-empty run of ASCII digits (no signs).
+fn parse_digits(text: &str) -> Option<u64> {
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+/// SplitMix64 output for position `counter` in the stream for `seed`.
+#[inline]
+fn splitmix64(seed: u64, counter: u64) -> u64 {
+    // Pre-mix the seed so adjacent seeds don't produce shifted copies of one stream
+    let gamma = counter.wrapping_add(1).wrapping_mul(0x9e3779b97f4a7c15);
+    mix64(mix64(seed).wrapping_add(gamma))
+}
+
+#[inline]
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Map a u64 to [0, 1) using the top 24 bits (full f32 mantissa precision).
+#[inline]
+fn unit_from_u64(value: u64) -> f32 {
+    (value >> 40) as f32 / (1u64 << 24) as f32
 }
 
 impl Default for Rng {
@@ -514,7 +1044,431 @@ mod tests {
         let mut rng = Rng::with_seed(42);
         assert!(rng.poisson(0.0).is_err());
     }
+
+    fn assert_no_repeats_within(values: &[f32], window: usize) {
+        for (i, a) in values.iter().enumerate() {
+            for b in values.iter().skip(i + 1).take(window) {
+                assert_ne!(a, b, "Repeated value within window at draw {}", i);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rng_time_step_sequences_finite_and_distinct() {
+        for step in [0.001, 0.0015, 0.005, 0.1] {
+            let mut rng = Rng::with_seed(42).with_time_step(step);
+            let values: Vec<f32> = (0..2000).map(|_| rng.uniform(0.0, 1.0)).collect();
+            assert!(values.iter().all(|v| v.is_finite()));
+            assert_no_repeats_within(&values, 100);
+        }
+    }
+
+    #[test]
+    fn test_rng_time_step_determinism() {
+        let mut rng1 = Rng::with_seed(7).with_time_step(0.005);
+        let mut rng2 = Rng::with_seed(7).with_time_step(0.005);
+        for _ in 0..100 {
+            assert_eq!(rng1.uniform(0.0, 1.0), rng2.uniform(0.0, 1.0));
+            assert_eq!(rng1.gaussian(0.0, 1.0), rng2.gaussian(0.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn test_rng_time_step_changes_sequence() {
+        let mut fine = Rng::with_seed(7).with_time_step(0.001);
+        let mut coarse = Rng::with_seed(7).with_time_step(0.01);
+        let a: Vec<f32> = (0..10).map(|_| fine.uniform(0.0, 1.0)).collect();
+        let b: Vec<f32> = (0..10).map(|_| coarse.uniform(0.0, 1.0)).collect();
+        assert_eq!(a[0], b[0]); // Both start at t=0
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_rng_time_step_sanitized() {
+        assert_eq!(Rng::with_seed(1).with_time_step(0.0001).time_step(), 0.001);
+        assert_eq!(Rng::with_seed(1).with_time_step(-1.0).time_step(), 0.001);
+        assert_eq!(
+            Rng::with_seed(1).with_time_step(f64::NAN).time_step(),
+            0.001
+        );
+    }
+
+    #[test]
+    fn test_rng_time_step_has_no_bucket_collisions() {
+        // Accumulated float time used to truncate into the previous ms bucket
+        let mut rng = Rng::with_seed(3).with_time_step(0.001);
+        let values: Vec<f32> = (0..10_000).map(|_| rng.uniform(0.0, 1.0)).collect();
+        assert_no_repeats_within(&values, 1);
+    }
+
+    #[test]
+    fn test_rng_counter_stream() {
+        let mut rng1 = Rng::with_seed(42).with_counter_stream();
+        let mut rng2 = Rng::with_seed(42).with_counter_stream();
+        assert_eq!(rng1.stream(), RngStream::Counter);
+
+        let values: Vec<f32> = (0..2000).map(|_| rng1.uniform(0.0, 1.0)).collect();
+        for v in &values {
+            assert!((0.0..1.0).contains(v));
+            assert_eq!(*v, rng2.uniform(0.0, 1.0));
+        }
+        assert_no_repeats_within(&values, 100);
+
+        rng1.reset();
+        assert_eq!(rng1.uniform(0.0, 1.0), values[0]);
+    }
+
+    #[test]
+    fn test_rng_counter_stream_adjacent_seeds_independent() {
+        let mut a = Rng::with_seed(1).with_counter_stream();
+        let mut b = Rng::with_seed(2).with_counter_stream();
+        let va: Vec<f32> = (0..50).map(|_| a.uniform(0.0, 1.0)).collect();
+        let vb: Vec<f32> = (0..50).map(|_| b.uniform(0.0, 1.0)).collect();
+        // No shifted overlap between the two streams
+        assert!(va[1..].iter().all(|v| !vb.contains(v)));
+    }
+
+    /// Chi-square statistic for `draws` values bucketed into `bins`.
+    fn chi_square<F: FnMut() -> usize>(bins: usize, draws: usize, mut draw: F) -> f64 {
+        let mut counts = vec![0usize; bins];
+        for _ in 0..draws {
+            counts[draw()] += 1;
+        }
+        let expected = draws as f64 / bins as f64;
+        counts
+            .iter()
+            .map(|&c| (c as f64 - expected).powi(2) / expected)
+            .sum()
+    }
+
+    #[test]
+    fn test_rng_default_stream_is_counter() {
+        assert_eq!(Rng::with_seed(1).stream(), RngStream::Counter);
+        assert_eq!(
+            Rng::with_seed(1).with_time_step(0.002).stream(),
+            RngStream::TimeStep
+        );
+    }
+
+    #[test]
+    fn test_rng_uniform_chi_square() {
+        // 100 bins, 99 dof: p=0.001 critical value is ~148.2
+        let mut rng = Rng::with_seed(42);
+        let chi2 = chi_square(100, 100_000, || {
+            (rng.uniform(0.0, 100.0).floor() as usize).min(99)
+        });
+        assert!(chi2 < 148.2, "uniform chi-square {} too high", chi2);
+    }
+
+    #[test]
+    fn test_rng_chance_chi_square() {
+        // 2 outcomes, 1 dof: p=0.001 critical value is ~10.83
+        let mut rng = Rng::with_seed(7);
+        let mut hits = 0usize;
+        let n = 100_000;
+        for _ in 0..n {
+            if rng.chance(0.3) {
+                hits += 1;
+            }
+        }
+        let expected_hits = n as f64 * 0.3;
+        let expected_misses = n as f64 * 0.7;
+        let chi2 = (hits as f64 - expected_hits).powi(2) / expected_hits
+            + ((n - hits) as f64 - expected_misses).powi(2) / expected_misses;
+        assert!(chi2 < 10.83, "chance chi-square {} too high", chi2);
+    }
+
+    #[test]
+    fn test_rng_choose_chi_square() {
+        // 6 faces, 5 dof: p=0.001 critical value is ~20.52
+        let mut rng = Rng::with_seed(99);
+        let faces = [0usize, 1, 2, 3, 4, 5];
+        let chi2 = chi_square(6, 100_000, || *rng.choose(&faces).unwrap());
+        assert!(chi2 < 20.52, "choose chi-square {} too high", chi2);
+    }
+
+    #[test]
+    fn test_rng_dice_in_range_and_uniform() {
+        let mut rng = Rng::with_seed(11);
+        let stat = chi_square(6, 60_000, || {
+            let face = rng.dice(6);
+            assert!((1..=6).contains(&face));
+            face as usize - 1
+        });
+        // df = 5, p = 0.001
+        assert!(stat < 20.52, "chi-square {stat}");
+        assert_eq!(rng.dice(1), 1);
+        assert_eq!(rng.dice(0), 0);
+    }
+
+    #[test]
+    fn test_rng_roll_one_d_one() {
+        let mut rng = Rng::with_seed(3);
+        for _ in 0..100 {
+            assert_eq!(rng.roll("1d1").unwrap(), 1);
+        }
+    }
+
+    #[test]
+    fn test_rng_roll_range_and_modifiers() {
+        let mut rng = Rng::with_seed(5);
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..2000 {
+            let total = rng.roll("2d6+3").unwrap();
+            assert!((5..=15).contains(&total), "2d6+3 = {total}");
+            seen.insert(total);
+        }
+        assert_eq!(seen.len(), 11);
+
+        for _ in 0..200 {
+            let total = rng.roll("1d20-1").unwrap();
+            assert!((0..=19).contains(&total));
+            let total = rng.roll(" 3d4 + d8 - 1d2 + 10 ").unwrap();
+            assert!((12..=29).contains(&total), "{total}");
+        }
+        assert_eq!(rng.roll("-1d1+5").unwrap(), 4);
+    }
+
+    #[test]
+    fn test_rng_roll_deterministic() {
+        let mut a = Rng::with_seed(99);
+        let mut b = Rng::with_seed(99);
+        for _ in 0..50 {
+            assert_eq!(a.roll("4d10+2").unwrap(), b.roll("4d10+2").unwrap());
+        }
+    }
+
+    #[test]
+    fn test_rng_roll_malformed_errors() {
+        let mut rng = Rng::with_seed(1);
+        for bad in [
+            "",
+            "   ",
+            "d",
+            "2d",
+            "0d6",
+            "2d0",
+            "2d6+",
+            "+",
+            "2d6++1",
+            "3",
+            "5+2",
+            "2x6",
+            "d6d6",
+            "1001d6",
+            "2d6+a",
+            "2d-6",
+            "1.5d6",
+            "99999999999999999999d6",
+        ] {
+            assert!(rng.roll(bad).is_err(), "expected error for {bad:?}");
+        }
+        // Errors don't consume draws
+        let mut fresh = Rng::with_seed(1);
+        assert_eq!(rng.roll("3d6").unwrap(), fresh.roll("3d6").unwrap());
+    }
+
+    #[test]
+    fn test_rng_default_algorithm_is_chacha() {
+        assert_eq!(Rng::with_seed(1).algorithm(), RngAlgo::ChaCha);
+        let mut default = Rng::with_seed(5);
+        let mut chacha = Rng::with_seed(5).with_algorithm(RngAlgo::ChaCha);
+        for _ in 0..20 {
+            assert_eq!(
+                default.gaussian(0.0, 1.0).unwrap(),
+                chacha.gaussian(0.0, 1.0).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_rng_algorithms_deterministic_per_seed() {
+        for algorithm in [RngAlgo::ChaCha, RngAlgo::Fast] {
+            for stream_step in [None, Some(0.002)] {
+                let make = || {
+                    let rng = Rng::with_seed(123).with_algorithm(algorithm);
+                    match stream_step {
+                        Some(step) => rng.with_time_step(step),
+                        None => rng,
+                    }
+                };
+                let (mut a, mut b) = (make(), make());
+                for _ in 0..50 {
+                    assert_eq!(a.uniform(0.0, 1.0), b.uniform(0.0, 1.0));
+                    assert_eq!(a.gaussian(0.0, 1.0), b.gaussian(0.0, 1.0));
+                    assert_eq!(a.poisson(3.0), b.poisson(3.0));
+                    assert_eq!(a.dice(20), b.dice(20));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rng_fast_gaussian_differs_from_chacha() {
+        let mut chacha = Rng::with_seed(9);
+        let mut fast = Rng::with_seed(9).with_algorithm(RngAlgo::Fast);
+        let differs = (0..20).any(|_| chacha.gaussian(0.0, 1.0) != fast.gaussian(0.0, 1.0));
+        assert!(differs);
+    }
+
+    #[test]
+    fn test_rng_fast_uniform_range_and_chi_square() {
+        for stream_step in [None, Some(0.001)] {
+            let rng = Rng::with_seed(42).with_algorithm(RngAlgo::Fast);
+            let mut rng = match stream_step {
+                Some(step) => rng.with_time_step(step),
+                None => rng,
+            };
+            for _ in 0..100 {
+                let value = rng.uniform(5.0, 10.0);
+                assert!((5.0..=10.0).contains(&value));
+            }
+            let chi2 = chi_square(100, 100_000, || {
+                (rng.uniform(0.0, 100.0).floor() as usize).min(99)
+            });
+            assert!(chi2 < 148.2, "fast uniform chi-square {} too high", chi2);
+        }
+    }
+
+    #[test]
+    fn test_rng_fast_gaussian_moments() {
+        let mut rng = Rng::with_seed(7).with_algorithm(RngAlgo::Fast);
+        let n = 20_000;
+        let samples: Vec<f64> = (0..n)
+            .map(|_| rng.gaussian(2.0, 0.5).unwrap() as f64)
+            .collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        assert!((mean - 2.0).abs() < 0.02, "mean {}", mean);
+        assert!(
+            (variance.sqrt() - 0.5).abs() < 0.02,
+            "std dev {}",
+            variance.sqrt()
+        );
+    }
+
+    #[test]
+    fn test_rng_weighted_index_ratio() {
+        let mut rng = Rng::with_seed(2024);
+        let mut counts = [0usize; 2];
+        for _ in 0..5_500 {
+            counts[rng.weighted_index(&[10.0, 1.0]).unwrap()] += 1;
+        }
+        let ratio = counts[0] as f64 / counts[1] as f64;
+        assert!(
+            (8.0..12.5).contains(&ratio),
+            "ratio {} counts {:?}",
+            ratio,
+            counts
+        );
+    }
+
+    #[test]
+    fn test_rng_weighted_index_ignores_bad_weights() {
+        let mut rng = Rng::with_seed(5);
+        let weights = [0.0, -3.0, f32::NAN, 2.0, f32::INFINITY];
+        for _ in 0..500 {
+            assert_eq!(rng.weighted_index(&weights), Some(3));
+        }
+        assert_eq!(rng.weighted_index(&[0.0, -1.0, f32::NAN]), None);
+        assert_eq!(rng.weighted_index(&[]), None);
+    }
+
+    #[test]
+    fn test_rng_choose_weighted_uses_min_length() {
+        let mut rng = Rng::with_seed(17);
+        let items = ["a", "b", "c"];
+        // Third item has no weight; extra weights beyond items are ignored
+        for _ in 0..200 {
+            let pick = *rng.choose_weighted(&items, &[1.0, 1.0]).unwrap();
+            assert!(pick == "a" || pick == "b");
+        }
+        assert_eq!(rng.choose_weighted(&items[..1], &[0.0, 9.0]), None);
+        assert_eq!(rng.choose_weighted::<&str>(&[], &[1.0]), None);
+    }
+
+    #[test]
+    fn test_rng_choose_weighted_deterministic() {
+        let items = [1, 2, 3, 4];
+        let weights = [4.0, 3.0, 2.0, 1.0];
+        let draw = |seed| {
+            let mut rng = Rng::with_seed(seed);
+            (0..50)
+                .map(|_| *rng.choose_weighted(&items, &weights).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(draw(9), draw(9));
+        assert_ne!(draw(9), draw(10));
+    }
+
+    #[test]
+    fn test_rng_choose_multiple_distinct_and_deterministic() {
+        let items: Vec<u32> = (0..20).collect();
+        let draw = |seed| {
+            let mut rng = Rng::with_seed(seed);
+            rng.choose_multiple(&items, 5)
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>()
+        };
+        let picks = draw(3);
+        assert_eq!(picks, draw(3));
+        assert_ne!(picks, draw(4));
+
+        let mut sorted = picks.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 5, "picks must be distinct: {:?}", picks);
+    }
+
+    #[test]
+    fn test_rng_choose_multiple_edge_counts() {
+        let mut rng = Rng::with_seed(8);
+        let items = [1, 2, 3, 4];
+        assert!(rng.choose_multiple(&items, 0).is_empty());
+        assert!(rng.choose_multiple::<i32>(&[], 3).is_empty());
+
+        let mut all: Vec<i32> = rng
+            .choose_multiple(&items, 10)
+            .into_iter()
+            .copied()
+            .collect();
+        all.sort();
+        assert_eq!(all, items);
+    }
+
+    #[test]
+    fn test_rng_choose_multiple_indices_matches_partial_shuffle() {
+        // Sparse path must agree with a dense partial shuffle on the same stream
+        for (len, k) in [(1000, 7), (12, 10), (50, 1)] {
+            let mut a = Rng::with_seed(77);
+            let mut b = Rng::with_seed(77);
+            let picks = a.choose_multiple_indices(len, k);
+            let mut dense: Vec<usize> = (0..len).collect();
+            b.shuffle_partial(&mut dense, k);
+            assert_eq!(picks, dense[..k], "len={} k={}", len, k);
+        }
+    }
+
+    #[test]
+    fn test_rng_choose_multiple_chi_square() {
+        // Each of 10 indices should be picked equally often; 9 dof, p=0.001 ~ 27.88
+        let mut rng = Rng::with_seed(123);
+        let mut counts = [0usize; 10];
+        let rounds = 20_000;
+        for _ in 0..rounds {
+            for i in rng.choose_multiple_indices(10, 2) {
+                counts[i] += 1;
+            }
+        }
+        let expected = (rounds * 2) as f64 / 10.0;
+        let chi2: f64 = counts
+            .iter()
+            .map(|&c| (c as f64 - expected).powi(2) / expected)
+            .sum();
+        assert!(chi2 < 27.88, "choose_multiple chi-square {} too high", chi2);
+    }
 }
 
 // <FILE>src/rng.rs</FILE> - <DESC>Unified RNG API for common randomness patterns</DESC>
-// <VERS>END OF VERSION: 1.4.0</VERS>
+// <VERS>END OF VERSION: 1.10.1</VERS>
@@ -1,10 +1,10 @@
 // <FILE>src/shuffle/cls_overhand_animator.rs</FILE> - <DESC>Stepped overhand shuffle animator for frame-by-frame animation</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Implementing shuffle algorithms phases 2-4</WCTX>
-// <CLOG>Initial implementation of stepped overhand animator</CLOG>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>Exposing mid-animation shuffle state for rendering</WCTX>
+// <CLOG>Added hand/pile/packet accessors; decisions now match overhand_shuffle for the same seed</CLOG>
 
 use crate::rng::Rng;
-use std::collections::VecDeque;
+use std::ops::Range;
 
 /// Animation state for overhand shuffle.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,11 +17,28 @@ pub enum OverhandState {
     Complete,
 }
 
+/// Draw one pass of chunk sizes from `rng` exactly as
+/// [`overhand_shuffle`](super::overhand_shuffle) does.
+fn chunk_plan(len: usize, rng: &mut Rng) -> Vec<usize> {
+    let mut sizes = Vec::new();
+    let mut remaining = len;
+    while remaining > 0 {
+        let avg_chunk = (remaining / 5).max(1);
+        let u = rng.uniform(0.01, 1.0);
+        let raw_size = (-u.ln() * avg_chunk as f32).ceil() as usize;
+        let chunk_size = raw_size.clamp(1, remaining);
+        sizes.push(chunk_size);
+        remaining -= chunk_size;
+    }
+    sizes
+}
+
 /// Stepped overhand shuffle animator for frame-by-frame visualization.
 ///
 /// Unlike [`overhand_shuffle`](super::overhand_shuffle) which performs the shuffle
 /// immediately, this struct allows stepping through the animation one chunk at
-/// a time for visual effects.
+/// a time for visual effects. Given the same seed and pass count, the final
+/// order is identical to `overhand_shuffle`.
 ///
 /// # Usage Pattern
 ///
@@ -34,8 +51,11 @@ pub enum OverhandState {
 /// let mut animator = OverhandAnimator::new(deck, 1, &mut rng);
 ///
 /// while animator.step() {
-///     // Render current state: animator.items()
-///     // animator.current_chunk() shows the chunk being moved
+///     // Render the hand, the chunk in flight, and the growing pile:
+///     let (hand, chunk) = (animator.hand(), animator.current_chunk());
+///     let pile = animator.pile();
+///     assert_eq!(hand.len() + chunk.len() + pile.len(), 10);
+///     // animator.pile_packets() gives the chunk boundaries within the pile
 /// }
 ///
 /// let shuffled = animator.into_items();
@@ -46,83 +66,67 @@ pub enum OverhandState {
 /// 1. **TakingChunk**: A chunk is being cut from the back
 /// 2. **PlacingChunk**: The chunk is being placed on top
 /// 3. **Complete**: All chunks placed, pass complete
+///
+/// While a pass is in progress, [`hand`](Self::hand),
+/// [`current_chunk`](Self::current_chunk), and [`pile`](Self::pile) together
+/// hold every card exactly once; [`items`](Self::items) shows the deck as it
+/// was before the pass.
 #[derive(Debug, Clone)]
 pub struct OverhandAnimator<T> {
-    /// Current visible state
+    /// Deck at the start of the current pass (final order once complete)
     items: Vec<T>,
     /// Cards remaining in hand (back of deck)
     hand: Vec<T>,
-    /// Result pile (front of deck)
-    result: VecDeque<T>,
+    /// Result pile, top card first
+    pile: Vec<T>,
     /// Current chunk being transferred
     current_chunk: Vec<T>,
-    /// Pre-computed chunk sizes for this pass
-    chunk_sizes: Vec<usize>,
-    /// Index into chunk_sizes
+    /// Pre-computed chunk sizes for every pass
+    plan: Vec<Vec<usize>>,
+    /// Index of the pass in progress
+    pass_index: usize,
+    /// Index into the current pass's chunk sizes
     chunk_index: usize,
-    /// Remaining passes
-    passes_remaining: usize,
     /// Current animation state
     state: OverhandState,
-    /// Stored RNG seed for deterministic replay
-    seed: u64,
 }
 
 impl<T: Clone> OverhandAnimator<T> {
     /// Create a new overhand animator.
     ///
-    /// Random decisions are pre-computed for deterministic replay.
+    /// Random decisions for all passes are pre-computed for deterministic
+    /// replay, consuming `rng` the same way [`overhand_shuffle`](super::overhand_shuffle) would.
     pub fn new(items: Vec<T>, passes: usize, rng: &mut Rng) -> Self {
         let len = items.len();
-        let seed = rng.uniform(0.0, u32::MAX as f32) as u64;
+        let plan = if len <= 1 {
+            Vec::new()
+        } else {
+            (0..passes).map(|_| chunk_plan(len, rng)).collect()
+        };
 
         let mut animator = Self {
             items,
             hand: Vec::new(),
-            result: VecDeque::new(),
+            pile: Vec::new(),
             current_chunk: Vec::new(),
-            chunk_sizes: Vec::new(),
+            plan,
+            pass_index: 0,
             chunk_index: 0,
-            passes_remaining: passes,
-            state: if passes == 0 || len <= 1 {
-                OverhandState::Complete
-            } else {
-                OverhandState::TakingChunk
-            },
-            seed,
+            state: OverhandState::Complete,
         };
 
-        if animator.state != OverhandState::Complete {
-            animator.prepare_pass(rng);
+        if !animator.plan.is_empty() {
+            animator.begin_pass();
         }
 
         animator
     }
 
-    /// Prepare decisions for a single pass.
-    fn prepare_pass(&mut self, rng: &mut Rng) {
-        let len = self.items.len();
-        if len <= 1 {
-            self.state = OverhandState::Complete;
-            return;
-        }
-
+    /// Pick up the whole deck for the current pass.
+    fn begin_pass(&mut self) {
         self.hand = self.items.clone();
-        self.result.clear();
-        self.chunk_sizes.clear();
+        self.pile.clear();
         self.chunk_index = 0;
-
-        // Pre-compute chunk sizes
-        let mut remaining = len;
-        while remaining > 0 {
-            let avg_chunk = (remaining / 5).max(1);
-            let u = rng.uniform(0.01, 1.0);
-            let raw_size = (-u.ln() * avg_chunk as f32).ceil() as usize;
-            let chunk_size = raw_size.clamp(1, remaining);
-            self.chunk_sizes.push(chunk_size);
-            remaining -= chunk_size;
-        }
-
         self.state = OverhandState::TakingChunk;
     }
 
@@ -134,25 +138,23 @@ impl<T: Clone> OverhandAnimator<T> {
             OverhandState::Complete => false,
 
             OverhandState::TakingChunk => {
-                if self.chunk_index >= self.chunk_sizes.len() {
+                let sizes = &self.plan[self.pass_index];
+                if self.chunk_index >= sizes.len() {
                     // Pass complete
-                    self.items = self.result.drain(..).collect();
-                    self.passes_remaining -= 1;
+                    self.items = std::mem::take(&mut self.pile);
+                    self.pass_index += 1;
 
-                    if self.passes_remaining == 0 {
+                    if self.pass_index >= self.plan.len() {
                         self.state = OverhandState::Complete;
                         return false;
                     }
 
-                    // Prepare next pass
-                    let mut rng =
-                        Rng::with_seed(self.seed.wrapping_add(self.passes_remaining as u64));
-                    self.prepare_pass(&mut rng);
+                    self.begin_pass();
                     return true;
                 }
 
                 // Take chunk from back of hand
-                let chunk_size = self.chunk_sizes[self.chunk_index];
+                let chunk_size = sizes[self.chunk_index];
                 let chunk_start = self.hand.len().saturating_sub(chunk_size);
                 self.current_chunk = self.hand.drain(chunk_start..).collect();
 
@@ -161,10 +163,8 @@ impl<T: Clone> OverhandAnimator<T> {
             }
 
             OverhandState::PlacingChunk => {
-                // Place current chunk on top of result without extra clones
-                while let Some(value) = self.current_chunk.pop() {
-                    self.result.push_front(value);
-                }
+                // Place current chunk on top of the pile without extra clones
+                self.pile.splice(0..0, self.current_chunk.drain(..));
                 self.chunk_index += 1;
 
                 self.state = OverhandState::TakingChunk;
@@ -183,6 +183,52 @@ impl<T: Clone> OverhandAnimator<T> {
         &self.current_chunk
     }
 
+    /// Cards still in hand; chunks are taken from the back.
+    pub fn hand(&self) -> &[T] {
+        &self.hand
+    }
+
+    /// Cards placed so far in the current pass, top card first.
+    pub fn pile(&self) -> &[T] {
+        &self.pile
+    }
+
+    /// Index ranges into [`pile`](Self::pile) for each placed chunk, top first.
+    pub fn pile_packets(&self) -> Vec<Range<usize>> {
+        let placed = match self.plan.get(self.pass_index) {
+            Some(sizes) if self.state != OverhandState::Complete => &sizes[..self.chunk_index],
+            _ => &[],
+        };
+        let mut start = 0;
+        placed
+            .iter()
+            .rev()
+            .map(|&size| {
+                let range = start..start + size;
+                start += size;
+                range
+            })
+            .collect()
+    }
+
+    /// Chunks not yet taken from the hand in the current pass.
+    pub fn chunks_remaining(&self) -> usize {
+        match self.plan.get(self.pass_index) {
+            Some(sizes) if self.state == OverhandState::TakingChunk => {
+                sizes.len() - self.chunk_index
+            }
+            Some(sizes) if self.state == OverhandState::PlacingChunk => {
+                sizes.len() - self.chunk_index - 1
+            }
+            _ => 0,
+        }
+    }
+
+    /// Number of passes fully completed so far.
+    pub fn passes_completed(&self) -> usize {
+        self.pass_index
+    }
+
     /// Get current animation state.
     pub fn state(&self) -> OverhandState {
         self.state
@@ -200,4 +246,4 @@ impl<T: Clone> OverhandAnimator<T> {
 }
 
 // <FILE>src/shuffle/cls_overhand_animator.rs</FILE> - <DESC>Stepped overhand shuffle animator for frame-by-frame animation</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -0,0 +1,246 @@
+// <FILE>src/shuffle/cls_random_permutation.rs</FILE> - <DESC>Allocation-free seeded permutation of 0..n</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Shuffling huge ranges without materializing them</WCTX>
+// <CLOG>Initial implementation via cycle-walking Feistel network</CLOG>
+
+/// Feistel rounds; 6 is plenty for visual randomness and still cheap.
+const ROUNDS: usize = 6;
+
+/// Seeded pseudo-random permutation of `0..n` that never allocates.
+///
+/// Visits every index in `0..n` exactly once in a shuffled, deterministic
+/// order, without building an n-element array. Any position can be looked up
+/// directly with [`nth_index`](Self::nth_index), and any index mapped back to
+/// its position with [`inverse`](Self::inverse).
+///
+/// # Algorithm
+///
+/// A balanced Feistel network over the smallest even bit width covering `n`
+/// gives a bijection on `0..2^(2h)`. Cycle-walking (re-applying the network
+/// until the result lands below `n`) restricts it to `0..n`. The domain is
+/// less than `4n`, so a lookup takes a handful of network passes on average.
+///
+/// - Time complexity: O(1) expected per lookup
+/// - Space complexity: O(1)
+///
+/// The order is well mixed but not cryptographically uniform over all `n!`
+/// permutations; use [`fisher_yates`](super::fisher_yates) when that matters.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::shuffle::RandomPermutation;
+///
+/// let perm = RandomPermutation::new(10_000_000, 42);
+/// let fifth = perm.nth_index(5).unwrap();
+/// assert_eq!(perm.inverse(fifth), Some(5));
+///
+/// let first_three: Vec<usize> = RandomPermutation::new(10, 7).take(3).collect();
+/// assert_eq!(first_three.len(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RandomPermutation {
+    len: usize,
+    seed: u64,
+    half_bits: u32,
+    keys: [u64; ROUNDS],
+    position: usize,
+}
+
+impl RandomPermutation {
+    /// Create a permutation of `0..len` determined by `seed`.
+    pub fn new(len: usize, seed: u64) -> Self {
+        let bits = usize::BITS - len.saturating_sub(1).leading_zeros();
+        let half_bits = bits.div_ceil(2).max(1);
+
+        let mut keys = [0u64; ROUNDS];
+        let mut state = seed;
+        for key in keys.iter_mut() {
+            state = state.wrapping_add(0x9e3779b97f4a7c15);
+            *key = mix(state);
+        }
+
+        Self {
+            len,
+            seed,
+            half_bits,
+            keys,
+            position: 0,
+        }
+    }
+
+    /// Number of indices in the permutation.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True when the permutation covers no indices.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Seed the permutation was created with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The index at position `i`, or `None` if `i >= len`.
+    pub fn nth_index(&self, i: usize) -> Option<usize> {
+        if i >= self.len {
+            return None;
+        }
+        let mut x = i as u64;
+        loop {
+            x = self.encrypt(x);
+            if x < self.len as u64 {
+                return Some(x as usize);
+            }
+        }
+    }
+
+    /// The position at which `index` appears, or `None` if `index >= len`.
+    pub fn inverse(&self, index: usize) -> Option<usize> {
+        if index >= self.len {
+            return None;
+        }
+        let mut x = index as u64;
+        loop {
+            x = self.decrypt(x);
+            if x < self.len as u64 {
+                return Some(x as usize);
+            }
+        }
+    }
+
+    /// Restart iteration from position 0.
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+
+    fn mask(&self) -> u64 {
+        (1u64 << self.half_bits) - 1
+    }
+
+    fn round(&self, key: u64, half: u64) -> u64 {
+        mix(key ^ half) & self.mask()
+    }
+
+    fn encrypt(&self, x: u64) -> u64 {
+        let mask = self.mask();
+        let (mut left, mut right) = (x >> self.half_bits, x & mask);
+        for &key in &self.keys {
+            let next = left ^ self.round(key, right);
+            left = right;
+            right = next;
+        }
+        (left << self.half_bits) | right
+    }
+
+    fn decrypt(&self, x: u64) -> u64 {
+        let mask = self.mask();
+        let (mut left, mut right) = (x >> self.half_bits, x & mask);
+        for &key in self.keys.iter().rev() {
+            let prev = right ^ self.round(key, left);
+            right = left;
+            left = prev;
+        }
+        (left << self.half_bits) | right
+    }
+}
+
+impl Iterator for RandomPermutation {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let index = self.nth_index(self.position)?;
+        self.position += 1;
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.position;
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<usize> {
+        self.position = self.position.saturating_add(n).min(self.len);
+        self.next()
+    }
+}
+
+impl ExactSizeIterator for RandomPermutation {}
+
+/// SplitMix64 finalizer.
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_permutation(len: usize, seed: u64) {
+        let perm = RandomPermutation::new(len, seed);
+        let mut seen = vec![false; len];
+        for (pos, index) in perm.clone().enumerate() {
+            assert!(index < len, "n={} produced {}", len, index);
+            assert!(!seen[index], "n={} repeated {}", len, index);
+            seen[index] = true;
+            assert_eq!(perm.inverse(index), Some(pos));
+        }
+        assert!(seen.iter().all(|&s| s), "n={} missed an index", len);
+    }
+
+    #[test]
+    fn test_is_true_permutation() {
+        for len in [1, 2, 3, 7, 16, 17, 1000, 4097] {
+            assert_permutation(len, 42);
+        }
+        assert_permutation(1000, 0);
+        assert_permutation(1000, u64::MAX);
+    }
+
+    #[test]
+    fn test_deterministic_per_seed() {
+        let a: Vec<usize> = RandomPermutation::new(500, 9).collect();
+        let b: Vec<usize> = RandomPermutation::new(500, 9).collect();
+        let c: Vec<usize> = RandomPermutation::new(500, 10).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, (0..500).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_random_access_matches_iteration() {
+        let perm = RandomPermutation::new(10_000_000, 3);
+        let mut iter = perm.clone();
+        for i in 0..100 {
+            assert_eq!(iter.next(), perm.nth_index(i));
+        }
+        assert_eq!(perm.nth_index(10_000_000), None);
+        assert_eq!(perm.inverse(10_000_000), None);
+
+        let last = perm.nth_index(9_999_999).unwrap();
+        assert_eq!(perm.inverse(last), Some(9_999_999));
+    }
+
+    #[test]
+    fn test_empty_and_size_hint() {
+        let mut empty = RandomPermutation::new(0, 1);
+        assert!(empty.is_empty());
+        assert_eq!(empty.next(), None);
+
+        let mut perm = RandomPermutation::new(7, 1);
+        assert_eq!(perm.len(), 7);
+        perm.next();
+        assert_eq!(perm.size_hint(), (6, Some(6)));
+        assert_eq!(perm.by_ref().count(), 6);
+        perm.rewind();
+        assert_eq!(perm.count(), 7);
+    }
+}
+
+// <FILE>src/shuffle/cls_random_permutation.rs</FILE> - <DESC>Allocation-free seeded permutation of 0..n</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
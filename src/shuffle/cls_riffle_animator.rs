@@ -1,15 +1,14 @@
 // <FILE>src/shuffle/cls_riffle_animator.rs</FILE> - <DESC>Stepped riffle shuffle animator for frame-by-frame animation</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Implementing shuffle algorithms phases 2-4</WCTX>
-// <CLOG>Initial implementation of stepped riffle animator</CLOG>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>Exposing mid-animation shuffle state for rendering</WCTX>
+// <CLOG>Added packet/source accessors; decisions now match riffle_shuffle for the same seed</CLOG>
 
 use crate::rng::Rng;
-use std::collections::VecDeque;
 
 /// Animation state for riffle shuffle.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RiffleState {
-    /// About to cut the deck
+    /// Deck has been cut into two packets, nothing dropped yet
     Cutting,
     /// Dropping cards from left and right halves
     Dropping,
@@ -17,11 +16,60 @@ pub enum RiffleState {
     Complete,
 }
 
+/// Which packet a riffled card was dropped from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiffleSource {
+    /// The top part of the cut (`items()[..cut_point]`)
+    Left,
+    /// The bottom part of the cut (`items()[cut_point..]`)
+    Right,
+}
+
+/// Pre-computed random decisions for one riffle pass.
+#[derive(Debug, Clone)]
+struct RifflePass {
+    cut: usize,
+    drops: Vec<RiffleSource>,
+}
+
+impl RifflePass {
+    /// Draw a pass from `rng` exactly as [`riffle_shuffle`](super::riffle_shuffle) does.
+    fn generate(len: usize, rng: &mut Rng) -> Self {
+        // Cut point
+        let cut_min = (len / 4).max(1);
+        let cut_max = (3 * len / 4).min(len - 1);
+        let cut = (rng.uniform(cut_min as f32, cut_max as f32).floor() as usize).clamp(1, len - 1);
+
+        // GSR drops: probability proportional to cards remaining in each packet
+        let mut l_remaining = cut;
+        let mut r_remaining = len - cut;
+        let mut drops = Vec::with_capacity(len);
+        while l_remaining > 0 || r_remaining > 0 {
+            let total = l_remaining + r_remaining;
+            let p_left = l_remaining as f32 / total as f32;
+
+            if rng.uniform(0.0, 1.0) < p_left && l_remaining > 0 {
+                l_remaining -= 1;
+                drops.push(RiffleSource::Left);
+            } else if r_remaining > 0 {
+                r_remaining -= 1;
+                drops.push(RiffleSource::Right);
+            } else {
+                l_remaining -= 1;
+                drops.push(RiffleSource::Left);
+            }
+        }
+
+        Self { cut, drops }
+    }
+}
+
 /// Stepped riffle shuffle animator for frame-by-frame visualization.
 ///
 /// Unlike [`riffle_shuffle`](super::riffle_shuffle) which performs the shuffle
 /// immediately, this struct allows stepping through the animation one card at
-/// a time for visual effects.
+/// a time for visual effects. Given the same seed and pass count, the final
+/// order is identical to `riffle_shuffle`.
 ///
 /// # Usage Pattern
 ///
@@ -34,8 +82,11 @@ pub enum RiffleState {
 /// let mut animator = RiffleAnimator::new(deck, 1, &mut rng);
 ///
 /// while animator.step() {
-///     // Render current state: animator.items()
-///     // animator.state() tells you what phase we're in
+///     // Render the two packets and the pile between them:
+///     let (left, right) = (animator.left_packet(), animator.right_packet());
+///     let pile = animator.merged();
+///     assert_eq!(left.len() + right.len() + pile.len(), 10);
+///     // animator.next_source() tells you which packet drops next
 /// }
 ///
 /// let shuffled = animator.into_items();
@@ -46,99 +97,80 @@ pub enum RiffleState {
 /// 1. **Cutting**: Deck is split into two halves
 /// 2. **Dropping**: Cards drop one at a time from alternating halves
 /// 3. **Complete**: All cards dropped, pass complete (may restart for more passes)
+///
+/// While a pass is in progress, [`left_packet`](Self::left_packet),
+/// [`merged`](Self::merged), and [`right_packet`](Self::right_packet) together
+/// hold every card exactly once; [`items`](Self::items) shows the deck as it
+/// was before the pass.
 #[derive(Debug, Clone)]
 pub struct RiffleAnimator<T> {
-    /// Current visible state
+    /// Deck at the start of the current pass (final order once complete)
     items: Vec<T>,
-    /// Left half (being depleted during drop)
-    left: VecDeque<T>,
-    /// Right half (being depleted during drop)
-    right: VecDeque<T>,
+    /// Left half, consumed from the front during drop
+    left: Vec<T>,
+    /// Right half, consumed from the front during drop
+    right: Vec<T>,
+    /// Cards already dropped from the left half
+    left_dropped: usize,
+    /// Cards already dropped from the right half
+    right_dropped: usize,
     /// Result being built
-    result: Vec<T>,
-    /// Pre-computed drop decisions (true = drop from left)
-    drop_from_left: Vec<bool>,
-    /// Index into drop decisions
+    merged: Vec<T>,
+    /// Pre-computed decisions for every pass
+    plan: Vec<RifflePass>,
+    /// Index of the pass in progress
+    pass_index: usize,
+    /// Index into the current pass's drop decisions
     drop_index: usize,
-    /// Remaining passes
-    passes_remaining: usize,
     /// Current animation state
     state: RiffleState,
-    /// Stored RNG seed for deterministic replay
-    seed: u64,
 }
 
 impl<T: Clone> RiffleAnimator<T> {
     /// Create a new riffle animator.
     ///
-    /// Random decisions are pre-computed for deterministic replay.
+    /// Random decisions for all passes are pre-computed for deterministic
+    /// replay, consuming `rng` the same way [`riffle_shuffle`](super::riffle_shuffle) would.
     pub fn new(items: Vec<T>, passes: usize, rng: &mut Rng) -> Self {
         let len = items.len();
-        let seed = rng.uniform(0.0, u32::MAX as f32) as u64;
+        let plan = if len <= 1 {
+            Vec::new()
+        } else {
+            (0..passes)
+                .map(|_| RifflePass::generate(len, rng))
+                .collect()
+        };
 
         let mut animator = Self {
             items,
-            left: VecDeque::new(),
-            right: VecDeque::new(),
-            result: Vec::new(),
-            drop_from_left: Vec::new(),
+            left: Vec::new(),
+            right: Vec::new(),
+            left_dropped: 0,
+            right_dropped: 0,
+            merged: Vec::new(),
+            plan,
+            pass_index: 0,
             drop_index: 0,
-            passes_remaining: passes,
-            state: if passes == 0 || len <= 1 {
-                RiffleState::Complete
-            } else {
-                RiffleState::Cutting
-            },
-            seed,
+            state: RiffleState::Complete,
         };
 
-        if animator.state != RiffleState::Complete {
-            animator.prepare_pass(rng);
+        if !animator.plan.is_empty() {
+            animator.begin_pass();
         }
 
         animator
     }
 
-    /// Prepare decisions for a single pass.
-    fn prepare_pass(&mut self, rng: &mut Rng) {
-        let len = self.items.len();
-        if len <= 1 {
-            self.state = RiffleState::Complete;
-            return;
-        }
-
-        // Cut point
-        let cut_min = (len / 4).max(1);
-        let cut_max = (3 * len / 4).min(len - 1);
-        let cut = (rng.uniform(cut_min as f32, cut_max as f32).floor() as usize).clamp(1, len - 1);
-
-        self.left = self.items[..cut].iter().cloned().collect();
-        self.right = self.items[cut..].iter().cloned().collect();
-        self.result.clear();
-        self.drop_from_left.clear();
+    /// Cut the deck for the current pass.
+    fn begin_pass(&mut self) {
+        let cut = self.plan[self.pass_index].cut;
+        self.left = self.items[..cut].to_vec();
+        self.right = self.items[cut..].to_vec();
+        self.left_dropped = 0;
+        self.right_dropped = 0;
+        self.merged.clear();
         self.drop_index = 0;
-
-        // Pre-compute all drop decisions
-        let mut l_remaining = self.left.len();
-        let mut r_remaining = self.right.len();
-
-        while l_remaining > 0 || r_remaining > 0 {
-            let total = l_remaining + r_remaining;
-            let p_left = l_remaining as f32 / total as f32;
-            let from_left = rng.uniform(0.0, 1.0) < p_left && l_remaining > 0;
-
-            if from_left {
-                l_remaining -= 1;
-            } else if r_remaining > 0 {
-                r_remaining -= 1;
-            } else {
-                l_remaining -= 1;
-            }
-            self.drop_from_left
-                .push(from_left || r_remaining == 0 && l_remaining > 0);
-        }
-
-        self.state = RiffleState::Dropping;
+        self.state = RiffleState::Cutting;
     }
 
     /// Advance animation by one step.
@@ -149,41 +181,37 @@ impl<T: Clone> RiffleAnimator<T> {
             RiffleState::Complete => false,
 
             RiffleState::Cutting => {
-                // Cutting happens in prepare_pass, just transition
                 self.state = RiffleState::Dropping;
                 true
             }
 
             RiffleState::Dropping => {
-                if self.drop_index >= self.drop_from_left.len() {
+                let Some(source) = self.next_source() else {
                     // Pass complete
-                    self.items = std::mem::take(&mut self.result);
-                    self.passes_remaining -= 1;
+                    self.items = std::mem::take(&mut self.merged);
+                    self.left.clear();
+                    self.right.clear();
+                    self.pass_index += 1;
 
-                    if self.passes_remaining == 0 {
+                    if self.pass_index >= self.plan.len() {
                         self.state = RiffleState::Complete;
                         return false;
                     }
 
-                    // Prepare next pass (need fresh RNG state)
-                    let mut rng =
-                        Rng::with_seed(self.seed.wrapping_add(self.passes_remaining as u64));
-                    self.prepare_pass(&mut rng);
+                    self.begin_pass();
                     return true;
-                }
+                };
 
                 // Drop one card
-                let from_left = self.drop_from_left[self.drop_index];
-                if from_left {
-                    if let Some(value) = self.left.pop_front() {
-                        self.result.push(value);
-                    } else if let Some(value) = self.right.pop_front() {
-                        self.result.push(value);
+                match source {
+                    RiffleSource::Left => {
+                        self.merged.push(self.left[self.left_dropped].clone());
+                        self.left_dropped += 1;
+                    }
+                    RiffleSource::Right => {
+                        self.merged.push(self.right[self.right_dropped].clone());
+                        self.right_dropped += 1;
                     }
-                } else if let Some(value) = self.right.pop_front() {
-                    self.result.push(value);
-                } else if let Some(value) = self.left.pop_front() {
-                    self.result.push(value);
                 }
 
                 self.drop_index += 1;
@@ -197,6 +225,55 @@ impl<T: Clone> RiffleAnimator<T> {
         &self.items
     }
 
+    /// Cards still waiting in the left packet, next to drop first.
+    pub fn left_packet(&self) -> &[T] {
+        &self.left[self.left_dropped.min(self.left.len())..]
+    }
+
+    /// Cards still waiting in the right packet, next to drop first.
+    pub fn right_packet(&self) -> &[T] {
+        &self.right[self.right_dropped.min(self.right.len())..]
+    }
+
+    /// Cards dropped so far in the current pass, in pile order.
+    pub fn merged(&self) -> &[T] {
+        &self.merged
+    }
+
+    /// Size of the left packet at the cut (0 when complete).
+    pub fn cut_point(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Which packet each card in [`merged`](Self::merged) came from.
+    pub fn sources(&self) -> &[RiffleSource] {
+        match self.plan.get(self.pass_index) {
+            Some(pass) if self.state != RiffleState::Complete => &pass.drops[..self.drop_index],
+            _ => &[],
+        }
+    }
+
+    /// Packet the most recently dropped card came from.
+    pub fn last_source(&self) -> Option<RiffleSource> {
+        self.sources().last().copied()
+    }
+
+    /// Packet the next card will drop from, or `None` when no drop is pending.
+    pub fn next_source(&self) -> Option<RiffleSource> {
+        if self.state == RiffleState::Complete {
+            return None;
+        }
+        self.plan
+            .get(self.pass_index)
+            .and_then(|pass| pass.drops.get(self.drop_index))
+            .copied()
+    }
+
+    /// Number of passes fully completed so far.
+    pub fn passes_completed(&self) -> usize {
+        self.pass_index
+    }
+
     /// Get current animation state.
     pub fn state(&self) -> RiffleState {
         self.state
@@ -214,4 +291,4 @@ impl<T: Clone> RiffleAnimator<T> {
 }
 
 // <FILE>src/shuffle/cls_riffle_animator.rs</FILE> - <DESC>Stepped riffle shuffle animator for frame-by-frame animation</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.1.0</VERS>
@@ -1,10 +1,38 @@
 // <FILE>src/shuffle/fnc_constrained_shuffle.rs</FILE> - <DESC>Variety-enforced constrained shuffle algorithm</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Implementing shuffle algorithms phases 2-4</WCTX>
-// <CLOG>Initial implementation with retry-based constraint satisfaction</CLOG>
+// <VERS>VERSION: 2.0.0</VERS>
+// <WCTX>Scalable constrained shuffle with error reporting</WCTX>
+// <CLOG>Bucketed greedy with feasibility lookahead; added try_constrained_shuffle and ConstraintError</CLOG>
 
 use crate::rng::Rng;
 use crate::shuffle::fisher_yates;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// The run limit of a constrained shuffle could not be met.
+///
+/// Returned by [`try_constrained_shuffle`]. The slice still holds a full
+/// permutation of its items, arranged with as few violations as the greedy
+/// pass could manage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintError {
+    /// Positions whose item extends a run past `max_consecutive`.
+    pub violations: Vec<usize>,
+    /// The run limit that was requested (after clamping to at least 1).
+    pub max_consecutive: usize,
+}
+
+impl fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "constrained shuffle left {} position(s) exceeding max_consecutive = {}",
+            self.violations.len(),
+            self.max_consecutive
+        )
+    }
+}
+
+impl std::error::Error for ConstraintError {}
 
 /// Constrained shuffle with variety enforcement.
 ///
@@ -12,16 +40,9 @@ use crate::shuffle::fisher_yates;
 /// same category appear in a row. Categories are determined by the classifier
 /// function.
 ///
-/// # Algorithm
-///
-/// Uses retry-based constraint satisfaction:
-/// 1. Perform Fisher-Yates shuffle
-/// 2. Scan for violations (runs > max_consecutive)
-/// 3. Swap violating elements with valid positions
-/// 4. Repeat until satisfied or max attempts reached
-///
-/// - Time complexity: O(n²) worst case
-/// - Space complexity: O(n) for category tracking
+/// This is the best-effort form of [`try_constrained_shuffle`]: if the
+/// constraint is impossible (e.g. too many items of one category), the slice
+/// is left with as few violations as possible and no error is reported.
 ///
 /// # Arguments
 ///
@@ -30,11 +51,6 @@ use crate::shuffle::fisher_yates;
 /// * `max_consecutive` - Maximum allowed consecutive items of same category
 /// * `classifier` - Function that returns category ID for each element
 ///
-/// # Best-Effort Behavior
-///
-/// If constraints cannot be fully satisfied (e.g., too many items of one category),
-/// returns the best-effort result with minimum violations. Does not panic.
-///
 /// # Example
 ///
 /// ```rust
@@ -63,143 +79,267 @@ pub fn constrained_shuffle<T, F>(
     classifier: F,
 ) where
     F: Fn(&T) -> usize,
+{
+    // Best-effort: the arrangement is applied either way
+    let _ = try_constrained_shuffle(slice, rng, max_consecutive, classifier);
+}
+
+/// Constrained shuffle that reports when the run limit cannot be met.
+///
+/// Same contract as [`constrained_shuffle`], but returns
+/// [`ConstraintError`] listing the violating positions when no arrangement
+/// can keep every run within `max_consecutive`.
+///
+/// # Algorithm
+///
+/// Bucketed greedy with feasibility lookahead:
+/// 1. Classify every item once and shuffle each category's bucket
+/// 2. Build the output one slot at a time, drawing a category weighted by
+///    its remaining count (so unconstrained input looks like a plain shuffle)
+/// 3. Accept the draw only if the remaining items can still be arranged
+///    legally; a category can follow itself only while its run is short, and
+///    the largest category can never outgrow the gaps the others leave
+/// 4. Otherwise fall back to the largest categories, which are the ones the
+///    lookahead protects
+///
+/// - Time complexity: O(n log c) for c categories (O(n·c) in rare fallbacks)
+/// - Space complexity: O(n)
+///
+/// A solution exists exactly when the largest category's count `m` satisfies
+/// `m <= max_consecutive * (n - m + 1)`; the greedy pass never gets stuck
+/// when it does.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::rng::Rng;
+/// use mixed_signals::shuffle::try_constrained_shuffle;
+///
+/// let mut rng = Rng::with_seed(42);
+/// let mut ok = vec!['A', 'A', 'B', 'B', 'C'];
+/// assert!(try_constrained_shuffle(&mut ok, &mut rng, 1, |c| *c as usize).is_ok());
+///
+/// let mut impossible = vec!['A', 'A', 'A', 'B'];
+/// let err = try_constrained_shuffle(&mut impossible, &mut rng, 1, |c| *c as usize);
+/// assert!(err.is_err());
+/// ```
+pub fn try_constrained_shuffle<T, F>(
+    slice: &mut [T],
+    rng: &mut Rng,
+    max_consecutive: usize,
+    classifier: F,
+) -> Result<(), ConstraintError>
+where
+    F: Fn(&T) -> usize,
 {
     let len = slice.len();
+    let k = max_consecutive.max(1); // At least 1 allowed
     if len <= 1 {
-        return;
+        return Ok(());
     }
 
-    let max_consecutive = max_consecutive.max(1); // At least 1 allowed
-    let max_attempts = len * 10;
+    // Dense category ids in order of first appearance (deterministic)
+    let mut dense: HashMap<usize, usize> = HashMap::new();
+    let mut buckets: Vec<Vec<usize>> = Vec::new();
+    for (i, item) in slice.iter().enumerate() {
+        let next_id = buckets.len();
+        let id = *dense.entry(classifier(item)).or_insert(next_id);
+        if id == next_id {
+            buckets.push(Vec::new());
+        }
+        buckets[id].push(i);
+    }
+    for bucket in buckets.iter_mut() {
+        fisher_yates(bucket, rng);
+    }
 
-    // Initial shuffle
-    fisher_yates(slice, rng);
+    let mut state = Counts::new(buckets.iter().map(Vec::len).collect());
+    let mut order = Vec::with_capacity(len);
+    let mut last: Option<(usize, usize)> = None; // (category, run length)
 
-    // Iteratively fix violations
-    for _ in 0..max_attempts {
-        if let Some(violation_idx) = find_violation(slice, max_consecutive, &classifier) {
-            // Try to fix by swapping with a valid position
-            if !try_fix_violation(slice, violation_idx, max_consecutive, &classifier, rng) {
-                // Couldn't fix this violation, continue trying
-                continue;
-            }
+    while state.remaining > 0 {
+        let drawn = state.sample(rng);
+        let cat = if state.can_place(drawn, last, k) {
+            drawn
         } else {
-            // No violations found
-            return;
-        }
-    }
-    // Best-effort: return whatever we have
-}
+            state.fallback(last, k)
+        };
 
-/// Find the first index where a run violation occurs.
-fn find_violation<T, F>(slice: &[T], max_consecutive: usize, classifier: &F) -> Option<usize>
-where
-    F: Fn(&T) -> usize,
-{
-    if slice.len() < 2 {
-        return None;
+        order.push(buckets[cat].pop().expect("category has remaining items"));
+        state.take(cat);
+        last = match last {
+            Some((prev, run)) if prev == cat => Some((cat, run + 1)),
+            _ => Some((cat, 1)),
+        };
     }
 
-    let mut run_length = 1;
-    let mut prev_cat = classifier(&slice[0]);
+    apply_order(slice, &order);
 
-    for (i, item) in slice.iter().enumerate().skip(1) {
-        let cat = classifier(item);
-        if cat == prev_cat {
-            run_length += 1;
-            if run_length > max_consecutive {
-                return Some(i);
-            }
-        } else {
-            run_length = 1;
-            prev_cat = cat;
-        }
+    let categories: Vec<usize> = slice.iter().map(&classifier).collect();
+    let violations = run_violations(&categories, k);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ConstraintError {
+            violations,
+            max_consecutive: k,
+        })
     }
-    None
 }
 
-/// Try to fix a violation by swapping with a valid position.
-fn try_fix_violation<T, F>(
-    slice: &mut [T],
-    violation_idx: usize,
-    max_consecutive: usize,
-    classifier: &F,
-    rng: &mut Rng,
-) -> bool
-where
-    F: Fn(&T) -> usize,
-{
-    let violation_cat = classifier(&slice[violation_idx]);
-    let len = slice.len();
+/// Remaining item counts per category, indexed for weighted draws and
+/// largest-category queries.
+struct Counts {
+    counts: Vec<usize>,
+    /// Fenwick tree over `counts` for O(log c) weighted sampling
+    tree: Vec<usize>,
+    /// (count, category) ordered by size for largest-category lookups
+    by_size: BTreeSet<(usize, usize)>,
+    remaining: usize,
+}
 
-    // Find valid swap candidates (different category, won't create new violation)
-    let candidates: Vec<usize> = (0..len)
-        .filter(|&i| {
-            if i == violation_idx {
-                return false;
+impl Counts {
+    fn new(counts: Vec<usize>) -> Self {
+        let mut tree = vec![0; counts.len() + 1];
+        for (cat, &count) in counts.iter().enumerate() {
+            let mut i = cat + 1;
+            while i < tree.len() {
+                tree[i] += count;
+                i += i & i.wrapping_neg();
             }
-            let cat = classifier(&slice[i]);
-            if cat == violation_cat {
-                return false;
+        }
+        let by_size = counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c > 0)
+            .map(|(cat, &c)| (c, cat))
+            .collect();
+        let remaining = counts.iter().sum();
+        Self {
+            counts,
+            tree,
+            by_size,
+            remaining,
+        }
+    }
+
+    /// Draw a category with probability proportional to its remaining count.
+    fn sample(&self, rng: &mut Rng) -> usize {
+        let target =
+            (rng.uniform(0.0, self.remaining as f32).floor() as usize).min(self.remaining - 1);
+        // Fenwick descent: find the first category whose prefix sum exceeds target
+        let mut pos = 0;
+        let mut rest = target;
+        let mut step = (self.tree.len() - 1).next_power_of_two();
+        while step > 0 {
+            let next = pos + step;
+            if next < self.tree.len() && self.tree[next] <= rest {
+                pos = next;
+                rest -= self.tree[next];
             }
-            // Check if swapping wouldn't create a new violation at position i
-            would_be_valid_at(slice, i, violation_idx, max_consecutive, classifier)
-        })
-        .collect();
+            step >>= 1;
+        }
+        pos
+    }
 
-    if candidates.is_empty() {
-        return false;
+    fn take(&mut self, cat: usize) {
+        let count = self.counts[cat];
+        self.by_size.remove(&(count, cat));
+        if count > 1 {
+            self.by_size.insert((count - 1, cat));
+        }
+        self.counts[cat] -= 1;
+        self.remaining -= 1;
+        let mut i = cat + 1;
+        while i < self.tree.len() {
+            self.tree[i] -= 1;
+            i += i & i.wrapping_neg();
+        }
     }
 
-    // Pick a random valid candidate
-    let pick_idx =
-        (rng.uniform(0.0, candidates.len() as f32).floor() as usize).min(candidates.len() - 1);
-    let swap_with = candidates[pick_idx];
-    slice.swap(violation_idx, swap_with);
-    true
+    /// Whether placing `cat` next keeps the rest of the sequence solvable.
+    fn can_place(&self, cat: usize, last: Option<(usize, usize)>, k: usize) -> bool {
+        if self.counts[cat] == 0 {
+            return false;
+        }
+        let run = match last {
+            Some((prev, run)) if prev == cat => run + 1,
+            _ => 1,
+        };
+        if run > k {
+            return false;
+        }
+
+        let remaining = self.remaining - 1;
+        let own = self.counts[cat] - 1;
+        // The new run can grow by k - run more before others must break it
+        if own > (k - run) + k * (remaining - own) {
+            return false;
+        }
+        // Every other category must fit into the gaps around the rest
+        self.by_size
+            .iter()
+            .rev()
+            .find(|&&(_, c)| c != cat)
+            .map_or(true, |&(count, _)| count <= k * (remaining - count + 1))
+    }
+
+    /// Pick a category when the weighted draw was not placeable.
+    fn fallback(&self, last: Option<(usize, usize)>, k: usize) -> usize {
+        // The largest categories are the ones the lookahead protects
+        let largest = self.by_size.iter().rev().take(3).map(|&(_, c)| c);
+        if let Some(cat) = largest.clone().find(|&c| self.can_place(c, last, k)) {
+            return cat;
+        }
+        if let Some(cat) = (0..self.counts.len()).find(|&c| self.can_place(c, last, k)) {
+            return cat;
+        }
+        // Infeasible: prefer breaking the current run, otherwise accept a violation
+        let current = last.map(|(c, _)| c);
+        largest
+            .clone()
+            .find(|&c| Some(c) != current)
+            .or_else(|| largest.clone().next())
+            .expect("remaining > 0 implies a non-empty category")
+    }
 }
 
-/// Check if swapping elements at pos1 and pos2 would be valid at pos1.
-fn would_be_valid_at<T, F>(
-    slice: &[T],
-    check_pos: usize,
-    swap_from: usize,
-    max_consecutive: usize,
-    classifier: &F,
-) -> bool
-where
-    F: Fn(&T) -> usize,
-{
-    let new_cat = classifier(&slice[swap_from]);
-
-    // Check run before check_pos
-    let mut run_before = 0;
-    if check_pos > 0 {
-        for i in (0..check_pos).rev() {
-            if classifier(&slice[i]) == new_cat {
-                run_before += 1;
-            } else {
+/// Reorder `slice` so that `slice[i]` becomes the original `slice[order[i]]`.
+fn apply_order<T>(slice: &mut [T], order: &[usize]) {
+    let mut placed = vec![false; slice.len()];
+    for start in 0..slice.len() {
+        if placed[start] {
+            continue;
+        }
+        let mut cur = start;
+        loop {
+            placed[cur] = true;
+            let next = order[cur];
+            if next == start {
                 break;
             }
+            slice.swap(cur, next);
+            cur = next;
         }
     }
+}
 
-    // Check run after check_pos
-    let mut run_after = 0;
-    for (i, item) in slice.iter().enumerate().skip(check_pos + 1) {
-        if i == swap_from {
-            // Skip the element being swapped out
-            continue;
-        }
-        if classifier(item) == new_cat {
-            run_after += 1;
+/// Positions that extend a run of equal categories past `max_consecutive`.
+fn run_violations(categories: &[usize], max_consecutive: usize) -> Vec<usize> {
+    let mut violations = Vec::new();
+    let mut run = 0;
+    for (i, cat) in categories.iter().enumerate() {
+        run = if i > 0 && categories[i - 1] == *cat {
+            run + 1
         } else {
-            break;
+            1
+        };
+        if run > max_consecutive {
+            violations.push(i);
         }
     }
-
-    run_before + 1 + run_after <= max_consecutive
+    violations
 }
 
 // <FILE>src/shuffle/fnc_constrained_shuffle.rs</FILE> - <DESC>Variety-enforced constrained shuffle algorithm</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 2.0.0</VERS>
@@ -1,7 +1,7 @@
 // <FILE>src/shuffle/mod.rs</FILE> - <DESC>Shuffle algorithm module orchestrator</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Implementing shuffle algorithms phases 2-4</WCTX>
-// <CLOG>Added Phase 2-4 algorithms: weighted, constrained, interleave, riffle, overhand, reservoir, smooth, and animators</CLOG>
+// <VERS>VERSION: 2.4.0</VERS>
+// <WCTX>Exposing mid-animation shuffle state for rendering</WCTX>
+// <CLOG>Exported RiffleSource</CLOG>
 
 //! Shuffle algorithms for collections.
 //!
@@ -18,12 +18,15 @@
 //! | [`shuffle_copy`] | O(n) | O(n) | Non-mutating shuffle |
 //! | [`sattolo`] | O(n) | O(1) | Cyclic permutation (no fixed points) |
 //! | [`weighted_shuffle`] | O(n log n) | O(n) | Priority-biased ordering |
-//! | [`constrained_shuffle`] | O(n²) | O(n) | Variety enforcement (max consecutive) |
+//! | [`constrained_shuffle`] | O(n log n) | O(n) | Variety enforcement (max consecutive) |
+//! | [`try_constrained_shuffle`] | O(n log n) | O(n) | Variety enforcement with error reporting |
 //! | [`interleave`] | O(n) | O(n) | Deterministic Faro shuffle |
 //! | [`riffle_shuffle`] | O(n) | O(n) | Realistic card riffle |
 //! | [`overhand_shuffle`] | O(n) | O(n) | Casual card shuffle |
 //! | [`reservoir_shuffle`] | O(n) | O(n) | Streaming/iterator input |
 //! | [`smooth_shuffle`] | O(n²) | O(n) | Minimize transition jarring |
+//! | [`permutation_from_index`] | O(n²) | O(n) | Exact integer → permutation mapping |
+//! | [`RandomPermutation`] | O(1) per index | O(1) | Lazy shuffled order of a huge range |
 //!
 //! # Animation Structs
 //!
@@ -71,6 +74,8 @@ mod fnc_overhand_shuffle;
 mod fnc_riffle_shuffle;
 
 // Phase 4: Advanced
+mod cls_random_permutation;
+mod fnc_permutation_index;
 mod fnc_reservoir_shuffle;
 mod fnc_smooth_shuffle;
 
@@ -81,19 +86,21 @@ pub use fnc_sattolo::sattolo;
 pub use fnc_shuffle_copy::shuffle_copy;
 
 // Phase 2 exports
-pub use fnc_constrained_shuffle::constrained_shuffle;
+pub use fnc_constrained_shuffle::{constrained_shuffle, try_constrained_shuffle, ConstraintError};
 pub use fnc_weighted_shuffle::weighted_shuffle;
 
 // Phase 3 exports
 pub use cls_overhand_animator::{OverhandAnimator, OverhandState};
-pub use cls_riffle_animator::{RiffleAnimator, RiffleState};
+pub use cls_riffle_animator::{RiffleAnimator, RiffleSource, RiffleState};
 pub use fnc_interleave::interleave;
 pub use fnc_overhand_shuffle::overhand_shuffle;
 pub use fnc_riffle_shuffle::riffle_shuffle;
 
 // Phase 4 exports
+pub use cls_random_permutation::RandomPermutation;
+pub use fnc_permutation_index::permutation_from_index;
 pub use fnc_reservoir_shuffle::reservoir_shuffle;
 pub use fnc_smooth_shuffle::smooth_shuffle;
 
 // <FILE>src/shuffle/mod.rs</FILE> - <DESC>Shuffle algorithm module orchestrator</DESC>
-// <VERS>END OF VERSION: 2.0.0</VERS>
+// <VERS>END OF VERSION: 2.4.0</VERS>
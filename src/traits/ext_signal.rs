@@ -1,11 +1,14 @@
 // <FILE>src/traits/ext_signal.rs</FILE> - <DESC>Fluent combinator methods for Signal</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Bipolar core refactor</WCTX>
-// <CLOG>Fixed .invert() to use negation (-v), updated docstrings to remove stale clamp references</CLOG>
+// <VERS>VERSION: 2.13.0</VERS>
+// <WCTX>Stereo audio output</WCTX>
+// <CLOG>Added pan combinator</CLOG>
 
 use super::{Signal, SignalContext, SignalRange, SignalTime};
-use crate::composition::{Add, Mix, Multiply};
-use crate::processing::Normalized;
+use crate::composition::{Add, Delay, Mix, Multiply, Pan};
+use crate::easing::{CubicBezier, EasingType};
+use crate::processing::{BezierEase, Eased, Loop, LoopBlend, Normalized, NormalizedFromSamples};
+#[cfg(feature = "std")]
+use crate::processing::{Gate, SlewLimit, TimeWarp};
 
 /// Extension trait providing fluent combinator methods for signals.
 ///
@@ -55,6 +58,77 @@ pub trait SignalExt: Signal + Sized {
         Mix::new(self, other, blend)
     }
 
+    /// Shift this signal later in time by `offset` seconds.
+    ///
+    /// Output = self(t - offset). Negative offsets advance the signal;
+    /// non-finite offsets are treated as 0.0.
+    fn delay(self, offset: f32) -> Delay<Self> {
+        Delay::new(self, offset)
+    }
+
+    /// Repeat the first `period` seconds of this signal forever.
+    ///
+    /// Output = self(t mod period). Non-finite or non-positive periods
+    /// pass t through unchanged.
+    fn looped(self, period: f32) -> Loop<Self> {
+        Loop::new(self, period)
+    }
+
+    /// Repeat the first `period` seconds forever, crossfading the last
+    /// `blend` seconds of each lap so the wrap doesn't pop.
+    ///
+    /// See [`LoopBlend`] for the exact blend shape.
+    fn loop_blend(self, period: f32, blend: f32) -> LoopBlend<Self> {
+        LoopBlend::new(self, period, blend)
+    }
+
+    /// Shape this [0, 1] signal through an easing curve.
+    ///
+    /// Output = ease(self(t)); overshooting curves are not clamped.
+    fn eased(self, easing: EasingType) -> Eased<Self> {
+        Eased::new(self, easing)
+    }
+
+    /// Shape this [0, 1] signal through a CSS-style cubic-bezier curve.
+    ///
+    /// Output = curve(self(t)); see [`CubicBezier`] for the presets.
+    fn bezier_eased(self, curve: CubicBezier) -> BezierEase<Self> {
+        BezierEase::new(self, curve)
+    }
+
+    /// Limit the rate of change to `rate` units per second (portamento).
+    ///
+    /// Stateful: sample with increasing `t`. Use [`SlewLimit::with_rates`]
+    /// for different rising and falling rates.
+    #[cfg(feature = "std")]
+    fn slew(self, rate: f32) -> SlewLimit<Self> {
+        SlewLimit::new(self, rate)
+    }
+
+    /// Place this mono signal in the stereo field (-1 = left, 1 = right).
+    ///
+    /// Equal-power; pass a `Constant` for a fixed position or any signal to
+    /// sweep. See [`Pan`].
+    fn pan<P: Signal>(self, position: P) -> Pan<Self, P> {
+        Pan::new(self, position)
+    }
+
+    /// Play this signal at a speed set by `rate` (1.0 = normal speed).
+    ///
+    /// Output = self(∫₀ᵗ rate). Speed changes never make the output jump.
+    #[cfg(feature = "std")]
+    fn time_warp<R: Signal>(self, rate: R) -> TimeWarp<Self, R> {
+        TimeWarp::new(self, rate)
+    }
+
+    /// Output 0.0 whenever |self| falls below `threshold` (a noise gate).
+    ///
+    /// Use [`Gate::with_floor`] and [`Gate::with_hysteresis`] for more control.
+    #[cfg(feature = "std")]
+    fn gate(self, threshold: f32) -> Gate<Self> {
+        Gate::new(self, threshold)
+    }
+
     /// Apply a mapping function to the signal output.
     ///
     /// The function receives the signal value and should return a new value.
@@ -113,6 +187,20 @@ pub trait SignalExt: Signal + Sized {
     fn normalized_from(self, from: SignalRange) -> NormalizedFrom<Self> {
         NormalizedFrom::new(self, from)
     }
+
+    /// Normalize to [0, 1] using the min/max measured over a time window.
+    ///
+    /// Samples `samples` points over `[t_start, t_start + duration]` once, up
+    /// front. Use this when `output_range()` is too conservative or degenerate
+    /// for [`normalized`](Self::normalized).
+    fn normalized_over(
+        self,
+        t_start: SignalTime,
+        duration: SignalTime,
+        samples: usize,
+    ) -> NormalizedFromSamples<Self> {
+        NormalizedFromSamples::new(self, t_start, duration, samples)
+    }
 }
 
 // Blanket implementation for all Signal types
@@ -125,7 +213,7 @@ pub struct Map<S, F> {
     f: F,
 }
 
-impl<S: Signal, F: Fn(f32) -> f32 + Send + Sync> Signal for Map<S, F> {
+impl<S: Signal + Clone, F: Fn(f32) -> f32 + Send + Sync + Clone> Signal for Map<S, F> {
     fn sample(&self, t: SignalTime) -> f32 {
         (self.f)(self.signal.sample(t))
     }
@@ -149,7 +237,7 @@ impl<S> NormalizedFrom<S> {
     }
 }
 
-impl<S: Signal> Signal for NormalizedFrom<S> {
+impl<S: Signal + Clone> Signal for NormalizedFrom<S> {
     fn output_range(&self) -> SignalRange {
         SignalRange::UNIT
     }
@@ -246,6 +334,7 @@ mod tests {
     #[test]
     fn test_normalized_from_custom_range() {
         // Create a raw signal that returns 15.0 without clamping
+        #[derive(Clone)]
         struct RawValue(f32);
         impl Signal for RawValue {
             fn output_range(&self) -> SignalRange {
@@ -260,7 +349,16 @@ mod tests {
         let sig = RawValue(15.0).normalized_from(SignalRange::new(10.0, 20.0));
         assert!((sig.sample(0.0) - 0.5).abs() < 0.001);
     }
+
+    #[test]
+    fn test_pan_fluent() {
+        use crate::traits::StereoSignal;
+        let (l, r) = Constant::new(1.0)
+            .pan(Constant::new(-1.0))
+            .sample_stereo(0.0);
+        assert!((l - 1.0).abs() < 1e-6 && r.abs() < 1e-6);
+    }
 }
 
 // <FILE>src/traits/ext_signal.rs</FILE> - <DESC>Fluent combinator methods for Signal</DESC>
-// <VERS>END OF VERSION: 2.1.0</VERS>
+// <VERS>END OF VERSION: 2.13.0</VERS>
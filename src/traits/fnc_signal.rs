@@ -1,9 +1,65 @@
 // <FILE>src/traits/fnc_signal.rs</FILE> - <DESC>Closure wrapper for Signal trait</DESC>
-// <VERS>VERSION: 1.0.0</VERS>
-// <WCTX>Ergonomics improvements</WCTX>
-// <CLOG>Initial creation - Fn1 wrapper for closures as signals</CLOG>
+// <VERS>VERSION: 1.3.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Core:: instead of std:: paths</CLOG>
 
-use super::{Signal, SignalContext, SignalTime};
+use super::{Signal, SignalContext, SignalRange, SignalTime};
+
+/// Use a plain `Fn(SignalTime) -> f32` closure as a [`Signal`].
+///
+/// Unlike [`Fn1`], the closure output is passed through unclamped, so the
+/// result composes like any other bipolar generator. The reported
+/// `output_range()` defaults to [`SignalRange::BIPOLAR`]; use
+/// [`FromFn::with_range`] when the closure produces a different range so
+/// `.normalized()` maps it correctly.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::traits::{from_fn, Signal};
+///
+/// let wobble = from_fn(|t| (t as f32 * 3.0).sin());
+/// assert!((wobble.sample(0.0)).abs() < 1e-6);
+/// ```
+pub fn from_fn<F: Fn(SignalTime) -> f32 + Send + Sync>(f: F) -> FromFn<F> {
+    FromFn {
+        f,
+        range: SignalRange::BIPOLAR,
+    }
+}
+
+/// Closure-backed signal created by [`from_fn`].
+#[derive(Clone, Copy)]
+pub struct FromFn<F> {
+    f: F,
+    range: SignalRange,
+}
+
+impl<F> FromFn<F> {
+    /// Declare the range the closure produces (reported by `output_range()`).
+    pub fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.range = SignalRange::new(min, max);
+        self
+    }
+}
+
+impl<F> core::fmt::Debug for FromFn<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FromFn")
+            .field("range", &self.range)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: Fn(SignalTime) -> f32 + Send + Sync + Clone> Signal for FromFn<F> {
+    fn output_range(&self) -> SignalRange {
+        self.range
+    }
+
+    fn sample(&self, t: SignalTime) -> f32 {
+        (self.f)(t)
+    }
+}
 
 /// Wrapper that allows a closure to be used as a Signal.
 ///
@@ -23,13 +79,13 @@ use super::{Signal, SignalContext, SignalTime};
 #[derive(Clone, Copy)]
 pub struct Fn1<F>(pub F);
 
-impl<F> std::fmt::Debug for Fn1<F> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<F> core::fmt::Debug for Fn1<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Fn1").finish_non_exhaustive()
     }
 }
 
-impl<F: Fn(SignalTime) -> f32 + Send + Sync> Signal for Fn1<F> {
+impl<F: Fn(SignalTime) -> f32 + Send + Sync + Clone> Signal for Fn1<F> {
     fn sample(&self, t: SignalTime) -> f32 {
         (self.0)(t).clamp(0.0, 1.0)
     }
@@ -53,13 +109,13 @@ impl<F: Fn(SignalTime) -> f32 + Send + Sync> Signal for Fn1<F> {
 #[derive(Clone, Copy)]
 pub struct Fn2<F>(pub F);
 
-impl<F> std::fmt::Debug for Fn2<F> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<F> core::fmt::Debug for Fn2<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Fn2").finish_non_exhaustive()
     }
 }
 
-impl<F: Fn(SignalTime, &SignalContext) -> f32 + Send + Sync> Signal for Fn2<F> {
+impl<F: Fn(SignalTime, &SignalContext) -> f32 + Send + Sync + Clone> Signal for Fn2<F> {
     fn sample(&self, t: SignalTime) -> f32 {
         // For sample() without context, provide a default context
         (self.0)(t, &SignalContext::default()).clamp(0.0, 1.0)
@@ -89,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_fn1_sine() {
-        let sig = Fn1(|t: SignalTime| (t * std::f64::consts::TAU).sin() as f32 * 0.5 + 0.5);
+        let sig = Fn1(|t: SignalTime| (t * core::f64::consts::TAU).sin() as f32 * 0.5 + 0.5);
         assert!((sig.sample(0.0) - 0.5).abs() < 0.001);
         assert!((sig.sample(0.25) - 1.0).abs() < 0.001);
     }
@@ -108,7 +164,37 @@ mod tests {
         let debug = format!("{:?}", sig);
         assert!(debug.contains("Fn1"));
     }
+
+    #[test]
+    fn test_from_fn_samples_closure() {
+        let sig = from_fn(|t| (t as f32).sin());
+        for t in [0.0, 0.5, 1.0, 2.5, 4.0] {
+            assert!((sig.sample(t) - (t as f32).sin()).abs() < 1e-6);
+        }
+        // Not clamped: negative values pass through
+        assert!(sig.sample(4.0) < 0.0);
+    }
+
+    #[test]
+    fn test_from_fn_composes_with_add() {
+        use crate::composition::Add;
+        use crate::generators::Constant;
+
+        let sum = Add::new(from_fn(|t| (t as f32).sin()), Constant::new(0.5));
+        assert!((sum.sample(1.0) - (1.0f32.sin() + 0.5)).abs() < 1e-6);
+
+        let both = Add::new(from_fn(|t| t as f32), from_fn(|t| 2.0 * t as f32));
+        assert!((both.sample(0.25) - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_from_fn_output_range() {
+        let sig = from_fn(|t| t as f32);
+        assert_eq!(sig.output_range(), SignalRange::BIPOLAR);
+        let sig = sig.with_range(0.0, 10.0);
+        assert_eq!(sig.output_range(), SignalRange::new(0.0, 10.0));
+    }
 }
 
 // <FILE>src/traits/fnc_signal.rs</FILE> - <DESC>Closure wrapper for Signal trait</DESC>
-// <VERS>END OF VERSION: 1.0.0</VERS>
+// <VERS>END OF VERSION: 1.3.0</VERS>
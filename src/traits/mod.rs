@@ -1,16 +1,22 @@
 // <FILE>mixed-signals/src/traits/mod.rs</FILE> - <DESC>Signal traits module</DESC>
-// <VERS>VERSION: 2.2.0</VERS>
-// <WCTX>Bipolar core refactor - normalization layer</WCTX>
-// <CLOG>Added NormalizedFrom export for explicit range normalization</CLOG>
+// <VERS>VERSION: 2.7.0</VERS>
+// <WCTX>Stereo audio output</WCTX>
+// <CLOG>Added StereoSignal trait</CLOG>
 
 mod ext_signal;
 mod fnc_signal;
+mod planar;
+mod processor;
 mod signal;
+mod stereo;
 
 pub use ext_signal::{Map, NormalizedFrom, SignalExt};
-pub use fnc_signal::{Fn1, Fn2};
+pub use fnc_signal::{from_fn, Fn1, Fn2, FromFn};
+pub use planar::{Axis, Planar};
+pub use processor::Processor;
 pub use signal::SignalTime;
-pub use signal::{Phase, Signal, SignalContext, SignalRange};
+pub use signal::{Phase, Signal, SignalClone, SignalContext, SignalRange};
+pub use stereo::StereoSignal;
 
 // <FILE>mixed-signals/src/traits/mod.rs</FILE> - <DESC>Signal traits module</DESC>
-// <VERS>END OF VERSION: 2.2.0</VERS>
+// <VERS>END OF VERSION: 2.7.0</VERS>
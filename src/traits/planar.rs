@@ -0,0 +1,37 @@
+// <FILE>src/traits/planar.rs</FILE> - <DESC>Two-dimensional motion trait</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>2D physics output</WCTX>
+// <CLOG>Initial Planar trait and Axis selector</CLOG>
+
+use super::{SignalRange, SignalTime};
+
+/// Coordinate axis selector for 2D motion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Axis {
+    /// Horizontal axis.
+    #[default]
+    X,
+    /// Vertical axis (positive is down in UI coordinates).
+    Y,
+}
+
+/// Motion in a plane, evaluated analytically at any time.
+///
+/// Implemented by 2D physics solvers such as
+/// [`BallisticTrajectory`](crate::physics::BallisticTrajectory) and
+/// [`CircularOrbit`](crate::physics::CircularOrbit). A `Signal` can only
+/// return one value, so [`AxisSignal`](crate::physics::AxisSignal) projects a
+/// `Planar` solver onto a single axis for composition.
+pub trait Planar {
+    /// Position `(x, y)` at time `t`.
+    fn position_at(&self, t: SignalTime) -> (f32, f32);
+
+    /// Velocity `(vx, vy)` at time `t`.
+    fn velocity_at(&self, t: SignalTime) -> (f32, f32);
+
+    /// Expected range of positions along `axis`.
+    fn axis_range(&self, axis: Axis) -> SignalRange;
+}
+
+// <FILE>src/traits/planar.rs</FILE> - <DESC>Two-dimensional motion trait</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
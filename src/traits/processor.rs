@@ -0,0 +1,54 @@
+// <FILE>src/traits/processor.rs</FILE> - <DESC>Streaming sample processor trait</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Import Box from alloc</CLOG>
+
+use alloc::boxed::Box;
+
+/// Stateful, sample-by-sample processor for streaming audio.
+///
+/// Where [`Signal`](crate::traits::Signal) is a pure function of time, a
+/// `Processor` consumes one input sample per call and keeps its own history.
+/// This is the shape real-time audio callbacks need: no time bookkeeping, no
+/// locking, and state that only advances when a sample is pushed through.
+///
+/// # Example
+/// ```
+/// use mixed_signals::processing::BiquadState;
+/// use mixed_signals::traits::Processor;
+///
+/// let mut lp = BiquadState::lowpass(1000.0, 48000.0);
+/// let out: Vec<f32> = [1.0, 0.0, 0.0].iter().map(|&x| lp.process(x)).collect();
+/// assert!(out[0] > 0.0);
+/// ```
+pub trait Processor: Send {
+    /// Process a single input sample and return the output sample.
+    fn process(&mut self, input: f32) -> f32;
+
+    /// Clear internal history so the next sample starts from silence.
+    fn reset(&mut self);
+
+    /// Process a buffer in place.
+    fn process_block(&mut self, buffer: &mut [f32]) {
+        for sample in buffer.iter_mut() {
+            *sample = self.process(*sample);
+        }
+    }
+}
+
+impl<P: Processor + ?Sized> Processor for Box<P> {
+    fn process(&mut self, input: f32) -> f32 {
+        (**self).process(input)
+    }
+
+    fn reset(&mut self) {
+        (**self).reset()
+    }
+
+    fn process_block(&mut self, buffer: &mut [f32]) {
+        (**self).process_block(buffer)
+    }
+}
+
+// <FILE>src/traits/processor.rs</FILE> - <DESC>Streaming sample processor trait</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
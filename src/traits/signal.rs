@@ -1,7 +1,10 @@
 // <FILE>mixed-signals/src/traits/signal.rs</FILE> - <DESC>Core Signal trait definition</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>mixed-signals extraction: Universal Phase implementation (Option E)</WCTX>
-// <CLOG>Replaced AnimationPhase with universal Phase enum for broader applicability</CLOG>
+// <VERS>VERSION: 2.8.1</VERS>
+// <WCTX>Batch sampling</WCTX>
+// <CLOG>Added sample_batch_from so operators can batch in fixed-size chunks</CLOG>
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 
 /// Universal phase model for lifecycle-aware signal evaluation.
 ///
@@ -67,6 +70,9 @@ pub struct SignalContext {
     // feat-20251224-155211: Per-character context
     /// Character index for per-character signal evaluation (used by PerCharacterNoise)
     pub char_index: Option<usize>,
+
+    /// Cell position `(x, y)` for spatial signals sampled per cell
+    pub position: Option<(u16, u16)>,
 }
 
 impl SignalContext {
@@ -81,6 +87,7 @@ impl SignalContext {
             loop_t: None,
             absolute_t: None,
             char_index: None,
+            position: None,
         }
     }
 
@@ -95,6 +102,19 @@ impl SignalContext {
         self
     }
 
+    /// Set the cell position `(x, y)` being sampled.
+    pub fn with_position(mut self, x: u16, y: u16) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Set the wall-clock time since the animation started, independent of
+    /// the `t` passed to `sample` (stored in `absolute_t`).
+    pub fn with_elapsed(mut self, seconds: SignalTime) -> Self {
+        self.absolute_t = Some(crate::math::finite_or_f64(seconds, 0.0).max(0.0));
+        self
+    }
+
     /// Context for phase-based effects (easing, entrance/exit animations)
     pub fn for_phase(phase: Phase, phase_t: SignalTime, frame: u64) -> Self {
         Self {
@@ -107,6 +127,7 @@ impl SignalContext {
             loop_t: None,
             absolute_t: None,
             char_index: None,
+            position: None,
         }
     }
 
@@ -122,6 +143,7 @@ impl SignalContext {
             loop_t: Some(loop_t.clamp(0.0, 1.0)),
             absolute_t: None,
             char_index: None,
+            position: None,
         }
     }
 
@@ -143,6 +165,7 @@ impl SignalContext {
             loop_t: loop_t.map(|value| value.clamp(0.0, 1.0)),
             absolute_t: Some(absolute_t.max(0.0)),
             char_index: None,
+            position: None,
         }
     }
 }
@@ -197,7 +220,20 @@ impl SignalRange {
 /// - Normalized progress (0.0 to 1.0 for animation-based signals)
 ///
 /// The interpretation depends on the signal type and configuration.
-pub trait Signal: Send + Sync {
+///
+/// # Cloning
+///
+/// Every signal is `Clone` (via the [`SignalClone`] supertrait), so
+/// `Box<dyn Signal>` is `Clone` too. Stateful filters clone their
+/// configuration only; the clone starts from fresh state.
+///
+/// # Sharing
+///
+/// `&S`, `Box<S>` and `Arc<S>` are signals whenever `S` is (including
+/// `dyn Signal`), so one signal can feed several chains without cloning
+/// it: `Mix::new(shared.clone(), other, 0.5)` with `shared: Arc<dyn Signal>`
+/// only bumps a refcount.
+pub trait Signal: Send + Sync + SignalClone {
     /// Report the expected output range for this signal.
     ///
     /// Default is the unit range (0.0..1.0).
@@ -205,6 +241,16 @@ pub trait Signal: Send + Sync {
         SignalRange::UNIT
     }
 
+    /// Recommended value window for display, as `(min, max)`.
+    ///
+    /// Defaults to `output_range()`. Signals whose extremes are rare (e.g. noise)
+    /// can override this with a tighter practical window so visualizations
+    /// don't squash the typical signal into a thin band.
+    fn display_range(&self) -> (f32, f32) {
+        let range = self.output_range();
+        (range.min, range.max)
+    }
+
     /// Sample the signal at time t.
     ///
     /// # Arguments
@@ -224,15 +270,52 @@ pub trait Signal: Send + Sync {
         self.sample(t)
     }
 
+    /// Definite integral of the signal from 0 to `t` (negative `t`
+    /// integrates backwards).
+    ///
+    /// Depends only on `t`, never on earlier calls, so out-of-order and
+    /// repeated queries agree. The default is [`integrate`](crate::math::integrate)
+    /// (Simpson's rule, 1000 panels per second); generators with a closed
+    /// form such as `Constant`, `Sine`, `Triangle` and `Keyframes` override
+    /// it with the exact value. Used by `PhaseAccumulator` for true FM.
+    fn integral(&self, t: SignalTime) -> f64 {
+        crate::math::integrate(|tau| self.sample(tau), 0.0, t)
+    }
+
+    /// Fill `out` with samples at `t_start + i * dt`.
+    ///
+    /// Time is computed from the index rather than accumulated, so `out[i]`
+    /// is bitwise identical to `sample(t_start + i as SignalTime * dt)`.
+    /// Equivalent to [`sample_batch_from`](Self::sample_batch_from) with
+    /// `first = 0`; override that method rather than this one.
+    fn sample_batch(&self, t_start: SignalTime, dt: SignalTime, out: &mut [f32]) {
+        self.sample_batch_from(t_start, dt, 0, out);
+    }
+
+    /// Fill `out` with samples at `t_start + (first + i) * dt`.
+    ///
+    /// Lets callers process a long buffer in fixed-size chunks while every
+    /// sample time stays bitwise identical to a single `sample_batch` call.
+    /// Hot generators and composition operators override this to hoist
+    /// per-sample setup and forward whole chunks to their children.
+    fn sample_batch_from(
+        &self,
+        t_start: SignalTime,
+        dt: SignalTime,
+        first: usize,
+        out: &mut [f32],
+    ) {
+        for (i, value) in out.iter_mut().enumerate() {
+            *value = self.sample(t_start + (first + i) as SignalTime * dt);
+        }
+    }
+
     /// Sample the signal into a pre-allocated buffer.
     ///
     /// Values are sampled starting at `t_start` and incrementing by `dt`.
+    /// Equivalent to [`sample_batch`](Self::sample_batch).
     fn sample_into(&self, t_start: SignalTime, dt: SignalTime, out: &mut [f32]) {
-        let mut t = t_start;
-        for value in out.iter_mut() {
-            *value = self.sample(t);
-            t += dt;
-        }
+        self.sample_batch(t_start, dt, out);
     }
 
     /// Sample the signal into a pre-allocated buffer with context.
@@ -252,6 +335,18 @@ pub trait Signal: Send + Sync {
         }
     }
 
+    /// Sample one row of `width` cells at time `t`.
+    ///
+    /// Cell `x` is sampled with `ctx` positioned at `(x, y)`, where `y` is the
+    /// row of `ctx.position` (0 when unset). Spatial signals can override this
+    /// to fill a row without rebuilding the context per cell.
+    fn sample_row(&self, width: u16, t: SignalTime, ctx: &SignalContext) -> Vec<f32> {
+        let y = ctx.position.map_or(0, |(_, y)| y);
+        (0..width)
+            .map(|x| self.sample_with_context(t, &ctx.clone().with_position(x, y)))
+            .collect()
+    }
+
     /// Convenience helper that returns a Vec of sampled values.
     fn sample_vec(&self, t_start: SignalTime, dt: SignalTime, count: usize) -> Vec<f32> {
         let mut values = vec![0.0; count];
@@ -260,40 +355,113 @@ pub trait Signal: Send + Sync {
     }
 }
 
-// Allow boxed signals to be used as signals
-impl Signal for Box<dyn Signal> {
-    fn output_range(&self) -> SignalRange {
-        (**self).output_range()
-    }
+/// Object-safe cloning for signals (the `dyn-clone` pattern).
+///
+/// Implemented automatically for every `Signal + Clone` type; never implement
+/// it by hand. Call [`clone_box`](Self::clone_box) to clone a signal behind a
+/// trait object, or just `.clone()` a `Box<dyn Signal>`.
+pub trait SignalClone {
+    /// Clone this signal into a new box.
+    fn clone_box<'a>(&self) -> Box<dyn Signal + 'a>
+    where
+        Self: 'a;
+}
 
-    fn sample(&self, t: SignalTime) -> f32 {
-        (**self).sample(t)
+impl<T: Signal + Clone> SignalClone for T {
+    fn clone_box<'a>(&self) -> Box<dyn Signal + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self.clone())
     }
+}
 
-    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
-        (**self).sample_with_context(t, ctx)
+impl<'a> Clone for Box<dyn Signal + 'a> {
+    fn clone(&self) -> Self {
+        (**self).clone_box()
     }
 }
 
-// Allow Arc<dyn Signal> to be used as signals
-impl Signal for std::sync::Arc<dyn Signal> {
-    fn output_range(&self) -> SignalRange {
-        (**self).output_range()
-    }
+/// Forward every overridable [`Signal`] method to the pointee, so wrapping a
+/// signal in a reference or smart pointer keeps its batch and row fast paths.
+macro_rules! forward_signal {
+    () => {
+        fn output_range(&self) -> SignalRange {
+            (**self).output_range()
+        }
 
-    fn sample(&self, t: SignalTime) -> f32 {
-        (**self).sample(t)
-    }
+        fn display_range(&self) -> (f32, f32) {
+            (**self).display_range()
+        }
 
-    fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
-        (**self).sample_with_context(t, ctx)
-    }
+        fn sample(&self, t: SignalTime) -> f32 {
+            (**self).sample(t)
+        }
+
+        fn sample_with_context(&self, t: SignalTime, ctx: &SignalContext) -> f32 {
+            (**self).sample_with_context(t, ctx)
+        }
+
+        fn integral(&self, t: SignalTime) -> f64 {
+            (**self).integral(t)
+        }
+
+        fn sample_batch(&self, t_start: SignalTime, dt: SignalTime, out: &mut [f32]) {
+            (**self).sample_batch(t_start, dt, out)
+        }
+
+        fn sample_batch_from(
+            &self,
+            t_start: SignalTime,
+            dt: SignalTime,
+            first: usize,
+            out: &mut [f32],
+        ) {
+            (**self).sample_batch_from(t_start, dt, first, out)
+        }
+
+        fn sample_with_context_into(
+            &self,
+            t_start: SignalTime,
+            dt: SignalTime,
+            ctx: &SignalContext,
+            out: &mut [f32],
+        ) {
+            (**self).sample_with_context_into(t_start, dt, ctx, out)
+        }
+
+        fn sample_row(&self, width: u16, t: SignalTime, ctx: &SignalContext) -> Vec<f32> {
+            (**self).sample_row(width, t, ctx)
+        }
+    };
+}
+
+// Borrowed signals: `Add::new(&sine, &sine)` shares one oscillator
+impl<S: Signal + ?Sized> Signal for &S {
+    forward_signal!();
+}
+
+// Boxed signals, including `Box<dyn Signal>` (cloned via `clone_box`)
+impl<S: Signal + ?Sized> Signal for Box<S>
+where
+    Box<S>: Clone,
+{
+    forward_signal!();
+}
+
+// Shared signals: cloning an `Arc<dyn Signal>` bumps a refcount instead of
+// copying the tree. There is no `Rc` impl because `Signal` requires
+// `Send + Sync`.
+#[cfg(target_has_atomic = "ptr")]
+impl<S: Signal + ?Sized> Signal for alloc::sync::Arc<S> {
+    forward_signal!();
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[derive(Clone)]
     struct ConstantSignal(f32);
 
     impl Signal for ConstantSignal {
@@ -346,6 +514,29 @@ mod tests {
         assert_eq!(ctx.absolute_t, Some(0.0));
     }
 
+    #[test]
+    fn test_context_builders_compose() {
+        let ctx = SignalContext::new(3, 9)
+            .with_dimensions(80, 24)
+            .with_position(12, 5)
+            .with_elapsed(2.5)
+            .with_char_index(7);
+        assert_eq!((ctx.width, ctx.height), (80, 24));
+        assert_eq!(ctx.position, Some((12, 5)));
+        assert_eq!(ctx.absolute_t, Some(2.5));
+        assert_eq!(ctx.char_index, Some(7));
+
+        assert_eq!(SignalContext::new(0, 0).absolute_t, None);
+        assert_eq!(
+            SignalContext::new(0, 0).with_elapsed(-1.0).absolute_t,
+            Some(0.0)
+        );
+        assert_eq!(
+            SignalContext::new(0, 0).with_elapsed(f64::NAN).absolute_t,
+            Some(0.0)
+        );
+    }
+
     #[test]
     fn test_sample_into_uniform() {
         let sig = ConstantSignal(0.25);
@@ -358,6 +549,7 @@ mod tests {
 
     #[test]
     fn test_sample_with_context_into() {
+        #[derive(Clone)]
         struct ContextSignal;
 
         impl Signal for ContextSignal {
@@ -384,7 +576,174 @@ mod tests {
         let range = SignalRange::new(f32::NAN, 1.0);
         assert_eq!(range, SignalRange::UNIT);
     }
+
+    #[test]
+    fn test_display_range_defaults_to_output_range() {
+        let signal = ConstantSignal(0.5);
+        assert_eq!(signal.display_range(), (0.0, 1.0));
+        let boxed: Box<dyn Signal> = Box::new(ConstantSignal(0.5));
+        assert_eq!(boxed.display_range(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_boxed_sine_clone_is_independent_and_equal() {
+        use crate::generators::Sine;
+
+        let original: Box<dyn Signal> = Box::new(Sine::with_frequency(3.0));
+        let cloned = original.clone();
+        for i in 0..20 {
+            let t = i as f64 * 0.037;
+            assert_eq!(original.sample(t), cloned.sample(t));
+        }
+        drop(original);
+        assert!((cloned.sample(1.0 / 12.0) - 1.0).abs() < 1e-6);
+
+        let collection: Vec<Box<dyn Signal>> =
+            vec![cloned.clone_box(), Box::new(ConstantSignal(0.4))];
+        let copied = collection.clone();
+        assert_eq!(copied[1].sample(0.0), 0.4);
+    }
+
+    #[test]
+    fn test_sample_row_positions_each_column() {
+        #[derive(Clone)]
+        struct PositionSignal;
+
+        impl Signal for PositionSignal {
+            fn sample(&self, _t: SignalTime) -> f32 {
+                -1.0
+            }
+
+            fn sample_with_context(&self, _t: SignalTime, ctx: &SignalContext) -> f32 {
+                ctx.position
+                    .map_or(-1.0, |(x, y)| x as f32 + 100.0 * y as f32)
+            }
+        }
+
+        let ctx = SignalContext::new(0, 0).with_position(7, 3);
+        assert_eq!(
+            PositionSignal.sample_row(4, 0.0, &ctx),
+            vec![300.0, 301.0, 302.0, 303.0]
+        );
+        let boxed: Box<dyn Signal> = Box::new(PositionSignal);
+        assert_eq!(
+            boxed.sample_row(2, 0.0, &SignalContext::default()),
+            vec![0.0, 1.0]
+        );
+        assert!(boxed.sample_row(0, 0.0, &ctx).is_empty());
+    }
+
+    #[test]
+    fn test_sample_batch_default_matches_sample() {
+        #[derive(Clone)]
+        struct Wobble;
+        impl Signal for Wobble {
+            fn sample(&self, t: SignalTime) -> f32 {
+                (t * 7.3).sin() as f32
+            }
+        }
+        let signal = Wobble;
+        let mut batch = vec![0.0f32; 64];
+        signal.sample_batch(0.1, 0.013, &mut batch);
+        for (i, value) in batch.iter().enumerate() {
+            assert_eq!(
+                value.to_bits(),
+                signal.sample(0.1 + i as f64 * 0.013).to_bits()
+            );
+        }
+        let boxed: Box<dyn Signal> = Box::new(Wobble);
+        let mut via_box = vec![0.0; 64];
+        boxed.sample_into(0.1, 0.013, &mut via_box);
+        assert_eq!(batch, via_box);
+    }
+
+    #[test]
+    fn test_sample_batch_from_chunks_match_whole_batch() {
+        let signal = crate::composition::Add::new(
+            crate::generators::Sine::with_frequency(3.0),
+            crate::generators::Sawtooth::with_frequency(5.0),
+        );
+        let mut whole = vec![0.0f32; 100];
+        signal.sample_batch(-0.3, 0.0071, &mut whole);
+        let mut chunked = vec![0.0f32; 100];
+        for (c, chunk) in chunked.chunks_mut(17).enumerate() {
+            signal.sample_batch_from(-0.3, 0.0071, c * 17, chunk);
+        }
+        for (a, b) in whole.iter().zip(&chunked) {
+            assert_eq!(a.to_bits(), b.to_bits());
+        }
+    }
+    #[test]
+    fn test_integral_default_is_order_independent() {
+        let half = ConstantSignal(0.5);
+        let boxed: Box<dyn Signal> = Box::new(half.clone());
+        let later = half.integral(2.0);
+        let earlier = half.integral(0.5);
+        assert_eq!(half.integral(2.0), later);
+        assert_eq!(boxed.integral(0.5), earlier);
+        assert!((later - 1.0).abs() < 1e-9);
+        assert!((half.integral(-1.0) + 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_arc_signal_shared_between_chains() {
+        use crate::composition::{Add, Mix};
+        use crate::generators::{Constant, Sine};
+        use alloc::sync::Arc;
+
+        let shared: Arc<dyn Signal> = Arc::new(Sine::with_frequency(2.0));
+        let offset = Add::new(Arc::clone(&shared), Constant::new(0.5));
+        let blended = Mix::new(shared.clone(), Constant::new(1.0), 0.25);
+        assert_eq!(Arc::strong_count(&shared), 3);
+
+        let owned = Mix::new(Sine::with_frequency(2.0), Constant::new(1.0), 0.25);
+        for i in 0..50 {
+            let t = i as f64 * 0.013;
+            assert_eq!(offset.sample(t), shared.sample(t) + 0.5);
+            assert_eq!(blended.sample(t), owned.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_borrowed_signal_matches_owned() {
+        use crate::composition::Add;
+        use crate::generators::{Constant, Sine};
+
+        let sine = Sine::new(3.0, 0.8, 0.1, 0.0);
+        let borrowed = Add::new(&sine, Constant::new(0.2));
+        let owned = Add::new(sine, Constant::new(0.2));
+        assert_eq!(borrowed.output_range(), owned.output_range());
+        for i in 0..50 {
+            let t = i as f64 * 0.017;
+            assert_eq!(borrowed.sample(t), owned.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_pointer_signals_keep_signal_ext() {
+        use crate::generators::Sine;
+        use crate::traits::SignalExt;
+        use alloc::sync::Arc;
+
+        let sine = Sine::with_frequency(1.0);
+        let scaled_ref = (&sine).scale(0.5);
+        let scaled_box = Box::new(sine).scale(0.5);
+        let scaled_arc = Arc::new(sine).scale(0.5);
+        for i in 0..20 {
+            let t = i as f64 * 0.05;
+            let expected = sine.sample(t) * 0.5;
+            assert_eq!(scaled_ref.sample(t), expected);
+            assert_eq!(scaled_box.sample(t), expected);
+            assert_eq!(scaled_arc.sample(t), expected);
+        }
+
+        let mut batch = [0.0; 16];
+        let mut expected = [0.0; 16];
+        <&Sine as Signal>::sample_batch(&&sine, 0.1, 0.01, &mut batch);
+        sine.sample_batch(0.1, 0.01, &mut expected);
+        assert_eq!(batch, expected);
+    }
 }
 
 // <FILE>mixed-signals/src/traits/signal.rs</FILE> - <DESC>Core Signal trait definition</DESC>
-// <VERS>END OF VERSION: 1.1.0 - 2025-12-23</VERS>
+// <VERS>END OF VERSION: 2.8.1</VERS>
@@ -0,0 +1,39 @@
+// <FILE>src/traits/stereo.rs</FILE> - <DESC>Two-channel signal trait</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Stereo audio output</WCTX>
+// <CLOG>Initial StereoSignal trait</CLOG>
+
+use super::{SignalContext, SignalTime};
+
+/// A signal with a left and a right channel, evaluated at any time.
+///
+/// Implemented by [`Stereo`](crate::composition::Stereo) (two mono signals)
+/// and [`Pan`](crate::composition::Pan) (one mono signal placed in the
+/// stereo field). `Signal` stays mono; this trait sits beside it the way
+/// [`Planar`](super::Planar) does for 2D motion.
+pub trait StereoSignal: Send + Sync {
+    /// Sample `(left, right)` at time `t`.
+    fn sample_stereo(&self, t: SignalTime) -> (f32, f32);
+
+    /// Sample `(left, right)` with runtime context.
+    ///
+    /// Default implementation ignores context and calls `sample_stereo(t)`.
+    fn sample_stereo_with_context(&self, t: SignalTime, _ctx: &SignalContext) -> (f32, f32) {
+        self.sample_stereo(t)
+    }
+
+    /// Sample interleaved frames `[l0, r0, l1, r1, ...]` into `out`.
+    ///
+    /// Frame `i` is sampled at `t_start + i * dt`. A trailing odd slot is
+    /// left untouched.
+    fn sample_stereo_interleaved(&self, t_start: SignalTime, dt: SignalTime, out: &mut [f32]) {
+        for (i, frame) in out.chunks_exact_mut(2).enumerate() {
+            let (left, right) = self.sample_stereo(t_start + i as SignalTime * dt);
+            frame[0] = left;
+            frame[1] = right;
+        }
+    }
+}
+
+// <FILE>src/traits/stereo.rs</FILE> - <DESC>Two-channel signal trait</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
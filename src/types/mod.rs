@@ -1,13 +1,17 @@
 // <FILE>mixed-signals/src/types/mod.rs</FILE> - <DESC>Types module</DESC>
-// <VERS>VERSION: 1.1.0</VERS>
-// <WCTX>Signal generator implementation + signal-driven parameters</WCTX>
-// <CLOG>Added SignalOrFloat for flexible parameter specification</CLOG>
+// <VERS>VERSION: 1.4.0</VERS>
+// <WCTX>Spec validation for hand-edited configs</WCTX>
+// <CLOG>Added spec validation</CLOG>
 
 mod signal_or_float;
 mod signal_spec;
+mod spec_registry;
+mod spec_validation;
 
 pub use signal_or_float::SignalOrFloat;
-pub use signal_spec::{SignalBuildError, SignalSpec};
+pub use signal_spec::{SignalBuildError, SignalSpec, DEFAULT_MAX_BUILD_DEPTH};
+pub use spec_registry::{CustomBuilder, SpecRegistry};
+pub use spec_validation::SpecValidationError;
 
 // <FILE>mixed-signals/src/types/mod.rs</FILE> - <DESC>Types module</DESC>
-// <VERS>END OF VERSION: 1.1.0</VERS>
+// <VERS>END OF VERSION: 1.4.0</VERS>
@@ -1,9 +1,9 @@
 // <FILE>mixed-signals/src/types/signal_or_float.rs</FILE> - <DESC>Parameter that can be static float or signal</DESC>
-// <VERS>VERSION: 1.2.0</VERS>
-// <WCTX>Migrate time types from f32 to f64</WCTX>
-// <CLOG>Updated evaluate methods to use f64 for time parameter</CLOG>
+// <VERS>VERSION: 1.4.1</VERS>
+// <WCTX>no_std core for embedded targets</WCTX>
+// <CLOG>Removed prebuilt; SignalSpec builds FrequencyMod depth into FmDepth</CLOG>
 
-use crate::traits::{Signal, SignalContext};
+use crate::traits::{Signal, SignalContext, SignalRange};
 use crate::types::{SignalBuildError, SignalSpec};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -29,7 +29,8 @@ pub enum SignalOrFloat {
     Static(f32),
     /// A dynamic signal specification
     Signal {
-        spec: SignalSpec,
+        /// Boxed so `SignalSpec` variants can hold a `SignalOrFloat`
+        spec: Box<SignalSpec>,
         #[serde(skip)]
         cache: OnceLock<Result<Box<dyn Signal>, SignalBuildError>>,
     },
@@ -55,7 +56,7 @@ impl From<SignalOrFloat> for SignalOrFloatSerde {
     fn from(value: SignalOrFloat) -> Self {
         match value {
             SignalOrFloat::Static(v) => SignalOrFloatSerde::Static(v),
-            SignalOrFloat::Signal { spec, .. } => SignalOrFloatSerde::Signal(spec),
+            SignalOrFloat::Signal { spec, .. } => SignalOrFloatSerde::Signal(*spec),
         }
     }
 }
@@ -130,6 +131,20 @@ impl SignalOrFloat {
         self.evaluate(t, &SignalContext::default())
     }
 
+    /// Range of values the parameter can take.
+    ///
+    /// A static value is the single-point range `[v, v]`; a signal reports its
+    /// built signal's `output_range()`. Invalid specs report the unit range.
+    pub fn output_range(&self) -> SignalRange {
+        match self {
+            SignalOrFloat::Static(value) => SignalRange::new(*value, *value),
+            SignalOrFloat::Signal { spec, cache } => match cache.get_or_init(|| spec.build()) {
+                Ok(signal) => signal.output_range(),
+                Err(_) => SignalRange::UNIT,
+            },
+        }
+    }
+
     /// Get the static value if this is a Static variant.
     pub fn as_static(&self) -> Option<f32> {
         match self {
@@ -141,7 +156,7 @@ impl SignalOrFloat {
     /// Get the signal spec if this is a Signal variant.
     pub fn as_signal(&self) -> Option<&SignalSpec> {
         match self {
-            SignalOrFloat::Signal { spec, .. } => Some(spec),
+            SignalOrFloat::Signal { spec, .. } => Some(spec.as_ref()),
             _ => None,
         }
     }
@@ -156,7 +171,7 @@ impl From<f32> for SignalOrFloat {
 impl From<SignalSpec> for SignalOrFloat {
     fn from(spec: SignalSpec) -> Self {
         SignalOrFloat::Signal {
-            spec,
+            spec: Box::new(spec),
             cache: OnceLock::new(),
         }
     }
@@ -246,7 +261,23 @@ mod tests {
             assert!(cache.get().is_some());
         }
     }
+
+    #[test]
+    fn output_range_static_and_signal() {
+        assert_eq!(
+            SignalOrFloat::Static(0.3).output_range(),
+            SignalRange::new(0.3, 0.3)
+        );
+        let param = SignalOrFloat::from(SignalSpec::Sine {
+            frequency: 1.0,
+            amplitude: 0.5,
+            offset: 2.0,
+            phase: 0.0,
+            harmonics: vec![],
+        });
+        assert_eq!(param.output_range(), SignalRange::new(1.5, 2.5));
+    }
 }
 
 // <FILE>mixed-signals/src/types/signal_or_float.rs</FILE> - <DESC>Parameter that can be static float or signal</DESC>
-// <VERS>END OF VERSION: 1.2.0</VERS>
+// <VERS>END OF VERSION: 1.4.1</VERS>
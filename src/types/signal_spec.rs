@@ -1,21 +1,36 @@
 // <FILE>mixed-signals/src/types/signal_spec.rs</FILE> - <DESC>SignalSpec enum for serialization</DESC>
-// <VERS>VERSION: 2.1.0</VERS>
-// <WCTX>Deduplication refactor</WCTX>
-// <CLOG>Deprecated Sum/Scale variants (now build to Add/Multiply)
+// <VERS>VERSION: 2.42.0</VERS>
+// <WCTX>One blend operator</WCTX>
+// <CLOG>DryWet spec builds a Mix</CLOG>
 
-use crate::composition::{Add, FrequencyMod, Mix, Multiply, VcaCentered};
-use crate::envelopes::{Adsr, Impact, LinearEnvelope};
+use crate::composition::{
+    Add, Crossfade, Delay, FmDepth, FrequencyMod, Mix, Multiply, SampleHold, TriggerEdge,
+    TrueFrequencyMod, VcaCentered,
+};
+use crate::easing::{CubicBezier, EaseParams, EasingType};
+use crate::envelopes::{Adsr, BreakpointEnvelope, GatedAdsr, Impact, LinearEnvelope};
 use crate::generators::{
-    Constant, Keyframes, PhaseAccumulator, PhaseSine, Pulse, Ramp, Sawtooth, Sine, Square, Step,
-    Triangle,
+    BankWaveform, CellularAutomaton, Constant, Detune, Ensemble, Interpolation, Keyframes,
+    OscillatorBank, PhaseAccumulator, PhaseSine, Pulse, Ramp, Sawtooth, Sequence, Sine, Square,
+    Step, Triangle, Wavetable, MAX_CA_STEPS_PER_SECOND,
+};
+use crate::noise::{PerlinNoise, SimplexNoise, ValueNoise, WhiteNoise, WorleyNoise};
+use crate::physics::{
+    BallisticTrajectory, BouncingDrop, CircularOrbit, DampedSpring, FrictionDecay, PointAttractor,
+    SimplePendulum, DEFAULT_FRICTION_STOP_THRESHOLD,
+};
+use crate::processing::{
+    moving_average_window_error, time_warp_resolution_error, Abs, BezierEase, Clamp, Eased, Gate,
+    HoldAfter, Iir, Invert, LoopBlend, MovingAverage, Quantize, QuantizeToSet, Remap, SlewLimit,
+    StartAt, TimeWarp, WaveFolder,
 };
-use crate::noise::{PerlinNoise, WhiteNoise};
-use crate::processing::{Abs, Clamp, Invert, Quantize, Remap};
 use crate::random::{
-    CorrelatedNoise, GaussianNoise, ImpulseNoise, PerCharacterNoise, PinkNoise, PoissonNoise,
-    SeededRandom, SpatialNoise, StudentTNoise,
+    CorrelatedNoise, FastCorrelatedNoise, FastPinkNoise, FastSeededRandom, GaussianNoise,
+    ImpulseNoise, Interp, PerCharacterNoise, PinkNoise, PoissonNoise, SeededRandom, SmoothRandom,
+    SpatialNoise, StudentTNoise,
 };
 use crate::traits::Signal;
+use crate::types::{SignalOrFloat, SpecRegistry, SpecValidationError};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -25,6 +40,22 @@ pub enum SignalBuildError {
     Poisson(String),
     Correlated(String),
     StudentT(String),
+    Table(String),
+    Worley(String),
+    /// Spec nesting exceeded the build depth limit (carries the limit)
+    TooDeep(usize),
+    /// No builder registered for this custom kind
+    UnknownCustom(String),
+    /// A registered custom builder rejected its params
+    Custom(String),
+    /// [`SignalSpec::build_validated`] found problems (carries all of them)
+    Invalid(Vec<SpecValidationError>),
+    /// Window longer than [`MAX_WINDOW_SAMPLES`](crate::processing::MAX_WINDOW_SAMPLES)
+    MovingAverage(String),
+    /// Rate above [`MAX_CA_STEPS_PER_SECOND`]
+    CellularAutomaton(String),
+    /// Resolution outside `1..=`[`MAX_WARP_RESOLUTION`](crate::processing::MAX_WARP_RESOLUTION)
+    TimeWarp(String),
 }
 
 impl fmt::Display for SignalBuildError {
@@ -36,6 +67,29 @@ impl fmt::Display for SignalBuildError {
                 write!(f, "CorrelatedNoise build failed: {}", msg)
             }
             SignalBuildError::StudentT(msg) => write!(f, "StudentTNoise build failed: {}", msg),
+            SignalBuildError::Table(msg) => write!(f, "Table build failed: {}", msg),
+            SignalBuildError::Worley(msg) => write!(f, "WorleyNoise build failed: {}", msg),
+            SignalBuildError::TooDeep(limit) => {
+                write!(f, "SignalSpec nesting exceeds maximum depth of {}", limit)
+            }
+            SignalBuildError::UnknownCustom(kind) => {
+                write!(f, "No builder registered for custom spec kind '{}'", kind)
+            }
+            SignalBuildError::Custom(msg) => write!(f, "Custom spec build failed: {}", msg),
+            SignalBuildError::Invalid(errors) => {
+                write!(f, "SignalSpec has {} validation error(s)", errors.len())?;
+                for error in errors {
+                    write!(f, "; {}", error)?;
+                }
+                Ok(())
+            }
+            SignalBuildError::MovingAverage(msg) => {
+                write!(f, "MovingAverage build failed: {}", msg)
+            }
+            SignalBuildError::CellularAutomaton(msg) => {
+                write!(f, "CellularAutomaton build failed: {}", msg)
+            }
+            SignalBuildError::TimeWarp(msg) => write!(f, "TimeWarp build failed: {}", msg),
         }
     }
 }
@@ -59,6 +113,9 @@ pub enum SignalSpec {
         offset: f32,
         #[serde(default)]
         phase: f32,
+        /// Overtones as (harmonic, amplitude) pairs
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        harmonics: Vec<(u32, f32)>,
     },
     Triangle {
         #[serde(default = "default_frequency")]
@@ -69,6 +126,9 @@ pub enum SignalSpec {
         offset: f32,
         #[serde(default)]
         phase: f32,
+        /// Fraction of the cycle spent rising (0.5 = symmetric)
+        #[serde(default = "default_skew")]
+        skew: f32,
     },
     Square {
         #[serde(default = "default_frequency")]
@@ -123,6 +183,16 @@ pub enum SignalSpec {
         start: f32,
         end: f32,
     },
+    /// Live-cell density of an elementary cellular automaton per generation.
+    CellularAutomaton {
+        rule: u8,
+        #[serde(default = "default_ca_width")]
+        width: usize,
+        #[serde(default)]
+        seed: u64,
+        #[serde(default = "default_one")]
+        steps_per_second: f32,
+    },
 
     // === Noise ===
     WhiteNoise {
@@ -145,6 +215,44 @@ pub enum SignalSpec {
         #[serde(default = "default_persistence")]
         persistence: f32,
     },
+    /// Gradient noise that crosses zero at every lattice point.
+    Simplex {
+        #[serde(default)]
+        seed: u64,
+        #[serde(default = "default_one")]
+        scale: f32,
+        #[serde(default = "default_amplitude")]
+        amplitude: f32,
+        #[serde(default = "default_octaves")]
+        octaves: u8,
+        #[serde(default = "default_persistence")]
+        persistence: f32,
+    },
+    /// Cellular noise: distance to the nearest feature point.
+    Worley {
+        #[serde(default)]
+        seed: u64,
+        #[serde(default = "default_one")]
+        scale: f32,
+        #[serde(default = "default_amplitude")]
+        amplitude: f32,
+        /// "euclidean", "manhattan", or "chebyshev"
+        #[serde(default = "default_metric")]
+        metric: String,
+    },
+    /// Lattice value noise with a quintic fade.
+    Value {
+        #[serde(default)]
+        seed: u64,
+        #[serde(default = "default_one")]
+        scale: f32,
+        #[serde(default = "default_amplitude")]
+        amplitude: f32,
+        #[serde(default = "default_octaves")]
+        octaves: u8,
+        #[serde(default = "default_persistence")]
+        persistence: f32,
+    },
 
     // === Random/RNG ===
     SeededRandom {
@@ -200,6 +308,9 @@ pub enum SignalSpec {
         amplitude: f32,
         #[serde(default)]
         offset: f32,
+        /// Voss-McCartney row count (None = classic five weighted rows)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rows: Option<usize>,
     },
     PerCharacterNoise {
         #[serde(default)]
@@ -243,11 +354,39 @@ pub enum SignalSpec {
         #[serde(default = "default_one")]
         peak: f32,
     },
+    /// ADSR with stage times in seconds and release at a note-off time.
+    GatedAdsr {
+        #[serde(default = "default_attack")]
+        attack: f32,
+        #[serde(default = "default_decay")]
+        decay: f32,
+        #[serde(default = "default_sustain")]
+        sustain: f32,
+        #[serde(default = "default_release")]
+        release: f32,
+        #[serde(default = "default_one")]
+        peak: f32,
+        /// Note-off time in seconds (omit to hold forever)
+        #[serde(default)]
+        gate: Option<f32>,
+    },
+    /// Impact hit; omit the rebound and double-hit fields for a plain decay.
     Impact {
         #[serde(default = "default_one")]
         intensity: f32,
         #[serde(default = "default_decay_rate")]
         decay: f32,
+        /// Rebound frequency in Hz (0 = no rebounds)
+        #[serde(default)]
+        frequency: f32,
+        /// Rebounds before settling (omit for unlimited)
+        #[serde(default)]
+        bounces: Option<u32>,
+        /// Secondary hit delay in seconds (0 = single hit)
+        #[serde(default)]
+        double_hit_delay: f32,
+        #[serde(default = "default_one")]
+        double_hit_level: f32,
     },
     LinearEnvelope {
         #[serde(default = "default_attack")]
@@ -257,6 +396,12 @@ pub enum SignalSpec {
         #[serde(default = "default_one")]
         peak: f32,
     },
+    /// Linear ramps through (duration, target) segments from an initial value.
+    BreakpointEnvelope {
+        #[serde(default)]
+        initial: f32,
+        segments: Vec<(f32, f32)>,
+    },
 
     // === Composition ===
     Add {
@@ -288,6 +433,17 @@ pub enum SignalSpec {
     FrequencyMod {
         carrier: Box<SignalSpec>,
         modulator: Box<SignalSpec>,
+        /// Modulation depth: a number, or a spec sampled per t
+        #[serde(default = "default_fm_depth")]
+        depth: SignalOrFloat,
+        #[serde(default = "default_frequency")]
+        carrier_freq: f32,
+    },
+    /// True FM: sine at `carrier_freq + depth * modulator` Hz, integrated
+    /// over time. Stateful; sample with increasing t.
+    TrueFrequencyMod {
+        modulator: Box<SignalSpec>,
+        /// Frequency deviation in Hz per unit of modulator
         #[serde(default = "default_one")]
         depth: f32,
         #[serde(default = "default_frequency")]
@@ -297,6 +453,20 @@ pub enum SignalSpec {
         carrier: Box<SignalSpec>,
         amplitude: Box<SignalSpec>,
     },
+    /// Sample & hold: captures `source` when `trigger` crosses `threshold`.
+    /// Set `trigger_period` for periodic clocks to skip the backward scan.
+    SampleHold {
+        source: Box<SignalSpec>,
+        trigger: Box<SignalSpec>,
+        #[serde(default)]
+        threshold: f32,
+        #[serde(default)]
+        edge: TriggerEdge,
+        #[serde(default)]
+        trigger_period: Option<f32>,
+        #[serde(default = "default_sample_hold_resolution")]
+        resolution: f32,
+    },
     PhaseAccumulator {
         frequency: Box<SignalSpec>,
         #[serde(default)]
@@ -306,11 +476,51 @@ pub enum SignalSpec {
     PhaseSine {
         phase: Box<SignalSpec>,
     },
-    /// Piecewise linear interpolation between keyframe points.
+    /// Piecewise interpolation between keyframe points (linear by default).
     /// Enables arbitrary curve shapes that can't be expressed with oscillators.
     Keyframes {
         /// List of (time, value) keyframe pairs
         keyframes: Vec<(f32, f32)>,
+        /// Interpolation leaving each keyframe, parallel to `keyframes`;
+        /// missing entries are linear
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        modes: Vec<Interpolation>,
+    },
+    /// Baked single-cycle lookup table played as a wavetable oscillator.
+    Table {
+        /// One cycle of the waveform at evenly spaced phases
+        values: Vec<f32>,
+        #[serde(default = "default_frequency")]
+        frequency: f32,
+    },
+    /// Chorus-like sum of sine voices with slowly drifting phase offsets.
+    Ensemble {
+        #[serde(default = "default_frequency")]
+        frequency: f32,
+        #[serde(default = "default_voices")]
+        voices: u32,
+        /// Maximum per-voice phase offset in cycles
+        #[serde(default = "default_spread")]
+        spread: f32,
+        seed: u64,
+    },
+    /// Detuned stack of oscillator voices, normalized to [-1, 1].
+    OscillatorBank {
+        #[serde(default = "default_frequency")]
+        frequency: f32,
+        #[serde(default = "default_voices")]
+        voices: u32,
+        /// Outermost voice offset, e.g. `{"hz": 2.0}` or `{"cents": 15.0}`
+        #[serde(default)]
+        detune: Detune,
+        /// Per-step amplitude falloff away from the centre voice
+        #[serde(default = "default_one")]
+        falloff: f32,
+        #[serde(default)]
+        waveform: BankWaveform,
+        /// Seed for per-voice starting phases (omit for aligned voices)
+        #[serde(default)]
+        seed: Option<u64>,
     },
 
     // === Processing ===
@@ -326,6 +536,13 @@ pub enum SignalSpec {
         #[serde(default = "default_levels")]
         levels: u8,
     },
+    /// Snap to the nearest of `values` (ties go to the lower value).
+    QuantizeToSet {
+        signal: Box<SignalSpec>,
+        /// Snap targets, any order; empty passes the input through
+        #[serde(default)]
+        values: Vec<f32>,
+    },
     Remap {
         signal: Box<SignalSpec>,
         #[serde(default = "default_zero")]
@@ -343,12 +560,274 @@ pub enum SignalSpec {
     Abs {
         signal: Box<SignalSpec>,
     },
+    /// Amplify by gain and fold back into [-1, 1] at the edges.
+    WaveFolder {
+        signal: Box<SignalSpec>,
+        #[serde(default = "default_one")]
+        gain: f32,
+    },
+    /// Direct-form IIR filter; `a_coeffs` starts with the a0 term.
+    Iir {
+        signal: Box<SignalSpec>,
+        b_coeffs: Vec<f32>,
+        a_coeffs: Vec<f32>,
+        #[serde(default = "default_sample_rate")]
+        sample_rate: f32,
+    },
+    /// Boxcar average over the last `window_seconds` of input.
+    MovingAverage {
+        signal: Box<SignalSpec>,
+        window_seconds: f32,
+        #[serde(default = "default_sample_rate")]
+        sample_rate: f32,
+    },
+    /// Limit the rate of change in units per second (portamento).
+    SlewLimit {
+        signal: Box<SignalSpec>,
+        rise: f32,
+        /// Falling rate; defaults to `rise`
+        #[serde(default)]
+        fall: Option<f32>,
+    },
+    /// Variable playback speed: output = signal(∫₀ᵗ rate).
+    TimeWarp {
+        signal: Box<SignalSpec>,
+        rate: Box<SignalSpec>,
+        /// Integration steps per second
+        #[serde(default = "default_warp_resolution")]
+        resolution: f32,
+    },
+    /// Blend of an unprocessed and a processed signal (0 = dry, 1 = wet).
+    DryWet {
+        dry: Box<SignalSpec>,
+        wet: Box<SignalSpec>,
+        #[serde(default = "default_one")]
+        mix: f32,
+    },
+    /// Time shift: output = signal(t - offset).
+    Delay {
+        signal: Box<SignalSpec>,
+        #[serde(default)]
+        offset: f32,
+    },
+    /// Blend from `a` to `b` driven by `blend` (clamped to [0, 1]).
+    Crossfade {
+        a: Box<SignalSpec>,
+        b: Box<SignalSpec>,
+        blend: Box<SignalSpec>,
+    },
+    /// Consecutive `(signal, duration)` segments, each sampled with local time.
+    Sequence {
+        segments: Vec<(SignalSpec, f32)>,
+        /// Repeat the sequence instead of holding the final value
+        #[serde(default)]
+        looping: bool,
+    },
+    /// Noise gate: outputs `floor` while |signal| < threshold.
+    Gate {
+        signal: Box<SignalSpec>,
+        #[serde(default)]
+        threshold: f32,
+        #[serde(default)]
+        floor: f32,
+        /// Lower close threshold for hysteresis (None = plain gate)
+        #[serde(default)]
+        close_threshold: Option<f32>,
+    },
+    /// Follow `signal` until `hold_time`, then hold the value it had there.
+    HoldAfter {
+        signal: Box<SignalSpec>,
+        #[serde(default)]
+        hold_time: f32,
+    },
+    /// Output `before` until `start_time`, then `signal(t - start_time)`.
+    StartAt {
+        signal: Box<SignalSpec>,
+        #[serde(default)]
+        start_time: f32,
+        #[serde(default)]
+        before: f32,
+    },
+    /// Repeat the first `period` seconds, crossfading the last `blend` seconds
+    /// of each lap into the next so the wrap is seamless.
+    LoopBlend {
+        signal: Box<SignalSpec>,
+        #[serde(default = "default_one")]
+        period: f32,
+        #[serde(default)]
+        blend: f32,
+    },
+    /// Shape a [0, 1] signal through an easing curve (overshoot preserved).
+    Eased {
+        signal: Box<SignalSpec>,
+        #[serde(default)]
+        easing: EasingType,
+        /// Back overshoot / Elastic amplitude and period
+        #[serde(default)]
+        params: EaseParams,
+    },
+    /// Shape a [0, 1] signal through a CSS cubic-bezier curve.
+    BezierEase {
+        signal: Box<SignalSpec>,
+        /// Control points; defaults to CSS `ease`
+        #[serde(default)]
+        curve: CubicBezier,
+    },
+
+    // === Physics ===
+    /// Damped harmonic oscillator displacement.
+    DampedSpring {
+        #[serde(default = "default_one")]
+        mass: f32,
+        #[serde(default = "default_spring_stiffness")]
+        stiffness: f32,
+        #[serde(default = "default_spring_damping")]
+        damping: f32,
+        #[serde(default)]
+        v0: f32,
+        #[serde(default = "default_one")]
+        x0: f32,
+    },
+    /// Ball dropped under gravity, bouncing with energy loss.
+    BouncingDrop {
+        #[serde(default)]
+        start_height: f32,
+        #[serde(default = "default_ground_height")]
+        ground_height: f32,
+        #[serde(default = "default_screen_gravity")]
+        gravity: f32,
+        #[serde(default = "default_restitution")]
+        restitution: f32,
+    },
+    /// Position of a body coasting to a stop under exponential drag.
+    FrictionDecay {
+        #[serde(default = "default_friction_v0")]
+        v0: f32,
+        #[serde(default = "default_drag")]
+        drag: f32,
+        /// Speed below which the motion counts as stopped
+        #[serde(default = "default_stop_threshold")]
+        stop_threshold: f32,
+    },
+    /// Small-angle pendulum angle with optional damping.
+    SimplePendulum {
+        #[serde(default = "default_one")]
+        length: f32,
+        #[serde(default = "default_earth_gravity")]
+        gravity: f32,
+        #[serde(default = "default_theta0")]
+        theta0: f32,
+        #[serde(default = "default_pendulum_damping")]
+        damping: f32,
+    },
+    /// Uniform circular motion around a center point.
+    CircularOrbit {
+        #[serde(default)]
+        center_x: f32,
+        #[serde(default)]
+        center_y: f32,
+        #[serde(default = "default_orbit_radius")]
+        radius: f32,
+        #[serde(default = "default_angular_velocity")]
+        angular_velocity: f32,
+        #[serde(default)]
+        start_phase: f32,
+    },
+    /// Inverse-square pull (or push) toward a fixed point.
+    PointAttractor {
+        #[serde(default)]
+        target_x: f32,
+        #[serde(default)]
+        target_y: f32,
+        #[serde(default = "default_attractor_strength")]
+        strength: f32,
+    },
+    /// Projectile under constant gravity with an optional ground plane.
+    BallisticTrajectory {
+        #[serde(default)]
+        start_x: f32,
+        #[serde(default)]
+        start_y: f32,
+        #[serde(default = "default_launch_vx")]
+        v0_x: f32,
+        #[serde(default = "default_launch_vy")]
+        v0_y: f32,
+        #[serde(default = "default_screen_gravity")]
+        gravity: f32,
+        #[serde(default = "default_ground_y")]
+        ground_y: Option<f32>,
+    },
+
+    // === Frame-driven Random ===
+    /// Like SeededRandom, but hashes frame-resolution time for speed.
+    FastSeededRandom {
+        #[serde(default)]
+        seed: u64,
+        #[serde(default = "default_amplitude")]
+        amplitude: f32,
+        #[serde(default)]
+        offset: f32,
+    },
+    /// Correlated noise driven by `SignalContext::frame` (t is ignored).
+    FastCorrelatedNoise {
+        #[serde(default)]
+        seed: u64,
+        #[serde(default = "default_correlation")]
+        correlation: f32,
+        #[serde(default = "default_amplitude")]
+        amplitude: f32,
+        #[serde(default)]
+        offset: f32,
+    },
+    /// Pink noise driven by `SignalContext::frame` (t is ignored).
+    FastPinkNoise {
+        #[serde(default)]
+        seed: u64,
+        #[serde(default = "default_amplitude")]
+        amplitude: f32,
+        #[serde(default)]
+        offset: f32,
+        /// Voss-McCartney row count (None = classic five weighted rows)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rows: Option<usize>,
+    },
+    /// Random targets at `rate_hz`, interpolated (bipolar smooth LFO).
+    SmoothRandom {
+        #[serde(default)]
+        seed: u64,
+        #[serde(default = "default_one")]
+        rate_hz: f32,
+        #[serde(default = "default_amplitude")]
+        amplitude: f32,
+        #[serde(default)]
+        interpolation: Interp,
+    },
+
+    // === Extensions ===
+    /// Application-defined signal, built by a [`SpecRegistry`] builder for `kind`.
+    ///
+    /// Only [`build_with`](SignalSpec::build_with) can build these; plain
+    /// [`build`](SignalSpec::build) returns [`SignalBuildError::UnknownCustom`].
+    Custom {
+        kind: String,
+        #[serde(default)]
+        params: serde_json::Value,
+    },
 }
 
 // Default value functions
 fn default_frequency() -> f32 {
     1.0
 }
+fn default_skew() -> f32 {
+    0.5
+}
+fn default_voices() -> u32 {
+    3
+}
+fn default_spread() -> f32 {
+    0.1
+}
 fn default_amplitude() -> f32 {
     1.0
 }
@@ -358,6 +837,9 @@ fn default_duty() -> f32 {
 fn default_one() -> f32 {
     1.0
 }
+fn default_fm_depth() -> SignalOrFloat {
+    SignalOrFloat::Static(1.0)
+}
 fn default_half() -> f32 {
     0.5
 }
@@ -367,9 +849,15 @@ fn default_zero() -> f32 {
 fn default_sample_rate() -> f32 {
     60.0
 }
+fn default_ca_width() -> usize {
+    64
+}
 fn default_octaves() -> u8 {
     1
 }
+fn default_metric() -> String {
+    "euclidean".to_string()
+}
 fn default_persistence() -> f32 {
     0.5
 }
@@ -385,6 +873,12 @@ fn default_sustain() -> f32 {
 fn default_release() -> f32 {
     0.2
 }
+fn default_warp_resolution() -> f32 {
+    crate::processing::DEFAULT_WARP_RESOLUTION
+}
+fn default_sample_hold_resolution() -> f32 {
+    crate::composition::DEFAULT_SAMPLE_HOLD_RESOLUTION
+}
 fn default_decay_rate() -> f32 {
     3.0
 }
@@ -412,44 +906,430 @@ fn default_impulse_rate() -> f32 {
 fn default_impulse_width() -> f32 {
     0.001
 }
+fn default_spring_stiffness() -> f32 {
+    100.0
+}
+fn default_spring_damping() -> f32 {
+    10.0
+}
+fn default_ground_height() -> f32 {
+    200.0
+}
+fn default_screen_gravity() -> f32 {
+    500.0
+}
+fn default_restitution() -> f32 {
+    0.6
+}
+fn default_friction_v0() -> f32 {
+    200.0
+}
+fn default_drag() -> f32 {
+    4.0
+}
+fn default_stop_threshold() -> f32 {
+    DEFAULT_FRICTION_STOP_THRESHOLD
+}
+fn default_earth_gravity() -> f32 {
+    9.8
+}
+fn default_theta0() -> f32 {
+    0.2
+}
+fn default_pendulum_damping() -> f32 {
+    0.1
+}
+fn default_orbit_radius() -> f32 {
+    50.0
+}
+fn default_angular_velocity() -> f32 {
+    std::f32::consts::TAU
+}
+fn default_attractor_strength() -> f32 {
+    100.0
+}
+fn default_launch_vx() -> f32 {
+    100.0
+}
+fn default_launch_vy() -> f32 {
+    -100.0
+}
+fn default_ground_y() -> Option<f32> {
+    Some(300.0)
+}
+
+/// Default maximum nesting depth accepted by [`SignalSpec::build`].
+pub const DEFAULT_MAX_BUILD_DEPTH: usize = 256;
 
 impl SignalSpec {
     /// Build a boxed Signal from this specification.
+    ///
+    /// Nesting deeper than [`DEFAULT_MAX_BUILD_DEPTH`] returns
+    /// [`SignalBuildError::TooDeep`] instead of overflowing the stack.
     pub fn build(&self) -> Result<Box<dyn Signal>, SignalBuildError> {
-        match self {
-            // Oscillators
-            SignalSpec::Sine {
-                frequency,
-                amplitude,
-                offset,
-                phase,
-            } => Ok(Box::new(Sine::new(*frequency, *amplitude, *offset, *phase))),
+        self.build_with_limit(DEFAULT_MAX_BUILD_DEPTH)
+    }
 
-            SignalSpec::Triangle {
-                frequency,
-                amplitude,
-                offset,
-                phase,
-            } => Ok(Box::new(Triangle::new(
-                *frequency, *amplitude, *offset, *phase,
-            ))),
+    /// Build with a custom maximum nesting depth.
+    ///
+    /// A leaf spec has depth 1; each composition level adds 1.
+    pub fn build_with_limit(&self, limit: usize) -> Result<Box<dyn Signal>, SignalBuildError> {
+        self.build_at_depth(1, limit, None)
+    }
 
-            SignalSpec::Square {
-                frequency,
-                amplitude,
-                offset,
-                phase,
-                duty,
-            } => Ok(Box::new(Square::new(
-                *frequency, *amplitude, *offset, *phase, *duty,
-            ))),
+    /// Build, resolving [`SignalSpec::Custom`] specs through `registry`.
+    ///
+    /// Unregistered kinds return [`SignalBuildError::UnknownCustom`]. The
+    /// depth limit is [`DEFAULT_MAX_BUILD_DEPTH`], as for [`build`](Self::build).
+    pub fn build_with(&self, registry: &SpecRegistry) -> Result<Box<dyn Signal>, SignalBuildError> {
+        self.build_at_depth(1, DEFAULT_MAX_BUILD_DEPTH, Some(registry))
+    }
 
-            SignalSpec::Sawtooth {
-                frequency,
-                amplitude,
-                offset,
-                phase,
-                inverted,
+    fn build_at_depth(
+        &self,
+        depth: usize,
+        limit: usize,
+        registry: Option<&SpecRegistry>,
+    ) -> Result<Box<dyn Signal>, SignalBuildError> {
+        if depth > limit {
+            return Err(SignalBuildError::TooDeep(limit));
+        }
+
+        // Dispatch only: each recursion level pays for this small frame plus
+        // the one category builder it lands in, never for every arm at once.
+        #[allow(deprecated)]
+        match self {
+            SignalSpec::Add { .. }
+            | SignalSpec::Multiply { .. }
+            | SignalSpec::Scale { .. }
+            | SignalSpec::Sum { .. }
+            | SignalSpec::Mix { .. }
+            | SignalSpec::FrequencyMod { .. }
+            | SignalSpec::TrueFrequencyMod { .. }
+            | SignalSpec::VcaCentered { .. }
+            | SignalSpec::SampleHold { .. }
+            | SignalSpec::PhaseAccumulator { .. }
+            | SignalSpec::PhaseSine { .. } => self.build_composition(depth, limit, registry),
+            SignalSpec::Custom { kind, params } => match registry {
+                Some(registry) => registry.build(kind, params),
+                None => Err(SignalBuildError::UnknownCustom(kind.clone())),
+            },
+            other => other.build_processing(depth, limit, registry),
+        }
+    }
+
+    /// Build composition specs.
+    #[inline(never)]
+    fn build_composition(
+        &self,
+        depth: usize,
+        limit: usize,
+        registry: Option<&SpecRegistry>,
+    ) -> Result<Box<dyn Signal>, SignalBuildError> {
+        let child = |spec: &SignalSpec| spec.build_at_depth(depth + 1, limit, registry);
+
+        match self {
+            SignalSpec::Add { a, b } => Ok(Box::new(Add::new(child(a)?, child(b)?))),
+
+            SignalSpec::Multiply { a, b } => Ok(Box::new(Multiply::new(child(a)?, child(b)?))),
+
+            #[allow(deprecated)]
+            SignalSpec::Scale { a, b } => Ok(Box::new(Multiply::new(child(a)?, child(b)?))),
+
+            #[allow(deprecated)]
+            SignalSpec::Sum { a, b } => Ok(Box::new(Add::new(child(a)?, child(b)?))),
+
+            SignalSpec::Mix { a, b, mix } => Ok(Box::new(Mix::new(child(a)?, child(b)?, *mix))),
+
+            SignalSpec::FrequencyMod {
+                carrier,
+                modulator,
+                depth,
+                carrier_freq,
+            } => {
+                // Build a signal depth as a child so the depth limit and
+                // registry apply to it like any other nested spec
+                let depth = match depth {
+                    SignalOrFloat::Static(value) => FmDepth::Static(*value),
+                    SignalOrFloat::Signal { spec, .. } => FmDepth::Signal(child(spec)?),
+                };
+                Ok(Box::new(FrequencyMod::new(
+                    child(carrier)?,
+                    child(modulator)?,
+                    depth,
+                    *carrier_freq,
+                )))
+            }
+
+            SignalSpec::TrueFrequencyMod {
+                modulator,
+                depth,
+                carrier_freq,
+            } => Ok(Box::new(TrueFrequencyMod::new(
+                child(modulator)?,
+                *depth,
+                *carrier_freq,
+            ))),
+
+            SignalSpec::VcaCentered { carrier, amplitude } => Ok(Box::new(VcaCentered::new(
+                child(carrier)?,
+                child(amplitude)?,
+            ))),
+
+            SignalSpec::SampleHold {
+                source,
+                trigger,
+                threshold,
+                edge,
+                trigger_period,
+                resolution,
+            } => {
+                let held = SampleHold::new(child(source)?, child(trigger)?)
+                    .with_threshold(*threshold)
+                    .with_edge(*edge)
+                    .with_resolution(*resolution);
+                Ok(match trigger_period {
+                    Some(period) => Box::new(held.with_trigger_period(*period)),
+                    None => Box::new(held),
+                })
+            }
+
+            SignalSpec::PhaseAccumulator {
+                frequency,
+                initial_phase,
+            } => Ok(Box::new(PhaseAccumulator::new(
+                child(frequency)?,
+                *initial_phase,
+            ))),
+
+            SignalSpec::PhaseSine { phase } => Ok(Box::new(PhaseSine::new(child(phase)?))),
+
+            _ => unreachable!("build_at_depth only dispatches composition specs here"),
+        }
+    }
+
+    /// Build processing and sequencing specs, falling through to
+    /// [`build_leaf`](Self::build_leaf) for specs without children.
+    #[inline(never)]
+    fn build_processing(
+        &self,
+        depth: usize,
+        limit: usize,
+        registry: Option<&SpecRegistry>,
+    ) -> Result<Box<dyn Signal>, SignalBuildError> {
+        let child = |spec: &SignalSpec| spec.build_at_depth(depth + 1, limit, registry);
+
+        match self {
+            SignalSpec::Clamp { signal, min, max } => {
+                Ok(Box::new(Clamp::new(child(signal)?, *min, *max)))
+            }
+
+            SignalSpec::Quantize { signal, levels } => {
+                Ok(Box::new(Quantize::new(child(signal)?, *levels)))
+            }
+
+            SignalSpec::QuantizeToSet { signal, values } => {
+                Ok(Box::new(QuantizeToSet::new(child(signal)?, values.clone())))
+            }
+
+            SignalSpec::Remap {
+                signal,
+                in_min,
+                in_max,
+                out_min,
+                out_max,
+            } => Ok(Box::new(Remap::new(
+                child(signal)?,
+                *in_min,
+                *in_max,
+                *out_min,
+                *out_max,
+            ))),
+
+            SignalSpec::Invert { signal } => Ok(Box::new(Invert::new(child(signal)?))),
+
+            SignalSpec::Abs { signal } => Ok(Box::new(Abs::new(child(signal)?))),
+
+            SignalSpec::WaveFolder { signal, gain } => {
+                Ok(Box::new(WaveFolder::new(child(signal)?, *gain)))
+            }
+
+            SignalSpec::Iir {
+                signal,
+                b_coeffs,
+                a_coeffs,
+                sample_rate,
+            } => Ok(Box::new(Iir::new(
+                child(signal)?,
+                b_coeffs.clone(),
+                a_coeffs.clone(),
+                *sample_rate,
+            ))),
+
+            SignalSpec::MovingAverage {
+                signal,
+                window_seconds,
+                sample_rate,
+            } => {
+                if let Some(msg) = moving_average_window_error(*window_seconds, *sample_rate) {
+                    return Err(SignalBuildError::MovingAverage(msg));
+                }
+                Ok(Box::new(MovingAverage::new(
+                    child(signal)?,
+                    *window_seconds,
+                    *sample_rate,
+                )))
+            }
+
+            SignalSpec::SlewLimit { signal, rise, fall } => Ok(Box::new(SlewLimit::with_rates(
+                child(signal)?,
+                *rise,
+                fall.unwrap_or(*rise),
+            ))),
+
+            SignalSpec::TimeWarp {
+                signal,
+                rate,
+                resolution,
+            } => {
+                if let Some(msg) = time_warp_resolution_error(*resolution) {
+                    return Err(SignalBuildError::TimeWarp(msg));
+                }
+                Ok(Box::new(
+                    TimeWarp::new(child(signal)?, child(rate)?).with_resolution(*resolution),
+                ))
+            }
+
+            SignalSpec::DryWet { dry, wet, mix } => {
+                Ok(Box::new(Mix::new(child(dry)?, child(wet)?, *mix)))
+            }
+
+            SignalSpec::Delay { signal, offset } => {
+                Ok(Box::new(Delay::new(child(signal)?, *offset)))
+            }
+
+            SignalSpec::Crossfade { a, b, blend } => Ok(Box::new(Crossfade::new(
+                child(a)?,
+                child(b)?,
+                child(blend)?,
+            ))),
+
+            SignalSpec::Sequence { segments, looping } => {
+                let mut sequence = Sequence::new().looping(*looping);
+                for (spec, duration) in segments {
+                    sequence = sequence.then_boxed(child(spec)?, *duration);
+                }
+                Ok(Box::new(sequence))
+            }
+
+            SignalSpec::Gate {
+                signal,
+                threshold,
+                floor,
+                close_threshold,
+            } => Ok(Self::build_gate(
+                child(signal)?,
+                *threshold,
+                *floor,
+                *close_threshold,
+            )),
+
+            SignalSpec::HoldAfter { signal, hold_time } => {
+                Ok(Box::new(HoldAfter::new(child(signal)?, *hold_time)))
+            }
+
+            SignalSpec::StartAt {
+                signal,
+                start_time,
+                before,
+            } => Ok(Box::new(
+                StartAt::new(child(signal)?, *start_time).with_before(*before),
+            )),
+
+            SignalSpec::LoopBlend {
+                signal,
+                period,
+                blend,
+            } => Ok(Box::new(LoopBlend::new(child(signal)?, *period, *blend))),
+
+            SignalSpec::Eased {
+                signal,
+                easing,
+                params,
+            } => Ok(Box::new(
+                Eased::new(child(signal)?, *easing).with_params(*params),
+            )),
+
+            SignalSpec::BezierEase { signal, curve } => {
+                Ok(Box::new(BezierEase::new(child(signal)?, *curve)))
+            }
+
+            leaf => leaf.build_leaf(),
+        }
+    }
+
+    /// Gate construction, out of line for the same stack reason as
+    /// [`build_leaf`](Self::build_leaf).
+    #[inline(never)]
+    fn build_gate(
+        signal: Box<dyn Signal>,
+        threshold: f32,
+        floor: f32,
+        close_threshold: Option<f32>,
+    ) -> Box<dyn Signal> {
+        let gate = Gate::new(signal, threshold).with_floor(floor);
+        match close_threshold {
+            Some(close) => Box::new(gate.with_hysteresis(close)),
+            None => Box::new(gate),
+        }
+    }
+
+    /// Build a spec with no child specs.
+    ///
+    /// Kept out of `build_at_depth` so the recursive frame only holds the
+    /// composite arms; otherwise debug builds can overflow a default thread
+    /// stack well before [`DEFAULT_MAX_BUILD_DEPTH`].
+    #[inline(never)]
+    fn build_leaf(&self) -> Result<Box<dyn Signal>, SignalBuildError> {
+        match self {
+            // Oscillators
+            SignalSpec::Sine {
+                frequency,
+                amplitude,
+                offset,
+                phase,
+                harmonics,
+            } => Ok(Box::new(Sine {
+                harmonics: harmonics.clone().into(),
+                ..Sine::new(*frequency, *amplitude, *offset, *phase)
+            })),
+
+            SignalSpec::Triangle {
+                frequency,
+                amplitude,
+                offset,
+                phase,
+                skew,
+            } => Ok(Box::new(
+                Triangle::new(*frequency, *amplitude, *offset, *phase).with_skew(*skew),
+            )),
+
+            SignalSpec::Square {
+                frequency,
+                amplitude,
+                offset,
+                phase,
+                duty,
+            } => Ok(Box::new(Square::new(
+                *frequency, *amplitude, *offset, *phase, *duty,
+            ))),
+
+            SignalSpec::Sawtooth {
+                frequency,
+                amplitude,
+                offset,
+                phase,
+                inverted,
             } => Ok(Box::new(Sawtooth::new(
                 *frequency, *amplitude, *offset, *phase, *inverted,
             ))),
@@ -476,6 +1356,26 @@ impl SignalSpec {
                 end,
             } => Ok(Box::new(Pulse::new(*low, *high, *start, *end))),
 
+            SignalSpec::CellularAutomaton {
+                rule,
+                width,
+                seed,
+                steps_per_second,
+            } => {
+                if *steps_per_second > MAX_CA_STEPS_PER_SECOND {
+                    return Err(SignalBuildError::CellularAutomaton(format!(
+                        "steps_per_second {} exceeds MAX_CA_STEPS_PER_SECOND ({})",
+                        steps_per_second, MAX_CA_STEPS_PER_SECOND
+                    )));
+                }
+                Ok(Box::new(CellularAutomaton::new(
+                    *rule,
+                    *width,
+                    *seed,
+                    *steps_per_second,
+                )))
+            }
+
             // Noise
             SignalSpec::WhiteNoise {
                 seed,
@@ -493,6 +1393,38 @@ impl SignalSpec {
                 PerlinNoise::new(*seed, *scale, *amplitude).with_octaves(*octaves, *persistence),
             )),
 
+            SignalSpec::Simplex {
+                seed,
+                scale,
+                amplitude,
+                octaves,
+                persistence,
+            } => Ok(Box::new(
+                SimplexNoise::new(*seed, *scale, *amplitude).with_octaves(*octaves, *persistence),
+            )),
+
+            SignalSpec::Worley {
+                seed,
+                scale,
+                amplitude,
+                metric,
+            } => {
+                let metric = metric.parse().map_err(SignalBuildError::Worley)?;
+                Ok(Box::new(
+                    WorleyNoise::new(*seed, *scale, *amplitude).with_metric(metric),
+                ))
+            }
+
+            SignalSpec::Value {
+                seed,
+                scale,
+                amplitude,
+                octaves,
+                persistence,
+            } => Ok(Box::new(
+                ValueNoise::new(*seed, *scale, *amplitude).with_octaves(*octaves, *persistence),
+            )),
+
             // Random/RNG
             SignalSpec::SeededRandom {
                 seed,
@@ -537,7 +1469,14 @@ impl SignalSpec {
                 seed,
                 amplitude,
                 offset,
-            } => Ok(Box::new(PinkNoise::new(*seed, *amplitude, *offset))),
+                rows,
+            } => {
+                let noise = PinkNoise::new(*seed, *amplitude, *offset);
+                Ok(Box::new(match rows {
+                    Some(rows) => noise.with_rows(*rows),
+                    None => noise,
+                }))
+            }
 
             SignalSpec::PerCharacterNoise {
                 base_seed,
@@ -578,8 +1517,32 @@ impl SignalSpec {
                 Adsr::new(*attack, *decay, *sustain, *release).with_peak(*peak),
             )),
 
-            SignalSpec::Impact { intensity, decay } => {
-                Ok(Box::new(Impact::new(*intensity, *decay)))
+            SignalSpec::GatedAdsr {
+                attack,
+                decay,
+                sustain,
+                release,
+                peak,
+                gate,
+            } => {
+                let mut env = GatedAdsr::new(*attack, *decay, *sustain, *release).with_peak(*peak);
+                env.gate = *gate;
+                Ok(Box::new(env))
+            }
+
+            SignalSpec::Impact {
+                intensity,
+                decay,
+                frequency,
+                bounces,
+                double_hit_delay,
+                double_hit_level,
+            } => {
+                let mut env = Impact::new(*intensity, *decay)
+                    .with_frequency(*frequency)
+                    .with_double_hit(*double_hit_delay, *double_hit_level);
+                env.bounces = *bounces;
+                Ok(Box::new(env))
             }
 
             SignalSpec::LinearEnvelope {
@@ -590,73 +1553,155 @@ impl SignalSpec {
                 LinearEnvelope::new(*attack, *release).with_peak(*peak),
             )),
 
-            // Composition
-            SignalSpec::Add { a, b } => Ok(Box::new(Add::new(a.build()?, b.build()?))),
+            SignalSpec::BreakpointEnvelope { initial, segments } => Ok(Box::new(
+                BreakpointEnvelope::from_segments(segments.clone()).with_initial(*initial),
+            )),
 
-            SignalSpec::Multiply { a, b } => Ok(Box::new(Multiply::new(a.build()?, b.build()?))),
+            // Generators
+            SignalSpec::Keyframes { keyframes, modes } => {
+                Ok(Box::new(Keyframes::from_pairs_with_modes(keyframes, modes)))
+            }
 
-            #[allow(deprecated)]
-            SignalSpec::Scale { a, b } => Ok(Box::new(Multiply::new(a.build()?, b.build()?))),
+            SignalSpec::Table { values, frequency } => Wavetable::new(values.clone(), *frequency)
+                .map(|table| Box::new(table) as Box<dyn Signal>)
+                .map_err(SignalBuildError::Table),
 
-            #[allow(deprecated)]
-            SignalSpec::Sum { a, b } => Ok(Box::new(Add::new(a.build()?, b.build()?))),
+            SignalSpec::Ensemble {
+                frequency,
+                voices,
+                spread,
+                seed,
+            } => Ok(Box::new(Ensemble::new(*frequency, *voices, *spread, *seed))),
 
-            SignalSpec::Mix { a, b, mix } => Ok(Box::new(Mix::new(a.build()?, b.build()?, *mix))),
+            SignalSpec::OscillatorBank {
+                frequency,
+                voices,
+                detune,
+                falloff,
+                waveform,
+                seed,
+            } => {
+                let bank = OscillatorBank::new(*frequency, *voices, *detune)
+                    .with_falloff(*falloff)
+                    .with_waveform(*waveform);
+                Ok(Box::new(match seed {
+                    Some(seed) => bank.with_seed(*seed),
+                    None => bank,
+                }))
+            }
 
-            SignalSpec::FrequencyMod {
-                carrier,
-                modulator,
-                depth,
-                carrier_freq,
-            } => Ok(Box::new(FrequencyMod::new(
-                carrier.build()?,
-                modulator.build()?,
-                *depth,
-                *carrier_freq,
+            // Physics
+            SignalSpec::DampedSpring {
+                mass,
+                stiffness,
+                damping,
+                v0,
+                x0,
+            } => Ok(Box::new(DampedSpring::new(
+                *mass, *stiffness, *damping, *v0, *x0,
             ))),
 
-            SignalSpec::VcaCentered { carrier, amplitude } => Ok(Box::new(VcaCentered::new(
-                carrier.build()?,
-                amplitude.build()?,
+            SignalSpec::BouncingDrop {
+                start_height,
+                ground_height,
+                gravity,
+                restitution,
+            } => Ok(Box::new(BouncingDrop::new(
+                *start_height,
+                *ground_height,
+                *gravity,
+                *restitution,
             ))),
 
-            SignalSpec::PhaseAccumulator {
-                frequency,
-                initial_phase,
-            } => Ok(Box::new(PhaseAccumulator::new(
-                frequency.build()?,
-                *initial_phase,
-            ))),
-            SignalSpec::PhaseSine { phase } => Ok(Box::new(PhaseSine::new(phase.build()?))),
+            SignalSpec::FrictionDecay {
+                v0,
+                drag,
+                stop_threshold,
+            } => Ok(Box::new(
+                FrictionDecay::new(*v0, *drag).with_stop_threshold(*stop_threshold),
+            )),
 
-            SignalSpec::Keyframes { keyframes } => Ok(Box::new(Keyframes::from_pairs(keyframes))),
+            SignalSpec::SimplePendulum {
+                length,
+                gravity,
+                theta0,
+                damping,
+            } => Ok(Box::new(SimplePendulum::new(
+                *length, *gravity, *theta0, *damping,
+            ))),
 
-            // Processing
-            SignalSpec::Clamp { signal, min, max } => {
-                Ok(Box::new(Clamp::new(signal.build()?, *min, *max)))
-            }
+            SignalSpec::CircularOrbit {
+                center_x,
+                center_y,
+                radius,
+                angular_velocity,
+                start_phase,
+            } => Ok(Box::new(CircularOrbit::new(
+                *center_x,
+                *center_y,
+                *radius,
+                *angular_velocity,
+                *start_phase,
+            ))),
 
-            SignalSpec::Quantize { signal, levels } => {
-                Ok(Box::new(Quantize::new(signal.build()?, *levels)))
-            }
+            SignalSpec::PointAttractor {
+                target_x,
+                target_y,
+                strength,
+            } => Ok(Box::new(PointAttractor::new(
+                *target_x, *target_y, *strength,
+            ))),
 
-            SignalSpec::Remap {
-                signal,
-                in_min,
-                in_max,
-                out_min,
-                out_max,
-            } => Ok(Box::new(Remap::new(
-                signal.build()?,
-                *in_min,
-                *in_max,
-                *out_min,
-                *out_max,
+            SignalSpec::BallisticTrajectory {
+                start_x,
+                start_y,
+                v0_x,
+                v0_y,
+                gravity,
+                ground_y,
+            } => Ok(Box::new(BallisticTrajectory::new(
+                *start_x, *start_y, *v0_x, *v0_y, *gravity, *ground_y,
             ))),
 
-            SignalSpec::Invert { signal } => Ok(Box::new(Invert::new(signal.build()?))),
+            // Frame-driven Random
+            SignalSpec::FastSeededRandom {
+                seed,
+                amplitude,
+                offset,
+            } => Ok(Box::new(FastSeededRandom::new(*seed, *amplitude, *offset))),
+
+            SignalSpec::FastCorrelatedNoise {
+                seed,
+                correlation,
+                amplitude,
+                offset,
+            } => FastCorrelatedNoise::new(*seed, *correlation, *amplitude, *offset)
+                .map(|noise| Box::new(noise) as Box<dyn Signal>)
+                .map_err(SignalBuildError::Correlated),
+
+            SignalSpec::FastPinkNoise {
+                seed,
+                amplitude,
+                offset,
+                rows,
+            } => {
+                let noise = FastPinkNoise::new(*seed, *amplitude, *offset);
+                Ok(Box::new(match rows {
+                    Some(rows) => noise.with_rows(*rows),
+                    None => noise,
+                }))
+            }
+
+            SignalSpec::SmoothRandom {
+                seed,
+                rate_hz,
+                amplitude,
+                interpolation,
+            } => Ok(Box::new(
+                SmoothRandom::new(*seed, *rate_hz, *amplitude).with_interpolation(*interpolation),
+            )),
 
-            SignalSpec::Abs { signal } => Ok(Box::new(Abs::new(signal.build()?))),
+            _ => unreachable!("composite specs are built by build_at_depth"),
         }
     }
 }
@@ -664,6 +1709,7 @@ impl SignalSpec {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::traits::SignalContext;
 
     #[test]
     fn test_build_sine() {
@@ -672,6 +1718,7 @@ mod tests {
             amplitude: 0.5,
             offset: 0.0,
             phase: 0.0,
+            harmonics: vec![],
         };
         let signal = spec.build().unwrap();
         // At t=0.125 (quarter cycle at 2Hz), should be at peak (0.5)
@@ -695,6 +1742,7 @@ mod tests {
             amplitude: 1.0,
             offset: 0.5,
             phase: 0.25,
+            harmonics: vec![],
         };
         let json = serde_json::to_string(&spec).unwrap();
         let parsed: SignalSpec = serde_json::from_str(&json).unwrap();
@@ -709,12 +1757,14 @@ mod tests {
                 amplitude: 1.0,
                 offset: 0.0,
                 phase: 0.0,
+                harmonics: vec![],
             }),
             b: Box::new(SignalSpec::Triangle {
                 frequency: 2.0,
                 amplitude: 0.5,
                 offset: 0.0,
                 phase: 0.0,
+                skew: 0.5,
             }),
             mix: 0.3,
         };
@@ -733,7 +1783,1045 @@ mod tests {
         };
         assert!(spec.build().is_err());
     }
+
+    fn add_chain(depth: usize) -> SignalSpec {
+        let mut spec = SignalSpec::Constant { value: 0.0 };
+        for _ in 1..depth {
+            spec = SignalSpec::Add {
+                a: Box::new(spec),
+                b: Box::new(SignalSpec::Constant { value: 0.0 }),
+            };
+        }
+        spec
+    }
+
+    /// Tear down a deep chain iteratively (recursive drop would overflow).
+    fn drop_chain(mut spec: SignalSpec) {
+        while let SignalSpec::Add { a, .. } = spec {
+            spec = *a;
+        }
+    }
+
+    #[test]
+    fn test_build_rejects_deep_nesting() {
+        let spec = add_chain(10_000);
+        assert_eq!(
+            spec.build().err(),
+            Some(SignalBuildError::TooDeep(DEFAULT_MAX_BUILD_DEPTH))
+        );
+        drop_chain(spec);
+    }
+
+    #[test]
+    fn test_build_with_limit() {
+        let spec = add_chain(10);
+        assert!(spec.build_with_limit(10).is_ok());
+        assert_eq!(
+            spec.build_with_limit(9).err(),
+            Some(SignalBuildError::TooDeep(9))
+        );
+        assert!(spec.build().is_ok());
+    }
+
+    #[test]
+    fn test_build_frequency_mod_signal_depth() {
+        let sine = |frequency| SignalSpec::Sine {
+            frequency,
+            amplitude: 1.0,
+            offset: 0.0,
+            phase: 0.0,
+            harmonics: vec![],
+        };
+        let fm = |depth: SignalOrFloat| SignalSpec::FrequencyMod {
+            carrier: Box::new(sine(1.0)),
+            modulator: Box::new(sine(3.0)),
+            depth,
+            carrier_freq: 1.0,
+        };
+        let ramp = SignalSpec::Ramp {
+            start: 0.0,
+            end: 2.0,
+            duration: 1.0,
+        };
+        let swept = fm(ramp.into()).build().unwrap();
+        let carrier = sine(1.0).build().unwrap();
+        assert_eq!(swept.sample(0.0), carrier.sample(0.0));
+        assert!((swept.sample(0.4) - carrier.sample(0.4)).abs() > 0.01);
+
+        let bad_depth = SignalSpec::GaussianNoise {
+            seed: 0,
+            std_dev: -1.0,
+            amplitude: 1.0,
+            offset: 0.0,
+        };
+        assert!(matches!(
+            fm(bad_depth.into()).build(),
+            Err(SignalBuildError::Gaussian(_))
+        ));
+    }
+
+    fn fm_depth_chain(levels: usize) -> SignalSpec {
+        let mut spec = SignalSpec::Constant { value: 1.0 };
+        for _ in 0..levels {
+            spec = SignalSpec::FrequencyMod {
+                carrier: Box::new(SignalSpec::Constant { value: 0.5 }),
+                modulator: Box::new(SignalSpec::Constant { value: 0.5 }),
+                depth: spec.into(),
+                carrier_freq: 1.0,
+            };
+        }
+        spec
+    }
+
+    #[test]
+    fn test_build_frequency_mod_depth_counts_toward_limit() {
+        // Nine FrequencyMod levels around a leaf: depth 10
+        let spec = fm_depth_chain(9);
+        assert!(spec.build_with_limit(10).is_ok());
+        assert_eq!(
+            spec.build_with_limit(9).err(),
+            Some(SignalBuildError::TooDeep(9))
+        );
+    }
+
+    #[test]
+    fn test_build_frequency_mod_depth_uses_registry() {
+        let spec = SignalSpec::FrequencyMod {
+            carrier: Box::new(SignalSpec::Constant { value: 0.5 }),
+            modulator: Box::new(SignalSpec::Constant { value: 0.5 }),
+            depth: SignalSpec::Custom {
+                kind: "octave_sine".into(),
+                params: serde_json::Value::default(),
+            }
+            .into(),
+            carrier_freq: 1.0,
+        };
+        assert_eq!(
+            spec.build().err(),
+            Some(SignalBuildError::UnknownCustom("octave_sine".into()))
+        );
+
+        let registry = SpecRegistry::new().with_builder("octave_sine", octave_sine);
+        assert!(spec.build_with(&registry).is_ok());
+    }
+
+    #[test]
+    fn test_build_true_frequency_mod() {
+        let spec = SignalSpec::TrueFrequencyMod {
+            modulator: Box::new(SignalSpec::Constant { value: 0.5 }),
+            depth: 4.0,
+            carrier_freq: 3.0,
+        };
+        // Constant modulator detunes 3 Hz to 5 Hz
+        let signal = spec.build().unwrap();
+        let t = 0.05;
+        let expected = (std::f64::consts::TAU * 5.0 * t).sin() as f32;
+        assert!((signal.sample(t) - expected).abs() < 1e-5);
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_build_sample_hold() {
+        let spec = SignalSpec::SampleHold {
+            source: Box::new(SignalSpec::WhiteNoise {
+                seed: 3,
+                amplitude: 1.0,
+                sample_rate: 1000.0,
+            }),
+            trigger: Box::new(SignalSpec::Square {
+                frequency: 4.0,
+                amplitude: 1.0,
+                offset: 0.0,
+                phase: 0.0,
+                duty: 0.5,
+            }),
+            threshold: 0.0,
+            edge: TriggerEdge::Rising,
+            trigger_period: Some(0.25),
+            resolution: 0.001,
+        };
+        let signal = spec.build().unwrap();
+        assert_eq!(signal.sample(0.3), signal.sample(0.45));
+        assert_ne!(signal.sample(0.45), signal.sample(0.55));
+        assert!(spec.validate().is_ok());
+    }
+
+    fn octave_sine(_: &serde_json::Value) -> Result<Box<dyn Signal>, SignalBuildError> {
+        Ok(Box::new(Sine::with_frequency(2.0)))
+    }
+
+    #[test]
+    fn test_build_custom_requires_registry() {
+        let custom = SignalSpec::Custom {
+            kind: "octave_sine".into(),
+            params: serde_json::Value::default(),
+        };
+        let spec = SignalSpec::Multiply {
+            a: Box::new(custom),
+            b: Box::new(SignalSpec::Constant { value: 0.5 }),
+        };
+        assert_eq!(
+            spec.build().err(),
+            Some(SignalBuildError::UnknownCustom("octave_sine".into()))
+        );
+        assert!(spec.build_with(&SpecRegistry::new()).is_err());
+
+        let registry = SpecRegistry::new().with_builder("octave_sine", octave_sine);
+        let signal = spec.build_with(&registry).unwrap();
+        assert!((signal.sample(0.125) - 0.5).abs() < 1e-6);
+    }
+
+    fn sine_table(len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| (std::f32::consts::TAU * i as f32 / len as f32).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_build_table_approximates_sine() {
+        let spec = SignalSpec::Table {
+            values: sine_table(128),
+            frequency: 3.0,
+        };
+        let table = spec.build().unwrap();
+        let sine = Sine::with_frequency(3.0);
+        for i in 0..100 {
+            let t = i as f64 * 0.0071;
+            assert!((table.sample(t) - sine.sample(t)).abs() < 2e-3);
+        }
+    }
+
+    #[test]
+    fn test_build_empty_table_returns_err() {
+        let spec = SignalSpec::Table {
+            values: vec![],
+            frequency: 1.0,
+        };
+        assert!(matches!(spec.build(), Err(SignalBuildError::Table(_))));
+    }
+
+    #[test]
+    fn test_serde_frequency_mod_depth_number_or_spec() {
+        // Existing files with a plain number still load
+        let json = r#"{"type": "frequency_mod", "carrier": {"type": "sine"},
+            "modulator": {"type": "sine", "frequency": 2.0}, "depth": 1.5}"#;
+        let spec: SignalSpec = serde_json::from_str(json).unwrap();
+        let SignalSpec::FrequencyMod { depth, .. } = &spec else {
+            panic!("expected FrequencyMod, got {spec:?}");
+        };
+        assert_eq!(depth.as_static(), Some(1.5));
+
+        let json = r#"{"type": "frequency_mod", "carrier": {"type": "sine"},
+            "modulator": {"type": "sine"},
+            "depth": {"type": "ramp", "start": 0.0, "end": 1.0, "duration": 1.0}}"#;
+        let spec: SignalSpec = serde_json::from_str(json).unwrap();
+        let SignalSpec::FrequencyMod { depth, .. } = &spec else {
+            panic!("expected FrequencyMod, got {spec:?}");
+        };
+        assert!(matches!(depth.as_signal(), Some(SignalSpec::Ramp { .. })));
+        let parsed: SignalSpec =
+            serde_json::from_str(&serde_json::to_string(&spec).unwrap()).unwrap();
+        assert_eq!(parsed, spec);
+    }
+
+    #[test]
+    fn test_serde_table_roundtrip() {
+        let spec = SignalSpec::Table {
+            values: sine_table(16),
+            frequency: 2.0,
+        };
+        let json = serde_json::to_string(&spec).unwrap();
+        let parsed: SignalSpec = serde_json::from_str(&json).unwrap();
+        assert_eq!(spec, parsed);
+    }
+
+    #[test]
+    fn test_build_triangle_skew() {
+        let spec = SignalSpec::Triangle {
+            frequency: 1.0,
+            amplitude: 1.0,
+            offset: 0.0,
+            phase: 0.0,
+            skew: 0.25,
+        };
+        let signal = spec.build().unwrap();
+        // Peak reached a quarter of the way through the cycle
+        assert!((signal.sample(0.25) - 1.0).abs() < 0.001);
+        assert!((signal.sample(0.625) - 0.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_build_sine_harmonics() {
+        let spec = SignalSpec::Sine {
+            frequency: 1.0,
+            amplitude: 1.0,
+            offset: 0.0,
+            phase: 0.0,
+            harmonics: vec![(2, 0.5)],
+        };
+        let signal = spec.build().unwrap();
+        let expected = Sine::with_frequency(1.0).with_harmonic(2, 0.5);
+        for i in 0..20 {
+            let t = i as f64 * 0.05;
+            assert_eq!(signal.sample(t), expected.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_build_oscillator_bank() {
+        let spec = SignalSpec::OscillatorBank {
+            frequency: 50.0,
+            voices: 2,
+            detune: Detune::Hz(1.0),
+            falloff: 1.0,
+            waveform: BankWaveform::Sine,
+            seed: None,
+        };
+        let bank = spec.build().unwrap();
+        let direct = OscillatorBank::new(50.0, 2, Detune::Hz(1.0));
+        for i in 0..50 {
+            let t = i as f64 * 0.017;
+            assert_eq!(bank.sample(t), direct.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_build_ensemble_single_voice_is_sine() {
+        let spec = SignalSpec::Ensemble {
+            frequency: 2.0,
+            voices: 1,
+            spread: 0.2,
+            seed: 5,
+        };
+        let ensemble = spec.build().unwrap();
+        let sine = Sine::with_frequency(2.0);
+        for i in 0..50 {
+            let t = i as f64 * 0.021;
+            assert!((ensemble.sample(t) - sine.sample(t)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_build_gated_adsr() {
+        let spec = SignalSpec::GatedAdsr {
+            attack: 0.1,
+            decay: 0.1,
+            sustain: 0.5,
+            release: 1.0,
+            peak: 1.0,
+            gate: Some(2.0),
+        };
+        let env = spec.build().unwrap();
+        assert!((env.sample(1.0) - 0.5).abs() < 1e-6);
+        assert!((env.sample(2.5) - 0.25).abs() < 1e-6);
+        assert_eq!(env.sample(3.5), 0.0);
+    }
+
+    #[test]
+    fn test_build_impact_bounces() {
+        let spec = SignalSpec::Impact {
+            intensity: 1.0,
+            decay: 2.0,
+            frequency: 4.0,
+            bounces: Some(2),
+            double_hit_delay: 0.0,
+            double_hit_level: 1.0,
+        };
+        let expected = Impact::new(1.0, 2.0).with_frequency(4.0).with_bounces(2);
+        let env = spec.build().unwrap();
+        for i in 0..40 {
+            let t = i as f64 * 0.025;
+            assert_eq!(env.sample(t), expected.sample(t));
+        }
+        assert_eq!(env.sample(0.7), 0.0);
+    }
+
+    #[test]
+    fn test_build_breakpoint_envelope() {
+        let spec = SignalSpec::BreakpointEnvelope {
+            initial: 0.2,
+            segments: vec![(1.0, 1.0), (0.5, 0.0)],
+        };
+        let env = spec.build().unwrap();
+        assert!((env.sample(0.0) - 0.2).abs() < 1e-6);
+        assert!((env.sample(0.5) - 0.6).abs() < 1e-6);
+        assert!((env.sample(1.0) - 1.0).abs() < 1e-6);
+        assert!((env.sample(1.25) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_build_wave_folder() {
+        let spec = SignalSpec::WaveFolder {
+            signal: Box::new(SignalSpec::Constant { value: 0.75 }),
+            gain: 2.0,
+        };
+        let folder = spec.build().unwrap();
+        assert!((folder.sample(0.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_build_iir() {
+        let spec = SignalSpec::Iir {
+            signal: Box::new(SignalSpec::Constant { value: 1.0 }),
+            b_coeffs: vec![0.5],
+            a_coeffs: vec![1.0, -0.5],
+            sample_rate: 100.0,
+        };
+        let iir = spec.build().unwrap();
+        assert!((iir.sample(0.01) - 0.5).abs() < 1e-6);
+        assert!((iir.sample(0.02) - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_build_moving_average() {
+        let spec = SignalSpec::MovingAverage {
+            signal: Box::new(SignalSpec::Ramp {
+                start: 0.0,
+                end: 1.0,
+                duration: 1.0,
+            }),
+            window_seconds: 0.5,
+            sample_rate: 4.0,
+        };
+        let avg = spec.build().unwrap();
+        assert!((avg.sample(0.0) - 0.0).abs() < 1e-6);
+        assert!((avg.sample(0.25) - 0.125).abs() < 1e-6);
+        assert!((avg.sample(0.5) - 0.375).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_build_moving_average_rejects_huge_window() {
+        let spec = SignalSpec::MovingAverage {
+            signal: Box::new(SignalSpec::Constant { value: 0.25 }),
+            window_seconds: 1e30,
+            sample_rate: 1000.0,
+        };
+        assert!(matches!(
+            spec.build(),
+            Err(SignalBuildError::MovingAverage(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_slew_limit() {
+        let spec = SignalSpec::SlewLimit {
+            signal: Box::new(SignalSpec::Step {
+                before: 0.0,
+                after: 1.0,
+                threshold: 0.0,
+            }),
+            rise: 2.0,
+            fall: None,
+        };
+        let slewed = spec.build().unwrap();
+        assert_eq!(slewed.sample(-0.1), 0.0);
+        assert!((slewed.sample(0.1) - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_build_time_warp() {
+        let spec = SignalSpec::TimeWarp {
+            signal: Box::new(SignalSpec::Ramp {
+                start: 0.0,
+                end: 10.0,
+                duration: 10.0,
+            }),
+            rate: Box::new(SignalSpec::Step {
+                before: 1.0,
+                after: 2.0,
+                threshold: 1.0,
+            }),
+            resolution: 100.0,
+        };
+        let warped = spec.build().unwrap();
+        assert!((warped.sample(1.0) - 1.0).abs() < 1e-5);
+        assert!((warped.sample(2.0) - 3.0).abs() < 1e-5);
+
+        let too_fine = SignalSpec::TimeWarp {
+            signal: Box::new(SignalSpec::Constant { value: 0.0 }),
+            rate: Box::new(SignalSpec::Constant { value: 1.0 }),
+            resolution: 1e9,
+        };
+        assert!(matches!(
+            too_fine.build(),
+            Err(SignalBuildError::TimeWarp(_))
+        ));
+    }
+
+    fn noise_specs() -> Vec<SignalSpec> {
+        vec![
+            SignalSpec::Simplex {
+                seed: 4,
+                scale: 2.0,
+                amplitude: 0.8,
+                octaves: 3,
+                persistence: 0.5,
+            },
+            SignalSpec::Worley {
+                seed: 4,
+                scale: 2.0,
+                amplitude: 0.8,
+                metric: "manhattan".to_string(),
+            },
+            SignalSpec::Value {
+                seed: 4,
+                scale: 2.0,
+                amplitude: 0.8,
+                octaves: 3,
+                persistence: 0.5,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_serde_noise_family_roundtrip() {
+        for spec in noise_specs() {
+            let json = serde_json::to_string(&spec).unwrap();
+            let parsed: SignalSpec = serde_json::from_str(&json).unwrap();
+            assert_eq!(spec, parsed);
+        }
+        let parsed: SignalSpec = serde_json::from_str(r#"{"type": "worley"}"#).unwrap();
+        assert_eq!(
+            parsed,
+            SignalSpec::Worley {
+                seed: 0,
+                scale: 1.0,
+                amplitude: 1.0,
+                metric: "euclidean".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_build_noise_family_finite_and_deterministic() {
+        for spec in noise_specs() {
+            let a = spec.build().unwrap();
+            let b = spec.build().unwrap();
+            for i in 0..200 {
+                let t = i as f64 * 0.037;
+                let v = a.sample(t);
+                assert!(v.is_finite() && v.abs() <= 0.8 + 1e-6, "{:?}: {}", spec, v);
+                assert_eq!(v, b.sample(t));
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_worley_unknown_metric_returns_err() {
+        let spec = SignalSpec::Worley {
+            seed: 0,
+            scale: 1.0,
+            amplitude: 1.0,
+            metric: "taxicab".to_string(),
+        };
+        assert!(matches!(spec.build(), Err(SignalBuildError::Worley(_))));
+    }
+
+    #[test]
+    fn test_build_dry_wet() {
+        let dry = SignalSpec::Sine {
+            frequency: 1.0,
+            amplitude: 1.0,
+            offset: 0.0,
+            phase: 0.0,
+            harmonics: vec![],
+        };
+        let wet = SignalSpec::Abs {
+            signal: Box::new(dry.clone()),
+        };
+        let build = |mix: f32| {
+            SignalSpec::DryWet {
+                dry: Box::new(dry.clone()),
+                wet: Box::new(wet.clone()),
+                mix,
+            }
+            .build()
+            .unwrap()
+        };
+        let (all_dry, all_wet) = (build(0.0), build(1.0));
+        for i in 0..40 {
+            let t = 0.5 + i as f64 * 0.0125;
+            assert_eq!(all_dry.sample(t), dry.build().unwrap().sample(t));
+            assert_eq!(all_wet.sample(t), wet.build().unwrap().sample(t));
+        }
+    }
+
+    #[test]
+    fn test_serde_dry_wet_default_mix_is_wet() {
+        let json = r#"{"type":"dry_wet","dry":{"type":"constant","value":0.2},"wet":{"type":"constant","value":0.8}}"#;
+        let spec: SignalSpec = serde_json::from_str(json).unwrap();
+        assert!(matches!(spec, SignalSpec::DryWet { mix, .. } if mix == 1.0));
+    }
+
+    #[test]
+    fn test_build_cellular_automaton() {
+        let build = |rule: u8| {
+            SignalSpec::CellularAutomaton {
+                rule,
+                width: 32,
+                seed: 5,
+                steps_per_second: 2.0,
+            }
+            .build()
+            .unwrap()
+        };
+        let (dead, full) = (build(0), build(255));
+        assert_eq!(dead.sample(1.0), 0.0);
+        assert_eq!(full.sample(1.0), 1.0);
+        assert_eq!(build(30).sample(3.0), build(30).sample(3.0));
+
+        let too_fast = SignalSpec::CellularAutomaton {
+            rule: 30,
+            width: 32,
+            seed: 5,
+            steps_per_second: 1e9,
+        };
+        assert!(matches!(
+            too_fast.build(),
+            Err(SignalBuildError::CellularAutomaton(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_delay() {
+        let impact = SignalSpec::Impact {
+            intensity: 1.0,
+            decay: 5.0,
+            frequency: 0.0,
+            bounces: None,
+            double_hit_delay: 0.0,
+            double_hit_level: 1.0,
+        };
+        let spec = SignalSpec::Delay {
+            signal: Box::new(impact.clone()),
+            offset: 0.3,
+        };
+        let (plain, delayed) = (impact.build().unwrap(), spec.build().unwrap());
+        for i in 0..20 {
+            let t = i as f64 * 0.05;
+            assert!((delayed.sample(t + 0.3) - plain.sample(t)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_build_crossfade() {
+        let spec = SignalSpec::Crossfade {
+            a: Box::new(SignalSpec::Constant { value: 0.2 }),
+            b: Box::new(SignalSpec::Constant { value: 0.8 }),
+            blend: Box::new(SignalSpec::Ramp {
+                start: 0.0,
+                end: 1.0,
+                duration: 2.0,
+            }),
+        };
+        let fade = spec.build().unwrap();
+        assert!((fade.sample(0.0) - 0.2).abs() < 1e-6);
+        assert!((fade.sample(1.0) - 0.5).abs() < 1e-6);
+        assert!((fade.sample(3.0) - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_build_sequence() {
+        let spec = SignalSpec::Sequence {
+            segments: vec![
+                (
+                    SignalSpec::Ramp {
+                        start: 0.0,
+                        end: 1.0,
+                        duration: 1.0,
+                    },
+                    1.0,
+                ),
+                (SignalSpec::Constant { value: 0.25 }, 0.5),
+            ],
+            looping: false,
+        };
+        let seq = spec.build().unwrap();
+        assert!((seq.sample(0.5) - 0.5).abs() < 1e-6);
+        assert_eq!(seq.sample(1.2), 0.25);
+        assert_eq!(seq.sample(9.0), 0.25);
+        assert_eq!(
+            SignalSpec::Sequence {
+                segments: vec![],
+                looping: true
+            }
+            .build()
+            .unwrap()
+            .sample(1.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_build_bouncing_drop_matches_code() {
+        let spec = SignalSpec::BouncingDrop {
+            start_height: 10.0,
+            ground_height: 120.0,
+            gravity: 400.0,
+            restitution: 0.5,
+        };
+        let built = spec.build().unwrap();
+        let direct = BouncingDrop::new(10.0, 120.0, 400.0, 0.5);
+        for i in 0..40 {
+            let t = i as f64 * 0.05;
+            assert_eq!(built.sample(t), direct.sample(t));
+        }
+        assert_eq!(built.output_range(), direct.output_range());
+    }
+
+    #[test]
+    fn test_build_physics_variants() {
+        let cases: Vec<(SignalSpec, Box<dyn Signal>)> = vec![
+            (
+                SignalSpec::DampedSpring {
+                    mass: 2.0,
+                    stiffness: 50.0,
+                    damping: 3.0,
+                    v0: 1.0,
+                    x0: 0.5,
+                },
+                Box::new(DampedSpring::new(2.0, 50.0, 3.0, 1.0, 0.5)),
+            ),
+            (
+                SignalSpec::FrictionDecay {
+                    v0: 80.0,
+                    drag: 2.0,
+                    stop_threshold: 0.5,
+                },
+                Box::new(FrictionDecay::new(80.0, 2.0).with_stop_threshold(0.5)),
+            ),
+            (
+                SignalSpec::SimplePendulum {
+                    length: 2.0,
+                    gravity: 9.8,
+                    theta0: 0.3,
+                    damping: 0.0,
+                },
+                Box::new(SimplePendulum::new(2.0, 9.8, 0.3, 0.0)),
+            ),
+            (
+                SignalSpec::CircularOrbit {
+                    center_x: 5.0,
+                    center_y: -5.0,
+                    radius: 20.0,
+                    angular_velocity: 3.0,
+                    start_phase: 1.0,
+                },
+                Box::new(CircularOrbit::new(5.0, -5.0, 20.0, 3.0, 1.0)),
+            ),
+            (
+                SignalSpec::PointAttractor {
+                    target_x: 1.0,
+                    target_y: 2.0,
+                    strength: 30.0,
+                },
+                Box::new(PointAttractor::new(1.0, 2.0, 30.0)),
+            ),
+            (
+                SignalSpec::BallisticTrajectory {
+                    start_x: 0.0,
+                    start_y: 50.0,
+                    v0_x: 20.0,
+                    v0_y: -80.0,
+                    gravity: 300.0,
+                    ground_y: None,
+                },
+                Box::new(BallisticTrajectory::new(
+                    0.0, 50.0, 20.0, -80.0, 300.0, None,
+                )),
+            ),
+        ];
+        for (spec, direct) in cases {
+            let built = spec.build().unwrap();
+            for i in 0..20 {
+                let t = i as f64 * 0.1;
+                assert_eq!(built.sample(t), direct.sample(t), "{spec:?} at t={t}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_serde_bouncing_drop_json_matches_code() {
+        let json = r#"{"type":"bouncing_drop","start_height":10.0,"ground_height":120.0,"gravity":400.0,"restitution":0.5}"#;
+        let spec: SignalSpec = serde_json::from_str(json).unwrap();
+        let built = spec.build().unwrap();
+        let direct = BouncingDrop::new(10.0, 120.0, 400.0, 0.5);
+        for i in 0..40 {
+            let t = i as f64 * 0.05;
+            assert_eq!(built.sample(t), direct.sample(t));
+        }
+    }
+
+    #[test]
+    fn test_serde_physics_defaults_match_constructors() {
+        let parse = |ty: &str| -> SignalSpec {
+            serde_json::from_str(&format!(r#"{{"type":"{ty}"}}"#)).unwrap()
+        };
+        let pairs: Vec<(&str, Box<dyn Signal>)> = vec![
+            ("damped_spring", Box::new(DampedSpring::default())),
+            ("bouncing_drop", Box::new(BouncingDrop::default())),
+            ("friction_decay", Box::new(FrictionDecay::default())),
+            ("simple_pendulum", Box::new(SimplePendulum::default())),
+            ("circular_orbit", Box::new(CircularOrbit::default())),
+            ("point_attractor", Box::new(PointAttractor::default())),
+            (
+                "ballistic_trajectory",
+                Box::new(BallisticTrajectory::default()),
+            ),
+        ];
+        for (ty, direct) in pairs {
+            let built = parse(ty).build().unwrap();
+            for i in 0..10 {
+                let t = i as f64 * 0.1;
+                assert_eq!(built.sample(t), direct.sample(t), "{ty} at t={t}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_serde_physics_roundtrip() {
+        let specs = vec![
+            SignalSpec::DampedSpring {
+                mass: 1.5,
+                stiffness: 80.0,
+                damping: 4.0,
+                v0: -2.0,
+                x0: 1.0,
+            },
+            SignalSpec::BallisticTrajectory {
+                start_x: 1.0,
+                start_y: 2.0,
+                v0_x: 3.0,
+                v0_y: -4.0,
+                gravity: 9.8,
+                ground_y: None,
+            },
+            SignalSpec::CircularOrbit {
+                center_x: 0.0,
+                center_y: 0.0,
+                radius: 10.0,
+                angular_velocity: 1.0,
+                start_phase: 0.5,
+            },
+        ];
+        for spec in specs {
+            let json = serde_json::to_string(&spec).unwrap();
+            let parsed: SignalSpec = serde_json::from_str(&json).unwrap();
+            assert_eq!(spec, parsed);
+        }
+    }
+
+    #[test]
+    fn test_build_fast_noise_uses_context_frame() {
+        let specs = [
+            SignalSpec::FastSeededRandom {
+                seed: 3,
+                amplitude: 1.0,
+                offset: 0.0,
+            },
+            SignalSpec::FastCorrelatedNoise {
+                seed: 3,
+                correlation: 0.5,
+                amplitude: 1.0,
+                offset: 0.0,
+            },
+            SignalSpec::FastPinkNoise {
+                seed: 3,
+                amplitude: 1.0,
+                offset: 0.0,
+                rows: Some(10),
+            },
+        ];
+        for spec in specs {
+            let signal = spec.build().unwrap();
+            let a = signal.sample_with_context(0.0, &SignalContext::new(0, 9));
+            let b = signal.sample_with_context(0.0, &SignalContext::new(0, 10));
+            assert_ne!(a, b, "{spec:?} ignored context seed");
+        }
+        let invalid = SignalSpec::FastCorrelatedNoise {
+            seed: 0,
+            correlation: 2.0,
+            amplitude: 1.0,
+            offset: 0.0,
+        };
+        assert!(matches!(
+            invalid.build(),
+            Err(SignalBuildError::Correlated(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_smooth_random() {
+        let spec = SignalSpec::SmoothRandom {
+            seed: 4,
+            rate_hz: 2.0,
+            amplitude: 0.5,
+            interpolation: Interp::Linear,
+        };
+        let built = spec.build().unwrap();
+        let direct = SmoothRandom::new(4, 2.0, 0.5).with_interpolation(Interp::Linear);
+        for i in 0..20 {
+            let t = i as f64 * 0.07;
+            assert_eq!(built.sample(t), direct.sample(t));
+        }
+        assert_eq!(built.output_range(), direct.output_range());
+    }
+
+    #[test]
+    fn test_serde_smooth_random_defaults() {
+        let spec: SignalSpec =
+            serde_json::from_str(r#"{"type":"smooth_random","seed":4}"#).unwrap();
+        assert_eq!(
+            spec,
+            SignalSpec::SmoothRandom {
+                seed: 4,
+                rate_hz: 1.0,
+                amplitude: 1.0,
+                interpolation: Interp::Cosine,
+            }
+        );
+    }
+
+    #[test]
+    fn test_build_gate() {
+        let spec = |threshold: f32| SignalSpec::Gate {
+            signal: Box::new(SignalSpec::Constant { value: -0.3 }),
+            threshold,
+            floor: 0.1,
+            close_threshold: None,
+        };
+        assert_eq!(spec(0.2).build().unwrap().sample(0.0), -0.3);
+        assert_eq!(spec(0.5).build().unwrap().sample(0.0), 0.1);
+        assert_eq!(spec(f32::NAN).build().unwrap().sample(0.0), -0.3);
+    }
+
+    #[test]
+    fn test_build_hold_after_and_start_at() {
+        let ramp = SignalSpec::Ramp {
+            start: 0.0,
+            end: 1.0,
+            duration: 2.0,
+        };
+        let held = SignalSpec::HoldAfter {
+            signal: Box::new(ramp.clone()),
+            hold_time: 1.0,
+        }
+        .build()
+        .unwrap();
+        assert!((held.sample(1.0) - 0.5).abs() < 1e-6);
+        assert!((held.sample(9.0) - 0.5).abs() < 1e-6);
+
+        let started = SignalSpec::StartAt {
+            signal: Box::new(ramp),
+            start_time: -1.0,
+            before: 0.25,
+        }
+        .build()
+        .unwrap();
+        assert_eq!(started.sample(-0.5), 0.25);
+        assert!((started.sample(1.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_build_loop_blend() {
+        let noise = SignalSpec::Perlin {
+            seed: 5,
+            scale: 2.0,
+            amplitude: 1.0,
+            octaves: 3,
+            persistence: 0.5,
+        };
+        let looped = SignalSpec::LoopBlend {
+            signal: Box::new(noise.clone()),
+            period: 2.0,
+            blend: 0.5,
+        }
+        .build()
+        .unwrap();
+        let raw = noise.build().unwrap();
+        assert_eq!(looped.sample(0.75), raw.sample(0.75));
+        assert!((looped.sample(2.0 - 1e-6) - looped.sample(2.0)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_build_eased() {
+        let spec = SignalSpec::Eased {
+            signal: Box::new(SignalSpec::Ramp {
+                start: 0.0,
+                end: 1.0,
+                duration: 1.0,
+            }),
+            easing: EasingType::BackOut,
+            params: EaseParams::default().with_overshoot(3.0),
+        };
+        let eased = spec.build().unwrap();
+        assert_eq!(eased.sample(0.0), 0.0);
+        assert!((eased.sample(1.0) - 1.0).abs() < 1e-5);
+        assert!(eased.output_range().max > 1.2);
+    }
+
+    #[test]
+    fn test_build_bezier_ease() {
+        let spec = SignalSpec::BezierEase {
+            signal: Box::new(SignalSpec::Ramp {
+                start: 0.0,
+                end: 1.0,
+                duration: 1.0,
+            }),
+            curve: CubicBezier::ease_in_out(),
+        };
+        let eased = spec.build().unwrap();
+        assert_eq!(eased.sample(0.0), 0.0);
+        assert_eq!(eased.sample(1.0), 1.0);
+        assert!(eased.sample(0.2) < 0.2);
+    }
+
+    #[test]
+    fn test_build_keyframes_with_modes() {
+        let spec = SignalSpec::Keyframes {
+            keyframes: vec![(0.0, 0.0), (0.5, 1.0), (1.0, 0.0)],
+            modes: vec![Interpolation::Step],
+        };
+        let kf = spec.build().unwrap();
+        assert_eq!(kf.sample(0.4), 0.0);
+        assert!((kf.sample(0.75) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_build_quantize_to_set() {
+        let spec = SignalSpec::QuantizeToSet {
+            signal: Box::new(SignalSpec::Ramp {
+                start: 0.0,
+                end: 1.0,
+                duration: 1.0,
+            }),
+            values: vec![0.75, 0.25],
+        };
+        let q = spec.build().unwrap();
+        assert_eq!(q.sample(0.0), 0.25);
+        assert_eq!(q.sample(0.5), 0.25);
+        assert_eq!(q.sample(0.6), 0.75);
+        assert_eq!(
+            q.output_range(),
+            crate::traits::SignalRange::new(0.25, 0.75)
+        );
+    }
+
+    #[test]
+    fn test_build_rejects_deep_processing_nesting() {
+        let mut spec = SignalSpec::Constant { value: 0.1 };
+        for _ in 0..DEFAULT_MAX_BUILD_DEPTH + 10 {
+            spec = SignalSpec::HoldAfter {
+                signal: Box::new(spec),
+                hold_time: 1.0,
+            };
+        }
+        assert_eq!(
+            spec.build().err(),
+            Some(SignalBuildError::TooDeep(DEFAULT_MAX_BUILD_DEPTH))
+        );
+    }
 }
 
 // <FILE>mixed-signals/src/types/signal_spec.rs</FILE> - <DESC>SignalSpec enum for serialization</DESC>
-// <VERS>END OF VERSION: 2.0.0</VERS>
+// <VERS>END OF VERSION: 2.42.0</VERS>
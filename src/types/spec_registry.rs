@@ -0,0 +1,139 @@
+// <FILE>mixed-signals/src/types/spec_registry.rs</FILE> - <DESC>Registry of builders for custom SignalSpec kinds</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Application-defined signal specs</WCTX>
+// <CLOG>Initial creation - SpecRegistry keyed by custom kind</CLOG>
+
+use crate::traits::Signal;
+use crate::types::SignalBuildError;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Builds a signal from the `params` of a [`SignalSpec::Custom`](super::SignalSpec::Custom).
+pub type CustomBuilder = fn(&serde_json::Value) -> Result<Box<dyn Signal>, SignalBuildError>;
+
+/// Builders for application-defined [`SignalSpec::Custom`](super::SignalSpec::Custom) kinds.
+///
+/// `SignalSpec` is a closed enum; a registry lets an embedding application add
+/// its own JSON-configurable signals without forking. Pass it to
+/// [`SignalSpec::build_with`](super::SignalSpec::build_with). Custom specs may
+/// appear anywhere in a tree, including inside built-in composites.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Sine;
+/// use mixed_signals::traits::Signal;
+/// use mixed_signals::types::{SignalBuildError, SignalSpec, SpecRegistry};
+///
+/// fn fast_sine(_: &serde_json::Value) -> Result<Box<dyn Signal>, SignalBuildError> {
+///     Ok(Box::new(Sine::with_frequency(8.0)))
+/// }
+///
+/// let registry = SpecRegistry::new().with_builder("fast_sine", fast_sine);
+/// let spec = SignalSpec::Custom {
+///     kind: "fast_sine".into(),
+///     params: serde_json::Value::default(),
+/// };
+/// assert!(spec.build_with(&registry).is_ok());
+/// assert!(spec.build().is_err());
+/// ```
+#[derive(Clone, Default)]
+pub struct SpecRegistry {
+    builders: HashMap<String, CustomBuilder>,
+}
+
+impl SpecRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `builder` for `kind`, returning the builder it replaced.
+    pub fn register(
+        &mut self,
+        kind: impl Into<String>,
+        builder: CustomBuilder,
+    ) -> Option<CustomBuilder> {
+        self.builders.insert(kind.into(), builder)
+    }
+
+    /// Chainable form of [`register`](Self::register).
+    pub fn with_builder(mut self, kind: impl Into<String>, builder: CustomBuilder) -> Self {
+        self.register(kind, builder);
+        self
+    }
+
+    pub fn contains(&self, kind: &str) -> bool {
+        self.builders.contains_key(kind)
+    }
+
+    /// Registered kinds, in no particular order.
+    pub fn kinds(&self) -> impl Iterator<Item = &str> {
+        self.builders.keys().map(String::as_str)
+    }
+
+    /// Build `kind` from `params`, or [`SignalBuildError::UnknownCustom`].
+    pub fn build(
+        &self,
+        kind: &str,
+        params: &serde_json::Value,
+    ) -> Result<Box<dyn Signal>, SignalBuildError> {
+        match self.builders.get(kind) {
+            Some(builder) => builder(params),
+            None => Err(SignalBuildError::UnknownCustom(kind.to_string())),
+        }
+    }
+}
+
+impl fmt::Debug for SpecRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut kinds: Vec<&str> = self.kinds().collect();
+        kinds.sort_unstable();
+        f.debug_struct("SpecRegistry")
+            .field("kinds", &kinds)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    fn half(_: &serde_json::Value) -> Result<Box<dyn Signal>, SignalBuildError> {
+        Ok(Box::new(Constant::new(0.5)))
+    }
+
+    fn failing(_: &serde_json::Value) -> Result<Box<dyn Signal>, SignalBuildError> {
+        Err(SignalBuildError::Custom("missing frequency".into()))
+    }
+
+    #[test]
+    fn test_registry_builds_registered_kind() {
+        let registry = SpecRegistry::new().with_builder("half", half);
+        assert!(registry.contains("half"));
+        let signal = registry
+            .build("half", &serde_json::Value::default())
+            .unwrap();
+        assert_eq!(signal.sample(1.0), 0.5);
+    }
+
+    #[test]
+    fn test_registry_unknown_and_failing_kinds() {
+        let mut registry = SpecRegistry::new();
+        assert!(registry.register("bad", failing).is_none());
+        assert!(registry.register("bad", failing).is_some());
+        let params = serde_json::Value::default();
+        assert_eq!(
+            registry.build("nope", &params).err(),
+            Some(SignalBuildError::UnknownCustom("nope".into()))
+        );
+        assert_eq!(
+            registry.build("bad", &params).err(),
+            Some(SignalBuildError::Custom("missing frequency".into()))
+        );
+        assert_eq!(format!("{registry:?}"), "SpecRegistry { kinds: [\"bad\"] }");
+    }
+}
+
+// <FILE>mixed-signals/src/types/spec_registry.rs</FILE> - <DESC>Registry of builders for custom SignalSpec kinds</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
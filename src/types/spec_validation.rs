@@ -0,0 +1,1019 @@
+// <FILE>mixed-signals/src/types/spec_validation.rs</FILE> - <DESC>Whole-tree validation for SignalSpec</DESC>
+// <VERS>VERSION: 1.6.0</VERS>
+// <WCTX>Bounded sampling cost</WCTX>
+// <CLOG>Validate MovingAverage window, CellularAutomaton rate and TimeWarp resolution limits</CLOG>
+
+use crate::generators::{Detune, MAX_CA_STEPS_PER_SECOND};
+use crate::noise::DistanceMetric;
+use crate::processing::{moving_average_window_error, time_warp_resolution_error};
+use crate::traits::Signal;
+use crate::types::{SignalBuildError, SignalOrFloat, SignalSpec, DEFAULT_MAX_BUILD_DEPTH};
+use std::fmt;
+
+/// One problem found by [`SignalSpec::validate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecValidationError {
+    /// JSON-pointer-like location of the bad value, e.g. `/a/b/modulator/frequency`
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for SpecValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for SpecValidationError {}
+
+impl SignalSpec {
+    /// Check the whole tree and collect every problem instead of stopping at
+    /// the first.
+    ///
+    /// Reports non-finite parameters, out-of-domain values (negative
+    /// `std_dev`, `lambda <= 0`, mix factors outside [0, 1], zero quantize
+    /// levels, ...), empty keyframe and table lists, unknown Worley metrics,
+    /// and nesting deeper than [`DEFAULT_MAX_BUILD_DEPTH`]. Each error carries
+    /// the path to the offending field, in tree order. [`SignalSpec::Custom`]
+    /// params are opaque and left to their builder.
+    ///
+    /// Some specs that fail validation still build, because the signal
+    /// sanitizes the value at sample time; validation flags them anyway since
+    /// the config almost certainly doesn't say what its author meant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mixed_signals::types::SignalSpec;
+    ///
+    /// let spec = SignalSpec::Mix {
+    ///     a: Box::new(SignalSpec::Constant { value: f32::NAN }),
+    ///     b: Box::new(SignalSpec::Constant { value: 1.0 }),
+    ///     mix: 1.5,
+    /// };
+    /// let errors = spec.validate().unwrap_err();
+    /// let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+    /// assert_eq!(paths, ["/mix", "/a/value"]);
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<SpecValidationError>> {
+        let mut validator = Validator::default();
+        // Explicit work stack: deep trees must not overflow the call stack
+        let mut pending = vec![(String::new(), self, 1usize)];
+        while let Some((path, spec, depth)) = pending.pop() {
+            if depth > DEFAULT_MAX_BUILD_DEPTH {
+                validator.errors.push(SpecValidationError {
+                    path,
+                    message: format!(
+                        "nesting exceeds maximum depth of {}",
+                        DEFAULT_MAX_BUILD_DEPTH
+                    ),
+                });
+                continue;
+            }
+            let children = validator.check(&path, spec);
+            pending.extend(
+                children
+                    .into_iter()
+                    .rev()
+                    .map(|(child_path, child)| (child_path, child, depth + 1)),
+            );
+        }
+
+        if validator.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validator.errors)
+        }
+    }
+
+    /// [`validate`](Self::validate), then [`build`](Self::build).
+    ///
+    /// Returns [`SignalBuildError::Invalid`] with every validation error if
+    /// the tree has any.
+    pub fn build_validated(&self) -> Result<Box<dyn Signal>, SignalBuildError> {
+        self.validate().map_err(SignalBuildError::Invalid)?;
+        self.build()
+    }
+}
+
+#[derive(Default)]
+struct Validator {
+    errors: Vec<SpecValidationError>,
+}
+
+impl Validator {
+    fn error(&mut self, path: &str, field: &str, message: String) {
+        self.errors.push(SpecValidationError {
+            path: format!("{}/{}", path, field),
+            message,
+        });
+    }
+
+    fn finite(&mut self, path: &str, field: &str, value: f32) {
+        if !value.is_finite() {
+            self.error(path, field, format!("must be finite, got {}", value));
+        }
+    }
+
+    fn finite_all(&mut self, path: &str, fields: &[(&str, f32)]) {
+        for (field, value) in fields {
+            self.finite(path, field, *value);
+        }
+    }
+
+    fn finite_list(&mut self, path: &str, field: &str, values: &[f32]) {
+        for (i, value) in values.iter().enumerate() {
+            self.finite(path, &format!("{}/{}", field, i), *value);
+        }
+    }
+
+    fn finite_pairs(&mut self, path: &str, field: &str, pairs: &[(f32, f32)]) {
+        for (i, (first, second)) in pairs.iter().enumerate() {
+            self.finite(path, &format!("{}/{}/0", field, i), *first);
+            self.finite(path, &format!("{}/{}/1", field, i), *second);
+        }
+    }
+
+    fn positive(&mut self, path: &str, field: &str, value: f32) {
+        if !value.is_finite() {
+            self.finite(path, field, value);
+        } else if value <= 0.0 {
+            self.error(path, field, format!("must be > 0, got {}", value));
+        }
+    }
+
+    fn non_negative(&mut self, path: &str, field: &str, value: f32) {
+        if !value.is_finite() {
+            self.finite(path, field, value);
+        } else if value < 0.0 {
+            self.error(path, field, format!("must be >= 0, got {}", value));
+        }
+    }
+
+    fn unit(&mut self, path: &str, field: &str, value: f32) {
+        if !(0.0..=1.0).contains(&value) {
+            self.error(path, field, format!("must be in [0, 1], got {}", value));
+        }
+    }
+
+    fn not_empty<T>(&mut self, path: &str, field: &str, values: &[T]) {
+        if values.is_empty() {
+            self.error(path, field, "must not be empty".to_string());
+        }
+    }
+
+    /// Check one node's own parameters and return its child specs with paths.
+    #[inline(never)]
+    fn check<'a>(&mut self, path: &str, spec: &'a SignalSpec) -> Vec<(String, &'a SignalSpec)> {
+        let child = |field: &str, spec: &'a SignalSpec| (format!("{}/{}", path, field), spec);
+
+        #[allow(deprecated)]
+        match spec {
+            // Oscillators
+            SignalSpec::Sine {
+                frequency,
+                amplitude,
+                offset,
+                phase,
+                harmonics,
+            } => {
+                self.finite_all(
+                    path,
+                    &[
+                        ("frequency", *frequency),
+                        ("amplitude", *amplitude),
+                        ("offset", *offset),
+                        ("phase", *phase),
+                    ],
+                );
+                for (i, (_, level)) in harmonics.iter().enumerate() {
+                    self.finite(path, &format!("harmonics/{}/1", i), *level);
+                }
+            }
+            SignalSpec::Triangle {
+                frequency,
+                amplitude,
+                offset,
+                phase,
+                skew,
+            } => {
+                self.finite_all(
+                    path,
+                    &[
+                        ("frequency", *frequency),
+                        ("amplitude", *amplitude),
+                        ("offset", *offset),
+                        ("phase", *phase),
+                    ],
+                );
+                self.unit(path, "skew", *skew);
+            }
+            SignalSpec::Square {
+                frequency,
+                amplitude,
+                offset,
+                phase,
+                duty,
+            } => {
+                self.finite_all(
+                    path,
+                    &[
+                        ("frequency", *frequency),
+                        ("amplitude", *amplitude),
+                        ("offset", *offset),
+                        ("phase", *phase),
+                    ],
+                );
+                self.unit(path, "duty", *duty);
+            }
+            SignalSpec::Sawtooth {
+                frequency,
+                amplitude,
+                offset,
+                phase,
+                ..
+            } => self.finite_all(
+                path,
+                &[
+                    ("frequency", *frequency),
+                    ("amplitude", *amplitude),
+                    ("offset", *offset),
+                    ("phase", *phase),
+                ],
+            ),
+
+            // Utility
+            SignalSpec::Constant { value } => self.finite(path, "value", *value),
+            SignalSpec::Ramp {
+                start,
+                end,
+                duration,
+            } => {
+                self.finite_all(path, &[("start", *start), ("end", *end)]);
+                self.non_negative(path, "duration", *duration);
+            }
+            SignalSpec::Step {
+                before,
+                after,
+                threshold,
+            } => self.finite_all(
+                path,
+                &[
+                    ("before", *before),
+                    ("after", *after),
+                    ("threshold", *threshold),
+                ],
+            ),
+            SignalSpec::Pulse {
+                low,
+                high,
+                start,
+                end,
+            } => self.finite_all(
+                path,
+                &[
+                    ("low", *low),
+                    ("high", *high),
+                    ("start", *start),
+                    ("end", *end),
+                ],
+            ),
+            SignalSpec::CellularAutomaton {
+                steps_per_second, ..
+            } => {
+                self.finite(path, "steps_per_second", *steps_per_second);
+                if *steps_per_second > MAX_CA_STEPS_PER_SECOND {
+                    self.error(
+                        path,
+                        "steps_per_second",
+                        format!(
+                            "must be <= {}, got {}",
+                            MAX_CA_STEPS_PER_SECOND, steps_per_second
+                        ),
+                    );
+                }
+            }
+
+            // Noise
+            SignalSpec::WhiteNoise {
+                amplitude,
+                sample_rate,
+                ..
+            } => {
+                self.finite(path, "amplitude", *amplitude);
+                self.positive(path, "sample_rate", *sample_rate);
+            }
+            SignalSpec::Perlin {
+                scale,
+                amplitude,
+                persistence,
+                ..
+            }
+            | SignalSpec::Simplex {
+                scale,
+                amplitude,
+                persistence,
+                ..
+            }
+            | SignalSpec::Value {
+                scale,
+                amplitude,
+                persistence,
+                ..
+            } => self.finite_all(
+                path,
+                &[
+                    ("scale", *scale),
+                    ("amplitude", *amplitude),
+                    ("persistence", *persistence),
+                ],
+            ),
+            SignalSpec::Worley {
+                scale,
+                amplitude,
+                metric,
+                ..
+            } => {
+                self.finite_all(path, &[("scale", *scale), ("amplitude", *amplitude)]);
+                if let Err(message) = metric.parse::<DistanceMetric>() {
+                    self.error(path, "metric", message);
+                }
+            }
+
+            // Random
+            SignalSpec::SeededRandom {
+                amplitude, offset, ..
+            }
+            | SignalSpec::PinkNoise {
+                amplitude, offset, ..
+            }
+            | SignalSpec::PerCharacterNoise {
+                amplitude, offset, ..
+            }
+            | SignalSpec::FastSeededRandom {
+                amplitude, offset, ..
+            }
+            | SignalSpec::FastPinkNoise {
+                amplitude, offset, ..
+            } => self.finite_all(path, &[("amplitude", *amplitude), ("offset", *offset)]),
+            SignalSpec::SpatialNoise {
+                frequency,
+                amplitude,
+                ..
+            } => self.finite_all(
+                path,
+                &[("frequency", *frequency), ("amplitude", *amplitude)],
+            ),
+            SignalSpec::GaussianNoise {
+                std_dev,
+                amplitude,
+                offset,
+                ..
+            } => {
+                self.non_negative(path, "std_dev", *std_dev);
+                self.finite_all(path, &[("amplitude", *amplitude), ("offset", *offset)]);
+            }
+            SignalSpec::PoissonNoise {
+                lambda,
+                amplitude,
+                offset,
+                ..
+            } => {
+                self.positive(path, "lambda", *lambda);
+                self.finite_all(path, &[("amplitude", *amplitude), ("offset", *offset)]);
+            }
+            SignalSpec::CorrelatedNoise {
+                correlation,
+                amplitude,
+                offset,
+                ..
+            }
+            | SignalSpec::FastCorrelatedNoise {
+                correlation,
+                amplitude,
+                offset,
+                ..
+            } => {
+                self.unit(path, "correlation", *correlation);
+                self.finite_all(path, &[("amplitude", *amplitude), ("offset", *offset)]);
+            }
+            SignalSpec::StudentTNoise {
+                degrees_of_freedom,
+                scale,
+                amplitude,
+                offset,
+                ..
+            } => {
+                self.positive(path, "degrees_of_freedom", *degrees_of_freedom);
+                self.finite_all(
+                    path,
+                    &[
+                        ("scale", *scale),
+                        ("amplitude", *amplitude),
+                        ("offset", *offset),
+                    ],
+                );
+            }
+            SignalSpec::ImpulseNoise {
+                rate_hz,
+                impulse_width,
+                ..
+            } => {
+                self.non_negative(path, "rate_hz", *rate_hz);
+                self.non_negative(path, "impulse_width", *impulse_width);
+            }
+            SignalSpec::SmoothRandom {
+                rate_hz, amplitude, ..
+            } => {
+                self.non_negative(path, "rate_hz", *rate_hz);
+                self.finite(path, "amplitude", *amplitude);
+            }
+
+            // Envelopes
+            SignalSpec::Adsr {
+                attack,
+                decay,
+                sustain,
+                release,
+                peak,
+            } => {
+                self.non_negative(path, "attack", *attack);
+                self.non_negative(path, "decay", *decay);
+                self.non_negative(path, "release", *release);
+                self.finite_all(path, &[("sustain", *sustain), ("peak", *peak)]);
+            }
+            SignalSpec::GatedAdsr {
+                attack,
+                decay,
+                sustain,
+                release,
+                peak,
+                gate,
+            } => {
+                self.non_negative(path, "attack", *attack);
+                self.non_negative(path, "decay", *decay);
+                self.non_negative(path, "release", *release);
+                self.finite_all(path, &[("sustain", *sustain), ("peak", *peak)]);
+                if let Some(gate) = gate {
+                    self.finite(path, "gate", *gate);
+                }
+            }
+            SignalSpec::Impact {
+                intensity,
+                decay,
+                frequency,
+                double_hit_delay,
+                double_hit_level,
+                ..
+            } => {
+                self.finite(path, "intensity", *intensity);
+                self.non_negative(path, "decay", *decay);
+                self.non_negative(path, "frequency", *frequency);
+                self.non_negative(path, "double_hit_delay", *double_hit_delay);
+                self.unit(path, "double_hit_level", *double_hit_level);
+            }
+            SignalSpec::LinearEnvelope {
+                attack,
+                release,
+                peak,
+            } => {
+                self.non_negative(path, "attack", *attack);
+                self.non_negative(path, "release", *release);
+                self.finite(path, "peak", *peak);
+            }
+            SignalSpec::BreakpointEnvelope { initial, segments } => {
+                self.finite(path, "initial", *initial);
+                self.finite_pairs(path, "segments", segments);
+            }
+
+            // Composition
+            SignalSpec::Add { a, b }
+            | SignalSpec::Multiply { a, b }
+            | SignalSpec::Scale { a, b }
+            | SignalSpec::Sum { a, b } => return vec![child("a", a), child("b", b)],
+            SignalSpec::Mix { a, b, mix } => {
+                self.unit(path, "mix", *mix);
+                return vec![child("a", a), child("b", b)];
+            }
+            SignalSpec::FrequencyMod {
+                carrier,
+                modulator,
+                depth,
+                carrier_freq,
+            } => {
+                self.finite(path, "carrier_freq", *carrier_freq);
+                let mut children = vec![child("carrier", carrier), child("modulator", modulator)];
+                match depth {
+                    SignalOrFloat::Static(depth) => self.finite(path, "depth", *depth),
+                    SignalOrFloat::Signal { spec, .. } => children.push(child("depth", spec)),
+                }
+                return children;
+            }
+            SignalSpec::TrueFrequencyMod {
+                modulator,
+                depth,
+                carrier_freq,
+            } => {
+                self.finite_all(path, &[("depth", *depth), ("carrier_freq", *carrier_freq)]);
+                return vec![child("modulator", modulator)];
+            }
+            SignalSpec::VcaCentered { carrier, amplitude } => {
+                return vec![child("carrier", carrier), child("amplitude", amplitude)];
+            }
+            SignalSpec::SampleHold {
+                source,
+                trigger,
+                threshold,
+                trigger_period,
+                resolution,
+                ..
+            } => {
+                self.finite(path, "threshold", *threshold);
+                self.positive(path, "resolution", *resolution);
+                if let Some(period) = trigger_period {
+                    self.positive(path, "trigger_period", *period);
+                }
+                return vec![child("source", source), child("trigger", trigger)];
+            }
+            SignalSpec::PhaseAccumulator {
+                frequency,
+                initial_phase,
+            } => {
+                self.finite(path, "initial_phase", *initial_phase);
+                return vec![child("frequency", frequency)];
+            }
+            SignalSpec::PhaseSine { phase } => return vec![child("phase", phase)],
+            SignalSpec::Keyframes { keyframes, .. } => {
+                self.not_empty(path, "keyframes", keyframes);
+                self.finite_pairs(path, "keyframes", keyframes);
+            }
+            SignalSpec::Table { values, frequency } => {
+                self.not_empty(path, "values", values);
+                self.finite_list(path, "values", values);
+                self.finite(path, "frequency", *frequency);
+            }
+            SignalSpec::Ensemble {
+                frequency, spread, ..
+            } => self.finite_all(path, &[("frequency", *frequency), ("spread", *spread)]),
+            SignalSpec::OscillatorBank {
+                frequency,
+                detune,
+                falloff,
+                ..
+            } => {
+                self.finite_all(path, &[("frequency", *frequency), ("falloff", *falloff)]);
+                let (Detune::Hz(amount) | Detune::Cents(amount)) = detune;
+                self.finite(path, "detune", *amount);
+            }
+
+            // Processing
+            SignalSpec::Clamp { signal, min, max } => {
+                self.finite_all(path, &[("min", *min), ("max", *max)]);
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::Quantize { signal, levels } => {
+                if *levels == 0 {
+                    self.error(path, "levels", "must be at least 1".to_string());
+                }
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::QuantizeToSet { signal, values } => {
+                self.finite_list(path, "values", values);
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::Remap {
+                signal,
+                in_min,
+                in_max,
+                out_min,
+                out_max,
+            } => {
+                self.finite_all(
+                    path,
+                    &[
+                        ("in_min", *in_min),
+                        ("in_max", *in_max),
+                        ("out_min", *out_min),
+                        ("out_max", *out_max),
+                    ],
+                );
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::Invert { signal } | SignalSpec::Abs { signal } => {
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::WaveFolder { signal, gain } => {
+                self.finite(path, "gain", *gain);
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::Iir {
+                signal,
+                b_coeffs,
+                a_coeffs,
+                sample_rate,
+            } => {
+                self.finite_list(path, "b_coeffs", b_coeffs);
+                self.finite_list(path, "a_coeffs", a_coeffs);
+                self.positive(path, "sample_rate", *sample_rate);
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::MovingAverage {
+                signal,
+                window_seconds,
+                sample_rate,
+            } => {
+                self.non_negative(path, "window_seconds", *window_seconds);
+                self.positive(path, "sample_rate", *sample_rate);
+                if let Some(msg) = moving_average_window_error(*window_seconds, *sample_rate) {
+                    self.error(path, "window_seconds", msg);
+                }
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::SlewLimit { signal, rise, fall } => {
+                // Infinite rates are meaningful (no limit); only NaN is an error
+                for (field, rate) in [("rise", Some(*rise)), ("fall", *fall)] {
+                    if rate.is_some_and(f32::is_nan) {
+                        self.error(path, field, "must not be NaN".to_string());
+                    }
+                }
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::TimeWarp {
+                signal,
+                rate,
+                resolution,
+            } => {
+                self.positive(path, "resolution", *resolution);
+                if let Some(msg) = time_warp_resolution_error(*resolution) {
+                    if *resolution > 0.0 {
+                        self.error(path, "resolution", msg);
+                    }
+                }
+                return vec![child("signal", signal), child("rate", rate)];
+            }
+            SignalSpec::DryWet { dry, wet, mix } => {
+                self.unit(path, "mix", *mix);
+                return vec![child("dry", dry), child("wet", wet)];
+            }
+            SignalSpec::Delay { signal, offset } => {
+                self.finite(path, "offset", *offset);
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::Crossfade { a, b, blend } => {
+                return vec![child("a", a), child("b", b), child("blend", blend)];
+            }
+            SignalSpec::Sequence { segments, .. } => {
+                let mut children = Vec::with_capacity(segments.len());
+                for (i, (segment, duration)) in segments.iter().enumerate() {
+                    self.non_negative(path, &format!("segments/{}/1", i), *duration);
+                    children.push(child(&format!("segments/{}/0", i), segment));
+                }
+                return children;
+            }
+            SignalSpec::Gate {
+                signal,
+                threshold,
+                floor,
+                close_threshold,
+            } => {
+                self.finite_all(path, &[("threshold", *threshold), ("floor", *floor)]);
+                if let Some(close) = close_threshold {
+                    self.finite(path, "close_threshold", *close);
+                }
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::HoldAfter { signal, hold_time } => {
+                self.finite(path, "hold_time", *hold_time);
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::StartAt {
+                signal,
+                start_time,
+                before,
+            } => {
+                self.finite_all(path, &[("start_time", *start_time), ("before", *before)]);
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::LoopBlend {
+                signal,
+                period,
+                blend,
+            } => {
+                self.positive(path, "period", *period);
+                self.non_negative(path, "blend", *blend);
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::Eased { signal, params, .. } => {
+                self.finite_all(
+                    path,
+                    &[
+                        ("params/overshoot", params.overshoot),
+                        ("params/amplitude", params.amplitude),
+                    ],
+                );
+                if let Some(period) = params.period {
+                    self.positive(path, "params/period", period);
+                }
+                return vec![child("signal", signal)];
+            }
+            SignalSpec::BezierEase { signal, curve } => {
+                self.finite_all(
+                    path,
+                    &[
+                        ("curve/x1", curve.x1),
+                        ("curve/y1", curve.y1),
+                        ("curve/x2", curve.x2),
+                        ("curve/y2", curve.y2),
+                    ],
+                );
+                return vec![child("signal", signal)];
+            }
+
+            // Physics
+            SignalSpec::DampedSpring {
+                mass,
+                stiffness,
+                damping,
+                v0,
+                x0,
+            } => {
+                self.positive(path, "mass", *mass);
+                self.non_negative(path, "stiffness", *stiffness);
+                self.non_negative(path, "damping", *damping);
+                self.finite_all(path, &[("v0", *v0), ("x0", *x0)]);
+            }
+            SignalSpec::BouncingDrop {
+                start_height,
+                ground_height,
+                gravity,
+                restitution,
+            } => {
+                self.finite_all(
+                    path,
+                    &[
+                        ("start_height", *start_height),
+                        ("ground_height", *ground_height),
+                        ("gravity", *gravity),
+                    ],
+                );
+                self.unit(path, "restitution", *restitution);
+            }
+            SignalSpec::FrictionDecay {
+                v0,
+                drag,
+                stop_threshold,
+            } => {
+                self.finite(path, "v0", *v0);
+                self.non_negative(path, "drag", *drag);
+                self.non_negative(path, "stop_threshold", *stop_threshold);
+            }
+            SignalSpec::SimplePendulum {
+                length,
+                gravity,
+                theta0,
+                damping,
+            } => {
+                self.positive(path, "length", *length);
+                self.finite_all(path, &[("gravity", *gravity), ("theta0", *theta0)]);
+                self.non_negative(path, "damping", *damping);
+            }
+            SignalSpec::CircularOrbit {
+                center_x,
+                center_y,
+                radius,
+                angular_velocity,
+                start_phase,
+            } => self.finite_all(
+                path,
+                &[
+                    ("center_x", *center_x),
+                    ("center_y", *center_y),
+                    ("radius", *radius),
+                    ("angular_velocity", *angular_velocity),
+                    ("start_phase", *start_phase),
+                ],
+            ),
+            SignalSpec::PointAttractor {
+                target_x,
+                target_y,
+                strength,
+            } => self.finite_all(
+                path,
+                &[
+                    ("target_x", *target_x),
+                    ("target_y", *target_y),
+                    ("strength", *strength),
+                ],
+            ),
+            SignalSpec::BallisticTrajectory {
+                start_x,
+                start_y,
+                v0_x,
+                v0_y,
+                gravity,
+                ground_y,
+            } => {
+                self.finite_all(
+                    path,
+                    &[
+                        ("start_x", *start_x),
+                        ("start_y", *start_y),
+                        ("v0_x", *v0_x),
+                        ("v0_y", *v0_y),
+                        ("gravity", *gravity),
+                    ],
+                );
+                if let Some(ground) = ground_y {
+                    self.finite(path, "ground_y", *ground);
+                }
+            }
+
+            // Params belong to the registered builder
+            SignalSpec::Custom { .. } => {}
+        }
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(frequency: f32) -> SignalSpec {
+        SignalSpec::Sine {
+            frequency,
+            amplitude: 1.0,
+            offset: 0.0,
+            phase: 0.0,
+            harmonics: vec![],
+        }
+    }
+
+    #[test]
+    fn test_validate_valid_tree() {
+        let spec = SignalSpec::Mix {
+            a: Box::new(sine(2.0)),
+            b: Box::new(SignalSpec::Quantize {
+                signal: Box::new(sine(1.0)),
+                levels: 4,
+            }),
+            mix: 0.25,
+        };
+        assert_eq!(spec.validate(), Ok(()));
+        assert!(spec.build_validated().is_ok());
+    }
+
+    #[test]
+    fn test_validate_collects_errors_in_tree_order() {
+        let spec = SignalSpec::Add {
+            a: Box::new(SignalSpec::Quantize {
+                signal: Box::new(SignalSpec::Keyframes {
+                    keyframes: vec![],
+                    modes: vec![],
+                }),
+                levels: 0,
+            }),
+            b: Box::new(SignalSpec::PoissonNoise {
+                seed: 0,
+                lambda: 0.0,
+                amplitude: f32::INFINITY,
+                offset: 0.0,
+            }),
+        };
+        let errors = spec.validate().unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/a/levels",
+                "/a/signal/keyframes",
+                "/b/lambda",
+                "/b/amplitude"
+            ]
+        );
+        assert_eq!(errors[2].to_string(), "/b/lambda: must be > 0, got 0");
+    }
+
+    #[test]
+    fn test_validate_three_independent_errors() {
+        let fm = SignalSpec::FrequencyMod {
+            carrier: Box::new(sine(1.0)),
+            modulator: Box::new(sine(f32::NAN)),
+            depth: 0.5.into(),
+            carrier_freq: 440.0,
+        };
+        let spec = SignalSpec::Add {
+            a: Box::new(SignalSpec::Add {
+                a: Box::new(SignalSpec::GaussianNoise {
+                    seed: 1,
+                    std_dev: -1.0,
+                    amplitude: 1.0,
+                    offset: 0.0,
+                }),
+                b: Box::new(fm),
+            }),
+            b: Box::new(SignalSpec::Mix {
+                a: Box::new(sine(1.0)),
+                b: Box::new(sine(2.0)),
+                mix: 2.0,
+            }),
+        };
+        let errors = spec.validate().unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["/a/a/std_dev", "/a/b/modulator/frequency", "/b/mix"]
+        );
+        // build() still stops at the first bad node
+        assert!(matches!(spec.build(), Err(SignalBuildError::Gaussian(_))));
+    }
+
+    #[test]
+    fn test_validate_sampling_cost_limits() {
+        let spec = SignalSpec::Add {
+            a: Box::new(SignalSpec::MovingAverage {
+                signal: Box::new(sine(1.0)),
+                window_seconds: 1e30,
+                sample_rate: 1000.0,
+            }),
+            b: Box::new(SignalSpec::Add {
+                a: Box::new(SignalSpec::CellularAutomaton {
+                    rule: 30,
+                    width: 64,
+                    seed: 0,
+                    steps_per_second: 1e9,
+                }),
+                b: Box::new(SignalSpec::TimeWarp {
+                    signal: Box::new(sine(1.0)),
+                    rate: Box::new(SignalSpec::Constant { value: 1.0 }),
+                    resolution: 1e9,
+                }),
+            }),
+        };
+        let errors = spec.validate().unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/a/window_seconds",
+                "/b/a/steps_per_second",
+                "/b/b/resolution"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_list_and_sequence_paths() {
+        let spec = SignalSpec::Sequence {
+            segments: vec![
+                (sine(1.0), 1.0),
+                (
+                    SignalSpec::Table {
+                        values: vec![0.0, f32::NAN],
+                        frequency: 1.0,
+                    },
+                    -2.0,
+                ),
+            ],
+            looping: false,
+        };
+        let errors = spec.validate().unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, ["/segments/1/1", "/segments/1/0/values/1"]);
+    }
+
+    #[test]
+    fn test_build_validated_reports_all_errors() {
+        let spec = SignalSpec::Mix {
+            a: Box::new(sine(f32::NAN)),
+            b: Box::new(sine(1.0)),
+            mix: -0.5,
+        };
+        // build() sanitizes and succeeds; build_validated() refuses
+        assert!(spec.build().is_ok());
+        match spec.build_validated() {
+            Err(SignalBuildError::Invalid(errors)) => assert_eq!(errors.len(), 2),
+            other => panic!("expected validation errors, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn test_validate_deep_nesting_does_not_overflow() {
+        let mut spec = SignalSpec::Constant { value: 1.0 };
+        for _ in 0..10_000 {
+            spec = SignalSpec::Invert {
+                signal: Box::new(spec),
+            };
+        }
+        let errors = spec.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("maximum depth"));
+        // Tear down iteratively (recursive drop would overflow)
+        while let SignalSpec::Invert { signal } = spec {
+            spec = *signal;
+        }
+    }
+}
+
+// <FILE>mixed-signals/src/types/spec_validation.rs</FILE> - <DESC>Whole-tree validation for SignalSpec</DESC>
+// <VERS>END OF VERSION: 1.6.0</VERS>
@@ -0,0 +1,243 @@
+// <FILE>src/visualization/cls_color_field.rs</FILE> - <DESC>Two-signal color mapping (hue + brightness)</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Dual-signal heatmaps</WCTX>
+// <CLOG>Initial creation - ColorField combining a hue gradient with a brightness ramp</CLOG>
+
+use super::ColorGradient;
+use ratatui::style::Color;
+
+/// Maps two signal values to one color: the primary picks the hue from a
+/// [`ColorGradient`], the secondary darkens or lightens it.
+///
+/// The secondary value is normalized over `brightness_range`: the midpoint
+/// leaves the gradient color unchanged, the minimum blends toward black and
+/// the maximum toward white. `contrast` (0..1, default 0.75) limits how far
+/// the blend goes so extremes keep some of their hue.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::visualization::{ColorField, ColorGradient};
+/// use ratatui::style::Color;
+///
+/// let field = ColorField::new(
+///     ColorGradient::new()
+///         .add_stop(0.0, Color::Blue)
+///         .add_stop(0.5, Color::Red),
+/// );
+/// let dim_blue = field.sample(0.2, 0.0);
+/// let bright_red = field.sample(0.8, 1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColorField {
+    hue: ColorGradient,
+    hue_range: (f32, f32),
+    brightness_range: (f32, f32),
+    contrast: f32,
+}
+
+impl ColorField {
+    /// Create a field using `hue` for the primary signal.
+    ///
+    /// Both value ranges default to (0.0, 1.0).
+    pub fn new(hue: ColorGradient) -> Self {
+        Self {
+            hue,
+            hue_range: (0.0, 1.0),
+            brightness_range: (0.0, 1.0),
+            contrast: 0.75,
+        }
+    }
+
+    /// Value range of the primary (hue) signal.
+    pub fn hue_range(mut self, min: f32, max: f32) -> Self {
+        self.hue_range = (min, max);
+        self
+    }
+
+    /// Value range of the secondary (brightness) signal.
+    pub fn brightness_range(mut self, min: f32, max: f32) -> Self {
+        self.brightness_range = (min, max);
+        self
+    }
+
+    /// How far brightness extremes blend toward black/white (0..1).
+    pub fn contrast(mut self, contrast: f32) -> Self {
+        self.contrast = if contrast.is_finite() {
+            contrast.clamp(0.0, 1.0)
+        } else {
+            0.75
+        };
+        self
+    }
+
+    /// Color for a primary value (hue) and secondary value (brightness).
+    pub fn sample(&self, value_a: f32, value_b: f32) -> Color {
+        let base = self
+            .hue
+            .color_for(value_a, self.hue_range)
+            .unwrap_or(Color::White);
+        let (r, g, b) = color_to_rgb(base);
+
+        // -1 = darkest, 0 = unchanged, +1 = lightest
+        let (min, max) = self.brightness_range;
+        let span = max - min;
+        let level = if span.abs() < 0.0001 || !value_b.is_finite() {
+            0.5
+        } else {
+            ((value_b - min) / span).clamp(0.0, 1.0)
+        };
+        let shift = (level * 2.0 - 1.0) * self.contrast;
+
+        let target = if shift < 0.0 { 0.0 } else { 255.0 };
+        let amount = shift.abs();
+        let blend = |c: u8| (c as f32 + (target - c as f32) * amount).round() as u8;
+        Color::Rgb(blend(r), blend(g), blend(b))
+    }
+}
+
+/// Approximate RGB for a terminal color.
+fn color_to_rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i) => indexed_to_rgb(i),
+        Color::Black => (0, 0, 0),
+        Color::Red => (128, 0, 0),
+        Color::Green => (0, 128, 0),
+        Color::Yellow => (128, 128, 0),
+        Color::Blue => (0, 0, 128),
+        Color::Magenta => (128, 0, 128),
+        Color::Cyan => (0, 128, 128),
+        Color::Gray => (192, 192, 192),
+        Color::DarkGray => (128, 128, 128),
+        Color::LightRed => (255, 0, 0),
+        Color::LightGreen => (0, 255, 0),
+        Color::LightYellow => (255, 255, 0),
+        Color::LightBlue => (0, 0, 255),
+        Color::LightMagenta => (255, 0, 255),
+        Color::LightCyan => (0, 255, 255),
+        Color::White | Color::Reset => (255, 255, 255),
+    }
+}
+
+/// xterm 256-color palette lookup.
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => {
+            const BASIC: [Color; 16] = [
+                Color::Black,
+                Color::Red,
+                Color::Green,
+                Color::Yellow,
+                Color::Blue,
+                Color::Magenta,
+                Color::Cyan,
+                Color::Gray,
+                Color::DarkGray,
+                Color::LightRed,
+                Color::LightGreen,
+                Color::LightYellow,
+                Color::LightBlue,
+                Color::LightMagenta,
+                Color::LightCyan,
+                Color::White,
+            ];
+            color_to_rgb(BASIC[index as usize])
+        }
+        16..=231 => {
+            let i = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        232..=255 => {
+            let v = 8 + (index - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(color: Color) -> (u8, u8, u8) {
+        match color {
+            Color::Rgb(r, g, b) => (r, g, b),
+            other => panic!("expected Rgb, got {other:?}"),
+        }
+    }
+
+    fn luminance(color: Color) -> u32 {
+        let (r, g, b) = rgb(color);
+        r as u32 + g as u32 + b as u32
+    }
+
+    fn field() -> ColorField {
+        ColorField::new(
+            ColorGradient::new()
+                .add_stop(0.0, Color::Rgb(0, 0, 200))
+                .add_stop(0.5, Color::Rgb(200, 0, 0)),
+        )
+    }
+
+    #[test]
+    fn test_color_field_midpoint_brightness_keeps_gradient_color() {
+        let field = field();
+        assert_eq!(field.sample(0.2, 0.5), Color::Rgb(0, 0, 200));
+        assert_eq!(field.sample(0.8, 0.5), Color::Rgb(200, 0, 0));
+    }
+
+    #[test]
+    fn test_color_field_brightness_darkens_and_lightens() {
+        let field = field();
+        let dark = field.sample(0.8, 0.0);
+        let mid = field.sample(0.8, 0.5);
+        let light = field.sample(0.8, 1.0);
+        assert!(luminance(dark) < luminance(mid));
+        assert!(luminance(mid) < luminance(light));
+        // Monotonic across the brightness range
+        let mut last = 0;
+        for i in 0..=10 {
+            let l = luminance(field.sample(0.8, i as f32 / 10.0));
+            assert!(l >= last);
+            last = l;
+        }
+    }
+
+    #[test]
+    fn test_color_field_hue_tied_to_primary() {
+        let field = field();
+        for i in 0..=10 {
+            let brightness = i as f32 / 10.0;
+            // Red hue: red channel dominates at any brightness
+            let (r, g, b) = rgb(field.sample(0.9, brightness));
+            assert!(r > g && r > b, "{brightness}: {:?}", (r, g, b));
+            // Blue hue: blue channel dominates at any brightness
+            let (r, g, b) = rgb(field.sample(0.1, brightness));
+            assert!(b > r && b > g, "{brightness}: {:?}", (r, g, b));
+        }
+    }
+
+    #[test]
+    fn test_color_field_ranges_and_named_colors() {
+        let field = ColorField::new(ColorGradient::new().add_stop(0.0, Color::LightGreen))
+            .hue_range(-1.0, 1.0)
+            .brightness_range(-1.0, 1.0)
+            .contrast(1.0);
+        assert_eq!(field.sample(0.0, 0.0), Color::Rgb(0, 255, 0));
+        assert_eq!(field.sample(0.0, -1.0), Color::Rgb(0, 0, 0));
+        assert_eq!(field.sample(0.0, 1.0), Color::Rgb(255, 255, 255));
+        assert_eq!(field.sample(0.0, f32::NAN), Color::Rgb(0, 255, 0));
+    }
+
+    #[test]
+    fn test_indexed_palette() {
+        assert_eq!(indexed_to_rgb(16), (0, 0, 0));
+        assert_eq!(indexed_to_rgb(196), (255, 0, 0));
+        assert_eq!(indexed_to_rgb(232), (8, 8, 8));
+        assert_eq!(indexed_to_rgb(9), (255, 0, 0));
+    }
+}
+
+// <FILE>src/visualization/cls_color_field.rs</FILE> - <DESC>Two-signal color mapping (hue + brightness)</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
@@ -0,0 +1,163 @@
+// <FILE>src/visualization/cls_frame_clock.rs</FILE> - <DESC>Fixed-rate frame clock for deterministic animation time</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Jitter-free demo time stepping</WCTX>
+// <CLOG>Initial creation - frame-counted time with optional wrap and rebasing fps changes</CLOG>
+
+use crate::traits::SignalTime;
+
+/// Fixed-rate clock that turns frame counts into animation time.
+///
+/// Each [`tick`](Self::tick) advances exactly one frame of `1 / fps` seconds,
+/// independent of how long rendering actually took, so demos step
+/// deterministically. Time is computed from the integer frame count rather
+/// than accumulated, so spacing never drifts from float error.
+///
+/// With [`with_wrap`](Self::with_wrap) time loops back into `[0, period)`.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::visualization::FrameClock;
+///
+/// let mut clock = FrameClock::new(60.0);
+/// assert_eq!(clock.tick(), 0.0);
+/// assert!((clock.tick() - 1.0 / 60.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameClock {
+    fps: f64,
+    /// Frames ticked since the last rebase
+    frame: u64,
+    /// Time at the last rebase (fps change)
+    base: SignalTime,
+    wrap: Option<SignalTime>,
+}
+
+impl FrameClock {
+    /// Create a clock ticking at `fps` frames per second.
+    ///
+    /// Non-finite or non-positive rates fall back to 60 fps.
+    pub fn new(fps: f64) -> Self {
+        Self {
+            fps: sanitize_fps(fps),
+            frame: 0,
+            base: 0.0,
+            wrap: None,
+        }
+    }
+
+    /// Loop time back into `[0, period)`.
+    ///
+    /// Non-finite or non-positive periods disable wrapping.
+    pub fn with_wrap(mut self, period: SignalTime) -> Self {
+        self.wrap = (period.is_finite() && period > 0.0).then_some(period);
+        self
+    }
+
+    /// Advance one frame and return the time of the frame just ticked.
+    ///
+    /// The first tick returns 0.0 (or the time at the last rate change).
+    pub fn tick(&mut self) -> SignalTime {
+        let time = self.time();
+        self.frame = self.frame.saturating_add(1);
+        time
+    }
+
+    /// Time of the next frame to be ticked, without advancing.
+    pub fn time(&self) -> SignalTime {
+        let time = self.base + self.frame as SignalTime / self.fps;
+        match self.wrap {
+            Some(period) => time.rem_euclid(period),
+            None => time,
+        }
+    }
+
+    /// Change the frame rate, continuing from the current time.
+    pub fn set_fps(&mut self, fps: f64) {
+        self.base = self.time();
+        self.frame = 0;
+        self.fps = sanitize_fps(fps);
+    }
+
+    /// Frames per second.
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    /// Seconds per frame.
+    pub fn frame_duration(&self) -> SignalTime {
+        1.0 / self.fps
+    }
+
+    /// Restart at time 0.0.
+    pub fn reset(&mut self) {
+        self.frame = 0;
+        self.base = 0.0;
+    }
+}
+
+impl Default for FrameClock {
+    fn default() -> Self {
+        Self::new(60.0)
+    }
+}
+
+fn sanitize_fps(fps: f64) -> f64 {
+    if fps.is_finite() && fps > 0.0 {
+        fps
+    } else {
+        60.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_clock_even_monotonic_spacing() {
+        for fps in [24.0, 30.0, 60.0, 144.0] {
+            let mut clock = FrameClock::new(fps);
+            let times: Vec<f64> = (0..1000).map(|_| clock.tick()).collect();
+            assert_eq!(times[0], 0.0);
+            for (i, pair) in times.windows(2).enumerate() {
+                assert!(pair[1] > pair[0]);
+                assert!((pair[1] - pair[0] - 1.0 / fps).abs() < 1e-9);
+                assert!((pair[1] - (i + 1) as f64 / fps).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_frame_clock_wrap() {
+        let mut clock = FrameClock::new(10.0).with_wrap(1.0);
+        let times: Vec<f64> = (0..25).map(|_| clock.tick()).collect();
+        assert!((times[9] - 0.9).abs() < 1e-12);
+        assert!(times[10].abs() < 1e-12);
+        assert!((times[24] - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_frame_clock_set_fps_is_continuous() {
+        let mut clock = FrameClock::new(10.0);
+        for _ in 0..5 {
+            clock.tick();
+        }
+        clock.set_fps(100.0);
+        assert!((clock.tick() - 0.5).abs() < 1e-12);
+        assert!((clock.tick() - 0.51).abs() < 1e-12);
+        clock.reset();
+        assert_eq!(clock.tick(), 0.0);
+    }
+
+    #[test]
+    fn test_frame_clock_invalid_inputs() {
+        let clock = FrameClock::new(f64::NAN).with_wrap(-1.0);
+        assert_eq!(clock.fps(), 60.0);
+        assert_eq!(clock, FrameClock::default());
+        assert!((FrameClock::new(0.0).frame_duration() - 1.0 / 60.0).abs() < 1e-12);
+    }
+}
+
+// <FILE>src/visualization/cls_frame_clock.rs</FILE> - <DESC>Fixed-rate frame clock for deterministic animation time</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
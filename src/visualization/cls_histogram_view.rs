@@ -0,0 +1,310 @@
+// <FILE>src/visualization/cls_histogram_view.rs</FILE> - <DESC>Value distribution histogram widget</DESC>
+// <VERS>VERSION: 1.0.1</VERS>
+// <WCTX>Tuning noise distributions visually</WCTX>
+// <CLOG>Tests read Cell::fg as a field (ratatui 0.30)</CLOG>
+
+use super::cls_signal_view::BRAILLE_GRAPH_SYMBOLS;
+use super::ColorGradient;
+use crate::traits::{Signal, SignalTime};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget;
+
+/// Bucket count used until [`HistogramView::bins`] is called.
+const DEFAULT_BINS: usize = 32;
+
+/// Histogram widget showing how a set of values is distributed.
+///
+/// Samples are counted into `bins` equal-width buckets over the value range
+/// (by default the min..max of the samples) and drawn as vertical bars with
+/// Braille sub-cell resolution: two bars per cell horizontally, four levels
+/// per cell vertically. Bins are stretched or squeezed to fit the area width.
+///
+/// - NaN and infinite samples are skipped; see [`nan_count`](Self::nan_count).
+/// - Samples outside an explicit value range are not counted.
+/// - A zero-width range (all samples identical) puts every sample in the
+///   middle bin, which renders as a single full-height bar.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::visualization::HistogramView;
+///
+/// let view = HistogramView::new(&[0.1, 0.2, 0.8, f32::NAN])
+///     .bins(2)
+///     .value_range(0.0, 1.0);
+/// assert_eq!(view.counts(), vec![2, 1]);
+/// assert_eq!(view.nan_count(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HistogramView {
+    /// Finite samples only
+    samples: Vec<f32>,
+    /// Samples skipped because they were not finite
+    nan_count: usize,
+    bins: usize,
+    /// Explicit value range, or None for the samples' min..max
+    value_range: Option<(f32, f32)>,
+    log_scale: bool,
+    style: Style,
+    gradient: Option<ColorGradient>,
+}
+
+impl HistogramView {
+    pub fn new(samples: &[f32]) -> Self {
+        let finite: Vec<f32> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+        Self {
+            nan_count: samples.len() - finite.len(),
+            samples: finite,
+            bins: DEFAULT_BINS,
+            value_range: None,
+            log_scale: false,
+            style: Style::default().fg(Color::Cyan),
+            gradient: None,
+        }
+    }
+
+    /// Take `sample_count` evenly spaced samples over `time_range` (inclusive).
+    pub fn from_signal(
+        signal: &dyn Signal,
+        time_range: (SignalTime, SignalTime),
+        sample_count: usize,
+    ) -> Self {
+        let (start, end) = time_range;
+        let samples: Vec<f32> = (0..sample_count)
+            .map(|i| {
+                let t = if sample_count <= 1 {
+                    start
+                } else {
+                    start + (end - start) * (i as SignalTime / (sample_count - 1) as SignalTime)
+                };
+                signal.sample(t)
+            })
+            .collect();
+        Self::new(&samples)
+    }
+
+    /// Number of buckets (at least 1).
+    pub fn bins(mut self, bins: usize) -> Self {
+        self.bins = bins.max(1);
+        self
+    }
+
+    /// Count values in [min, max] instead of the samples' own range.
+    pub fn value_range(mut self, min: f32, max: f32) -> Self {
+        self.value_range = Some((min, max));
+        self
+    }
+
+    /// Scale bar heights by `ln(1 + count)` so sparse tails stay visible.
+    pub fn log_scale(mut self, log: bool) -> Self {
+        self.log_scale = log;
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Color each bar by the value at its position in the value range.
+    pub fn gradient(mut self, gradient: ColorGradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    /// Number of NaN or infinite samples that were skipped.
+    pub fn nan_count(&self) -> usize {
+        self.nan_count
+    }
+
+    /// The value range being binned.
+    pub fn range(&self) -> (f32, f32) {
+        if let Some(range) = self.value_range {
+            return range;
+        }
+        let min = self.samples.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = self
+            .samples
+            .iter()
+            .copied()
+            .fold(f32::NEG_INFINITY, f32::max);
+        if min <= max {
+            (min, max)
+        } else {
+            (0.0, 1.0)
+        }
+    }
+
+    /// Samples per bucket, lowest values first.
+    pub fn counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.bins];
+        let (min, max) = self.range();
+        let span = max as f64 - min as f64;
+        for &value in &self.samples {
+            if value < min || value > max {
+                continue;
+            }
+            let bin = if span > 0.0 {
+                let normalized = (value as f64 - min as f64) / span;
+                ((normalized * self.bins as f64) as usize).min(self.bins - 1)
+            } else {
+                self.bins / 2
+            };
+            counts[bin] += 1;
+        }
+        counts
+    }
+
+    /// Bar height in [0, 1] for a bucket count.
+    fn height(&self, count: usize, max_count: usize) -> f64 {
+        if max_count == 0 {
+            0.0
+        } else if self.log_scale {
+            (count as f64).ln_1p() / (max_count as f64).ln_1p()
+        } else {
+            count as f64 / max_count as f64
+        }
+    }
+
+    fn style_at(&self, x: u16, width: u16) -> Style {
+        let Some(gradient) = &self.gradient else {
+            return self.style;
+        };
+        let range = self.range();
+        let position = (x as f32 + 0.5) / width as f32;
+        let value = range.0 + position * (range.1 - range.0);
+        match gradient.color_for(value, range) {
+            Some(color) => self.style.fg(color),
+            None => self.style,
+        }
+    }
+}
+
+impl Widget for HistogramView {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let counts = self.counts();
+        let max_count = counts.iter().copied().max().unwrap_or(0);
+        if max_count == 0 {
+            return;
+        }
+        // Bar height in Braille dots for each half-cell column
+        let dot_rows = area.height as f64 * 4.0;
+        let half_columns = area.width as usize * 2;
+        let dots: Vec<usize> = (0..half_columns)
+            .map(|hx| {
+                let count = counts[hx * counts.len() / half_columns];
+                let dots = (self.height(count, max_count) * dot_rows).round() as usize;
+                if count > 0 {
+                    dots.max(1)
+                } else {
+                    dots
+                }
+            })
+            .collect();
+        for cell_x in 0..area.width {
+            let left = dots[cell_x as usize * 2];
+            let right = dots[cell_x as usize * 2 + 1];
+            let style = self.style_at(cell_x, area.width);
+            for cell_y in 0..area.height {
+                let bottom = (area.height - 1 - cell_y) as usize * 4;
+                let level = |dots: usize| dots.saturating_sub(bottom).min(4);
+                let ch = BRAILLE_GRAPH_SYMBOLS[level(left)][level(right)];
+                if ch != ' ' {
+                    if let Some(cell) = buf.cell_mut((area.x + cell_x, area.y + cell_y)) {
+                        cell.set_char(ch);
+                        cell.set_style(style);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::Constant;
+
+    fn render_rows(view: HistogramView, width: u16, height: u16) -> (Buffer, Vec<String>) {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        view.render(area, &mut buf);
+        let rows = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buf.cell((x, y)).unwrap().symbol().to_string())
+                    .collect()
+            })
+            .collect();
+        (buf, rows)
+    }
+
+    #[test]
+    fn test_histogram_counts_and_nan() {
+        let view = HistogramView::new(&[0.0, 0.1, 0.5, 0.9, 1.0, 1.5, f32::NAN, f32::INFINITY])
+            .bins(2)
+            .value_range(0.0, 1.0);
+        // 1.5 is out of range; 1.0 lands in the last bin
+        assert_eq!(view.counts(), vec![2, 3]);
+        assert_eq!(view.nan_count(), 2);
+        assert_eq!(
+            HistogramView::new(&[0.0, 0.1, 0.5, 0.9, 1.0]).range(),
+            (0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_histogram_bar_heights() {
+        let samples = [0.0, 1.0, 1.0];
+        let view = HistogramView::new(&samples).bins(2);
+        // Bin 0 half height (2 dots), bin 1 full height (4 dots)
+        let (_, rows) = render_rows(view.clone(), 1, 1);
+        assert_eq!(rows, ["⣼"]);
+        // ln(2) / ln(3) * 4 = 2.5 -> 3 dots
+        let (_, rows) = render_rows(view.log_scale(true), 1, 1);
+        assert_eq!(rows, ["⣾"]);
+    }
+
+    #[test]
+    fn test_histogram_identical_values_single_bar() {
+        let view = HistogramView::from_signal(&Constant::new(0.3), (0.0, 1.0), 10).bins(4);
+        assert_eq!(view.counts(), vec![0, 0, 10, 0]);
+        let (buf, rows) = render_rows(view, 4, 2);
+        assert_eq!(rows, ["  ⣿ ", "  ⣿ "]);
+        assert_eq!(buf.cell((2, 0)).unwrap().fg, Color::Cyan);
+    }
+
+    #[test]
+    fn test_histogram_empty_and_tiny_areas() {
+        let empty = HistogramView::new(&[]);
+        assert_eq!(empty.counts(), vec![0; DEFAULT_BINS]);
+        let (_, rows) = render_rows(empty, 3, 1);
+        assert_eq!(rows, ["   "]);
+        let only_nan = HistogramView::new(&[f32::NAN; 3]);
+        assert_eq!(only_nan.nan_count(), 3);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        only_nan.render(Rect::new(0, 0, 0, 0), &mut buf);
+        // More bins than columns still renders
+        let (_, rows) = render_rows(HistogramView::new(&[0.0, 0.5, 1.0]).bins(100), 1, 1);
+        assert_eq!(rows.len(), 1);
+    }
+
+    #[test]
+    fn test_histogram_gradient_colors_by_value() {
+        let gradient = ColorGradient::new()
+            .add_stop(0.0, Color::Blue)
+            .add_stop(0.5, Color::Red);
+        let view = HistogramView::new(&[0.0, 1.0]).bins(2).gradient(gradient);
+        let (buf, _) = render_rows(view, 2, 1);
+        assert_eq!(buf.cell((0, 0)).unwrap().fg, Color::Blue);
+        assert_eq!(buf.cell((1, 0)).unwrap().fg, Color::Red);
+    }
+}
+
+// <FILE>src/visualization/cls_histogram_view.rs</FILE> - <DESC>Value distribution histogram widget</DESC>
+// <VERS>END OF VERSION: 1.0.1</VERS>
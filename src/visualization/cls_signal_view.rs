@@ -1,7 +1,7 @@
 // <FILE>src/visualization/cls_signal_view.rs</FILE> - <DESC>Signal oscilloscope widget</DESC>
-// <VERS>VERSION: 1.3.0 - 2025-12-27T13:14:12Z</VERS>
-// <WCTX>Upgrade time precision to f64</WCTX>
-// <CLOG>Changed time_range and internal time math to f64 to support long-running animations</CLOG>
+// <VERS>VERSION: 1.8.0</VERS>
+// <WCTX>Histogram bars</WCTX>
+// <CLOG>Share the Braille graph table within the module</CLOG>
 
 use crate::traits::Signal;
 use ratatui::buffer::Buffer;
@@ -12,13 +12,17 @@ use std::cmp::Ordering;
 /// btop-style Braille graph symbols: 5×5 lookup table
 /// Row index = previous value (0-4), Column index = current value (0-4)
 /// Creates filled area graph transitions
-const BRAILLE_GRAPH_SYMBOLS: [[char; 5]; 5] = [
+pub(super) const BRAILLE_GRAPH_SYMBOLS: [[char; 5]; 5] = [
     [' ', '⢀', '⢠', '⢰', '⢸'],
     ['⡀', '⣀', '⣠', '⣰', '⣸'],
     ['⡄', '⣄', '⣤', '⣴', '⣼'],
     ['⡆', '⣆', '⣦', '⣶', '⣾'],
     ['⡇', '⣇', '⣧', '⣷', '⣿'],
 ];
+/// Narrowest area that gets axis or time labels.
+const MIN_LABEL_WIDTH: u16 = 8;
+/// Shortest area that gets axis or time labels.
+const MIN_LABEL_HEIGHT: u16 = 2;
 /// Rendering mode for signal visualization
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RenderMode {
@@ -49,7 +53,7 @@ impl ColorGradient {
             .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
         self
     }
-    fn color_for(&self, value: f32, range: (f32, f32)) -> Option<Color> {
+    pub(crate) fn color_for(&self, value: f32, range: (f32, f32)) -> Option<Color> {
         if self.stops.is_empty() {
             return None;
         }
@@ -70,12 +74,29 @@ impl ColorGradient {
         Some(last)
     }
 }
+/// An additional signal drawn on a [`SignalView`]'s axes.
+struct Trace<'a> {
+    signal: &'a dyn Signal,
+    style: Style,
+    label: String,
+}
 /// ASCII oscilloscope widget for visualizing signals.
 ///
 /// Renders a signal waveform within a given area, showing the signal's
 /// value over time. Supports Braille rendering for higher resolution.
+///
+/// Further signals can be drawn on the same time/value axes with
+/// [`overlay`](Self::overlay), e.g. a raw signal against its filtered
+/// version. Traces are drawn in order, primary first, and where they meet
+/// in a cell the last one drawn wins.
 pub struct SignalView<'a> {
     signal: &'a dyn Signal,
+    /// Legend name for the primary signal
+    label: Option<String>,
+    /// Extra signals drawn after the primary one
+    overlays: Vec<Trace<'a>>,
+    /// Show a legend of labelled traces on the top row
+    show_legend: bool,
     /// Time range to display (start, end)
     /// Uses f64 to support long-running animations without jitter
     time_range: (f64, f64),
@@ -93,20 +114,40 @@ pub struct SignalView<'a> {
     zero_line_style: Style,
     /// Optional gradient for value-based coloring
     gradient: Option<ColorGradient>,
+    /// Show min/max value labels on the left edge
+    show_axis_labels: bool,
+    /// Also label the midpoint value (requires axis labels)
+    show_midpoint_label: bool,
+    /// Show start/end times along the bottom row
+    show_time_labels: bool,
+    /// Decimal places for axis and time labels
+    label_precision: usize,
+    /// Style for axis and time labels
+    label_style: Style,
+    /// Optional playhead marker (time, style)
+    cursor: Option<(f64, Style)>,
 }
 impl<'a> SignalView<'a> {
     pub fn new(signal: &'a dyn Signal) -> Self {
-        let range = signal.output_range();
         Self {
             signal,
+            label: None,
+            overlays: Vec::new(),
+            show_legend: false,
             time_range: (0.0, 1.0),
-            value_range: (range.min, range.max),
+            value_range: signal.display_range(),
             style: Style::default().fg(Color::Cyan),
             render_mode: RenderMode::Braille,
             wave_char: '█',
             show_zero_line: true,
             zero_line_style: Style::default().fg(Color::DarkGray),
             gradient: None,
+            show_axis_labels: false,
+            show_midpoint_label: false,
+            show_time_labels: false,
+            label_precision: 2,
+            label_style: Style::default().fg(Color::Gray),
+            cursor: None,
         }
     }
     pub fn time_range(mut self, start: f64, end: f64) -> Self {
@@ -142,6 +183,72 @@ impl<'a> SignalView<'a> {
         self.gradient = Some(gradient);
         self
     }
+    /// Legend name for the primary signal.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+    /// Draw another signal on the same axes in its own style.
+    ///
+    /// The gradient only applies to the primary signal.
+    pub fn overlay(
+        mut self,
+        signal: &'a dyn Signal,
+        style: Style,
+        label: impl Into<String>,
+    ) -> Self {
+        self.overlays.push(Trace {
+            signal,
+            style,
+            label: label.into(),
+        });
+        self
+    }
+    /// Show `■ name` swatches for labelled traces, right-aligned on the top row.
+    pub fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+    /// Label the min and max of the value range on the left edge.
+    pub fn show_axis_labels(mut self, show: bool) -> Self {
+        self.show_axis_labels = show;
+        self
+    }
+    /// Also label the midpoint of the value range when axis labels are shown.
+    pub fn show_midpoint_label(mut self, show: bool) -> Self {
+        self.show_midpoint_label = show;
+        self
+    }
+    /// Label the start and end of the time range along the bottom row.
+    pub fn time_labels(mut self, show: bool) -> Self {
+        self.show_time_labels = show;
+        self
+    }
+    /// Decimal places for axis and time labels (default 2).
+    pub fn label_precision(mut self, precision: usize) -> Self {
+        self.label_precision = precision;
+        self
+    }
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+    /// Draw a vertical marker at `time`. Times outside the time range are ignored.
+    pub fn cursor(mut self, time: f64, style: Style) -> Self {
+        self.cursor = Some((time, style));
+        self
+    }
+    /// Every trace in draw order; `None` style means the primary's gradient style.
+    fn traces(&self) -> impl Iterator<Item = (&dyn Signal, Option<Style>)> {
+        std::iter::once((self.signal, None)).chain(
+            self.overlays
+                .iter()
+                .map(|trace| (trace.signal, Some(trace.style))),
+        )
+    }
+    fn trace_style(&self, value: f32, fixed: Option<Style>) -> Style {
+        fixed.unwrap_or_else(|| self.style_for_value(value))
+    }
     fn style_for_value(&self, value: f32) -> Style {
         if let Some(gradient) = &self.gradient {
             if let Some(color) = gradient.color_for(value, self.value_range) {
@@ -168,6 +275,22 @@ impl<'a> SignalView<'a> {
         let normalized = x as f64 / (width - 1).max(1) as f64;
         start + normalized * (end - start)
     }
+    /// Map a time to an X coordinate, or None if it falls outside the time range
+    fn time_to_x(&self, time: f64, width: u16) -> Option<u16> {
+        let (start, end) = self.time_range;
+        let span = end - start;
+        if !time.is_finite() || !span.is_finite() || span == 0.0 {
+            return None;
+        }
+        let normalized = (time - start) / span;
+        if !(0.0..=1.0).contains(&normalized) {
+            return None;
+        }
+        Some((normalized * (width - 1) as f64).round() as u16)
+    }
+    fn format_label(&self, value: f64) -> String {
+        format!("{:.*}", self.label_precision, value)
+    }
     /// Map a value to a vertical level (0-4) for a given cell row
     /// Returns the fill level within this cell
     fn value_to_level(&self, value: f32, height: u16, cell_y: u16) -> usize {
@@ -216,9 +339,107 @@ impl Widget for SignalView<'_> {
             RenderMode::Braille => self.render_braille(area, buf),
             RenderMode::Block => self.render_block(area, buf),
         }
+        self.render_overlays(area, buf);
     }
 }
 impl SignalView<'_> {
+    /// Cursor, then labels on top. Labels that don't fit are skipped.
+    fn render_overlays(&self, area: Rect, buf: &mut Buffer) {
+        if let Some((time, style)) = self.cursor {
+            if let Some(x) = self.time_to_x(time, area.width) {
+                for y in 0..area.height {
+                    if let Some(cell) = buf.cell_mut((area.x + x, area.y + y)) {
+                        cell.set_char('│');
+                        cell.set_style(style);
+                    }
+                }
+            }
+        }
+        if area.width < MIN_LABEL_WIDTH || area.height < MIN_LABEL_HEIGHT {
+            return;
+        }
+        let mut plot_rows = area.height;
+        if self.show_time_labels {
+            let (start, end) = self.time_range;
+            let start = self.format_label(start);
+            let end = self.format_label(end);
+            let (start_len, end_len) = (start.chars().count(), end.chars().count());
+            if start_len + end_len < area.width as usize {
+                let y = area.height - 1;
+                self.put_label(area, buf, 0, y, &start);
+                self.put_label(area, buf, area.width - end_len as u16, y, &end);
+                plot_rows -= 1;
+            }
+        }
+        let mut top_row_used = 0;
+        if self.show_axis_labels && plot_rows >= MIN_LABEL_HEIGHT {
+            let (min, max) = self.value_range;
+            let max_label = self.format_label(max as f64);
+            top_row_used = max_label.chars().count() + 1;
+            self.put_label(area, buf, 0, 0, &max_label);
+            self.put_label(area, buf, 0, plot_rows - 1, &self.format_label(min as f64));
+            let mid_row = (plot_rows - 1) / 2;
+            if self.show_midpoint_label && mid_row > 0 && mid_row < plot_rows - 1 {
+                let mid = (min as f64 + max as f64) / 2.0;
+                self.put_label(area, buf, 0, mid_row, &self.format_label(mid));
+            }
+        }
+        if self.show_legend {
+            self.render_legend(area, buf, top_row_used);
+        }
+    }
+    /// Right-aligned `■ name` entries; skipped unless all fit beside `reserved` cells.
+    fn render_legend(&self, area: Rect, buf: &mut Buffer, reserved: usize) {
+        let primary = self.label.as_deref().map(|label| (self.style, label));
+        let entries: Vec<(Style, &str)> = primary
+            .into_iter()
+            .chain(
+                self.overlays
+                    .iter()
+                    .map(|trace| (trace.style, trace.label.as_str())),
+            )
+            .filter(|(_, label)| !label.is_empty())
+            .collect();
+        if entries.is_empty() {
+            return;
+        }
+        let len: usize = entries
+            .iter()
+            .map(|(_, label)| label.chars().count() + 2)
+            .sum::<usize>()
+            + entries.len()
+            - 1;
+        if reserved + len > area.width as usize {
+            return;
+        }
+        let mut x = area.width - len as u16;
+        for (i, (style, label)) in entries.iter().enumerate() {
+            if let Some(cell) = buf.cell_mut((area.x + x, area.y)) {
+                cell.set_char('■');
+                cell.set_style(*style);
+            }
+            // Separator space after every entry but the last
+            let text = if i + 1 < entries.len() {
+                format!(" {label} ")
+            } else {
+                format!(" {label}")
+            };
+            self.put_label(area, buf, x + 1, 0, &text);
+            x += 1 + text.chars().count() as u16;
+        }
+    }
+    /// Write a label at (x, y) within `area`, skipping it if it would be cut off.
+    fn put_label(&self, area: Rect, buf: &mut Buffer, x: u16, y: u16, text: &str) {
+        if x as usize + text.chars().count() > area.width as usize {
+            return;
+        }
+        for (i, ch) in text.chars().enumerate() {
+            if let Some(cell) = buf.cell_mut((area.x + x + i as u16, area.y + y)) {
+                cell.set_char(ch);
+                cell.set_style(self.label_style);
+            }
+        }
+    }
     fn render_block(&self, area: Rect, buf: &mut Buffer) {
         // Draw zero line if enabled
         if self.show_zero_line {
@@ -233,11 +454,21 @@ impl SignalView<'_> {
                 }
             }
         }
-        // Draw waveform
+        for (signal, fixed) in self.traces() {
+            self.render_block_trace(area, buf, signal, fixed);
+        }
+    }
+    fn render_block_trace(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        signal: &dyn Signal,
+        fixed: Option<Style>,
+    ) {
         let mut prev_y: Option<u16> = None;
         for x in 0..area.width {
             let t = self.x_to_time(x, area.width);
-            let value = self.signal.sample(t);
+            let value = signal.sample(t);
             let y = self.value_to_y(value, area.height);
             // Draw vertical line from previous Y to current Y for continuity
             if let Some(py) = prev_y {
@@ -247,7 +478,7 @@ impl SignalView<'_> {
                         let cell = buf.cell_mut((area.x + x, area.y + draw_y));
                         if let Some(cell) = cell {
                             cell.set_char(self.wave_char);
-                            cell.set_style(self.style_for_value(value));
+                            cell.set_style(self.trace_style(value, fixed));
                         }
                     }
                 }
@@ -255,13 +486,45 @@ impl SignalView<'_> {
                 let cell = buf.cell_mut((area.x + x, area.y + y));
                 if let Some(cell) = cell {
                     cell.set_char(self.wave_char);
-                    cell.set_style(self.style_for_value(value));
+                    cell.set_style(self.trace_style(value, fixed));
                 }
             }
             prev_y = Some(y);
         }
     }
     fn render_braille(&self, area: Rect, buf: &mut Buffer) {
+        for (signal, fixed) in self.traces() {
+            self.render_braille_trace(area, buf, signal, fixed);
+        }
+        // Draw zero line if enabled (as an overlay)
+        if self.show_zero_line {
+            for cell_x in 0..area.width {
+                // Find which cell row contains the zero line
+                for cell_y in 0..area.height {
+                    let cell_level = self.value_to_level(0.0, area.height, cell_y);
+                    if cell_level > 0 && cell_level < 4 {
+                        // Zero line crosses this cell - draw a horizontal line
+                        let cell = buf.cell_mut((area.x + cell_x, area.y + cell_y));
+                        if let Some(cell) = cell {
+                            // Overlay a subtle zero line marker
+                            if cell.symbol() == " " {
+                                cell.set_char('─');
+                                cell.set_style(self.zero_line_style);
+                            }
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    fn render_braille_trace(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        signal: &dyn Signal,
+        fixed: Option<Style>,
+    ) {
         // btop-style filled area graph using Braille characters
         // Each character encodes transition from previous to current value
         let (time_start, time_end) = self.time_range;
@@ -271,7 +534,7 @@ impl SignalView<'_> {
         let values: Vec<f32> = (0..area.width)
             .map(|x| {
                 let t = time_start + (x as f64 / area.width.max(1) as f64) * time_range;
-                self.signal.sample(t)
+                signal.sample(t)
             })
             .collect();
         // Render each cell
@@ -289,33 +552,12 @@ impl SignalView<'_> {
                     let cell = buf.cell_mut((area.x + cell_x, area.y + cell_y));
                     if let Some(cell) = cell {
                         cell.set_char(ch);
-                        cell.set_style(self.style_for_value(current_value));
+                        cell.set_style(self.trace_style(current_value, fixed));
                     }
                 }
                 prev_level = Some(current_level);
             }
         }
-        // Draw zero line if enabled (as an overlay)
-        if self.show_zero_line {
-            for cell_x in 0..area.width {
-                // Find which cell row contains the zero line
-                for cell_y in 0..area.height {
-                    let cell_level = self.value_to_level(0.0, area.height, cell_y);
-                    if cell_level > 0 && cell_level < 4 {
-                        // Zero line crosses this cell - draw a horizontal line
-                        let cell = buf.cell_mut((area.x + cell_x, area.y + cell_y));
-                        if let Some(cell) = cell {
-                            // Overlay a subtle zero line marker
-                            if cell.symbol() == " " {
-                                cell.set_char('─');
-                                cell.set_style(self.zero_line_style);
-                            }
-                        }
-                        break;
-                    }
-                }
-            }
-        }
     }
 }
 #[cfg(test)]
@@ -370,7 +612,153 @@ mod tests {
         assert_eq!(gradient.color_for(0.5, (0.0, 1.0)).unwrap(), Color::Green);
         assert_eq!(gradient.color_for(1.0, (0.0, 1.0)).unwrap(), Color::Red);
     }
+
+    /// Render a flat zero signal in Block mode over [-1, 1] and return its rows.
+    fn render_rows(view: SignalView, width: u16, height: u16) -> (Buffer, Vec<String>) {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        view.render_mode(RenderMode::Block)
+            .show_zero_line(false)
+            .value_range(-1.0, 1.0)
+            .wave_char('=')
+            .render(area, &mut buf);
+        let rows = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buf.cell((x, y)).unwrap().symbol().to_string())
+                    .collect()
+            })
+            .collect();
+        (buf, rows)
+    }
+
+    #[test]
+    fn test_axis_labels() {
+        let flat = Constant::new(0.0);
+        let view = SignalView::new(&flat)
+            .show_axis_labels(true)
+            .show_midpoint_label(true)
+            .label_precision(1);
+        let (buf, rows) = render_rows(view, 10, 5);
+        assert_eq!(
+            rows,
+            [
+                "1.0       ",
+                "          ",
+                "0.0=======",
+                "          ",
+                "-1.0      "
+            ]
+        );
+        assert_eq!(buf.cell((0, 0)).unwrap().fg, Color::Gray);
+    }
+
+    #[test]
+    fn test_time_labels_share_bottom_row() {
+        let flat = Constant::new(0.0);
+        let view = SignalView::new(&flat)
+            .time_range(0.5, 2.25)
+            .time_labels(true)
+            .show_axis_labels(true);
+        let (_, rows) = render_rows(view, 12, 4);
+        assert_eq!(
+            rows,
+            [
+                "1.00        ",
+                "============",
+                "-1.00       ",
+                "0.50    2.25"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cursor_marks_column() {
+        let flat = Constant::new(0.0);
+        let marker = Style::default().fg(Color::Yellow);
+        let view = SignalView::new(&flat)
+            .time_range(0.0, 2.0)
+            .cursor(1.0, marker);
+        let (buf, rows) = render_rows(view, 9, 3);
+        assert_eq!(rows, ["    │    ", "====│====", "    │    "]);
+        assert_eq!(buf.cell((4, 1)).unwrap().fg, Color::Yellow);
+    }
+
+    #[test]
+    fn test_overlay_signals_share_axes() {
+        let high = Constant::new(0.5);
+        let low = Constant::new(-0.5);
+        let red = Style::default().fg(Color::Red);
+        let view = SignalView::new(&high).style(red).overlay(
+            &low,
+            Style::default().fg(Color::Green),
+            "low",
+        );
+        let (buf, rows) = render_rows(view, 4, 5);
+        assert_eq!(rows, ["    ", "====", "    ", "====", "    "]);
+        assert_eq!(buf.cell((0, 1)).unwrap().fg, Color::Red);
+        assert_eq!(buf.cell((3, 3)).unwrap().fg, Color::Green);
+    }
+
+    #[test]
+    fn test_overlay_braille_last_trace_wins() {
+        let full = Constant::new(1.0);
+        let half = Constant::new(0.0);
+        let area = Rect::new(0, 0, 3, 2);
+        let mut buf = Buffer::empty(area);
+        SignalView::new(&full)
+            .value_range(-1.0, 1.0)
+            .style(Style::default().fg(Color::Red))
+            .overlay(&half, Style::default().fg(Color::Blue), "half")
+            .render(area, &mut buf);
+        // The top row only holds the full-scale trace; the bottom row is covered by both
+        assert_eq!(buf.cell((1, 0)).unwrap().symbol(), "⣿");
+        assert_eq!(buf.cell((1, 0)).unwrap().fg, Color::Red);
+        assert_eq!(buf.cell((1, 1)).unwrap().symbol(), "⣿");
+        assert_eq!(buf.cell((1, 1)).unwrap().fg, Color::Blue);
+    }
+
+    #[test]
+    fn test_legend_row() {
+        let high = Constant::new(0.5);
+        let low = Constant::new(-0.5);
+        let green = Style::default().fg(Color::Green);
+        let view = SignalView::new(&high)
+            .label("raw")
+            .overlay(&low, green, "lp")
+            .show_legend(true);
+        let (buf, rows) = render_rows(view, 12, 3);
+        assert_eq!(rows[0], "==■ raw ■ lp");
+        assert_eq!(buf.cell((8, 0)).unwrap().fg, Color::Green);
+        assert_eq!(buf.cell((9, 0)).unwrap().fg, Color::Gray);
+        // Too narrow for every entry: no legend at all
+        let view = SignalView::new(&high).label("raw").show_legend(true);
+        let (_, rows) = render_rows(view, 4, 3);
+        assert!(!rows[0].contains('■'));
+    }
+
+    #[test]
+    fn test_overlays_degrade_gracefully() {
+        let flat = Constant::new(0.0);
+        let marker = Style::default().fg(Color::Yellow);
+        // Out-of-range and non-finite cursors draw nothing
+        for time in [-0.1, 1.5, f64::NAN, f64::INFINITY] {
+            let view = SignalView::new(&flat).cursor(time, marker);
+            let (_, rows) = render_rows(view, 6, 3);
+            assert_eq!(rows, ["      ", "======", "      "]);
+        }
+        // Too small for labels: only the waveform is drawn
+        for (width, height) in [(6, 5), (12, 1), (1, 1)] {
+            let view = SignalView::new(&flat)
+                .show_axis_labels(true)
+                .show_midpoint_label(true)
+                .time_labels(true)
+                .cursor(0.0, marker);
+            let (_, rows) = render_rows(view, width, height);
+            assert!(rows.iter().all(|row| !row.contains('.')), "{rows:?}");
+        }
+    }
 }
 
 // <FILE>src/visualization/cls_signal_view.rs</FILE> - <DESC>Signal oscilloscope widget</DESC>
-// <VERS>END OF VERSION: 1.3.0 - 2025-12-27T13:14:12Z</VERS>
+// <VERS>END OF VERSION: 1.8.0</VERS>
@@ -0,0 +1,128 @@
+// <FILE>src/visualization/fnc_sparkline.rs</FILE> - <DESC>Render a signal as a one-line block-character sparkline</DESC>
+// <VERS>VERSION: 1.1.0</VERS>
+// <WCTX>Sparklines without a TUI</WCTX>
+// <CLOG>Builds without the visualization feature or std (alloc String, FloatExt)</CLOG>
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatExt;
+use crate::traits::{Signal, SignalTime};
+use alloc::string::String;
+
+/// Block characters from lowest to highest level.
+const SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Render `width` samples of a signal as a single-line sparkline.
+///
+/// Samples are evenly spaced from `time_range.0` to `time_range.1`
+/// (inclusive). Each value is mapped over `value_range` onto one of eight
+/// block characters (`▁` = min, `█` = max); values outside the range clamp to
+/// the nearest end and non-finite values render as a space.
+///
+/// # Example
+///
+/// ```rust
+/// use mixed_signals::generators::Ramp;
+/// use mixed_signals::visualization::sparkline;
+///
+/// let line = sparkline(&Ramp::new(0.0, 1.0, 1.0), 8, (0.0, 1.0), (0.0, 1.0));
+/// assert_eq!(line, "▁▂▃▄▅▆▇█");
+/// ```
+pub fn sparkline(
+    signal: &dyn Signal,
+    width: usize,
+    time_range: (SignalTime, SignalTime),
+    value_range: (f32, f32),
+) -> String {
+    let (start, end) = time_range;
+    let (min, max) = value_range;
+    let span = max - min;
+
+    (0..width)
+        .map(|x| {
+            let t = if width <= 1 {
+                start
+            } else {
+                start + (end - start) * (x as SignalTime / (width - 1) as SignalTime)
+            };
+            let value = signal.sample(t);
+            if !value.is_finite() {
+                return ' ';
+            }
+            let normalized = if span.abs() < 0.0001 {
+                0.5
+            } else {
+                ((value - min) / span).clamp(0.0, 1.0)
+            };
+            let level = (normalized * (SPARK_CHARS.len() - 1) as f32).round() as usize;
+            SPARK_CHARS[level.min(SPARK_CHARS.len() - 1)]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Constant, Ramp};
+    use crate::traits::from_fn;
+
+    #[test]
+    fn test_sparkline_ramp_ascends() {
+        let ramp = Ramp::new(0.0, 1.0, 1.0);
+        let line = sparkline(&ramp, 16, (0.0, 1.0), (0.0, 1.0));
+        let chars: Vec<char> = line.chars().collect();
+        assert_eq!(chars.len(), 16);
+        assert_eq!(chars[0], '▁');
+        assert_eq!(chars[15], '█');
+        for pair in chars.windows(2) {
+            let a = SPARK_CHARS.iter().position(|&c| c == pair[0]).unwrap();
+            let b = SPARK_CHARS.iter().position(|&c| c == pair[1]).unwrap();
+            assert!(b >= a, "{line}");
+        }
+    }
+
+    #[test]
+    fn test_sparkline_constant_is_flat() {
+        let line = sparkline(&Constant::new(0.3), 20, (0.0, 5.0), (0.0, 1.0));
+        let first = line.chars().next().unwrap();
+        assert!(line.chars().all(|c| c == first));
+        assert_eq!(line.chars().count(), 20);
+    }
+
+    #[test]
+    fn test_sparkline_clamps_and_non_finite() {
+        assert_eq!(
+            sparkline(&Constant::new(5.0), 3, (0.0, 1.0), (0.0, 1.0)),
+            "███"
+        );
+        assert_eq!(
+            sparkline(&Constant::new(-5.0), 3, (0.0, 1.0), (0.0, 1.0)),
+            "▁▁▁"
+        );
+        assert_eq!(
+            sparkline(&from_fn(|_| f32::NAN), 2, (0.0, 1.0), (0.0, 1.0)),
+            "  "
+        );
+    }
+
+    #[test]
+    fn test_sparkline_degenerate_sizes() {
+        assert_eq!(
+            sparkline(&Constant::new(0.5), 0, (0.0, 1.0), (0.0, 1.0)),
+            ""
+        );
+        assert_eq!(
+            sparkline(&Constant::new(0.5), 1, (0.0, 1.0), (0.0, 1.0))
+                .chars()
+                .count(),
+            1
+        );
+        // Zero-width value range renders the middle level
+        assert_eq!(
+            sparkline(&Constant::new(0.5), 2, (0.0, 1.0), (0.5, 0.5)),
+            "▅▅"
+        );
+    }
+}
+
+// <FILE>src/visualization/fnc_sparkline.rs</FILE> - <DESC>Render a signal as a one-line block-character sparkline</DESC>
+// <VERS>END OF VERSION: 1.1.0</VERS>
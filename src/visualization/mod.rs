@@ -1,13 +1,30 @@
 // <FILE>src/visualization/mod.rs</FILE> - <DESC>Signal visualization module</DESC>
-// <VERS>VERSION: 2.0.1</VERS>
-// <WCTX>mixed-signals extraction: bgraph integration</WCTX>
-// <CLOG>Fixed SignalView wrapper to work with bgraph OFPF refactor</CLOG>
+// <VERS>VERSION: 2.5.0</VERS>
+// <WCTX>Sparklines without a TUI</WCTX>
+// <CLOG>sparkline ungated; ratatui widgets behind the visualization feature</CLOG>
 
-//! Signal visualization using an internal SignalView widget.
+//! Signal visualization: text sparklines, plus ratatui widgets (SignalView,
+//! HistogramView, ...) with the `visualization` feature.
 
+#[cfg(feature = "visualization")]
+mod cls_color_field;
+#[cfg(feature = "visualization")]
+mod cls_frame_clock;
+#[cfg(feature = "visualization")]
+mod cls_histogram_view;
+#[cfg(feature = "visualization")]
 mod cls_signal_view;
+mod fnc_sparkline;
 
+#[cfg(feature = "visualization")]
+pub use cls_color_field::ColorField;
+#[cfg(feature = "visualization")]
+pub use cls_frame_clock::FrameClock;
+#[cfg(feature = "visualization")]
+pub use cls_histogram_view::HistogramView;
+#[cfg(feature = "visualization")]
 pub use cls_signal_view::{ColorGradient, RenderMode, SignalView};
+pub use fnc_sparkline::sparkline;
 
 // <FILE>src/visualization/mod.rs</FILE> - <DESC>Signal visualization module</DESC>
-// <VERS>END OF VERSION: 2.0.1</VERS>
+// <VERS>END OF VERSION: 2.5.0</VERS>
@@ -0,0 +1,122 @@
+// <FILE>tests/pink_noise_spectrum.rs</FILE> - <DESC>Spectral slope tests for pink noise row counts</DESC>
+// <VERS>VERSION: 1.0.0</VERS>
+// <WCTX>Pink noise spectral accuracy</WCTX>
+// <CLOG>Initial creation - octave-band DFT slope checks for PinkNoise and FastPinkNoise</CLOG>
+
+//! Statistical checks that Voss-McCartney pink noise falls at -3 dB/octave.
+//!
+//! Each test renders a few thousand frames per seed, measures the mean power in
+//! octave bands with a plain DFT, and fits a line through the band levels.
+
+use mixed_signals::random::{FastPinkNoise, PinkNoise};
+use mixed_signals::traits::{Signal, SignalContext};
+
+/// Frames per DFT.
+const N: usize = 4096;
+
+/// Independent seeds averaged per measurement.
+const REPS: u64 = 4;
+
+/// Octave bands measured, counted down from the band just below Nyquist.
+const BANDS: usize = 6;
+
+/// Least-squares slope in dB/octave across `BANDS` octave bands.
+///
+/// Band `j` covers DFT bins `[N / 2^(j + 2), N / 2^(j + 1))`, i.e. a quarter to
+/// half of the frame rate for `j = 0`, an octave lower for each step after.
+fn octave_slope(signal: &dyn Signal) -> f64 {
+    let twiddles: Vec<(f64, f64)> = (0..N)
+        .map(|i| {
+            let angle = std::f64::consts::TAU * i as f64 / N as f64;
+            (angle.cos(), angle.sin())
+        })
+        .collect();
+    let mut band_power = [0.0f64; BANDS];
+
+    for rep in 0..REPS {
+        let buffer: Vec<f64> = (0..N as u64)
+            .map(|frame| signal.sample_with_context(0.0, &SignalContext::new(frame, rep)) as f64)
+            .collect();
+        let mean = buffer.iter().sum::<f64>() / N as f64;
+
+        for (band, power) in band_power.iter_mut().enumerate() {
+            let (lo, hi) = (N >> (band + 2), N >> (band + 1));
+            for k in lo..hi {
+                let (mut re, mut im) = (0.0, 0.0);
+                for (n, x) in buffer.iter().enumerate() {
+                    let (c, s) = twiddles[(k * n) % N];
+                    re += (x - mean) * c;
+                    im -= (x - mean) * s;
+                }
+                *power += (re * re + im * im) / (hi - lo) as f64;
+            }
+        }
+    }
+
+    // Fit dB against octave index (higher index = lower frequency)
+    let levels: Vec<f64> = band_power.iter().map(|p| 10.0 * p.log10()).collect();
+    let x_mean = (BANDS - 1) as f64 / 2.0;
+    let y_mean = levels.iter().sum::<f64>() / BANDS as f64;
+    let (mut num, mut den) = (0.0, 0.0);
+    for (j, y) in levels.iter().enumerate() {
+        let dx = j as f64 - x_mean;
+        num += dx * (y - y_mean);
+        den += dx * dx;
+    }
+    // Each band index step is one octave down, so negate for dB per octave up
+    -num / den
+}
+
+fn assert_pink(slope: f64, label: &str) {
+    assert!(
+        (slope + 3.0).abs() < 0.5,
+        "{label}: slope {slope:.2} dB/octave, expected ~-3"
+    );
+}
+
+#[test]
+fn pink_noise_rows_slope_is_minus_3db() {
+    for rows in [8, 10, 12] {
+        let noise = PinkNoise::with_seed(17).with_rows(rows);
+        assert_pink(octave_slope(&noise), &format!("PinkNoise rows={rows}"));
+    }
+}
+
+#[test]
+fn fast_pink_noise_rows_slope_is_minus_3db() {
+    for rows in [8, 10, 12] {
+        let noise = FastPinkNoise::with_seed(17).with_rows(rows);
+        assert_pink(octave_slope(&noise), &format!("FastPinkNoise rows={rows}"));
+    }
+}
+
+#[test]
+fn pink_noise_rows_stay_within_amplitude() {
+    for rows in [1, 3, 8, 16, 32] {
+        let pink = PinkNoise::new(5, 0.7, 0.0).with_rows(rows);
+        let fast = FastPinkNoise::new(5, 0.7, 0.0).with_rows(rows);
+        for frame in 0..2000 {
+            let ctx = SignalContext::new(frame, 0);
+            for v in [
+                pink.sample_with_context(0.0, &ctx),
+                fast.sample_with_context(0.0, &ctx),
+            ] {
+                assert!(v.abs() <= 0.7, "rows={rows} frame={frame}: {v}");
+            }
+        }
+    }
+}
+
+#[test]
+fn more_rows_add_low_frequency_power() {
+    // The lowest measured band gains power relative to the top band
+    let shallow = octave_slope(&PinkNoise::with_seed(3));
+    let deep = octave_slope(&PinkNoise::with_seed(3).with_rows(12));
+    assert!(
+        deep < shallow,
+        "rows=12 slope {deep:.2} vs default {shallow:.2}"
+    );
+}
+
+// <FILE>tests/pink_noise_spectrum.rs</FILE> - <DESC>Spectral slope tests for pink noise row counts</DESC>
+// <VERS>END OF VERSION: 1.0.0</VERS>
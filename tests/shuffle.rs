@@ -1,13 +1,14 @@
 // <FILE>tests/shuffle.rs</FILE> - <DESC>Integration tests for shuffle algorithms</DESC>
-// <VERS>VERSION: 2.0.0</VERS>
-// <WCTX>Implementing shuffle algorithms phases 2-4</WCTX>
-// <CLOG>Added tests for weighted, constrained, interleave, riffle, overhand, reservoir, smooth, and animators</CLOG>
+// <VERS>VERSION: 2.3.0</VERS>
+// <WCTX>Exposing mid-animation shuffle state for rendering</WCTX>
+// <CLOG>Added animator visual state tests</CLOG>
 
 use mixed_signals::rng::Rng;
 use mixed_signals::shuffle::{
     constrained_shuffle, fisher_yates, interleave, overhand_shuffle, partial_shuffle,
-    reservoir_shuffle, riffle_shuffle, sattolo, shuffle_copy, smooth_shuffle, weighted_shuffle,
-    OverhandAnimator, RiffleAnimator,
+    permutation_from_index, reservoir_shuffle, riffle_shuffle, sattolo, shuffle_copy,
+    smooth_shuffle, try_constrained_shuffle, weighted_shuffle, OverhandAnimator, RiffleAnimator,
+    RiffleSource, RiffleState,
 };
 use std::collections::HashSet;
 
@@ -489,6 +490,77 @@ fn test_constrained_shuffle_empty() {
     assert!(empty.is_empty());
 }
 
+fn max_run(items: &[usize]) -> usize {
+    let mut best = 0;
+    let mut run = 0;
+    for (i, item) in items.iter().enumerate() {
+        run = if i > 0 && items[i - 1] == *item {
+            run + 1
+        } else {
+            1
+        };
+        best = best.max(run);
+    }
+    best
+}
+
+#[test]
+fn test_try_constrained_shuffle_impossible_returns_err() {
+    let mut rng = Rng::with_seed(42);
+    // 9 of one kind, 1 of another: at most 2 A's can be separated by one B
+    let original: Vec<usize> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+    let mut items = original.clone();
+
+    let err = try_constrained_shuffle(&mut items, &mut rng, 1, |x| *x)
+        .expect_err("9 A's and 1 B cannot alternate");
+    assert_eq!(err.max_consecutive, 1);
+    assert!(!err.violations.is_empty());
+    for &pos in &err.violations {
+        assert_eq!(items[pos], items[pos - 1], "position {} is not a run", pos);
+    }
+
+    let mut sorted = items.clone();
+    sorted.sort();
+    assert_eq!(sorted, original, "Should preserve all elements");
+}
+
+#[test]
+fn test_try_constrained_shuffle_large_input() {
+    let mut rng = Rng::with_seed(7);
+    // 5000 tracks over 10 uneven genres; the largest genre is still feasible
+    let mut items: Vec<usize> = (0..50_000).map(|i| (i * i + 3 * i) % 10).collect();
+    items.truncate(5000);
+    let mut expected_counts = [0usize; 10];
+    for &g in &items {
+        expected_counts[g] += 1;
+    }
+
+    let start = std::time::Instant::now();
+    try_constrained_shuffle(&mut items, &mut rng, 1, |g| *g).expect("feasible input");
+    assert!(start.elapsed().as_secs() < 2, "took {:?}", start.elapsed());
+
+    assert_eq!(max_run(&items), 1);
+    let mut counts = [0usize; 10];
+    for &g in &items {
+        counts[g] += 1;
+    }
+    assert_eq!(counts, expected_counts);
+}
+
+#[test]
+fn test_try_constrained_shuffle_determinism() {
+    let items: Vec<usize> = (0..200).map(|i| i % 7 % 4).collect();
+    let mut a = items.clone();
+    let mut b = items.clone();
+    let mut c = items.clone();
+    assert!(try_constrained_shuffle(&mut a, &mut Rng::with_seed(3), 2, |x| *x).is_ok());
+    assert!(try_constrained_shuffle(&mut b, &mut Rng::with_seed(3), 2, |x| *x).is_ok());
+    assert!(try_constrained_shuffle(&mut c, &mut Rng::with_seed(4), 2, |x| *x).is_ok());
+    assert_eq!(a, b, "Same seed should produce same order");
+    assert_ne!(a, c, "Different seeds should differ");
+    assert!(max_run(&a) <= 2);
+}
+
 // ============================================================================
 // Interleave Tests (Phase 3)
 // ============================================================================
@@ -673,6 +745,70 @@ fn test_riffle_animator_zero_passes() {
     assert_eq!(animator.into_items(), deck);
 }
 
+#[test]
+fn test_riffle_animator_packets_partition_deck() {
+    let deck: Vec<i32> = (1..=52).collect();
+    let mut animator = RiffleAnimator::new(deck.clone(), 3, &mut Rng::with_seed(9));
+    assert_eq!(animator.state(), RiffleState::Cutting);
+    assert_eq!(animator.left_packet().len(), animator.cut_point());
+    assert!(animator.next_source().is_some());
+
+    loop {
+        let mut all: Vec<i32> = animator.left_packet().to_vec();
+        all.extend_from_slice(animator.merged());
+        all.extend_from_slice(animator.right_packet());
+        all.sort();
+        assert_eq!(all, deck, "packets must partition the deck");
+        assert_eq!(animator.sources().len(), animator.merged().len());
+
+        let next = animator.next_source();
+        let merged_before = animator.merged().len();
+        if !animator.step() {
+            break;
+        }
+        if animator.merged().len() == merged_before + 1 {
+            assert_eq!(animator.last_source(), next);
+        }
+    }
+
+    assert!(animator.left_packet().is_empty());
+    assert!(animator.right_packet().is_empty());
+    assert_eq!(animator.next_source(), None);
+    assert_eq!(animator.passes_completed(), 3);
+}
+
+#[test]
+fn test_riffle_animator_sources_track_packets() {
+    let deck: Vec<i32> = (0..20).collect();
+    let mut animator = RiffleAnimator::new(deck, 1, &mut Rng::with_seed(4));
+    let cut = animator.cut_point() as i32;
+
+    while animator.next_source().is_some() || animator.state() == RiffleState::Cutting {
+        animator.step();
+    }
+    for (card, source) in animator.merged().iter().zip(animator.sources()) {
+        let expected = if *card < cut {
+            RiffleSource::Left
+        } else {
+            RiffleSource::Right
+        };
+        assert_eq!(*source, expected, "card {}", card);
+    }
+}
+
+#[test]
+fn test_riffle_animator_matches_riffle_shuffle() {
+    for (seed, passes) in [(42, 1), (7, 3), (1234, 7)] {
+        let deck: Vec<i32> = (1..=52).collect();
+        let mut expected = deck.clone();
+        riffle_shuffle(&mut expected, passes, &mut Rng::with_seed(seed));
+
+        let mut animator = RiffleAnimator::new(deck, passes, &mut Rng::with_seed(seed));
+        while animator.step() {}
+        assert_eq!(animator.into_items(), expected, "seed {}", seed);
+    }
+}
+
 // ============================================================================
 // Overhand Animator Tests (Phase 3)
 // ============================================================================
@@ -707,6 +843,47 @@ fn test_overhand_animator_preserves_elements() {
     assert_eq!(original_set, result_set);
 }
 
+#[test]
+fn test_overhand_animator_packets_partition_deck() {
+    let deck: Vec<i32> = (1..=40).collect();
+    let mut animator = OverhandAnimator::new(deck.clone(), 2, &mut Rng::with_seed(11));
+
+    loop {
+        let mut all: Vec<i32> = animator.hand().to_vec();
+        all.extend_from_slice(animator.current_chunk());
+        all.extend_from_slice(animator.pile());
+        all.sort();
+        assert_eq!(all, deck, "hand, chunk and pile must partition the deck");
+
+        let packets = animator.pile_packets();
+        let covered: usize = packets.iter().map(|r| r.len()).sum();
+        assert_eq!(covered, animator.pile().len());
+        for pair in packets.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+
+        if !animator.step() {
+            break;
+        }
+    }
+
+    assert_eq!(animator.chunks_remaining(), 0);
+    assert_eq!(animator.passes_completed(), 2);
+}
+
+#[test]
+fn test_overhand_animator_matches_overhand_shuffle() {
+    for (seed, passes) in [(42, 1), (7, 5), (99, 20)] {
+        let deck: Vec<i32> = (1..=52).collect();
+        let mut expected = deck.clone();
+        overhand_shuffle(&mut expected, passes, &mut Rng::with_seed(seed));
+
+        let mut animator = OverhandAnimator::new(deck, passes, &mut Rng::with_seed(seed));
+        while animator.step() {}
+        assert_eq!(animator.into_items(), expected, "seed {}", seed);
+    }
+}
+
 // ============================================================================
 // Reservoir Shuffle Tests (Phase 4)
 // ============================================================================
@@ -838,5 +1015,65 @@ fn test_rng_shuffle_weighted() {
     assert!(items.contains(&"legendary"));
 }
 
+// ============================================================================
+// Permutation Index Tests
+// ============================================================================
+
+#[test]
+fn test_permutation_from_index_zero_is_identity() {
+    let mut items: Vec<i32> = (0..12).collect();
+    permutation_from_index(&mut items, 0);
+    assert_eq!(items, (0..12).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_permutation_from_index_bijective_small_n() {
+    for n in 1..=6usize {
+        let count: u128 = (1..=n as u128).product();
+        let mut seen = HashSet::new();
+        let mut previous: Option<Vec<usize>> = None;
+        for index in 0..count {
+            let mut items: Vec<usize> = (0..n).collect();
+            permutation_from_index(&mut items, index);
+            // Lexicographic order: each permutation follows the previous one
+            if let Some(prev) = &previous {
+                assert!(items > *prev, "n={} index={}", n, index);
+            }
+            previous = Some(items.clone());
+            assert!(seen.insert(items));
+        }
+        assert_eq!(seen.len() as u128, count);
+    }
+    let mut items = vec![1, 2, 3, 4];
+    permutation_from_index(&mut items, 23);
+    assert_eq!(items, vec![4, 3, 2, 1]);
+}
+
+#[test]
+fn test_permutation_from_index_wraps_modulo_factorial() {
+    for index in [0u128, 1, 17, 119] {
+        let mut a: Vec<char> = "abcde".chars().collect();
+        let mut b = a.clone();
+        permutation_from_index(&mut a, index);
+        permutation_from_index(&mut b, index + 120 * 7);
+        assert_eq!(a, b);
+    }
+    let mut wide: Vec<u32> = (0..40).collect();
+    permutation_from_index(&mut wide, u128::MAX);
+    let mut sorted = wide.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..40).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_permutation_from_index_empty_and_single() {
+    let mut empty: Vec<i32> = vec![];
+    permutation_from_index(&mut empty, 5);
+    assert!(empty.is_empty());
+    let mut single = vec![9];
+    permutation_from_index(&mut single, 5);
+    assert_eq!(single, vec![9]);
+}
+
 // <FILE>tests/shuffle.rs</FILE> - <DESC>Integration tests for shuffle algorithms</DESC>
-// <VERS>END OF VERSION: 2.0.0</VERS>
+// <VERS>END OF VERSION: 2.3.0</VERS>
@@ -1,4 +1,6 @@
-use mixed_signals::types::SignalSpec;
+use mixed_signals::generators::Sine;
+use mixed_signals::traits::{Signal, SignalContext, SignalExt};
+use mixed_signals::types::{SignalBuildError, SignalSpec, SpecRegistry};
 
 #[test]
 fn signal_spec_build_ok() {
@@ -7,6 +9,7 @@ fn signal_spec_build_ok() {
         amplitude: 0.5,
         offset: 0.0,
         phase: 0.0,
+        harmonics: vec![],
     };
     let signal = spec.build().unwrap();
     let value = signal.sample(0.125);
@@ -37,3 +40,215 @@ fn signal_spec_build_composed_propagates_error() {
     };
     assert!(spec.build().is_err());
 }
+
+fn per_char_noise() -> SignalSpec {
+    SignalSpec::PerCharacterNoise {
+        base_seed: 42,
+        amplitude: 1.0,
+        offset: 0.0,
+    }
+}
+
+/// Sample a freshly built tree per character, so stateful filters (which
+/// cache their output per time step) start from the same state each time.
+fn char_values(spec: &SignalSpec) -> Vec<f32> {
+    (0..8)
+        .map(|i| {
+            let ctx = SignalContext::new(0, 0).with_char_index(i);
+            spec.build().unwrap().sample_with_context(0.5, &ctx)
+        })
+        .collect()
+}
+
+#[test]
+fn signal_spec_json_per_character_noise_sees_context() {
+    let json = r#"{
+        "type": "mix",
+        "a": {
+            "type": "delay",
+            "offset": 0.25,
+            "signal": {
+                "type": "clamp",
+                "min": -1.0,
+                "signal": { "type": "per_character_noise", "base_seed": 42 }
+            }
+        },
+        "b": { "type": "constant", "value": 0.0 },
+        "mix": 0.5
+    }"#;
+    let spec: SignalSpec = serde_json::from_str(json).unwrap();
+    let signal = spec.build().unwrap();
+    let ctx = |i| SignalContext::new(0, 0).with_char_index(i);
+
+    let (a, b) = (
+        signal.sample_with_context(1.0, &ctx(3)),
+        signal.sample_with_context(1.0, &ctx(7)),
+    );
+    assert_ne!(a, b);
+    assert_eq!(a, signal.sample_with_context(1.0, &ctx(3)));
+    assert_eq!(b, spec.build().unwrap().sample_with_context(1.0, &ctx(7)));
+}
+
+#[test]
+fn signal_spec_composites_forward_context() {
+    let noise = || Box::new(per_char_noise());
+    let zero = || Box::new(SignalSpec::Constant { value: 0.0 });
+    #[allow(deprecated)]
+    let wrappers = vec![
+        SignalSpec::Add {
+            a: noise(),
+            b: zero(),
+        },
+        SignalSpec::Multiply {
+            a: noise(),
+            b: Box::new(SignalSpec::Constant { value: 0.5 }),
+        },
+        SignalSpec::Scale {
+            a: noise(),
+            b: Box::new(SignalSpec::Constant { value: 0.5 }),
+        },
+        SignalSpec::Sum {
+            a: noise(),
+            b: zero(),
+        },
+        SignalSpec::Mix {
+            a: noise(),
+            b: zero(),
+            mix: 0.5,
+        },
+        SignalSpec::FrequencyMod {
+            carrier: noise(),
+            modulator: zero(),
+            depth: 1.0.into(),
+            carrier_freq: 1.0,
+        },
+        SignalSpec::VcaCentered {
+            carrier: noise(),
+            amplitude: Box::new(SignalSpec::Constant { value: 1.0 }),
+        },
+        SignalSpec::PhaseSine { phase: noise() },
+        SignalSpec::Clamp {
+            signal: noise(),
+            min: -1.0,
+            max: 1.0,
+        },
+        SignalSpec::Quantize {
+            signal: noise(),
+            levels: 16,
+        },
+        SignalSpec::Remap {
+            signal: noise(),
+            in_min: -1.0,
+            in_max: 1.0,
+            out_min: 0.0,
+            out_max: 1.0,
+        },
+        SignalSpec::Invert { signal: noise() },
+        SignalSpec::Abs { signal: noise() },
+        SignalSpec::WaveFolder {
+            signal: noise(),
+            gain: 1.0,
+        },
+        SignalSpec::Iir {
+            signal: noise(),
+            b_coeffs: vec![1.0],
+            a_coeffs: vec![1.0],
+            sample_rate: 60.0,
+        },
+        SignalSpec::MovingAverage {
+            signal: noise(),
+            window_seconds: 0.0,
+            sample_rate: 60.0,
+        },
+        SignalSpec::DryWet {
+            dry: zero(),
+            wet: noise(),
+            mix: 1.0,
+        },
+        SignalSpec::Delay {
+            signal: noise(),
+            offset: 0.1,
+        },
+        SignalSpec::Crossfade {
+            a: noise(),
+            b: zero(),
+            blend: zero(),
+        },
+        SignalSpec::Sequence {
+            segments: vec![(per_char_noise(), 1.0)],
+            looping: false,
+        },
+    ];
+    for spec in wrappers {
+        let values = char_values(&spec);
+        assert!(
+            values.windows(2).any(|w| w[0] != w[1]),
+            "context lost through {spec:?}: {values:?}"
+        );
+        assert_eq!(values, char_values(&spec), "non-deterministic: {spec:?}");
+    }
+}
+
+/// Two sines an octave apart, averaged. Params: `{"frequency": f}` (default 1).
+fn double_sine(params: &serde_json::Value) -> Result<Box<dyn Signal>, SignalBuildError> {
+    let frequency = params
+        .get("frequency")
+        .map(|f| {
+            f.as_f64()
+                .ok_or_else(|| SignalBuildError::Custom("frequency must be a number".into()))
+        })
+        .transpose()?
+        .unwrap_or(1.0) as f32;
+    let low = Sine::with_frequency(frequency);
+    let high = Sine::with_frequency(frequency * 2.0);
+    Ok(Box::new(low.add(high).scale(0.5)))
+}
+
+fn double_sine_registry() -> SpecRegistry {
+    SpecRegistry::new().with_builder("double_sine", double_sine)
+}
+
+#[test]
+fn custom_spec_json_round_trip() {
+    let json = r#"{"type": "custom", "kind": "double_sine", "params": {"frequency": 2.0}}"#;
+    let spec: SignalSpec = serde_json::from_str(json).unwrap();
+    let SignalSpec::Custom { kind, params } = &spec else {
+        panic!("expected a custom spec, got {spec:?}");
+    };
+    assert_eq!(kind, "double_sine");
+    assert_eq!(params.get("frequency").and_then(|f| f.as_f64()), Some(2.0));
+
+    let encoded = serde_json::to_string(&spec).unwrap();
+    let decoded: SignalSpec = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(decoded, spec);
+
+    let signal = decoded.build_with(&double_sine_registry()).unwrap();
+    let expected =
+        (Sine::with_frequency(2.0).sample(0.1) + Sine::with_frequency(4.0).sample(0.1)) * 0.5;
+    assert!((signal.sample(0.1) - expected).abs() < 1e-6);
+}
+
+#[test]
+fn custom_spec_builds_inside_nested_add() {
+    let spec = SignalSpec::Add {
+        a: Box::new(SignalSpec::Constant { value: 1.0 }),
+        b: Box::new(SignalSpec::Add {
+            a: Box::new(SignalSpec::Constant { value: 0.25 }),
+            b: Box::new(SignalSpec::Custom {
+                kind: "double_sine".into(),
+                params: serde_json::Value::default(),
+            }),
+        }),
+    };
+    let signal = spec.build_with(&double_sine_registry()).unwrap();
+    let double = double_sine(&serde_json::Value::default()).unwrap();
+    for i in 0..20 {
+        let t = i as f64 * 0.05;
+        assert!((signal.sample(t) - (1.25 + double.sample(t))).abs() < 1e-6);
+    }
+
+    // Without the builder, the error names the missing kind
+    let err = spec.build().err().unwrap();
+    assert_eq!(err, SignalBuildError::UnknownCustom("double_sine".into()));
+    assert!(err.to_string().contains("double_sine"));
+}